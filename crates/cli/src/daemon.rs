@@ -0,0 +1,690 @@
+//! `roc daemon`, which keeps a roc process running in the background, so that `roc check` and
+//! `roc build` can start from a process that has the compiler loaded already.
+//!
+//! The daemon listens on a Unix socket in the Roc cache directory. A `check` or `build` that
+//! finds it there sends along its stdin, stdout and stderr, and a JSON line
+//! `{ "build", "executable", "cwd", "args", "env" }` with its working directory, arguments and
+//! environment variables. The daemon answers with `{ "pid" }` once the command has started, and
+//! with `{ "exitCode" }` once it is done. While it waits, the CLI forwards signals like Ctrl-C to
+//! that pid, and if the CLI goes away, the daemon stops the command. The daemon answers with
+//! `{ "error" }` instead if it can't run the command, e.g. because it is a different build of
+//! roc. Then the CLI runs the command itself, as it does when no daemon is running, when it
+//! can't send the request, or when `ROC_NO_DAEMON` is set. Once the request is sent, though, the
+//! CLI never runs the command itself, since the daemon may be running it already. Builds are
+//! told apart by the size and modification time of the binary, so a roc that was rebuilt after
+//! the daemon started doesn't use it.
+//!
+//! Before it accepts requests, the daemon type checks a small module and parses the builtins
+//! bitcode, so the code and data of the compiler are paged in and LLVM has set itself up. Each
+//! connection is read on a thread of its own, with a timeout, and each command then runs in a
+//! child process forked from the main thread, with the caller's standard streams and
+//! environment, so it prints straight to the caller's terminal, and a crash or `exit` in the
+//! compiler doesn't take the daemon down with it. That is all the daemon saves: it keeps no
+//! compiler state between commands, so every command loads the builtins, solves its modules and
+//! creates its LLVM context from scratch.
+
+#[cfg(unix)]
+pub use imp::{delegate, serve};
+#[cfg(not(unix))]
+pub use unsupported::{delegate, serve};
+
+/// Set this environment variable to run commands in the CLI even when a daemon is running.
+pub const NO_DAEMON_VAR: &str = "ROC_NO_DAEMON";
+
+#[cfg(not(unix))]
+mod unsupported {
+    use clap::ArgMatches;
+    use std::io;
+
+    pub fn serve(_run: fn(&ArgMatches) -> io::Result<i32>) -> io::Result<i32> {
+        eprintln!("`roc daemon` is only available on Unix-like systems for now.");
+
+        Ok(1)
+    }
+
+    pub fn delegate() -> Option<i32> {
+        None
+    }
+}
+
+#[cfg(unix)]
+mod imp {
+    use bumpalo::Bump;
+    use clap::ArgMatches;
+    use roc_load::FunctionKind;
+    use roc_packaging::cache::{self, RocCacheDir};
+    use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+    use roc_target::Target;
+    use serde_json::{json, Value};
+    use std::io::{self, BufRead, BufReader, Read, Write};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::sync::mpsc;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::NO_DAEMON_VAR;
+    use crate::build_app;
+    use crate::ice::{install_panic_hook, is_compiling_command};
+
+    const SOCKET_FILE_NAME: &str = "daemon.sock";
+
+    /// The caller's stdin, stdout and stderr
+    const STANDARD_STREAMS: [RawFd; 3] = [0, 1, 2];
+
+    /// How long either side waits for the other to send or take a request or its answer, other
+    /// than the exit code, which comes once the command is done.
+    const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+    const WARM_UP_MODULE: &str =
+        "module [main]\n\nmain = Dict.empty {} |> Dict.insert \"\" [1.5]\n";
+
+    fn socket_path() -> PathBuf {
+        cache::roc_cache_dir().join(SOCKET_FILE_NAME)
+    }
+
+    fn executable() -> Option<String> {
+        std::env::current_exe()
+            .ok()?
+            .into_os_string()
+            .into_string()
+            .ok()
+    }
+
+    /// What tells this build of roc apart from others, even ones of the same version: the size
+    /// and modification time of its binary.
+    fn build_id() -> Option<String> {
+        let metadata = std::fs::metadata(std::env::current_exe().ok()?).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+        Some(format!("{} {}", metadata.len(), modified.as_nanos()))
+    }
+
+    /// A request that was read, and that the main thread should start.
+    struct Request {
+        stream: UnixStream,
+        streams: Vec<OwnedFd>,
+        cwd: String,
+        args: Vec<String>,
+        env: Vec<(String, String)>,
+    }
+
+    /// A command that is running in a child process.
+    struct Running {
+        pid: libc::pid_t,
+        stream: UnixStream,
+        /// Whether the caller went away, so that the child was told to stop.
+        hung_up: bool,
+    }
+
+    /// Accepts requests until the process is stopped, running them with `run`.
+    pub fn serve(run: fn(&ArgMatches) -> io::Result<i32>) -> io::Result<i32> {
+        let path = socket_path();
+
+        if UnixStream::connect(&path).is_ok() {
+            eprintln!("A roc daemon is already running at {}", path.display());
+
+            return Ok(1);
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // A daemon that was killed leaves its socket behind.
+        let _ = std::fs::remove_file(&path);
+
+        let listener = UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+
+        // Taken before warming up, so a binary that is replaced from now on counts as another
+        // build.
+        let build = build_id();
+
+        warm_up();
+
+        eprintln!("The roc daemon is listening at {}", path.display());
+
+        // Requests are read on threads of their own, so a caller that is slow to send one doesn't
+        // hold up the others. Only this thread forks, and the reading threads only read from
+        // their sockets and allocate, so they can't hold a lock that a child needs. They wake this
+        // thread up with a byte on `waker` once a request is in `requests`.
+        let (sender, requests) = mpsc::channel::<io::Result<Request>>();
+        let (wake_sender, waker) = UnixStream::pair()?;
+        waker.set_nonblocking(true)?;
+
+        let mut running: Vec<Running> = Vec::new();
+
+        loop {
+            // Wait for a new request, and while commands are running, check on them every few
+            // milliseconds.
+            let mut pollfds = [listener.as_raw_fd(), waker.as_raw_fd()].map(|fd| libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            });
+            let timeout = if running.is_empty() { -1 } else { 10 };
+
+            unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as _, timeout) };
+
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    let sender = sender.clone();
+                    let build = build.clone();
+                    let wake_sender = wake_sender.try_clone()?;
+
+                    let spawned = std::thread::Builder::new()
+                        .name("roc_daemon_request".to_string())
+                        .spawn(move || {
+                            if let Some(request) =
+                                read_request(stream, build.as_deref()).transpose()
+                            {
+                                let _ = sender.send(request);
+                                let _ = (&wake_sender).write_all(&[0]);
+                            }
+                        });
+
+                    if let Err(error) = spawned {
+                        eprintln!("A request to the daemon failed: {error}");
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => {}
+                Err(error) => eprintln!("A request to the daemon failed: {error}"),
+            }
+
+            while (&waker).read(&mut [0; 64]).is_ok_and(|read| read > 0) {}
+
+            while let Ok(request) = requests.try_recv() {
+                let mut inherited = vec![
+                    listener.as_raw_fd(),
+                    waker.as_raw_fd(),
+                    wake_sender.as_raw_fd(),
+                ];
+                inherited.extend(running.iter().map(|child| child.stream.as_raw_fd()));
+
+                match request.and_then(|request| start(request, &inherited, run)) {
+                    Ok(Some(child)) => running.push(child),
+                    Ok(None) => {}
+                    Err(error) => eprintln!("A request to the daemon failed: {error}"),
+                }
+            }
+
+            running.retain_mut(|child| {
+                // The caller never sends anything after its request, so a readable socket means
+                // it hung up, e.g. because it was killed. Then its command has nobody to report
+                // to.
+                if !child.hung_up && has_hung_up(&child.stream) {
+                    unsafe { libc::kill(child.pid, libc::SIGTERM) };
+
+                    child.hung_up = true;
+                }
+
+                match exit_code_if_done(child.pid) {
+                    Some(exit_code) => {
+                        let response = json!({ "exitCode": exit_code });
+
+                        if !child.hung_up {
+                            if let Err(error) = writeln!(&child.stream, "{response}") {
+                                eprintln!("A request to the daemon failed: {error}");
+                            }
+                        }
+
+                        false
+                    }
+                    None => true,
+                }
+            });
+        }
+    }
+
+    fn warm_up() {
+        let arena = Bump::new();
+
+        let _ = roc_load::load_and_typecheck_str(
+            &arena,
+            PathBuf::from("WarmUp.roc"),
+            WARM_UP_MODULE,
+            PathBuf::from("."),
+            Target::default(),
+            FunctionKind::LambdaSet,
+            RenderTarget::Generic,
+            RocCacheDir::Disallowed,
+            DEFAULT_PALETTE,
+            None,
+        );
+
+        let context = inkwell::context::Context::create();
+        let _ =
+            roc_gen_llvm::llvm::build::module_from_builtins(Target::default(), &context, "warm_up");
+    }
+
+    /// Reads a request from a caller that just connected. Requests that can't run are answered
+    /// right away, and give `None`.
+    fn read_request(stream: UnixStream, build: Option<&str>) -> io::Result<Option<Request>> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        let streams = receive_fds(&stream)?;
+
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line)?;
+
+        let request: Value = serde_json::from_str(&line)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        let is_same_build = build.is_some()
+            && request.get("build").and_then(Value::as_str) == build
+            && request.get("executable").and_then(Value::as_str) == executable().as_deref();
+
+        let cwd = request.get("cwd").and_then(Value::as_str);
+        let args: Option<Vec<String>> =
+            request
+                .get("args")
+                .and_then(Value::as_array)
+                .and_then(|args| {
+                    args.iter()
+                        .map(|arg| Some(arg.as_str()?.to_string()))
+                        .collect()
+                });
+        let env: Option<Vec<(String, String)>> = request
+            .get("env")
+            .and_then(Value::as_array)
+            .and_then(|vars| {
+                vars.iter()
+                    .map(|var| match var.as_array()?.as_slice() {
+                        [key, value] => {
+                            Some((key.as_str()?.to_string(), value.as_str()?.to_string()))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            });
+
+        let response = match (cwd, args, env) {
+            _ if !is_same_build => json!({ "error": "The daemon is a different build of roc." }),
+            _ if streams.len() != STANDARD_STREAMS.len() => {
+                json!({ "error": "The request did not send the standard streams." })
+            }
+            (Some(cwd), Some(args), Some(env)) => {
+                return Ok(Some(Request {
+                    stream,
+                    streams,
+                    cwd: cwd.to_string(),
+                    args,
+                    env,
+                }));
+            }
+            _ => json!({ "error": "The request has no cwd, args or env." }),
+        };
+
+        writeln!(&stream, "{response}")?;
+
+        Ok(None)
+    }
+
+    /// Starts the command of a request in a child process, and tells the caller its pid, so it
+    /// can forward signals to it.
+    fn start(
+        request: Request,
+        inherited: &[RawFd],
+        run: fn(&ArgMatches) -> io::Result<i32>,
+    ) -> io::Result<Option<Running>> {
+        eprintln!("Running `{}` in {}", request.args.join(" "), request.cwd);
+
+        let pid = match run_in_child(&request, inherited, run) {
+            Ok(pid) => pid,
+            Err(error) => {
+                // Nothing ran, so the caller can still run the command itself.
+                let response =
+                    json!({ "error": format!("The daemon could not start the command: {error}") });
+
+                writeln!(&request.stream, "{response}")?;
+
+                return Ok(None);
+            }
+        };
+
+        let hung_up = match writeln!(&request.stream, "{}", json!({ "pid": pid })) {
+            Ok(()) => false,
+            Err(error) => {
+                eprintln!("A request to the daemon failed: {error}");
+
+                unsafe { libc::kill(pid, libc::SIGTERM) };
+
+                true
+            }
+        };
+
+        Ok(Some(Running {
+            pid,
+            stream: request.stream,
+            hung_up,
+        }))
+    }
+
+    /// Whether the other end of the stream has closed it.
+    fn has_hung_up(stream: &UnixStream) -> bool {
+        let mut byte = [0u8];
+
+        let read = unsafe {
+            libc::recv(
+                stream.as_raw_fd(),
+                byte.as_mut_ptr().cast(),
+                byte.len(),
+                libc::MSG_PEEK | libc::MSG_DONTWAIT,
+            )
+        };
+
+        read == 0
+    }
+
+    /// The exit code of the child, if it has exited.
+    fn exit_code_if_done(pid: libc::pid_t) -> Option<i32> {
+        let mut status = 0;
+
+        match unsafe { libc::waitpid(pid, &mut status, libc::WNOHANG) } {
+            0 => None,
+            -1 => Some(1),
+            _ if libc::WIFEXITED(status) => Some(libc::WEXITSTATUS(status)),
+            _ if libc::WIFSIGNALED(status) => Some(128 + libc::WTERMSIG(status)),
+            _ => Some(1),
+        }
+    }
+
+    /// Starts the command in a child process, and returns its pid. The child closes the daemon's
+    /// own file descriptors, which are `inherited`.
+    fn run_in_child(
+        request: &Request,
+        inherited: &[RawFd],
+        run: fn(&ArgMatches) -> io::Result<i32>,
+    ) -> io::Result<libc::pid_t> {
+        // Flushed now, so the child doesn't print what the daemon buffered.
+        io::stdout().flush()?;
+        io::stderr().flush()?;
+
+        match unsafe { libc::fork() } {
+            -1 => Err(io::Error::last_os_error()),
+            0 => {
+                // We're in the child, which must never return to the daemon's loop.
+                for fd in inherited {
+                    unsafe { libc::close(*fd) };
+                }
+
+                // A panic must not unwind into the daemon's loop either.
+                let exit_code =
+                    std::panic::catch_unwind(|| run_command(request, run)).unwrap_or(101);
+
+                let _ = io::stdout().flush();
+                let _ = io::stderr().flush();
+
+                std::process::exit(exit_code);
+            }
+            pid => Ok(pid),
+        }
+    }
+
+    fn run_command(request: &Request, run: fn(&ArgMatches) -> io::Result<i32>) -> i32 {
+        for (fd, target) in request.streams.iter().zip(STANDARD_STREAMS) {
+            if unsafe { libc::dup2(fd.as_raw_fd(), target) } == -1 {
+                return 1;
+            }
+        }
+
+        let cwd = &request.cwd;
+
+        if let Err(error) = std::env::set_current_dir(cwd) {
+            eprintln!("The daemon could not change to the directory {cwd}: {error}");
+
+            return 1;
+        }
+
+        // The command sees the caller's environment variables, not the daemon's.
+        for (key, _) in std::env::vars_os() {
+            std::env::remove_var(key);
+        }
+
+        for (key, value) in &request.env {
+            std::env::set_var(key, value);
+        }
+
+        let matches = match build_app().try_get_matches_from(&request.args) {
+            Ok(matches) => matches,
+            Err(error) => {
+                let _ = error.print();
+
+                return error.exit_code();
+            }
+        };
+
+        // The daemon itself doesn't compile anything, so this child sets up the crash report
+        // for the command it runs.
+        if is_compiling_command(&matches) {
+            install_panic_hook(request.args.clone());
+        }
+
+        match run(&matches) {
+            Ok(exit_code) => exit_code,
+            Err(error) => {
+                eprintln!("{error}");
+
+                1
+            }
+        }
+    }
+
+    /// The pid of the child running this process's command in the daemon, once it has started.
+    static CHILD_PID: AtomicI32 = AtomicI32::new(0);
+
+    extern "C" fn forward_signal(signal: libc::c_int) {
+        let pid = CHILD_PID.load(Ordering::SeqCst);
+
+        if pid > 0 {
+            unsafe { libc::kill(pid, signal) };
+        }
+    }
+
+    /// Sends the signals that stop this process, e.g. Ctrl-C, on to the daemon's child instead,
+    /// since that is where the command runs.
+    fn forward_signals(pid: libc::pid_t) {
+        CHILD_PID.store(pid, Ordering::SeqCst);
+
+        for signal in [libc::SIGINT, libc::SIGTERM, libc::SIGHUP] {
+            unsafe {
+                libc::signal(
+                    signal,
+                    forward_signal as extern "C" fn(libc::c_int) as libc::sighandler_t,
+                )
+            };
+        }
+    }
+
+    /// Runs this process's command in the daemon, if one is running, and returns its exit code.
+    /// Returns `None` if the command should run in this process instead, which is only the case
+    /// when the daemon can't have started it.
+    pub fn delegate() -> Option<i32> {
+        if std::env::var_os(NO_DAEMON_VAR).is_some() {
+            return None;
+        }
+
+        let stream = UnixStream::connect(socket_path()).ok()?;
+
+        let args = std::env::args_os()
+            .map(|arg| arg.into_string().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let cwd = std::env::current_dir()
+            .ok()?
+            .into_os_string()
+            .into_string()
+            .ok()?;
+        let env = std::env::vars_os()
+            .map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+            .collect::<Option<Vec<_>>>()?;
+
+        let request = json!({
+            "build": build_id()?,
+            "executable": executable()?,
+            "cwd": cwd,
+            "args": args,
+            "env": env,
+        });
+
+        stream.set_write_timeout(Some(IO_TIMEOUT)).ok()?;
+        send_fds(&stream, &STANDARD_STREAMS).ok()?;
+        writeln!(&stream, "{request}").ok()?;
+
+        // From here on the daemon may be running the command, so running it here as well could
+        // run it twice.
+        match wait_for_command(&stream) {
+            Ok(exit_code) => exit_code,
+            Err(error) => {
+                eprintln!("Lost the connection to the roc daemon: {error}");
+
+                Some(1)
+            }
+        }
+    }
+
+    /// Waits for the daemon to start the command and then for it to finish, and returns its exit
+    /// code, or `None` if the daemon refused to run it.
+    fn wait_for_command(stream: &UnixStream) -> io::Result<Option<i32>> {
+        let mut reader = BufReader::new(stream);
+
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+
+        let response = read_response(&mut reader)?;
+
+        if response.get("error").is_some() {
+            return Ok(None);
+        }
+
+        let pid = response
+            .get("pid")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| invalid_response(&response))?;
+
+        forward_signals(pid as libc::pid_t);
+
+        // The command takes as long as it takes.
+        stream.set_read_timeout(None)?;
+
+        let response = read_response(&mut reader)?;
+
+        response
+            .get("exitCode")
+            .and_then(Value::as_i64)
+            .map(|exit_code| Some(exit_code as i32))
+            .ok_or_else(|| invalid_response(&response))
+    }
+
+    fn read_response(reader: &mut BufReader<&UnixStream>) -> io::Result<Value> {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        serde_json::from_str(&line)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    fn invalid_response(response: &Value) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unexpected response {response}"),
+        )
+    }
+
+    /// A buffer for one control message with up to this many file descriptors, aligned for
+    /// `cmsghdr`.
+    #[allow(unused_unsafe)] // `CMSG_SPACE` is only unsafe on some platforms
+    fn control_buffer(fd_count: usize) -> (Vec<u64>, usize) {
+        let space = unsafe { libc::CMSG_SPACE((fd_count * std::mem::size_of::<RawFd>()) as _) };
+        let space = space as usize;
+
+        (vec![0; (space + 7) / 8], space)
+    }
+
+    /// Sends the file descriptors, along with a single byte, because a message can't be empty.
+    fn send_fds(stream: &UnixStream, fds: &[RawFd]) -> io::Result<()> {
+        let mut byte = [0u8];
+        let mut iov = libc::iovec {
+            iov_base: byte.as_mut_ptr().cast(),
+            iov_len: byte.len(),
+        };
+
+        let (mut control, space) = control_buffer(fds.len());
+
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = space as _;
+
+        unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(std::mem::size_of_val(fds) as _) as _;
+
+            std::ptr::copy_nonoverlapping(
+                fds.as_ptr(),
+                libc::CMSG_DATA(cmsg).cast::<RawFd>(),
+                fds.len(),
+            );
+
+            if libc::sendmsg(stream.as_raw_fd(), &msg, 0) == -1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Receives the file descriptors that [`send_fds`] sent.
+    fn receive_fds(stream: &UnixStream) -> io::Result<Vec<OwnedFd>> {
+        let mut byte = [0u8];
+        let mut iov = libc::iovec {
+            iov_base: byte.as_mut_ptr().cast(),
+            iov_len: byte.len(),
+        };
+
+        let (mut control, space) = control_buffer(STANDARD_STREAMS.len());
+
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = space as _;
+
+        let mut fds = Vec::new();
+
+        unsafe {
+            match libc::recvmsg(stream.as_raw_fd(), &mut msg, 0) {
+                -1 => return Err(io::Error::last_os_error()),
+                0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+                _ => {}
+            }
+
+            let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+
+            while !cmsg.is_null() {
+                if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                    let data_len = (*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize;
+                    let data = libc::CMSG_DATA(cmsg).cast::<RawFd>();
+
+                    for index in 0..data_len / std::mem::size_of::<RawFd>() {
+                        let fd = std::ptr::read_unaligned(data.add(index));
+
+                        fds.push(OwnedFd::from_raw_fd(fd));
+                    }
+                }
+
+                cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+            }
+        }
+
+        Ok(fds)
+    }
+}
@@ -3,11 +3,12 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use bumpalo::Bump;
-use roc_error_macros::{internal_error, user_error};
+use rayon::prelude::*;
+use roc_error_macros::internal_error;
 use roc_fmt::def::fmt_defs;
 use roc_fmt::module::fmt_module;
 use roc_fmt::spaces::RemoveSpaces;
-use roc_fmt::{Ast, Buf};
+use roc_fmt::{match_line_endings, Ast, Buf};
 use roc_parse::module::parse_module_defs;
 use roc_parse::{module, parser::SyntaxError, state::State};
 
@@ -62,102 +63,264 @@ fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
+/// What happened to one of the files given to `roc format`.
+enum FormatOutcome {
+    Changed {
+        formatted: String,
+    },
+    Unchanged {
+        formatted: String,
+    },
+    /// The file doesn't parse, so it was left alone.
+    Skipped {
+        parse_err: String,
+    },
+}
+
+/// Formats the files, and the `.roc` files in the directories, in parallel. Files that don't
+/// parse are skipped rather than stopping the others, and a summary of what happened to the files
+/// is printed to stderr at the end.
 pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
-    let arena = Bump::new();
+    let mut files = flatten_directories(files);
 
-    for file in flatten_directories(files) {
-        let src = std::fs::read_to_string(&file).unwrap();
+    // Report the files in a predictable order, whatever order the threads finish in.
+    files.sort();
 
-        match format_src(&arena, &src) {
-            Ok(buf) => {
-                match mode {
-                    FormatMode::CheckOnly => {
-                        // If we notice that this file needs to be formatted, return early
-                        if buf.as_str() != src {
-                            return Err("One or more files need to be reformatted.".to_string());
-                        }
-                    }
-                    FormatMode::WriteToFile => {
-                        // If all the checks above passed, actually write out the new file.
-                        std::fs::write(&file, buf.as_str()).unwrap();
-                    }
-                    FormatMode::WriteToStdout => {
-                        std::io::stdout().lock().write_all(buf.as_bytes()).unwrap()
-                    }
-                }
+    let outcomes: Vec<FormatOutcome> = files
+        .par_iter()
+        .map(|file| format_file(file, mode))
+        .collect();
+
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    let mut skipped = Vec::new();
+
+    for (file, outcome) in files.iter().zip(outcomes) {
+        let formatted = match outcome {
+            FormatOutcome::Changed { formatted } => {
+                changed.push(file);
+
+                formatted
             }
-            Err(err) => match err {
-                FormatProblem::ParsingFailed {
-                    formatted_src,
-                    parse_err,
-                } => {
-                    let fail_file = file.with_extension("roc-format-failed");
-
-                    std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatted code isn't valid\n\n\
-                        I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                        Parse error was: {:?}\n\n",
-                        fail_file.display(),
-                        parse_err
-                    );
-                }
-                FormatProblem::ReformattingChangedAst {
-                    formatted_src,
-                    ast_before,
-                    ast_after,
-                } => {
-                    let mut fail_file = file.clone();
-                    fail_file.set_extension("roc-format-failed");
-                    std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
-
-                    let mut before_file = file.clone();
-                    before_file.set_extension("roc-format-failed-ast-before");
-                    std::fs::write(&before_file, ast_before).unwrap();
-
-                    let mut after_file = file.clone();
-                    after_file.set_extension("roc-format-failed-ast-after");
-                    std::fs::write(&after_file, ast_after).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatting didn't reparse as the same tree\n\n\
-                        I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                        I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
-                        fail_file.display(),
-                        before_file.display(),
-                        after_file.display()
-                    );
-                }
-                FormatProblem::ReformattingUnstable {
-                    formatted_src,
-                    reformatted_src,
-                } => {
-                    let mut unstable_1_file = file.clone();
-                    unstable_1_file.set_extension("roc-format-unstable-1");
-                    std::fs::write(&unstable_1_file, formatted_src).unwrap();
-
-                    let mut unstable_2_file = file.clone();
-                    unstable_2_file.set_extension("roc-format-unstable-2");
-                    std::fs::write(&unstable_2_file, reformatted_src).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
-                        I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
-                        I wrote the result of double-formatting here:\n{}\n\n",
-                        unstable_1_file.display(),
-                        unstable_2_file.display()
-                    );
-                }
-            },
+            FormatOutcome::Unchanged { formatted } => {
+                unchanged += 1;
+
+                formatted
+            }
+            FormatOutcome::Skipped { parse_err } => {
+                skipped.push((file, parse_err));
+
+                continue;
+            }
+        };
+
+        if let FormatMode::WriteToStdout = mode {
+            std::io::stdout()
+                .lock()
+                .write_all(formatted.as_bytes())
+                .unwrap()
         }
     }
 
-    Ok(())
+    let mut stderr = std::io::stderr().lock();
+
+    if !skipped.is_empty() {
+        writeln!(stderr, "These files don't parse, so I skipped them:\n").unwrap();
+
+        for (file, parse_err) in skipped.iter() {
+            writeln!(stderr, "    {}\n        {parse_err}", file.display()).unwrap();
+        }
+
+        writeln!(stderr).unwrap();
+    }
+
+    if let FormatMode::CheckOnly = mode {
+        if !changed.is_empty() {
+            writeln!(stderr, "These files need to be reformatted:\n").unwrap();
+
+            for file in changed.iter() {
+                writeln!(stderr, "    {}", file.display()).unwrap();
+            }
+
+            writeln!(stderr).unwrap();
+        }
+    }
+
+    let summary = match mode {
+        FormatMode::CheckOnly => Some(("Checked", "need formatting", "already formatted")),
+        FormatMode::WriteToFile => Some(("Formatted", "changed", "unchanged")),
+        // Callers that ask for the formatted code on stdout only want the code
+        FormatMode::WriteToStdout => None,
+    };
+
+    if let Some((verb, changed_label, unchanged_label)) = summary {
+        writeln!(
+            stderr,
+            "{verb} {}: {} {changed_label}, {unchanged} {unchanged_label}, {} failed",
+            files_count(files.len()),
+            changed.len(),
+            skipped.len(),
+        )
+        .unwrap();
+    }
+
+    if !skipped.is_empty() {
+        Err("One or more files couldn't be parsed.".to_string())
+    } else if matches!(mode, FormatMode::CheckOnly) && !changed.is_empty() {
+        Err("One or more files need to be reformatted.".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Prints the syntax tree of each of the files, and of the `.roc` files in the directories, to
+/// stdout as one line of JSON per file. Like formatting, files that don't parse are skipped and
+/// listed on stderr at the end.
+pub fn emit_ast_json(files: std::vec::Vec<PathBuf>) -> Result<(), String> {
+    let mut files = flatten_directories(files);
+
+    files.sort();
+
+    let mut stdout = std::io::stdout().lock();
+    let mut skipped = Vec::new();
+
+    for file in files.iter() {
+        let arena = Bump::new();
+        let src = std::fs::read_to_string(file).unwrap();
+
+        match ast_json(&arena, &src, Some(file)) {
+            Ok(json) => writeln!(stdout, "{json}").unwrap(),
+            Err(parse_err) => skipped.push((file, parse_err)),
+        }
+    }
+
+    if skipped.is_empty() {
+        return Ok(());
+    }
+
+    let mut stderr = std::io::stderr().lock();
+
+    writeln!(stderr, "These files don't parse, so I skipped them:\n").unwrap();
+
+    for (file, parse_err) in skipped.iter() {
+        writeln!(stderr, "    {}\n        {parse_err}", file.display()).unwrap();
+    }
+
+    writeln!(stderr).unwrap();
+
+    Err("One or more files couldn't be parsed.".to_string())
+}
+
+/// The syntax tree of the source as a JSON object with `path`, `header` and `defs` fields. Every
+/// node is wrapped in its region, given as byte offsets into the source.
+pub fn ast_json(arena: &Bump, src: &str, path: Option<&Path>) -> Result<String, String> {
+    let ast = parse_all(arena, src).map_err(|err| format!("{err:?}"))?;
+
+    let json = serde_json::json!({
+        "path": path.map(|path| path.display().to_string()),
+        "header": ast.module,
+        "defs": ast.defs,
+    });
+
+    Ok(json.to_string())
+}
+
+fn files_count(count: usize) -> String {
+    match count {
+        1 => "1 file".to_string(),
+        _ => format!("{count} files"),
+    }
+}
+
+fn format_file(file: &Path, mode: FormatMode) -> FormatOutcome {
+    let arena = Bump::new();
+    let src = std::fs::read_to_string(file).unwrap();
+
+    match format_src(&arena, &src) {
+        Ok(buf) => {
+            if buf == src {
+                return FormatOutcome::Unchanged { formatted: buf };
+            }
+
+            if let FormatMode::WriteToFile = mode {
+                std::fs::write(file, buf.as_str()).unwrap();
+            }
+
+            FormatOutcome::Changed { formatted: buf }
+        }
+        Err(err) => match err {
+            FormatProblem::SourceDoesNotParse { parse_err } => FormatOutcome::Skipped { parse_err },
+            FormatProblem::ParsingFailed {
+                formatted_src,
+                parse_err,
+            } => {
+                let fail_file = file.with_extension("roc-format-failed");
+
+                std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
+
+                internal_error!(
+                    "Formatting bug; formatted code isn't valid\n\n\
+                    I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+                    Parse error was: {:?}\n\n",
+                    fail_file.display(),
+                    parse_err
+                );
+            }
+            FormatProblem::ReformattingChangedAst {
+                formatted_src,
+                ast_before,
+                ast_after,
+            } => {
+                let mut fail_file = file.to_path_buf();
+                fail_file.set_extension("roc-format-failed");
+                std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
+
+                let mut before_file = file.to_path_buf();
+                before_file.set_extension("roc-format-failed-ast-before");
+                std::fs::write(&before_file, ast_before).unwrap();
+
+                let mut after_file = file.to_path_buf();
+                after_file.set_extension("roc-format-failed-ast-after");
+                std::fs::write(&after_file, ast_after).unwrap();
+
+                internal_error!(
+                    "Formatting bug; formatting didn't reparse as the same tree\n\n\
+                    I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+                    I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
+                    fail_file.display(),
+                    before_file.display(),
+                    after_file.display()
+                );
+            }
+            FormatProblem::ReformattingUnstable {
+                formatted_src,
+                reformatted_src,
+            } => {
+                let mut unstable_1_file = file.to_path_buf();
+                unstable_1_file.set_extension("roc-format-unstable-1");
+                std::fs::write(&unstable_1_file, formatted_src).unwrap();
+
+                let mut unstable_2_file = file.to_path_buf();
+                unstable_2_file.set_extension("roc-format-unstable-2");
+                std::fs::write(&unstable_2_file, reformatted_src).unwrap();
+
+                internal_error!(
+                    "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
+                    I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
+                    I wrote the result of double-formatting here:\n{}\n\n",
+                    unstable_1_file.display(),
+                    unstable_2_file.display()
+                );
+            }
+        },
+    }
 }
 
 #[derive(Debug)]
 pub enum FormatProblem {
+    SourceDoesNotParse {
+        parse_err: String,
+    },
     ParsingFailed {
         formatted_src: String,
         parse_err: String,
@@ -174,9 +337,14 @@ pub enum FormatProblem {
 }
 
 pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
-    let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
-        user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
-    }));
+    let ast = match parse_all(arena, src) {
+        Ok(ast) => arena.alloc(ast),
+        Err(e) => {
+            return Err(FormatProblem::SourceDoesNotParse {
+                parse_err: format!("{:?}", e),
+            });
+        }
+    };
     let mut buf = Buf::new_in(arena);
     fmt_all(&mut buf, ast);
 
@@ -218,10 +386,10 @@ pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
         });
     }
 
-    Ok(buf.as_str().to_string())
+    Ok(match_line_endings(src, buf.as_str()).into_owned())
 }
 
-fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+pub(crate) fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
     let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
 
@@ -0,0 +1,230 @@
+//! `roc graph`, which prints which modules import which, and optionally which exposed functions
+//! call which, so that the structure of a large project can be looked at instead of pieced
+//! together from its imports.
+
+use bumpalo::Bump;
+use roc_build::program::handle_loading_problem;
+use roc_collections::{MutMap, MutSet};
+use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, Threading};
+use roc_module::symbol::{ModuleId, Symbol};
+use roc_packaging::cache::RocCacheDir;
+use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_target::Target;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz, for `dot -Tsvg`
+    Dot,
+    /// One JSON object with `modules`, `imports`, and `calls`
+    Json,
+}
+
+/// Prints the import graph of the program's modules, leaving out builtins. With `include_calls`,
+/// also prints which exposed functions call which other exposed functions, looking through the
+/// private functions in between.
+pub fn print_graph(
+    arena: &Bump,
+    roc_file_path: PathBuf,
+    format: GraphFormat,
+    include_calls: bool,
+    roc_cache_dir: RocCacheDir<'_>,
+    threading: Threading,
+) -> io::Result<i32> {
+    let load_config = LoadConfig {
+        target: Target::default(),
+        function_kind: FunctionKind::LambdaSet,
+        render: RenderTarget::ColorTerminal,
+        palette: DEFAULT_PALETTE,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
+    };
+
+    let loaded =
+        match roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config) {
+            Ok(loaded) => loaded,
+            Err(problem) => return handle_loading_problem(problem),
+        };
+
+    let graph = Graph::new(&loaded, include_calls);
+
+    match format {
+        GraphFormat::Dot => print!("{}", graph.to_dot(include_calls)),
+        GraphFormat::Json => println!("{}", graph.to_json()),
+    }
+
+    Ok(0)
+}
+
+/// Edges go from the importer or caller to what it uses. Everything is sorted, so that the
+/// output only changes when the program does.
+struct Graph {
+    modules: BTreeSet<String>,
+    imports: BTreeSet<(String, String)>,
+    /// The exposed functions in [Graph::calls], by module
+    functions: BTreeMap<String, BTreeSet<String>>,
+    calls: BTreeSet<(String, String)>,
+}
+
+impl Graph {
+    fn new(loaded: &LoadedModule, include_calls: bool) -> Self {
+        let module_name = |id: ModuleId| loaded.interns.module_name(id).to_string();
+
+        let mut modules = BTreeSet::new();
+        let mut imports = BTreeSet::new();
+
+        for (importer, imported) in loaded.imports.iter() {
+            if importer.is_builtin() {
+                continue;
+            }
+
+            modules.insert(module_name(*importer));
+
+            for imported in imported.iter().filter(|id| !id.is_builtin()) {
+                modules.insert(module_name(*imported));
+                imports.insert((module_name(*importer), module_name(*imported)));
+            }
+        }
+
+        let mut functions: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut calls = BTreeSet::new();
+
+        if include_calls {
+            let function_name = |symbol: Symbol| {
+                format!(
+                    "{}.{}",
+                    symbol.module_string(&loaded.interns),
+                    symbol.as_str(&loaded.interns)
+                )
+            };
+
+            for (caller, callee) in exposed_calls(loaded) {
+                for function in [caller, callee] {
+                    functions
+                        .entry(module_name(function.module_id()))
+                        .or_default()
+                        .insert(function_name(function));
+                }
+
+                calls.insert((function_name(caller), function_name(callee)));
+            }
+        }
+
+        Self {
+            modules,
+            imports,
+            functions,
+            calls,
+        }
+    }
+
+    fn to_dot(&self, include_calls: bool) -> String {
+        let mut dot = String::from("digraph {\n");
+
+        if include_calls {
+            // Each module is a box around its exposed functions.
+            for (module, functions) in self.functions.iter() {
+                dot.push_str(&format!(
+                    "    subgraph {} {{\n        label = {};\n",
+                    quoted(&format!("cluster_{module}")),
+                    quoted(module)
+                ));
+
+                for function in functions.iter() {
+                    dot.push_str(&format!("        {};\n", quoted(function)));
+                }
+
+                dot.push_str("    }\n");
+            }
+
+            for (caller, callee) in self.calls.iter() {
+                dot.push_str(&format!("    {} -> {};\n", quoted(caller), quoted(callee)));
+            }
+        } else {
+            dot.push_str("    node [shape = box];\n");
+
+            for module in self.modules.iter() {
+                dot.push_str(&format!("    {};\n", quoted(module)));
+            }
+
+            for (importer, imported) in self.imports.iter() {
+                dot.push_str(&format!(
+                    "    {} -> {};\n",
+                    quoted(importer),
+                    quoted(imported)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    fn to_json(&self) -> String {
+        let edges = |edges: &BTreeSet<(String, String)>| {
+            edges
+                .iter()
+                .map(|(from, to)| serde_json::json!({ "from": from, "to": to }))
+                .collect::<Vec<_>>()
+        };
+
+        serde_json::json!({
+            "modules": self.modules,
+            "imports": edges(&self.imports),
+            "calls": edges(&self.calls),
+        })
+        .to_string()
+    }
+}
+
+/// The calls between exposed functions of modules that aren't builtins. A call that goes
+/// through private functions counts as a call from the exposed function it starts in.
+fn exposed_calls(loaded: &LoadedModule) -> BTreeSet<(Symbol, Symbol)> {
+    let exposed: MutSet<Symbol> = loaded
+        .exposes
+        .iter()
+        .filter(|(module_id, _)| !module_id.is_builtin())
+        .flat_map(|(_, exposes)| exposes.iter().map(|(symbol, _)| *symbol))
+        .collect();
+
+    let mut callees: MutMap<Symbol, Vec<Symbol>> = MutMap::default();
+
+    for calls in loaded.calls.values() {
+        for call in calls.iter() {
+            if !call.callee.module_id().is_builtin() {
+                callees.entry(call.caller).or_default().push(call.callee);
+            }
+        }
+    }
+
+    let mut edges = BTreeSet::new();
+
+    for caller in exposed.iter() {
+        let mut seen = MutSet::default();
+        let mut stack = vec![*caller];
+
+        while let Some(function) = stack.pop() {
+            for callee in callees.get(&function).into_iter().flatten() {
+                if exposed.contains(callee) {
+                    edges.insert((*caller, *callee));
+                } else if seen.insert(*callee) {
+                    stack.push(*callee);
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+fn quoted(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
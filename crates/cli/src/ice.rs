@@ -0,0 +1,153 @@
+//! Reporting internal compiler errors.
+//!
+//! When the compiler panics, we print what it was working on and write a bundle with what is
+//! needed to reproduce the crash, before the usual panic message and backtrace.
+
+use crate::{
+    CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_GLUE, CMD_GRAPH, CMD_INFO, CMD_LINT, CMD_RUN,
+    CMD_TEST,
+};
+use clap::ArgMatches;
+use roc_load::ice::CompilerContext;
+use roc_region::all::{LineColumnRegion, LineInfo, Region};
+use std::fmt::Write as _;
+use std::panic::PanicInfo;
+use std::path::{Path, PathBuf};
+
+const VERSION: &str = include_str!("../../../version.txt");
+
+const ISSUES_URL: &str = "https://github.com/roc-lang/roc/issues";
+
+/// Whether the command runs the compiler, so a panic in it is an internal compiler error.
+pub fn is_compiling_command(matches: &ArgMatches) -> bool {
+    matches!(
+        matches.subcommand(),
+        None | Some((
+            CMD_BUILD
+                | CMD_CHECK
+                | CMD_DEV
+                | CMD_DOCS
+                | CMD_GLUE
+                | CMD_GRAPH
+                | CMD_INFO
+                | CMD_LINT
+                | CMD_RUN
+                | CMD_TEST,
+            _
+        ))
+    )
+}
+
+/// Replaces the panic hook with one that reports the crash as an internal compiler error of the
+/// command with these arguments.
+pub fn install_panic_hook(args: Vec<String>) {
+    let default_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        report(info, &args);
+
+        default_hook(info);
+    }));
+}
+
+fn report(info: &PanicInfo<'_>, args: &[String]) {
+    let context = roc_load::ice::current();
+
+    let mut summary = String::new();
+
+    // Writing to a String can't fail.
+    let _ = writeln!(summary, "roc version: {}", VERSION.trim());
+    let _ = writeln!(summary, "panic: {info}");
+
+    match &context {
+        Some(context) => {
+            let _ = writeln!(summary, "phase: {}", context.phase);
+            let _ = writeln!(summary, "module: {}", context.module);
+
+            if let Some(path) = &context.module_path {
+                let _ = writeln!(summary, "file: {}", path.display());
+            }
+
+            if let Some(region) = context.region {
+                let _ = writeln!(
+                    summary,
+                    "region: {}",
+                    describe_region(region, context.module_path.as_deref())
+                );
+            }
+        }
+        None => {
+            let _ = writeln!(summary, "phase: unknown");
+        }
+    }
+
+    eprintln!(
+        "\n── INTERNAL COMPILER ERROR ──────────────────────────────────────────────────────\n"
+    );
+    eprintln!("The Roc compiler crashed. This is a bug in the compiler, not in your code!\n");
+    eprintln!("{summary}");
+
+    match write_bundle(&summary, args, context.as_ref()) {
+        Ok(dir) => eprintln!(
+            "Everything needed to reproduce the crash was written to:\n\n    {}\n\nPlease open an issue at {ISSUES_URL} and attach the files in it.\n",
+            dir.display()
+        ),
+        Err(error) => eprintln!(
+            "Writing the files to reproduce the crash failed: {error}\n\nPlease open an issue at {ISSUES_URL}.\n"
+        ),
+    }
+}
+
+/// The lines and columns of the region if the source file can still be read, and its byte offsets
+/// otherwise.
+fn describe_region(region: Region, module_path: Option<&Path>) -> String {
+    match module_path.and_then(|path| std::fs::read_to_string(path).ok()) {
+        Some(src) => {
+            let LineColumnRegion { start, end } = LineInfo::new(&src).convert_region(region);
+
+            format!(
+                "{}:{}-{}:{}",
+                start.line + 1,
+                start.column + 1,
+                end.line + 1,
+                end.column + 1
+            )
+        }
+        None => format!("bytes {}-{}", region.start().offset, region.end().offset),
+    }
+}
+
+/// Writes the summary, the command line, and the source files involved to a new temporary
+/// directory, and returns its path. The directory is removed again if writing to it fails.
+fn write_bundle(
+    summary: &str,
+    args: &[String],
+    context: Option<&CompilerContext>,
+) -> std::io::Result<PathBuf> {
+    let temp_dir = tempfile::Builder::new().prefix("roc-crash-").tempdir()?;
+    let dir = temp_dir.path();
+
+    std::fs::write(dir.join("summary.txt"), summary)?;
+    std::fs::write(dir.join("command.txt"), args.join("\n") + "\n")?;
+
+    // The module being compiled, and the files the compiler was called with.
+    let module_path = context.and_then(|context| context.module_path.as_deref());
+    let arg_paths = args
+        .iter()
+        .skip(1)
+        .map(Path::new)
+        .filter(|path| path.is_file());
+
+    for path in module_path.into_iter().chain(arg_paths) {
+        if let Some(file_name) = path.file_name() {
+            let destination = dir.join(file_name);
+
+            if !destination.exists() {
+                std::fs::copy(path, destination)?;
+            }
+        }
+    }
+
+    // Keep the directory, so it can be attached to an issue.
+    Ok(temp_dir.into_path())
+}
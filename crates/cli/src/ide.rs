@@ -0,0 +1,505 @@
+//! `roc ide`, which runs the compiler as a long-running service for editors and tools that don't
+//! want a full language server.
+//!
+//! Requests and responses are JSON-RPC 2.0 messages, one per line, on stdin and stdout. The
+//! service answers these methods:
+//!
+//! - `check` with `{ "path", "source"? }` returns the problems in the module.
+//! - `format` with `{ "source", "range"? }` returns the edit that formats the source. A `range`
+//!   is `{ "start": line, "end": line }`, zero-based and end-exclusive. The edit then only
+//!   formats those lines: changes that reach outside of them are left out, and no edit is
+//!   returned when there are no changes left.
+//! - `typeAt` with `{ "path", "source"?, "line", "column" }` returns the type of the narrowest
+//!   expression at the position, zero-based.
+//! - `shutdown` stops the service.
+//!
+//! When `source` is missing, the file at `path` is read. The last check of each file is kept, so
+//! asking about a file that hasn't changed since doesn't load it again. Each time a file is
+//! loaded, the service logs it on stderr.
+
+use bumpalo::Bump;
+use roc_load::{FunctionKind, LoadedModule, LoadingProblem};
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_problem::Severity;
+use roc_region::all::{LineColumn, LineInfo, Region};
+use roc_reporting::report::{RenderTarget, RocDocAllocator, DEFAULT_PALETTE};
+use roc_target::Target;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::format::{format_src, parse_all};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const REQUEST_FAILED: i64 = -32000;
+
+struct RequestError {
+    code: i64,
+    message: String,
+}
+
+impl RequestError {
+    fn invalid_params(message: impl Into<String>) -> Self {
+        RequestError {
+            code: INVALID_PARAMS,
+            message: message.into(),
+        }
+    }
+
+    fn failed(message: impl Into<String>) -> Self {
+        RequestError {
+            code: REQUEST_FAILED,
+            message: message.into(),
+        }
+    }
+}
+
+/// A module as it was last checked.
+struct Checked {
+    source: String,
+    line_info: LineInfo,
+    /// `None` if the module could not be loaded, e.g. because it doesn't parse.
+    loaded: Option<LoadedModule>,
+    problems: Vec<Value>,
+}
+
+#[derive(Default)]
+struct Service {
+    checked: HashMap<PathBuf, Checked>,
+}
+
+pub fn serve() -> io::Result<i32> {
+    let mut service = Service::default();
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+
+    for line in stdin.lock().lines() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, shutdown) = match serde_json::from_str::<Value>(&line) {
+            Ok(request) => service.handle(&request),
+            Err(error) => (
+                Some(error_response(Value::Null, PARSE_ERROR, &error.to_string())),
+                false,
+            ),
+        };
+
+        if let Some(response) = response {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+
+        if shutdown {
+            break;
+        }
+    }
+
+    Ok(0)
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+impl Service {
+    /// The response to the request, which is `None` for a notification, and whether to shut down.
+    fn handle(&mut self, request: &Value) -> (Option<Value>, bool) {
+        let id = request.get("id").cloned();
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+
+        let method = match request.get("method").and_then(Value::as_str) {
+            Some(method) => method,
+            None => {
+                let response = error_response(
+                    id.unwrap_or(Value::Null),
+                    INVALID_REQUEST,
+                    "The request has no method.",
+                );
+
+                return (Some(response), false);
+            }
+        };
+
+        let result = match method {
+            "check" => self.check(&params),
+            "format" => format_range(&params),
+            "typeAt" => self.type_at(&params),
+            "shutdown" => Ok(Value::Null),
+            _ => Err(RequestError {
+                code: METHOD_NOT_FOUND,
+                message: format!("There is no method named {method}."),
+            }),
+        };
+
+        let response = id.map(|id| match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(RequestError { code, message }) => error_response(id, code, &message),
+        });
+
+        (response, method == "shutdown")
+    }
+
+    fn check(&mut self, params: &Value) -> Result<Value, RequestError> {
+        let checked = self.checked_module(params)?;
+
+        Ok(json!({ "problems": checked.problems }))
+    }
+
+    fn type_at(&mut self, params: &Value) -> Result<Value, RequestError> {
+        let line = u32_param(params, "line")?;
+        let column = u32_param(params, "column")?;
+        let checked = self.checked_module(params)?;
+
+        let loaded = match &mut checked.loaded {
+            Some(loaded) => loaded,
+            None => return Ok(Value::Null),
+        };
+
+        if line >= checked.line_info.num_lines() {
+            return Ok(Value::Null);
+        }
+
+        let position = checked
+            .line_info
+            .convert_line_column(LineColumn { line, column });
+
+        let declarations = match loaded.declarations_by_id.get(&loaded.module_id) {
+            Some(declarations) => declarations,
+            None => return Ok(Value::Null),
+        };
+
+        match roc_can::traverse::find_closest_type_at(position, declarations) {
+            Some((region, var)) => {
+                let type_str = name_and_print_var(
+                    var,
+                    loaded.solved.inner_mut(),
+                    loaded.module_id,
+                    &loaded.interns,
+                    DebugPrint::NOTHING,
+                );
+
+                Ok(json!({
+                    "type": type_str,
+                    "range": range_json(&checked.line_info, region),
+                }))
+            }
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// Checks the module, unless its source is the same as the last time it was checked.
+    fn checked_module(&mut self, params: &Value) -> Result<&mut Checked, RequestError> {
+        let path = match params.get("path").and_then(Value::as_str) {
+            Some(path) => PathBuf::from(path),
+            None => return Err(RequestError::invalid_params("A path is required.")),
+        };
+
+        let source = match params.get("source").and_then(Value::as_str) {
+            Some(source) => source.to_string(),
+            None => std::fs::read_to_string(&path).map_err(|error| {
+                RequestError::failed(format!("Reading {} failed: {error}", path.display()))
+            })?,
+        };
+
+        let is_current =
+            matches!(self.checked.get(&path), Some(checked) if checked.source == source);
+
+        if !is_current {
+            let checked = check_module(&path, source);
+
+            self.checked.insert(path.clone(), checked);
+        }
+
+        Ok(self.checked.get_mut(&path).unwrap())
+    }
+}
+
+fn check_module(path: &Path, source: String) -> Checked {
+    eprintln!("Checking {}", path.display());
+
+    let arena = Bump::new();
+    let line_info = LineInfo::new(&source);
+    let src_dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let result = roc_load::load_and_typecheck_str(
+        &arena,
+        path.to_path_buf(),
+        &source,
+        src_dir,
+        Target::LinuxX64, // Only type-checking, so the target doesn't matter
+        FunctionKind::LambdaSet,
+        RenderTarget::Generic,
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        DEFAULT_PALETTE,
+        None,
+    );
+
+    let (loaded, problems) = match result {
+        Ok(loaded) => {
+            let problems = module_problems(path, &source, &line_info, &loaded);
+
+            (Some(loaded), problems)
+        }
+        Err(LoadingProblem::FormattedReport(report)) => {
+            (None, vec![problem_json(Severity::Fatal, None, report)])
+        }
+        Err(other) => (
+            None,
+            vec![problem_json(Severity::Fatal, None, format!("{other:?}"))],
+        ),
+    };
+
+    Checked {
+        source,
+        line_info,
+        loaded,
+        problems,
+    }
+}
+
+fn module_problems(
+    path: &Path,
+    source: &str,
+    line_info: &LineInfo,
+    loaded: &LoadedModule,
+) -> Vec<Value> {
+    let module_id = loaded.module_id;
+    let lines: Vec<_> = source.lines().collect();
+    let alloc = RocDocAllocator::new(&lines, module_id, &loaded.interns);
+
+    let mut problems = Vec::new();
+
+    let can_problems = loaded.can_problems.get(&module_id).into_iter().flatten();
+
+    for problem in can_problems.cloned() {
+        let region = problem.region();
+        let report =
+            roc_reporting::report::can_problem(&alloc, line_info, path.to_path_buf(), problem);
+
+        let severity = report.severity;
+        let mut message = String::new();
+        report.render_ci(&mut message, &alloc);
+
+        problems.push(problem_json(
+            severity,
+            region.map(|r| (line_info, r)),
+            message,
+        ));
+    }
+
+    let type_problems = loaded.type_problems.get(&module_id).into_iter().flatten();
+
+    for problem in type_problems.cloned() {
+        let region = problem.region();
+        let report =
+            roc_reporting::report::type_problem(&alloc, line_info, path.to_path_buf(), problem);
+
+        if let Some(report) = report {
+            let severity = report.severity;
+            let mut message = String::new();
+            report.render_ci(&mut message, &alloc);
+
+            problems.push(problem_json(
+                severity,
+                region.map(|r| (line_info, r)),
+                message,
+            ));
+        }
+    }
+
+    problems
+}
+
+fn problem_json(severity: Severity, region: Option<(&LineInfo, Region)>, message: String) -> Value {
+    let severity = match severity {
+        Severity::Fatal | Severity::RuntimeError => "error",
+        Severity::Warning => "warning",
+    };
+
+    json!({
+        "severity": severity,
+        "range": region.map(|(line_info, region)| range_json(line_info, region)),
+        "message": message,
+    })
+}
+
+fn range_json(line_info: &LineInfo, region: Region) -> Value {
+    let region = line_info.convert_region(region);
+
+    json!({
+        "start": { "line": region.start.line, "column": region.start.column },
+        "end": { "line": region.end.line, "column": region.end.column },
+    })
+}
+
+/// The edit that formats the source, as the lines it replaces and their new text. With a range,
+/// the edit only includes the changes that lie within those lines.
+fn format_range(params: &Value) -> Result<Value, RequestError> {
+    let source = match params.get("source").and_then(Value::as_str) {
+        Some(source) => source,
+        None => return Err(RequestError::invalid_params("A source is required.")),
+    };
+
+    let arena = Bump::new();
+
+    if let Err(error) = parse_all(&arena, source) {
+        return Err(RequestError::failed(format!(
+            "The source doesn't parse, so it can't be formatted: {error:?}"
+        )));
+    }
+
+    let formatted = format_src(&arena, source)
+        .map_err(|problem| RequestError::failed(format!("Formatting failed: {problem:?}")))?;
+
+    let old_lines: Vec<&str> = source.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = formatted.split_inclusive('\n').collect();
+
+    let mut hunks = changed_lines(&old_lines, &new_lines);
+
+    if let Some(range) = params.get("range") {
+        let range_start = u32_param(range, "start")? as usize;
+        let range_end = u32_param(range, "end")? as usize;
+
+        // Lines that are only inserted count as changing the line they are inserted at.
+        hunks.retain(|hunk| {
+            range_start <= hunk.old.start && hunk.old.end.max(hunk.old.start + 1) <= range_end
+        });
+    }
+
+    let (first, last) = match (hunks.first(), hunks.last()) {
+        (Some(first), Some(last)) => (first.old.start, last.old.end),
+        _ => return Ok(Value::Null),
+    };
+
+    // The lines between the hunks are the same before and after formatting.
+    let mut text = String::new();
+    let mut unchanged = first;
+
+    for hunk in &hunks {
+        text.extend(old_lines[unchanged..hunk.old.start].iter().copied());
+        text.extend(new_lines[hunk.new.clone()].iter().copied());
+        unchanged = hunk.old.end;
+    }
+
+    Ok(json!({
+        "range": { "start": first, "end": last },
+        "text": text,
+    }))
+}
+
+/// Lines of the old text that were replaced by lines of the new text.
+struct Hunk {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+/// Beyond this many pairs of lines to compare, the changed lines are reported as a single hunk.
+const MAX_DIFF_SIZE: usize = 4_000_000;
+
+/// The hunks that turn the old lines into the new ones, found through their longest common
+/// subsequence.
+fn changed_lines(old: &[&str], new: &[&str]) -> Vec<Hunk> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    if old_middle.is_empty() && new_middle.is_empty() {
+        return Vec::new();
+    }
+
+    if old_middle.len() * new_middle.len() > MAX_DIFF_SIZE {
+        return vec![Hunk {
+            old: prefix..old.len() - suffix,
+            new: prefix..new.len() - suffix,
+        }];
+    }
+
+    // common[i][j] is the length of the longest common subsequence of old_middle[i..] and
+    // new_middle[j..].
+    let width = new_middle.len() + 1;
+    let mut common = vec![0u32; (old_middle.len() + 1) * width];
+
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            common[i * width + j] = if old_middle[i] == new_middle[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        let removes = j == new_middle.len()
+            || (i < old_middle.len() && common[(i + 1) * width + j] >= common[i * width + j + 1]);
+
+        let (old_line, new_line) = (prefix + i, prefix + j);
+
+        match hunks.last_mut() {
+            Some(hunk) if hunk.old.end == old_line && hunk.new.end == new_line => {
+                if removes {
+                    hunk.old.end += 1;
+                } else {
+                    hunk.new.end += 1;
+                }
+            }
+            _ => hunks.push(Hunk {
+                old: old_line..old_line + removes as usize,
+                new: new_line..new_line + !removes as usize,
+            }),
+        }
+
+        if removes {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    hunks
+}
+
+fn u32_param(params: &Value, name: &str) -> Result<u32, RequestError> {
+    params
+        .get(name)
+        .and_then(Value::as_u64)
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| RequestError::invalid_params(format!("A {name} is required.")))
+}
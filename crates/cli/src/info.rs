@@ -0,0 +1,142 @@
+//! `roc info`, which prints what the compiler decided about a program, so that platform authors
+//! don't have to work it out from crashes.
+
+use bumpalo::Bump;
+use roc_build::program::handle_loading_problem;
+use roc_load::{ExecutionMode, FunctionKind, LoadConfig, Threading};
+use roc_module::ident::ModuleName;
+use roc_module::symbol::Symbol;
+use roc_mono::layout::{layout_report, GlobalLayoutInterner, LayoutCache};
+use roc_packaging::cache::RocCacheDir;
+use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_target::Target;
+use roc_types::subs::{Content, Subs, Variable};
+use std::io;
+use std::path::PathBuf;
+
+/// Prints the layout the compiler chose for a type on the target: its size and alignment, the
+/// offsets of its fields, and how tag unions store their tag id.
+///
+/// The type is named like `Shape.Shape`, or just `Shape` for a type of the root module. Type
+/// variables have no layout, so the type has to be used at a concrete type somewhere in its
+/// module, and a type with type parameters is reported at the first such use.
+pub fn print_layout(
+    arena: &Bump,
+    roc_file_path: PathBuf,
+    type_path: &str,
+    target: Target,
+    roc_cache_dir: RocCacheDir<'_>,
+    threading: Threading,
+) -> io::Result<i32> {
+    let load_config = LoadConfig {
+        target,
+        function_kind: FunctionKind::LambdaSet,
+        render: RenderTarget::ColorTerminal,
+        palette: DEFAULT_PALETTE,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
+    };
+
+    let loaded =
+        match roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config) {
+            Ok(loaded) => loaded,
+            Err(problem) => return handle_loading_problem(problem),
+        };
+
+    let (module_name, type_name) = match type_path.rsplit_once('.') {
+        Some((module_name, type_name)) => (ModuleName::from(module_name), type_name),
+        None => (
+            loaded.interns.module_name(loaded.module_id).clone(),
+            type_path,
+        ),
+    };
+
+    let module_id = match loaded.interns.module_ids.get_id(&module_name) {
+        Some(module_id) => module_id,
+        None => {
+            eprintln!("This program has no module named {module_name}.");
+
+            return Ok(1);
+        }
+    };
+
+    // The root module's types are solved into `solved`, and the other modules keep their own.
+    let subs = if module_id == loaded.module_id {
+        Some(loaded.solved.inner())
+    } else {
+        loaded
+            .typechecked
+            .get(&module_id)
+            .map(|checked| checked.solved_subs.inner())
+    };
+
+    let symbol = loaded
+        .interns
+        .all_ident_ids
+        .get(&module_id)
+        .and_then(|ident_ids| ident_ids.get_id(type_name))
+        .map(|ident_id| Symbol::new(module_id, ident_id));
+
+    let (subs, symbol) = match (subs, symbol) {
+        (Some(subs), Some(symbol)) => (subs, symbol),
+        _ => {
+            eprintln!("The module {module_name} has no type named {type_name}.");
+
+            return Ok(1);
+        }
+    };
+
+    let layout_interner = GlobalLayoutInterner::with_capacity(128, target);
+    let mut layout_cache = LayoutCache::new(layout_interner.fork(), target);
+
+    let found = alias_vars(subs, symbol).find_map(|var| {
+        layout_cache
+            .from_var(arena, var, subs)
+            .ok()
+            .map(|layout| (var, layout))
+    });
+
+    let (var, layout) = match found {
+        Some(found) => found,
+        None => {
+            eprintln!(
+                "{type_path} isn't used at a concrete type in {module_name}, so it has no layout yet. (If `roc check` reports problems, fix those first.)"
+            );
+
+            return Ok(1);
+        }
+    };
+
+    let target_name: &'static str = target.into();
+    let report = layout_report(arena, &mut layout_cache, subs, var, layout);
+
+    println!("{type_path} on {target_name}\n");
+
+    for line in report.lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {line}");
+        }
+    }
+
+    Ok(0)
+}
+
+/// The variables in the subs that stand for the type.
+fn alias_vars(subs: &Subs, symbol: Symbol) -> impl Iterator<Item = Variable> + '_ {
+    (0..subs.len())
+        // SAFETY: every index below subs.len() is a variable of the subs
+        .map(|index| unsafe { Variable::from_index(index as u32) })
+        .filter(move |var| {
+            matches!(
+                subs.get_content_without_compacting(*var),
+                Content::Alias(alias, ..) if *alias == symbol
+            )
+        })
+}
@@ -10,17 +10,18 @@ use clap::{
 };
 use roc_build::link::{LinkType, LinkingStrategy};
 use roc_build::program::{
-    handle_error_module, handle_loading_problem, standard_load_config, BuildFileError,
+    check_file, handle_error_module, handle_loading_problem, standard_load_config, BuildFileError,
     BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions, DEFAULT_ROC_FILENAME,
 };
+use roc_build::size_report::SizeReport;
 use roc_collections::MutMap;
 use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
-use roc_load::{ExpectMetadata, Threading};
-use roc_module::symbol::ModuleId;
+use roc_load::{Defines, ExpectMetadata, LoadingProblem, Threading};
+use roc_module::symbol::{Interns, ModuleId};
 use roc_mono::ir::OptLevel;
-use roc_packaging::cache::RocCacheDir;
+use roc_packaging::cache::{self, RocCacheDir};
 use roc_packaging::tarball::Compression;
 use roc_reporting::report::ANSI_STYLE_CODES;
 use roc_target::{Architecture, Target};
@@ -36,21 +37,40 @@ use strum::IntoEnumIterator;
 #[cfg(not(target_os = "linux"))]
 use tempfile::TempDir;
 
+mod daemon;
 mod format;
-pub use format::{format_files, format_src, FormatMode};
+mod graph;
+mod ice;
+mod ide;
+mod info;
+mod watch;
+pub use daemon::{delegate as delegate_to_daemon, serve as serve_daemon, NO_DAEMON_VAR};
+pub use format::{ast_json, emit_ast_json, format_files, format_src, FormatMode};
+pub use graph::{print_graph, GraphFormat};
+pub use ice::{install_panic_hook, is_compiling_command};
+pub use ide::serve as serve_ide;
+pub use info::print_layout;
+pub use watch::watch_lib;
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
 pub const CMD_DEV: &str = "dev";
 pub const CMD_REPL: &str = "repl";
+pub const CMD_IDE: &str = "ide";
+pub const CMD_DAEMON: &str = "daemon";
+pub const CMD_INFO: &str = "info";
 pub const CMD_DOCS: &str = "docs";
 pub const CMD_CHECK: &str = "check";
+pub const CMD_LINT: &str = "lint";
+pub const CMD_GRAPH: &str = "graph";
 pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
+pub const CMD_PUBLISH: &str = "publish";
+pub const CMD_VENDOR: &str = "vendor";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
 pub const FLAG_PROFILING: &str = "profiling";
@@ -61,17 +81,41 @@ pub const FLAG_MAX_THREADS: &str = "max-threads";
 pub const FLAG_OPT_SIZE: &str = "opt-size";
 pub const FLAG_LIB: &str = "lib";
 pub const FLAG_NO_LINK: &str = "no-link";
+pub const FLAG_FREESTANDING: &str = "freestanding";
+pub const FLAG_EMIT_HEADERS_ONLY: &str = "emit-headers-only";
+pub const FLAG_GLUE_SPEC: &str = "glue-spec";
+pub const FLAG_LAYOUTS: &str = "layouts";
+pub const FLAG_WATCH: &str = "watch";
 pub const FLAG_TARGET: &str = "target";
 pub const FLAG_TIME: &str = "time";
 pub const FLAG_VERBOSE: &str = "verbose";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
+pub const FLAG_DEFINE: &str = "define";
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_STDIN: &str = "stdin";
 pub const FLAG_STDOUT: &str = "stdout";
+pub const FLAG_EMIT_AST: &str = "emit-ast";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
 pub const FLAG_FUZZ: &str = "fuzz";
+pub const FLAG_LEAK_CHECK: &str = "leak-check";
+pub const FLAG_SPLIT_OBJECTS: &str = "split-objects";
+pub const FLAG_COVERAGE: &str = "coverage";
+pub const FLAG_UPDATE: &str = "update";
+pub const FLAG_FILTER: &str = "filter";
+pub const FLAG_HIDE_VALUES: &str = "hide-values";
+pub const FLAG_DOC: &str = "doc";
+pub const FLAG_OFFLINE: &str = "offline";
+pub const FLAG_FAST: &str = "fast";
+pub const FLAG_SEED: &str = "seed";
+pub const FLAG_TIMEOUT: &str = "timeout";
+pub const FLAG_BUILTINS: &str = "builtins";
+pub const FLAG_FIND: &str = "find";
+pub const FLAG_DOT: &str = "dot";
+pub const FLAG_JSON: &str = "json";
+pub const FLAG_CALLS: &str = "calls";
+pub const FLAG_SIZE_REPORT: &str = "size-report";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_DIR: &str = "GLUE_DIR";
@@ -81,6 +125,7 @@ pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
 
 const VERSION: &str = include_str!("../../../version.txt");
 const DEFAULT_GENERATED_DOCS_DIR: &str = "generated-docs";
+const VENDOR_DIR_NAME: &str = "vendor";
 
 pub fn build_app() -> Command {
     let flag_optimize = Arg::new(FLAG_OPTIMIZE)
@@ -125,6 +170,12 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_offline = Arg::new(FLAG_OFFLINE)
+        .long(FLAG_OFFLINE)
+        .help("Never download packages; only use the ones that are already cached\n(This is implied when the app has a vendor directory made by `roc vendor`.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let flag_linker = Arg::new(FLAG_LINKER)
         .long(FLAG_LINKER)
         .help("Set which linker to use\n(The surgical linker is enabled by default only when building for wasm32 or x86_64 Linux, because those are the only targets it currently supports. Otherwise the legacy linker is used by default.)")
@@ -137,6 +188,13 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_define = Arg::new(FLAG_DEFINE)
+        .long(FLAG_DEFINE)
+        .help("Give the app a constant to read from its `Defines` module, e.g. `--define version=1.2.0`\n(`true` and `false` become a `Bool`, and anything else is a `Str`. Can be given more than once.)")
+        .value_name("NAME=VALUE")
+        .action(ArgAction::Append)
+        .required(false);
+
     let flag_wasm_stack_size_kb = Arg::new(FLAG_WASM_STACK_SIZE_KB)
         .long(FLAG_WASM_STACK_SIZE_KB)
         .help("Stack size in kilobytes for wasm32 target\n(This only applies when --dev also provided.)")
@@ -149,6 +207,18 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_leak_check = Arg::new(FLAG_LEAK_CHECK)
+        .long(FLAG_LEAK_CHECK)
+        .help("Track every allocation, and report the ones that were never freed when the program exits\n(Useful for finding refcounting bugs. Uses the legacy linker.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_split_objects = Arg::new(FLAG_SPLIT_OBJECTS)
+        .long(FLAG_SPLIT_OBJECTS)
+        .help("Generate machine code for each Roc module of the app on its own thread, and cache it per module\n(Experimental. The app is still optimized as a whole first. Uses the legacy linker.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .value_parser(value_parser!(PathBuf))
@@ -183,9 +253,13 @@ pub fn build_app() -> Command {
             .arg(flag_emit_llvm_ir.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_offline.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_define.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_leak_check.clone())
+            .arg(flag_split_objects.clone())
             .arg(flag_wasm_stack_size_kb)
             .arg(
                 Arg::new(FLAG_TARGET)
@@ -202,6 +276,14 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_WATCH)
+                    .long(FLAG_WATCH)
+                    .help("Rebuild the library whenever a .roc file changes, so a running host can reload it\n(See `roc_std::hot_reload` for how the host finds out.)")
+                    .action(ArgAction::SetTrue)
+                    .requires(FLAG_LIB)
+                    .required(false),
+            )
             .arg(
                 Arg::new(FLAG_BUNDLE)
                     .long(FLAG_BUNDLE)
@@ -210,6 +292,13 @@ pub fn build_app() -> Command {
                     .value_parser([".tar", ".tar.gz", ".tar.br"])
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_SIZE_REPORT)
+                    .long(FLAG_SIZE_REPORT)
+                    .help("After building, print how much of the binary's code came from each module, and which functions have the most code across all their specializations\n(This reads the binary's symbol table, so the surgical linker's executables, which don't list Roc's functions there, need --linker=legacy.)")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
             .arg(
                 Arg::new(FLAG_NO_LINK)
                     .long(FLAG_NO_LINK)
@@ -217,6 +306,30 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_FREESTANDING)
+                    .long(FLAG_FREESTANDING)
+                    .help("Output a `.o` file that doesn't depend on libc, for hosts like microcontrollers\n(The host must define roc_alloc, roc_realloc, roc_dealloc, roc_panic, and roc_dbg.)")
+                    .action(ArgAction::SetTrue)
+                    .requires(FLAG_NO_LINK)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_EMIT_HEADERS_ONLY)
+                    .long(FLAG_EMIT_HEADERS_ONLY)
+                    .help("Type check a platform and write the layouts of its entry points, without generating any code\n(The files go in the --output directory, which defaults to `headers`.)")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with_all([FLAG_LIB, FLAG_NO_LINK, FLAG_WATCH, FLAG_BUNDLE])
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_SPEC)
+                    .long(FLAG_GLUE_SPEC)
+                    .help("With --emit-headers-only, also write the glue that this spec generates, like `roc glue` does")
+                    .value_parser(value_parser!(PathBuf))
+                    .requires(FLAG_EMIT_HEADERS_ONLY)
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to build")
@@ -234,8 +347,10 @@ pub fn build_app() -> Command {
             .arg(flag_emit_llvm_ir.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_offline.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_define.clone())
             .arg(flag_fuzz.clone())
             .arg(
                 Arg::new(FLAG_VERBOSE)
@@ -244,6 +359,63 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false)
             )
+            .arg(
+                Arg::new(FLAG_COVERAGE)
+                    .long(FLAG_COVERAGE)
+                    .help("Report the `when` branches and functions that no test reached, by module")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_UPDATE)
+                    .long(FLAG_UPDATE)
+                    .help("In failing `expect`s that compare a string to a string literal, replace the literal with the actual string")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_DOC)
+                    .long(FLAG_DOC)
+                    .help("Also run the `expect`s in code blocks of doc comments")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_FAST)
+                    .long(FLAG_FAST)
+                    .help("Evaluate the `expect`s with an interpreter, and only compile them if one fails or can't be interpreted")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_FILTER)
+                    .long(FLAG_FILTER)
+                    .help("Only run the top-level `expect`s that come after a def whose name contains this text, up to the next def")
+                    .value_parser(value_parser!(String))
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_HIDE_VALUES)
+                    .long(FLAG_HIDE_VALUES)
+                    .help("Don't print the values of the variables in failed `expect`s")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_SEED)
+                    .long(FLAG_SEED)
+                    .help("The seed that `Random.check` generates the inputs of properties from, instead of 0")
+                    .value_parser(value_parser!(u64))
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_TIMEOUT)
+                    .long(FLAG_TIMEOUT)
+                    .help("How many seconds each top-level `expect` may run before it counts as failed")
+                    .value_parser(value_parser!(u64))
+                    .default_value("60")
+                    .required(false)
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -256,6 +428,12 @@ pub fn build_app() -> Command {
         .subcommand(Command::new(CMD_REPL)
             .about("Launch the interactive Read Eval Print Loop (REPL)")
         )
+        .subcommand(Command::new(CMD_IDE)
+            .about("Run the compiler as a service for editors and tools, answering JSON-RPC requests on stdin")
+        )
+        .subcommand(Command::new(CMD_DAEMON)
+            .about(concatcp!("Keep a warm compiler running in the background, which `roc check` and `roc build` hand their work to\n(Set ", NO_DAEMON_VAR, " to build without it.)"))
+        )
         .subcommand(Command::new(CMD_RUN)
             .about("Run a .roc file even if it has build errors")
             .arg(flag_optimize.clone())
@@ -265,9 +443,13 @@ pub fn build_app() -> Command {
             .arg(flag_emit_llvm_ir.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_offline.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_define.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_leak_check.clone())
+            .arg(flag_split_objects.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone().last(true))
         )
@@ -280,9 +462,13 @@ pub fn build_app() -> Command {
             .arg(flag_emit_llvm_ir.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_offline.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_define.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_leak_check.clone())
+            .arg(flag_split_objects.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone().last(true))
         )
@@ -315,14 +501,24 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
-            .after_help("If DIRECTORY_OR_FILES is omitted, the .roc files in the current working\ndirectory are formatted.")
+            .arg(
+                Arg::new(FLAG_EMIT_AST)
+                    .long(FLAG_EMIT_AST)
+                    .help("Print the syntax tree of each file instead of formatting it\n(Each file's tree is printed to stdout as one line of JSON, with the byte offsets of every node.)")
+                    .value_parser(["json"])
+                    .conflicts_with_all([FLAG_CHECK, FLAG_STDOUT])
+                    .required(false),
+            )
+            .after_help("If DIRECTORY_OR_FILES is omitted, the .roc files in the current working\ndirectory are formatted. Files that don't parse are skipped and\nlisted at the end.")
         )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
+            .arg(flag_offline.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_define.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -331,6 +527,101 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_LINT)
+            .about("Check the code, then point out code that works but could be simpler")
+            .arg(flag_offline.clone())
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of an app to lint, along with the modules next to it")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+            .after_help("A rule can be turned off for one line with a `# lint-disable-next-line RULE`\ncomment above it, or for a whole module with `# lint-disable RULE`.")
+            )
+        .subcommand(Command::new(CMD_GRAPH)
+            .about("Print which modules import which, as a Graphviz DOT graph or as JSON")
+            .arg(flag_offline.clone())
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_DOT)
+                    .long(FLAG_DOT)
+                    .help("Print the graph in Graphviz's DOT language, which `dot -Tsvg` can draw (the default)")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with(FLAG_JSON)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_JSON)
+                    .long(FLAG_JSON)
+                    .help("Print the graph as one JSON object with `modules`, `imports`, and `calls`")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_CALLS)
+                    .long(FLAG_CALLS)
+                    .help("Also find which exposed functions call which\n(Calls through private functions count as calls from the exposed function they start in. In DOT, this graph replaces the import graph.)")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of the program to graph")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_INFO)
+            .about("Print what the compiler decided about a program, like the memory layout of a type")
+            .arg(flag_offline.clone())
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_LAYOUTS)
+                    .long(FLAG_LAYOUTS)
+                    .help("Print the size, alignment, field offsets and tag id storage of a type, like `Shape.Shape`\n(A type without a module name is looked up in the .roc file's own module.)")
+                    .value_name("TYPE")
+                    .required(true),
+            )
+            .arg(
+                Arg::new(FLAG_TARGET)
+                    .long(FLAG_TARGET)
+                    .help("Choose a different target")
+                    .default_value(Into::<&'static str>::into(Target::default()))
+                    .value_parser(build_target_values_parser.clone())
+                    .required(false),
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of the program that uses the type")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_PUBLISH)
+            .about("Check a package or platform for problems and, if there are no errors, bundle it into a .tar.br archive that apps can depend on by URL")
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The main.roc file of the package or platform to publish")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_VENDOR)
+            .about("Copy the packages an app downloads from URLs into a vendor directory next to it, so it can be built without downloading anything")
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of the app whose packages should be vendored")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
@@ -341,6 +632,18 @@ pub fn build_app() -> Command {
                     .required(false)
                     .default_value(DEFAULT_GENERATED_DOCS_DIR),
                 )
+                .arg(Arg::new(FLAG_BUILTINS)
+                    .long(FLAG_BUILTINS)
+                    .help("Generate the documentation of the standard library instead of a package.")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+                )
+                .arg(Arg::new(FLAG_FIND)
+                    .long(FLAG_FIND)
+                    .help("Print the exposed values whose type matches this one, e.g. `roc docs --find \"List a, (a -> b) -> List b\"`, instead of generating documentation.")
+                    .value_name("TYPE")
+                    .required(false),
+                )
                 .arg(Arg::new(ROC_FILE)
                     .help("The package's main .roc file")
                     .value_parser(value_parser!(PathBuf))
@@ -412,9 +715,13 @@ pub fn build_app() -> Command {
         .arg(flag_emit_llvm_ir)
         .arg(flag_profiling)
         .arg(flag_time)
+        .arg(flag_offline)
         .arg(flag_linker)
         .arg(flag_prebuilt)
+        .arg(flag_define)
         .arg(flag_fuzz)
+        .arg(flag_leak_check)
+        .arg(flag_split_objects)
         .arg(roc_file_to_run)
         .arg(args_for_app.trailing_var_arg(true))
 }
@@ -426,6 +733,262 @@ pub enum BuildConfig {
     BuildAndRunIfNoErrors,
 }
 
+/// Bundle the package or platform whose main module is at `path` into an archive that can be
+/// downloaded as a URL dependency, and print the URL users should depend on.
+pub fn bundle(path: &Path, compression: Compression) -> io::Result<i32> {
+    let filename = build_archive(path, compression)?;
+
+    println!(
+        "To distribute this archive as a package, upload this to some URL and then add it as a dependency with:\n\n\t\x1B[32m\"https://your-url-goes-here/{filename}\"\x1B[39m\n"
+    );
+
+    Ok(0)
+}
+
+/// Check a package or platform for problems, and only if there are no errors, bundle it into a
+/// `.tar.br` archive and print the line users should add to their app header to depend on it.
+pub fn publish(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+    let path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let threading = threading_from_matches(matches);
+
+    // Nobody should be able to depend on a package that does not compile.
+    match check_file(
+        &arena,
+        path.to_owned(),
+        false,
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        threading,
+        Defines::default(),
+    ) {
+        Ok((problems, total_time)) => {
+            problems.print_error_warning_count(total_time);
+
+            if problems.errors > 0 {
+                eprintln!(
+                    "\nPlease fix these errors before publishing {}.\n",
+                    path.display()
+                );
+
+                return Ok(problems.exit_code());
+            }
+        }
+        Err(LoadingProblem::FormattedReport(report)) => {
+            print!("{report}");
+
+            return Ok(1);
+        }
+        Err(other) => {
+            internal_error!("checking the package failed with error:\n{other:?}");
+        }
+    }
+
+    let filename = build_archive(path, Compression::Brotli)?;
+    let url = format!("https://your-url-goes-here/{filename}");
+
+    let dependency = match read_header_kind(&arena, path)? {
+        HeaderKind::Platform => format!("pf: platform \"{url}\""),
+        HeaderKind::Package => {
+            let shorthand = path
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(OsStr::to_str)
+                .filter(|name| roc_parse::keyword::KEYWORDS.iter().all(|k| k != name))
+                .filter(|name| name.chars().all(|c| c.is_ascii_lowercase()))
+                .unwrap_or("pkg");
+
+            format!("{shorthand}: \"{url}\"")
+        }
+    };
+
+    println!(
+        "Upload this archive to \x1B[33m{url}\x1B[39m, replacing the placeholder with where it is hosted, and then add this to the header of any app that uses it:\n\n\t\x1B[32m{dependency}\x1B[39m\n"
+    );
+
+    Ok(0)
+}
+
+/// The dir to look for packages from URLs in: the vendor dir next to the .roc file, if
+/// `roc vendor` made one, and otherwise the cache dir.
+pub fn packages_dir(matches: &ArgMatches) -> PathBuf {
+    vendor_dir(matches).unwrap_or_else(cache::roc_cache_dir)
+}
+
+/// How to resolve packages from URLs that are not in `packages_dir` yet: download them, unless
+/// `--offline` was passed or the packages were vendored.
+pub fn roc_cache_dir_from_flags<'a>(
+    matches: &ArgMatches,
+    packages_dir: &'a Path,
+) -> RocCacheDir<'a> {
+    if matches.get_flag(FLAG_OFFLINE) || vendor_dir(matches).is_some() {
+        RocCacheDir::Offline(packages_dir)
+    } else {
+        RocCacheDir::Persistent(packages_dir)
+    }
+}
+
+/// The constants given with `--define`, which the app reads from its `Defines` module.
+pub fn defines_from_flags(matches: &ArgMatches) -> Defines {
+    let args = matches
+        .get_many::<String>(FLAG_DEFINE)
+        .into_iter()
+        .flatten()
+        .map(String::as_str);
+
+    match Defines::parse(args) {
+        Ok(defines) => defines,
+        Err(err) => user_error!("Invalid --{FLAG_DEFINE}: {err}"),
+    }
+}
+
+fn vendor_dir(matches: &ArgMatches) -> Option<PathBuf> {
+    let roc_file = matches.get_one::<PathBuf>(ROC_FILE)?;
+    let vendor_dir = roc_file.with_file_name(VENDOR_DIR_NAME);
+
+    vendor_dir.is_dir().then_some(vendor_dir)
+}
+
+/// Copy every package that the app at ROC_FILE downloads from a URL into a vendor dir next to
+/// it. From then on, builds of the app only use the packages in the vendor dir.
+pub fn vendor(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+    let path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let vendor_dir = path.with_file_name(VENDOR_DIR_NAME);
+    let cache_dir = cache::roc_cache_dir();
+
+    let src = arena.alloc_slice_copy(&std::fs::read(path)?);
+    let header = match roc_parse::module::parse_header(&arena, roc_parse::state::State::new(src)) {
+        Ok((module, _)) => module.header,
+        Err(err) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Unable to parse the header of {}: {:?}",
+                    path.display(),
+                    err.problem
+                ),
+            ));
+        }
+    };
+
+    let packages = match header {
+        roc_parse::ast::Header::App(header) => header.packages.value.items,
+        roc_parse::ast::Header::Package(header) => header.packages.value.items,
+        roc_parse::ast::Header::Platform(header) => header.packages.item.items,
+        roc_parse::ast::Header::Module(_) | roc_parse::ast::Header::Hosted(_) => &[],
+    };
+
+    let mut vendored = 0;
+
+    for package in packages {
+        let url = package.value.item().package_name.value.to_str();
+
+        // Packages given as paths are already on disk, so there is nothing to vendor.
+        if !url.starts_with("https://") {
+            continue;
+        }
+
+        match cache::vendor_package(RocCacheDir::Persistent(&cache_dir), url, &vendor_dir) {
+            Ok(_) => vendored += 1,
+            Err(problem) => {
+                let report = roc_reporting::report::to_https_problem_report_string(
+                    url,
+                    problem,
+                    path.to_path_buf(),
+                );
+                eprint!("{report}");
+
+                return Ok(1);
+            }
+        }
+    }
+
+    println!(
+        "Vendored {vendored} package{} into \x1B[33m{}\x1B[39m\n\nFrom now on, {} will only be built with the packages in this directory, without downloading anything. Run `roc {CMD_VENDOR}` again after changing its packages.",
+        if vendored == 1 { "" } else { "s" },
+        vendor_dir.display(),
+        path.display(),
+    );
+
+    Ok(0)
+}
+
+enum HeaderKind {
+    Package,
+    Platform,
+}
+
+fn read_header_kind(arena: &Bump, path: &Path) -> io::Result<HeaderKind> {
+    let src = std::fs::read(path)?;
+    let src = arena.alloc_slice_copy(&src);
+
+    match roc_parse::module::parse_header(arena, roc_parse::state::State::new(src)) {
+        Ok((module, _)) => match module.header {
+            roc_parse::ast::Header::Platform(_) => Ok(HeaderKind::Platform),
+            _ => Ok(HeaderKind::Package),
+        },
+        Err(err) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Unable to parse the header of {}: {:?}",
+                path.display(),
+                err.problem
+            ),
+        )),
+    }
+}
+
+/// Write the archive next to `path` and return its file name, which is its content hash
+/// followed by the extension of the compression.
+fn build_archive(path: &Path, compression: Compression) -> io::Result<String> {
+    let start_time = Instant::now();
+
+    // Print a note of advice. This is mainly here because brotli takes so long but produces
+    // such smaller output files; the idea is to encourage people to wait for brotli,
+    // so that downloads go faster. The compression only happens once, but the network
+    // transfer and decompression will happen many more times!
+    match compression {
+        Compression::Brotli => {
+            println!("Compressing with Brotli at maximum quality level…\n\n(Note: Brotli compression can take awhile! Using --{FLAG_BUNDLE} .tar.gz takes less time, but usually produces a significantly larger output file. Brotli is generally worth the up-front wait if this is a file people will be downloading!)\n");
+        }
+        Compression::Gzip => {
+            println!("Compressing with gzip at minimum quality…\n\n(Note: Gzip usually runs faster than Brotli but typically produces significantly larger output files. Consider using --{FLAG_BUNDLE} .tar.br if this is a file people will be downloading!)\n");
+        }
+        Compression::Uncompressed => {
+            println!("Building .tar archive without compression…\n\n(Note: Compression takes more time to run but typically produces much smaller output files. Consider using --{FLAG_BUNDLE} .tar.br if this is a file people will be downloading!)\n");
+        }
+    }
+
+    // Rather than building an executable or library, we're building
+    // a tarball so this code can be distributed via a HTTPS
+    let filename = roc_packaging::tarball::build(path, compression)?;
+    let total_time_ms = start_time.elapsed().as_millis();
+    let total_time = if total_time_ms > 1000 {
+        format!("{}s {}ms", total_time_ms / 1000, total_time_ms % 1000)
+    } else {
+        format!("{total_time_ms} ms")
+    };
+    let created_path = path.with_file_name(&filename);
+
+    println!(
+        "\nBundled \x1B[33m{}\x1B[39m and its dependent files into the following archive in {total_time}:\n\n\t\x1B[33m{}\x1B[39m\n",
+        path.to_string_lossy(),
+        created_path.to_string_lossy()
+    );
+
+    Ok(filename)
+}
+
+/// Reads `--max-threads` for any command that loads modules.
+pub fn threading_from_matches(matches: &ArgMatches) -> Threading {
+    match matches.get_one::<usize>(FLAG_MAX_THREADS) {
+        None => Threading::AllAvailable,
+        Some(0) => user_error!("cannot build with at most 0 threads"),
+        Some(1) => Threading::Single,
+        Some(n) => Threading::AtMost(*n),
+    }
+}
+
 fn opt_level_from_flags(matches: &ArgMatches) -> OptLevel {
     match (
         matches.get_flag(FLAG_OPTIMIZE),
@@ -455,21 +1018,17 @@ struct ModuleTestResults {
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     use roc_build::program::report_problems_monomorphized;
-    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError};
-    use roc_packaging::cache;
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, TestOptions};
+    use std::io::Write;
 
     let start_time = Instant::now();
     let arena = Bump::new();
     let opt_level = opt_level_from_flags(matches);
 
-    let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
-        None => Threading::AllAvailable,
-        Some(0) => user_error!("cannot build with at most 0 threads"),
-        Some(1) => Threading::Single,
-        Some(n) => Threading::AtMost(*n),
-    };
+    let threading = threading_from_matches(matches);
 
     let path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let coverage = matches.get_flag(FLAG_COVERAGE);
 
     // Spawn the root task
     if !path.exists() {
@@ -504,12 +1063,20 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading,
-        exec_mode: ExecutionMode::Test,
+        exec_mode: ExecutionMode::Test(TestOptions {
+            coverage,
+            doc_tests: matches.get_flag(FLAG_DOC),
+        }),
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: defines_from_flags(matches),
     };
     let load_result = roc_load::load_and_monomorphize(
         arena,
         path.to_path_buf(),
-        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        roc_cache_dir_from_flags(matches, &packages_dir(matches)),
         load_config,
     );
 
@@ -523,12 +1090,35 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         }
     };
     let problems = report_problems_monomorphized(&mut loaded);
+    let filter = matches.get_one::<String>(FLAG_FILTER);
+
+    // Coverage counters and string literal updates need the compiled expects.
+    if matches.get_flag(FLAG_FAST) && !coverage && !matches.get_flag(FLAG_UPDATE) {
+        if let Some(passed_count) = interpret_expects(&loaded, filter) {
+            if problems.warnings > 0 {
+                problems.print_error_warning_count(start_time.elapsed());
+                println!(".\n");
+            }
+
+            if passed_count == 0 {
+                println!("No expectations were found.");
+
+                return Ok(2);
+            }
+
+            println!("{}", test_summary(0, passed_count, start_time.elapsed()));
+
+            return Ok(0);
+        }
+    }
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
 
     let interns = loaded.interns.clone();
     let sources = loaded.sources.clone();
 
+    let coverage_points = roc_mono::coverage::coverage_points(loaded.procedures.values());
+
     let (dyn_lib, expects_by_module, layout_interner) =
         roc_repl_expect::run::expect_mono_module_to_dylib(
             arena,
@@ -539,6 +1129,10 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         )
         .unwrap();
 
+    if let Some(seed) = matches.get_one::<u64>(FLAG_SEED) {
+        set_test_seed(&dyn_lib, *seed);
+    }
+
     // Print warnings before running tests.
     {
         debug_assert_eq!(
@@ -561,24 +1155,137 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     let mut total_passed_count = 0;
 
     let mut results_by_module = Vec::new();
+    let mut snapshot_updates = Vec::new();
+    let mut expect_durations = Vec::new();
     let global_layout_interner = layout_interner.into_global();
 
+    let show_values = !matches.get_flag(FLAG_HIDE_VALUES);
+    let timeout = Duration::from_secs(*matches.get_one::<u64>(FLAG_TIMEOUT).unwrap());
+
+    // The coverage counters are in the dylib, so with --coverage the expects run in this process,
+    // one at a time. Otherwise each one runs in a child process, several at once.
+    let jobs = if coverage {
+        None
+    } else {
+        Some(match threading {
+            Threading::AllAvailable => std::thread::available_parallelism().map_or(1, usize::from),
+            Threading::Single => 1,
+            Threading::AtMost(n) => n,
+        })
+    };
+
     let compilation_duration = start_time.elapsed();
 
     for (module_id, expects) in expects_by_module.into_iter() {
+        let (module_path, src) = sources.get(&module_id).unwrap();
+        let def_starts = filter.map(|_| top_level_def_starts(src));
+
+        let fx = expects.fx.iter().map(|expect| (true, *expect));
+        let pure = expects.pure.iter().map(|expect| (false, *expect));
+        let expects: Vec<_> = fx
+            .chain(pure)
+            .filter(|(_, expect)| match (filter, &def_starts) {
+                (Some(filter), Some(def_starts)) => {
+                    follows_matching_def(def_starts, expect.region, filter)
+                }
+                _ => true,
+            })
+            .collect();
+
         let test_start_time = Instant::now();
 
-        let (failed_count, passed_count) = roc_repl_expect::run::run_toplevel_expects(
-            &mut writer,
-            roc_reporting::report::RenderTarget::ColorTerminal,
-            arena,
-            interns,
-            &global_layout_interner,
-            &dyn_lib,
-            &mut expectations,
-            expects,
-        )
-        .unwrap();
+        let mut failed_count = 0;
+        let mut passed_count = 0;
+
+        if let Some(jobs) = jobs {
+            // The children are forked from this thread, which is the only one running by now.
+            let result = roc_repl_expect::run::run_toplevel_expects_isolated(
+                roc_reporting::report::RenderTarget::ColorTerminal,
+                interns,
+                &global_layout_interner,
+                &dyn_lib,
+                &mut expectations,
+                &expects,
+                show_values,
+                jobs,
+                timeout,
+            );
+
+            let outcomes = match result {
+                Ok(outcomes) => outcomes,
+                Err(error) => {
+                    eprintln!("\nCould not start a process to run the expects in: {error}\n");
+
+                    return Ok(1);
+                }
+            };
+
+            // Print the reports in source order, whichever expect finished first.
+            for ((_, expect), (outcome, duration)) in expects.iter().zip(outcomes) {
+                use roc_repl_expect::run::IsolatedExpectOutcome;
+
+                expect_durations.push((module_id, expect.region, duration));
+
+                match outcome {
+                    IsolatedExpectOutcome::Passed => passed_count += 1,
+                    IsolatedExpectOutcome::Failed {
+                        report,
+                        snapshot_updates: updates,
+                    } => {
+                        writer.write_all(&report)?;
+                        snapshot_updates.extend(updates);
+                        failed_count += 1;
+                    }
+                    IsolatedExpectOutcome::TimedOut => {
+                        let start =
+                            roc_region::all::LineInfo::new(src).convert_pos(expect.region.start());
+
+                        writeln!(
+                            writer,
+                            "The expect at {}:{} was stopped after running for {} s.\n",
+                            module_path.display(),
+                            start.line + 1,
+                            timeout.as_secs()
+                        )?;
+                        failed_count += 1;
+                    }
+                }
+            }
+        } else {
+            // Run the expects one at a time, so we can tell which ones are slow.
+            for (is_fx, expect) in expects {
+                let mut single = roc_repl_expect::run::ExpectFunctions {
+                    pure: bumpalo::collections::Vec::new_in(arena),
+                    fx: bumpalo::collections::Vec::new_in(arena),
+                };
+
+                if is_fx {
+                    single.fx.push(expect);
+                } else {
+                    single.pure.push(expect);
+                }
+
+                let expect_start_time = Instant::now();
+
+                let (failed, passed) = roc_repl_expect::run::run_toplevel_expects(
+                    &mut writer,
+                    roc_reporting::report::RenderTarget::ColorTerminal,
+                    arena,
+                    interns,
+                    &global_layout_interner,
+                    &dyn_lib,
+                    &mut expectations,
+                    single,
+                    &mut snapshot_updates,
+                    show_values,
+                )?;
+
+                expect_durations.push((module_id, expect.region, expect_start_time.elapsed()));
+
+                failed_count += failed;
+                passed_count += passed;
+            }
+        }
 
         let tests_duration = test_start_time.elapsed();
 
@@ -611,12 +1318,22 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
             for module_test_results in results_by_module {
                 print_test_results(module_test_results, &sources);
             }
+            print_slowest_expects(expect_durations, &sources);
         } else {
             let test_summary_str =
                 test_summary(total_failed_count, total_passed_count, total_duration);
             println!("{test_summary_str}");
         }
 
+        if coverage {
+            print_coverage_report(&dyn_lib, coverage_points, &sources);
+        }
+
+        if matches.get_flag(FLAG_UPDATE) && !snapshot_updates.is_empty() {
+            let updated_count = roc_repl_expect::run::apply_snapshot_updates(snapshot_updates)?;
+            println!("\nUpdated {updated_count} string literal(s) to match the actual values.");
+        }
+
         Ok((total_failed_count > 0) as i32)
     }
 }
@@ -640,6 +1357,212 @@ fn print_test_results(
     println!("\n{module_name}:\n    {test_summary_str}",);
 }
 
+/// Makes `Random.check` generate inputs from the given seed, for the tests in the library.
+#[cfg(not(windows))]
+fn set_test_seed(lib: &libloading::Library, seed: u64) {
+    let name = roc_builtins::bitcode::UTILS_SET_TEST_SEED;
+
+    unsafe {
+        if let Ok(set_test_seed) = lib.get::<unsafe extern "C" fn(u64)>(name.as_bytes()) {
+            set_test_seed(seed);
+        }
+    }
+}
+
+/// Print the `when` branches and functions that none of the tests reached, grouped by module.
+///
+/// Only code that the tests can reach is compiled, so functions that no test calls at all
+/// do not show up here. Counters of `expect-fx` tests are lost, because those run in a
+/// forked process.
+#[cfg(not(windows))]
+fn print_coverage_report(
+    lib: &libloading::Library,
+    coverage_points: roc_collections::MutSet<roc_mono::coverage::CoveragePoint>,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+) {
+    use roc_mono::coverage::CoverageKind;
+
+    let mut points_by_module: MutMap<ModuleId, Vec<_>> = MutMap::default();
+
+    for point in coverage_points {
+        points_by_module
+            .entry(point.module_id)
+            .or_default()
+            .push(point);
+    }
+
+    let mut modules: Vec<_> = points_by_module
+        .into_iter()
+        .filter_map(|(module_id, points)| {
+            let (module_path, src) = sources.get(&module_id)?;
+
+            Some((module_path, src, points))
+        })
+        .collect();
+
+    modules.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+    let red = ANSI_STYLE_CODES.red;
+    let green = ANSI_STYLE_CODES.green;
+    let reset = ANSI_STYLE_CODES.reset;
+
+    println!("\nCoverage of `when` branches and functions:");
+
+    for (module_path, src, mut points) in modules {
+        points.sort_by_key(|point| point.region.start());
+
+        let uncovered: Vec<_> = points
+            .iter()
+            .filter(|point| {
+                let name = point.symbol_name();
+                let count = unsafe {
+                    lib.get::<*const u64>(name.as_bytes())
+                        .map(|counter| **counter)
+                        .unwrap_or(0)
+                };
+
+                count == 0
+            })
+            .collect();
+
+        println!("\n{}:", module_path.display());
+
+        for (kind, noun) in [
+            (CoverageKind::Branch, "branches"),
+            (CoverageKind::Function, "functions"),
+        ] {
+            let total = points.iter().filter(|point| point.kind == kind).count();
+            let uncovered_count = uncovered.iter().filter(|point| point.kind == kind).count();
+            let color = if uncovered_count == 0 { green } else { red };
+
+            println!(
+                "    {color}{}{reset} of {total} {noun} covered.",
+                total - uncovered_count
+            );
+        }
+
+        let line_info = roc_region::all::LineInfo::new(src);
+
+        for point in uncovered {
+            let start = line_info.convert_pos(point.region.start());
+            let what = match point.kind {
+                CoverageKind::Branch => "branch",
+                CoverageKind::Function => "function",
+            };
+
+            println!(
+                "    {red}{what} not covered{reset}: line {}, column {}",
+                start.line + 1,
+                start.column + 1
+            );
+        }
+    }
+}
+
+/// Run the top-level `expect`s with the interpreter, without compiling them, and return how many
+/// passed. Returns `None` if any of them failed or did something the interpreter can't evaluate;
+/// then they all need to be compiled, so failures are reported with the values involved.
+fn interpret_expects(
+    loaded: &roc_load::MonomorphizedModule,
+    filter: Option<&String>,
+) -> Option<usize> {
+    use roc_mono::interpret::Interpreter;
+
+    let mut interpreter = Interpreter::new(&loaded.procedures, &loaded.layout_interner);
+    let mut passed_count = 0;
+
+    for (module_id, expects) in loaded.toplevel_expects.iter() {
+        let (_, src) = loaded.sources.get(module_id)?;
+        let def_starts = filter.map(|_| top_level_def_starts(src));
+
+        for (symbol, region) in expects.pure.iter().chain(expects.fx.iter()) {
+            if let (Some(filter), Some(def_starts)) = (filter, &def_starts) {
+                if !follows_matching_def(def_starts, *region, filter) {
+                    continue;
+                }
+            }
+
+            match interpreter.run_toplevel_expect(*symbol) {
+                Ok(true) => passed_count += 1,
+                Ok(false) | Err(_) => return None,
+            }
+        }
+    }
+
+    Some(passed_count)
+}
+
+/// Where each named top-level def of a module starts, in source order.
+fn top_level_def_starts(src: &str) -> Vec<(u32, String)> {
+    use roc_parse::ast::{Pattern, ValueDef};
+
+    let arena = Bump::new();
+
+    let Ok(ast) = format::parse_all(&arena, src) else {
+        return Vec::new();
+    };
+
+    ast.defs
+        .defs()
+        .zip(ast.defs.regions.iter())
+        .filter_map(|(def, region)| {
+            let pattern = match def {
+                Err(ValueDef::Annotation(pattern, _)) => &pattern.value,
+                Err(ValueDef::Body(pattern, _)) => &pattern.value,
+                Err(ValueDef::AnnotatedBody { body_pattern, .. }) => &body_pattern.value,
+                _ => return None,
+            };
+
+            match pattern {
+                Pattern::Identifier { ident } => Some((region.start().offset, ident.to_string())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Whether the closest named def before a top-level `expect` has a name that contains `filter`.
+/// Expects go right after the def they test, so that's the def `roc test --filter` matches.
+fn follows_matching_def(
+    def_starts: &[(u32, String)],
+    region: roc_region::all::Region,
+    filter: &str,
+) -> bool {
+    def_starts
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= region.start().offset)
+        .map_or(false, |(_, name)| name.contains(filter))
+}
+
+/// Print where the slowest few top-level `expect`s are, and how long each of them took.
+fn print_slowest_expects(
+    mut expect_durations: Vec<(ModuleId, roc_region::all::Region, Duration)>,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+) {
+    const SLOWEST_COUNT: usize = 5;
+
+    if expect_durations.is_empty() {
+        return;
+    }
+
+    expect_durations.sort_by_key(|(_, _, duration)| std::cmp::Reverse(*duration));
+
+    println!("\nSlowest expects:");
+
+    for (module_id, region, duration) in expect_durations.into_iter().take(SLOWEST_COUNT) {
+        let (module_path, src) = sources.get(&module_id).unwrap();
+        let start = roc_region::all::LineInfo::new(src).convert_pos(region.start());
+
+        println!(
+            "    {}:{} in {} ms",
+            module_path.display(),
+            start.line + 1,
+            duration.as_millis()
+        );
+    }
+}
+
 fn test_summary(failed_count: usize, passed_count: usize, tests_duration: Duration) -> String {
     let failed_color = if failed_count == 0 {
         ANSI_STYLE_CODES.green
@@ -724,46 +1647,11 @@ pub fn build(
         }
 
         if config == BuildConfig::BuildOnly && matches.contains_id(FLAG_BUNDLE) {
-            let start_time = Instant::now();
-
             let compression =
                 Compression::try_from(matches.get_one::<String>(FLAG_BUNDLE).unwrap().as_str())
                     .unwrap();
 
-            // Print a note of advice. This is mainly here because brotli takes so long but produces
-            // such smaller output files; the idea is to encourage people to wait for brotli,
-            // so that downloads go faster. The compression only happens once, but the network
-            // transfer and decompression will happen many more times!
-            match compression {
-                Compression::Brotli => {
-                    println!("Compressing with Brotli at maximum quality level…\n\n(Note: Brotli compression can take awhile! Using --{FLAG_BUNDLE} .tar.gz takes less time, but usually produces a significantly larger output file. Brotli is generally worth the up-front wait if this is a file people will be downloading!)\n");
-                }
-                Compression::Gzip => {
-                    println!("Compressing with gzip at minimum quality…\n\n(Note: Gzip usually runs faster than Brotli but typically produces significantly larger output files. Consider using --{FLAG_BUNDLE} .tar.br if this is a file people will be downloading!)\n");
-                }
-                Compression::Uncompressed => {
-                    println!("Building .tar archive without compression…\n\n(Note: Compression takes more time to run but typically produces much smaller output files. Consider using --{FLAG_BUNDLE} .tar.br if this is a file people will be downloading!)\n");
-                }
-            }
-
-            // Rather than building an executable or library, we're building
-            // a tarball so this code can be distributed via a HTTPS
-            let filename = roc_packaging::tarball::build(path, compression)?;
-            let total_time_ms = start_time.elapsed().as_millis();
-            let total_time = if total_time_ms > 1000 {
-                format!("{}s {}ms", total_time_ms / 1000, total_time_ms % 1000)
-            } else {
-                format!("{total_time_ms} ms")
-            };
-            let created_path = path.with_file_name(&filename);
-
-            println!(
-                "\nBundled \x1B[33m{}\x1B[39m and its dependent files into the following archive in {total_time}:\n\n\t\x1B[33m{}\x1B[39m\n\nTo distribute this archive as a package, upload this to some URL and then add it as a dependency with:\n\n\t\x1B[32m\"https://your-url-goes-here/{filename}\"\x1B[39m\n",
-                path.to_string_lossy(),
-                created_path.to_string_lossy()
-            );
-
-            return Ok(0);
+            return bundle(path, compression);
         }
     }
 
@@ -803,19 +1691,28 @@ pub fn build(
         || matches!(opt_level, OptLevel::Development | OptLevel::Normal);
     let emit_timings = matches.get_flag(FLAG_TIME);
 
-    let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
-        None => Threading::AllAvailable,
-        Some(0) => user_error!("cannot build with at most 0 threads"),
-        Some(1) => Threading::Single,
-        Some(n) => Threading::AtMost(*n),
-    };
+    let threading = threading_from_matches(matches);
 
     let wasm_dev_backend = matches!(code_gen_backend, CodeGenBackend::Wasm);
 
+    let leak_check = matches.get_flag(FLAG_LEAK_CHECK);
+    if leak_check && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot check for leaks while using a dev backend.");
+    }
+
+    let split_objects = matches.get_flag(FLAG_SPLIT_OBJECTS);
+    if split_objects && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot split the app into objects while using a dev backend.");
+    }
+
+    // The surgical linker doesn't carry over the app's destructors, which print the leak report,
+    // and takes a single object for the app.
     let linking_strategy = if wasm_dev_backend {
         LinkingStrategy::Additive
     } else if !roc_linker::supported(link_type, target)
         || matches.get_one::<String>(FLAG_LINKER).map(|s| s.as_str()) == Some("legacy")
+        || leak_check
+        || split_objects
     {
         LinkingStrategy::Legacy
     } else {
@@ -839,6 +1736,16 @@ pub fn build(
         user_error!("Cannot instrument binary for fuzzing while using a dev backend.");
     }
 
+    let freestanding = matches
+        .try_get_one::<bool>(FLAG_FREESTANDING)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or(false);
+    if freestanding && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot build a freestanding object while using a dev backend.");
+    }
+
     let wasm_dev_stack_bytes: Option<u32> = matches
         .try_get_one::<u32>(FLAG_WASM_STACK_SIZE_KB)
         .ok()
@@ -856,9 +1763,21 @@ pub fn build(
         emit_debug_info,
         emit_llvm_ir,
         fuzz,
+        freestanding,
+        leak_check,
+        split_objects,
     };
 
-    let load_config = standard_load_config(target, build_ordering, threading);
+    let load_config = roc_load::LoadConfig {
+        // Evaluating constants ahead of time only pays off in optimized builds.
+        optimize_ir: matches!(opt_level, OptLevel::Optimize | OptLevel::Size),
+        // Only the LLVM backend can stop and report a failing inline `expect`.
+        report_expects_with_dbg: !matches!(code_gen_backend, CodeGenBackend::Llvm(_)),
+        cancellation: None,
+        problem_sink: None,
+        defines: defines_from_flags(matches),
+        ..standard_load_config(target, build_ordering, threading)
+    };
 
     let res_binary_path = build_file(
         &arena,
@@ -898,6 +1817,10 @@ pub fn build(
                     problems.print_error_warning_count(total_time);
                     println!(" while successfully building:\n\n    {generated_filename}");
 
+                    if matches.get_flag(FLAG_SIZE_REPORT) {
+                        print_size_report(&binary_path, &expect_metadata.interns);
+                    }
+
                     // Return a nonzero exit code if there were problems
                     Ok(problems.exit_code())
                 }
@@ -1176,6 +2099,20 @@ impl ExecutableFile {
     }
 }
 
+fn print_size_report(binary_path: &Path, interns: &Interns) {
+    let report = std::fs::read(binary_path)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| SizeReport::new(&bytes, interns).map_err(|err| err.to_string()));
+
+    match report {
+        Ok(report) if report.modules.is_empty() => {
+            println!("\nThe binary's symbol table doesn't name any Roc functions, so there's no size report. (Was it built with the surgical linker?)");
+        }
+        Ok(report) => println!("\n{report}"),
+        Err(err) => eprintln!("\nCould not read the binary for the size report: {err}"),
+    }
+}
+
 // with Expect
 #[cfg(target_family = "unix")]
 fn roc_dev_native(
@@ -1,20 +1,29 @@
 //! The `roc` binary that brings together all functionality in the Roc toolset.
 use bumpalo::Bump;
+use clap::ArgMatches;
 use roc_build::link::LinkType;
 use roc_build::program::{check_file, CodeGenBackend};
 use roc_cli::{
-    build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
-    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL,
-    CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB,
-    FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR,
-    GLUE_SPEC, ROC_FILE,
+    ast_json, build_app, defines_from_flags, delegate_to_daemon, emit_ast_json, format_files,
+    format_src, install_panic_hook, is_compiling_command, packages_dir, print_graph, print_layout,
+    publish, roc_cache_dir_from_flags, serve_daemon, serve_ide, test, threading_from_matches,
+    vendor, watch_lib, BuildConfig, FormatMode, GraphFormat, CMD_BUILD, CMD_CHECK, CMD_DAEMON,
+    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_GRAPH, CMD_IDE, CMD_INFO,
+    CMD_LINT, CMD_PREPROCESS_HOST, CMD_PUBLISH, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VENDOR,
+    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_BUILTINS, FLAG_CALLS, FLAG_CHECK, FLAG_DEV,
+    FLAG_EMIT_AST, FLAG_EMIT_HEADERS_ONLY, FLAG_FIND, FLAG_GLUE_SPEC, FLAG_JSON, FLAG_LAYOUTS,
+    FLAG_LIB, FLAG_NO_LINK, FLAG_OFFLINE, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET,
+    FLAG_TIME, FLAG_WATCH, GLUE_DIR, GLUE_SPEC, ROC_FILE,
+};
+use roc_docs::{
+    find_builtins_by_type, find_by_type, generate_builtin_docs_html, generate_docs_html,
 };
-use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
-use roc_load::{FunctionKind, LoadingProblem, Threading};
+use roc_load::{FunctionKind, LoadingProblem};
 use roc_packaging::cache::{self, RocCacheDir};
+use roc_reporting::report::RenderTarget;
 use roc_target::Target;
 use std::fs::{self, FileType};
 use std::io::{self, Read, Write};
@@ -25,6 +34,8 @@ use target_lexicon::Triple;
 #[macro_use]
 extern crate const_format;
 
+const DEFAULT_HEADERS_DIR: &str = "headers";
+
 #[global_allocator]
 static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
@@ -42,6 +53,24 @@ fn main() -> io::Result<()> {
         .collect();
     let matches = app.get_matches();
 
+    if is_compiling_command(&matches) {
+        let args = std::env::args_os()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+
+        install_panic_hook(args);
+    }
+
+    if let Some((CMD_CHECK | CMD_BUILD, sub_matches)) = matches.subcommand() {
+        let watching = matches!(sub_matches.try_get_one::<bool>(FLAG_WATCH), Ok(Some(&true)));
+
+        if !watching {
+            if let Some(exit_code) = delegate_to_daemon() {
+                std::process::exit(exit_code);
+            }
+        }
+    }
+
     let exit_code = match matches.subcommand() {
         None => {
             if matches.contains_id(ROC_FILE) {
@@ -51,7 +80,7 @@ fn main() -> io::Result<()> {
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host().into(),
                     None,
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(&matches, &packages_dir(&matches)),
                     LinkType::Executable,
                 )
             } else {
@@ -66,7 +95,7 @@ fn main() -> io::Result<()> {
                     BuildConfig::BuildAndRun,
                     Triple::host().into(),
                     None,
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(matches, &packages_dir(matches)),
                     LinkType::Executable,
                 )
             } else {
@@ -92,7 +121,7 @@ fn main() -> io::Result<()> {
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host().into(),
                     None,
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(matches, &packages_dir(matches)),
                     LinkType::Executable,
                 )
             } else {
@@ -163,71 +192,37 @@ fn main() -> io::Result<()> {
             );
             Ok(0)
         }
-        Some((CMD_BUILD, matches)) => {
-            let target = matches
-                .get_one::<String>(FLAG_TARGET)
-                .and_then(|s| Target::from_str(s).ok())
-                .unwrap_or_default();
-            let link_type = match (matches.get_flag(FLAG_LIB), matches.get_flag(FLAG_NO_LINK)) {
-                (true, false) => LinkType::Dylib,
-                (true, true) => user_error!("build can only be one of `--lib` or `--no-link`"),
-                (false, true) => LinkType::None,
-                (false, false) => LinkType::Executable,
-            };
-            let out_path = matches
-                .get_one::<OsString>(FLAG_OUTPUT)
-                .map(OsString::as_ref);
-
-            Ok(build(
-                matches,
-                &subcommands,
-                BuildConfig::BuildOnly,
-                target,
-                out_path,
-                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-                link_type,
-            )?)
-        }
-        Some((CMD_CHECK, matches)) => {
-            let arena = Bump::new();
-
-            let emit_timings = matches.get_flag(FLAG_TIME);
-            let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
-            let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
-                None => Threading::AllAvailable,
-                Some(0) => user_error!("cannot build with at most 0 threads"),
-                Some(1) => Threading::Single,
-                Some(n) => Threading::AtMost(*n),
-            };
-
-            match check_file(
-                &arena,
-                roc_file_path.to_owned(),
-                emit_timings,
-                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-                threading,
-            ) {
-                Ok((problems, total_time)) => {
-                    problems.print_error_warning_count(total_time);
-                    Ok(problems.exit_code())
-                }
-
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{report}");
-
-                    Ok(1)
-                }
-                Err(other) => {
-                    panic!("build_file failed with error:\n{other:?}");
-                }
-            }
-        }
+        Some((CMD_BUILD, matches)) => build_command(matches, &subcommands),
+        Some((CMD_CHECK, matches)) => check_command(matches),
+        Some((CMD_LINT, matches)) => lint_command(matches),
+        Some((CMD_INFO, matches)) => info_command(matches),
+        Some((CMD_GRAPH, matches)) => graph_command(matches),
+        Some((CMD_PUBLISH, matches)) => Ok(publish(matches)?),
+        Some((CMD_VENDOR, matches)) => Ok(vendor(matches)?),
         Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
+        Some((CMD_IDE, _)) => Ok(serve_ide()?),
+        Some((CMD_DAEMON, _)) => serve_daemon(run_for_daemon),
         Some((CMD_DOCS, matches)) => {
             let root_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
             let out_dir = matches.get_one::<OsString>(FLAG_OUTPUT).unwrap();
 
-            generate_docs_html(root_path.to_owned(), out_dir.as_ref());
+            let builtins = matches.get_flag(FLAG_BUILTINS);
+
+            if let Some(query) = matches.get_one::<String>(FLAG_FIND) {
+                let found = if builtins {
+                    find_builtins_by_type(query)
+                } else {
+                    find_by_type(root_path.to_owned(), query)
+                };
+
+                return Ok(if found.is_empty() { 1 } else { 0 });
+            }
+
+            if builtins {
+                generate_builtin_docs_html(out_dir.as_ref());
+            } else {
+                generate_docs_html(root_path.to_owned(), out_dir.as_ref());
+            }
 
             Ok(0)
         }
@@ -243,7 +238,9 @@ fn main() -> io::Result<()> {
                 }
             };
 
-            if from_stdin && matches!(format_mode, FormatMode::WriteToFile) {
+            let emit_ast = matches.contains_id(FLAG_EMIT_AST);
+
+            if from_stdin && !emit_ast && matches!(format_mode, FormatMode::WriteToFile) {
                 eprintln!("When using the --stdin flag, either the --check or the --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
                 std::process::exit(1);
             }
@@ -259,7 +256,7 @@ fn main() -> io::Result<()> {
                             values.push(os_string.to_owned());
                         }
                     }
-                    None if from_stdin || to_stdout => {}
+                    None if from_stdin || to_stdout || emit_ast => {}
                     None => {
                         let mut os_string_values: Vec<OsString> = Vec::new();
 
@@ -283,7 +280,36 @@ fn main() -> io::Result<()> {
                 roc_files
             };
 
-            let format_exit_code = if from_stdin {
+            let format_exit_code = if emit_ast && from_stdin {
+                let mut buf = Vec::new();
+                let arena = Bump::new();
+
+                io::stdin().read_to_end(&mut buf)?;
+
+                let src = std::str::from_utf8(&buf).unwrap_or_else(|err| {
+                    eprintln!("Stdin contained invalid UTF-8 bytes: {err:?}");
+                    std::process::exit(1);
+                });
+
+                match ast_json(&arena, src, None) {
+                    Ok(json) => {
+                        println!("{json}");
+                        0
+                    }
+                    Err(parse_err) => {
+                        eprintln!("`roc format --emit-ast` failed: {parse_err}");
+                        1
+                    }
+                }
+            } else if emit_ast {
+                match emit_ast_json(roc_files) {
+                    Ok(()) => 0,
+                    Err(message) => {
+                        eprintln!("{message}");
+                        1
+                    }
+                }
+            } else if from_stdin {
                 let mut buf = Vec::new();
                 let arena = Bump::new();
 
@@ -351,6 +377,181 @@ fn main() -> io::Result<()> {
     std::process::exit(exit_code);
 }
 
+fn build_command(matches: &ArgMatches, subcommands: &[String]) -> io::Result<i32> {
+    let target = matches
+        .get_one::<String>(FLAG_TARGET)
+        .and_then(|s| Target::from_str(s).ok())
+        .unwrap_or_default();
+    let link_type = match (matches.get_flag(FLAG_LIB), matches.get_flag(FLAG_NO_LINK)) {
+        (true, false) => LinkType::Dylib,
+        (true, true) => user_error!("build can only be one of `--lib` or `--no-link`"),
+        (false, true) => LinkType::None,
+        (false, false) => LinkType::Executable,
+    };
+    let out_path = matches
+        .get_one::<OsString>(FLAG_OUTPUT)
+        .map(OsString::as_ref);
+
+    if matches.get_flag(FLAG_WATCH) {
+        let roc_file = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+
+        watch_lib(roc_file, out_path.map(Path::new));
+    }
+
+    if matches.get_flag(FLAG_EMIT_HEADERS_ONLY) {
+        let roc_file = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+        let spec_path = matches.get_one::<PathBuf>(FLAG_GLUE_SPEC);
+
+        return roc_glue::emit_headers(
+            roc_file,
+            out_path.map_or(Path::new(DEFAULT_HEADERS_DIR), Path::new),
+            spec_path.map(PathBuf::as_path),
+            CodeGenBackend::Llvm(LlvmBackendMode::BinaryGlue),
+        );
+    }
+
+    build(
+        matches,
+        subcommands,
+        BuildConfig::BuildOnly,
+        target,
+        out_path,
+        roc_cache_dir_from_flags(matches, &packages_dir(matches)),
+        link_type,
+    )
+}
+
+fn check_command(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+
+    let emit_timings = matches.get_flag(FLAG_TIME);
+    let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let threading = threading_from_matches(matches);
+
+    match check_file(
+        &arena,
+        roc_file_path.to_owned(),
+        emit_timings,
+        roc_cache_dir_from_flags(matches, &packages_dir(matches)),
+        threading,
+        defines_from_flags(matches),
+    ) {
+        Ok((problems, total_time)) => {
+            problems.print_error_warning_count(total_time);
+            Ok(problems.exit_code())
+        }
+
+        Err(LoadingProblem::FormattedReport(report)) => {
+            print!("{report}");
+
+            Ok(1)
+        }
+        Err(other) => {
+            panic!("build_file failed with error:\n{other:?}");
+        }
+    }
+}
+
+fn lint_command(matches: &ArgMatches) -> io::Result<i32> {
+    let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let threading = threading_from_matches(matches);
+    let options = roc_compiler::Options {
+        render: RenderTarget::ColorTerminal,
+        threading,
+        offline: matches.get_flag(FLAG_OFFLINE),
+        ..roc_compiler::Options::default()
+    };
+
+    let checked = match roc_compiler::check_file(roc_file_path.to_owned(), &options) {
+        Ok(checked) => checked,
+        Err(diagnostic) => {
+            print!("{}", diagnostic.message);
+
+            return Ok(1);
+        }
+    };
+
+    // Lints are about code that works, so errors come first, and on their own.
+    if checked.has_errors() {
+        for diagnostic in checked.diagnostics.iter().filter(|d| d.is_error()) {
+            println!("{}\n", diagnostic.message);
+        }
+
+        println!("Fix the errors above before linting.");
+
+        return Ok(1);
+    }
+
+    let lints = roc_lint::lint(&checked, RenderTarget::ColorTerminal);
+
+    for lint in lints.iter() {
+        println!("{}\n", lint.message);
+    }
+
+    match lints.len() {
+        0 => println!("No lints found."),
+        1 => println!("1 lint found."),
+        n => println!("{n} lints found."),
+    }
+
+    Ok(if lints.is_empty() { 0 } else { 2 })
+}
+
+fn info_command(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+
+    let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let type_path = matches.get_one::<String>(FLAG_LAYOUTS).unwrap();
+    let target = matches
+        .get_one::<String>(FLAG_TARGET)
+        .and_then(|s| Target::from_str(s).ok())
+        .unwrap_or_default();
+    let threading = threading_from_matches(matches);
+
+    print_layout(
+        &arena,
+        roc_file_path.to_owned(),
+        type_path,
+        target,
+        roc_cache_dir_from_flags(matches, &packages_dir(matches)),
+        threading,
+    )
+}
+
+fn graph_command(matches: &ArgMatches) -> io::Result<i32> {
+    let arena = Bump::new();
+
+    let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+    let format = match matches.get_flag(FLAG_JSON) {
+        true => GraphFormat::Json,
+        false => GraphFormat::Dot,
+    };
+    let threading = threading_from_matches(matches);
+
+    print_graph(
+        &arena,
+        roc_file_path.to_owned(),
+        format,
+        matches.get_flag(FLAG_CALLS),
+        roc_cache_dir_from_flags(matches, &packages_dir(matches)),
+        threading,
+    )
+}
+
+/// Runs a command that `roc daemon` was asked to run.
+fn run_for_daemon(matches: &ArgMatches) -> io::Result<i32> {
+    let subcommands: Vec<String> = build_app()
+        .get_subcommands()
+        .map(|c| c.get_name().to_owned())
+        .collect();
+
+    match matches.subcommand() {
+        Some((CMD_BUILD, matches)) => build_command(matches, &subcommands),
+        Some((CMD_CHECK, matches)) => check_command(matches),
+        _ => user_error!("roc daemon only runs `roc check` and `roc build`"),
+    }
+}
+
 fn read_all_roc_files(
     dir: &OsString,
     roc_file_paths: &mut Vec<OsString>,
@@ -0,0 +1,194 @@
+//! `roc build --lib --watch`, which rebuilds a shared library whenever the app's code changes.
+//!
+//! Each build runs in a fresh `roc` process, since a build leaks its arena on purpose to exit
+//! quickly. Only the Roc code is rebuilt; the host is not involved in building a library. Each
+//! new library is written to a path of its own following the protocol in [`roc_std::hot_reload`],
+//! so a running host can load it without restarting.
+
+use roc_std::hot_reload::{generation_library_path, read_generation, write_generation};
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::{FLAG_OUTPUT, FLAG_WATCH};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+pub fn watch_lib(roc_file: &Path, out_path: Option<&Path>) -> ! {
+    let watched_dir = match roc_file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let (out_dir, out_name) = match out_path {
+        Some(path) if ends_with_separator(path) => (path.to_path_buf(), None),
+        Some(path) => (
+            match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+                _ => PathBuf::from("."),
+            },
+            path.file_name().map(OsStr::to_os_string),
+        ),
+        None => (watched_dir.clone(), None),
+    };
+
+    let build_args = build_args_without_watch();
+    let mut last_change = None;
+
+    println!(
+        "Watching {} for changes. Press Ctrl+C to stop.\n",
+        watched_dir.display()
+    );
+
+    loop {
+        let change = latest_change(&watched_dir);
+
+        if last_change != Some(change) {
+            last_change = Some(change);
+
+            // A failed rebuild leaves the previous library in place, so keep watching for the
+            // next change either way.
+            match rebuild(&build_args, &out_dir, out_name.as_deref()) {
+                Ok(Some((library, generation))) => {
+                    println!("Updated {} (generation {generation})\n", library.display());
+                }
+                Ok(None) => {
+                    println!("The build failed, so the previous library was kept.\n");
+                }
+                Err(error) => {
+                    eprintln!(
+                        "Could not update the library, so the previous one was kept: {error}\n"
+                    );
+                }
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Build the library in a temporary directory next to its destination, then move it to the path
+/// of its generation, which no host has loaded yet. Returns that path and the generation, or
+/// `None` if the build failed.
+fn rebuild(
+    build_args: &[OsString],
+    out_dir: &Path,
+    out_name: Option<&OsStr>,
+) -> io::Result<Option<(PathBuf, u64)>> {
+    fs::create_dir_all(out_dir)?;
+
+    let staging = tempfile::Builder::new()
+        .prefix(".roc-watch")
+        .tempdir_in(out_dir)?;
+
+    let staging_out = match out_name {
+        Some(name) => staging.path().join(name).into_os_string(),
+        None => {
+            let mut dir = staging.path().as_os_str().to_os_string();
+            dir.push(std::path::MAIN_SEPARATOR_STR);
+            dir
+        }
+    };
+
+    let status = Command::new(env::current_exe()?)
+        .args(build_args)
+        .arg(format!("--{FLAG_OUTPUT}"))
+        .arg(staging_out)
+        .status()?;
+
+    if !status.success() {
+        return Ok(None);
+    }
+
+    // The linker picks the extension of a library, so look for what it wrote.
+    let built = fs::read_dir(staging.path())?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_file() && !matches!(path.extension().and_then(OsStr::to_str), Some("o" | "obj"))
+        });
+
+    let Some(built) = built else {
+        return Ok(None);
+    };
+
+    // Carry on from the generation a previous watch left behind, so the paths stay new.
+    let library = out_dir.join(built.file_name().unwrap());
+    let generation = read_generation(&library).map_or(1, |generation| generation + 1);
+    let generation_library = generation_library_path(&library, generation);
+
+    fs::rename(&built, &generation_library)?;
+    write_generation(&library, generation)?;
+
+    Ok(Some((generation_library, generation)))
+}
+
+/// The arguments this process was started with, minus `--watch` and `--output`, which the
+/// watcher handles itself.
+fn build_args_without_watch() -> Vec<OsString> {
+    let watch = format!("--{FLAG_WATCH}");
+    let output = format!("--{FLAG_OUTPUT}");
+    let output_with_value = format!("--{FLAG_OUTPUT}=");
+
+    let mut args = Vec::new();
+    let mut iter = env::args_os().skip(1);
+
+    while let Some(arg) = iter.next() {
+        if arg == *watch {
+            continue;
+        } else if arg == *output {
+            iter.next();
+        } else if !arg.to_string_lossy().starts_with(&output_with_value) {
+            args.push(arg);
+        }
+    }
+
+    args
+}
+
+/// The number of `.roc` files under `dir`, and the most recent time one of them was modified.
+/// Counting the files notices when one is deleted. Hidden directories are skipped, which includes
+/// the one the library is built in.
+fn latest_change(dir: &Path) -> (usize, Option<SystemTime>) {
+    let mut count = 0;
+    let mut latest = None;
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(dir) = stack.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() && !is_hidden => stack.push(path),
+                Ok(file_type)
+                    if file_type.is_file() && path.extension() == Some(OsStr::new("roc")) =>
+                {
+                    count += 1;
+
+                    let modified = entry.metadata().and_then(|metadata| metadata.modified());
+                    latest = latest.max(modified.ok());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    (count, latest)
+}
+
+fn ends_with_separator(path: &Path) -> bool {
+    path.as_os_str()
+        .to_string_lossy()
+        .ends_with(std::path::is_separator)
+}
@@ -17,7 +17,9 @@ mod cli_run {
     use const_format::concatcp;
     use indoc::indoc;
     use regex::Regex;
-    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_FORMAT, CMD_RUN, CMD_TEST};
+    use roc_cli::{
+        CMD_BUILD, CMD_CHECK, CMD_DAEMON, CMD_DEV, CMD_FORMAT, CMD_IDE, CMD_RUN, CMD_TEST,
+    };
     use roc_reporting::report::strip_colors;
     use roc_test_utils::assert_multiline_str_eq;
     use serial_test::serial;
@@ -54,6 +56,7 @@ mod cli_run {
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const SPLIT_OBJECTS_FLAG: &str = concatcp!("--", roc_cli::FLAG_SPLIT_OBJECTS);
     #[allow(dead_code)]
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT);
     #[allow(dead_code)]
@@ -603,6 +606,14 @@ mod cli_run {
                 9│      expect a == 2
                                ^^^^^^
 
+                It failed in a function called by this top-level expect:
+
+                14│>  expect
+                15│>      a = makeA
+                16│>      b = 2i64
+                17│>
+                18│>      a == b
+
                 When it failed, these variables had these values:
 
                 a : Num *
@@ -615,6 +626,14 @@ mod cli_run {
                 10│      expect a == 3
                                 ^^^^^^
 
+                It failed in a function called by this top-level expect:
+
+                14│>  expect
+                15│>      a = makeA
+                16│>      b = 2i64
+                17│>
+                18│>      a == b
+
                 When it failed, these variables had these values:
 
                 a : Num *
@@ -750,6 +769,22 @@ mod cli_run {
         )
     }
 
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn quicksort_surgical_linker() {
+        // quicksortHelp is not tail recursive, so this links recursive functions surgically.
+        check_output_with_stdin(
+            &file_path_from_root("crates/cli/tests/algorithms", "quicksort.roc"),
+            &[],
+            &[LINKER_FLAG, "surgical"],
+            &[],
+            &[],
+            "[0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2]\n",
+            UseValgrind::No,
+            TestCliCommands::Run,
+        )
+    }
+
     #[test]
     #[ignore = "currently broken in basic-cli platform"]
     #[cfg_attr(windows, ignore = "missing __udivdi3 and some other symbols")]
@@ -783,6 +818,205 @@ mod cli_run {
         assert!(out.status.success());
     }
 
+    #[test]
+    #[cfg(unix)]
+    #[serial(daemon)]
+    fn check_in_daemon() {
+        use cli_utils::helpers::build_roc_bin_cached;
+        use std::process::{Command, Stdio};
+        use std::time::{Duration, Instant};
+
+        // A cache directory of its own, so the test neither uses nor disturbs a daemon that is
+        // already running.
+        let cache_dir =
+            std::env::temp_dir().join(format!("roc_daemon_test_{}", std::process::id()));
+        let cache_env = [("XDG_CACHE_HOME", cache_dir.to_str().unwrap())];
+        let socket = cache_dir.join("roc").join("packages").join("daemon.sock");
+
+        let mut daemon = Command::new(build_roc_bin_cached())
+            .arg(CMD_DAEMON)
+            .envs(cache_env)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let started = Instant::now();
+
+        while !socket.exists() {
+            assert!(
+                started.elapsed() < Duration::from_secs(120),
+                "the daemon did not start listening"
+            );
+
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        let path = file_path_from_root("crates/cli/tests/algorithms", "fibonacci.roc");
+        let out = run_roc([CMD_CHECK, path.to_str().unwrap()], &[], &cache_env);
+
+        let type_error = known_bad_file("TypeError.roc");
+        let bad_out = run_roc([CMD_CHECK, type_error.to_str().unwrap()], &[], &cache_env);
+
+        daemon.kill().unwrap();
+        let daemon_out = daemon.wait_with_output().unwrap();
+        let _ = std::fs::remove_dir_all(&cache_dir);
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert!(out.stdout.contains("0 errors and 0 warnings found"));
+        assert!(!bad_out.status.success());
+        assert!(bad_out.stdout.contains("TYPE MISMATCH"));
+
+        // Both commands ran in the daemon, not in the CLI.
+        let daemon_stderr = String::from_utf8_lossy(&daemon_out.stderr);
+        assert_eq!(
+            daemon_stderr.matches("Running `").count(),
+            2,
+            "{daemon_stderr}"
+        );
+    }
+
+    #[test]
+    fn ide_requests() {
+        use cli_utils::helpers::build_roc_bin_cached;
+        use serde_json::{json, Value};
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let fibonacci = file_path_from_root("crates/cli/tests/algorithms", "fibonacci.roc");
+        let type_error = known_bad_file("TypeError.roc");
+        let answer = fixtures_dir("packages").join("Answer.roc");
+        let answer_source = "module [answer]\n\nanswer = 42\n";
+
+        let requests = [
+            json!({ "method": "check", "params": { "path": fibonacci } }),
+            json!({ "method": "check", "params": { "path": type_error } }),
+            json!({ "method": "check", "params": { "path": answer, "source": answer_source } }),
+            // The module hasn't changed since the check above, so it isn't loaded again.
+            json!({
+                "method": "typeAt",
+                "params": { "path": answer, "source": answer_source, "line": 2, "column": 9 },
+            }),
+            json!({
+                "method": "check",
+                "params": { "path": answer, "source": "module [answer]\n\nanswer = \"42\"\n" },
+            }),
+            json!({
+                "method": "format",
+                "params": { "source": "module [a, b]\n\na =  1\n\nb =  2\n" },
+            }),
+            json!({
+                "method": "format",
+                "params": {
+                    "source": "module [a, b]\n\na =  1\n\nb =  2\n",
+                    "range": { "start": 4, "end": 5 },
+                },
+            }),
+            json!({
+                "method": "format",
+                "params": {
+                    "source": "module [a, b]\n\na =  1\n\nb =  2\n",
+                    "range": { "start": 0, "end": 2 },
+                },
+            }),
+            json!({ "method": "hover", "params": {} }),
+            json!({ "method": "shutdown" }),
+        ];
+
+        let mut ide = Command::new(build_roc_bin_cached())
+            .arg(CMD_IDE)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stdin = ide.stdin.take().unwrap();
+
+        for (id, request) in requests.iter().enumerate() {
+            let mut request = request.clone();
+            request["jsonrpc"] = json!("2.0");
+            request["id"] = json!(id);
+
+            writeln!(stdin, "{request}").unwrap();
+        }
+
+        drop(stdin);
+
+        let out = ide.wait_with_output().unwrap();
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        let stderr = String::from_utf8_lossy(&out.stderr);
+
+        assert!(out.status.success(), "{stderr}");
+
+        let responses: Vec<Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(responses.len(), requests.len(), "{stdout}");
+
+        for (id, response) in responses.iter().enumerate() {
+            assert_eq!(response["id"], json!(id), "{response}");
+        }
+
+        assert_eq!(responses[0]["result"], json!({ "problems": [] }));
+
+        let problems = responses[1]["result"]["problems"].as_array().unwrap();
+        let type_mismatch = problems
+            .iter()
+            .find(|problem| {
+                problem["message"]
+                    .as_str()
+                    .unwrap()
+                    .contains("TYPE MISMATCH")
+            })
+            .expect("the type error is reported");
+        assert_eq!(type_mismatch["severity"], json!("error"));
+
+        assert_eq!(responses[2]["result"], json!({ "problems": [] }));
+
+        assert_eq!(
+            responses[3]["result"],
+            json!({
+                "type": "Num *",
+                "range": {
+                    "start": { "line": 2, "column": 9 },
+                    "end": { "line": 2, "column": 11 },
+                },
+            })
+        );
+
+        assert_eq!(responses[4]["result"], json!({ "problems": [] }));
+
+        assert_eq!(
+            responses[5]["result"],
+            json!({ "range": { "start": 2, "end": 5 }, "text": "a = 1\n\nb = 2\n" })
+        );
+
+        // Only the change on the requested line is returned.
+        assert_eq!(
+            responses[6]["result"],
+            json!({ "range": { "start": 4, "end": 5 }, "text": "b = 2\n" })
+        );
+
+        // Nothing changes on the requested lines.
+        assert_eq!(responses[7]["result"], Value::Null);
+
+        assert_eq!(responses[8]["error"]["code"], json!(-32601));
+        assert_eq!(responses[9]["result"], Value::Null);
+
+        // The answer module was loaded for the first check and after its source changed, but
+        // `typeAt` reused the first check.
+        assert_eq!(
+            stderr
+                .matches(&format!("Checking {}", answer.display()))
+                .count(),
+            2,
+            "{stderr}"
+        );
+    }
+
     // TODO: write a new test once mono bugs are resolved in investigation
     #[test]
     #[cfg(not(debug_assertions))] // https://github.com/roc-lang/roc/issues/4806
@@ -1370,6 +1604,23 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[serial(multi_dep_thunk)]
+    #[cfg_attr(windows, ignore)]
+    fn run_multi_dep_thunk_split_objects() {
+        // Main, Dep1 and Dep2 each get an object, which the legacy linker links together.
+        check_output_with_stdin(
+            &fixture_file("multi-dep-thunk", "Main.roc"),
+            &[],
+            &[SPLIT_OBJECTS_FLAG],
+            &[],
+            &[],
+            "I am Dep2.value2\n",
+            UseValgrind::Yes,
+            TestCliCommands::Run,
+        );
+    }
+
     #[test]
     #[serial(multi_dep_thunk)]
     #[cfg_attr(windows, ignore)]
@@ -1547,6 +1798,21 @@ mod cli_run {
         // This doesn't fail, since only "Formatted.roc" and non-roc files are present in this folder
         check_format_check_as_expected(&fixtures_dir("format/formatted_directory"), true);
     }
+
+    #[test]
+    fn format_check_skips_unparseable() {
+        let dir = fixtures_dir("format/unparseable_directory");
+        let out = run_roc([CMD_FORMAT, dir.to_str().unwrap(), CHECK_FLAG], &[], &[]);
+
+        assert!(!out.status.success());
+        assert!(out.stderr.contains("DoesNotParse.roc"), "{}", out.stderr);
+        assert!(
+            out.stderr
+                .contains("Checked 2 files: 0 need formatting, 1 already formatted, 1 failed"),
+            "{}",
+            out.stderr
+        );
+    }
 }
 
 #[cfg(feature = "wasm32-cli-run")]
@@ -0,0 +1,4 @@
+app [main] { pf: "platform/main.roc" }
+
+main : Str
+main = (
@@ -929,6 +929,28 @@ fn call_spec<'a>(
                     add_loop(builder, block, state_type, init_state, loop_body)
                 }
 
+                ListWalk { xs, state } => {
+                    let list = env.symbols[xs];
+
+                    let state_type =
+                        layout_spec(env, builder, interner, interner.get_repr(*return_layout))?;
+                    let init_state = env.symbols[state];
+
+                    add_loop(
+                        builder,
+                        block,
+                        state_type,
+                        init_state,
+                        |builder, block, current| {
+                            let bag = builder.add_get_tuple_field(block, list, LIST_BAG_INDEX)?;
+
+                            let element = builder.add_bag_get(block, bag)?;
+
+                            Ok(call_function!(builder, block, [current, element]))
+                        },
+                    )
+                }
+
                 ListMap2 { xs, ys } => {
                     let list1 = env.symbols[xs];
                     let list2 = env.symbols[ys];
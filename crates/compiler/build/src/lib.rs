@@ -3,5 +3,8 @@
 // See github.com/roc-lang/roc/issues/800 for discussion of the large_enum_variant check.
 #![allow(clippy::large_enum_variant)]
 pub mod link;
+mod object_cache;
 pub mod program;
+pub mod size_report;
+mod split_objects;
 pub mod target;
@@ -0,0 +1,234 @@
+//! Reusing the object code of an earlier build when the app's LLVM IR hasn't changed.
+//!
+//! The cache works on whole objects, not on single procedures: the app is one LLVM module, which
+//! compiles to one object, so an object is keyed by a hash of the IR of the whole module before
+//! optimization, along with everything else that decides the machine code: the target, the
+//! optimization level, whether the build is freestanding, and the roc binary itself. Changing any
+//! procedure misses the cache. Objects are kept in the `objects` directory of the Roc cache, and
+//! `ROC_NO_OBJECT_CACHE` turns this off. When the directory grows past `MAX_CACHE_BYTES`, the
+//! objects that were used least recently are removed.
+//!
+//! A build that leaves the IR as it was, like one after editing comments or switching back to a
+//! branch, skips optimization and code generation entirely. With `--split-objects` (see
+//! [`crate::split_objects`]) each Roc module's object is keyed by its optimized IR instead, so
+//! changing one module only regenerates the objects whose code changed.
+
+use inkwell::module::Module;
+use roc_mono::ir::OptLevel;
+use roc_target::Target;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const VERSION: &str = include_str!("../../../../version.txt");
+
+const NO_OBJECT_CACHE_VAR: &str = "ROC_NO_OBJECT_CACHE";
+
+const OBJECTS_DIR_NAME: &str = "objects";
+
+/// How big the objects directory may get before old objects are removed.
+const MAX_CACHE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// What tells this build of roc apart from others: the version, and the path, size and
+/// modification time of the binary. A roc built from changed sources has the same version, but
+/// may generate different code for the same IR.
+fn compiler_identity() -> &'static str {
+    static IDENTITY: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+    IDENTITY.get_or_init(|| {
+        let exe = std::env::current_exe().ok();
+        let metadata = exe.as_ref().and_then(|exe| std::fs::metadata(exe).ok());
+
+        format!(
+            "{} {:?} {:?} {:?}\n",
+            VERSION.trim(),
+            exe,
+            metadata.as_ref().map(|metadata| metadata.len()),
+            metadata.and_then(|metadata| metadata.modified().ok()),
+        )
+    })
+}
+
+/// The key of the object that the module compiles to, or `None` if caching is turned off.
+pub(crate) fn key(
+    module: &Module,
+    target: Target,
+    opt_level: OptLevel,
+    freestanding: bool,
+) -> Option<String> {
+    if std::env::var_os(NO_OBJECT_CACHE_VAR).is_some() {
+        return None;
+    }
+
+    let mut hasher = blake3::Hasher::new();
+
+    hasher.update(compiler_identity().as_bytes());
+    hasher.update(format!("{target:?} {opt_level:?} {freestanding}\n").as_bytes());
+    hasher.update(module.print_to_string().to_bytes());
+
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+fn objects_dir() -> PathBuf {
+    roc_packaging::cache::roc_cache_dir().join(OBJECTS_DIR_NAME)
+}
+
+fn object_path(dir: &Path, key: &str) -> PathBuf {
+    dir.join(key).with_extension("o")
+}
+
+/// The object an earlier build stored under the key, if any.
+pub(crate) fn load(key: &str) -> Option<Vec<u8>> {
+    load_from(&objects_dir(), key)
+}
+
+fn load_from(dir: &Path, key: &str) -> Option<Vec<u8>> {
+    let path = object_path(dir, key);
+    let object = std::fs::read(&path).ok()?;
+
+    // Eviction goes by modification time, so mark the object as recently used.
+    let _ = std::fs::File::options()
+        .append(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+
+    Some(object)
+}
+
+/// Stores the object under the key. The cache is only an optimization, so this fails silently.
+pub(crate) fn store(key: &str, object: &[u8]) {
+    store_in(&objects_dir(), key, object, MAX_CACHE_BYTES)
+}
+
+fn store_in(dir: &Path, key: &str, object: &[u8], max_bytes: u64) {
+    let path = object_path(dir, key);
+
+    if std::fs::create_dir_all(dir).is_err() {
+        return;
+    }
+
+    // Written to a temporary file first, so that a build running at the same time never reads a
+    // partly written object.
+    if let Ok(mut file) = tempfile::NamedTempFile::new_in(dir) {
+        use std::io::Write;
+
+        if file.write_all(object).is_ok() {
+            let _ = file.persist(&path);
+        }
+    }
+
+    evict(dir, max_bytes);
+}
+
+/// Removes the least recently used objects until the ones left take up at most `max_bytes`.
+fn evict(dir: &Path, max_bytes: u64) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    let mut objects: Vec<(SystemTime, u64, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+
+            // Skips the temporary files of builds that are storing an object right now.
+            if path.extension() != Some("o".as_ref()) {
+                return None;
+            }
+
+            let metadata = std::fs::metadata(&path).ok()?;
+
+            Some((metadata.modified().ok()?, metadata.len(), path))
+        })
+        .collect();
+
+    let mut total: u64 = objects.iter().map(|(_, len, _)| len).sum();
+
+    if total <= max_bytes {
+        return;
+    }
+
+    objects.sort_by_key(|(modified, _, _)| *modified);
+
+    for (_, len, path) in objects {
+        if total <= max_bytes {
+            break;
+        }
+
+        if std::fs::remove_file(path).is_ok() {
+            total -= len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use inkwell::context::Context;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn module_with_function<'ctx>(context: &'ctx Context, name: &str) -> Module<'ctx> {
+        let module = context.create_module("app");
+        let function_type = context.void_type().fn_type(&[], false);
+
+        module.add_function(name, function_type, None);
+
+        module
+    }
+
+    #[test]
+    fn key_changes_with_the_ir_and_the_options() {
+        let context = Context::create();
+        let module = module_with_function(&context, "a");
+        let same = module_with_function(&context, "a");
+        let changed = module_with_function(&context, "b");
+
+        let key =
+            |module: &Module, opt_level| key(module, Target::LinuxX64, opt_level, false).unwrap();
+
+        assert_eq!(key(&module, OptLevel::Normal), key(&same, OptLevel::Normal));
+        assert_ne!(
+            key(&module, OptLevel::Normal),
+            key(&changed, OptLevel::Normal)
+        );
+        assert_ne!(
+            key(&module, OptLevel::Normal),
+            key(&module, OptLevel::Optimize)
+        );
+    }
+
+    #[test]
+    fn stored_objects_hit_and_others_miss() {
+        let dir = tempfile::tempdir().unwrap();
+
+        store_in(dir.path(), "stored", b"object", MAX_CACHE_BYTES);
+
+        assert_eq!(load_from(dir.path(), "stored"), Some(b"object".to_vec()));
+        assert_eq!(load_from(dir.path(), "missing"), None);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_objects() {
+        let dir = tempfile::tempdir().unwrap();
+        let set_modified = |key, seconds| {
+            std::fs::File::options()
+                .append(true)
+                .open(object_path(dir.path(), key))
+                .unwrap()
+                .set_modified(UNIX_EPOCH + Duration::from_secs(seconds))
+                .unwrap();
+        };
+
+        store_in(dir.path(), "a", &[0; 10], 25);
+        store_in(dir.path(), "b", &[0; 10], 25);
+        set_modified("a", 1000);
+        set_modified("b", 2000);
+
+        // Loading `a` makes it the most recently used, so storing `c` evicts `b`.
+        assert!(load_from(dir.path(), "a").is_some());
+        store_in(dir.path(), "c", &[0; 10], 25);
+
+        assert!(load_from(dir.path(), "a").is_some());
+        assert!(load_from(dir.path(), "b").is_none());
+        assert!(load_from(dir.path(), "c").is_some());
+    }
+}
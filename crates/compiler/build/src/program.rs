@@ -3,13 +3,14 @@ use crate::link::{
 };
 use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
+use roc_load::ice::{self, CompilerContext, CompilerPhase};
 use roc_load::{
-    EntryPoint, ExecutionMode, ExpectMetadata, FunctionKind, LoadConfig, LoadMonomorphizedError,
-    LoadedModule, LoadingProblem, MonomorphizedModule, Threading,
+    Defines, EntryPoint, ExecutionMode, ExpectMetadata, FunctionKind, LoadConfig,
+    LoadMonomorphizedError, LoadedModule, LoadingProblem, MonomorphizedModule, Threading,
 };
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
@@ -86,9 +87,17 @@ pub struct CodeGenOptions {
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
     pub fuzz: bool,
+    /// Don't depend on libc, see [`roc_gen_llvm::llvm::freestanding`]
+    pub freestanding: bool,
+    /// Report unfreed allocations at exit, see [`roc_gen_llvm::llvm::leak_check`]
+    pub leak_check: bool,
+    /// Generate an object per Roc module, see [`crate::split_objects`]
+    pub split_objects: bool,
 }
 
-type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
+/// The objects of the app: a single one, unless it was split per Roc module, see
+/// [`crate::split_objects`].
+type GenFromMono<'a> = (Vec<CodeObject>, CodeGenTiming, ExpectMetadata<'a>);
 
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module<'a>(
@@ -99,11 +108,15 @@ pub fn gen_from_mono_module<'a>(
     code_gen_options: CodeGenOptions,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
+    split_objects: bool,
+    stack_check: bool,
 ) -> GenFromMono<'a> {
     let path = roc_file_path;
     let debug = code_gen_options.emit_debug_info;
     let emit_llvm_ir = code_gen_options.emit_llvm_ir;
     let fuzz = code_gen_options.fuzz;
+    let freestanding = code_gen_options.freestanding;
+    let leak_check = code_gen_options.leak_check;
     let opt = code_gen_options.opt_level;
 
     match code_gen_options.backend {
@@ -133,6 +146,10 @@ pub fn gen_from_mono_module<'a>(
             debug,
             emit_llvm_ir,
             fuzz,
+            freestanding,
+            leak_check,
+            split_objects,
+            stack_check,
         ),
     }
 }
@@ -151,6 +168,10 @@ fn gen_from_mono_module_llvm<'a>(
     emit_debug_info: bool,
     emit_llvm_ir: bool,
     fuzz: bool,
+    freestanding: bool,
+    leak_check: bool,
+    split_objects: bool,
+    stack_check: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
     use inkwell::attributes::{Attribute, AttributeLoc};
@@ -217,6 +238,8 @@ fn gen_from_mono_module_llvm<'a>(
             .keys()
             .copied()
             .collect(),
+        leak_check,
+        stack_check,
     };
 
     // does not add any externs for this mode (we have a host) but cleans up some functions around
@@ -248,6 +271,8 @@ fn gen_from_mono_module_llvm<'a>(
         &loaded.glue_layouts,
     );
 
+    roc_gen_llvm::llvm::leak_check::configure(&env);
+
     // We are now finished building the LLVM IR.
     let generate_final_ir = all_code_gen_start.elapsed();
     let code_gen_object_start = Instant::now();
@@ -258,11 +283,56 @@ fn gen_from_mono_module_llvm<'a>(
         module.strip_debug_info();
     }
 
+    let gen_sanitizers = cfg!(feature = "sanitizers") && std::env::var("ROC_SANITIZERS").is_ok();
+
+    let split_objects = split_objects
+        && !(fuzz || gen_sanitizers || freestanding)
+        && matches!(
+            target.architecture(),
+            Architecture::X86_64 | Architecture::X86_32 | Architecture::Aarch64
+        );
+
+    // The IR printed with --emit-llvm-ir is the optimized one, which a cached object skips. Split
+    // objects are cached one by one instead.
+    let object_key = if fuzz || gen_sanitizers || emit_llvm_ir || split_objects {
+        None
+    } else {
+        crate::object_cache::key(module, target, opt_level, freestanding)
+    };
+
+    if let Some(object) = object_key.as_deref().and_then(crate::object_cache::load) {
+        let code_gen_object = code_gen_object_start.elapsed();
+        let total = all_code_gen_start.elapsed();
+
+        return (
+            vec![CodeObject::Vector(object)],
+            CodeGenTiming {
+                generate_final_ir,
+                code_gen_object,
+                total,
+            },
+            ExpectMetadata {
+                interns: env.interns,
+                layout_interner: loaded.layout_interner,
+                expectations: loaded.expectations,
+            },
+        );
+    }
+
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
     mpm.run_on(module);
 
+    if freestanding {
+        if let Err(missing) = roc_gen_llvm::llvm::freestanding::prepare(module) {
+            user_error!(
+                "A freestanding build can only call functions the host provides, but this program calls:\n\n    {}\n",
+                missing.join("\n    ")
+            );
+        }
+    }
+
     // Verify the module
     if let Err(errors) = env.module.verify() {
         // write the ll code to a file, so we can modify it
@@ -278,7 +348,47 @@ fn gen_from_mono_module_llvm<'a>(
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
-    let gen_sanitizers = cfg!(feature = "sanitizers") && std::env::var("ROC_SANITIZERS").is_ok();
+    if split_objects {
+        // The specializations of builtins depend on the app, so they aren't split off by the
+        // builtin module they come from.
+        let module_ids = &env.interns.module_ids;
+        let module_names: Vec<String> = module_ids
+            .available_modules()
+            .filter(|module_name| {
+                module_ids
+                    .get_id(module_name)
+                    .is_some_and(|module_id| !module_id.is_builtin())
+            })
+            .map(|module_name| module_name.to_string())
+            .collect();
+
+        if let Some(objects) =
+            crate::split_objects::write_objects(module, &module_names, target, opt_level)
+        {
+            if emit_llvm_ir {
+                eprintln!("Emitting LLVM IR to {}", &app_ll_file.display());
+                module.print_to_file(&app_ll_file).unwrap();
+            }
+
+            let code_gen_object = code_gen_object_start.elapsed();
+            let total = all_code_gen_start.elapsed();
+
+            return (
+                objects.into_iter().map(CodeObject::Vector).collect(),
+                CodeGenTiming {
+                    generate_final_ir,
+                    code_gen_object,
+                    total,
+                },
+                ExpectMetadata {
+                    interns: env.interns,
+                    layout_interner: loaded.layout_interner,
+                    expectations: loaded.expectations,
+                },
+            );
+        }
+    }
+
     let memory_buffer = if fuzz || gen_sanitizers {
         let dir = tempfile::tempdir().unwrap();
         let dir = dir.into_path();
@@ -407,11 +517,15 @@ fn gen_from_mono_module_llvm<'a>(
         }
     };
 
+    if let Some(key) = &object_key {
+        crate::object_cache::store(key, memory_buffer.as_slice());
+    }
+
     let code_gen_object = code_gen_object_start.elapsed();
     let total = all_code_gen_start.elapsed();
 
     (
-        CodeObject::MemoryBuffer(memory_buffer),
+        vec![CodeObject::MemoryBuffer(memory_buffer)],
         CodeGenTiming {
             generate_final_ir,
             code_gen_object,
@@ -525,7 +639,7 @@ fn gen_from_mono_module_dev_wasm32<'a>(
     let total = all_code_gen_start.elapsed();
 
     (
-        CodeObject::Vector(final_binary_bytes),
+        vec![CodeObject::Vector(final_binary_bytes)],
         CodeGenTiming {
             generate_final_ir,
             code_gen_object,
@@ -580,7 +694,7 @@ fn gen_from_mono_module_dev_assembly<'a>(
     let total = all_code_gen_start.elapsed();
 
     (
-        CodeObject::Vector(module_out),
+        vec![CodeObject::Vector(module_out)],
         CodeGenTiming {
             generate_final_ir,
             code_gen_object,
@@ -714,6 +828,11 @@ pub fn standard_load_config(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
     }
 }
 
@@ -933,7 +1052,26 @@ fn build_loaded_file<'a>(
         None
     };
 
-    let (roc_app_bytes, code_gen_timing, expect_metadata) = gen_from_mono_module(
+    // Only the legacy linker takes more than one object for the app.
+    let split_objects = code_gen_options.split_objects
+        && linking_strategy == LinkingStrategy::Legacy
+        && matches!(link_type, LinkType::Executable | LinkType::Dylib)
+        && target != Target::Wasm32;
+
+    // The stack check keeps its limit in a thread-local global, which the surgical linker has
+    // nowhere to put in the host.
+    let stack_check = linking_strategy != LinkingStrategy::Surgical;
+
+    let app_module = format!("{:?}", loaded.module_id);
+    let phase_context = |phase| CompilerContext {
+        phase,
+        module: app_module.clone(),
+        module_path: Some(app_module_path.clone()),
+        region: None,
+    };
+
+    let codegen_context = ice::enter(phase_context(CompilerPhase::Codegen));
+    let (roc_app_objects, code_gen_timing, expect_metadata) = gen_from_mono_module(
         arena,
         loaded,
         &app_module_path,
@@ -941,7 +1079,10 @@ fn build_loaded_file<'a>(
         code_gen_options,
         &preprocessed_host_path,
         wasm_dev_stack_bytes,
+        split_objects,
+        stack_check,
     );
+    drop(codegen_context);
 
     buf.push('\n');
     buf.push_str("    ");
@@ -958,7 +1099,7 @@ fn build_loaded_file<'a>(
     report_timing(buf, "Total", code_gen_timing.total);
 
     let compilation_end = compilation_start.elapsed();
-    let size = roc_app_bytes.len();
+    let size: usize = roc_app_objects.iter().map(|object| object.len()).sum();
 
     if emit_timings {
         println!(
@@ -982,19 +1123,24 @@ fn build_loaded_file<'a>(
 
     // Step 2: link the prebuilt platform and compiled app
     let link_start = Instant::now();
+    let link_context = ice::enter(phase_context(CompilerPhase::Link));
 
     match (linking_strategy, link_type) {
         (LinkingStrategy::Surgical, _) => {
+            debug_assert_eq!(roc_app_objects.len(), 1);
+
             roc_linker::link_preprocessed_host(
                 target,
                 &platform_main_roc,
-                &roc_app_bytes,
+                &roc_app_objects[0],
                 &output_exe_path,
             );
         }
         (LinkingStrategy::Additive, _) | (LinkingStrategy::Legacy, LinkType::None) => {
+            debug_assert_eq!(roc_app_objects.len(), 1);
+
             // Just copy the object file to the output folder.
-            std::fs::write(&output_exe_path, &*roc_app_bytes).unwrap();
+            std::fs::write(&output_exe_path, &*roc_app_objects[0]).unwrap();
         }
         (LinkingStrategy::Legacy, _) => {
             let extension = if target == Target::Wasm32 {
@@ -1005,19 +1151,32 @@ fn build_loaded_file<'a>(
             } else {
                 target.object_file_ext()
             };
-            let app_o_file = tempfile::Builder::new()
-                .prefix("roc_app")
-                .suffix(&format!(".{extension}"))
-                .tempfile()
-                .map_err(|err| todo!("TODO Gracefully handle tempfile creation error {:?}", err))?;
-            let app_o_file = app_o_file.path();
-
-            std::fs::write(app_o_file, &*roc_app_bytes).unwrap();
+            let mut app_o_files = Vec::with_capacity(roc_app_objects.len());
+
+            for object in roc_app_objects.iter() {
+                let app_o_file = tempfile::Builder::new()
+                    .prefix("roc_app")
+                    .suffix(&format!(".{extension}"))
+                    .tempfile()
+                    .map_err(|err| {
+                        BuildFileError::LoadingProblem(LoadingProblem::FileProblem {
+                            filename: std::env::temp_dir(),
+                            error: err.kind(),
+                        })
+                    })?;
+
+                std::fs::write(app_o_file.path(), &**object).unwrap();
+
+                app_o_files.push(app_o_file);
+            }
 
             let builtins_host_tempfile = roc_bitcode::host_tempfile()
                 .expect("failed to write host builtins object to tempfile");
 
-            let mut inputs = vec![app_o_file.to_str().unwrap()];
+            let mut inputs: Vec<&str> = app_o_files
+                .iter()
+                .map(|app_o_file| app_o_file.path().to_str().unwrap())
+                .collect();
 
             if !matches!(link_type, LinkType::Dylib | LinkType::None) {
                 // the host has been compiled into a .o or .obj file
@@ -1048,6 +1207,8 @@ fn build_loaded_file<'a>(
         }
     }
 
+    drop(link_context);
+
     let linking_time = link_start.elapsed();
 
     if emit_timings {
@@ -1190,6 +1351,7 @@ pub fn check_file<'a>(
     emit_timings: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
+    defines: Defines,
 ) -> Result<(Problems, Duration), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
@@ -1208,6 +1370,11 @@ pub fn check_file<'a>(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines,
     };
     let mut loaded =
         roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config)?;
@@ -1271,6 +1438,9 @@ pub fn build_str_test<'a>(
         emit_debug_info: false,
         emit_llvm_ir: false,
         fuzz: false,
+        freestanding: false,
+        leak_check: false,
+        split_objects: false,
     };
 
     let emit_timings = false;
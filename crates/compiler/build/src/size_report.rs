@@ -0,0 +1,195 @@
+//! `roc build --size-report`, which attributes the code in a built binary to the Roc modules and
+//! the specializations of each function, so that a generic function that got instantiated at
+//! many types stands out.
+//!
+//! This works from the symbol table of the binary: the backends name each specialization like
+//! `Module_function_id`, and a symbol's size comes from the symbol table where the format has
+//! one, or otherwise from the distance to the next symbol in its section. A binary that was
+//! stripped after linking has no symbols left to go by.
+
+use object::{Object, ObjectSection, ObjectSymbol, SectionKind, SymbolKind};
+use roc_collections::MutMap;
+use roc_module::symbol::Interns;
+use std::fmt;
+
+/// How many of the largest functions [SizeReport]'s [fmt::Display] lists.
+const LARGEST_FUNCTIONS: usize = 20;
+
+pub struct SizeReport {
+    /// Bytes of code from each module, largest first
+    pub modules: Vec<(String, u64)>,
+    /// Largest first
+    pub functions: Vec<FunctionSize>,
+    /// Bytes of code that didn't come from Roc, like the host and the builtins written in Zig
+    pub other: u64,
+}
+
+pub struct FunctionSize {
+    pub module: String,
+    pub function: String,
+    pub specializations: usize,
+    /// Of all the specializations together
+    pub bytes: u64,
+}
+
+impl SizeReport {
+    pub fn new(binary: &[u8], interns: &Interns) -> Result<Self, object::Error> {
+        let file = object::File::parse(binary)?;
+        let module_names: Vec<String> = interns
+            .module_ids
+            .available_modules()
+            .map(|name| name.to_string())
+            .collect();
+
+        let mut modules: MutMap<&str, u64> = MutMap::default();
+        let mut functions: MutMap<(&str, &str), FunctionSize> = MutMap::default();
+        let mut other = 0;
+
+        let symbols = code_symbols(&file);
+
+        for (name, bytes) in symbols.iter() {
+            match roc_function(name, &module_names) {
+                Some((module, function)) => {
+                    *modules.entry(module).or_default() += bytes;
+
+                    let size =
+                        functions
+                            .entry((module, function))
+                            .or_insert_with(|| FunctionSize {
+                                module: module.to_string(),
+                                function: function.to_string(),
+                                specializations: 0,
+                                bytes: 0,
+                            });
+
+                    size.specializations += 1;
+                    size.bytes += bytes;
+                }
+                None => other += bytes,
+            }
+        }
+
+        let mut modules: Vec<(String, u64)> = modules
+            .into_iter()
+            .map(|(module, bytes)| (module.to_string(), bytes))
+            .collect();
+        let mut functions: Vec<FunctionSize> = functions.into_values().collect();
+
+        modules.sort_by(|(a_name, a_bytes), (b_name, b_bytes)| {
+            b_bytes.cmp(a_bytes).then(a_name.cmp(b_name))
+        });
+        functions.sort_by(|a, b| {
+            b.bytes
+                .cmp(&a.bytes)
+                .then_with(|| (&a.module, &a.function).cmp(&(&b.module, &b.function)))
+        });
+
+        Ok(Self {
+            modules,
+            functions,
+            other,
+        })
+    }
+}
+
+impl fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let roc_bytes: u64 = self.modules.iter().map(|(_, bytes)| bytes).sum();
+
+        writeln!(
+            f,
+            "Code size by module ({roc_bytes} bytes from Roc, {} bytes in all):\n",
+            roc_bytes + self.other
+        )?;
+
+        for (module, bytes) in self.modules.iter() {
+            writeln!(f, "    {bytes:>10}  {module}")?;
+        }
+
+        writeln!(f, "    {:>10}  (not Roc code)", self.other)?;
+
+        writeln!(f, "\nLargest functions, with all their specializations:\n")?;
+
+        for size in self.functions.iter().take(LARGEST_FUNCTIONS) {
+            let plural = if size.specializations == 1 { "" } else { "s" };
+
+            writeln!(
+                f,
+                "    {:>10}  {}.{} ({} specialization{plural})",
+                size.bytes, size.module, size.function, size.specializations
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The names and sizes of the functions in the binary's code sections.
+fn code_symbols<'data>(file: &object::File<'data>) -> Vec<(&'data str, u64)> {
+    let mut by_section: MutMap<object::SectionIndex, Vec<(u64, u64, &'data str)>> =
+        MutMap::default();
+
+    for symbol in file.symbols() {
+        let section = match symbol.section_index() {
+            Some(section) if symbol.kind() == SymbolKind::Text => section,
+            _ => continue,
+        };
+
+        if let Ok(name) = symbol.name() {
+            by_section
+                .entry(section)
+                .or_default()
+                .push((symbol.address(), symbol.size(), name));
+        }
+    }
+
+    let mut sizes = Vec::new();
+
+    for (section, mut symbols) in by_section {
+        let section = match file.section_by_index(section) {
+            Ok(section) if section.kind() == SectionKind::Text => section,
+            _ => continue,
+        };
+        let section_end = section.address() + section.size();
+
+        symbols.sort_unstable();
+
+        for (index, (address, size, name)) in symbols.iter().enumerate() {
+            let size = if *size > 0 {
+                *size
+            } else {
+                // Mach-O has no symbol sizes, so a function ends where the next one starts.
+                let next = symbols[index + 1..]
+                    .iter()
+                    .map(|(address, _, _)| *address)
+                    .find(|next| next > address)
+                    .unwrap_or(section_end);
+
+                next.saturating_sub(*address)
+            };
+
+            sizes.push((*name, size));
+        }
+    }
+
+    sizes
+}
+
+/// The module and function a symbol is a specialization of, if it's named like the backends
+/// name Roc functions: `Module_function_id`, with a leading `_` on macOS.
+fn roc_function<'a>(symbol: &'a str, module_names: &[String]) -> Option<(&'a str, &'a str)> {
+    std::iter::once(symbol)
+        .chain(symbol.strip_prefix('_'))
+        .find_map(|symbol| {
+            let (module_and_function, _id) = symbol.rsplit_once('_')?;
+
+            let module = module_names.iter().find(|module| {
+                module_and_function.len() > module.len() + 1
+                    && module_and_function.starts_with(module.as_str())
+                    && module_and_function.as_bytes()[module.len()] == b'_'
+            })?;
+
+            Some(module_and_function.split_at(module.len() + 1))
+        })
+        .map(|(module, function)| (&module[..module.len() - 1], function))
+}
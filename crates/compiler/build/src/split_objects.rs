@@ -0,0 +1,298 @@
+//! Generating the app as one object per Roc module, on worker threads, with `--split-objects`.
+//!
+//! This is not per-module code generation: the app is still built and optimized as a single LLVM
+//! module, so inlining works across Roc modules as before, and only machine code generation is
+//! split up. Every worker parses its own copy of the optimized module into its own LLVM context,
+//! because contexts can't be shared between threads, and keeps just the functions of its Roc
+//! module. The functions of the other modules become declarations. Internal definitions get hidden
+//! external linkage, under a name that can't clash with the host's, so the objects can call each
+//! other once they are linked. The builtins and their specializations, the functions exposed to
+//! the host and the mutable globals go in the first object, while every object gets its own copy
+//! of the constants it uses.
+//!
+//! Each object is cached on its own (see [`crate::object_cache`]), so a rebuild after changing one
+//! module only generates machine code for the modules whose code changed.
+//!
+//! The objects are linked by the legacy linker, which takes any number of them. The surgical
+//! linker and `--no-link` take a single object, so those builds aren't split.
+
+use inkwell::attributes::AttributeLoc;
+use inkwell::context::Context;
+use inkwell::memory_buffer::MemoryBuffer;
+use inkwell::module::{Linkage, Module};
+use inkwell::passes::PassManager;
+use inkwell::targets::{FileType, RelocMode};
+use inkwell::values::{BasicValue, FunctionValue, GlobalValue};
+use inkwell::GlobalVisibility;
+use roc_error_macros::internal_error;
+use roc_mono::ir::OptLevel;
+use roc_target::Target;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::target::{self, convert_opt_level};
+
+/// Put in front of the names of internal definitions that the objects share.
+const SHARED_PREFIX: &str = "roc__shared.";
+
+/// Generates one object per Roc module of the optimized `module`, along with one for everything
+/// else. Returns `None` if the functions of fewer than two Roc modules are left, so splitting
+/// wouldn't save anything.
+pub(crate) fn write_objects(
+    module: &Module,
+    module_names: &[String],
+    target: Target,
+    opt_level: OptLevel,
+) -> Option<Vec<Vec<u8>>> {
+    // `None` stands for the first object, with the functions that belong to no Roc module.
+    let mut partitions: Vec<Option<&str>> = vec![None];
+
+    for function in module.get_functions() {
+        if function.count_basic_blocks() > 0 {
+            let name = function.get_name().to_string_lossy();
+
+            if let Some(owner) = owner(&name, module_names) {
+                if !partitions.contains(&Some(owner)) {
+                    partitions.push(Some(owner));
+                }
+            }
+        }
+    }
+
+    if partitions.len() < 3 {
+        return None;
+    }
+
+    let bitcode = module.write_bitcode_to_memory();
+    let bitcode = bitcode.as_slice();
+
+    let objects: Mutex<Vec<Option<Vec<u8>>>> = Mutex::new(vec![None; partitions.len()]);
+    let next_partition = AtomicUsize::new(0);
+
+    let thread_count = std::thread::available_parallelism()
+        .map_or(1, |count| count.get())
+        .min(partitions.len());
+
+    std::thread::scope(|scope| {
+        for _ in 0..thread_count {
+            scope.spawn(|| loop {
+                let index = next_partition.fetch_add(1, Ordering::Relaxed);
+
+                let Some(partition) = partitions.get(index) else {
+                    break;
+                };
+
+                let object = write_object(bitcode, *partition, module_names, target, opt_level);
+
+                objects.lock().unwrap()[index] = Some(object);
+            });
+        }
+    });
+
+    objects.into_inner().unwrap().into_iter().collect()
+}
+
+/// The Roc module that a function was generated for, going by its name, like `Json` for
+/// `Json_decode_1f`. Only the app's own modules are in `module_names`, so the builtins, and the
+/// functions exposed to the host, belong to none.
+fn owner<'n>(function_name: &str, module_names: &'n [String]) -> Option<&'n str> {
+    module_names
+        .iter()
+        .filter(|module_name| {
+            function_name.len() > module_name.len()
+                && function_name.starts_with(module_name.as_str())
+                && function_name.as_bytes()[module_name.len()] == b'_'
+        })
+        .max_by_key(|module_name| module_name.len())
+        .map(String::as_str)
+}
+
+fn write_object(
+    bitcode: &[u8],
+    partition: Option<&str>,
+    module_names: &[String],
+    target: Target,
+    opt_level: OptLevel,
+) -> Vec<u8> {
+    let context = Context::create();
+    let memory_buffer = MemoryBuffer::create_from_memory_range(bitcode, "app");
+
+    let module = Module::parse_bitcode_from_buffer(&memory_buffer, &context)
+        .unwrap_or_else(|err| internal_error!("Unable to split the app's LLVM module: {err:?}"));
+
+    keep_partition(&module, partition, module_names);
+
+    let object_key = crate::object_cache::key(&module, target, opt_level, false);
+
+    if let Some(object) = object_key.as_deref().and_then(crate::object_cache::load) {
+        return object;
+    }
+
+    let target_machine =
+        target::target_machine(target, convert_opt_level(opt_level), RelocMode::PIC).unwrap();
+
+    let object = target_machine
+        .write_to_memory_buffer(&module, FileType::Object)
+        .expect("Writing .o file failed")
+        .as_slice()
+        .to_vec();
+
+    if let Some(key) = &object_key {
+        crate::object_cache::store(key, &object);
+    }
+
+    object
+}
+
+/// Leaves only the definitions that go in the object of `partition`, and declarations of the
+/// rest.
+fn keep_partition(module: &Module, partition: Option<&str>, module_names: &[String]) {
+    let is_first = partition.is_none();
+
+    let functions: Vec<_> = module.get_functions().collect();
+
+    for function in functions {
+        if function.count_basic_blocks() == 0 {
+            continue;
+        }
+
+        let name = function.get_name().to_string_lossy().into_owned();
+        let global = function.as_global_value();
+
+        if owner(&name, module_names) == partition {
+            share(global);
+        } else {
+            let declaration_name = shared_name(global, &name);
+
+            // Frees up the name for the declaration.
+            global.as_pointer_value().set_name("");
+
+            let declaration = module.add_function(
+                &declaration_name,
+                function.get_type(),
+                Some(Linkage::External),
+            );
+
+            declaration.set_call_conventions(function.get_call_conventions());
+            copy_attributes(function, declaration);
+
+            replace_with_declaration(global, declaration.as_global_value(), &declaration_name);
+
+            unsafe { function.delete() };
+        }
+    }
+
+    let globals: Vec<_> = module.get_globals().collect();
+
+    for global in globals {
+        let Some(initializer) = global.get_initializer() else {
+            continue;
+        };
+
+        let name = global.get_name().to_string_lossy().into_owned();
+
+        if name.starts_with("llvm.") {
+            // Like `llvm.global_dtors`, which must only be defined once.
+            if !is_first {
+                unsafe { global.delete() };
+            }
+        } else if global.is_constant() && is_internal(global) {
+            // Every object keeps its own copy.
+        } else if is_first {
+            share(global);
+        } else {
+            let declaration_name = shared_name(global, &name);
+
+            global.as_pointer_value().set_name("");
+
+            let declaration = module.add_global(initializer.get_type(), None, &declaration_name);
+            declaration.set_thread_local(global.is_thread_local());
+
+            replace_with_declaration(global, declaration, &declaration_name);
+
+            unsafe { global.delete() };
+        }
+    }
+
+    // Drops the constants and declarations that no function of this object uses.
+    let pass_manager = PassManager::create(());
+    pass_manager.add_global_dce_pass();
+    pass_manager.run_on(module);
+}
+
+fn is_internal(global: GlobalValue) -> bool {
+    matches!(global.get_linkage(), Linkage::Internal | Linkage::Private)
+}
+
+/// The name that the objects know a definition by.
+fn shared_name(global: GlobalValue, name: &str) -> String {
+    if is_internal(global) {
+        format!("{SHARED_PREFIX}{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Lets the other objects of the app refer to an internal definition, without exporting it.
+fn share(global: GlobalValue) {
+    if is_internal(global) {
+        let name = global.get_name().to_string_lossy().into_owned();
+
+        global
+            .as_pointer_value()
+            .set_name(&shared_name(global, &name));
+        global.set_linkage(Linkage::External);
+        global.set_visibility(GlobalVisibility::Hidden);
+    }
+}
+
+/// Makes the uses of a definition use the declaration instead. The definition is deleted after.
+fn replace_with_declaration(definition: GlobalValue, declaration: GlobalValue, name: &str) {
+    declaration.set_linkage(Linkage::External);
+
+    if name.starts_with(SHARED_PREFIX) {
+        declaration.set_visibility(GlobalVisibility::Hidden);
+    }
+
+    definition
+        .as_pointer_value()
+        .replace_all_uses_with(declaration.as_pointer_value());
+}
+
+fn copy_attributes<'ctx>(from: FunctionValue<'ctx>, to: FunctionValue<'ctx>) {
+    // Attributes like `sret` and `byval` change how the function is called.
+    let locations = [AttributeLoc::Function, AttributeLoc::Return]
+        .into_iter()
+        .chain((0..from.count_params()).map(AttributeLoc::Param));
+
+    for location in locations {
+        for attribute in from.attributes(location) {
+            to.add_attribute(location, attribute);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::owner;
+
+    #[test]
+    fn owner_is_the_longest_module_name_prefix() {
+        let module_names = ["Dep".to_string(), "Dep1".to_string(), "Main".to_string()];
+
+        assert_eq!(owner("Dep1_value1_1", &module_names), Some("Dep1"));
+        assert_eq!(owner("Dep_value_1", &module_names), Some("Dep"));
+        assert_eq!(owner("Main_main_3", &module_names), Some("Main"));
+        assert_eq!(owner("Dep1", &module_names), None);
+        assert_eq!(owner("Depth_1", &module_names), None);
+    }
+
+    #[test]
+    fn builtin_specializations_have_no_owner() {
+        // The app's own modules, without the builtins.
+        let module_names = ["Main".to_string()];
+
+        assert_eq!(owner("List_map_4", &module_names), None);
+        assert_eq!(owner("roc__mainForHost_1_exposed", &module_names), None);
+    }
+}
@@ -0,0 +1,197 @@
+#!/usr/bin/env python3
+"""Regenerates the Unicode tables at the end of src/grapheme.zig.
+
+The tables are built from three files of the Unicode character database:
+
+- auxiliary/GraphemeBreakProperty.txt for the Grapheme_Cluster_Break property
+- emoji/emoji-data.txt for Extended_Pictographic
+- DerivedCoreProperties.txt for the Indic_Conjunct_Break property
+
+Usage:
+
+    ./generate-grapheme-tables.py              # downloads the files of UNICODE_VERSION
+    ./generate-grapheme-tables.py --ucd-dir D  # reads them from D, laid out like the UCD
+
+To move to a new Unicode release, bump UNICODE_VERSION, run this script, and run the Str tests.
+"""
+
+import argparse
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = "17.0.0"
+
+UCD_URL = f"https://www.unicode.org/Public/{UNICODE_VERSION}/ucd"
+
+GRAPHEME_BREAK_PROPERTY = "auxiliary/GraphemeBreakProperty.txt"
+EMOJI_DATA = "emoji/emoji-data.txt"
+DERIVED_CORE_PROPERTIES = "DerivedCoreProperties.txt"
+
+GRAPHEME_ZIG = os.path.join(os.path.dirname(os.path.abspath(__file__)), "src", "grapheme.zig")
+
+# Everything from this line to the end of grapheme.zig is generated.
+TABLES_START = "const category_ranges = [_]CategoryRange{"
+
+# Grapheme_Cluster_Break values, and the names of the matching `Category` in grapheme.zig.
+CATEGORIES = {
+    "CR": "CR",
+    "Control": "Control",
+    "Extend": "Extend",
+    "L": "L",
+    "LF": "LF",
+    "LV": "LV",
+    "LVT": "LVT",
+    "Prepend": "Prepend",
+    "Regional_Indicator": "RegionalIndicator",
+    "SpacingMark": "SpacingMark",
+    "T": "T",
+    "V": "V",
+    "ZWJ": "ZWJ",
+}
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([^#]+?)\s*(?:#.*)?$")
+
+
+def read_ucd_file(ucd_dir, path):
+    if ucd_dir is not None:
+        with open(os.path.join(ucd_dir, path), encoding="utf-8") as file:
+            return file.read()
+
+    url = f"{UCD_URL}/{path}"
+    print(f"Downloading {url}", file=sys.stderr)
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode("utf-8")
+
+
+def check_version(path, text):
+    # The header of each file names its version, either in the file name, like
+    # "# GraphemeBreakProperty-17.0.0.txt", or on a line like "# Used with Emoji Version 17.0".
+    header = "\n".join(text.splitlines()[:20])
+    major_minor = re.escape(".".join(UNICODE_VERSION.split(".")[:2]))
+
+    if f"-{UNICODE_VERSION}.txt" not in header and not re.search(
+        rf"Version:? {major_minor}\b", header
+    ):
+        sys.exit(f"{path} is not from Unicode {UNICODE_VERSION}")
+
+
+def parse(path, text):
+    """Yields (start, end, fields) for every entry of a UCD file."""
+    check_version(path, text)
+
+    for line in text.splitlines():
+        line = line.strip()
+
+        if not line or line.startswith("#"):
+            continue
+
+        match = LINE.match(line)
+
+        if match is None:
+            sys.exit(f"Unexpected line in {path}: {line}")
+
+        start = int(match.group(1), 16)
+        end = int(match.group(2) or match.group(1), 16)
+        fields = [field.strip() for field in match.group(3).split(";")]
+
+        yield start, end, fields
+
+
+def merge(ranges):
+    """Sorts the ranges and joins the adjacent ones with the same value."""
+    merged = []
+
+    for start, end, value in sorted(ranges):
+        if merged and merged[-1][2] == value and merged[-1][1] + 1 == start:
+            merged[-1] = (merged[-1][0], end, value)
+        else:
+            merged.append((start, end, value))
+
+    return merged
+
+
+def categories(grapheme_break, emoji_data, derived_core):
+    category_of = {}
+
+    for start, end, fields in parse(GRAPHEME_BREAK_PROPERTY, grapheme_break):
+        for codepoint in range(start, end + 1):
+            category_of[codepoint] = CATEGORIES[fields[0]]
+
+    # Extended_Pictographic and Indic_Conjunct_Break=Consonant only ever apply to code points
+    # whose Grapheme_Cluster_Break is Other, so they can share the category with it.
+    def add(codepoint, category):
+        if codepoint in category_of:
+            sys.exit(f"U+{codepoint:04X} is both {category_of[codepoint]} and {category}")
+
+        category_of[codepoint] = category
+
+    for start, end, fields in parse(EMOJI_DATA, emoji_data):
+        if fields == ["Extended_Pictographic"]:
+            for codepoint in range(start, end + 1):
+                add(codepoint, "ExtendedPictographic")
+
+    for start, end, fields in parse(DERIVED_CORE_PROPERTIES, derived_core):
+        if fields == ["InCB", "Consonant"]:
+            for codepoint in range(start, end + 1):
+                add(codepoint, "InCbConsonant")
+
+    return merge((codepoint, codepoint, category) for codepoint, category in category_of.items())
+
+
+def incb(derived_core, value):
+    return merge(
+        (start, end, None)
+        for start, end, fields in parse(DERIVED_CORE_PROPERTIES, derived_core)
+        if fields == ["InCB", value]
+    )
+
+
+def tables(grapheme_break, emoji_data, derived_core):
+    lines = [TABLES_START]
+
+    for start, end, category in categories(grapheme_break, emoji_data, derived_core):
+        lines.append(f"    .{{ .start = 0x{start:X}, .end = 0x{end:X}, .category = .{category} }},")
+
+    lines += ["};", "", "const incb_extend_ranges = [_]Range{"]
+
+    for start, end, _ in incb(derived_core, "Extend"):
+        lines.append(f"    .{{ .start = 0x{start:X}, .end = 0x{end:X} }},")
+
+    lines += ["};", "", "const incb_linkers = [_]u21{"]
+
+    for start, end, _ in incb(derived_core, "Linker"):
+        for codepoint in range(start, end + 1):
+            lines.append(f"    0x{codepoint:X},")
+
+    lines.append("};")
+
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument(
+        "--ucd-dir",
+        help=f"a directory with the Unicode {UNICODE_VERSION} files, instead of downloading them",
+    )
+    args = parser.parse_args()
+
+    generated = tables(
+        read_ucd_file(args.ucd_dir, GRAPHEME_BREAK_PROPERTY),
+        read_ucd_file(args.ucd_dir, EMOJI_DATA),
+        read_ucd_file(args.ucd_dir, DERIVED_CORE_PROPERTIES),
+    )
+
+    with open(GRAPHEME_ZIG, encoding="utf-8") as file:
+        source = file.read()
+
+    handwritten = source[: source.index(TABLES_START)]
+
+    with open(GRAPHEME_ZIG, "w", encoding="utf-8") as file:
+        file.write(handwritten + generated)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,2240 @@
+// Extended grapheme cluster segmentation, following the rules of
+// https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+//
+// The tables at the end of this file are generated from the Unicode 17.0.0 character database:
+// the Grapheme_Cluster_Break property, Extended_Pictographic from emoji-data.txt, and the
+// Indic_Conjunct_Break property from DerivedCoreProperties.txt. Don't edit them by hand; run
+// generate-grapheme-tables.py, which pins the Unicode version, to regenerate them.
+const std = @import("std");
+const unicode = std.unicode;
+
+const Category = enum(u8) {
+    Other,
+    CR,
+    Control,
+    Extend,
+    ExtendedPictographic,
+    // Indic_Conjunct_Break=Consonant, which otherwise has the Grapheme_Cluster_Break of Other
+    InCbConsonant,
+    L,
+    LF,
+    LV,
+    LVT,
+    Prepend,
+    RegionalIndicator,
+    SpacingMark,
+    T,
+    V,
+    ZWJ,
+};
+
+const Range = struct {
+    start: u21,
+    end: u21,
+};
+
+const CategoryRange = struct {
+    start: u21,
+    end: u21,
+    category: Category,
+};
+
+fn categoryOf(codepoint: u21) Category {
+    var low: usize = 0;
+    var high: usize = category_ranges.len;
+
+    while (low < high) {
+        const middle = low + (high - low) / 2;
+        const range = category_ranges[middle];
+
+        if (codepoint < range.start) {
+            high = middle;
+        } else if (codepoint > range.end) {
+            low = middle + 1;
+        } else {
+            return range.category;
+        }
+    }
+
+    return .Other;
+}
+
+fn isInCbExtend(codepoint: u21) bool {
+    var low: usize = 0;
+    var high: usize = incb_extend_ranges.len;
+
+    while (low < high) {
+        const middle = low + (high - low) / 2;
+        const range = incb_extend_ranges[middle];
+
+        if (codepoint < range.start) {
+            high = middle;
+        } else if (codepoint > range.end) {
+            low = middle + 1;
+        } else {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+fn isInCbLinker(codepoint: u21) bool {
+    for (incb_linkers) |linker| {
+        if (codepoint == linker) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+fn isControl(category: Category) bool {
+    return switch (category) {
+        .Control, .CR, .LF => true,
+        else => false,
+    };
+}
+
+// What the code points before a boundary tell about the rules that look further back than
+// the previous code point.
+const Breaker = struct {
+    previous: Category = .Other,
+    // An odd number of regional indicators ends at the previous code point (GB12, GB13)
+    odd_regional_indicators: bool = false,
+    // ExtendedPictographic Extend* ends at the previous code point, or that followed by a ZWJ (GB11)
+    emoji: enum { None, Pictographic, PictographicZwj } = .None,
+    // InCB=Consonant [InCB=Extend InCB=Linker]* ends at the previous code point, and whether that
+    // has a linker (GB9c)
+    conjunct: enum { None, Consonant, Linked } = .None,
+
+    // Whether there is a boundary before a code point of this category. Only called after the
+    // first code point of a string.
+    fn breaksBefore(self: Breaker, category: Category) bool {
+        const previous = self.previous;
+
+        // GB3, GB4, GB5
+        if (previous == .CR and category == .LF) {
+            return false;
+        } else if (isControl(previous) or isControl(category)) {
+            return true;
+        }
+
+        // GB6, GB7, GB8
+        switch (previous) {
+            .L => if (category == .L or category == .V or category == .LV or category == .LVT) {
+                return false;
+            },
+            .LV, .V => if (category == .V or category == .T) {
+                return false;
+            },
+            .LVT, .T => if (category == .T) {
+                return false;
+            },
+            else => {},
+        }
+
+        // GB9, GB9a, GB9b
+        if (category == .Extend or category == .ZWJ or category == .SpacingMark or previous == .Prepend) {
+            return false;
+        }
+
+        // GB9c
+        if (category == .InCbConsonant and self.conjunct == .Linked) {
+            return false;
+        }
+
+        // GB11
+        if (category == .ExtendedPictographic and self.emoji == .PictographicZwj) {
+            return false;
+        }
+
+        // GB12, GB13
+        if (previous == .RegionalIndicator and category == .RegionalIndicator and self.odd_regional_indicators) {
+            return false;
+        }
+
+        // GB999
+        return true;
+    }
+
+    fn advance(self: *Breaker, codepoint: u21, category: Category) void {
+        self.odd_regional_indicators = category == .RegionalIndicator and
+            !(self.previous == .RegionalIndicator and self.odd_regional_indicators);
+
+        self.emoji = switch (category) {
+            .ExtendedPictographic => .Pictographic,
+            .Extend => if (self.emoji == .Pictographic) .Pictographic else .None,
+            .ZWJ => if (self.emoji == .Pictographic) .PictographicZwj else .None,
+            else => .None,
+        };
+
+        if (category == .InCbConsonant) {
+            self.conjunct = .Consonant;
+        } else if (self.conjunct != .None and isInCbLinker(codepoint)) {
+            self.conjunct = .Linked;
+        } else if (!isInCbExtend(codepoint)) {
+            self.conjunct = .None;
+        }
+
+        self.previous = category;
+    }
+};
+
+// Iterates over the extended grapheme clusters of valid UTF-8.
+pub const GraphemeIterator = struct {
+    codepoints: unicode.Utf8Iterator,
+    breaker: Breaker = .{},
+
+    pub fn init(bytes: []const u8) GraphemeIterator {
+        return GraphemeIterator{ .codepoints = unicode.Utf8View.initUnchecked(bytes).iterator() };
+    }
+
+    // Returns the bytes of the next grapheme cluster.
+    pub fn next(self: *GraphemeIterator) ?[]const u8 {
+        const start = self.codepoints.i;
+
+        while (true) {
+            const end = self.codepoints.i;
+            const codepoint = self.codepoints.nextCodepoint() orelse break;
+            const category = categoryOf(codepoint);
+
+            if (end > start and self.breaker.breaksBefore(category)) {
+                self.codepoints.i = end;
+                break;
+            }
+
+            self.breaker.advance(codepoint, category);
+        }
+
+        if (self.codepoints.i == start) {
+            return null;
+        }
+
+        return self.codepoints.bytes[start..self.codepoints.i];
+    }
+};
+
+const category_ranges = [_]CategoryRange{
+    .{ .start = 0x0, .end = 0x9, .category = .Control },
+    .{ .start = 0xA, .end = 0xA, .category = .LF },
+    .{ .start = 0xB, .end = 0xC, .category = .Control },
+    .{ .start = 0xD, .end = 0xD, .category = .CR },
+    .{ .start = 0xE, .end = 0x1F, .category = .Control },
+    .{ .start = 0x7F, .end = 0x9F, .category = .Control },
+    .{ .start = 0xA9, .end = 0xA9, .category = .ExtendedPictographic },
+    .{ .start = 0xAD, .end = 0xAD, .category = .Control },
+    .{ .start = 0xAE, .end = 0xAE, .category = .ExtendedPictographic },
+    .{ .start = 0x300, .end = 0x36F, .category = .Extend },
+    .{ .start = 0x483, .end = 0x489, .category = .Extend },
+    .{ .start = 0x591, .end = 0x5BD, .category = .Extend },
+    .{ .start = 0x5BF, .end = 0x5BF, .category = .Extend },
+    .{ .start = 0x5C1, .end = 0x5C2, .category = .Extend },
+    .{ .start = 0x5C4, .end = 0x5C5, .category = .Extend },
+    .{ .start = 0x5C7, .end = 0x5C7, .category = .Extend },
+    .{ .start = 0x600, .end = 0x605, .category = .Prepend },
+    .{ .start = 0x610, .end = 0x61A, .category = .Extend },
+    .{ .start = 0x61C, .end = 0x61C, .category = .Control },
+    .{ .start = 0x64B, .end = 0x65F, .category = .Extend },
+    .{ .start = 0x670, .end = 0x670, .category = .Extend },
+    .{ .start = 0x6D6, .end = 0x6DC, .category = .Extend },
+    .{ .start = 0x6DD, .end = 0x6DD, .category = .Prepend },
+    .{ .start = 0x6DF, .end = 0x6E4, .category = .Extend },
+    .{ .start = 0x6E7, .end = 0x6E8, .category = .Extend },
+    .{ .start = 0x6EA, .end = 0x6ED, .category = .Extend },
+    .{ .start = 0x70F, .end = 0x70F, .category = .Prepend },
+    .{ .start = 0x711, .end = 0x711, .category = .Extend },
+    .{ .start = 0x730, .end = 0x74A, .category = .Extend },
+    .{ .start = 0x7A6, .end = 0x7B0, .category = .Extend },
+    .{ .start = 0x7EB, .end = 0x7F3, .category = .Extend },
+    .{ .start = 0x7FD, .end = 0x7FD, .category = .Extend },
+    .{ .start = 0x816, .end = 0x819, .category = .Extend },
+    .{ .start = 0x81B, .end = 0x823, .category = .Extend },
+    .{ .start = 0x825, .end = 0x827, .category = .Extend },
+    .{ .start = 0x829, .end = 0x82D, .category = .Extend },
+    .{ .start = 0x859, .end = 0x85B, .category = .Extend },
+    .{ .start = 0x890, .end = 0x891, .category = .Prepend },
+    .{ .start = 0x897, .end = 0x89F, .category = .Extend },
+    .{ .start = 0x8CA, .end = 0x8E1, .category = .Extend },
+    .{ .start = 0x8E2, .end = 0x8E2, .category = .Prepend },
+    .{ .start = 0x8E3, .end = 0x902, .category = .Extend },
+    .{ .start = 0x903, .end = 0x903, .category = .SpacingMark },
+    .{ .start = 0x915, .end = 0x939, .category = .InCbConsonant },
+    .{ .start = 0x93A, .end = 0x93A, .category = .Extend },
+    .{ .start = 0x93B, .end = 0x93B, .category = .SpacingMark },
+    .{ .start = 0x93C, .end = 0x93C, .category = .Extend },
+    .{ .start = 0x93E, .end = 0x940, .category = .SpacingMark },
+    .{ .start = 0x941, .end = 0x948, .category = .Extend },
+    .{ .start = 0x949, .end = 0x94C, .category = .SpacingMark },
+    .{ .start = 0x94D, .end = 0x94D, .category = .Extend },
+    .{ .start = 0x94E, .end = 0x94F, .category = .SpacingMark },
+    .{ .start = 0x951, .end = 0x957, .category = .Extend },
+    .{ .start = 0x958, .end = 0x95F, .category = .InCbConsonant },
+    .{ .start = 0x962, .end = 0x963, .category = .Extend },
+    .{ .start = 0x978, .end = 0x97F, .category = .InCbConsonant },
+    .{ .start = 0x981, .end = 0x981, .category = .Extend },
+    .{ .start = 0x982, .end = 0x983, .category = .SpacingMark },
+    .{ .start = 0x995, .end = 0x9A8, .category = .InCbConsonant },
+    .{ .start = 0x9AA, .end = 0x9B0, .category = .InCbConsonant },
+    .{ .start = 0x9B2, .end = 0x9B2, .category = .InCbConsonant },
+    .{ .start = 0x9B6, .end = 0x9B9, .category = .InCbConsonant },
+    .{ .start = 0x9BC, .end = 0x9BC, .category = .Extend },
+    .{ .start = 0x9BE, .end = 0x9BE, .category = .Extend },
+    .{ .start = 0x9BF, .end = 0x9C0, .category = .SpacingMark },
+    .{ .start = 0x9C1, .end = 0x9C4, .category = .Extend },
+    .{ .start = 0x9C7, .end = 0x9C8, .category = .SpacingMark },
+    .{ .start = 0x9CB, .end = 0x9CC, .category = .SpacingMark },
+    .{ .start = 0x9CD, .end = 0x9CD, .category = .Extend },
+    .{ .start = 0x9D7, .end = 0x9D7, .category = .Extend },
+    .{ .start = 0x9DC, .end = 0x9DD, .category = .InCbConsonant },
+    .{ .start = 0x9DF, .end = 0x9DF, .category = .InCbConsonant },
+    .{ .start = 0x9E2, .end = 0x9E3, .category = .Extend },
+    .{ .start = 0x9F0, .end = 0x9F1, .category = .InCbConsonant },
+    .{ .start = 0x9FE, .end = 0x9FE, .category = .Extend },
+    .{ .start = 0xA01, .end = 0xA02, .category = .Extend },
+    .{ .start = 0xA03, .end = 0xA03, .category = .SpacingMark },
+    .{ .start = 0xA3C, .end = 0xA3C, .category = .Extend },
+    .{ .start = 0xA3E, .end = 0xA40, .category = .SpacingMark },
+    .{ .start = 0xA41, .end = 0xA42, .category = .Extend },
+    .{ .start = 0xA47, .end = 0xA48, .category = .Extend },
+    .{ .start = 0xA4B, .end = 0xA4D, .category = .Extend },
+    .{ .start = 0xA51, .end = 0xA51, .category = .Extend },
+    .{ .start = 0xA70, .end = 0xA71, .category = .Extend },
+    .{ .start = 0xA75, .end = 0xA75, .category = .Extend },
+    .{ .start = 0xA81, .end = 0xA82, .category = .Extend },
+    .{ .start = 0xA83, .end = 0xA83, .category = .SpacingMark },
+    .{ .start = 0xA95, .end = 0xAA8, .category = .InCbConsonant },
+    .{ .start = 0xAAA, .end = 0xAB0, .category = .InCbConsonant },
+    .{ .start = 0xAB2, .end = 0xAB3, .category = .InCbConsonant },
+    .{ .start = 0xAB5, .end = 0xAB9, .category = .InCbConsonant },
+    .{ .start = 0xABC, .end = 0xABC, .category = .Extend },
+    .{ .start = 0xABE, .end = 0xAC0, .category = .SpacingMark },
+    .{ .start = 0xAC1, .end = 0xAC5, .category = .Extend },
+    .{ .start = 0xAC7, .end = 0xAC8, .category = .Extend },
+    .{ .start = 0xAC9, .end = 0xAC9, .category = .SpacingMark },
+    .{ .start = 0xACB, .end = 0xACC, .category = .SpacingMark },
+    .{ .start = 0xACD, .end = 0xACD, .category = .Extend },
+    .{ .start = 0xAE2, .end = 0xAE3, .category = .Extend },
+    .{ .start = 0xAF9, .end = 0xAF9, .category = .InCbConsonant },
+    .{ .start = 0xAFA, .end = 0xAFF, .category = .Extend },
+    .{ .start = 0xB01, .end = 0xB01, .category = .Extend },
+    .{ .start = 0xB02, .end = 0xB03, .category = .SpacingMark },
+    .{ .start = 0xB15, .end = 0xB28, .category = .InCbConsonant },
+    .{ .start = 0xB2A, .end = 0xB30, .category = .InCbConsonant },
+    .{ .start = 0xB32, .end = 0xB33, .category = .InCbConsonant },
+    .{ .start = 0xB35, .end = 0xB39, .category = .InCbConsonant },
+    .{ .start = 0xB3C, .end = 0xB3C, .category = .Extend },
+    .{ .start = 0xB3E, .end = 0xB3F, .category = .Extend },
+    .{ .start = 0xB40, .end = 0xB40, .category = .SpacingMark },
+    .{ .start = 0xB41, .end = 0xB44, .category = .Extend },
+    .{ .start = 0xB47, .end = 0xB48, .category = .SpacingMark },
+    .{ .start = 0xB4B, .end = 0xB4C, .category = .SpacingMark },
+    .{ .start = 0xB4D, .end = 0xB4D, .category = .Extend },
+    .{ .start = 0xB55, .end = 0xB57, .category = .Extend },
+    .{ .start = 0xB5C, .end = 0xB5D, .category = .InCbConsonant },
+    .{ .start = 0xB5F, .end = 0xB5F, .category = .InCbConsonant },
+    .{ .start = 0xB62, .end = 0xB63, .category = .Extend },
+    .{ .start = 0xB71, .end = 0xB71, .category = .InCbConsonant },
+    .{ .start = 0xB82, .end = 0xB82, .category = .Extend },
+    .{ .start = 0xBBE, .end = 0xBBE, .category = .Extend },
+    .{ .start = 0xBBF, .end = 0xBBF, .category = .SpacingMark },
+    .{ .start = 0xBC0, .end = 0xBC0, .category = .Extend },
+    .{ .start = 0xBC1, .end = 0xBC2, .category = .SpacingMark },
+    .{ .start = 0xBC6, .end = 0xBC8, .category = .SpacingMark },
+    .{ .start = 0xBCA, .end = 0xBCC, .category = .SpacingMark },
+    .{ .start = 0xBCD, .end = 0xBCD, .category = .Extend },
+    .{ .start = 0xBD7, .end = 0xBD7, .category = .Extend },
+    .{ .start = 0xC00, .end = 0xC00, .category = .Extend },
+    .{ .start = 0xC01, .end = 0xC03, .category = .SpacingMark },
+    .{ .start = 0xC04, .end = 0xC04, .category = .Extend },
+    .{ .start = 0xC15, .end = 0xC28, .category = .InCbConsonant },
+    .{ .start = 0xC2A, .end = 0xC39, .category = .InCbConsonant },
+    .{ .start = 0xC3C, .end = 0xC3C, .category = .Extend },
+    .{ .start = 0xC3E, .end = 0xC40, .category = .Extend },
+    .{ .start = 0xC41, .end = 0xC44, .category = .SpacingMark },
+    .{ .start = 0xC46, .end = 0xC48, .category = .Extend },
+    .{ .start = 0xC4A, .end = 0xC4D, .category = .Extend },
+    .{ .start = 0xC55, .end = 0xC56, .category = .Extend },
+    .{ .start = 0xC58, .end = 0xC5A, .category = .InCbConsonant },
+    .{ .start = 0xC62, .end = 0xC63, .category = .Extend },
+    .{ .start = 0xC81, .end = 0xC81, .category = .Extend },
+    .{ .start = 0xC82, .end = 0xC83, .category = .SpacingMark },
+    .{ .start = 0xCBC, .end = 0xCBC, .category = .Extend },
+    .{ .start = 0xCBE, .end = 0xCBE, .category = .SpacingMark },
+    .{ .start = 0xCBF, .end = 0xCC0, .category = .Extend },
+    .{ .start = 0xCC1, .end = 0xCC1, .category = .SpacingMark },
+    .{ .start = 0xCC2, .end = 0xCC2, .category = .Extend },
+    .{ .start = 0xCC3, .end = 0xCC4, .category = .SpacingMark },
+    .{ .start = 0xCC6, .end = 0xCC8, .category = .Extend },
+    .{ .start = 0xCCA, .end = 0xCCD, .category = .Extend },
+    .{ .start = 0xCD5, .end = 0xCD6, .category = .Extend },
+    .{ .start = 0xCE2, .end = 0xCE3, .category = .Extend },
+    .{ .start = 0xCF3, .end = 0xCF3, .category = .SpacingMark },
+    .{ .start = 0xD00, .end = 0xD01, .category = .Extend },
+    .{ .start = 0xD02, .end = 0xD03, .category = .SpacingMark },
+    .{ .start = 0xD15, .end = 0xD3A, .category = .InCbConsonant },
+    .{ .start = 0xD3B, .end = 0xD3C, .category = .Extend },
+    .{ .start = 0xD3E, .end = 0xD3E, .category = .Extend },
+    .{ .start = 0xD3F, .end = 0xD40, .category = .SpacingMark },
+    .{ .start = 0xD41, .end = 0xD44, .category = .Extend },
+    .{ .start = 0xD46, .end = 0xD48, .category = .SpacingMark },
+    .{ .start = 0xD4A, .end = 0xD4C, .category = .SpacingMark },
+    .{ .start = 0xD4D, .end = 0xD4D, .category = .Extend },
+    .{ .start = 0xD4E, .end = 0xD4E, .category = .Prepend },
+    .{ .start = 0xD57, .end = 0xD57, .category = .Extend },
+    .{ .start = 0xD62, .end = 0xD63, .category = .Extend },
+    .{ .start = 0xD81, .end = 0xD81, .category = .Extend },
+    .{ .start = 0xD82, .end = 0xD83, .category = .SpacingMark },
+    .{ .start = 0xDCA, .end = 0xDCA, .category = .Extend },
+    .{ .start = 0xDCF, .end = 0xDCF, .category = .Extend },
+    .{ .start = 0xDD0, .end = 0xDD1, .category = .SpacingMark },
+    .{ .start = 0xDD2, .end = 0xDD4, .category = .Extend },
+    .{ .start = 0xDD6, .end = 0xDD6, .category = .Extend },
+    .{ .start = 0xDD8, .end = 0xDDE, .category = .SpacingMark },
+    .{ .start = 0xDDF, .end = 0xDDF, .category = .Extend },
+    .{ .start = 0xDF2, .end = 0xDF3, .category = .SpacingMark },
+    .{ .start = 0xE31, .end = 0xE31, .category = .Extend },
+    .{ .start = 0xE33, .end = 0xE33, .category = .SpacingMark },
+    .{ .start = 0xE34, .end = 0xE3A, .category = .Extend },
+    .{ .start = 0xE47, .end = 0xE4E, .category = .Extend },
+    .{ .start = 0xEB1, .end = 0xEB1, .category = .Extend },
+    .{ .start = 0xEB3, .end = 0xEB3, .category = .SpacingMark },
+    .{ .start = 0xEB4, .end = 0xEBC, .category = .Extend },
+    .{ .start = 0xEC8, .end = 0xECE, .category = .Extend },
+    .{ .start = 0xF18, .end = 0xF19, .category = .Extend },
+    .{ .start = 0xF35, .end = 0xF35, .category = .Extend },
+    .{ .start = 0xF37, .end = 0xF37, .category = .Extend },
+    .{ .start = 0xF39, .end = 0xF39, .category = .Extend },
+    .{ .start = 0xF3E, .end = 0xF3F, .category = .SpacingMark },
+    .{ .start = 0xF71, .end = 0xF7E, .category = .Extend },
+    .{ .start = 0xF7F, .end = 0xF7F, .category = .SpacingMark },
+    .{ .start = 0xF80, .end = 0xF84, .category = .Extend },
+    .{ .start = 0xF86, .end = 0xF87, .category = .Extend },
+    .{ .start = 0xF8D, .end = 0xF97, .category = .Extend },
+    .{ .start = 0xF99, .end = 0xFBC, .category = .Extend },
+    .{ .start = 0xFC6, .end = 0xFC6, .category = .Extend },
+    .{ .start = 0x1000, .end = 0x102A, .category = .InCbConsonant },
+    .{ .start = 0x102D, .end = 0x1030, .category = .Extend },
+    .{ .start = 0x1031, .end = 0x1031, .category = .SpacingMark },
+    .{ .start = 0x1032, .end = 0x1037, .category = .Extend },
+    .{ .start = 0x1039, .end = 0x103A, .category = .Extend },
+    .{ .start = 0x103B, .end = 0x103C, .category = .SpacingMark },
+    .{ .start = 0x103D, .end = 0x103E, .category = .Extend },
+    .{ .start = 0x103F, .end = 0x103F, .category = .InCbConsonant },
+    .{ .start = 0x1050, .end = 0x1055, .category = .InCbConsonant },
+    .{ .start = 0x1056, .end = 0x1057, .category = .SpacingMark },
+    .{ .start = 0x1058, .end = 0x1059, .category = .Extend },
+    .{ .start = 0x105A, .end = 0x105D, .category = .InCbConsonant },
+    .{ .start = 0x105E, .end = 0x1060, .category = .Extend },
+    .{ .start = 0x1061, .end = 0x1061, .category = .InCbConsonant },
+    .{ .start = 0x1065, .end = 0x1066, .category = .InCbConsonant },
+    .{ .start = 0x106E, .end = 0x1070, .category = .InCbConsonant },
+    .{ .start = 0x1071, .end = 0x1074, .category = .Extend },
+    .{ .start = 0x1075, .end = 0x1081, .category = .InCbConsonant },
+    .{ .start = 0x1082, .end = 0x1082, .category = .Extend },
+    .{ .start = 0x1084, .end = 0x1084, .category = .SpacingMark },
+    .{ .start = 0x1085, .end = 0x1086, .category = .Extend },
+    .{ .start = 0x108D, .end = 0x108D, .category = .Extend },
+    .{ .start = 0x108E, .end = 0x108E, .category = .InCbConsonant },
+    .{ .start = 0x109D, .end = 0x109D, .category = .Extend },
+    .{ .start = 0x1100, .end = 0x115F, .category = .L },
+    .{ .start = 0x1160, .end = 0x11A7, .category = .V },
+    .{ .start = 0x11A8, .end = 0x11FF, .category = .T },
+    .{ .start = 0x135D, .end = 0x135F, .category = .Extend },
+    .{ .start = 0x1712, .end = 0x1715, .category = .Extend },
+    .{ .start = 0x1732, .end = 0x1734, .category = .Extend },
+    .{ .start = 0x1752, .end = 0x1753, .category = .Extend },
+    .{ .start = 0x1772, .end = 0x1773, .category = .Extend },
+    .{ .start = 0x1780, .end = 0x17B3, .category = .InCbConsonant },
+    .{ .start = 0x17B4, .end = 0x17B5, .category = .Extend },
+    .{ .start = 0x17B6, .end = 0x17B6, .category = .SpacingMark },
+    .{ .start = 0x17B7, .end = 0x17BD, .category = .Extend },
+    .{ .start = 0x17BE, .end = 0x17C5, .category = .SpacingMark },
+    .{ .start = 0x17C6, .end = 0x17C6, .category = .Extend },
+    .{ .start = 0x17C7, .end = 0x17C8, .category = .SpacingMark },
+    .{ .start = 0x17C9, .end = 0x17D3, .category = .Extend },
+    .{ .start = 0x17DD, .end = 0x17DD, .category = .Extend },
+    .{ .start = 0x180B, .end = 0x180D, .category = .Extend },
+    .{ .start = 0x180E, .end = 0x180E, .category = .Control },
+    .{ .start = 0x180F, .end = 0x180F, .category = .Extend },
+    .{ .start = 0x1885, .end = 0x1886, .category = .Extend },
+    .{ .start = 0x18A9, .end = 0x18A9, .category = .Extend },
+    .{ .start = 0x1920, .end = 0x1922, .category = .Extend },
+    .{ .start = 0x1923, .end = 0x1926, .category = .SpacingMark },
+    .{ .start = 0x1927, .end = 0x1928, .category = .Extend },
+    .{ .start = 0x1929, .end = 0x192B, .category = .SpacingMark },
+    .{ .start = 0x1930, .end = 0x1931, .category = .SpacingMark },
+    .{ .start = 0x1932, .end = 0x1932, .category = .Extend },
+    .{ .start = 0x1933, .end = 0x1938, .category = .SpacingMark },
+    .{ .start = 0x1939, .end = 0x193B, .category = .Extend },
+    .{ .start = 0x1A17, .end = 0x1A18, .category = .Extend },
+    .{ .start = 0x1A19, .end = 0x1A1A, .category = .SpacingMark },
+    .{ .start = 0x1A1B, .end = 0x1A1B, .category = .Extend },
+    .{ .start = 0x1A20, .end = 0x1A54, .category = .InCbConsonant },
+    .{ .start = 0x1A55, .end = 0x1A55, .category = .SpacingMark },
+    .{ .start = 0x1A56, .end = 0x1A56, .category = .Extend },
+    .{ .start = 0x1A57, .end = 0x1A57, .category = .SpacingMark },
+    .{ .start = 0x1A58, .end = 0x1A5E, .category = .Extend },
+    .{ .start = 0x1A60, .end = 0x1A60, .category = .Extend },
+    .{ .start = 0x1A62, .end = 0x1A62, .category = .Extend },
+    .{ .start = 0x1A65, .end = 0x1A6C, .category = .Extend },
+    .{ .start = 0x1A6D, .end = 0x1A72, .category = .SpacingMark },
+    .{ .start = 0x1A73, .end = 0x1A7C, .category = .Extend },
+    .{ .start = 0x1A7F, .end = 0x1A7F, .category = .Extend },
+    .{ .start = 0x1AB0, .end = 0x1ADD, .category = .Extend },
+    .{ .start = 0x1AE0, .end = 0x1AEB, .category = .Extend },
+    .{ .start = 0x1B00, .end = 0x1B03, .category = .Extend },
+    .{ .start = 0x1B04, .end = 0x1B04, .category = .SpacingMark },
+    .{ .start = 0x1B0B, .end = 0x1B0C, .category = .InCbConsonant },
+    .{ .start = 0x1B13, .end = 0x1B33, .category = .InCbConsonant },
+    .{ .start = 0x1B34, .end = 0x1B3D, .category = .Extend },
+    .{ .start = 0x1B3E, .end = 0x1B41, .category = .SpacingMark },
+    .{ .start = 0x1B42, .end = 0x1B44, .category = .Extend },
+    .{ .start = 0x1B45, .end = 0x1B4C, .category = .InCbConsonant },
+    .{ .start = 0x1B6B, .end = 0x1B73, .category = .Extend },
+    .{ .start = 0x1B80, .end = 0x1B81, .category = .Extend },
+    .{ .start = 0x1B82, .end = 0x1B82, .category = .SpacingMark },
+    .{ .start = 0x1B83, .end = 0x1BA0, .category = .InCbConsonant },
+    .{ .start = 0x1BA1, .end = 0x1BA1, .category = .SpacingMark },
+    .{ .start = 0x1BA2, .end = 0x1BA5, .category = .Extend },
+    .{ .start = 0x1BA6, .end = 0x1BA7, .category = .SpacingMark },
+    .{ .start = 0x1BA8, .end = 0x1BAD, .category = .Extend },
+    .{ .start = 0x1BAE, .end = 0x1BAF, .category = .InCbConsonant },
+    .{ .start = 0x1BBB, .end = 0x1BBD, .category = .InCbConsonant },
+    .{ .start = 0x1BE6, .end = 0x1BE6, .category = .Extend },
+    .{ .start = 0x1BE7, .end = 0x1BE7, .category = .SpacingMark },
+    .{ .start = 0x1BE8, .end = 0x1BE9, .category = .Extend },
+    .{ .start = 0x1BEA, .end = 0x1BEC, .category = .SpacingMark },
+    .{ .start = 0x1BED, .end = 0x1BED, .category = .Extend },
+    .{ .start = 0x1BEE, .end = 0x1BEE, .category = .SpacingMark },
+    .{ .start = 0x1BEF, .end = 0x1BF3, .category = .Extend },
+    .{ .start = 0x1C24, .end = 0x1C2B, .category = .SpacingMark },
+    .{ .start = 0x1C2C, .end = 0x1C33, .category = .Extend },
+    .{ .start = 0x1C34, .end = 0x1C35, .category = .SpacingMark },
+    .{ .start = 0x1C36, .end = 0x1C37, .category = .Extend },
+    .{ .start = 0x1CD0, .end = 0x1CD2, .category = .Extend },
+    .{ .start = 0x1CD4, .end = 0x1CE0, .category = .Extend },
+    .{ .start = 0x1CE1, .end = 0x1CE1, .category = .SpacingMark },
+    .{ .start = 0x1CE2, .end = 0x1CE8, .category = .Extend },
+    .{ .start = 0x1CED, .end = 0x1CED, .category = .Extend },
+    .{ .start = 0x1CF4, .end = 0x1CF4, .category = .Extend },
+    .{ .start = 0x1CF7, .end = 0x1CF7, .category = .SpacingMark },
+    .{ .start = 0x1CF8, .end = 0x1CF9, .category = .Extend },
+    .{ .start = 0x1DC0, .end = 0x1DFF, .category = .Extend },
+    .{ .start = 0x200B, .end = 0x200B, .category = .Control },
+    .{ .start = 0x200C, .end = 0x200C, .category = .Extend },
+    .{ .start = 0x200D, .end = 0x200D, .category = .ZWJ },
+    .{ .start = 0x200E, .end = 0x200F, .category = .Control },
+    .{ .start = 0x2028, .end = 0x202E, .category = .Control },
+    .{ .start = 0x203C, .end = 0x203C, .category = .ExtendedPictographic },
+    .{ .start = 0x2049, .end = 0x2049, .category = .ExtendedPictographic },
+    .{ .start = 0x2060, .end = 0x206F, .category = .Control },
+    .{ .start = 0x20D0, .end = 0x20F0, .category = .Extend },
+    .{ .start = 0x2122, .end = 0x2122, .category = .ExtendedPictographic },
+    .{ .start = 0x2139, .end = 0x2139, .category = .ExtendedPictographic },
+    .{ .start = 0x2194, .end = 0x2199, .category = .ExtendedPictographic },
+    .{ .start = 0x21A9, .end = 0x21AA, .category = .ExtendedPictographic },
+    .{ .start = 0x231A, .end = 0x231B, .category = .ExtendedPictographic },
+    .{ .start = 0x2328, .end = 0x2328, .category = .ExtendedPictographic },
+    .{ .start = 0x23CF, .end = 0x23CF, .category = .ExtendedPictographic },
+    .{ .start = 0x23E9, .end = 0x23F3, .category = .ExtendedPictographic },
+    .{ .start = 0x23F8, .end = 0x23FA, .category = .ExtendedPictographic },
+    .{ .start = 0x24C2, .end = 0x24C2, .category = .ExtendedPictographic },
+    .{ .start = 0x25AA, .end = 0x25AB, .category = .ExtendedPictographic },
+    .{ .start = 0x25B6, .end = 0x25B6, .category = .ExtendedPictographic },
+    .{ .start = 0x25C0, .end = 0x25C0, .category = .ExtendedPictographic },
+    .{ .start = 0x25FB, .end = 0x25FE, .category = .ExtendedPictographic },
+    .{ .start = 0x2600, .end = 0x2604, .category = .ExtendedPictographic },
+    .{ .start = 0x260E, .end = 0x260E, .category = .ExtendedPictographic },
+    .{ .start = 0x2611, .end = 0x2611, .category = .ExtendedPictographic },
+    .{ .start = 0x2614, .end = 0x2615, .category = .ExtendedPictographic },
+    .{ .start = 0x2618, .end = 0x2618, .category = .ExtendedPictographic },
+    .{ .start = 0x261D, .end = 0x261D, .category = .ExtendedPictographic },
+    .{ .start = 0x2620, .end = 0x2620, .category = .ExtendedPictographic },
+    .{ .start = 0x2622, .end = 0x2623, .category = .ExtendedPictographic },
+    .{ .start = 0x2626, .end = 0x2626, .category = .ExtendedPictographic },
+    .{ .start = 0x262A, .end = 0x262A, .category = .ExtendedPictographic },
+    .{ .start = 0x262E, .end = 0x262F, .category = .ExtendedPictographic },
+    .{ .start = 0x2638, .end = 0x263A, .category = .ExtendedPictographic },
+    .{ .start = 0x2640, .end = 0x2640, .category = .ExtendedPictographic },
+    .{ .start = 0x2642, .end = 0x2642, .category = .ExtendedPictographic },
+    .{ .start = 0x2648, .end = 0x2653, .category = .ExtendedPictographic },
+    .{ .start = 0x265F, .end = 0x2660, .category = .ExtendedPictographic },
+    .{ .start = 0x2663, .end = 0x2663, .category = .ExtendedPictographic },
+    .{ .start = 0x2665, .end = 0x2666, .category = .ExtendedPictographic },
+    .{ .start = 0x2668, .end = 0x2668, .category = .ExtendedPictographic },
+    .{ .start = 0x267B, .end = 0x267B, .category = .ExtendedPictographic },
+    .{ .start = 0x267E, .end = 0x267F, .category = .ExtendedPictographic },
+    .{ .start = 0x2692, .end = 0x2697, .category = .ExtendedPictographic },
+    .{ .start = 0x2699, .end = 0x2699, .category = .ExtendedPictographic },
+    .{ .start = 0x269B, .end = 0x269C, .category = .ExtendedPictographic },
+    .{ .start = 0x26A0, .end = 0x26A1, .category = .ExtendedPictographic },
+    .{ .start = 0x26A7, .end = 0x26A7, .category = .ExtendedPictographic },
+    .{ .start = 0x26AA, .end = 0x26AB, .category = .ExtendedPictographic },
+    .{ .start = 0x26B0, .end = 0x26B1, .category = .ExtendedPictographic },
+    .{ .start = 0x26BD, .end = 0x26BE, .category = .ExtendedPictographic },
+    .{ .start = 0x26C4, .end = 0x26C5, .category = .ExtendedPictographic },
+    .{ .start = 0x26C8, .end = 0x26C8, .category = .ExtendedPictographic },
+    .{ .start = 0x26CE, .end = 0x26CF, .category = .ExtendedPictographic },
+    .{ .start = 0x26D1, .end = 0x26D1, .category = .ExtendedPictographic },
+    .{ .start = 0x26D3, .end = 0x26D4, .category = .ExtendedPictographic },
+    .{ .start = 0x26E9, .end = 0x26EA, .category = .ExtendedPictographic },
+    .{ .start = 0x26F0, .end = 0x26F5, .category = .ExtendedPictographic },
+    .{ .start = 0x26F7, .end = 0x26FA, .category = .ExtendedPictographic },
+    .{ .start = 0x26FD, .end = 0x26FD, .category = .ExtendedPictographic },
+    .{ .start = 0x2702, .end = 0x2702, .category = .ExtendedPictographic },
+    .{ .start = 0x2705, .end = 0x2705, .category = .ExtendedPictographic },
+    .{ .start = 0x2708, .end = 0x270D, .category = .ExtendedPictographic },
+    .{ .start = 0x270F, .end = 0x270F, .category = .ExtendedPictographic },
+    .{ .start = 0x2712, .end = 0x2712, .category = .ExtendedPictographic },
+    .{ .start = 0x2714, .end = 0x2714, .category = .ExtendedPictographic },
+    .{ .start = 0x2716, .end = 0x2716, .category = .ExtendedPictographic },
+    .{ .start = 0x271D, .end = 0x271D, .category = .ExtendedPictographic },
+    .{ .start = 0x2721, .end = 0x2721, .category = .ExtendedPictographic },
+    .{ .start = 0x2728, .end = 0x2728, .category = .ExtendedPictographic },
+    .{ .start = 0x2733, .end = 0x2734, .category = .ExtendedPictographic },
+    .{ .start = 0x2744, .end = 0x2744, .category = .ExtendedPictographic },
+    .{ .start = 0x2747, .end = 0x2747, .category = .ExtendedPictographic },
+    .{ .start = 0x274C, .end = 0x274C, .category = .ExtendedPictographic },
+    .{ .start = 0x274E, .end = 0x274E, .category = .ExtendedPictographic },
+    .{ .start = 0x2753, .end = 0x2755, .category = .ExtendedPictographic },
+    .{ .start = 0x2757, .end = 0x2757, .category = .ExtendedPictographic },
+    .{ .start = 0x2763, .end = 0x2764, .category = .ExtendedPictographic },
+    .{ .start = 0x2795, .end = 0x2797, .category = .ExtendedPictographic },
+    .{ .start = 0x27A1, .end = 0x27A1, .category = .ExtendedPictographic },
+    .{ .start = 0x27B0, .end = 0x27B0, .category = .ExtendedPictographic },
+    .{ .start = 0x27BF, .end = 0x27BF, .category = .ExtendedPictographic },
+    .{ .start = 0x2934, .end = 0x2935, .category = .ExtendedPictographic },
+    .{ .start = 0x2B05, .end = 0x2B07, .category = .ExtendedPictographic },
+    .{ .start = 0x2B1B, .end = 0x2B1C, .category = .ExtendedPictographic },
+    .{ .start = 0x2B50, .end = 0x2B50, .category = .ExtendedPictographic },
+    .{ .start = 0x2B55, .end = 0x2B55, .category = .ExtendedPictographic },
+    .{ .start = 0x2CEF, .end = 0x2CF1, .category = .Extend },
+    .{ .start = 0x2D7F, .end = 0x2D7F, .category = .Extend },
+    .{ .start = 0x2DE0, .end = 0x2DFF, .category = .Extend },
+    .{ .start = 0x302A, .end = 0x302F, .category = .Extend },
+    .{ .start = 0x3030, .end = 0x3030, .category = .ExtendedPictographic },
+    .{ .start = 0x303D, .end = 0x303D, .category = .ExtendedPictographic },
+    .{ .start = 0x3099, .end = 0x309A, .category = .Extend },
+    .{ .start = 0x3297, .end = 0x3297, .category = .ExtendedPictographic },
+    .{ .start = 0x3299, .end = 0x3299, .category = .ExtendedPictographic },
+    .{ .start = 0xA66F, .end = 0xA672, .category = .Extend },
+    .{ .start = 0xA674, .end = 0xA67D, .category = .Extend },
+    .{ .start = 0xA69E, .end = 0xA69F, .category = .Extend },
+    .{ .start = 0xA6F0, .end = 0xA6F1, .category = .Extend },
+    .{ .start = 0xA802, .end = 0xA802, .category = .Extend },
+    .{ .start = 0xA806, .end = 0xA806, .category = .Extend },
+    .{ .start = 0xA80B, .end = 0xA80B, .category = .Extend },
+    .{ .start = 0xA823, .end = 0xA824, .category = .SpacingMark },
+    .{ .start = 0xA825, .end = 0xA826, .category = .Extend },
+    .{ .start = 0xA827, .end = 0xA827, .category = .SpacingMark },
+    .{ .start = 0xA82C, .end = 0xA82C, .category = .Extend },
+    .{ .start = 0xA880, .end = 0xA881, .category = .SpacingMark },
+    .{ .start = 0xA8B4, .end = 0xA8C3, .category = .SpacingMark },
+    .{ .start = 0xA8C4, .end = 0xA8C5, .category = .Extend },
+    .{ .start = 0xA8E0, .end = 0xA8F1, .category = .Extend },
+    .{ .start = 0xA8FF, .end = 0xA8FF, .category = .Extend },
+    .{ .start = 0xA926, .end = 0xA92D, .category = .Extend },
+    .{ .start = 0xA947, .end = 0xA951, .category = .Extend },
+    .{ .start = 0xA952, .end = 0xA952, .category = .SpacingMark },
+    .{ .start = 0xA953, .end = 0xA953, .category = .Extend },
+    .{ .start = 0xA960, .end = 0xA97C, .category = .L },
+    .{ .start = 0xA980, .end = 0xA982, .category = .Extend },
+    .{ .start = 0xA983, .end = 0xA983, .category = .SpacingMark },
+    .{ .start = 0xA989, .end = 0xA98B, .category = .InCbConsonant },
+    .{ .start = 0xA98F, .end = 0xA9B2, .category = .InCbConsonant },
+    .{ .start = 0xA9B3, .end = 0xA9B3, .category = .Extend },
+    .{ .start = 0xA9B4, .end = 0xA9B5, .category = .SpacingMark },
+    .{ .start = 0xA9B6, .end = 0xA9B9, .category = .Extend },
+    .{ .start = 0xA9BA, .end = 0xA9BB, .category = .SpacingMark },
+    .{ .start = 0xA9BC, .end = 0xA9BD, .category = .Extend },
+    .{ .start = 0xA9BE, .end = 0xA9BF, .category = .SpacingMark },
+    .{ .start = 0xA9C0, .end = 0xA9C0, .category = .Extend },
+    .{ .start = 0xA9E0, .end = 0xA9E4, .category = .InCbConsonant },
+    .{ .start = 0xA9E5, .end = 0xA9E5, .category = .Extend },
+    .{ .start = 0xA9E7, .end = 0xA9EF, .category = .InCbConsonant },
+    .{ .start = 0xA9FA, .end = 0xA9FE, .category = .InCbConsonant },
+    .{ .start = 0xAA29, .end = 0xAA2E, .category = .Extend },
+    .{ .start = 0xAA2F, .end = 0xAA30, .category = .SpacingMark },
+    .{ .start = 0xAA31, .end = 0xAA32, .category = .Extend },
+    .{ .start = 0xAA33, .end = 0xAA34, .category = .SpacingMark },
+    .{ .start = 0xAA35, .end = 0xAA36, .category = .Extend },
+    .{ .start = 0xAA43, .end = 0xAA43, .category = .Extend },
+    .{ .start = 0xAA4C, .end = 0xAA4C, .category = .Extend },
+    .{ .start = 0xAA4D, .end = 0xAA4D, .category = .SpacingMark },
+    .{ .start = 0xAA60, .end = 0xAA6F, .category = .InCbConsonant },
+    .{ .start = 0xAA71, .end = 0xAA73, .category = .InCbConsonant },
+    .{ .start = 0xAA7A, .end = 0xAA7A, .category = .InCbConsonant },
+    .{ .start = 0xAA7C, .end = 0xAA7C, .category = .Extend },
+    .{ .start = 0xAA7E, .end = 0xAA7F, .category = .InCbConsonant },
+    .{ .start = 0xAAB0, .end = 0xAAB0, .category = .Extend },
+    .{ .start = 0xAAB2, .end = 0xAAB4, .category = .Extend },
+    .{ .start = 0xAAB7, .end = 0xAAB8, .category = .Extend },
+    .{ .start = 0xAABE, .end = 0xAABF, .category = .Extend },
+    .{ .start = 0xAAC1, .end = 0xAAC1, .category = .Extend },
+    .{ .start = 0xAAE0, .end = 0xAAEA, .category = .InCbConsonant },
+    .{ .start = 0xAAEB, .end = 0xAAEB, .category = .SpacingMark },
+    .{ .start = 0xAAEC, .end = 0xAAED, .category = .Extend },
+    .{ .start = 0xAAEE, .end = 0xAAEF, .category = .SpacingMark },
+    .{ .start = 0xAAF5, .end = 0xAAF5, .category = .SpacingMark },
+    .{ .start = 0xAAF6, .end = 0xAAF6, .category = .Extend },
+    .{ .start = 0xABC0, .end = 0xABDA, .category = .InCbConsonant },
+    .{ .start = 0xABE3, .end = 0xABE4, .category = .SpacingMark },
+    .{ .start = 0xABE5, .end = 0xABE5, .category = .Extend },
+    .{ .start = 0xABE6, .end = 0xABE7, .category = .SpacingMark },
+    .{ .start = 0xABE8, .end = 0xABE8, .category = .Extend },
+    .{ .start = 0xABE9, .end = 0xABEA, .category = .SpacingMark },
+    .{ .start = 0xABEC, .end = 0xABEC, .category = .SpacingMark },
+    .{ .start = 0xABED, .end = 0xABED, .category = .Extend },
+    .{ .start = 0xAC00, .end = 0xAC00, .category = .LV },
+    .{ .start = 0xAC01, .end = 0xAC1B, .category = .LVT },
+    .{ .start = 0xAC1C, .end = 0xAC1C, .category = .LV },
+    .{ .start = 0xAC1D, .end = 0xAC37, .category = .LVT },
+    .{ .start = 0xAC38, .end = 0xAC38, .category = .LV },
+    .{ .start = 0xAC39, .end = 0xAC53, .category = .LVT },
+    .{ .start = 0xAC54, .end = 0xAC54, .category = .LV },
+    .{ .start = 0xAC55, .end = 0xAC6F, .category = .LVT },
+    .{ .start = 0xAC70, .end = 0xAC70, .category = .LV },
+    .{ .start = 0xAC71, .end = 0xAC8B, .category = .LVT },
+    .{ .start = 0xAC8C, .end = 0xAC8C, .category = .LV },
+    .{ .start = 0xAC8D, .end = 0xACA7, .category = .LVT },
+    .{ .start = 0xACA8, .end = 0xACA8, .category = .LV },
+    .{ .start = 0xACA9, .end = 0xACC3, .category = .LVT },
+    .{ .start = 0xACC4, .end = 0xACC4, .category = .LV },
+    .{ .start = 0xACC5, .end = 0xACDF, .category = .LVT },
+    .{ .start = 0xACE0, .end = 0xACE0, .category = .LV },
+    .{ .start = 0xACE1, .end = 0xACFB, .category = .LVT },
+    .{ .start = 0xACFC, .end = 0xACFC, .category = .LV },
+    .{ .start = 0xACFD, .end = 0xAD17, .category = .LVT },
+    .{ .start = 0xAD18, .end = 0xAD18, .category = .LV },
+    .{ .start = 0xAD19, .end = 0xAD33, .category = .LVT },
+    .{ .start = 0xAD34, .end = 0xAD34, .category = .LV },
+    .{ .start = 0xAD35, .end = 0xAD4F, .category = .LVT },
+    .{ .start = 0xAD50, .end = 0xAD50, .category = .LV },
+    .{ .start = 0xAD51, .end = 0xAD6B, .category = .LVT },
+    .{ .start = 0xAD6C, .end = 0xAD6C, .category = .LV },
+    .{ .start = 0xAD6D, .end = 0xAD87, .category = .LVT },
+    .{ .start = 0xAD88, .end = 0xAD88, .category = .LV },
+    .{ .start = 0xAD89, .end = 0xADA3, .category = .LVT },
+    .{ .start = 0xADA4, .end = 0xADA4, .category = .LV },
+    .{ .start = 0xADA5, .end = 0xADBF, .category = .LVT },
+    .{ .start = 0xADC0, .end = 0xADC0, .category = .LV },
+    .{ .start = 0xADC1, .end = 0xADDB, .category = .LVT },
+    .{ .start = 0xADDC, .end = 0xADDC, .category = .LV },
+    .{ .start = 0xADDD, .end = 0xADF7, .category = .LVT },
+    .{ .start = 0xADF8, .end = 0xADF8, .category = .LV },
+    .{ .start = 0xADF9, .end = 0xAE13, .category = .LVT },
+    .{ .start = 0xAE14, .end = 0xAE14, .category = .LV },
+    .{ .start = 0xAE15, .end = 0xAE2F, .category = .LVT },
+    .{ .start = 0xAE30, .end = 0xAE30, .category = .LV },
+    .{ .start = 0xAE31, .end = 0xAE4B, .category = .LVT },
+    .{ .start = 0xAE4C, .end = 0xAE4C, .category = .LV },
+    .{ .start = 0xAE4D, .end = 0xAE67, .category = .LVT },
+    .{ .start = 0xAE68, .end = 0xAE68, .category = .LV },
+    .{ .start = 0xAE69, .end = 0xAE83, .category = .LVT },
+    .{ .start = 0xAE84, .end = 0xAE84, .category = .LV },
+    .{ .start = 0xAE85, .end = 0xAE9F, .category = .LVT },
+    .{ .start = 0xAEA0, .end = 0xAEA0, .category = .LV },
+    .{ .start = 0xAEA1, .end = 0xAEBB, .category = .LVT },
+    .{ .start = 0xAEBC, .end = 0xAEBC, .category = .LV },
+    .{ .start = 0xAEBD, .end = 0xAED7, .category = .LVT },
+    .{ .start = 0xAED8, .end = 0xAED8, .category = .LV },
+    .{ .start = 0xAED9, .end = 0xAEF3, .category = .LVT },
+    .{ .start = 0xAEF4, .end = 0xAEF4, .category = .LV },
+    .{ .start = 0xAEF5, .end = 0xAF0F, .category = .LVT },
+    .{ .start = 0xAF10, .end = 0xAF10, .category = .LV },
+    .{ .start = 0xAF11, .end = 0xAF2B, .category = .LVT },
+    .{ .start = 0xAF2C, .end = 0xAF2C, .category = .LV },
+    .{ .start = 0xAF2D, .end = 0xAF47, .category = .LVT },
+    .{ .start = 0xAF48, .end = 0xAF48, .category = .LV },
+    .{ .start = 0xAF49, .end = 0xAF63, .category = .LVT },
+    .{ .start = 0xAF64, .end = 0xAF64, .category = .LV },
+    .{ .start = 0xAF65, .end = 0xAF7F, .category = .LVT },
+    .{ .start = 0xAF80, .end = 0xAF80, .category = .LV },
+    .{ .start = 0xAF81, .end = 0xAF9B, .category = .LVT },
+    .{ .start = 0xAF9C, .end = 0xAF9C, .category = .LV },
+    .{ .start = 0xAF9D, .end = 0xAFB7, .category = .LVT },
+    .{ .start = 0xAFB8, .end = 0xAFB8, .category = .LV },
+    .{ .start = 0xAFB9, .end = 0xAFD3, .category = .LVT },
+    .{ .start = 0xAFD4, .end = 0xAFD4, .category = .LV },
+    .{ .start = 0xAFD5, .end = 0xAFEF, .category = .LVT },
+    .{ .start = 0xAFF0, .end = 0xAFF0, .category = .LV },
+    .{ .start = 0xAFF1, .end = 0xB00B, .category = .LVT },
+    .{ .start = 0xB00C, .end = 0xB00C, .category = .LV },
+    .{ .start = 0xB00D, .end = 0xB027, .category = .LVT },
+    .{ .start = 0xB028, .end = 0xB028, .category = .LV },
+    .{ .start = 0xB029, .end = 0xB043, .category = .LVT },
+    .{ .start = 0xB044, .end = 0xB044, .category = .LV },
+    .{ .start = 0xB045, .end = 0xB05F, .category = .LVT },
+    .{ .start = 0xB060, .end = 0xB060, .category = .LV },
+    .{ .start = 0xB061, .end = 0xB07B, .category = .LVT },
+    .{ .start = 0xB07C, .end = 0xB07C, .category = .LV },
+    .{ .start = 0xB07D, .end = 0xB097, .category = .LVT },
+    .{ .start = 0xB098, .end = 0xB098, .category = .LV },
+    .{ .start = 0xB099, .end = 0xB0B3, .category = .LVT },
+    .{ .start = 0xB0B4, .end = 0xB0B4, .category = .LV },
+    .{ .start = 0xB0B5, .end = 0xB0CF, .category = .LVT },
+    .{ .start = 0xB0D0, .end = 0xB0D0, .category = .LV },
+    .{ .start = 0xB0D1, .end = 0xB0EB, .category = .LVT },
+    .{ .start = 0xB0EC, .end = 0xB0EC, .category = .LV },
+    .{ .start = 0xB0ED, .end = 0xB107, .category = .LVT },
+    .{ .start = 0xB108, .end = 0xB108, .category = .LV },
+    .{ .start = 0xB109, .end = 0xB123, .category = .LVT },
+    .{ .start = 0xB124, .end = 0xB124, .category = .LV },
+    .{ .start = 0xB125, .end = 0xB13F, .category = .LVT },
+    .{ .start = 0xB140, .end = 0xB140, .category = .LV },
+    .{ .start = 0xB141, .end = 0xB15B, .category = .LVT },
+    .{ .start = 0xB15C, .end = 0xB15C, .category = .LV },
+    .{ .start = 0xB15D, .end = 0xB177, .category = .LVT },
+    .{ .start = 0xB178, .end = 0xB178, .category = .LV },
+    .{ .start = 0xB179, .end = 0xB193, .category = .LVT },
+    .{ .start = 0xB194, .end = 0xB194, .category = .LV },
+    .{ .start = 0xB195, .end = 0xB1AF, .category = .LVT },
+    .{ .start = 0xB1B0, .end = 0xB1B0, .category = .LV },
+    .{ .start = 0xB1B1, .end = 0xB1CB, .category = .LVT },
+    .{ .start = 0xB1CC, .end = 0xB1CC, .category = .LV },
+    .{ .start = 0xB1CD, .end = 0xB1E7, .category = .LVT },
+    .{ .start = 0xB1E8, .end = 0xB1E8, .category = .LV },
+    .{ .start = 0xB1E9, .end = 0xB203, .category = .LVT },
+    .{ .start = 0xB204, .end = 0xB204, .category = .LV },
+    .{ .start = 0xB205, .end = 0xB21F, .category = .LVT },
+    .{ .start = 0xB220, .end = 0xB220, .category = .LV },
+    .{ .start = 0xB221, .end = 0xB23B, .category = .LVT },
+    .{ .start = 0xB23C, .end = 0xB23C, .category = .LV },
+    .{ .start = 0xB23D, .end = 0xB257, .category = .LVT },
+    .{ .start = 0xB258, .end = 0xB258, .category = .LV },
+    .{ .start = 0xB259, .end = 0xB273, .category = .LVT },
+    .{ .start = 0xB274, .end = 0xB274, .category = .LV },
+    .{ .start = 0xB275, .end = 0xB28F, .category = .LVT },
+    .{ .start = 0xB290, .end = 0xB290, .category = .LV },
+    .{ .start = 0xB291, .end = 0xB2AB, .category = .LVT },
+    .{ .start = 0xB2AC, .end = 0xB2AC, .category = .LV },
+    .{ .start = 0xB2AD, .end = 0xB2C7, .category = .LVT },
+    .{ .start = 0xB2C8, .end = 0xB2C8, .category = .LV },
+    .{ .start = 0xB2C9, .end = 0xB2E3, .category = .LVT },
+    .{ .start = 0xB2E4, .end = 0xB2E4, .category = .LV },
+    .{ .start = 0xB2E5, .end = 0xB2FF, .category = .LVT },
+    .{ .start = 0xB300, .end = 0xB300, .category = .LV },
+    .{ .start = 0xB301, .end = 0xB31B, .category = .LVT },
+    .{ .start = 0xB31C, .end = 0xB31C, .category = .LV },
+    .{ .start = 0xB31D, .end = 0xB337, .category = .LVT },
+    .{ .start = 0xB338, .end = 0xB338, .category = .LV },
+    .{ .start = 0xB339, .end = 0xB353, .category = .LVT },
+    .{ .start = 0xB354, .end = 0xB354, .category = .LV },
+    .{ .start = 0xB355, .end = 0xB36F, .category = .LVT },
+    .{ .start = 0xB370, .end = 0xB370, .category = .LV },
+    .{ .start = 0xB371, .end = 0xB38B, .category = .LVT },
+    .{ .start = 0xB38C, .end = 0xB38C, .category = .LV },
+    .{ .start = 0xB38D, .end = 0xB3A7, .category = .LVT },
+    .{ .start = 0xB3A8, .end = 0xB3A8, .category = .LV },
+    .{ .start = 0xB3A9, .end = 0xB3C3, .category = .LVT },
+    .{ .start = 0xB3C4, .end = 0xB3C4, .category = .LV },
+    .{ .start = 0xB3C5, .end = 0xB3DF, .category = .LVT },
+    .{ .start = 0xB3E0, .end = 0xB3E0, .category = .LV },
+    .{ .start = 0xB3E1, .end = 0xB3FB, .category = .LVT },
+    .{ .start = 0xB3FC, .end = 0xB3FC, .category = .LV },
+    .{ .start = 0xB3FD, .end = 0xB417, .category = .LVT },
+    .{ .start = 0xB418, .end = 0xB418, .category = .LV },
+    .{ .start = 0xB419, .end = 0xB433, .category = .LVT },
+    .{ .start = 0xB434, .end = 0xB434, .category = .LV },
+    .{ .start = 0xB435, .end = 0xB44F, .category = .LVT },
+    .{ .start = 0xB450, .end = 0xB450, .category = .LV },
+    .{ .start = 0xB451, .end = 0xB46B, .category = .LVT },
+    .{ .start = 0xB46C, .end = 0xB46C, .category = .LV },
+    .{ .start = 0xB46D, .end = 0xB487, .category = .LVT },
+    .{ .start = 0xB488, .end = 0xB488, .category = .LV },
+    .{ .start = 0xB489, .end = 0xB4A3, .category = .LVT },
+    .{ .start = 0xB4A4, .end = 0xB4A4, .category = .LV },
+    .{ .start = 0xB4A5, .end = 0xB4BF, .category = .LVT },
+    .{ .start = 0xB4C0, .end = 0xB4C0, .category = .LV },
+    .{ .start = 0xB4C1, .end = 0xB4DB, .category = .LVT },
+    .{ .start = 0xB4DC, .end = 0xB4DC, .category = .LV },
+    .{ .start = 0xB4DD, .end = 0xB4F7, .category = .LVT },
+    .{ .start = 0xB4F8, .end = 0xB4F8, .category = .LV },
+    .{ .start = 0xB4F9, .end = 0xB513, .category = .LVT },
+    .{ .start = 0xB514, .end = 0xB514, .category = .LV },
+    .{ .start = 0xB515, .end = 0xB52F, .category = .LVT },
+    .{ .start = 0xB530, .end = 0xB530, .category = .LV },
+    .{ .start = 0xB531, .end = 0xB54B, .category = .LVT },
+    .{ .start = 0xB54C, .end = 0xB54C, .category = .LV },
+    .{ .start = 0xB54D, .end = 0xB567, .category = .LVT },
+    .{ .start = 0xB568, .end = 0xB568, .category = .LV },
+    .{ .start = 0xB569, .end = 0xB583, .category = .LVT },
+    .{ .start = 0xB584, .end = 0xB584, .category = .LV },
+    .{ .start = 0xB585, .end = 0xB59F, .category = .LVT },
+    .{ .start = 0xB5A0, .end = 0xB5A0, .category = .LV },
+    .{ .start = 0xB5A1, .end = 0xB5BB, .category = .LVT },
+    .{ .start = 0xB5BC, .end = 0xB5BC, .category = .LV },
+    .{ .start = 0xB5BD, .end = 0xB5D7, .category = .LVT },
+    .{ .start = 0xB5D8, .end = 0xB5D8, .category = .LV },
+    .{ .start = 0xB5D9, .end = 0xB5F3, .category = .LVT },
+    .{ .start = 0xB5F4, .end = 0xB5F4, .category = .LV },
+    .{ .start = 0xB5F5, .end = 0xB60F, .category = .LVT },
+    .{ .start = 0xB610, .end = 0xB610, .category = .LV },
+    .{ .start = 0xB611, .end = 0xB62B, .category = .LVT },
+    .{ .start = 0xB62C, .end = 0xB62C, .category = .LV },
+    .{ .start = 0xB62D, .end = 0xB647, .category = .LVT },
+    .{ .start = 0xB648, .end = 0xB648, .category = .LV },
+    .{ .start = 0xB649, .end = 0xB663, .category = .LVT },
+    .{ .start = 0xB664, .end = 0xB664, .category = .LV },
+    .{ .start = 0xB665, .end = 0xB67F, .category = .LVT },
+    .{ .start = 0xB680, .end = 0xB680, .category = .LV },
+    .{ .start = 0xB681, .end = 0xB69B, .category = .LVT },
+    .{ .start = 0xB69C, .end = 0xB69C, .category = .LV },
+    .{ .start = 0xB69D, .end = 0xB6B7, .category = .LVT },
+    .{ .start = 0xB6B8, .end = 0xB6B8, .category = .LV },
+    .{ .start = 0xB6B9, .end = 0xB6D3, .category = .LVT },
+    .{ .start = 0xB6D4, .end = 0xB6D4, .category = .LV },
+    .{ .start = 0xB6D5, .end = 0xB6EF, .category = .LVT },
+    .{ .start = 0xB6F0, .end = 0xB6F0, .category = .LV },
+    .{ .start = 0xB6F1, .end = 0xB70B, .category = .LVT },
+    .{ .start = 0xB70C, .end = 0xB70C, .category = .LV },
+    .{ .start = 0xB70D, .end = 0xB727, .category = .LVT },
+    .{ .start = 0xB728, .end = 0xB728, .category = .LV },
+    .{ .start = 0xB729, .end = 0xB743, .category = .LVT },
+    .{ .start = 0xB744, .end = 0xB744, .category = .LV },
+    .{ .start = 0xB745, .end = 0xB75F, .category = .LVT },
+    .{ .start = 0xB760, .end = 0xB760, .category = .LV },
+    .{ .start = 0xB761, .end = 0xB77B, .category = .LVT },
+    .{ .start = 0xB77C, .end = 0xB77C, .category = .LV },
+    .{ .start = 0xB77D, .end = 0xB797, .category = .LVT },
+    .{ .start = 0xB798, .end = 0xB798, .category = .LV },
+    .{ .start = 0xB799, .end = 0xB7B3, .category = .LVT },
+    .{ .start = 0xB7B4, .end = 0xB7B4, .category = .LV },
+    .{ .start = 0xB7B5, .end = 0xB7CF, .category = .LVT },
+    .{ .start = 0xB7D0, .end = 0xB7D0, .category = .LV },
+    .{ .start = 0xB7D1, .end = 0xB7EB, .category = .LVT },
+    .{ .start = 0xB7EC, .end = 0xB7EC, .category = .LV },
+    .{ .start = 0xB7ED, .end = 0xB807, .category = .LVT },
+    .{ .start = 0xB808, .end = 0xB808, .category = .LV },
+    .{ .start = 0xB809, .end = 0xB823, .category = .LVT },
+    .{ .start = 0xB824, .end = 0xB824, .category = .LV },
+    .{ .start = 0xB825, .end = 0xB83F, .category = .LVT },
+    .{ .start = 0xB840, .end = 0xB840, .category = .LV },
+    .{ .start = 0xB841, .end = 0xB85B, .category = .LVT },
+    .{ .start = 0xB85C, .end = 0xB85C, .category = .LV },
+    .{ .start = 0xB85D, .end = 0xB877, .category = .LVT },
+    .{ .start = 0xB878, .end = 0xB878, .category = .LV },
+    .{ .start = 0xB879, .end = 0xB893, .category = .LVT },
+    .{ .start = 0xB894, .end = 0xB894, .category = .LV },
+    .{ .start = 0xB895, .end = 0xB8AF, .category = .LVT },
+    .{ .start = 0xB8B0, .end = 0xB8B0, .category = .LV },
+    .{ .start = 0xB8B1, .end = 0xB8CB, .category = .LVT },
+    .{ .start = 0xB8CC, .end = 0xB8CC, .category = .LV },
+    .{ .start = 0xB8CD, .end = 0xB8E7, .category = .LVT },
+    .{ .start = 0xB8E8, .end = 0xB8E8, .category = .LV },
+    .{ .start = 0xB8E9, .end = 0xB903, .category = .LVT },
+    .{ .start = 0xB904, .end = 0xB904, .category = .LV },
+    .{ .start = 0xB905, .end = 0xB91F, .category = .LVT },
+    .{ .start = 0xB920, .end = 0xB920, .category = .LV },
+    .{ .start = 0xB921, .end = 0xB93B, .category = .LVT },
+    .{ .start = 0xB93C, .end = 0xB93C, .category = .LV },
+    .{ .start = 0xB93D, .end = 0xB957, .category = .LVT },
+    .{ .start = 0xB958, .end = 0xB958, .category = .LV },
+    .{ .start = 0xB959, .end = 0xB973, .category = .LVT },
+    .{ .start = 0xB974, .end = 0xB974, .category = .LV },
+    .{ .start = 0xB975, .end = 0xB98F, .category = .LVT },
+    .{ .start = 0xB990, .end = 0xB990, .category = .LV },
+    .{ .start = 0xB991, .end = 0xB9AB, .category = .LVT },
+    .{ .start = 0xB9AC, .end = 0xB9AC, .category = .LV },
+    .{ .start = 0xB9AD, .end = 0xB9C7, .category = .LVT },
+    .{ .start = 0xB9C8, .end = 0xB9C8, .category = .LV },
+    .{ .start = 0xB9C9, .end = 0xB9E3, .category = .LVT },
+    .{ .start = 0xB9E4, .end = 0xB9E4, .category = .LV },
+    .{ .start = 0xB9E5, .end = 0xB9FF, .category = .LVT },
+    .{ .start = 0xBA00, .end = 0xBA00, .category = .LV },
+    .{ .start = 0xBA01, .end = 0xBA1B, .category = .LVT },
+    .{ .start = 0xBA1C, .end = 0xBA1C, .category = .LV },
+    .{ .start = 0xBA1D, .end = 0xBA37, .category = .LVT },
+    .{ .start = 0xBA38, .end = 0xBA38, .category = .LV },
+    .{ .start = 0xBA39, .end = 0xBA53, .category = .LVT },
+    .{ .start = 0xBA54, .end = 0xBA54, .category = .LV },
+    .{ .start = 0xBA55, .end = 0xBA6F, .category = .LVT },
+    .{ .start = 0xBA70, .end = 0xBA70, .category = .LV },
+    .{ .start = 0xBA71, .end = 0xBA8B, .category = .LVT },
+    .{ .start = 0xBA8C, .end = 0xBA8C, .category = .LV },
+    .{ .start = 0xBA8D, .end = 0xBAA7, .category = .LVT },
+    .{ .start = 0xBAA8, .end = 0xBAA8, .category = .LV },
+    .{ .start = 0xBAA9, .end = 0xBAC3, .category = .LVT },
+    .{ .start = 0xBAC4, .end = 0xBAC4, .category = .LV },
+    .{ .start = 0xBAC5, .end = 0xBADF, .category = .LVT },
+    .{ .start = 0xBAE0, .end = 0xBAE0, .category = .LV },
+    .{ .start = 0xBAE1, .end = 0xBAFB, .category = .LVT },
+    .{ .start = 0xBAFC, .end = 0xBAFC, .category = .LV },
+    .{ .start = 0xBAFD, .end = 0xBB17, .category = .LVT },
+    .{ .start = 0xBB18, .end = 0xBB18, .category = .LV },
+    .{ .start = 0xBB19, .end = 0xBB33, .category = .LVT },
+    .{ .start = 0xBB34, .end = 0xBB34, .category = .LV },
+    .{ .start = 0xBB35, .end = 0xBB4F, .category = .LVT },
+    .{ .start = 0xBB50, .end = 0xBB50, .category = .LV },
+    .{ .start = 0xBB51, .end = 0xBB6B, .category = .LVT },
+    .{ .start = 0xBB6C, .end = 0xBB6C, .category = .LV },
+    .{ .start = 0xBB6D, .end = 0xBB87, .category = .LVT },
+    .{ .start = 0xBB88, .end = 0xBB88, .category = .LV },
+    .{ .start = 0xBB89, .end = 0xBBA3, .category = .LVT },
+    .{ .start = 0xBBA4, .end = 0xBBA4, .category = .LV },
+    .{ .start = 0xBBA5, .end = 0xBBBF, .category = .LVT },
+    .{ .start = 0xBBC0, .end = 0xBBC0, .category = .LV },
+    .{ .start = 0xBBC1, .end = 0xBBDB, .category = .LVT },
+    .{ .start = 0xBBDC, .end = 0xBBDC, .category = .LV },
+    .{ .start = 0xBBDD, .end = 0xBBF7, .category = .LVT },
+    .{ .start = 0xBBF8, .end = 0xBBF8, .category = .LV },
+    .{ .start = 0xBBF9, .end = 0xBC13, .category = .LVT },
+    .{ .start = 0xBC14, .end = 0xBC14, .category = .LV },
+    .{ .start = 0xBC15, .end = 0xBC2F, .category = .LVT },
+    .{ .start = 0xBC30, .end = 0xBC30, .category = .LV },
+    .{ .start = 0xBC31, .end = 0xBC4B, .category = .LVT },
+    .{ .start = 0xBC4C, .end = 0xBC4C, .category = .LV },
+    .{ .start = 0xBC4D, .end = 0xBC67, .category = .LVT },
+    .{ .start = 0xBC68, .end = 0xBC68, .category = .LV },
+    .{ .start = 0xBC69, .end = 0xBC83, .category = .LVT },
+    .{ .start = 0xBC84, .end = 0xBC84, .category = .LV },
+    .{ .start = 0xBC85, .end = 0xBC9F, .category = .LVT },
+    .{ .start = 0xBCA0, .end = 0xBCA0, .category = .LV },
+    .{ .start = 0xBCA1, .end = 0xBCBB, .category = .LVT },
+    .{ .start = 0xBCBC, .end = 0xBCBC, .category = .LV },
+    .{ .start = 0xBCBD, .end = 0xBCD7, .category = .LVT },
+    .{ .start = 0xBCD8, .end = 0xBCD8, .category = .LV },
+    .{ .start = 0xBCD9, .end = 0xBCF3, .category = .LVT },
+    .{ .start = 0xBCF4, .end = 0xBCF4, .category = .LV },
+    .{ .start = 0xBCF5, .end = 0xBD0F, .category = .LVT },
+    .{ .start = 0xBD10, .end = 0xBD10, .category = .LV },
+    .{ .start = 0xBD11, .end = 0xBD2B, .category = .LVT },
+    .{ .start = 0xBD2C, .end = 0xBD2C, .category = .LV },
+    .{ .start = 0xBD2D, .end = 0xBD47, .category = .LVT },
+    .{ .start = 0xBD48, .end = 0xBD48, .category = .LV },
+    .{ .start = 0xBD49, .end = 0xBD63, .category = .LVT },
+    .{ .start = 0xBD64, .end = 0xBD64, .category = .LV },
+    .{ .start = 0xBD65, .end = 0xBD7F, .category = .LVT },
+    .{ .start = 0xBD80, .end = 0xBD80, .category = .LV },
+    .{ .start = 0xBD81, .end = 0xBD9B, .category = .LVT },
+    .{ .start = 0xBD9C, .end = 0xBD9C, .category = .LV },
+    .{ .start = 0xBD9D, .end = 0xBDB7, .category = .LVT },
+    .{ .start = 0xBDB8, .end = 0xBDB8, .category = .LV },
+    .{ .start = 0xBDB9, .end = 0xBDD3, .category = .LVT },
+    .{ .start = 0xBDD4, .end = 0xBDD4, .category = .LV },
+    .{ .start = 0xBDD5, .end = 0xBDEF, .category = .LVT },
+    .{ .start = 0xBDF0, .end = 0xBDF0, .category = .LV },
+    .{ .start = 0xBDF1, .end = 0xBE0B, .category = .LVT },
+    .{ .start = 0xBE0C, .end = 0xBE0C, .category = .LV },
+    .{ .start = 0xBE0D, .end = 0xBE27, .category = .LVT },
+    .{ .start = 0xBE28, .end = 0xBE28, .category = .LV },
+    .{ .start = 0xBE29, .end = 0xBE43, .category = .LVT },
+    .{ .start = 0xBE44, .end = 0xBE44, .category = .LV },
+    .{ .start = 0xBE45, .end = 0xBE5F, .category = .LVT },
+    .{ .start = 0xBE60, .end = 0xBE60, .category = .LV },
+    .{ .start = 0xBE61, .end = 0xBE7B, .category = .LVT },
+    .{ .start = 0xBE7C, .end = 0xBE7C, .category = .LV },
+    .{ .start = 0xBE7D, .end = 0xBE97, .category = .LVT },
+    .{ .start = 0xBE98, .end = 0xBE98, .category = .LV },
+    .{ .start = 0xBE99, .end = 0xBEB3, .category = .LVT },
+    .{ .start = 0xBEB4, .end = 0xBEB4, .category = .LV },
+    .{ .start = 0xBEB5, .end = 0xBECF, .category = .LVT },
+    .{ .start = 0xBED0, .end = 0xBED0, .category = .LV },
+    .{ .start = 0xBED1, .end = 0xBEEB, .category = .LVT },
+    .{ .start = 0xBEEC, .end = 0xBEEC, .category = .LV },
+    .{ .start = 0xBEED, .end = 0xBF07, .category = .LVT },
+    .{ .start = 0xBF08, .end = 0xBF08, .category = .LV },
+    .{ .start = 0xBF09, .end = 0xBF23, .category = .LVT },
+    .{ .start = 0xBF24, .end = 0xBF24, .category = .LV },
+    .{ .start = 0xBF25, .end = 0xBF3F, .category = .LVT },
+    .{ .start = 0xBF40, .end = 0xBF40, .category = .LV },
+    .{ .start = 0xBF41, .end = 0xBF5B, .category = .LVT },
+    .{ .start = 0xBF5C, .end = 0xBF5C, .category = .LV },
+    .{ .start = 0xBF5D, .end = 0xBF77, .category = .LVT },
+    .{ .start = 0xBF78, .end = 0xBF78, .category = .LV },
+    .{ .start = 0xBF79, .end = 0xBF93, .category = .LVT },
+    .{ .start = 0xBF94, .end = 0xBF94, .category = .LV },
+    .{ .start = 0xBF95, .end = 0xBFAF, .category = .LVT },
+    .{ .start = 0xBFB0, .end = 0xBFB0, .category = .LV },
+    .{ .start = 0xBFB1, .end = 0xBFCB, .category = .LVT },
+    .{ .start = 0xBFCC, .end = 0xBFCC, .category = .LV },
+    .{ .start = 0xBFCD, .end = 0xBFE7, .category = .LVT },
+    .{ .start = 0xBFE8, .end = 0xBFE8, .category = .LV },
+    .{ .start = 0xBFE9, .end = 0xC003, .category = .LVT },
+    .{ .start = 0xC004, .end = 0xC004, .category = .LV },
+    .{ .start = 0xC005, .end = 0xC01F, .category = .LVT },
+    .{ .start = 0xC020, .end = 0xC020, .category = .LV },
+    .{ .start = 0xC021, .end = 0xC03B, .category = .LVT },
+    .{ .start = 0xC03C, .end = 0xC03C, .category = .LV },
+    .{ .start = 0xC03D, .end = 0xC057, .category = .LVT },
+    .{ .start = 0xC058, .end = 0xC058, .category = .LV },
+    .{ .start = 0xC059, .end = 0xC073, .category = .LVT },
+    .{ .start = 0xC074, .end = 0xC074, .category = .LV },
+    .{ .start = 0xC075, .end = 0xC08F, .category = .LVT },
+    .{ .start = 0xC090, .end = 0xC090, .category = .LV },
+    .{ .start = 0xC091, .end = 0xC0AB, .category = .LVT },
+    .{ .start = 0xC0AC, .end = 0xC0AC, .category = .LV },
+    .{ .start = 0xC0AD, .end = 0xC0C7, .category = .LVT },
+    .{ .start = 0xC0C8, .end = 0xC0C8, .category = .LV },
+    .{ .start = 0xC0C9, .end = 0xC0E3, .category = .LVT },
+    .{ .start = 0xC0E4, .end = 0xC0E4, .category = .LV },
+    .{ .start = 0xC0E5, .end = 0xC0FF, .category = .LVT },
+    .{ .start = 0xC100, .end = 0xC100, .category = .LV },
+    .{ .start = 0xC101, .end = 0xC11B, .category = .LVT },
+    .{ .start = 0xC11C, .end = 0xC11C, .category = .LV },
+    .{ .start = 0xC11D, .end = 0xC137, .category = .LVT },
+    .{ .start = 0xC138, .end = 0xC138, .category = .LV },
+    .{ .start = 0xC139, .end = 0xC153, .category = .LVT },
+    .{ .start = 0xC154, .end = 0xC154, .category = .LV },
+    .{ .start = 0xC155, .end = 0xC16F, .category = .LVT },
+    .{ .start = 0xC170, .end = 0xC170, .category = .LV },
+    .{ .start = 0xC171, .end = 0xC18B, .category = .LVT },
+    .{ .start = 0xC18C, .end = 0xC18C, .category = .LV },
+    .{ .start = 0xC18D, .end = 0xC1A7, .category = .LVT },
+    .{ .start = 0xC1A8, .end = 0xC1A8, .category = .LV },
+    .{ .start = 0xC1A9, .end = 0xC1C3, .category = .LVT },
+    .{ .start = 0xC1C4, .end = 0xC1C4, .category = .LV },
+    .{ .start = 0xC1C5, .end = 0xC1DF, .category = .LVT },
+    .{ .start = 0xC1E0, .end = 0xC1E0, .category = .LV },
+    .{ .start = 0xC1E1, .end = 0xC1FB, .category = .LVT },
+    .{ .start = 0xC1FC, .end = 0xC1FC, .category = .LV },
+    .{ .start = 0xC1FD, .end = 0xC217, .category = .LVT },
+    .{ .start = 0xC218, .end = 0xC218, .category = .LV },
+    .{ .start = 0xC219, .end = 0xC233, .category = .LVT },
+    .{ .start = 0xC234, .end = 0xC234, .category = .LV },
+    .{ .start = 0xC235, .end = 0xC24F, .category = .LVT },
+    .{ .start = 0xC250, .end = 0xC250, .category = .LV },
+    .{ .start = 0xC251, .end = 0xC26B, .category = .LVT },
+    .{ .start = 0xC26C, .end = 0xC26C, .category = .LV },
+    .{ .start = 0xC26D, .end = 0xC287, .category = .LVT },
+    .{ .start = 0xC288, .end = 0xC288, .category = .LV },
+    .{ .start = 0xC289, .end = 0xC2A3, .category = .LVT },
+    .{ .start = 0xC2A4, .end = 0xC2A4, .category = .LV },
+    .{ .start = 0xC2A5, .end = 0xC2BF, .category = .LVT },
+    .{ .start = 0xC2C0, .end = 0xC2C0, .category = .LV },
+    .{ .start = 0xC2C1, .end = 0xC2DB, .category = .LVT },
+    .{ .start = 0xC2DC, .end = 0xC2DC, .category = .LV },
+    .{ .start = 0xC2DD, .end = 0xC2F7, .category = .LVT },
+    .{ .start = 0xC2F8, .end = 0xC2F8, .category = .LV },
+    .{ .start = 0xC2F9, .end = 0xC313, .category = .LVT },
+    .{ .start = 0xC314, .end = 0xC314, .category = .LV },
+    .{ .start = 0xC315, .end = 0xC32F, .category = .LVT },
+    .{ .start = 0xC330, .end = 0xC330, .category = .LV },
+    .{ .start = 0xC331, .end = 0xC34B, .category = .LVT },
+    .{ .start = 0xC34C, .end = 0xC34C, .category = .LV },
+    .{ .start = 0xC34D, .end = 0xC367, .category = .LVT },
+    .{ .start = 0xC368, .end = 0xC368, .category = .LV },
+    .{ .start = 0xC369, .end = 0xC383, .category = .LVT },
+    .{ .start = 0xC384, .end = 0xC384, .category = .LV },
+    .{ .start = 0xC385, .end = 0xC39F, .category = .LVT },
+    .{ .start = 0xC3A0, .end = 0xC3A0, .category = .LV },
+    .{ .start = 0xC3A1, .end = 0xC3BB, .category = .LVT },
+    .{ .start = 0xC3BC, .end = 0xC3BC, .category = .LV },
+    .{ .start = 0xC3BD, .end = 0xC3D7, .category = .LVT },
+    .{ .start = 0xC3D8, .end = 0xC3D8, .category = .LV },
+    .{ .start = 0xC3D9, .end = 0xC3F3, .category = .LVT },
+    .{ .start = 0xC3F4, .end = 0xC3F4, .category = .LV },
+    .{ .start = 0xC3F5, .end = 0xC40F, .category = .LVT },
+    .{ .start = 0xC410, .end = 0xC410, .category = .LV },
+    .{ .start = 0xC411, .end = 0xC42B, .category = .LVT },
+    .{ .start = 0xC42C, .end = 0xC42C, .category = .LV },
+    .{ .start = 0xC42D, .end = 0xC447, .category = .LVT },
+    .{ .start = 0xC448, .end = 0xC448, .category = .LV },
+    .{ .start = 0xC449, .end = 0xC463, .category = .LVT },
+    .{ .start = 0xC464, .end = 0xC464, .category = .LV },
+    .{ .start = 0xC465, .end = 0xC47F, .category = .LVT },
+    .{ .start = 0xC480, .end = 0xC480, .category = .LV },
+    .{ .start = 0xC481, .end = 0xC49B, .category = .LVT },
+    .{ .start = 0xC49C, .end = 0xC49C, .category = .LV },
+    .{ .start = 0xC49D, .end = 0xC4B7, .category = .LVT },
+    .{ .start = 0xC4B8, .end = 0xC4B8, .category = .LV },
+    .{ .start = 0xC4B9, .end = 0xC4D3, .category = .LVT },
+    .{ .start = 0xC4D4, .end = 0xC4D4, .category = .LV },
+    .{ .start = 0xC4D5, .end = 0xC4EF, .category = .LVT },
+    .{ .start = 0xC4F0, .end = 0xC4F0, .category = .LV },
+    .{ .start = 0xC4F1, .end = 0xC50B, .category = .LVT },
+    .{ .start = 0xC50C, .end = 0xC50C, .category = .LV },
+    .{ .start = 0xC50D, .end = 0xC527, .category = .LVT },
+    .{ .start = 0xC528, .end = 0xC528, .category = .LV },
+    .{ .start = 0xC529, .end = 0xC543, .category = .LVT },
+    .{ .start = 0xC544, .end = 0xC544, .category = .LV },
+    .{ .start = 0xC545, .end = 0xC55F, .category = .LVT },
+    .{ .start = 0xC560, .end = 0xC560, .category = .LV },
+    .{ .start = 0xC561, .end = 0xC57B, .category = .LVT },
+    .{ .start = 0xC57C, .end = 0xC57C, .category = .LV },
+    .{ .start = 0xC57D, .end = 0xC597, .category = .LVT },
+    .{ .start = 0xC598, .end = 0xC598, .category = .LV },
+    .{ .start = 0xC599, .end = 0xC5B3, .category = .LVT },
+    .{ .start = 0xC5B4, .end = 0xC5B4, .category = .LV },
+    .{ .start = 0xC5B5, .end = 0xC5CF, .category = .LVT },
+    .{ .start = 0xC5D0, .end = 0xC5D0, .category = .LV },
+    .{ .start = 0xC5D1, .end = 0xC5EB, .category = .LVT },
+    .{ .start = 0xC5EC, .end = 0xC5EC, .category = .LV },
+    .{ .start = 0xC5ED, .end = 0xC607, .category = .LVT },
+    .{ .start = 0xC608, .end = 0xC608, .category = .LV },
+    .{ .start = 0xC609, .end = 0xC623, .category = .LVT },
+    .{ .start = 0xC624, .end = 0xC624, .category = .LV },
+    .{ .start = 0xC625, .end = 0xC63F, .category = .LVT },
+    .{ .start = 0xC640, .end = 0xC640, .category = .LV },
+    .{ .start = 0xC641, .end = 0xC65B, .category = .LVT },
+    .{ .start = 0xC65C, .end = 0xC65C, .category = .LV },
+    .{ .start = 0xC65D, .end = 0xC677, .category = .LVT },
+    .{ .start = 0xC678, .end = 0xC678, .category = .LV },
+    .{ .start = 0xC679, .end = 0xC693, .category = .LVT },
+    .{ .start = 0xC694, .end = 0xC694, .category = .LV },
+    .{ .start = 0xC695, .end = 0xC6AF, .category = .LVT },
+    .{ .start = 0xC6B0, .end = 0xC6B0, .category = .LV },
+    .{ .start = 0xC6B1, .end = 0xC6CB, .category = .LVT },
+    .{ .start = 0xC6CC, .end = 0xC6CC, .category = .LV },
+    .{ .start = 0xC6CD, .end = 0xC6E7, .category = .LVT },
+    .{ .start = 0xC6E8, .end = 0xC6E8, .category = .LV },
+    .{ .start = 0xC6E9, .end = 0xC703, .category = .LVT },
+    .{ .start = 0xC704, .end = 0xC704, .category = .LV },
+    .{ .start = 0xC705, .end = 0xC71F, .category = .LVT },
+    .{ .start = 0xC720, .end = 0xC720, .category = .LV },
+    .{ .start = 0xC721, .end = 0xC73B, .category = .LVT },
+    .{ .start = 0xC73C, .end = 0xC73C, .category = .LV },
+    .{ .start = 0xC73D, .end = 0xC757, .category = .LVT },
+    .{ .start = 0xC758, .end = 0xC758, .category = .LV },
+    .{ .start = 0xC759, .end = 0xC773, .category = .LVT },
+    .{ .start = 0xC774, .end = 0xC774, .category = .LV },
+    .{ .start = 0xC775, .end = 0xC78F, .category = .LVT },
+    .{ .start = 0xC790, .end = 0xC790, .category = .LV },
+    .{ .start = 0xC791, .end = 0xC7AB, .category = .LVT },
+    .{ .start = 0xC7AC, .end = 0xC7AC, .category = .LV },
+    .{ .start = 0xC7AD, .end = 0xC7C7, .category = .LVT },
+    .{ .start = 0xC7C8, .end = 0xC7C8, .category = .LV },
+    .{ .start = 0xC7C9, .end = 0xC7E3, .category = .LVT },
+    .{ .start = 0xC7E4, .end = 0xC7E4, .category = .LV },
+    .{ .start = 0xC7E5, .end = 0xC7FF, .category = .LVT },
+    .{ .start = 0xC800, .end = 0xC800, .category = .LV },
+    .{ .start = 0xC801, .end = 0xC81B, .category = .LVT },
+    .{ .start = 0xC81C, .end = 0xC81C, .category = .LV },
+    .{ .start = 0xC81D, .end = 0xC837, .category = .LVT },
+    .{ .start = 0xC838, .end = 0xC838, .category = .LV },
+    .{ .start = 0xC839, .end = 0xC853, .category = .LVT },
+    .{ .start = 0xC854, .end = 0xC854, .category = .LV },
+    .{ .start = 0xC855, .end = 0xC86F, .category = .LVT },
+    .{ .start = 0xC870, .end = 0xC870, .category = .LV },
+    .{ .start = 0xC871, .end = 0xC88B, .category = .LVT },
+    .{ .start = 0xC88C, .end = 0xC88C, .category = .LV },
+    .{ .start = 0xC88D, .end = 0xC8A7, .category = .LVT },
+    .{ .start = 0xC8A8, .end = 0xC8A8, .category = .LV },
+    .{ .start = 0xC8A9, .end = 0xC8C3, .category = .LVT },
+    .{ .start = 0xC8C4, .end = 0xC8C4, .category = .LV },
+    .{ .start = 0xC8C5, .end = 0xC8DF, .category = .LVT },
+    .{ .start = 0xC8E0, .end = 0xC8E0, .category = .LV },
+    .{ .start = 0xC8E1, .end = 0xC8FB, .category = .LVT },
+    .{ .start = 0xC8FC, .end = 0xC8FC, .category = .LV },
+    .{ .start = 0xC8FD, .end = 0xC917, .category = .LVT },
+    .{ .start = 0xC918, .end = 0xC918, .category = .LV },
+    .{ .start = 0xC919, .end = 0xC933, .category = .LVT },
+    .{ .start = 0xC934, .end = 0xC934, .category = .LV },
+    .{ .start = 0xC935, .end = 0xC94F, .category = .LVT },
+    .{ .start = 0xC950, .end = 0xC950, .category = .LV },
+    .{ .start = 0xC951, .end = 0xC96B, .category = .LVT },
+    .{ .start = 0xC96C, .end = 0xC96C, .category = .LV },
+    .{ .start = 0xC96D, .end = 0xC987, .category = .LVT },
+    .{ .start = 0xC988, .end = 0xC988, .category = .LV },
+    .{ .start = 0xC989, .end = 0xC9A3, .category = .LVT },
+    .{ .start = 0xC9A4, .end = 0xC9A4, .category = .LV },
+    .{ .start = 0xC9A5, .end = 0xC9BF, .category = .LVT },
+    .{ .start = 0xC9C0, .end = 0xC9C0, .category = .LV },
+    .{ .start = 0xC9C1, .end = 0xC9DB, .category = .LVT },
+    .{ .start = 0xC9DC, .end = 0xC9DC, .category = .LV },
+    .{ .start = 0xC9DD, .end = 0xC9F7, .category = .LVT },
+    .{ .start = 0xC9F8, .end = 0xC9F8, .category = .LV },
+    .{ .start = 0xC9F9, .end = 0xCA13, .category = .LVT },
+    .{ .start = 0xCA14, .end = 0xCA14, .category = .LV },
+    .{ .start = 0xCA15, .end = 0xCA2F, .category = .LVT },
+    .{ .start = 0xCA30, .end = 0xCA30, .category = .LV },
+    .{ .start = 0xCA31, .end = 0xCA4B, .category = .LVT },
+    .{ .start = 0xCA4C, .end = 0xCA4C, .category = .LV },
+    .{ .start = 0xCA4D, .end = 0xCA67, .category = .LVT },
+    .{ .start = 0xCA68, .end = 0xCA68, .category = .LV },
+    .{ .start = 0xCA69, .end = 0xCA83, .category = .LVT },
+    .{ .start = 0xCA84, .end = 0xCA84, .category = .LV },
+    .{ .start = 0xCA85, .end = 0xCA9F, .category = .LVT },
+    .{ .start = 0xCAA0, .end = 0xCAA0, .category = .LV },
+    .{ .start = 0xCAA1, .end = 0xCABB, .category = .LVT },
+    .{ .start = 0xCABC, .end = 0xCABC, .category = .LV },
+    .{ .start = 0xCABD, .end = 0xCAD7, .category = .LVT },
+    .{ .start = 0xCAD8, .end = 0xCAD8, .category = .LV },
+    .{ .start = 0xCAD9, .end = 0xCAF3, .category = .LVT },
+    .{ .start = 0xCAF4, .end = 0xCAF4, .category = .LV },
+    .{ .start = 0xCAF5, .end = 0xCB0F, .category = .LVT },
+    .{ .start = 0xCB10, .end = 0xCB10, .category = .LV },
+    .{ .start = 0xCB11, .end = 0xCB2B, .category = .LVT },
+    .{ .start = 0xCB2C, .end = 0xCB2C, .category = .LV },
+    .{ .start = 0xCB2D, .end = 0xCB47, .category = .LVT },
+    .{ .start = 0xCB48, .end = 0xCB48, .category = .LV },
+    .{ .start = 0xCB49, .end = 0xCB63, .category = .LVT },
+    .{ .start = 0xCB64, .end = 0xCB64, .category = .LV },
+    .{ .start = 0xCB65, .end = 0xCB7F, .category = .LVT },
+    .{ .start = 0xCB80, .end = 0xCB80, .category = .LV },
+    .{ .start = 0xCB81, .end = 0xCB9B, .category = .LVT },
+    .{ .start = 0xCB9C, .end = 0xCB9C, .category = .LV },
+    .{ .start = 0xCB9D, .end = 0xCBB7, .category = .LVT },
+    .{ .start = 0xCBB8, .end = 0xCBB8, .category = .LV },
+    .{ .start = 0xCBB9, .end = 0xCBD3, .category = .LVT },
+    .{ .start = 0xCBD4, .end = 0xCBD4, .category = .LV },
+    .{ .start = 0xCBD5, .end = 0xCBEF, .category = .LVT },
+    .{ .start = 0xCBF0, .end = 0xCBF0, .category = .LV },
+    .{ .start = 0xCBF1, .end = 0xCC0B, .category = .LVT },
+    .{ .start = 0xCC0C, .end = 0xCC0C, .category = .LV },
+    .{ .start = 0xCC0D, .end = 0xCC27, .category = .LVT },
+    .{ .start = 0xCC28, .end = 0xCC28, .category = .LV },
+    .{ .start = 0xCC29, .end = 0xCC43, .category = .LVT },
+    .{ .start = 0xCC44, .end = 0xCC44, .category = .LV },
+    .{ .start = 0xCC45, .end = 0xCC5F, .category = .LVT },
+    .{ .start = 0xCC60, .end = 0xCC60, .category = .LV },
+    .{ .start = 0xCC61, .end = 0xCC7B, .category = .LVT },
+    .{ .start = 0xCC7C, .end = 0xCC7C, .category = .LV },
+    .{ .start = 0xCC7D, .end = 0xCC97, .category = .LVT },
+    .{ .start = 0xCC98, .end = 0xCC98, .category = .LV },
+    .{ .start = 0xCC99, .end = 0xCCB3, .category = .LVT },
+    .{ .start = 0xCCB4, .end = 0xCCB4, .category = .LV },
+    .{ .start = 0xCCB5, .end = 0xCCCF, .category = .LVT },
+    .{ .start = 0xCCD0, .end = 0xCCD0, .category = .LV },
+    .{ .start = 0xCCD1, .end = 0xCCEB, .category = .LVT },
+    .{ .start = 0xCCEC, .end = 0xCCEC, .category = .LV },
+    .{ .start = 0xCCED, .end = 0xCD07, .category = .LVT },
+    .{ .start = 0xCD08, .end = 0xCD08, .category = .LV },
+    .{ .start = 0xCD09, .end = 0xCD23, .category = .LVT },
+    .{ .start = 0xCD24, .end = 0xCD24, .category = .LV },
+    .{ .start = 0xCD25, .end = 0xCD3F, .category = .LVT },
+    .{ .start = 0xCD40, .end = 0xCD40, .category = .LV },
+    .{ .start = 0xCD41, .end = 0xCD5B, .category = .LVT },
+    .{ .start = 0xCD5C, .end = 0xCD5C, .category = .LV },
+    .{ .start = 0xCD5D, .end = 0xCD77, .category = .LVT },
+    .{ .start = 0xCD78, .end = 0xCD78, .category = .LV },
+    .{ .start = 0xCD79, .end = 0xCD93, .category = .LVT },
+    .{ .start = 0xCD94, .end = 0xCD94, .category = .LV },
+    .{ .start = 0xCD95, .end = 0xCDAF, .category = .LVT },
+    .{ .start = 0xCDB0, .end = 0xCDB0, .category = .LV },
+    .{ .start = 0xCDB1, .end = 0xCDCB, .category = .LVT },
+    .{ .start = 0xCDCC, .end = 0xCDCC, .category = .LV },
+    .{ .start = 0xCDCD, .end = 0xCDE7, .category = .LVT },
+    .{ .start = 0xCDE8, .end = 0xCDE8, .category = .LV },
+    .{ .start = 0xCDE9, .end = 0xCE03, .category = .LVT },
+    .{ .start = 0xCE04, .end = 0xCE04, .category = .LV },
+    .{ .start = 0xCE05, .end = 0xCE1F, .category = .LVT },
+    .{ .start = 0xCE20, .end = 0xCE20, .category = .LV },
+    .{ .start = 0xCE21, .end = 0xCE3B, .category = .LVT },
+    .{ .start = 0xCE3C, .end = 0xCE3C, .category = .LV },
+    .{ .start = 0xCE3D, .end = 0xCE57, .category = .LVT },
+    .{ .start = 0xCE58, .end = 0xCE58, .category = .LV },
+    .{ .start = 0xCE59, .end = 0xCE73, .category = .LVT },
+    .{ .start = 0xCE74, .end = 0xCE74, .category = .LV },
+    .{ .start = 0xCE75, .end = 0xCE8F, .category = .LVT },
+    .{ .start = 0xCE90, .end = 0xCE90, .category = .LV },
+    .{ .start = 0xCE91, .end = 0xCEAB, .category = .LVT },
+    .{ .start = 0xCEAC, .end = 0xCEAC, .category = .LV },
+    .{ .start = 0xCEAD, .end = 0xCEC7, .category = .LVT },
+    .{ .start = 0xCEC8, .end = 0xCEC8, .category = .LV },
+    .{ .start = 0xCEC9, .end = 0xCEE3, .category = .LVT },
+    .{ .start = 0xCEE4, .end = 0xCEE4, .category = .LV },
+    .{ .start = 0xCEE5, .end = 0xCEFF, .category = .LVT },
+    .{ .start = 0xCF00, .end = 0xCF00, .category = .LV },
+    .{ .start = 0xCF01, .end = 0xCF1B, .category = .LVT },
+    .{ .start = 0xCF1C, .end = 0xCF1C, .category = .LV },
+    .{ .start = 0xCF1D, .end = 0xCF37, .category = .LVT },
+    .{ .start = 0xCF38, .end = 0xCF38, .category = .LV },
+    .{ .start = 0xCF39, .end = 0xCF53, .category = .LVT },
+    .{ .start = 0xCF54, .end = 0xCF54, .category = .LV },
+    .{ .start = 0xCF55, .end = 0xCF6F, .category = .LVT },
+    .{ .start = 0xCF70, .end = 0xCF70, .category = .LV },
+    .{ .start = 0xCF71, .end = 0xCF8B, .category = .LVT },
+    .{ .start = 0xCF8C, .end = 0xCF8C, .category = .LV },
+    .{ .start = 0xCF8D, .end = 0xCFA7, .category = .LVT },
+    .{ .start = 0xCFA8, .end = 0xCFA8, .category = .LV },
+    .{ .start = 0xCFA9, .end = 0xCFC3, .category = .LVT },
+    .{ .start = 0xCFC4, .end = 0xCFC4, .category = .LV },
+    .{ .start = 0xCFC5, .end = 0xCFDF, .category = .LVT },
+    .{ .start = 0xCFE0, .end = 0xCFE0, .category = .LV },
+    .{ .start = 0xCFE1, .end = 0xCFFB, .category = .LVT },
+    .{ .start = 0xCFFC, .end = 0xCFFC, .category = .LV },
+    .{ .start = 0xCFFD, .end = 0xD017, .category = .LVT },
+    .{ .start = 0xD018, .end = 0xD018, .category = .LV },
+    .{ .start = 0xD019, .end = 0xD033, .category = .LVT },
+    .{ .start = 0xD034, .end = 0xD034, .category = .LV },
+    .{ .start = 0xD035, .end = 0xD04F, .category = .LVT },
+    .{ .start = 0xD050, .end = 0xD050, .category = .LV },
+    .{ .start = 0xD051, .end = 0xD06B, .category = .LVT },
+    .{ .start = 0xD06C, .end = 0xD06C, .category = .LV },
+    .{ .start = 0xD06D, .end = 0xD087, .category = .LVT },
+    .{ .start = 0xD088, .end = 0xD088, .category = .LV },
+    .{ .start = 0xD089, .end = 0xD0A3, .category = .LVT },
+    .{ .start = 0xD0A4, .end = 0xD0A4, .category = .LV },
+    .{ .start = 0xD0A5, .end = 0xD0BF, .category = .LVT },
+    .{ .start = 0xD0C0, .end = 0xD0C0, .category = .LV },
+    .{ .start = 0xD0C1, .end = 0xD0DB, .category = .LVT },
+    .{ .start = 0xD0DC, .end = 0xD0DC, .category = .LV },
+    .{ .start = 0xD0DD, .end = 0xD0F7, .category = .LVT },
+    .{ .start = 0xD0F8, .end = 0xD0F8, .category = .LV },
+    .{ .start = 0xD0F9, .end = 0xD113, .category = .LVT },
+    .{ .start = 0xD114, .end = 0xD114, .category = .LV },
+    .{ .start = 0xD115, .end = 0xD12F, .category = .LVT },
+    .{ .start = 0xD130, .end = 0xD130, .category = .LV },
+    .{ .start = 0xD131, .end = 0xD14B, .category = .LVT },
+    .{ .start = 0xD14C, .end = 0xD14C, .category = .LV },
+    .{ .start = 0xD14D, .end = 0xD167, .category = .LVT },
+    .{ .start = 0xD168, .end = 0xD168, .category = .LV },
+    .{ .start = 0xD169, .end = 0xD183, .category = .LVT },
+    .{ .start = 0xD184, .end = 0xD184, .category = .LV },
+    .{ .start = 0xD185, .end = 0xD19F, .category = .LVT },
+    .{ .start = 0xD1A0, .end = 0xD1A0, .category = .LV },
+    .{ .start = 0xD1A1, .end = 0xD1BB, .category = .LVT },
+    .{ .start = 0xD1BC, .end = 0xD1BC, .category = .LV },
+    .{ .start = 0xD1BD, .end = 0xD1D7, .category = .LVT },
+    .{ .start = 0xD1D8, .end = 0xD1D8, .category = .LV },
+    .{ .start = 0xD1D9, .end = 0xD1F3, .category = .LVT },
+    .{ .start = 0xD1F4, .end = 0xD1F4, .category = .LV },
+    .{ .start = 0xD1F5, .end = 0xD20F, .category = .LVT },
+    .{ .start = 0xD210, .end = 0xD210, .category = .LV },
+    .{ .start = 0xD211, .end = 0xD22B, .category = .LVT },
+    .{ .start = 0xD22C, .end = 0xD22C, .category = .LV },
+    .{ .start = 0xD22D, .end = 0xD247, .category = .LVT },
+    .{ .start = 0xD248, .end = 0xD248, .category = .LV },
+    .{ .start = 0xD249, .end = 0xD263, .category = .LVT },
+    .{ .start = 0xD264, .end = 0xD264, .category = .LV },
+    .{ .start = 0xD265, .end = 0xD27F, .category = .LVT },
+    .{ .start = 0xD280, .end = 0xD280, .category = .LV },
+    .{ .start = 0xD281, .end = 0xD29B, .category = .LVT },
+    .{ .start = 0xD29C, .end = 0xD29C, .category = .LV },
+    .{ .start = 0xD29D, .end = 0xD2B7, .category = .LVT },
+    .{ .start = 0xD2B8, .end = 0xD2B8, .category = .LV },
+    .{ .start = 0xD2B9, .end = 0xD2D3, .category = .LVT },
+    .{ .start = 0xD2D4, .end = 0xD2D4, .category = .LV },
+    .{ .start = 0xD2D5, .end = 0xD2EF, .category = .LVT },
+    .{ .start = 0xD2F0, .end = 0xD2F0, .category = .LV },
+    .{ .start = 0xD2F1, .end = 0xD30B, .category = .LVT },
+    .{ .start = 0xD30C, .end = 0xD30C, .category = .LV },
+    .{ .start = 0xD30D, .end = 0xD327, .category = .LVT },
+    .{ .start = 0xD328, .end = 0xD328, .category = .LV },
+    .{ .start = 0xD329, .end = 0xD343, .category = .LVT },
+    .{ .start = 0xD344, .end = 0xD344, .category = .LV },
+    .{ .start = 0xD345, .end = 0xD35F, .category = .LVT },
+    .{ .start = 0xD360, .end = 0xD360, .category = .LV },
+    .{ .start = 0xD361, .end = 0xD37B, .category = .LVT },
+    .{ .start = 0xD37C, .end = 0xD37C, .category = .LV },
+    .{ .start = 0xD37D, .end = 0xD397, .category = .LVT },
+    .{ .start = 0xD398, .end = 0xD398, .category = .LV },
+    .{ .start = 0xD399, .end = 0xD3B3, .category = .LVT },
+    .{ .start = 0xD3B4, .end = 0xD3B4, .category = .LV },
+    .{ .start = 0xD3B5, .end = 0xD3CF, .category = .LVT },
+    .{ .start = 0xD3D0, .end = 0xD3D0, .category = .LV },
+    .{ .start = 0xD3D1, .end = 0xD3EB, .category = .LVT },
+    .{ .start = 0xD3EC, .end = 0xD3EC, .category = .LV },
+    .{ .start = 0xD3ED, .end = 0xD407, .category = .LVT },
+    .{ .start = 0xD408, .end = 0xD408, .category = .LV },
+    .{ .start = 0xD409, .end = 0xD423, .category = .LVT },
+    .{ .start = 0xD424, .end = 0xD424, .category = .LV },
+    .{ .start = 0xD425, .end = 0xD43F, .category = .LVT },
+    .{ .start = 0xD440, .end = 0xD440, .category = .LV },
+    .{ .start = 0xD441, .end = 0xD45B, .category = .LVT },
+    .{ .start = 0xD45C, .end = 0xD45C, .category = .LV },
+    .{ .start = 0xD45D, .end = 0xD477, .category = .LVT },
+    .{ .start = 0xD478, .end = 0xD478, .category = .LV },
+    .{ .start = 0xD479, .end = 0xD493, .category = .LVT },
+    .{ .start = 0xD494, .end = 0xD494, .category = .LV },
+    .{ .start = 0xD495, .end = 0xD4AF, .category = .LVT },
+    .{ .start = 0xD4B0, .end = 0xD4B0, .category = .LV },
+    .{ .start = 0xD4B1, .end = 0xD4CB, .category = .LVT },
+    .{ .start = 0xD4CC, .end = 0xD4CC, .category = .LV },
+    .{ .start = 0xD4CD, .end = 0xD4E7, .category = .LVT },
+    .{ .start = 0xD4E8, .end = 0xD4E8, .category = .LV },
+    .{ .start = 0xD4E9, .end = 0xD503, .category = .LVT },
+    .{ .start = 0xD504, .end = 0xD504, .category = .LV },
+    .{ .start = 0xD505, .end = 0xD51F, .category = .LVT },
+    .{ .start = 0xD520, .end = 0xD520, .category = .LV },
+    .{ .start = 0xD521, .end = 0xD53B, .category = .LVT },
+    .{ .start = 0xD53C, .end = 0xD53C, .category = .LV },
+    .{ .start = 0xD53D, .end = 0xD557, .category = .LVT },
+    .{ .start = 0xD558, .end = 0xD558, .category = .LV },
+    .{ .start = 0xD559, .end = 0xD573, .category = .LVT },
+    .{ .start = 0xD574, .end = 0xD574, .category = .LV },
+    .{ .start = 0xD575, .end = 0xD58F, .category = .LVT },
+    .{ .start = 0xD590, .end = 0xD590, .category = .LV },
+    .{ .start = 0xD591, .end = 0xD5AB, .category = .LVT },
+    .{ .start = 0xD5AC, .end = 0xD5AC, .category = .LV },
+    .{ .start = 0xD5AD, .end = 0xD5C7, .category = .LVT },
+    .{ .start = 0xD5C8, .end = 0xD5C8, .category = .LV },
+    .{ .start = 0xD5C9, .end = 0xD5E3, .category = .LVT },
+    .{ .start = 0xD5E4, .end = 0xD5E4, .category = .LV },
+    .{ .start = 0xD5E5, .end = 0xD5FF, .category = .LVT },
+    .{ .start = 0xD600, .end = 0xD600, .category = .LV },
+    .{ .start = 0xD601, .end = 0xD61B, .category = .LVT },
+    .{ .start = 0xD61C, .end = 0xD61C, .category = .LV },
+    .{ .start = 0xD61D, .end = 0xD637, .category = .LVT },
+    .{ .start = 0xD638, .end = 0xD638, .category = .LV },
+    .{ .start = 0xD639, .end = 0xD653, .category = .LVT },
+    .{ .start = 0xD654, .end = 0xD654, .category = .LV },
+    .{ .start = 0xD655, .end = 0xD66F, .category = .LVT },
+    .{ .start = 0xD670, .end = 0xD670, .category = .LV },
+    .{ .start = 0xD671, .end = 0xD68B, .category = .LVT },
+    .{ .start = 0xD68C, .end = 0xD68C, .category = .LV },
+    .{ .start = 0xD68D, .end = 0xD6A7, .category = .LVT },
+    .{ .start = 0xD6A8, .end = 0xD6A8, .category = .LV },
+    .{ .start = 0xD6A9, .end = 0xD6C3, .category = .LVT },
+    .{ .start = 0xD6C4, .end = 0xD6C4, .category = .LV },
+    .{ .start = 0xD6C5, .end = 0xD6DF, .category = .LVT },
+    .{ .start = 0xD6E0, .end = 0xD6E0, .category = .LV },
+    .{ .start = 0xD6E1, .end = 0xD6FB, .category = .LVT },
+    .{ .start = 0xD6FC, .end = 0xD6FC, .category = .LV },
+    .{ .start = 0xD6FD, .end = 0xD717, .category = .LVT },
+    .{ .start = 0xD718, .end = 0xD718, .category = .LV },
+    .{ .start = 0xD719, .end = 0xD733, .category = .LVT },
+    .{ .start = 0xD734, .end = 0xD734, .category = .LV },
+    .{ .start = 0xD735, .end = 0xD74F, .category = .LVT },
+    .{ .start = 0xD750, .end = 0xD750, .category = .LV },
+    .{ .start = 0xD751, .end = 0xD76B, .category = .LVT },
+    .{ .start = 0xD76C, .end = 0xD76C, .category = .LV },
+    .{ .start = 0xD76D, .end = 0xD787, .category = .LVT },
+    .{ .start = 0xD788, .end = 0xD788, .category = .LV },
+    .{ .start = 0xD789, .end = 0xD7A3, .category = .LVT },
+    .{ .start = 0xD7B0, .end = 0xD7C6, .category = .V },
+    .{ .start = 0xD7CB, .end = 0xD7FB, .category = .T },
+    .{ .start = 0xFB1E, .end = 0xFB1E, .category = .Extend },
+    .{ .start = 0xFE00, .end = 0xFE0F, .category = .Extend },
+    .{ .start = 0xFE20, .end = 0xFE2F, .category = .Extend },
+    .{ .start = 0xFEFF, .end = 0xFEFF, .category = .Control },
+    .{ .start = 0xFF9E, .end = 0xFF9F, .category = .Extend },
+    .{ .start = 0xFFF0, .end = 0xFFFB, .category = .Control },
+    .{ .start = 0x101FD, .end = 0x101FD, .category = .Extend },
+    .{ .start = 0x102E0, .end = 0x102E0, .category = .Extend },
+    .{ .start = 0x10376, .end = 0x1037A, .category = .Extend },
+    .{ .start = 0x10A00, .end = 0x10A00, .category = .InCbConsonant },
+    .{ .start = 0x10A01, .end = 0x10A03, .category = .Extend },
+    .{ .start = 0x10A05, .end = 0x10A06, .category = .Extend },
+    .{ .start = 0x10A0C, .end = 0x10A0F, .category = .Extend },
+    .{ .start = 0x10A10, .end = 0x10A13, .category = .InCbConsonant },
+    .{ .start = 0x10A15, .end = 0x10A17, .category = .InCbConsonant },
+    .{ .start = 0x10A19, .end = 0x10A35, .category = .InCbConsonant },
+    .{ .start = 0x10A38, .end = 0x10A3A, .category = .Extend },
+    .{ .start = 0x10A3F, .end = 0x10A3F, .category = .Extend },
+    .{ .start = 0x10AE5, .end = 0x10AE6, .category = .Extend },
+    .{ .start = 0x10D24, .end = 0x10D27, .category = .Extend },
+    .{ .start = 0x10D69, .end = 0x10D6D, .category = .Extend },
+    .{ .start = 0x10EAB, .end = 0x10EAC, .category = .Extend },
+    .{ .start = 0x10EFA, .end = 0x10EFF, .category = .Extend },
+    .{ .start = 0x10F46, .end = 0x10F50, .category = .Extend },
+    .{ .start = 0x10F82, .end = 0x10F85, .category = .Extend },
+    .{ .start = 0x11000, .end = 0x11000, .category = .SpacingMark },
+    .{ .start = 0x11001, .end = 0x11001, .category = .Extend },
+    .{ .start = 0x11002, .end = 0x11002, .category = .SpacingMark },
+    .{ .start = 0x11038, .end = 0x11046, .category = .Extend },
+    .{ .start = 0x11070, .end = 0x11070, .category = .Extend },
+    .{ .start = 0x11073, .end = 0x11074, .category = .Extend },
+    .{ .start = 0x1107F, .end = 0x11081, .category = .Extend },
+    .{ .start = 0x11082, .end = 0x11082, .category = .SpacingMark },
+    .{ .start = 0x110B0, .end = 0x110B2, .category = .SpacingMark },
+    .{ .start = 0x110B3, .end = 0x110B6, .category = .Extend },
+    .{ .start = 0x110B7, .end = 0x110B8, .category = .SpacingMark },
+    .{ .start = 0x110B9, .end = 0x110BA, .category = .Extend },
+    .{ .start = 0x110BD, .end = 0x110BD, .category = .Prepend },
+    .{ .start = 0x110C2, .end = 0x110C2, .category = .Extend },
+    .{ .start = 0x110CD, .end = 0x110CD, .category = .Prepend },
+    .{ .start = 0x11100, .end = 0x11102, .category = .Extend },
+    .{ .start = 0x11103, .end = 0x11126, .category = .InCbConsonant },
+    .{ .start = 0x11127, .end = 0x1112B, .category = .Extend },
+    .{ .start = 0x1112C, .end = 0x1112C, .category = .SpacingMark },
+    .{ .start = 0x1112D, .end = 0x11134, .category = .Extend },
+    .{ .start = 0x11144, .end = 0x11144, .category = .InCbConsonant },
+    .{ .start = 0x11145, .end = 0x11146, .category = .SpacingMark },
+    .{ .start = 0x11147, .end = 0x11147, .category = .InCbConsonant },
+    .{ .start = 0x11173, .end = 0x11173, .category = .Extend },
+    .{ .start = 0x11180, .end = 0x11181, .category = .Extend },
+    .{ .start = 0x11182, .end = 0x11182, .category = .SpacingMark },
+    .{ .start = 0x111B3, .end = 0x111B5, .category = .SpacingMark },
+    .{ .start = 0x111B6, .end = 0x111BE, .category = .Extend },
+    .{ .start = 0x111BF, .end = 0x111BF, .category = .SpacingMark },
+    .{ .start = 0x111C0, .end = 0x111C0, .category = .Extend },
+    .{ .start = 0x111C2, .end = 0x111C3, .category = .Prepend },
+    .{ .start = 0x111C9, .end = 0x111CC, .category = .Extend },
+    .{ .start = 0x111CE, .end = 0x111CE, .category = .SpacingMark },
+    .{ .start = 0x111CF, .end = 0x111CF, .category = .Extend },
+    .{ .start = 0x1122C, .end = 0x1122E, .category = .SpacingMark },
+    .{ .start = 0x1122F, .end = 0x11231, .category = .Extend },
+    .{ .start = 0x11232, .end = 0x11233, .category = .SpacingMark },
+    .{ .start = 0x11234, .end = 0x11237, .category = .Extend },
+    .{ .start = 0x1123E, .end = 0x1123E, .category = .Extend },
+    .{ .start = 0x11241, .end = 0x11241, .category = .Extend },
+    .{ .start = 0x112DF, .end = 0x112DF, .category = .Extend },
+    .{ .start = 0x112E0, .end = 0x112E2, .category = .SpacingMark },
+    .{ .start = 0x112E3, .end = 0x112EA, .category = .Extend },
+    .{ .start = 0x11300, .end = 0x11301, .category = .Extend },
+    .{ .start = 0x11302, .end = 0x11303, .category = .SpacingMark },
+    .{ .start = 0x1133B, .end = 0x1133C, .category = .Extend },
+    .{ .start = 0x1133E, .end = 0x1133E, .category = .Extend },
+    .{ .start = 0x1133F, .end = 0x1133F, .category = .SpacingMark },
+    .{ .start = 0x11340, .end = 0x11340, .category = .Extend },
+    .{ .start = 0x11341, .end = 0x11344, .category = .SpacingMark },
+    .{ .start = 0x11347, .end = 0x11348, .category = .SpacingMark },
+    .{ .start = 0x1134B, .end = 0x1134C, .category = .SpacingMark },
+    .{ .start = 0x1134D, .end = 0x1134D, .category = .Extend },
+    .{ .start = 0x11357, .end = 0x11357, .category = .Extend },
+    .{ .start = 0x11362, .end = 0x11363, .category = .SpacingMark },
+    .{ .start = 0x11366, .end = 0x1136C, .category = .Extend },
+    .{ .start = 0x11370, .end = 0x11374, .category = .Extend },
+    .{ .start = 0x11380, .end = 0x11389, .category = .InCbConsonant },
+    .{ .start = 0x1138B, .end = 0x1138B, .category = .InCbConsonant },
+    .{ .start = 0x1138E, .end = 0x1138E, .category = .InCbConsonant },
+    .{ .start = 0x11390, .end = 0x113B5, .category = .InCbConsonant },
+    .{ .start = 0x113B8, .end = 0x113B8, .category = .Extend },
+    .{ .start = 0x113B9, .end = 0x113BA, .category = .SpacingMark },
+    .{ .start = 0x113BB, .end = 0x113C0, .category = .Extend },
+    .{ .start = 0x113C2, .end = 0x113C2, .category = .Extend },
+    .{ .start = 0x113C5, .end = 0x113C5, .category = .Extend },
+    .{ .start = 0x113C7, .end = 0x113C9, .category = .Extend },
+    .{ .start = 0x113CA, .end = 0x113CA, .category = .SpacingMark },
+    .{ .start = 0x113CC, .end = 0x113CD, .category = .SpacingMark },
+    .{ .start = 0x113CE, .end = 0x113D0, .category = .Extend },
+    .{ .start = 0x113D1, .end = 0x113D1, .category = .Prepend },
+    .{ .start = 0x113D2, .end = 0x113D2, .category = .Extend },
+    .{ .start = 0x113E1, .end = 0x113E2, .category = .Extend },
+    .{ .start = 0x11435, .end = 0x11437, .category = .SpacingMark },
+    .{ .start = 0x11438, .end = 0x1143F, .category = .Extend },
+    .{ .start = 0x11440, .end = 0x11441, .category = .SpacingMark },
+    .{ .start = 0x11442, .end = 0x11444, .category = .Extend },
+    .{ .start = 0x11445, .end = 0x11445, .category = .SpacingMark },
+    .{ .start = 0x11446, .end = 0x11446, .category = .Extend },
+    .{ .start = 0x1145E, .end = 0x1145E, .category = .Extend },
+    .{ .start = 0x114B0, .end = 0x114B0, .category = .Extend },
+    .{ .start = 0x114B1, .end = 0x114B2, .category = .SpacingMark },
+    .{ .start = 0x114B3, .end = 0x114B8, .category = .Extend },
+    .{ .start = 0x114B9, .end = 0x114B9, .category = .SpacingMark },
+    .{ .start = 0x114BA, .end = 0x114BA, .category = .Extend },
+    .{ .start = 0x114BB, .end = 0x114BC, .category = .SpacingMark },
+    .{ .start = 0x114BD, .end = 0x114BD, .category = .Extend },
+    .{ .start = 0x114BE, .end = 0x114BE, .category = .SpacingMark },
+    .{ .start = 0x114BF, .end = 0x114C0, .category = .Extend },
+    .{ .start = 0x114C1, .end = 0x114C1, .category = .SpacingMark },
+    .{ .start = 0x114C2, .end = 0x114C3, .category = .Extend },
+    .{ .start = 0x115AF, .end = 0x115AF, .category = .Extend },
+    .{ .start = 0x115B0, .end = 0x115B1, .category = .SpacingMark },
+    .{ .start = 0x115B2, .end = 0x115B5, .category = .Extend },
+    .{ .start = 0x115B8, .end = 0x115BB, .category = .SpacingMark },
+    .{ .start = 0x115BC, .end = 0x115BD, .category = .Extend },
+    .{ .start = 0x115BE, .end = 0x115BE, .category = .SpacingMark },
+    .{ .start = 0x115BF, .end = 0x115C0, .category = .Extend },
+    .{ .start = 0x115DC, .end = 0x115DD, .category = .Extend },
+    .{ .start = 0x11630, .end = 0x11632, .category = .SpacingMark },
+    .{ .start = 0x11633, .end = 0x1163A, .category = .Extend },
+    .{ .start = 0x1163B, .end = 0x1163C, .category = .SpacingMark },
+    .{ .start = 0x1163D, .end = 0x1163D, .category = .Extend },
+    .{ .start = 0x1163E, .end = 0x1163E, .category = .SpacingMark },
+    .{ .start = 0x1163F, .end = 0x11640, .category = .Extend },
+    .{ .start = 0x116AB, .end = 0x116AB, .category = .Extend },
+    .{ .start = 0x116AC, .end = 0x116AC, .category = .SpacingMark },
+    .{ .start = 0x116AD, .end = 0x116AD, .category = .Extend },
+    .{ .start = 0x116AE, .end = 0x116AF, .category = .SpacingMark },
+    .{ .start = 0x116B0, .end = 0x116B7, .category = .Extend },
+    .{ .start = 0x1171D, .end = 0x1171D, .category = .Extend },
+    .{ .start = 0x1171E, .end = 0x1171E, .category = .SpacingMark },
+    .{ .start = 0x1171F, .end = 0x1171F, .category = .Extend },
+    .{ .start = 0x11722, .end = 0x11725, .category = .Extend },
+    .{ .start = 0x11726, .end = 0x11726, .category = .SpacingMark },
+    .{ .start = 0x11727, .end = 0x1172B, .category = .Extend },
+    .{ .start = 0x1182C, .end = 0x1182E, .category = .SpacingMark },
+    .{ .start = 0x1182F, .end = 0x11837, .category = .Extend },
+    .{ .start = 0x11838, .end = 0x11838, .category = .SpacingMark },
+    .{ .start = 0x11839, .end = 0x1183A, .category = .Extend },
+    .{ .start = 0x11900, .end = 0x11906, .category = .InCbConsonant },
+    .{ .start = 0x11909, .end = 0x11909, .category = .InCbConsonant },
+    .{ .start = 0x1190C, .end = 0x11913, .category = .InCbConsonant },
+    .{ .start = 0x11915, .end = 0x11916, .category = .InCbConsonant },
+    .{ .start = 0x11918, .end = 0x1192F, .category = .InCbConsonant },
+    .{ .start = 0x11930, .end = 0x11930, .category = .Extend },
+    .{ .start = 0x11931, .end = 0x11935, .category = .SpacingMark },
+    .{ .start = 0x11937, .end = 0x11938, .category = .SpacingMark },
+    .{ .start = 0x1193B, .end = 0x1193E, .category = .Extend },
+    .{ .start = 0x1193F, .end = 0x1193F, .category = .Prepend },
+    .{ .start = 0x11940, .end = 0x11940, .category = .SpacingMark },
+    .{ .start = 0x11941, .end = 0x11941, .category = .Prepend },
+    .{ .start = 0x11942, .end = 0x11942, .category = .SpacingMark },
+    .{ .start = 0x11943, .end = 0x11943, .category = .Extend },
+    .{ .start = 0x119D1, .end = 0x119D3, .category = .SpacingMark },
+    .{ .start = 0x119D4, .end = 0x119D7, .category = .Extend },
+    .{ .start = 0x119DA, .end = 0x119DB, .category = .Extend },
+    .{ .start = 0x119DC, .end = 0x119DF, .category = .SpacingMark },
+    .{ .start = 0x119E0, .end = 0x119E0, .category = .Extend },
+    .{ .start = 0x119E4, .end = 0x119E4, .category = .SpacingMark },
+    .{ .start = 0x11A00, .end = 0x11A00, .category = .InCbConsonant },
+    .{ .start = 0x11A01, .end = 0x11A0A, .category = .Extend },
+    .{ .start = 0x11A0B, .end = 0x11A32, .category = .InCbConsonant },
+    .{ .start = 0x11A33, .end = 0x11A38, .category = .Extend },
+    .{ .start = 0x11A39, .end = 0x11A39, .category = .SpacingMark },
+    .{ .start = 0x11A3B, .end = 0x11A3E, .category = .Extend },
+    .{ .start = 0x11A47, .end = 0x11A47, .category = .Extend },
+    .{ .start = 0x11A50, .end = 0x11A50, .category = .InCbConsonant },
+    .{ .start = 0x11A51, .end = 0x11A56, .category = .Extend },
+    .{ .start = 0x11A57, .end = 0x11A58, .category = .SpacingMark },
+    .{ .start = 0x11A59, .end = 0x11A5B, .category = .Extend },
+    .{ .start = 0x11A5C, .end = 0x11A83, .category = .InCbConsonant },
+    .{ .start = 0x11A84, .end = 0x11A89, .category = .Prepend },
+    .{ .start = 0x11A8A, .end = 0x11A96, .category = .Extend },
+    .{ .start = 0x11A97, .end = 0x11A97, .category = .SpacingMark },
+    .{ .start = 0x11A98, .end = 0x11A99, .category = .Extend },
+    .{ .start = 0x11B60, .end = 0x11B60, .category = .Extend },
+    .{ .start = 0x11B61, .end = 0x11B61, .category = .SpacingMark },
+    .{ .start = 0x11B62, .end = 0x11B64, .category = .Extend },
+    .{ .start = 0x11B65, .end = 0x11B65, .category = .SpacingMark },
+    .{ .start = 0x11B66, .end = 0x11B66, .category = .Extend },
+    .{ .start = 0x11B67, .end = 0x11B67, .category = .SpacingMark },
+    .{ .start = 0x11C2F, .end = 0x11C2F, .category = .SpacingMark },
+    .{ .start = 0x11C30, .end = 0x11C36, .category = .Extend },
+    .{ .start = 0x11C38, .end = 0x11C3D, .category = .Extend },
+    .{ .start = 0x11C3E, .end = 0x11C3E, .category = .SpacingMark },
+    .{ .start = 0x11C3F, .end = 0x11C3F, .category = .Extend },
+    .{ .start = 0x11C92, .end = 0x11CA7, .category = .Extend },
+    .{ .start = 0x11CA9, .end = 0x11CA9, .category = .SpacingMark },
+    .{ .start = 0x11CAA, .end = 0x11CB0, .category = .Extend },
+    .{ .start = 0x11CB1, .end = 0x11CB1, .category = .SpacingMark },
+    .{ .start = 0x11CB2, .end = 0x11CB3, .category = .Extend },
+    .{ .start = 0x11CB4, .end = 0x11CB4, .category = .SpacingMark },
+    .{ .start = 0x11CB5, .end = 0x11CB6, .category = .Extend },
+    .{ .start = 0x11D31, .end = 0x11D36, .category = .Extend },
+    .{ .start = 0x11D3A, .end = 0x11D3A, .category = .Extend },
+    .{ .start = 0x11D3C, .end = 0x11D3D, .category = .Extend },
+    .{ .start = 0x11D3F, .end = 0x11D45, .category = .Extend },
+    .{ .start = 0x11D46, .end = 0x11D46, .category = .Prepend },
+    .{ .start = 0x11D47, .end = 0x11D47, .category = .Extend },
+    .{ .start = 0x11D8A, .end = 0x11D8E, .category = .SpacingMark },
+    .{ .start = 0x11D90, .end = 0x11D91, .category = .Extend },
+    .{ .start = 0x11D93, .end = 0x11D94, .category = .SpacingMark },
+    .{ .start = 0x11D95, .end = 0x11D95, .category = .Extend },
+    .{ .start = 0x11D96, .end = 0x11D96, .category = .SpacingMark },
+    .{ .start = 0x11D97, .end = 0x11D97, .category = .Extend },
+    .{ .start = 0x11EF3, .end = 0x11EF4, .category = .Extend },
+    .{ .start = 0x11EF5, .end = 0x11EF6, .category = .SpacingMark },
+    .{ .start = 0x11F00, .end = 0x11F01, .category = .Extend },
+    .{ .start = 0x11F02, .end = 0x11F02, .category = .Prepend },
+    .{ .start = 0x11F03, .end = 0x11F03, .category = .SpacingMark },
+    .{ .start = 0x11F04, .end = 0x11F10, .category = .InCbConsonant },
+    .{ .start = 0x11F12, .end = 0x11F33, .category = .InCbConsonant },
+    .{ .start = 0x11F34, .end = 0x11F35, .category = .SpacingMark },
+    .{ .start = 0x11F36, .end = 0x11F3A, .category = .Extend },
+    .{ .start = 0x11F3E, .end = 0x11F3F, .category = .SpacingMark },
+    .{ .start = 0x11F40, .end = 0x11F42, .category = .Extend },
+    .{ .start = 0x11F5A, .end = 0x11F5A, .category = .Extend },
+    .{ .start = 0x13430, .end = 0x1343F, .category = .Control },
+    .{ .start = 0x13440, .end = 0x13440, .category = .Extend },
+    .{ .start = 0x13447, .end = 0x13455, .category = .Extend },
+    .{ .start = 0x1611E, .end = 0x16129, .category = .Extend },
+    .{ .start = 0x1612A, .end = 0x1612C, .category = .SpacingMark },
+    .{ .start = 0x1612D, .end = 0x1612F, .category = .Extend },
+    .{ .start = 0x16AF0, .end = 0x16AF4, .category = .Extend },
+    .{ .start = 0x16B30, .end = 0x16B36, .category = .Extend },
+    .{ .start = 0x16D63, .end = 0x16D63, .category = .V },
+    .{ .start = 0x16D67, .end = 0x16D6A, .category = .V },
+    .{ .start = 0x16F4F, .end = 0x16F4F, .category = .Extend },
+    .{ .start = 0x16F51, .end = 0x16F87, .category = .SpacingMark },
+    .{ .start = 0x16F8F, .end = 0x16F92, .category = .Extend },
+    .{ .start = 0x16FE4, .end = 0x16FE4, .category = .Extend },
+    .{ .start = 0x16FF0, .end = 0x16FF1, .category = .Extend },
+    .{ .start = 0x1BC9D, .end = 0x1BC9E, .category = .Extend },
+    .{ .start = 0x1BCA0, .end = 0x1BCA3, .category = .Control },
+    .{ .start = 0x1CF00, .end = 0x1CF2D, .category = .Extend },
+    .{ .start = 0x1CF30, .end = 0x1CF46, .category = .Extend },
+    .{ .start = 0x1D165, .end = 0x1D169, .category = .Extend },
+    .{ .start = 0x1D16D, .end = 0x1D172, .category = .Extend },
+    .{ .start = 0x1D173, .end = 0x1D17A, .category = .Control },
+    .{ .start = 0x1D17B, .end = 0x1D182, .category = .Extend },
+    .{ .start = 0x1D185, .end = 0x1D18B, .category = .Extend },
+    .{ .start = 0x1D1AA, .end = 0x1D1AD, .category = .Extend },
+    .{ .start = 0x1D242, .end = 0x1D244, .category = .Extend },
+    .{ .start = 0x1DA00, .end = 0x1DA36, .category = .Extend },
+    .{ .start = 0x1DA3B, .end = 0x1DA6C, .category = .Extend },
+    .{ .start = 0x1DA75, .end = 0x1DA75, .category = .Extend },
+    .{ .start = 0x1DA84, .end = 0x1DA84, .category = .Extend },
+    .{ .start = 0x1DA9B, .end = 0x1DA9F, .category = .Extend },
+    .{ .start = 0x1DAA1, .end = 0x1DAAF, .category = .Extend },
+    .{ .start = 0x1E000, .end = 0x1E006, .category = .Extend },
+    .{ .start = 0x1E008, .end = 0x1E018, .category = .Extend },
+    .{ .start = 0x1E01B, .end = 0x1E021, .category = .Extend },
+    .{ .start = 0x1E023, .end = 0x1E024, .category = .Extend },
+    .{ .start = 0x1E026, .end = 0x1E02A, .category = .Extend },
+    .{ .start = 0x1E08F, .end = 0x1E08F, .category = .Extend },
+    .{ .start = 0x1E130, .end = 0x1E136, .category = .Extend },
+    .{ .start = 0x1E2AE, .end = 0x1E2AE, .category = .Extend },
+    .{ .start = 0x1E2EC, .end = 0x1E2EF, .category = .Extend },
+    .{ .start = 0x1E4EC, .end = 0x1E4EF, .category = .Extend },
+    .{ .start = 0x1E5EE, .end = 0x1E5EF, .category = .Extend },
+    .{ .start = 0x1E6E3, .end = 0x1E6E3, .category = .Extend },
+    .{ .start = 0x1E6E6, .end = 0x1E6E6, .category = .Extend },
+    .{ .start = 0x1E6EE, .end = 0x1E6EF, .category = .Extend },
+    .{ .start = 0x1E6F5, .end = 0x1E6F5, .category = .Extend },
+    .{ .start = 0x1E8D0, .end = 0x1E8D6, .category = .Extend },
+    .{ .start = 0x1E944, .end = 0x1E94A, .category = .Extend },
+    .{ .start = 0x1F004, .end = 0x1F004, .category = .ExtendedPictographic },
+    .{ .start = 0x1F02C, .end = 0x1F02F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F094, .end = 0x1F09F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F0AF, .end = 0x1F0B0, .category = .ExtendedPictographic },
+    .{ .start = 0x1F0C0, .end = 0x1F0C0, .category = .ExtendedPictographic },
+    .{ .start = 0x1F0CF, .end = 0x1F0D0, .category = .ExtendedPictographic },
+    .{ .start = 0x1F0F6, .end = 0x1F0FF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F170, .end = 0x1F171, .category = .ExtendedPictographic },
+    .{ .start = 0x1F17E, .end = 0x1F17F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F18E, .end = 0x1F18E, .category = .ExtendedPictographic },
+    .{ .start = 0x1F191, .end = 0x1F19A, .category = .ExtendedPictographic },
+    .{ .start = 0x1F1AE, .end = 0x1F1E5, .category = .ExtendedPictographic },
+    .{ .start = 0x1F1E6, .end = 0x1F1FF, .category = .RegionalIndicator },
+    .{ .start = 0x1F201, .end = 0x1F20F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F21A, .end = 0x1F21A, .category = .ExtendedPictographic },
+    .{ .start = 0x1F22F, .end = 0x1F22F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F232, .end = 0x1F23A, .category = .ExtendedPictographic },
+    .{ .start = 0x1F23C, .end = 0x1F23F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F249, .end = 0x1F25F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F266, .end = 0x1F321, .category = .ExtendedPictographic },
+    .{ .start = 0x1F324, .end = 0x1F393, .category = .ExtendedPictographic },
+    .{ .start = 0x1F396, .end = 0x1F397, .category = .ExtendedPictographic },
+    .{ .start = 0x1F399, .end = 0x1F39B, .category = .ExtendedPictographic },
+    .{ .start = 0x1F39E, .end = 0x1F3F0, .category = .ExtendedPictographic },
+    .{ .start = 0x1F3F3, .end = 0x1F3F5, .category = .ExtendedPictographic },
+    .{ .start = 0x1F3F7, .end = 0x1F3FA, .category = .ExtendedPictographic },
+    .{ .start = 0x1F3FB, .end = 0x1F3FF, .category = .Extend },
+    .{ .start = 0x1F400, .end = 0x1F4FD, .category = .ExtendedPictographic },
+    .{ .start = 0x1F4FF, .end = 0x1F53D, .category = .ExtendedPictographic },
+    .{ .start = 0x1F549, .end = 0x1F54E, .category = .ExtendedPictographic },
+    .{ .start = 0x1F550, .end = 0x1F567, .category = .ExtendedPictographic },
+    .{ .start = 0x1F56F, .end = 0x1F570, .category = .ExtendedPictographic },
+    .{ .start = 0x1F573, .end = 0x1F57A, .category = .ExtendedPictographic },
+    .{ .start = 0x1F587, .end = 0x1F587, .category = .ExtendedPictographic },
+    .{ .start = 0x1F58A, .end = 0x1F58D, .category = .ExtendedPictographic },
+    .{ .start = 0x1F590, .end = 0x1F590, .category = .ExtendedPictographic },
+    .{ .start = 0x1F595, .end = 0x1F596, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5A4, .end = 0x1F5A5, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5A8, .end = 0x1F5A8, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5B1, .end = 0x1F5B2, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5BC, .end = 0x1F5BC, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5C2, .end = 0x1F5C4, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5D1, .end = 0x1F5D3, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5DC, .end = 0x1F5DE, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5E1, .end = 0x1F5E1, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5E3, .end = 0x1F5E3, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5E8, .end = 0x1F5E8, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5EF, .end = 0x1F5EF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5F3, .end = 0x1F5F3, .category = .ExtendedPictographic },
+    .{ .start = 0x1F5FA, .end = 0x1F64F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F680, .end = 0x1F6C5, .category = .ExtendedPictographic },
+    .{ .start = 0x1F6CB, .end = 0x1F6D2, .category = .ExtendedPictographic },
+    .{ .start = 0x1F6D5, .end = 0x1F6E5, .category = .ExtendedPictographic },
+    .{ .start = 0x1F6E9, .end = 0x1F6E9, .category = .ExtendedPictographic },
+    .{ .start = 0x1F6EB, .end = 0x1F6F0, .category = .ExtendedPictographic },
+    .{ .start = 0x1F6F3, .end = 0x1F6FF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F7DA, .end = 0x1F7FF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F80C, .end = 0x1F80F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F848, .end = 0x1F84F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F85A, .end = 0x1F85F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F888, .end = 0x1F88F, .category = .ExtendedPictographic },
+    .{ .start = 0x1F8AE, .end = 0x1F8AF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F8BC, .end = 0x1F8BF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F8C2, .end = 0x1F8CF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F8D9, .end = 0x1F8FF, .category = .ExtendedPictographic },
+    .{ .start = 0x1F90C, .end = 0x1F93A, .category = .ExtendedPictographic },
+    .{ .start = 0x1F93C, .end = 0x1F945, .category = .ExtendedPictographic },
+    .{ .start = 0x1F947, .end = 0x1F9FF, .category = .ExtendedPictographic },
+    .{ .start = 0x1FA58, .end = 0x1FA5F, .category = .ExtendedPictographic },
+    .{ .start = 0x1FA6E, .end = 0x1FAFF, .category = .ExtendedPictographic },
+    .{ .start = 0x1FC00, .end = 0x1FFFD, .category = .ExtendedPictographic },
+    .{ .start = 0xE0000, .end = 0xE001F, .category = .Control },
+    .{ .start = 0xE0020, .end = 0xE007F, .category = .Extend },
+    .{ .start = 0xE0080, .end = 0xE00FF, .category = .Control },
+    .{ .start = 0xE0100, .end = 0xE01EF, .category = .Extend },
+    .{ .start = 0xE01F0, .end = 0xE0FFF, .category = .Control },
+};
+
+const incb_extend_ranges = [_]Range{
+    .{ .start = 0x300, .end = 0x36F },
+    .{ .start = 0x483, .end = 0x489 },
+    .{ .start = 0x591, .end = 0x5BD },
+    .{ .start = 0x5BF, .end = 0x5BF },
+    .{ .start = 0x5C1, .end = 0x5C2 },
+    .{ .start = 0x5C4, .end = 0x5C5 },
+    .{ .start = 0x5C7, .end = 0x5C7 },
+    .{ .start = 0x610, .end = 0x61A },
+    .{ .start = 0x64B, .end = 0x65F },
+    .{ .start = 0x670, .end = 0x670 },
+    .{ .start = 0x6D6, .end = 0x6DC },
+    .{ .start = 0x6DF, .end = 0x6E4 },
+    .{ .start = 0x6E7, .end = 0x6E8 },
+    .{ .start = 0x6EA, .end = 0x6ED },
+    .{ .start = 0x711, .end = 0x711 },
+    .{ .start = 0x730, .end = 0x74A },
+    .{ .start = 0x7A6, .end = 0x7B0 },
+    .{ .start = 0x7EB, .end = 0x7F3 },
+    .{ .start = 0x7FD, .end = 0x7FD },
+    .{ .start = 0x816, .end = 0x819 },
+    .{ .start = 0x81B, .end = 0x823 },
+    .{ .start = 0x825, .end = 0x827 },
+    .{ .start = 0x829, .end = 0x82D },
+    .{ .start = 0x859, .end = 0x85B },
+    .{ .start = 0x897, .end = 0x89F },
+    .{ .start = 0x8CA, .end = 0x8E1 },
+    .{ .start = 0x8E3, .end = 0x902 },
+    .{ .start = 0x93A, .end = 0x93A },
+    .{ .start = 0x93C, .end = 0x93C },
+    .{ .start = 0x941, .end = 0x948 },
+    .{ .start = 0x951, .end = 0x957 },
+    .{ .start = 0x962, .end = 0x963 },
+    .{ .start = 0x981, .end = 0x981 },
+    .{ .start = 0x9BC, .end = 0x9BC },
+    .{ .start = 0x9BE, .end = 0x9BE },
+    .{ .start = 0x9C1, .end = 0x9C4 },
+    .{ .start = 0x9D7, .end = 0x9D7 },
+    .{ .start = 0x9E2, .end = 0x9E3 },
+    .{ .start = 0x9FE, .end = 0x9FE },
+    .{ .start = 0xA01, .end = 0xA02 },
+    .{ .start = 0xA3C, .end = 0xA3C },
+    .{ .start = 0xA41, .end = 0xA42 },
+    .{ .start = 0xA47, .end = 0xA48 },
+    .{ .start = 0xA4B, .end = 0xA4D },
+    .{ .start = 0xA51, .end = 0xA51 },
+    .{ .start = 0xA70, .end = 0xA71 },
+    .{ .start = 0xA75, .end = 0xA75 },
+    .{ .start = 0xA81, .end = 0xA82 },
+    .{ .start = 0xABC, .end = 0xABC },
+    .{ .start = 0xAC1, .end = 0xAC5 },
+    .{ .start = 0xAC7, .end = 0xAC8 },
+    .{ .start = 0xAE2, .end = 0xAE3 },
+    .{ .start = 0xAFA, .end = 0xAFF },
+    .{ .start = 0xB01, .end = 0xB01 },
+    .{ .start = 0xB3C, .end = 0xB3C },
+    .{ .start = 0xB3E, .end = 0xB3F },
+    .{ .start = 0xB41, .end = 0xB44 },
+    .{ .start = 0xB55, .end = 0xB57 },
+    .{ .start = 0xB62, .end = 0xB63 },
+    .{ .start = 0xB82, .end = 0xB82 },
+    .{ .start = 0xBBE, .end = 0xBBE },
+    .{ .start = 0xBC0, .end = 0xBC0 },
+    .{ .start = 0xBCD, .end = 0xBCD },
+    .{ .start = 0xBD7, .end = 0xBD7 },
+    .{ .start = 0xC00, .end = 0xC00 },
+    .{ .start = 0xC04, .end = 0xC04 },
+    .{ .start = 0xC3C, .end = 0xC3C },
+    .{ .start = 0xC3E, .end = 0xC40 },
+    .{ .start = 0xC46, .end = 0xC48 },
+    .{ .start = 0xC4A, .end = 0xC4C },
+    .{ .start = 0xC55, .end = 0xC56 },
+    .{ .start = 0xC62, .end = 0xC63 },
+    .{ .start = 0xC81, .end = 0xC81 },
+    .{ .start = 0xCBC, .end = 0xCBC },
+    .{ .start = 0xCBF, .end = 0xCC0 },
+    .{ .start = 0xCC2, .end = 0xCC2 },
+    .{ .start = 0xCC6, .end = 0xCC8 },
+    .{ .start = 0xCCA, .end = 0xCCD },
+    .{ .start = 0xCD5, .end = 0xCD6 },
+    .{ .start = 0xCE2, .end = 0xCE3 },
+    .{ .start = 0xD00, .end = 0xD01 },
+    .{ .start = 0xD3B, .end = 0xD3C },
+    .{ .start = 0xD3E, .end = 0xD3E },
+    .{ .start = 0xD41, .end = 0xD44 },
+    .{ .start = 0xD57, .end = 0xD57 },
+    .{ .start = 0xD62, .end = 0xD63 },
+    .{ .start = 0xD81, .end = 0xD81 },
+    .{ .start = 0xDCA, .end = 0xDCA },
+    .{ .start = 0xDCF, .end = 0xDCF },
+    .{ .start = 0xDD2, .end = 0xDD4 },
+    .{ .start = 0xDD6, .end = 0xDD6 },
+    .{ .start = 0xDDF, .end = 0xDDF },
+    .{ .start = 0xE31, .end = 0xE31 },
+    .{ .start = 0xE34, .end = 0xE3A },
+    .{ .start = 0xE47, .end = 0xE4E },
+    .{ .start = 0xEB1, .end = 0xEB1 },
+    .{ .start = 0xEB4, .end = 0xEBC },
+    .{ .start = 0xEC8, .end = 0xECE },
+    .{ .start = 0xF18, .end = 0xF19 },
+    .{ .start = 0xF35, .end = 0xF35 },
+    .{ .start = 0xF37, .end = 0xF37 },
+    .{ .start = 0xF39, .end = 0xF39 },
+    .{ .start = 0xF71, .end = 0xF7E },
+    .{ .start = 0xF80, .end = 0xF84 },
+    .{ .start = 0xF86, .end = 0xF87 },
+    .{ .start = 0xF8D, .end = 0xF97 },
+    .{ .start = 0xF99, .end = 0xFBC },
+    .{ .start = 0xFC6, .end = 0xFC6 },
+    .{ .start = 0x102D, .end = 0x1030 },
+    .{ .start = 0x1032, .end = 0x1037 },
+    .{ .start = 0x103A, .end = 0x103A },
+    .{ .start = 0x103D, .end = 0x103E },
+    .{ .start = 0x1058, .end = 0x1059 },
+    .{ .start = 0x105E, .end = 0x1060 },
+    .{ .start = 0x1071, .end = 0x1074 },
+    .{ .start = 0x1082, .end = 0x1082 },
+    .{ .start = 0x1085, .end = 0x1086 },
+    .{ .start = 0x108D, .end = 0x108D },
+    .{ .start = 0x109D, .end = 0x109D },
+    .{ .start = 0x135D, .end = 0x135F },
+    .{ .start = 0x1712, .end = 0x1715 },
+    .{ .start = 0x1732, .end = 0x1734 },
+    .{ .start = 0x1752, .end = 0x1753 },
+    .{ .start = 0x1772, .end = 0x1773 },
+    .{ .start = 0x17B4, .end = 0x17B5 },
+    .{ .start = 0x17B7, .end = 0x17BD },
+    .{ .start = 0x17C6, .end = 0x17C6 },
+    .{ .start = 0x17C9, .end = 0x17D1 },
+    .{ .start = 0x17D3, .end = 0x17D3 },
+    .{ .start = 0x17DD, .end = 0x17DD },
+    .{ .start = 0x180B, .end = 0x180D },
+    .{ .start = 0x180F, .end = 0x180F },
+    .{ .start = 0x1885, .end = 0x1886 },
+    .{ .start = 0x18A9, .end = 0x18A9 },
+    .{ .start = 0x1920, .end = 0x1922 },
+    .{ .start = 0x1927, .end = 0x1928 },
+    .{ .start = 0x1932, .end = 0x1932 },
+    .{ .start = 0x1939, .end = 0x193B },
+    .{ .start = 0x1A17, .end = 0x1A18 },
+    .{ .start = 0x1A1B, .end = 0x1A1B },
+    .{ .start = 0x1A56, .end = 0x1A56 },
+    .{ .start = 0x1A58, .end = 0x1A5E },
+    .{ .start = 0x1A62, .end = 0x1A62 },
+    .{ .start = 0x1A65, .end = 0x1A6C },
+    .{ .start = 0x1A73, .end = 0x1A7C },
+    .{ .start = 0x1A7F, .end = 0x1A7F },
+    .{ .start = 0x1AB0, .end = 0x1ADD },
+    .{ .start = 0x1AE0, .end = 0x1AEB },
+    .{ .start = 0x1B00, .end = 0x1B03 },
+    .{ .start = 0x1B34, .end = 0x1B3D },
+    .{ .start = 0x1B42, .end = 0x1B43 },
+    .{ .start = 0x1B6B, .end = 0x1B73 },
+    .{ .start = 0x1B80, .end = 0x1B81 },
+    .{ .start = 0x1BA2, .end = 0x1BA5 },
+    .{ .start = 0x1BA8, .end = 0x1BAA },
+    .{ .start = 0x1BAC, .end = 0x1BAD },
+    .{ .start = 0x1BE6, .end = 0x1BE6 },
+    .{ .start = 0x1BE8, .end = 0x1BE9 },
+    .{ .start = 0x1BED, .end = 0x1BED },
+    .{ .start = 0x1BEF, .end = 0x1BF3 },
+    .{ .start = 0x1C2C, .end = 0x1C33 },
+    .{ .start = 0x1C36, .end = 0x1C37 },
+    .{ .start = 0x1CD0, .end = 0x1CD2 },
+    .{ .start = 0x1CD4, .end = 0x1CE0 },
+    .{ .start = 0x1CE2, .end = 0x1CE8 },
+    .{ .start = 0x1CED, .end = 0x1CED },
+    .{ .start = 0x1CF4, .end = 0x1CF4 },
+    .{ .start = 0x1CF8, .end = 0x1CF9 },
+    .{ .start = 0x1DC0, .end = 0x1DFF },
+    .{ .start = 0x200D, .end = 0x200D },
+    .{ .start = 0x20D0, .end = 0x20F0 },
+    .{ .start = 0x2CEF, .end = 0x2CF1 },
+    .{ .start = 0x2D7F, .end = 0x2D7F },
+    .{ .start = 0x2DE0, .end = 0x2DFF },
+    .{ .start = 0x302A, .end = 0x302F },
+    .{ .start = 0x3099, .end = 0x309A },
+    .{ .start = 0xA66F, .end = 0xA672 },
+    .{ .start = 0xA674, .end = 0xA67D },
+    .{ .start = 0xA69E, .end = 0xA69F },
+    .{ .start = 0xA6F0, .end = 0xA6F1 },
+    .{ .start = 0xA802, .end = 0xA802 },
+    .{ .start = 0xA806, .end = 0xA806 },
+    .{ .start = 0xA80B, .end = 0xA80B },
+    .{ .start = 0xA825, .end = 0xA826 },
+    .{ .start = 0xA82C, .end = 0xA82C },
+    .{ .start = 0xA8C4, .end = 0xA8C5 },
+    .{ .start = 0xA8E0, .end = 0xA8F1 },
+    .{ .start = 0xA8FF, .end = 0xA8FF },
+    .{ .start = 0xA926, .end = 0xA92D },
+    .{ .start = 0xA947, .end = 0xA951 },
+    .{ .start = 0xA953, .end = 0xA953 },
+    .{ .start = 0xA980, .end = 0xA982 },
+    .{ .start = 0xA9B3, .end = 0xA9B3 },
+    .{ .start = 0xA9B6, .end = 0xA9B9 },
+    .{ .start = 0xA9BC, .end = 0xA9BD },
+    .{ .start = 0xA9E5, .end = 0xA9E5 },
+    .{ .start = 0xAA29, .end = 0xAA2E },
+    .{ .start = 0xAA31, .end = 0xAA32 },
+    .{ .start = 0xAA35, .end = 0xAA36 },
+    .{ .start = 0xAA43, .end = 0xAA43 },
+    .{ .start = 0xAA4C, .end = 0xAA4C },
+    .{ .start = 0xAA7C, .end = 0xAA7C },
+    .{ .start = 0xAAB0, .end = 0xAAB0 },
+    .{ .start = 0xAAB2, .end = 0xAAB4 },
+    .{ .start = 0xAAB7, .end = 0xAAB8 },
+    .{ .start = 0xAABE, .end = 0xAABF },
+    .{ .start = 0xAAC1, .end = 0xAAC1 },
+    .{ .start = 0xAAEC, .end = 0xAAED },
+    .{ .start = 0xABE5, .end = 0xABE5 },
+    .{ .start = 0xABE8, .end = 0xABE8 },
+    .{ .start = 0xABED, .end = 0xABED },
+    .{ .start = 0xFB1E, .end = 0xFB1E },
+    .{ .start = 0xFE00, .end = 0xFE0F },
+    .{ .start = 0xFE20, .end = 0xFE2F },
+    .{ .start = 0xFF9E, .end = 0xFF9F },
+    .{ .start = 0x101FD, .end = 0x101FD },
+    .{ .start = 0x102E0, .end = 0x102E0 },
+    .{ .start = 0x10376, .end = 0x1037A },
+    .{ .start = 0x10A01, .end = 0x10A03 },
+    .{ .start = 0x10A05, .end = 0x10A06 },
+    .{ .start = 0x10A0C, .end = 0x10A0F },
+    .{ .start = 0x10A38, .end = 0x10A3A },
+    .{ .start = 0x10AE5, .end = 0x10AE6 },
+    .{ .start = 0x10D24, .end = 0x10D27 },
+    .{ .start = 0x10D69, .end = 0x10D6D },
+    .{ .start = 0x10EAB, .end = 0x10EAC },
+    .{ .start = 0x10EFA, .end = 0x10EFF },
+    .{ .start = 0x10F46, .end = 0x10F50 },
+    .{ .start = 0x10F82, .end = 0x10F85 },
+    .{ .start = 0x11001, .end = 0x11001 },
+    .{ .start = 0x11038, .end = 0x11046 },
+    .{ .start = 0x11070, .end = 0x11070 },
+    .{ .start = 0x11073, .end = 0x11074 },
+    .{ .start = 0x1107F, .end = 0x11081 },
+    .{ .start = 0x110B3, .end = 0x110B6 },
+    .{ .start = 0x110B9, .end = 0x110BA },
+    .{ .start = 0x110C2, .end = 0x110C2 },
+    .{ .start = 0x11100, .end = 0x11102 },
+    .{ .start = 0x11127, .end = 0x1112B },
+    .{ .start = 0x1112D, .end = 0x11132 },
+    .{ .start = 0x11134, .end = 0x11134 },
+    .{ .start = 0x11173, .end = 0x11173 },
+    .{ .start = 0x11180, .end = 0x11181 },
+    .{ .start = 0x111B6, .end = 0x111BE },
+    .{ .start = 0x111C0, .end = 0x111C0 },
+    .{ .start = 0x111C9, .end = 0x111CC },
+    .{ .start = 0x111CF, .end = 0x111CF },
+    .{ .start = 0x1122F, .end = 0x11231 },
+    .{ .start = 0x11234, .end = 0x11237 },
+    .{ .start = 0x1123E, .end = 0x1123E },
+    .{ .start = 0x11241, .end = 0x11241 },
+    .{ .start = 0x112DF, .end = 0x112DF },
+    .{ .start = 0x112E3, .end = 0x112EA },
+    .{ .start = 0x11300, .end = 0x11301 },
+    .{ .start = 0x1133B, .end = 0x1133C },
+    .{ .start = 0x1133E, .end = 0x1133E },
+    .{ .start = 0x11340, .end = 0x11340 },
+    .{ .start = 0x1134D, .end = 0x1134D },
+    .{ .start = 0x11357, .end = 0x11357 },
+    .{ .start = 0x11366, .end = 0x1136C },
+    .{ .start = 0x11370, .end = 0x11374 },
+    .{ .start = 0x113B8, .end = 0x113B8 },
+    .{ .start = 0x113BB, .end = 0x113C0 },
+    .{ .start = 0x113C2, .end = 0x113C2 },
+    .{ .start = 0x113C5, .end = 0x113C5 },
+    .{ .start = 0x113C7, .end = 0x113C9 },
+    .{ .start = 0x113CE, .end = 0x113CF },
+    .{ .start = 0x113D2, .end = 0x113D2 },
+    .{ .start = 0x113E1, .end = 0x113E2 },
+    .{ .start = 0x11438, .end = 0x1143F },
+    .{ .start = 0x11442, .end = 0x11444 },
+    .{ .start = 0x11446, .end = 0x11446 },
+    .{ .start = 0x1145E, .end = 0x1145E },
+    .{ .start = 0x114B0, .end = 0x114B0 },
+    .{ .start = 0x114B3, .end = 0x114B8 },
+    .{ .start = 0x114BA, .end = 0x114BA },
+    .{ .start = 0x114BD, .end = 0x114BD },
+    .{ .start = 0x114BF, .end = 0x114C0 },
+    .{ .start = 0x114C2, .end = 0x114C3 },
+    .{ .start = 0x115AF, .end = 0x115AF },
+    .{ .start = 0x115B2, .end = 0x115B5 },
+    .{ .start = 0x115BC, .end = 0x115BD },
+    .{ .start = 0x115BF, .end = 0x115C0 },
+    .{ .start = 0x115DC, .end = 0x115DD },
+    .{ .start = 0x11633, .end = 0x1163A },
+    .{ .start = 0x1163D, .end = 0x1163D },
+    .{ .start = 0x1163F, .end = 0x11640 },
+    .{ .start = 0x116AB, .end = 0x116AB },
+    .{ .start = 0x116AD, .end = 0x116AD },
+    .{ .start = 0x116B0, .end = 0x116B7 },
+    .{ .start = 0x1171D, .end = 0x1171D },
+    .{ .start = 0x1171F, .end = 0x1171F },
+    .{ .start = 0x11722, .end = 0x11725 },
+    .{ .start = 0x11727, .end = 0x1172B },
+    .{ .start = 0x1182F, .end = 0x11837 },
+    .{ .start = 0x11839, .end = 0x1183A },
+    .{ .start = 0x11930, .end = 0x11930 },
+    .{ .start = 0x1193B, .end = 0x1193D },
+    .{ .start = 0x11943, .end = 0x11943 },
+    .{ .start = 0x119D4, .end = 0x119D7 },
+    .{ .start = 0x119DA, .end = 0x119DB },
+    .{ .start = 0x119E0, .end = 0x119E0 },
+    .{ .start = 0x11A01, .end = 0x11A0A },
+    .{ .start = 0x11A33, .end = 0x11A38 },
+    .{ .start = 0x11A3B, .end = 0x11A3E },
+    .{ .start = 0x11A51, .end = 0x11A56 },
+    .{ .start = 0x11A59, .end = 0x11A5B },
+    .{ .start = 0x11A8A, .end = 0x11A96 },
+    .{ .start = 0x11A98, .end = 0x11A98 },
+    .{ .start = 0x11B60, .end = 0x11B60 },
+    .{ .start = 0x11B62, .end = 0x11B64 },
+    .{ .start = 0x11B66, .end = 0x11B66 },
+    .{ .start = 0x11C30, .end = 0x11C36 },
+    .{ .start = 0x11C38, .end = 0x11C3D },
+    .{ .start = 0x11C3F, .end = 0x11C3F },
+    .{ .start = 0x11C92, .end = 0x11CA7 },
+    .{ .start = 0x11CAA, .end = 0x11CB0 },
+    .{ .start = 0x11CB2, .end = 0x11CB3 },
+    .{ .start = 0x11CB5, .end = 0x11CB6 },
+    .{ .start = 0x11D31, .end = 0x11D36 },
+    .{ .start = 0x11D3A, .end = 0x11D3A },
+    .{ .start = 0x11D3C, .end = 0x11D3D },
+    .{ .start = 0x11D3F, .end = 0x11D45 },
+    .{ .start = 0x11D47, .end = 0x11D47 },
+    .{ .start = 0x11D90, .end = 0x11D91 },
+    .{ .start = 0x11D95, .end = 0x11D95 },
+    .{ .start = 0x11D97, .end = 0x11D97 },
+    .{ .start = 0x11EF3, .end = 0x11EF4 },
+    .{ .start = 0x11F00, .end = 0x11F01 },
+    .{ .start = 0x11F36, .end = 0x11F3A },
+    .{ .start = 0x11F40, .end = 0x11F41 },
+    .{ .start = 0x11F5A, .end = 0x11F5A },
+    .{ .start = 0x13440, .end = 0x13440 },
+    .{ .start = 0x13447, .end = 0x13455 },
+    .{ .start = 0x1611E, .end = 0x16129 },
+    .{ .start = 0x1612D, .end = 0x1612F },
+    .{ .start = 0x16AF0, .end = 0x16AF4 },
+    .{ .start = 0x16B30, .end = 0x16B36 },
+    .{ .start = 0x16F4F, .end = 0x16F4F },
+    .{ .start = 0x16F8F, .end = 0x16F92 },
+    .{ .start = 0x16FE4, .end = 0x16FE4 },
+    .{ .start = 0x16FF0, .end = 0x16FF1 },
+    .{ .start = 0x1BC9D, .end = 0x1BC9E },
+    .{ .start = 0x1CF00, .end = 0x1CF2D },
+    .{ .start = 0x1CF30, .end = 0x1CF46 },
+    .{ .start = 0x1D165, .end = 0x1D169 },
+    .{ .start = 0x1D16D, .end = 0x1D172 },
+    .{ .start = 0x1D17B, .end = 0x1D182 },
+    .{ .start = 0x1D185, .end = 0x1D18B },
+    .{ .start = 0x1D1AA, .end = 0x1D1AD },
+    .{ .start = 0x1D242, .end = 0x1D244 },
+    .{ .start = 0x1DA00, .end = 0x1DA36 },
+    .{ .start = 0x1DA3B, .end = 0x1DA6C },
+    .{ .start = 0x1DA75, .end = 0x1DA75 },
+    .{ .start = 0x1DA84, .end = 0x1DA84 },
+    .{ .start = 0x1DA9B, .end = 0x1DA9F },
+    .{ .start = 0x1DAA1, .end = 0x1DAAF },
+    .{ .start = 0x1E000, .end = 0x1E006 },
+    .{ .start = 0x1E008, .end = 0x1E018 },
+    .{ .start = 0x1E01B, .end = 0x1E021 },
+    .{ .start = 0x1E023, .end = 0x1E024 },
+    .{ .start = 0x1E026, .end = 0x1E02A },
+    .{ .start = 0x1E08F, .end = 0x1E08F },
+    .{ .start = 0x1E130, .end = 0x1E136 },
+    .{ .start = 0x1E2AE, .end = 0x1E2AE },
+    .{ .start = 0x1E2EC, .end = 0x1E2EF },
+    .{ .start = 0x1E4EC, .end = 0x1E4EF },
+    .{ .start = 0x1E5EE, .end = 0x1E5EF },
+    .{ .start = 0x1E6E3, .end = 0x1E6E3 },
+    .{ .start = 0x1E6E6, .end = 0x1E6E6 },
+    .{ .start = 0x1E6EE, .end = 0x1E6EF },
+    .{ .start = 0x1E6F5, .end = 0x1E6F5 },
+    .{ .start = 0x1E8D0, .end = 0x1E8D6 },
+    .{ .start = 0x1E944, .end = 0x1E94A },
+    .{ .start = 0x1F3FB, .end = 0x1F3FF },
+    .{ .start = 0xE0020, .end = 0xE007F },
+    .{ .start = 0xE0100, .end = 0xE01EF },
+};
+
+const incb_linkers = [_]u21{
+    0x94D,
+    0x9CD,
+    0xACD,
+    0xB4D,
+    0xC4D,
+    0xD4D,
+    0x1039,
+    0x17D2,
+    0x1A60,
+    0x1B44,
+    0x1BAB,
+    0xA9C0,
+    0xAAF6,
+    0x10A3F,
+    0x11133,
+    0x113D0,
+    0x1193E,
+    0x11A47,
+    0x11A99,
+    0x11F42,
+};
@@ -294,6 +294,48 @@ pub fn listMap(
     }
 }
 
+/// Calls `caller(data, state, element, new_state)` on every element in order. The state lives in
+/// two caller-provided buffers of `state_width` bytes that take turns holding the current state;
+/// the final state ends up in `state`. The list is borrowed: each element is incremented before
+/// it is handed to the function, which takes its arguments as owned.
+pub fn listWalk(
+    list: RocList,
+    caller: Caller2,
+    data: Opaque,
+    inc_n_data: IncN,
+    data_is_owned: bool,
+    inc_n_element: IncN,
+    element_width: usize,
+    state: ?[*]u8,
+    scratch: ?[*]u8,
+    state_width: usize,
+) callconv(.C) void {
+    if (list.bytes) |source_ptr| {
+        const size = list.len();
+        var i: usize = 0;
+        var current = state;
+        var next = scratch;
+
+        if (data_is_owned) {
+            inc_n_data(data, size);
+        }
+
+        while (i < size) : (i += 1) {
+            const element = source_ptr + (i * element_width);
+            inc_n_element(element, 1);
+            caller(data, current, element, next);
+
+            const previous = current;
+            current = next;
+            next = previous;
+        }
+
+        if (state_width > 0 and current != state) {
+            @memcpy(state.?[0..state_width], current.?[0..state_width]);
+        }
+    }
+}
+
 fn decrementTail(list: RocList, start_index: usize, element_width: usize, dec: Dec) void {
     if (list.bytes) |source| {
         var i = start_index;
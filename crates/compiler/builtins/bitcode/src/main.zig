@@ -69,6 +69,7 @@ comptime {
     exportListFn(list.listMap2, "map2");
     exportListFn(list.listMap3, "map3");
     exportListFn(list.listMap4, "map4");
+    exportListFn(list.listWalk, "walk");
     exportListFn(list.listAppendUnsafe, "append_unsafe");
     exportListFn(list.listReserve, "reserve");
     exportListFn(list.listPrepend, "prepend");
@@ -96,9 +97,20 @@ const FLOATS = [_]type{ f32, f64 };
 const NUMBERS = INTEGERS ++ FLOATS;
 
 comptime {
+    exportNumFn(num.shiftLeftByI128, "shift_left_by.i128");
+    exportNumFn(num.shiftLeftByU128, "shift_left_by.u128");
+    exportNumFn(num.shiftRightByI128, "shift_right_by.i128");
+    exportNumFn(num.shiftRightByU128, "shift_right_by.u128");
     exportNumFn(num.shiftRightZeroFillI128, "shift_right_zero_fill.i128");
     exportNumFn(num.shiftRightZeroFillU128, "shift_right_zero_fill.u128");
 
+    exportNumFn(num.bitwiseAndI128, "bitwise_and.i128");
+    exportNumFn(num.bitwiseAndU128, "bitwise_and.u128");
+    exportNumFn(num.bitwiseOrI128, "bitwise_or.i128");
+    exportNumFn(num.bitwiseOrU128, "bitwise_or.u128");
+    exportNumFn(num.bitwiseXorI128, "bitwise_xor.i128");
+    exportNumFn(num.bitwiseXorU128, "bitwise_xor.u128");
+
     exportNumFn(num.compareI128, "compare.i128");
     exportNumFn(num.compareU128, "compare.u128");
 
@@ -133,10 +145,12 @@ comptime {
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportAddOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_or_panic.");
         num.exportAddSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_saturated.");
+        num.exportAddWrappedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_wrapped.");
 
         num.exportSubWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_with_overflow.");
         num.exportSubOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_or_panic.");
         num.exportSubSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_saturated.");
+        num.exportSubWrappedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_wrapped.");
 
         num.exportMulWithOverflow(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
         num.exportMulOrPanic(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_or_panic.");
@@ -201,6 +215,9 @@ comptime {
     exportStrFn(str.getUnsafeC, "get_unsafe");
     exportStrFn(str.reserveC, "reserve");
     exportStrFn(str.strToUtf8C, "to_utf8");
+    exportStrFn(str.strToScalarsC, "to_scalars");
+    exportStrFn(str.strGraphemes, "graphemes");
+    exportStrFn(str.countGraphemeClusters, "count_graphemes");
     exportStrFn(str.fromUtf8C, "from_utf8");
     exportStrFn(str.repeatC, "repeat");
     exportStrFn(str.strTrim, "trim");
@@ -236,6 +253,18 @@ comptime {
     exportUtilsFn(utils.decrefCheckNullC, "decref_check_null");
     exportUtilsFn(utils.allocateWithRefcountC, "allocate_with_refcount");
     exportUtilsFn(utils.dictPseudoSeed, "dict_pseudo_seed");
+    exportUtilsFn(utils.testSeed, "test_seed");
+    exportUtilsFn(utils.leakCheckReport, "leak_check_report");
+
+    // set by the compiler in programs built with --leak-check
+    @export(utils.leak_check_enabled, .{ .name = "roc_builtins.utils.leak_check_enabled", .linkage = .Strong });
+    @export(utils.leak_check_site, .{ .name = "roc_builtins.utils.leak_check_site", .linkage = .Strong });
+
+    // lets the host make Dict hashing reproducible
+    @export(utils.setDictSeed, .{ .name = "roc_set_dict_seed", .linkage = .Weak });
+
+    // lets `roc test --seed` pick the inputs of properties
+    @export(utils.setTestSeed, .{ .name = "roc_set_test_seed", .linkage = .Weak });
 
     @export(panic_utils.panic, .{ .name = "roc_builtins.utils." ++ "panic", .linkage = .Weak });
     @export(dbg_utils.dbg_impl, .{ .name = "roc_builtins.utils." ++ "dbg_impl", .linkage = .Weak });
@@ -534,14 +534,54 @@ pub fn exportMulWrappedInt(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
-pub fn shiftRightZeroFillI128(self: i128, other: u8) callconv(.C) i128 {
+pub fn exportAddWrappedInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self +% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportSubWrappedInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self -% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn shiftLeftByI128(self: i128, other: u8) callconv(.C) i128 {
     if (other & 0b1000_0000 > 0) {
         return 0;
     } else {
-        return self >> @as(u7, @intCast(other));
+        return self << @as(u7, @intCast(other));
     }
 }
 
+pub fn shiftLeftByU128(self: u128, other: u8) callconv(.C) u128 {
+    if (other & 0b1000_0000 > 0) {
+        return 0;
+    } else {
+        return self << @as(u7, @intCast(other));
+    }
+}
+
+pub fn shiftRightByI128(self: i128, other: u8) callconv(.C) i128 {
+    // shifting by the whole width fills every bit with the sign bit
+    return self >> @as(u7, @intCast(@min(other, 127)));
+}
+
+pub fn shiftRightByU128(self: u128, other: u8) callconv(.C) u128 {
+    // Roc shifts right arithmetically even on unsigned integers
+    return @as(u128, @bitCast(shiftRightByI128(@as(i128, @bitCast(self)), other)));
+}
+
+pub fn shiftRightZeroFillI128(self: i128, other: u8) callconv(.C) i128 {
+    return @as(i128, @bitCast(shiftRightZeroFillU128(@as(u128, @bitCast(self)), other)));
+}
+
 pub fn shiftRightZeroFillU128(self: u128, other: u8) callconv(.C) u128 {
     if (other & 0b1000_0000 > 0) {
         return 0;
@@ -550,6 +590,30 @@ pub fn shiftRightZeroFillU128(self: u128, other: u8) callconv(.C) u128 {
     }
 }
 
+pub fn bitwiseAndI128(self: i128, other: i128) callconv(.C) i128 {
+    return self & other;
+}
+
+pub fn bitwiseAndU128(self: u128, other: u128) callconv(.C) u128 {
+    return self & other;
+}
+
+pub fn bitwiseOrI128(self: i128, other: i128) callconv(.C) i128 {
+    return self | other;
+}
+
+pub fn bitwiseOrU128(self: u128, other: u128) callconv(.C) u128 {
+    return self | other;
+}
+
+pub fn bitwiseXorI128(self: i128, other: i128) callconv(.C) i128 {
+    return self ^ other;
+}
+
+pub fn bitwiseXorU128(self: u128, other: u128) callconv(.C) u128 {
+    return self ^ other;
+}
+
 pub fn compareI128(self: i128, other: i128) callconv(.C) Ordering {
     if (self == other) {
         return Ordering.EQ;
@@ -1,7 +1,9 @@
 const utils = @import("utils.zig");
 const RocList = @import("list.zig").RocList;
+const GraphemeIterator = @import("grapheme.zig").GraphemeIterator;
 const UpdateMode = utils.UpdateMode;
 const std = @import("std");
+const builtin = @import("builtin");
 const mem = std.mem;
 const unicode = std.unicode;
 const testing = std.testing;
@@ -21,6 +23,14 @@ const SEAMLESS_SLICE_BIT: usize = MASK;
 const SMALL_STR_MAX_LENGTH = SMALL_STRING_SIZE - 1;
 const SMALL_STRING_SIZE = @sizeOf(RocStr);
 
+comptime {
+    // A small string stores its length in its last byte, whose high bit must also be the sign bit
+    // of `capacity_or_alloc_ptr`. That is only the case on little endian targets.
+    if (builtin.target.cpu.arch.endian() != .Little) {
+        @compileError("small strings are only supported on little endian targets");
+    }
+}
+
 fn init_blank_small_string(comptime n: usize) [n]u8 {
     var prime_list: [n]u8 = undefined;
 
@@ -1438,6 +1448,168 @@ inline fn strToBytes(arg: RocStr) RocList {
     }
 }
 
+// Str.toScalars
+pub fn strToScalarsC(string: RocStr) callconv(.C) RocList {
+    const bytes = string.asSlice();
+    const scalar_count = unicode.utf8CountCodepoints(bytes) catch unreachable;
+    const list = RocList.allocate(@alignOf(u32), scalar_count, @sizeOf(u32));
+
+    if (list.elements(u32)) |scalars| {
+        var it = unicode.Utf8View.initUnchecked(bytes).iterator();
+        var i: usize = 0;
+
+        while (it.nextCodepoint()) |codepoint| : (i += 1) {
+            scalars[i] = codepoint;
+        }
+    }
+
+    return list;
+}
+
+test "strToScalars: empty string" {
+    const list = strToScalarsC(RocStr.empty());
+
+    try expectEqual(list.len(), 0);
+}
+
+test "strToScalars: ascii and emoji" {
+    const str = RocStr.fromSlice("a🤔é");
+    defer str.decref();
+
+    const list = strToScalarsC(str);
+    defer list.decref(@alignOf(u32));
+
+    const expected = [_]u32{ 'a', 0x1F914, 0xE9 };
+    try expectEqual(list.len(), expected.len);
+    try expect(mem.eql(u32, (list.elements(u32) orelse unreachable)[0..list.len()], &expected));
+}
+
+// Str.countGraphemes
+pub fn countGraphemeClusters(string: RocStr) callconv(.C) u64 {
+    var it = GraphemeIterator.init(string.asSlice());
+    var count: u64 = 0;
+
+    while (it.next()) |_| : (count += 1) {}
+
+    return count;
+}
+
+test "countGraphemeClusters: empty string" {
+    const count = countGraphemeClusters(RocStr.empty());
+    try expectEqual(count, 0);
+}
+
+test "countGraphemeClusters: ascii characters" {
+    const str = RocStr.fromSlice("abcd");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 4);
+}
+
+test "countGraphemeClusters: utf8 characters" {
+    const str = RocStr.fromSlice("ãxā");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 3);
+}
+
+test "countGraphemeClusters: emojis, utf8, and ascii characters" {
+    const str = RocStr.fromSlice("6🤔å🤔e¥🤔çpp");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 10);
+}
+
+test "countGraphemeClusters: combining marks and CRLF" {
+    const str = RocStr.fromSlice("e\u{301}\r\n");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 2);
+}
+
+test "countGraphemeClusters: emoji sequences and flags" {
+    const str = RocStr.fromSlice("👩‍👩‍👦‍👦👍🏽🇺🇸🇨🇦");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 4);
+}
+
+test "countGraphemeClusters: hangul syllables and indic conjuncts" {
+    // ᄀ ᅡ ᆨ is one syllable, and so is क ् ष ि
+    const str = RocStr.fromSlice("\u{1100}\u{1161}\u{11A8}\u{915}\u{94D}\u{937}\u{93F}");
+    defer str.decref();
+
+    const count = countGraphemeClusters(str);
+    try expectEqual(count, 2);
+}
+
+// Str.graphemes
+pub fn strGraphemes(string: RocStr) callconv(.C) RocList {
+    const grapheme_count: usize = @intCast(countGraphemeClusters(string));
+    const list = RocList.allocate(@alignOf(RocStr), grapheme_count, @sizeOf(RocStr));
+
+    if (list.bytes) |bytes| {
+        const graphemes = @as([*]RocStr, @ptrCast(@alignCast(bytes)));
+        strGraphemesHelp(graphemes, string);
+    }
+
+    return list;
+}
+
+fn strGraphemesHelp(array: [*]RocStr, string: RocStr) void {
+    var it = GraphemeIterator.init(string.asSlice());
+
+    var i: usize = 0;
+    var offset: usize = 0;
+
+    while (it.next()) |grapheme| {
+        array[i] = substringUnsafe(string, offset, grapheme.len);
+
+        i += 1;
+        offset += grapheme.len;
+    }
+
+    // Correct refcount for all of the graphemes made.
+    string.incref(i); // i == array.len()
+}
+
+test "strGraphemesHelp: emoji, combining mark and ascii" {
+    // Str.graphemes "👩‍👩‍👦‍👦e\u(301)!" == ["👩‍👩‍👦‍👦", "e\u(301)", "!"]
+    const str = RocStr.fromSlice("👩‍👩‍👦‍👦e\u{301}!");
+
+    var array: [3]RocStr = undefined;
+    const array_ptr: [*]RocStr = &array;
+
+    strGraphemesHelp(array_ptr, str);
+
+    var expected = [3]RocStr{
+        RocStr.fromSlice("👩‍👩‍👦‍👦"),
+        RocStr.fromSlice("e\u{301}"),
+        RocStr.fromSlice("!"),
+    };
+
+    defer {
+        for (array) |roc_str| {
+            roc_str.decref();
+        }
+
+        for (expected) |roc_str| {
+            roc_str.decref();
+        }
+
+        str.decref();
+    }
+
+    try expect(array[0].eq(expected[0]));
+    try expect(array[1].eq(expected[1]));
+    try expect(array[2].eq(expected[2]));
+}
+
 const FromUtf8Result = extern struct {
     byte_index: u64,
     string: RocStr,
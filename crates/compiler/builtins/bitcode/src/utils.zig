@@ -126,20 +126,131 @@ fn testing_roc_panic(c_ptr: *anyopaque, tag_id: u32) callconv(.C) void {
 }
 
 pub fn alloc(size: usize, alignment: u32) ?[*]u8 {
-    return @as(?[*]u8, @ptrCast(roc_alloc(size, alignment)));
+    const ptr = @as(?[*]u8, @ptrCast(roc_alloc(size, alignment)));
+
+    if (leak_check_enabled) {
+        if (ptr) |tracked| leakCheckTrack(tracked, size);
+    }
+
+    return ptr;
 }
 
 pub fn realloc(c_ptr: [*]u8, new_size: usize, old_size: usize, alignment: u32) [*]u8 {
     if (DEBUG_INCDEC and builtin.target.cpu.arch != .wasm32) {
         std.debug.print("- realloc {*}\n", .{c_ptr});
     }
-    return @as([*]u8, @ptrCast(roc_realloc(c_ptr, new_size, old_size, alignment)));
+    const new_ptr = @as([*]u8, @ptrCast(roc_realloc(c_ptr, new_size, old_size, alignment)));
+
+    if (leak_check_enabled) {
+        if (leakCheckFind(c_ptr)) |index| {
+            leak_check_entries[index].ptr = new_ptr;
+            leak_check_entries[index].size = new_size;
+        }
+    }
+
+    return new_ptr;
 }
 
 pub fn dealloc(c_ptr: [*]u8, alignment: u32) void {
+    if (leak_check_enabled) {
+        leakCheckUntrack(c_ptr);
+    }
+
     return roc_dealloc(c_ptr, alignment);
 }
 
+// Leak checking, for programs built with `--leak-check`. The compiler turns on
+// `leak_check_enabled`, and before each call into the builtins points `leak_check_site` at the
+// name of the Roc function that makes the call. Every allocation is remembered along with that
+// name, and the compiler registers `leakCheckReport` to run at exit, which lists whatever was
+// never freed. An allocation that outlives the program means a missing decrement in the code
+// the refcounting pass generated for the function that made it.
+pub var leak_check_enabled: bool = false;
+pub var leak_check_site: [*:0]const u8 = "(unknown)";
+
+const LeakCheckEntry = struct {
+    ptr: [*]u8,
+    size: usize,
+    site: [*:0]const u8,
+};
+
+var leak_check_entries: [*]LeakCheckEntry = undefined;
+var leak_check_len: usize = 0;
+var leak_check_capacity: usize = 0;
+
+fn leakCheckTrack(ptr: [*]u8, size: usize) void {
+    if (leak_check_len == leak_check_capacity) {
+        const entry_size = @sizeOf(LeakCheckEntry);
+        const alignment = @alignOf(LeakCheckEntry);
+        const new_capacity = if (leak_check_capacity == 0) 64 else leak_check_capacity * 2;
+
+        // The entries are allocated with the host's allocator directly, so they aren't tracked.
+        const new_entries = if (leak_check_capacity == 0)
+            roc_alloc(new_capacity * entry_size, alignment)
+        else
+            roc_realloc(@ptrCast(leak_check_entries), new_capacity * entry_size, leak_check_capacity * entry_size, alignment);
+
+        leak_check_entries = @as([*]LeakCheckEntry, @ptrCast(@alignCast(new_entries orelse unreachable)));
+        leak_check_capacity = new_capacity;
+    }
+
+    leak_check_entries[leak_check_len] = .{ .ptr = ptr, .size = size, .site = leak_check_site };
+    leak_check_len += 1;
+}
+
+fn leakCheckFind(ptr: [*]u8) ?usize {
+    // Short-lived allocations are the most common, so start with the most recent ones.
+    var index = leak_check_len;
+    while (index > 0) {
+        index -= 1;
+        if (leak_check_entries[index].ptr == ptr) {
+            return index;
+        }
+    }
+
+    return null;
+}
+
+fn leakCheckUntrack(ptr: [*]u8) void {
+    if (leakCheckFind(ptr)) |index| {
+        leak_check_len -= 1;
+        leak_check_entries[index] = leak_check_entries[leak_check_len];
+    }
+}
+
+pub fn leakCheckReport() callconv(.C) void {
+    if (builtin.target.cpu.arch != .wasm32 and leak_check_enabled and leak_check_len > 0) {
+        var total_bytes: usize = 0;
+        for (leak_check_entries[0..leak_check_len]) |entry| {
+            total_bytes += entry.size;
+        }
+
+        std.debug.print("\nLeak check: {} Roc allocations ({} bytes) were never freed:\n\n", .{ leak_check_len, total_bytes });
+
+        // Each site's name is a single string in the binary, so sites can be told apart by address.
+        for (leak_check_entries[0..leak_check_len], 0..) |entry, index| {
+            const seen_before = for (leak_check_entries[0..index]) |earlier| {
+                if (earlier.site == entry.site) break true;
+            } else false;
+
+            if (seen_before) {
+                continue;
+            }
+
+            var count: usize = 0;
+            var bytes: usize = 0;
+            for (leak_check_entries[index..leak_check_len]) |other| {
+                if (other.site == entry.site) {
+                    count += 1;
+                    bytes += other.size;
+                }
+            }
+
+            std.debug.print("    {} allocations ({} bytes) from {s}\n", .{ count, bytes, entry.site });
+        }
+    }
+}
+
 // indirection because otherwise zig creates an alias to the panic function which our LLVM code
 // does not know how to deal with
 pub fn test_panic(c_ptr: *anyopaque, crash_tag: u32) callconv(.C) void {
@@ -518,7 +629,16 @@ test "increfC, static data" {
     try std.testing.expectEqual(mock_rc, REFCOUNT_MAX_ISIZE);
 }
 
-// This returns a compilation dependent pseudo random seed for dictionaries.
+// The seed the host chose for dictionaries with `roc_set_dict_seed`, if any.
+var dict_seed: ?u64 = null;
+
+// Makes every dictionary use the given seed, so hashing is the same on every run.
+// The host must call this before any dictionary is created, because a Dict does not store its seed.
+pub fn setDictSeed(seed: u64) callconv(.C) void {
+    dict_seed = seed;
+}
+
+// This returns the seed the host chose, or else a compilation dependent pseudo random seed for dictionaries.
 // The seed is the address of this function.
 // This avoids all roc Dicts using a known seed and being trivial to DOS.
 // Still not as secure as true random, but a lot better.
@@ -527,5 +647,34 @@ test "increfC, static data" {
 // In wasm, the value will be constant to the build as a whole.
 // Either way, it can not be know by an attacker unless they get access to the executable.
 pub fn dictPseudoSeed() callconv(.C) u64 {
+    if (dict_seed) |seed| {
+        return seed;
+    }
+
     return @as(u64, @intCast(@intFromPtr(&dictPseudoSeed)));
 }
+
+test "dictPseudoSeed, seed set by the host" {
+    setDictSeed(42);
+    defer dict_seed = null;
+
+    try std.testing.expectEqual(dictPseudoSeed(), 42);
+}
+
+// The seed `roc test --seed` picked for `Random.check`.
+var test_seed: u64 = 0;
+
+pub fn setTestSeed(seed: u64) callconv(.C) void {
+    test_seed = seed;
+}
+
+pub fn testSeed() callconv(.C) u64 {
+    return test_seed;
+}
+
+test "testSeed, seed set by roc test" {
+    setTestSeed(7);
+    defer test_seed = 0;
+
+    try std.testing.expectEqual(testSeed(), 7);
+}
@@ -57,22 +57,17 @@ false = @Bool False
 ## expect (Bool.false && Bool.false) == Bool.false
 ## ```
 ##
-## ## Performance Details
+## ## Short-circuiting
 ##
-## In Roc the `&&` and `||` work the same way as any
-## other function. However, in some languages `&&` and `||` are special-cased.
-## In these languages the compiler will skip evaluating the expression after the
-## first operator under certain circumstances. For example an expression like
-## `enablePets && likesDogs user` would compile to.
+## Unlike calling `Bool.and`, the `&&` operator only evaluates the expression on
+## its right when the one on its left is `Bool.true`. For example
+## `enablePets && likesDogs user` compiles to
 ## ```roc
 ## if enablePets then
 ##     likesDogs user
 ## else
 ##     Bool.false
 ## ```
-## Roc does not do this because conditionals like `if` and `when` have a
-## performance cost. Calling a function can sometimes be faster across the board
-## than doing an `if` to decide whether to skip calling it.
 and : Bool, Bool -> Bool
 
 ## Returns `Bool.true` when either input is a `Bool.true`. This is equivalent to
@@ -86,11 +81,11 @@ and : Bool, Bool -> Bool
 ## expect (Bool.false || Bool.false) == Bool.false
 ## ```
 ##
-## ## Performance Details
+## ## Short-circuiting
 ##
-## In Roc the `&&` and `||` work the same way as any
-## other functions. However, in some languages `&&` and `||` are special-cased.
-## Refer to the note in `Bool.and` for more detail.
+## Unlike calling `Bool.or`, the `||` operator only evaluates the expression on
+## its right when the one on its left is `Bool.false`, like `&&` does in
+## `Bool.and`.
 or : Bool, Bool -> Bool
 
 ## Returns `Bool.false` when given `Bool.true`, and vice versa. This is
@@ -0,0 +1,281 @@
+module [
+    Date,
+    Weekday,
+    fromCalendar,
+    year,
+    month,
+    day,
+    fromDaysSinceEpoch,
+    toDaysSinceEpoch,
+    weekday,
+    dayOfYear,
+    isLeapYear,
+    daysInMonth,
+    addDays,
+    addMonths,
+    addYears,
+    daysBetween,
+    compare,
+    toIsoStr,
+    fromIsoStr,
+]
+
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import List
+import Str
+import Num exposing [U8, U64, I64]
+import Hash exposing [Hash]
+
+## A day in the Gregorian calendar, like 2024-02-29, without a time of day or a time zone.
+##
+## Dates before the Gregorian calendar was introduced use it anyway (the proleptic Gregorian
+## calendar, like ISO 8601), and the year before 1 is 0.
+Date := { year : I64, month : U8, day : U8 } implements [Eq, Hash]
+
+Weekday : [Mon, Tue, Wed, Thu, Fri, Sat, Sun]
+
+## The date with the given year, month from 1 to 12, and day of the month from 1, if it exists.
+## ```roc
+## expect Date.fromCalendar 2024 2 29 |> Result.isOk
+## expect Date.fromCalendar 2023 2 29 == Err InvalidDate
+## ```
+fromCalendar : I64, U8, U8 -> Result Date [InvalidDate]
+fromCalendar = \y, m, d ->
+    if m >= 1 && m <= 12 && d >= 1 && d <= daysInMonth y m then
+        Ok (@Date { year: y, month: m, day: d })
+    else
+        Err InvalidDate
+
+year : Date -> I64
+year = \@Date date -> date.year
+
+## From 1 for January to 12 for December.
+month : Date -> U8
+month = \@Date date -> date.month
+
+## The day of the month, from 1.
+day : Date -> U8
+day = \@Date date -> date.day
+
+## The date the given number of days after 1970-01-01, or before it when the number is negative.
+## ```roc
+## expect Date.fromDaysSinceEpoch 0 |> Date.toIsoStr == "1970-01-01"
+## ```
+fromDaysSinceEpoch : I64 -> Date
+fromDaysSinceEpoch = \days ->
+    # Counts from 0000-03-01, so that leap days come at the end of each year, and each 400 years
+    # (an era) has the same number of days. See https://howardhinnant.github.io/date_algorithms.html
+    shifted = days + 719_468
+    era = floorDiv shifted 146_097
+    dayOfEra = shifted - era * 146_097
+    yearOfEra = (dayOfEra - dayOfEra // 1_460 + dayOfEra // 36_524 - dayOfEra // 146_096) // 365
+    dayOfShiftedYear = dayOfEra - (365 * yearOfEra + yearOfEra // 4 - yearOfEra // 100)
+    shiftedMonth = (5 * dayOfShiftedYear + 2) // 153
+    m = if shiftedMonth < 10 then shiftedMonth + 3 else shiftedMonth - 9
+    y = yearOfEra + era * 400 + (if m <= 2 then 1 else 0)
+
+    @Date {
+        year: y,
+        month: Num.toU8 m,
+        day: Num.toU8 (dayOfShiftedYear - (153 * shiftedMonth + 2) // 5 + 1),
+    }
+
+## The number of days from 1970-01-01 to the date, which is negative for earlier dates.
+toDaysSinceEpoch : Date -> I64
+toDaysSinceEpoch = \@Date { year: y, month: m, day: d } ->
+    shiftedYear = if m <= 2 then y - 1 else y
+    era = floorDiv shiftedYear 400
+    yearOfEra = shiftedYear - era * 400
+    shiftedMonth = (Num.toI64 m + 9) % 12
+    dayOfShiftedYear = (153 * shiftedMonth + 2) // 5 + Num.toI64 d - 1
+    dayOfEra = yearOfEra * 365 + yearOfEra // 4 - yearOfEra // 100 + dayOfShiftedYear
+
+    era * 146_097 + dayOfEra - 719_468
+
+## ```roc
+## expect Date.fromCalendar 2024 1 1 |> Result.map Date.weekday == Ok Mon
+## ```
+weekday : Date -> Weekday
+weekday = \date ->
+    # 1970-01-01 was a Thursday.
+    when floorMod (toDaysSinceEpoch date + 3) 7 is
+        0 -> Mon
+        1 -> Tue
+        2 -> Wed
+        3 -> Thu
+        4 -> Fri
+        5 -> Sat
+        _ -> Sun
+
+## The day of the year, from 1 for January 1st to 365, or 366 in leap years.
+dayOfYear : Date -> U64
+dayOfYear = \@Date date ->
+    startOfYear = toDaysSinceEpoch (@Date { year: date.year, month: 1, day: 1 })
+
+    Num.toU64 (toDaysSinceEpoch (@Date date) - startOfYear + 1)
+
+## Whether the year has a February 29th: years divisible by 4, except the ones divisible by 100
+## but not by 400.
+isLeapYear : I64 -> Bool
+isLeapYear = \y ->
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+
+## The number of days in the given month of the given year, or 0 if the month isn't from 1 to 12.
+daysInMonth : I64, U8 -> U8
+daysInMonth = \y, m ->
+    when m is
+        1 | 3 | 5 | 7 | 8 | 10 | 12 -> 31
+        4 | 6 | 9 | 11 -> 30
+        2 -> if isLeapYear y then 29 else 28
+        _ -> 0
+
+## Adds a number of days, which can be negative.
+## ```roc
+## expect
+##     Date.fromCalendar 2024 2 28
+##     |> Result.map \date -> Date.addDays date 2
+##     == Date.fromCalendar 2024 3 1
+## ```
+addDays : Date, I64 -> Date
+addDays = \date, days ->
+    fromDaysSinceEpoch (toDaysSinceEpoch date + days)
+
+## Adds a number of months, which can be negative. When the day doesn't exist in the new month,
+## this picks the last day of that month instead.
+## ```roc
+## expect
+##     Date.fromCalendar 2024 1 31
+##     |> Result.map \date -> Date.addMonths date 1
+##     == Date.fromCalendar 2024 2 29
+## ```
+addMonths : Date, I64 -> Date
+addMonths = \@Date date, months ->
+    monthIndex = date.year * 12 + Num.toI64 date.month - 1 + months
+    y = floorDiv monthIndex 12
+    m = Num.toU8 (floorMod monthIndex 12 + 1)
+
+    @Date { year: y, month: m, day: Num.min date.day (daysInMonth y m) }
+
+## Adds a number of years, which can be negative. February 29th becomes February 28th in years
+## that aren't leap years.
+addYears : Date, I64 -> Date
+addYears = \date, years ->
+    addMonths date (years * 12)
+
+## The number of days from the first date to the second, which is negative when the second date
+## comes first.
+daysBetween : Date, Date -> I64
+daysBetween = \from, to ->
+    toDaysSinceEpoch to - toDaysSinceEpoch from
+
+## Whether the first date comes before (`LT`), on (`EQ`), or after (`GT`) the second, for sorting
+## with `List.sortWith`.
+compare : Date, Date -> [LT, EQ, GT]
+compare = \@Date a, @Date b ->
+    when Num.compare a.year b.year is
+        EQ ->
+            when Num.compare a.month b.month is
+                EQ -> Num.compare a.day b.day
+                order -> order
+
+        order -> order
+
+## Formats the date like `2024-02-29`. Years before 0 or after 9999 start with `-` or `+`, like
+## `+10000-01-01`, as ISO 8601 allows.
+toIsoStr : Date -> Str
+toIsoStr = \@Date date ->
+    yearStr =
+        if date.year < 0 then
+            "-$(padDigits (Num.toStr -date.year) 4)"
+        else if date.year > 9999 then
+            "+$(Num.toStr date.year)"
+        else
+            padDigits (Num.toStr date.year) 4
+
+    "$(yearStr)-$(padDigits (Num.toStr date.month) 2)-$(padDigits (Num.toStr date.day) 2)"
+
+padDigits : Str, U64 -> Str
+padDigits = \digits, width ->
+    Str.concat (Str.repeat "0" (width - Num.min width (Str.countUtf8Bytes digits))) digits
+
+## Parses a date like `2024-02-29`, the way [toIsoStr] formats it. The year can also start with
+## `+` or `-`, and then have more than 4 digits.
+## ```roc
+## expect Date.fromIsoStr "2024-02-29" |> Result.map Date.dayOfYear == Ok 60
+## expect Date.fromIsoStr "2023-02-29" == Err InvalidDateStr
+## ```
+fromIsoStr : Str -> Result Date [InvalidDateStr]
+fromIsoStr = \str ->
+    when Str.toUtf8 str is
+        ['-', .. as rest] -> parseIso rest Bool.true
+        ['+', .. as rest] -> parseIso rest Bool.false
+        bytes if countDigits bytes == 4 -> parseIso bytes Bool.false
+        _ -> Err InvalidDateStr
+
+# Parses the date after the sign of the year, which needs at least 4 digits. Years with more than
+# 18 digits would be too large for an I64.
+parseIso : List U8, Bool -> Result Date [InvalidDateStr]
+parseIso = \bytes, isNegative ->
+    yearDigits = countDigits bytes
+    hasYear = yearDigits >= 4 && yearDigits <= 18
+
+    when List.dropFirst bytes yearDigits is
+        ['-', m1, m2, '-', d1, d2] if hasYear && List.all [m1, m2, d1, d2] isDigit ->
+            absYear = digitsToNum (List.takeFirst bytes yearDigits)
+            y = if isNegative then -absYear else absYear
+
+            fromCalendar y (Num.toU8 (digitsToNum [m1, m2])) (Num.toU8 (digitsToNum [d1, d2]))
+            |> Result.mapErr \InvalidDate -> InvalidDateStr
+
+        _ -> Err InvalidDateStr
+
+isDigit : U8 -> Bool
+isDigit = \byte -> byte >= '0' && byte <= '9'
+
+countDigits : List U8 -> U64
+countDigits = \bytes ->
+    List.walkUntil bytes 0 \count, byte ->
+        if isDigit byte then Continue (count + 1) else Break count
+
+digitsToNum : List U8 -> I64
+digitsToNum = \digits ->
+    List.walk digits 0 \num, digit -> num * 10 + Num.toI64 (digit - '0')
+
+# Division that rounds toward negative infinity, so days before 1970 still belong to the right
+# year and week.
+floorDiv : I64, I64 -> I64
+floorDiv = \a, b ->
+    quotient = a // b
+
+    if a % b != 0 && (a < 0) != (b < 0) then quotient - 1 else quotient
+
+floorMod : I64, I64 -> I64
+floorMod = \a, b -> a - floorDiv a b * b
+
+expect fromDaysSinceEpoch 19_782 == @Date { year: 2024, month: 2, day: 29 }
+expect toDaysSinceEpoch (@Date { year: 1969, month: 12, day: 31 }) == -1
+expect toDaysSinceEpoch (@Date { year: 0, month: 3, day: 1 }) == -719_468
+expect weekday (@Date { year: 1969, month: 12, day: 29 }) == Mon
+expect dayOfYear (@Date { year: 2024, month: 12, day: 31 }) == 366
+expect toIsoStr (@Date { year: -1, month: 1, day: 5 }) == "-0001-01-05"
+expect toIsoStr (@Date { year: 12_345, month: 6, day: 7 }) == "+12345-06-07"
+expect fromIsoStr "-0001-01-05" == Ok (@Date { year: -1, month: 1, day: 5 })
+expect fromIsoStr "+12345-06-07" == Ok (@Date { year: 12_345, month: 6, day: 7 })
+expect fromIsoStr "12345-06-07" == Err InvalidDateStr
+expect fromIsoStr "2024-2-29" == Err InvalidDateStr
+expect fromIsoStr "2024-13-01" == Err InvalidDateStr
+
+expect
+    date = addMonths (@Date { year: 2024, month: 3, day: 31 }) -13
+
+    date == @Date { year: 2023, month: 2, day: 28 }
+
+expect
+    date = addYears (@Date { year: 2024, month: 2, day: 29 }) 1
+
+    date == @Date { year: 2025, month: 2, day: 28 }
+
+expect
+    List.range { start: At -800_000, end: At 800_000, step: 97 }
+    |> List.all \days -> toDaysSinceEpoch (fromDaysSinceEpoch days) == days
@@ -118,11 +118,8 @@ isEq = \xs, ys ->
     else
         walkUntil xs Bool.true \_, k, xVal ->
             when get ys k is
-                Ok yVal if yVal == xVal ->
-                    Continue Bool.true
-
-                _ ->
-                    Break Bool.false
+                Ok yVal if yVal == xVal -> Continue Bool.true
+                _ -> Break Bool.false
 
 hashDict : hasher, Dict k v -> hasher where v implements Hash, hasher implements Hasher
 hashDict = \hasher, dict -> Hash.hashUnordered hasher (toList dict) List.walk
@@ -462,7 +459,10 @@ insertHelper = \buckets0, data0, bucketIndex0, distAndFingerprint0, key, value,
     else if distAndFingerprint0 > loaded.distAndFingerprint then
         data1 = List.append data0 (key, value)
         dataIndex = (List.len data1) |> Num.subWrap 1
-        buckets1 = placeAndShiftUp buckets0 { distAndFingerprint: distAndFingerprint0, dataIndex: Num.toU32 dataIndex } bucketIndex0
+        buckets1 = placeAndShiftUp buckets0 {
+            distAndFingerprint: distAndFingerprint0,
+            dataIndex: Num.toU32 dataIndex,
+        } bucketIndex0
         @Dict { buckets: buckets1, data: data1, maxBucketCapacity, maxLoadFactor, shifts }
     else
         bucketIndex1 = nextBucketIndex bucketIndex0 (List.len buckets0)
@@ -521,7 +521,13 @@ removeHelper = \buckets, bucketIndex, distAndFingerprint, data, key ->
 ## ```
 update : Dict k v, k, ([Present v, Missing] -> [Present v, Missing]) -> Dict k v
 update = \@Dict { buckets, data, maxBucketCapacity, maxLoadFactor, shifts }, key, alter ->
-    { bucketIndex, result } = find (@Dict { buckets, data, maxBucketCapacity, maxLoadFactor, shifts }) key
+    { bucketIndex, result } = find (@Dict {
+        buckets,
+        data,
+        maxBucketCapacity,
+        maxLoadFactor,
+        shifts,
+    }) key
     when result is
         Ok value ->
             when alter (Present value) is
@@ -556,8 +562,7 @@ update = \@Dict { buckets, data, maxBucketCapacity, maxLoadFactor, shifts }, key
                             distAndFingerprint = incrementDistN baseDistAndFingerprint (Num.toU32 dist)
                             insertHelper buckets data bucketIndex distAndFingerprint key newValue maxBucketCapacity maxLoadFactor shifts
 
-                Missing ->
-                    @Dict { buckets, data, maxBucketCapacity, maxLoadFactor, shifts }
+                Missing -> @Dict { buckets, data, maxBucketCapacity, maxLoadFactor, shifts }
 
 circularDist = \start, end, size ->
     correction =
@@ -678,11 +683,8 @@ keepShared = \xs0, ys0 ->
         (withCapacity (len xs1))
         (\state, k, v ->
             when get ys1 k is
-                Ok yv if v == yv ->
-                    insert state k v
-
-                _ ->
-                    state
+                Ok yv if v == yv -> insert state k v
+                _ -> state
         )
 
 ## Remove the key-value pairs in the first input that are also in the second
@@ -747,7 +749,10 @@ find = \@Dict { buckets, data, shifts }, key ->
 
 findManualUnrolls = 2
 
-findFirstUnroll : List Bucket, U64, U32, List (k, v), k -> { bucketIndex : U64, result : Result v [KeyNotFound] } where k implements Eq
+findFirstUnroll : List Bucket, U64, U32, List (k, v), k -> {
+    bucketIndex : U64,
+    result : Result v [KeyNotFound],
+} where k implements Eq
 findFirstUnroll = \buckets, bucketIndex, distAndFingerprint, data, key ->
     # TODO: once we have short circuit evaluation, use it here and other similar locations in this file.
     # Avoid the nested if with else block inconvenience.
@@ -761,7 +766,10 @@ findFirstUnroll = \buckets, bucketIndex, distAndFingerprint, data, key ->
     else
         findSecondUnroll buckets (nextBucketIndex bucketIndex (List.len buckets)) (incrementDist distAndFingerprint) data key
 
-findSecondUnroll : List Bucket, U64, U32, List (k, v), k -> { bucketIndex : U64, result : Result v [KeyNotFound] } where k implements Eq
+findSecondUnroll : List Bucket, U64, U32, List (k, v), k -> {
+    bucketIndex : U64,
+    result : Result v [KeyNotFound],
+} where k implements Eq
 findSecondUnroll = \buckets, bucketIndex, distAndFingerprint, data, key ->
     bucket = listGetUnsafe buckets bucketIndex
     if distAndFingerprint == bucket.distAndFingerprint then
@@ -773,7 +781,10 @@ findSecondUnroll = \buckets, bucketIndex, distAndFingerprint, data, key ->
     else
         findHelper buckets (nextBucketIndex bucketIndex (List.len buckets)) (incrementDist distAndFingerprint) data key
 
-findHelper : List Bucket, U64, U32, List (k, v), k -> { bucketIndex : U64, result : Result v [KeyNotFound] } where k implements Eq
+findHelper : List Bucket, U64, U32, List (k, v), k -> {
+    bucketIndex : U64,
+    result : Result v [KeyNotFound],
+} where k implements Eq
 findHelper = \buckets, bucketIndex, distAndFingerprint, data, key ->
     bucket = listGetUnsafe buckets bucketIndex
     if distAndFingerprint == bucket.distAndFingerprint then
@@ -837,7 +848,9 @@ removeBucketHelper = \buckets, bucketIndex ->
     nextBucket = listGetUnsafe buckets nextIndex
     # shift down until either empty or an element with correct spot is found
     if nextBucket.distAndFingerprint >= Num.mulWrap distInc 2 then
-        List.set buckets bucketIndex { nextBucket & distAndFingerprint: decrementDist nextBucket.distAndFingerprint }
+        List.set buckets bucketIndex { nextBucket &
+            distAndFingerprint: decrementDist nextBucket.distAndFingerprint,
+        }
         |> removeBucketHelper nextIndex
     else
         (buckets, bucketIndex)
@@ -1250,6 +1263,7 @@ LowLevelHasher := { initializedSeed : U64, state : U64 } implements [
 
 # Returns a application specific pseudo random seed for Dict.
 # This avoids trivial DOS attacks.
+# Hosts can pick the seed with `roc_set_dict_seed`, and tests always use the same one.
 pseudoSeed : {} -> U64
 
 createLowLevelHasher : [PseudoRandSeed, WithSeed U64] -> LowLevelHasher
@@ -1298,7 +1312,12 @@ addU8 = \@LowLevelHasher { initializedSeed, state }, u8 ->
         |> Num.bitwiseOr p0
     b = 0
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a, b, seed: initializedSeed, length: 1 }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a,
+        b,
+        seed: initializedSeed,
+        length: 1,
+    }
 
 addU16 = \@LowLevelHasher { initializedSeed, state }, u16 ->
     p0 = Num.bitwiseAnd u16 0xFF |> Num.toU64
@@ -1309,13 +1328,23 @@ addU16 = \@LowLevelHasher { initializedSeed, state }, u16 ->
         |> Num.bitwiseOr p1
     b = 0
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a, b, seed: initializedSeed, length: 2 }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a,
+        b,
+        seed: initializedSeed,
+        length: 2,
+    }
 
 addU32 = \@LowLevelHasher { initializedSeed, state }, u32 ->
     p0 = Num.toU64 u32
     a = Num.shiftLeftBy p0 32 |> Num.bitwiseOr p0
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a, b: a, seed: initializedSeed, length: 4 }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a,
+        b: a,
+        seed: initializedSeed,
+        length: 4,
+    }
 
 addU64 = \@LowLevelHasher { initializedSeed, state }, u64 ->
     p0 = Num.bitwiseAnd 0xFFFF_FFFF u64
@@ -1323,7 +1352,12 @@ addU64 = \@LowLevelHasher { initializedSeed, state }, u64 ->
     a = Num.shiftLeftBy p0 32 |> Num.bitwiseOr p1
     b = Num.shiftLeftBy p1 32 |> Num.bitwiseOr p0
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a, b, seed: initializedSeed, length: 8 }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a,
+        b,
+        seed: initializedSeed,
+        length: 8,
+    }
 
 addU128 = \@LowLevelHasher { initializedSeed, state }, u128 ->
     lower = u128 |> Num.toU64
@@ -1335,7 +1369,12 @@ addU128 = \@LowLevelHasher { initializedSeed, state }, u128 ->
     a = Num.shiftLeftBy p0 32 |> Num.bitwiseOr p2
     b = Num.shiftLeftBy p3 32 |> Num.bitwiseOr p1
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a, b, seed: initializedSeed, length: 16 }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a,
+        b,
+        seed: initializedSeed,
+        length: 16,
+    }
 
 addBytes : LowLevelHasher, List U8 -> LowLevelHasher
 addBytes = \@LowLevelHasher { initializedSeed, state }, list ->
@@ -1359,7 +1398,12 @@ addBytes = \@LowLevelHasher { initializedSeed, state }, list ->
         else
             hashBytesHelper48 initializedSeed initializedSeed initializedSeed list 0 length
 
-    combineState (@LowLevelHasher { initializedSeed, state }) { a: abs.a, b: abs.b, seed: abs.seed, length }
+    combineState (@LowLevelHasher { initializedSeed, state }) {
+        a: abs.a,
+        b: abs.b,
+        seed: abs.seed,
+        length,
+    }
 
 hashBytesHelper48 : U64, U64, U64, List U8, U64, U64 -> { a : U64, b : U64, seed : U64 }
 hashBytesHelper48 = \seed, see1, see2, list, index, remaining ->
@@ -1378,7 +1422,11 @@ hashBytesHelper48 = \seed, see1, see2, list, index, remaining ->
     else
         finalSeed = Num.bitwiseXor newSee2 (Num.bitwiseXor newSee1 newSeed)
 
-        { a: wyr8 list (Num.subWrap newRemaining 16 |> Num.addWrap newIndex), b: wyr8 list (Num.subWrap newRemaining 8 |> Num.addWrap newIndex), seed: finalSeed }
+        {
+            a: wyr8 list (Num.subWrap newRemaining 16 |> Num.addWrap newIndex),
+            b: wyr8 list (Num.subWrap newRemaining 8 |> Num.addWrap newIndex),
+            seed: finalSeed,
+        }
 
 hashBytesHelper16 : U64, List U8, U64, U64 -> { a : U64, b : U64, seed : U64 }
 hashBytesHelper16 = \seed, list, index, remaining ->
@@ -1387,7 +1435,11 @@ hashBytesHelper16 = \seed, list, index, remaining ->
     newIndex = Num.addWrap index 16
 
     if newRemaining <= 16 then
-        { a: wyr8 list (Num.subWrap newRemaining 16 |> Num.addWrap newIndex), b: wyr8 list (Num.subWrap newRemaining 8 |> Num.addWrap newIndex), seed: newSeed }
+        {
+            a: wyr8 list (Num.subWrap newRemaining 16 |> Num.addWrap newIndex),
+            b: wyr8 list (Num.subWrap newRemaining 8 |> Num.addWrap newIndex),
+            seed: newSeed,
+        }
     else
         hashBytesHelper16 newSeed list newIndex newRemaining
 
@@ -1557,7 +1609,24 @@ expect
 expect
     hash =
         createLowLevelHasher testSeed
-        |> addBytes [0x5D, 0x66, 0xB1, 0x8F, 0x68, 0x44, 0xC7, 0x03, 0xE1, 0xDD, 0x23, 0x34, 0xBB, 0x9A, 0x42, 0xA7]
+        |> addBytes [
+            0x5D,
+            0x66,
+            0xB1,
+            0x8F,
+            0x68,
+            0x44,
+            0xC7,
+            0x03,
+            0xE1,
+            0xDD,
+            0x23,
+            0x34,
+            0xBB,
+            0x9A,
+            0x42,
+            0xA7,
+        ]
         |> complete
 
     hash == 0xDD39A206AED64C73
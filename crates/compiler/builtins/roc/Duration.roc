@@ -0,0 +1,299 @@
+module [
+    Duration,
+    zero,
+    fromNanoseconds,
+    fromMicroseconds,
+    fromMilliseconds,
+    fromSeconds,
+    fromMinutes,
+    fromHours,
+    fromDays,
+    toNanoseconds,
+    toMicroseconds,
+    toMilliseconds,
+    toSeconds,
+    toMinutes,
+    toHours,
+    toDays,
+    add,
+    sub,
+    negate,
+    abs,
+    isNegative,
+    compare,
+    toIsoStr,
+    fromIsoStr,
+]
+
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import List
+import Str
+import Num exposing [U8, U64, I128]
+import Hash exposing [Hash]
+
+## A length of time, like 90 minutes, with nanosecond precision. Durations can be negative, like
+## the time from a later `Time` back to an earlier one.
+##
+## Every day of a duration is exactly 24 hours long. Months and years don't have a fixed length,
+## so adding them is up to `Date`.
+Duration := I128 implements [Eq, Hash]
+
+nanosPerMicrosecond : I128
+nanosPerMicrosecond = 1_000
+
+nanosPerMillisecond : I128
+nanosPerMillisecond = 1_000_000
+
+nanosPerSecond : I128
+nanosPerSecond = 1_000_000_000
+
+nanosPerMinute : I128
+nanosPerMinute = 60 * nanosPerSecond
+
+nanosPerHour : I128
+nanosPerHour = 60 * nanosPerMinute
+
+nanosPerDay : I128
+nanosPerDay = 24 * nanosPerHour
+
+## A duration of no time at all.
+zero : Duration
+zero = @Duration 0
+
+## ```roc
+## expect Duration.fromNanoseconds 1_500_000_000 == Duration.fromMilliseconds 1_500
+## ```
+fromNanoseconds : I128 -> Duration
+fromNanoseconds = \nanos -> @Duration nanos
+
+fromMicroseconds : I128 -> Duration
+fromMicroseconds = \micros -> @Duration (micros * nanosPerMicrosecond)
+
+fromMilliseconds : I128 -> Duration
+fromMilliseconds = \millis -> @Duration (millis * nanosPerMillisecond)
+
+fromSeconds : I128 -> Duration
+fromSeconds = \seconds -> @Duration (seconds * nanosPerSecond)
+
+fromMinutes : I128 -> Duration
+fromMinutes = \minutes -> @Duration (minutes * nanosPerMinute)
+
+fromHours : I128 -> Duration
+fromHours = \hours -> @Duration (hours * nanosPerHour)
+
+## A duration of whole days, each 24 hours long.
+fromDays : I128 -> Duration
+fromDays = \days -> @Duration (days * nanosPerDay)
+
+toNanoseconds : Duration -> I128
+toNanoseconds = \@Duration nanos -> nanos
+
+## The number of whole microseconds in the duration, rounded toward zero.
+toMicroseconds : Duration -> I128
+toMicroseconds = \@Duration nanos -> Num.divTrunc nanos nanosPerMicrosecond
+
+## The number of whole milliseconds in the duration, rounded toward zero.
+toMilliseconds : Duration -> I128
+toMilliseconds = \@Duration nanos -> Num.divTrunc nanos nanosPerMillisecond
+
+## The number of whole seconds in the duration, rounded toward zero.
+## ```roc
+## expect Duration.toSeconds (Duration.fromMilliseconds -1_500) == -1
+## ```
+toSeconds : Duration -> I128
+toSeconds = \@Duration nanos -> Num.divTrunc nanos nanosPerSecond
+
+## The number of whole minutes in the duration, rounded toward zero.
+toMinutes : Duration -> I128
+toMinutes = \@Duration nanos -> Num.divTrunc nanos nanosPerMinute
+
+## The number of whole hours in the duration, rounded toward zero.
+toHours : Duration -> I128
+toHours = \@Duration nanos -> Num.divTrunc nanos nanosPerHour
+
+## The number of whole 24-hour days in the duration, rounded toward zero.
+toDays : Duration -> I128
+toDays = \@Duration nanos -> Num.divTrunc nanos nanosPerDay
+
+## ```roc
+## expect Duration.add (Duration.fromHours 1) (Duration.fromMinutes 30) == Duration.fromMinutes 90
+## ```
+add : Duration, Duration -> Duration
+add = \@Duration a, @Duration b -> @Duration (a + b)
+
+## Subtracts the second duration from the first.
+sub : Duration, Duration -> Duration
+sub = \@Duration a, @Duration b -> @Duration (a - b)
+
+negate : Duration -> Duration
+negate = \@Duration nanos -> @Duration -nanos
+
+## The length of the duration, without its sign.
+abs : Duration -> Duration
+abs = \@Duration nanos -> @Duration (Num.abs nanos)
+
+isNegative : Duration -> Bool
+isNegative = \@Duration nanos -> nanos < 0
+
+## Whether the first duration is shorter (`LT`), equal (`EQ`), or longer (`GT`) than the second,
+## for sorting with `List.sortWith`.
+compare : Duration, Duration -> [LT, EQ, GT]
+compare = \@Duration a, @Duration b -> Num.compare a b
+
+## Formats the duration like the ISO 8601 duration `P1DT2H30M15.5S`, leaving out the parts that
+## are 0. A negative duration starts with `-`.
+## ```roc
+## expect Duration.toIsoStr (Duration.fromMinutes 90) == "PT1H30M"
+## ```
+toIsoStr : Duration -> Str
+toIsoStr = \@Duration nanos ->
+    sign = if nanos < 0 then "-" else ""
+    length = Num.abs nanos
+    days = length // nanosPerDay
+    hours = (length % nanosPerDay) // nanosPerHour
+    minutes = (length % nanosPerHour) // nanosPerMinute
+    seconds = (length % nanosPerMinute) // nanosPerSecond
+    fraction = length % nanosPerSecond
+
+    datePart = if days == 0 then "" else "$(Num.toStr days)D"
+    timePart =
+        [
+            if hours == 0 then "" else "$(Num.toStr hours)H",
+            if minutes == 0 then "" else "$(Num.toStr minutes)M",
+            if seconds == 0 && fraction == 0 then
+                ""
+            else
+                "$(Num.toStr seconds)$(fractionToStr fraction)S",
+        ]
+        |> Str.joinWith ""
+
+    if length == 0 then
+        "PT0S"
+    else if timePart == "" then
+        "$(sign)P$(datePart)"
+    else
+        "$(sign)P$(datePart)T$(timePart)"
+
+# The digits after the decimal point of a number of nanoseconds below a second, without trailing
+# zeros, or nothing when it's 0.
+fractionToStr : I128 -> Str
+fractionToStr = \nanos ->
+    if nanos == 0 then "" else fractionToStrHelp nanos 9
+
+fractionToStrHelp : I128, U64 -> Str
+fractionToStrHelp = \fraction, width ->
+    if fraction % 10 == 0 then
+        fractionToStrHelp (fraction // 10) (width - 1)
+    else
+        digits = Num.toStr fraction
+
+        ".$(Str.repeat "0" (width - Str.countUtf8Bytes digits))$(digits)"
+
+## Parses an ISO 8601 duration like `P1DT2H30M15.5S` or `PT90M`, with an optional `-` or `+` in
+## front. Weeks count as 7 days. Years and months aren't accepted, because they don't have a fixed
+## length.
+## ```roc
+## expect Duration.fromIsoStr "PT1H30M" == Ok (Duration.fromMinutes 90)
+## expect Duration.fromIsoStr "P1M" == Err InvalidDurationStr
+## ```
+fromIsoStr : Str -> Result Duration [InvalidDurationStr]
+fromIsoStr = \str ->
+    when Str.toUtf8 str is
+        ['-', 'P', .. as rest] -> parseIso rest 0 0 |> Result.map \nanos -> @Duration -nanos
+        ['+', 'P', .. as rest] | ['P', .. as rest] ->
+            parseIso rest 0 0 |> Result.map \nanos -> @Duration nanos
+
+        _ -> Err InvalidDurationStr
+
+# Parses what comes after the `P`, where each part is a number followed by its unit. The rank is
+# that of the previous part's unit, or 3 right after the `T`.
+parseIso : List U8, U8, I128 -> Result I128 [InvalidDurationStr]
+parseIso = \bytes, rank, nanos ->
+    when bytes is
+        [] -> if rank == 0 then Err InvalidDurationStr else Ok nanos
+        ['T', _, ..] if rank < 3 -> parseIso (List.dropFirst bytes 1) 3 nanos
+        _ ->
+            when parseNumber bytes is
+                Ok { count, fraction, rest } ->
+                    when (rest, fraction) is
+                        ([unit, .. as afterUnit], Whole) ->
+                            unitAfter unit rank
+                            |> Result.try \next -> parseIso afterUnit next.rank (nanos + count * next.nanos)
+
+                        ([unit, .. as afterUnit], Fraction fractionNanos) ->
+                            # Only seconds can have a fraction, so a duration is always whole nanoseconds.
+                            when unitAfter unit rank is
+                                Ok next if next.rank == 6 ->
+                                    parseIso afterUnit next.rank (nanos + count * next.nanos + fractionNanos)
+
+                                _ -> Err InvalidDurationStr
+
+                        _ -> Err InvalidDurationStr
+
+                Err InvalidDurationStr -> Err InvalidDurationStr
+
+# The rank and length of a unit that can come after one with the given rank. Weeks and days come
+# before the `T`, and hours, minutes, and seconds after it, each in that order.
+unitAfter : U8, U8 -> Result { rank : U8, nanos : I128 } [InvalidDurationStr]
+unitAfter = \unit, rank ->
+    when unit is
+        'W' if rank < 1 -> Ok { rank: 1, nanos: 7 * nanosPerDay }
+        'D' if rank < 2 -> Ok { rank: 2, nanos: nanosPerDay }
+        'H' if rank == 3 -> Ok { rank: 4, nanos: nanosPerHour }
+        'M' if rank == 3 || rank == 4 -> Ok { rank: 5, nanos: nanosPerMinute }
+        'S' if rank >= 3 && rank < 6 -> Ok { rank: 6, nanos: nanosPerSecond }
+        _ -> Err InvalidDurationStr
+
+# A number like `15` or `15.5`, where the fraction is in nanoseconds, and the bytes after it.
+Number : { count : I128, fraction : [Whole, Fraction I128], rest : List U8 }
+
+parseNumber : List U8 -> Result Number [InvalidDurationStr]
+parseNumber = \bytes ->
+    wholeDigits = countDigits bytes
+    count = digitsToNum (List.takeFirst bytes wholeDigits)
+
+    when List.dropFirst bytes wholeDigits is
+        _ if wholeDigits == 0 -> Err InvalidDurationStr
+        ['.', .. as afterPoint] | [',', .. as afterPoint] ->
+            fractionDigits = countDigits afterPoint
+
+            if fractionDigits == 0 || fractionDigits > 9 then
+                Err InvalidDurationStr
+            else
+                scale = Num.powInt 10 (Num.toI128 (9 - fractionDigits))
+                fraction = digitsToNum (List.takeFirst afterPoint fractionDigits) * scale
+
+                Ok {
+                    count,
+                    fraction: Fraction fraction,
+                    rest: List.dropFirst afterPoint fractionDigits,
+                }
+
+        rest -> Ok { count, fraction: Whole, rest }
+
+countDigits : List U8 -> U64
+countDigits = \bytes ->
+    List.walkUntil bytes 0 \count, byte ->
+        if byte >= '0' && byte <= '9' then Continue (count + 1) else Break count
+
+digitsToNum : List U8 -> I128
+digitsToNum = \digits ->
+    List.walk digits 0 \num, digit -> num * 10 + Num.toI128 (digit - '0')
+
+expect toIsoStr zero == "PT0S"
+expect toIsoStr (add (fromDays 1) (fromMilliseconds 1_500)) == "P1DT1.5S"
+expect toIsoStr (negate (fromSeconds 3_661)) == "-PT1H1M1S"
+expect fromIsoStr "P1W" == Ok (fromDays 7)
+expect fromIsoStr "-PT0.000000001S" == Ok (fromNanoseconds -1)
+expect fromIsoStr "PT1,5S" == Ok (fromMilliseconds 1_500)
+expect fromIsoStr "P" == Err InvalidDurationStr
+expect fromIsoStr "PT" == Err InvalidDurationStr
+expect fromIsoStr "PT1M1H" == Err InvalidDurationStr
+expect fromIsoStr "P1H" == Err InvalidDurationStr
+expect fromIsoStr "PT1.5M" == Err InvalidDurationStr
+
+expect
+    duration = add (fromDays 3) (fromNanoseconds 4_005_006_007)
+
+    fromIsoStr (toIsoStr duration) == Ok duration
@@ -530,7 +530,10 @@ walkWithIndexUntil = \list, state, f ->
         Break new -> new
 
 ## internal helper
-walkWithIndexUntilHelp : List elem, s, (s, elem, U64 -> [Continue s, Break b]), U64, U64 -> [Continue s, Break b]
+walkWithIndexUntilHelp : List elem, s, (s, elem, U64 -> [Continue s, Break b]), U64, U64 -> [
+    Continue s,
+    Break b,
+]
 walkWithIndexUntilHelp = \list, state, f, index, length ->
     if index < length then
         when f state (List.getUnsafe list index) index is
@@ -822,26 +825,14 @@ range = \{ start, end, step ? 0 } ->
             when T start end is
                 T (At x) (At y) | T (At x) (Before y) | T (After x) (At y) | T (After x) (Before y) ->
                     if x < y then
-                        {
-                            calcNext: \i -> Num.addChecked i 1,
-                            stepIsPositive: Bool.true,
-                        }
+                        { calcNext: \i -> Num.addChecked i 1, stepIsPositive: Bool.true }
                     else
-                        {
-                            calcNext: \i -> Num.subChecked i 1,
-                            stepIsPositive: Bool.false,
-                        }
+                        { calcNext: \i -> Num.subChecked i 1, stepIsPositive: Bool.false }
 
                 T (At _) (Length _) | T (After _) (Length _) ->
-                    {
-                        calcNext: \i -> Num.addChecked i 1,
-                        stepIsPositive: Bool.true,
-                    }
+                    { calcNext: \i -> Num.addChecked i 1, stepIsPositive: Bool.true }
         else
-            {
-                calcNext: \i -> Num.addChecked i step,
-                stepIsPositive: step > 0,
-            }
+            { calcNext: \i -> Num.addChecked i step, stepIsPositive: step > 0 }
 
     inclusiveStart =
         when start is
@@ -869,8 +860,7 @@ range = \{ start, end, step ? 0 } ->
             # TODO: switch to List.withCapacity
             rangeHelp [] inclusiveStart calcNext isValid
 
-        Length l ->
-            rangeLengthHelp (List.withCapacity l) inclusiveStart l calcNext
+        Length l -> rangeLengthHelp (List.withCapacity l) inclusiveStart l calcNext
 
 rangeHelp = \accum, i, calcNext, isValid ->
     when i is
@@ -938,7 +928,14 @@ expect
 expect
     List.range { start: At 4, end: At 0 } == [4, 3, 2, 1, 0]
 
-## Sort with a custom comparison function
+## Sort with a custom comparison function, like [Num.compare] or [Str.compare]. To sort by several
+## keys, the `Order` module combines comparisons.
+## ```roc
+## expect
+##     [{ name: "b", age: 2 }, { name: "a", age: 2 }, { name: "c", age: 1 }]
+##     |> List.sortWith (Order.byNum .age |> Order.thenBy (Order.byStr .name))
+##     == [{ name: "c", age: 1 }, { name: "a", age: 2 }, { name: "b", age: 2 }]
+## ```
 sortWith : List a, (a, a -> [LT, EQ, GT]) -> List a
 
 ## Sorts a list of numbers in ascending order (lowest to highest).
@@ -1026,11 +1023,8 @@ dropAt : List elem, U64 -> List elem
 min : List (Num a) -> Result (Num a) [ListWasEmpty]
 min = \list ->
     when List.first list is
-        Ok initial ->
-            Ok (minHelp list initial)
-
-        Err ListWasEmpty ->
-            Err ListWasEmpty
+        Ok initial -> Ok (minHelp list initial)
+        Err ListWasEmpty -> Err ListWasEmpty
 
 minHelp : List (Num a), Num a -> Num a
 minHelp = \list, initial ->
@@ -1043,11 +1037,8 @@ minHelp = \list, initial ->
 max : List (Num a) -> Result (Num a) [ListWasEmpty]
 max = \list ->
     when List.first list is
-        Ok initial ->
-            Ok (maxHelp list initial)
-
-        Err ListWasEmpty ->
-            Err ListWasEmpty
+        Ok initial -> Ok (maxHelp list initial)
+        Err ListWasEmpty -> Err ListWasEmpty
 
 maxHelp : List (Num a), Num a -> Num a
 maxHelp = \list, initial ->
@@ -1216,7 +1207,10 @@ splitFirst = \list, delimiter ->
     when List.findFirstIndex list (\elem -> elem == delimiter) is
         Ok index ->
             before = List.sublist list { start: 0, len: index }
-            after = List.sublist list { start: Num.addWrap index 1, len: Num.subWrap (List.len list) index |> Num.subWrap 1 }
+            after = List.sublist list {
+                start: Num.addWrap index 1,
+                len: Num.subWrap (List.len list) index |> Num.subWrap 1,
+            }
 
             Ok { before, after }
 
@@ -1232,7 +1226,10 @@ splitLast = \list, delimiter ->
     when List.findLastIndex list (\elem -> elem == delimiter) is
         Ok index ->
             before = List.sublist list { start: 0, len: index }
-            after = List.sublist list { start: Num.addWrap index 1, len: Num.subWrap (List.len list) index |> Num.subWrap 1 }
+            after = List.sublist list {
+                start: Num.addWrap index 1,
+                len: Num.subWrap (List.len list) index |> Num.subWrap 1,
+            }
 
             Ok { before, after }
 
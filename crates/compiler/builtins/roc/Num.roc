@@ -63,6 +63,8 @@ module [
     isFinite,
     rem,
     remChecked,
+    mod,
+    modChecked,
     div,
     divChecked,
     sqrt,
@@ -99,6 +101,8 @@ module [
     divCeilChecked,
     divTrunc,
     divTruncChecked,
+    divFloor,
+    divFloorChecked,
     toStr,
     isMultipleOf,
     minI8,
@@ -904,6 +908,15 @@ divChecked = \a, b ->
     else
         Ok (Num.div a b)
 
+## Divides two integers, rounding the result up, towards positive infinity.
+##
+## Dividing by zero crashes with a message, so use [divCeilChecked] when the
+## denominator might be zero.
+## ```roc
+## Num.divCeil 7 2
+##
+## Num.divCeil -7 2
+## ```
 divCeil : Int a, Int a -> Int a
 
 divCeilChecked : Int a, Int a -> Result (Int a) [DivByZero]
@@ -946,6 +959,42 @@ divTruncChecked = \a, b ->
 ## traps (hardware fault) when given zero as the second argument.
 divTruncUnchecked : Int a, Int a -> Int a
 
+## Divides two integers, rounding the result down, towards negative infinity.
+## This is different from [divTrunc] when exactly one of the numbers is negative.
+##
+## Dividing by zero crashes with a message, so use [divFloorChecked] when the
+## denominator might be zero.
+## ```roc
+## Num.divFloor 7 2
+##
+## Num.divFloor -7 2
+## ```
+divFloor : Int a, Int a -> Int a
+divFloor = \a, b ->
+    if Num.isZero b then
+        crash "Integer division by 0!"
+    else
+        divFloorUnchecked a b
+
+divFloorChecked : Int a, Int a -> Result (Int a) [DivByZero]
+divFloorChecked = \a, b ->
+    if Num.isZero b then
+        Err DivByZero
+    else
+        Ok (divFloorUnchecked a b)
+
+divFloorUnchecked : Int a, Int a -> Int a
+divFloorUnchecked = \a, b ->
+    quotient = Num.divTruncUnchecked a b
+    remainder = Num.remUnchecked a b
+
+    # The truncated quotient is one too high when the remainder has the
+    # opposite sign of the denominator.
+    if !(Num.isZero remainder) && Num.isNegative remainder != Num.isNegative b then
+        quotient - 1
+    else
+        quotient
+
 ## Obtains the remainder (truncating modulo) from the division of two integers.
 ##
 ## `a % b` is shorthand for `Num.rem a b`.
@@ -975,6 +1024,41 @@ remChecked = \a, b ->
 ## traps (hardware fault) when given zero as the second argument.
 remUnchecked : Int a, Int a -> Int a
 
+## Obtains the remainder of [divFloor], which has the same sign as the
+## denominator. This is different from [rem] when exactly one of the numbers is
+## negative, and it's what makes `Num.mod -1 7` equal 6, which is handy for
+## wrapping around.
+##
+## Dividing by zero crashes with a message, so use [modChecked] when the
+## denominator might be zero.
+## ```roc
+## Num.mod 8 3
+##
+## Num.mod -8 3
+## ```
+mod : Int a, Int a -> Int a
+mod = \a, b ->
+    if Num.isZero b then
+        crash "Integer division by 0!"
+    else
+        modUnchecked a b
+
+modChecked : Int a, Int a -> Result (Int a) [DivByZero]
+modChecked = \a, b ->
+    if Num.isZero b then
+        Err DivByZero
+    else
+        Ok (modUnchecked a b)
+
+modUnchecked : Int a, Int a -> Int a
+modUnchecked = \a, b ->
+    remainder = Num.remUnchecked a b
+
+    if !(Num.isZero remainder) && Num.isNegative remainder != Num.isNegative b then
+        remainder + b
+    else
+        remainder
+
 isMultipleOf : Int a, Int a -> Bool
 
 ## Does a "bitwise and". Each bit of the output is 1 if the corresponding bit
@@ -0,0 +1,90 @@
+module [
+    Order,
+    reverse,
+    breakTie,
+    byNum,
+    byStr,
+    descending,
+    thenBy,
+]
+
+import Bool
+import Str
+import Num exposing [Num]
+
+## The result of comparing two values, like [Num.compare] and [Str.compare] return: whether the
+## first one comes before (`LT`), together with (`EQ`), or after (`GT`) the second. A function that
+## compares two values this way is what [List.sortWith] sorts by.
+Order : [LT, EQ, GT]
+
+## Turns `LT` into `GT` and the other way around.
+## ```roc
+## expect Order.reverse (Num.compare 1 2) == GT
+## ```
+reverse : Order -> Order
+reverse = \order ->
+    when order is
+        LT -> GT
+        EQ -> EQ
+        GT -> LT
+
+## Uses the second comparison when the first one is `EQ`, to sort by several keys.
+## ```roc
+## expect
+##     [{ name: "b", age: 2 }, { name: "a", age: 2 }, { name: "c", age: 1 }]
+##     |> List.sortWith \a, b ->
+##         Num.compare a.age b.age
+##         |> Order.breakTie (Str.compare a.name b.name)
+##     == [{ name: "c", age: 1 }, { name: "a", age: 2 }, { name: "b", age: 2 }]
+## ```
+breakTie : Order, Order -> Order
+breakTie = \order, tieBreaker ->
+    when order is
+        EQ -> tieBreaker
+        _ -> order
+
+## Compares values by a number from each of them, from the lowest to the highest.
+## ```roc
+## expect List.sortWith ["ccc", "a", "bb"] (Order.byNum Str.countUtf8Bytes) == ["a", "bb", "ccc"]
+## ```
+byNum : (a -> Num n) -> (a, a -> Order)
+byNum = \toNum ->
+    \a, b -> Num.compare (toNum a) (toNum b)
+
+## Compares values by a string from each of them, like [Str.compare] does.
+byStr : (a -> Str) -> (a, a -> Order)
+byStr = \toStr ->
+    \a, b -> Str.compare (toStr a) (toStr b)
+
+## Compares the other way around, to sort from the highest to the lowest.
+## ```roc
+## expect List.sortWith [1, 3, 2] (Order.descending Num.compare) == [3, 2, 1]
+## ```
+descending : (a, a -> Order) -> (a, a -> Order)
+descending = \compare ->
+    \a, b -> compare b a
+
+## Uses the second way of comparing when the first one finds the values equal, like [breakTie].
+## ```roc
+## byAgeThenName = Order.byNum .age |> Order.descending |> Order.thenBy (Order.byStr .name)
+## ```
+thenBy : (a, a -> Order), (a, a -> Order) -> (a, a -> Order)
+thenBy = \compare, tieBreaker ->
+    \a, b ->
+        when compare a b is
+            EQ -> tieBreaker a b
+            order -> order
+
+expect reverse LT == GT
+expect breakTie GT LT == GT
+expect breakTie EQ LT == LT
+
+expect
+    compare = descending (byNum \n -> n)
+
+    compare 1 2 == GT
+
+expect
+    compare = byStr .name |> thenBy (byNum .age |> descending)
+
+    compare { name: "a", age: 1 } { name: "a", age: 2 } == GT
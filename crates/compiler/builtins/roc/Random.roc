@@ -0,0 +1,270 @@
+module [
+    Seed,
+    Generator,
+    Counterexample,
+    seed,
+    step,
+    replay,
+    constant,
+    map,
+    map2,
+    andThen,
+    u8,
+    u32,
+    u64,
+    i64,
+    between,
+    f64,
+    bool,
+    list,
+    oneOf,
+    check,
+]
+
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import List
+import Num exposing [U8, U32, U64, I64, F64]
+
+## The state of a pseudo-random number generator. The same seed always leads to the same values,
+## on every platform, so anything generated from a seed can be generated again.
+Seed := U64 implements [Eq]
+
+## Makes values of type `a` from a [Seed], without any side effects.
+##
+## Besides the seed, a generator receives a size, which bounds how large the values it makes can
+## get, like the length of a [list]. [check] makes the size smaller to shrink the inputs that
+## break a property.
+Generator a := Seed, U64 -> { value : a, seed : Seed }
+
+## An input that broke a property in [check], along with the seed and size that [replay] needs to
+## generate it again.
+Counterexample a : { seed : U64, size : U64, value : a }
+
+# A generated value, and the seed to generate the next value from
+Step a : { value : a, seed : Seed }
+
+## The size that [step] gives generators.
+defaultSize : U64
+defaultSize = 100
+
+## How many inputs [check] tries before it concludes that a property holds.
+checkRuns : U64
+checkRuns = 100
+
+## Creates a seed from a number. Different numbers lead to unrelated sequences of values.
+seed : U64 -> Seed
+seed = \n -> @Seed n
+
+## Generates a value, along with the seed to generate the next value from.
+## ```roc
+## { value: roll, seed: nextSeed } = Random.step (Random.between 1 6) (Random.seed 42)
+## ```
+step : Generator a, Seed -> { value : a, seed : Seed }
+step = \@Generator generate, s -> generate s defaultSize
+
+## Generates the input of a [Counterexample] again, from its seed and size.
+## ```roc
+## when Random.check generator property is
+##     Ok {} -> Ok {}
+##     Err { seed, size } -> Err (Random.replay generator seed size)
+## ```
+replay : Generator a, U64, U64 -> a
+replay = \@Generator generate, seedNum, size ->
+    { value } = generate (@Seed seedNum) size
+
+    value
+
+## A generator that always makes the given value.
+constant : a -> Generator a
+constant = \value -> @Generator \s, _ -> { value, seed: s }
+
+## Transforms the values a generator makes.
+## ```roc
+## even = Random.u64 |> Random.map \n -> n * 2
+## ```
+map : Generator a, (a -> b) -> Generator b
+map = \@Generator generate, transform ->
+    @Generator \s, size ->
+        { value, seed: nextSeed } = generate s size
+
+        { value: transform value, seed: nextSeed }
+
+## Combines the values of two generators.
+## ```roc
+## point = Random.map2 Random.f64 Random.f64 \x, y -> { x, y }
+## ```
+map2 : Generator a, Generator b, (a, b -> c) -> Generator c
+map2 = \@Generator generateA, @Generator generateB, combine ->
+    @Generator \s, size ->
+        a = generateA s size
+        b = generateB a.seed size
+
+        { value: combine a.value b.value, seed: b.seed }
+
+## Picks the next generator based on the value of the previous one.
+## ```roc
+## sameLengthLists =
+##     Random.between 0 10
+##     |> Random.andThen \length ->
+##         Random.list Random.u8
+##         |> Random.map \bytes -> List.takeFirst bytes (Num.toU64 length)
+## ```
+andThen : Generator a, (a -> Generator b) -> Generator b
+andThen = \@Generator generate, choose ->
+    @Generator \s, size ->
+        { value, seed: nextSeed } = generate s size
+
+        when choose value is
+            @Generator generateNext -> generateNext nextSeed size
+
+## Any [U8], each equally likely.
+u8 : Generator U8
+u8 = map u64 \n -> Num.toU8 (Num.shiftRightZfBy n 56)
+
+## Any [U32], each equally likely.
+u32 : Generator U32
+u32 = map u64 \n -> Num.toU32 (Num.shiftRightZfBy n 32)
+
+## Any [U64], each equally likely.
+u64 : Generator U64
+u64 = @Generator \s, _ -> next s
+
+## Any [I64], each equally likely.
+i64 : Generator I64
+i64 = map u64 Num.toI64
+
+## An [I64] from `low` up to and including `high`. The order of the bounds doesn't matter.
+## ```roc
+## roll = Random.between 1 6
+## ```
+between : I64, I64 -> Generator I64
+between = \low, high ->
+    lowest = Num.min low high
+    span = Num.toU64 (Num.subWrap (Num.max low high) lowest)
+
+    @Generator \s, _ ->
+        { value, seed: nextSeed } = next s
+        offset = if span == Num.maxU64 then value else value % (span + 1)
+
+        { value: Num.addWrap lowest (Num.toI64 offset), seed: nextSeed }
+
+## An [F64] from 0 up to, but not including, 1.
+f64 : Generator F64
+f64 = map u64 \n -> Num.toF64 (Num.shiftRightZfBy n 11) / 9007199254740992
+
+## [Bool.true] or [Bool.false], each equally likely.
+bool : Generator Bool
+bool = map u64 \n -> Num.shiftRightZfBy n 63 == 1
+
+## A list of values from the given generator, with a length from 0 up to the size.
+list : Generator a -> Generator (List a)
+list = \@Generator generateElem ->
+    @Generator \s, size ->
+        { value, seed: nextSeed } = next s
+        length = value % Num.addSaturated size 1
+
+        listHelp generateElem nextSeed size length (List.withCapacity length)
+
+listHelp : (Seed, U64 -> Step a), Seed, U64, U64, List a -> Step (List a)
+listHelp = \generateElem, s, size, remaining, elems ->
+    if remaining == 0 then
+        { value: elems, seed: s }
+    else
+        { value, seed: nextSeed } = generateElem s size
+
+        listHelp generateElem nextSeed size (remaining - 1) (List.append elems value)
+
+## One of the given values, each equally likely. The first one is separate, so that there is
+## always at least one to pick.
+## ```roc
+## direction = Random.oneOf North [East, South, West]
+## ```
+oneOf : a, List a -> Generator a
+oneOf = \first, rest ->
+    @Generator \s, _ ->
+        { value, seed: nextSeed } = next s
+        index = value % (List.len rest + 1)
+        picked =
+            if index == 0 then
+                first
+            else
+                List.get rest (index - 1) |> Result.withDefault first
+
+        { value: picked, seed: nextSeed }
+
+## Checks that a property holds for 100 inputs from the generator, with sizes from 0 up to 99.
+##
+## When the property doesn't hold for an input, this returns the smallest input with the same
+## seed that breaks it, which is usually easier to understand than the first one. Call it in an
+## `expect`, so that `roc test` shows the counterexample when the property is broken:
+## ```roc
+## expect
+##     result = Random.check (Random.list Random.u8) \bytes ->
+##         List.reverse (List.reverse bytes) == bytes
+##
+##     result == Ok {}
+## ```
+## The inputs only depend on the seed `roc test --seed` picks, which is 0 when it isn't given,
+## so a failure happens again on every run with the same seed.
+check : Generator a, (a -> Bool) -> Result {} (Counterexample a)
+check = \generator, property ->
+    checkHelp generator property (testSeed {}) 0
+
+checkHelp : Generator a, (a -> Bool), U64, U64 -> Result {} (Counterexample a)
+checkHelp = \generator, property, seedNum, size ->
+    if size == checkRuns then
+        Ok {}
+    else if property (replay generator seedNum size) then
+        { value: nextSeedNum } = next (@Seed seedNum)
+
+        checkHelp generator property nextSeedNum (size + 1)
+    else
+        Err (shrink generator property seedNum 0 size)
+
+# Finds the smallest size at which the input for this seed still breaks the property.
+shrink : Generator a, (a -> Bool), U64, U64, U64 -> Counterexample a
+shrink = \generator, property, seedNum, size, failingSize ->
+    value = replay generator seedNum size
+
+    if size == failingSize || !(property value) then
+        { seed: seedNum, size, value }
+    else
+        shrink generator property seedNum (size + 1) failingSize
+
+# The seed that `roc test --seed` picked for properties, or 0.
+testSeed : {} -> U64
+
+# SplitMix64, which is fast, and passes statistical tests of randomness even with the small
+# state that lets the seed be any U64.
+next : Seed -> Step U64
+next = \@Seed state ->
+    newState = Num.addWrap state 0x9e3779b97f4a7c15
+    a = Num.bitwiseXor newState (Num.shiftRightZfBy newState 30) |> Num.mulWrap 0xbf58476d1ce4e5b9
+    b = Num.bitwiseXor a (Num.shiftRightZfBy a 27) |> Num.mulWrap 0x94d049bb133111eb
+
+    { value: Num.bitwiseXor b (Num.shiftRightZfBy b 31), seed: @Seed newState }
+
+expect
+    first = step u64 (seed 42)
+    again = step u64 (seed 42)
+    second = step u64 first.seed
+
+    first == again && first.value != second.value
+
+expect
+    rolls = replay (list (between 1 6)) 7 100
+
+    List.all rolls \roll -> roll >= 1 && roll <= 6
+
+expect
+    generator = list u8
+
+    when check generator \bytes -> List.len bytes < 5 is
+        Ok {} -> Bool.false
+        Err { seed: seedNum, size, value } ->
+            List.len value >= 5 && replay generator seedNum size == value
+
+expect
+    check i64 \n -> n == n
+    == Ok {}
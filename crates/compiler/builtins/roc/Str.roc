@@ -1,6 +1,6 @@
 ## Strings represent text. For example, `"Hi!"` is a string.
 ##
-## This guide starts at a high level and works down to the in-memory representation of strings and their [performance characteristics](#performance). For reasons that will be explained later in this guide, some string operations are in the `Str` module while others (notably [capitalization](#capitalization), [code points](#code-points), [graphemes](#graphemes), and sorting) are in separate packages. There's also a list of recommendations for [when to use code points, graphemes, and UTF-8](#when-to-use).
+## This guide starts at a high level and works down to the in-memory representation of strings and their [performance characteristics](#performance). For reasons that will be explained later in this guide, some string operations are in the `Str` module while others (notably [capitalization](#capitalization), [normalization](#string-equality-and-normalization), and sorting) are in separate packages. There's also a list of recommendations for [when to use code points, graphemes, and UTF-8](#when-to-use).
 ##
 ## ## Syntax
 ##
@@ -121,13 +121,13 @@
 ## * `鹏`
 ## * `👩‍👩‍👦‍👦`
 ##
-## Note that although *grapheme* is less ambiguous than *character*, its definition is still open to interpretation. To address this, Unicode has formally specified [text segmentation rules](https://www.unicode.org/reports/tr29/) which define grapheme boundaries in precise technical terms. We won't get into those rules here, but since they can change with new Unicode releases, functions for working with graphemes are in the [roc-lang/unicode](https://github.com/roc-lang/unicode) package rather than in the builtin [`Str`](https://www.roc-lang.org/builtins/Str) module. This allows them to be updated without being blocked on a new release of the Roc language.
+## Note that although *grapheme* is less ambiguous than *character*, its definition is still open to interpretation. To address this, Unicode has formally specified [text segmentation rules](https://www.unicode.org/reports/tr29/) which define grapheme boundaries in precise technical terms. We won't get into those rules here. [`Str.graphemes`](https://www.roc-lang.org/builtins/Str#graphemes) and [`Str.countGraphemes`](https://www.roc-lang.org/builtins/Str#countGraphemes) follow them, using the Unicode data of the Roc release you're on. Since the rules can change with new Unicode releases, a newer release of Roc can split a string into graphemes slightly differently.
 ##
 ## ### Code Points
 ##
 ## Every Unicode text value can be broken down into [Unicode code points](http://www.unicode.org/glossary/#code_point), which are integers between `0` and `285_212_438` that describe components of the text. In memory, every Roc string is a sequence of these integers stored in a format called UTF-8, which will be discussed [later](#utf8).
 ##
-## The string `"👩‍👩‍👦‍👦"` happens to be made up of these code points:
+## The string `"👩‍👩‍👦‍👦"` happens to be made up of these code points, which is what [`Str.toScalars`](https://www.roc-lang.org/builtins/Str#toScalars) returns for it:
 ##
 ## ```
 ## [128105, 8205, 128105, 8205, 128102, 8205, 128102]
@@ -203,7 +203,7 @@
 ##
 ## It would be technically possible for Roc to perform string normalization automatically on every equality check. Unfortunately, although some programs might want to treat `"caf\u(e9)"` and `"cafe\u(301)"` as equivalent, for other programs it might actually be important to be able to tell them apart. If these equality checks always passed, then there would be no way to tell them apart!
 ##
-## As such, normalization must be performed explicitly when desired. Like graphemes, Unicode normalization rules can change with new releases of Unicode. As such, these functions are in separate packages instead of builtins (normalization is planned to be in [roc-lang/unicode](https://github.com/roc-lang/unicode) in the future, but it has not yet been implemented) so that updates to these functions based on new Unicode releases can happen without waiting on new releases of the Roc language.
+## As such, normalization must be performed explicitly when desired. Unicode normalization rules can change with new releases of Unicode. As such, these functions are in separate packages instead of builtins (normalization is planned to be in [roc-lang/unicode](https://github.com/roc-lang/unicode) in the future, but it has not yet been implemented) so that updates to these functions based on new Unicode releases can happen without waiting on new releases of the Roc language.
 ##
 ## ### Capitalization
 ##
@@ -258,7 +258,7 @@
 ## * If you are implementing a Unicode library like [roc-lang/unicode](https://github.com/roc-lang/unicode), working in terms of code points will be unavoidable. Aside from basic readability considerations like `\u(...)` in string literals, if you have the option to avoid working in terms of code points, it is almost always correct to avoid them.
 ## * If it seems like a good idea to split a string into "characters" (graphemes), you should definitely stop and reconsider whether this is really the best design. Almost always, doing this is some combination of more error-prone or slower (usually both) than doing something else that does not require taking graphemes into consideration.
 ##
-## For this reason (among others), grapheme functions live in [roc-lang/unicode](https://github.com/roc-lang/unicode) rather than in [`Str`](https://www.roc-lang.org/builtins/Str). They are more niche than they seem, so they should not be reached for all the time!
+## So even though [`Str.graphemes`](https://www.roc-lang.org/builtins/Str#graphemes) is right there in `Str`, it is more niche than it seems, and should not be reached for all the time!
 ##
 ## ## Performance
 ##
@@ -336,6 +336,9 @@ module [
     repeat,
     countUtf8Bytes,
     toUtf8,
+    toScalars,
+    graphemes,
+    countGraphemes,
     fromUtf8,
     startsWith,
     endsWith,
@@ -367,6 +370,7 @@ module [
     withCapacity,
     withPrefix,
     contains,
+    compare,
 ]
 
 import Bool exposing [Bool]
@@ -525,6 +529,37 @@ repeat : Str, U64 -> Str
 ## ```
 toUtf8 : Str -> List U8
 
+## Returns a [List] of the string's [Unicode scalar values](https://unicode.org/glossary/#unicode_scalar_value),
+## which are its [code points](#code-points), minus the surrogates that UTF-8 can't encode.
+## ```roc
+## expect Str.toScalars "Roc" == [82, 111, 99]
+## expect Str.toScalars "鹏" == [40527]
+## expect Str.toScalars "சி" == [2970, 3007]
+## expect Str.toScalars "👩‍👩‍👦‍👦" == [128105, 8205, 128105, 8205, 128102, 8205, 128102]
+## ```
+toScalars : Str -> List U32
+
+## Splits a string into its [graphemes](#graphemes), following the extended grapheme cluster
+## rules of [Unicode text segmentation](https://www.unicode.org/reports/tr29/).
+##
+## Each grapheme is a [seamless slice](#seamless-slices) of the original string where it can be.
+## ```roc
+## expect Str.graphemes "Roc" == ["R", "o", "c"]
+## expect Str.graphemes "சி" == ["சி"]
+## expect Str.graphemes "👩‍👩‍👦‍👦🇨🇦" == ["👩‍👩‍👦‍👦", "🇨🇦"]
+## expect Str.graphemes "" == []
+## ```
+graphemes : Str -> List Str
+
+## Counts the [graphemes](#graphemes) in a string, like [Str.graphemes] does without building
+## the [List].
+## ```roc
+## expect Str.countGraphemes "Roc" == 3
+## expect Str.countGraphemes "👩‍👩‍👦‍👦" == 1
+## expect Str.countGraphemes "e\u(301)" == 1
+## ```
+countGraphemes : Str -> U64
+
 ## Converts a [List] of [U8] UTF-8 [code units](https://unicode.org/glossary/#code_unit) to a string.
 ##
 ## Returns `Err` if the given bytes are invalid UTF-8, and returns `Ok ""` when given `[]`.
@@ -551,12 +586,7 @@ expect (Str.fromUtf8 [240, 159, 144, 166]) == Ok "🐦"
 expect (Str.fromUtf8 []) == Ok ""
 expect (Str.fromUtf8 [255]) |> Result.isErr
 
-FromUtf8Result : {
-    aByteIndex : U64,
-    bString : Str,
-    cIsOk : Bool,
-    dProblemCode : Utf8ByteProblem,
-}
+FromUtf8Result : { aByteIndex : U64, bString : Str, cIsOk : Bool, dProblemCode : Utf8ByteProblem }
 
 fromUtf8Lowlevel : List U8 -> FromUtf8Result
 
@@ -797,9 +827,7 @@ expect Str.replaceEach "abcdefg" "nothing" "_" == "abcdefg"
 replaceFirst : Str, Str, Str -> Str
 replaceFirst = \haystack, needle, flower ->
     when splitFirst haystack needle is
-        Ok { before, after } ->
-            "$(before)$(flower)$(after)"
-
+        Ok { before, after } -> "$(before)$(flower)$(after)"
         Err NotFound -> haystack
 
 expect Str.replaceFirst "abXdeXghi" "X" "_" == "ab_deXghi"
@@ -815,9 +843,7 @@ expect Str.replaceFirst "abcdefg" "nothing" "_" == "abcdefg"
 replaceLast : Str, Str, Str -> Str
 replaceLast = \haystack, needle, flower ->
     when splitLast haystack needle is
-        Ok { before, after } ->
-            "$(before)$(flower)$(after)"
-
+        Ok { before, after } -> "$(before)$(flower)$(after)"
         Err NotFound -> haystack
 
 expect Str.replaceLast "abXdeXghi" "X" "_" == "abXde_ghi"
@@ -841,8 +867,7 @@ splitFirst = \haystack, needle ->
 
             Ok { before, after }
 
-        None ->
-            Err NotFound
+        None -> Err NotFound
 
 # splitFirst when needle isn't in haystack
 expect splitFirst "foo" "z" == Err NotFound
@@ -895,8 +920,7 @@ splitLast = \haystack, needle ->
 
             Ok { before, after }
 
-        None ->
-            Err NotFound
+        None -> Err NotFound
 
 # splitLast when needle isn't in haystack
 expect Str.splitLast "foo" "z" == Err NotFound
@@ -924,11 +948,8 @@ lastMatchHelp = \haystack, needle, index ->
         Some index
     else
         when Num.subChecked index 1 is
-            Ok nextIndex ->
-                lastMatchHelp haystack needle nextIndex
-
-            Err _ ->
-                None
+            Ok nextIndex -> lastMatchHelp haystack needle nextIndex
+            Err _ -> None
 
 min = \x, y -> if x < y then x else y
 
@@ -938,14 +959,7 @@ matchesAt = \haystack, haystackIndex, needle ->
     needleLength = Str.countUtf8Bytes needle
     endIndex = min (Num.addSaturated haystackIndex needleLength) haystackLength
 
-    matchesAtHelp {
-        haystack,
-        haystackIndex,
-        needle,
-        needleIndex: 0,
-        needleLength,
-        endIndex,
-    }
+    matchesAtHelp { haystack, haystackIndex, needle, needleIndex: 0, needleLength, endIndex }
 
 matchesAtHelp = \state ->
     { haystack, haystackIndex, needle, needleIndex, needleLength, endIndex } = state
@@ -1052,3 +1066,23 @@ contains = \haystack, needle ->
     when firstMatch haystack needle is
         Some _index -> Bool.true
         None -> Bool.false
+
+## Compares strings by their UTF-8 bytes, which sorts them by their code points, so that
+## uppercase letters come before lowercase ones. Use it with [List.sortWith] to sort strings.
+## ```roc
+## expect Str.compare "apple" "banana" == LT
+## expect Str.compare "apple" "app" == GT
+## expect List.sortWith ["b", "a", "B"] Str.compare == ["B", "a", "b"]
+## ```
+compare : Str, Str -> [LT, EQ, GT]
+compare = \a, b ->
+    compareHelp (toUtf8 a) (toUtf8 b) 0
+
+compareHelp : List U8, List U8, U64 -> [LT, EQ, GT]
+compareHelp = \a, b, index ->
+    when (List.get a index, List.get b index) is
+        (Ok byteA, Ok byteB) if byteA == byteB -> compareHelp a b (index + 1)
+        (Ok byteA, Ok byteB) -> Num.compare byteA byteB
+        (Ok _, Err OutOfBounds) -> GT
+        (Err OutOfBounds, Ok _) -> LT
+        (Err OutOfBounds, Err OutOfBounds) -> EQ
@@ -0,0 +1,34 @@
+module [
+    isWasm,
+    isWindows,
+    isMac,
+    isLinux,
+]
+
+import Bool exposing [Bool]
+
+## Whether the program is being compiled for WebAssembly.
+##
+## Like the other constants in this module, the compiler knows its value for the target it's
+## compiling for. In an `if` that checks it directly, like `if Target.isWasm then` or
+## `if !Target.isWasm then`, only the branch for the target is compiled, so a package can use
+## a different implementation on each target, even one that wouldn't compile on the others.
+## ```roc
+## import Target
+##
+## now = if Target.isWasm then Browser.now else Native.now
+## ```
+isWasm : Bool
+isWasm = Bool.false
+
+## Whether the program is being compiled for Windows.
+isWindows : Bool
+isWindows = Bool.false
+
+## Whether the program is being compiled for macOS.
+isMac : Bool
+isMac = Bool.false
+
+## Whether the program is being compiled for Linux.
+isLinux : Bool
+isLinux = Bool.false
@@ -0,0 +1,240 @@
+module [
+    Time,
+    epoch,
+    fromUnixNanoseconds,
+    toUnixNanoseconds,
+    fromUnixMilliseconds,
+    toUnixMilliseconds,
+    fromUnixSeconds,
+    toUnixSeconds,
+    add,
+    sub,
+    since,
+    compare,
+    fromDate,
+    toDate,
+    toIsoStr,
+    fromIsoStr,
+]
+
+import Bool exposing [Bool, Eq]
+import Result exposing [Result]
+import List
+import Str
+import Num exposing [U8, U64, I128]
+import Hash exposing [Hash]
+import Duration exposing [Duration]
+import Date exposing [Date]
+
+## A moment in time, with nanosecond precision, which is the same everywhere in the world.
+##
+## Like Unix time, this pretends that every day has exactly 86400 seconds, so leap seconds don't
+## exist. Getting the current time is a side effect, so that is up to the platform, which can
+## give it to Roc with [fromUnixNanoseconds].
+Time := I128 implements [Eq, Hash]
+
+nanosPerMillisecond : I128
+nanosPerMillisecond = 1_000_000
+
+nanosPerSecond : I128
+nanosPerSecond = 1_000_000_000
+
+nanosPerMinute : I128
+nanosPerMinute = 60 * nanosPerSecond
+
+nanosPerHour : I128
+nanosPerHour = 60 * nanosPerMinute
+
+nanosPerDay : I128
+nanosPerDay = 24 * nanosPerHour
+
+## 1970-01-01T00:00:00Z, which Unix time counts from.
+epoch : Time
+epoch = @Time 0
+
+## The time the given number of nanoseconds after the [epoch], or before it when the number is
+## negative.
+fromUnixNanoseconds : I128 -> Time
+fromUnixNanoseconds = \nanos -> @Time nanos
+
+toUnixNanoseconds : Time -> I128
+toUnixNanoseconds = \@Time nanos -> nanos
+
+fromUnixMilliseconds : I128 -> Time
+fromUnixMilliseconds = \millis -> @Time (millis * nanosPerMillisecond)
+
+## The number of whole milliseconds since the [epoch], rounded down, so that times before it are
+## negative.
+toUnixMilliseconds : Time -> I128
+toUnixMilliseconds = \@Time nanos -> floorDiv nanos nanosPerMillisecond
+
+fromUnixSeconds : I128 -> Time
+fromUnixSeconds = \seconds -> @Time (seconds * nanosPerSecond)
+
+## The number of whole seconds since the [epoch], rounded down, so that times before it are
+## negative.
+toUnixSeconds : Time -> I128
+toUnixSeconds = \@Time nanos -> floorDiv nanos nanosPerSecond
+
+## The time a duration after the given time, or before it when the duration is negative.
+## ```roc
+## expect Time.add Time.epoch (Duration.fromDays 1) |> Time.toIsoStr == "1970-01-02T00:00:00Z"
+## ```
+add : Time, Duration -> Time
+add = \@Time nanos, duration -> @Time (nanos + Duration.toNanoseconds duration)
+
+## The time a duration before the given time.
+sub : Time, Duration -> Time
+sub = \@Time nanos, duration -> @Time (nanos - Duration.toNanoseconds duration)
+
+## How long after the second time the first one is, which is negative when it's before it.
+## ```roc
+## elapsed = end |> Time.since start
+## ```
+since : Time, Time -> Duration
+since = \@Time later, @Time earlier -> Duration.fromNanoseconds (later - earlier)
+
+## Whether the first time is before (`LT`), the same as (`EQ`), or after (`GT`) the second, for
+## sorting with `List.sortWith`.
+compare : Time, Time -> [LT, EQ, GT]
+compare = \@Time a, @Time b -> Num.compare a b
+
+## The start of the day, in UTC.
+fromDate : Date -> Time
+fromDate = \date -> @Time (Num.toI128 (Date.toDaysSinceEpoch date) * nanosPerDay)
+
+## The day it is in UTC at the given time.
+toDate : Time -> Date
+toDate = \@Time nanos -> Date.fromDaysSinceEpoch (Num.toI64 (floorDiv nanos nanosPerDay))
+
+## Formats the time like `2024-02-29T13:45:30.25Z`, in UTC, as in RFC 3339. The fraction of the
+## second is left out when it's 0.
+toIsoStr : Time -> Str
+toIsoStr = \@Time nanos ->
+    days = floorDiv nanos nanosPerDay
+    ofDay = nanos - days * nanosPerDay
+    hours = ofDay // nanosPerHour
+    minutes = (ofDay % nanosPerHour) // nanosPerMinute
+    seconds = (ofDay % nanosPerMinute) // nanosPerSecond
+    clock = "$(twoDigits hours):$(twoDigits minutes):$(twoDigits seconds)"
+
+    "$(Date.toIsoStr (Date.fromDaysSinceEpoch (Num.toI64 days)))T$(clock)$(fractionToStr (ofDay % nanosPerSecond))Z"
+
+twoDigits : I128 -> Str
+twoDigits = \num ->
+    if num < 10 then "0$(Num.toStr num)" else Num.toStr num
+
+# The digits after the decimal point of a number of nanoseconds below a second, without trailing
+# zeros, or nothing when it's 0.
+fractionToStr : I128 -> Str
+fractionToStr = \nanos ->
+    if nanos == 0 then "" else fractionToStrHelp nanos 9
+
+fractionToStrHelp : I128, U64 -> Str
+fractionToStrHelp = \fraction, width ->
+    if fraction % 10 == 0 then
+        fractionToStrHelp (fraction // 10) (width - 1)
+    else
+        digits = Num.toStr fraction
+
+        ".$(Str.repeat "0" (width - Str.countUtf8Bytes digits))$(digits)"
+
+## Parses a time like `2024-02-29T13:45:30.25Z` or `2024-02-29T14:45:30+01:00`, as in RFC 3339.
+## The date is like in `Date.fromIsoStr`, and the offset from UTC can be `Z` or like `+01:00`.
+## ```roc
+## expect Time.fromIsoStr "1970-01-01T01:00:00+01:00" == Ok Time.epoch
+## ```
+fromIsoStr : Str -> Result Time [InvalidTimeStr]
+fromIsoStr = \str ->
+    when Str.splitFirst str "T" is
+        Ok { before, after } ->
+            when Date.fromIsoStr before is
+                Ok date ->
+                    parseTimeOfDay (Str.toUtf8 after)
+                    |> Result.map \ofDay -> @Time (toUnixNanoseconds (fromDate date) + ofDay)
+
+                Err InvalidDateStr -> Err InvalidTimeStr
+
+        Err NotFound -> Err InvalidTimeStr
+
+# The nanoseconds from the start of the day in UTC, given a time like `13:45:30.25Z`.
+parseTimeOfDay : List U8 -> Result I128 [InvalidTimeStr]
+parseTimeOfDay = \bytes ->
+    when bytes is
+        [h1, h2, ':', m1, m2, ':', s1, s2, .. as rest] if List.all [h1, h2, m1, m2, s1, s2] isDigit ->
+            hours = digitsToNum [h1, h2]
+            minutes = digitsToNum [m1, m2]
+            seconds = digitsToNum [s1, s2]
+            fractionDigits =
+                when rest is
+                    ['.', .. as afterPoint] | [',', .. as afterPoint] -> countDigits afterPoint
+                    _ -> 0
+            afterFraction = List.dropFirst rest (if fractionDigits == 0 then 0 else fractionDigits + 1)
+            fraction =
+                List.sublist rest { start: 1, len: fractionDigits }
+                |> List.concat (List.repeat '0' (9 - Num.min 9 fractionDigits))
+                |> digitsToNum
+
+            when parseOffset afterFraction is
+                Ok offset if hours < 24 && minutes < 60 && seconds < 60 && fractionDigits <= 9 ->
+                    Ok (hours * nanosPerHour + minutes * nanosPerMinute + seconds * nanosPerSecond + fraction - offset)
+
+                _ -> Err InvalidTimeStr
+
+        _ -> Err InvalidTimeStr
+
+# The nanoseconds a time zone is ahead of UTC, given an offset like `Z` or `+01:00`.
+parseOffset : List U8 -> Result I128 [InvalidTimeStr]
+parseOffset = \bytes ->
+    when bytes is
+        ['Z'] | ['z'] -> Ok 0
+        [sign, h1, h2, ':', m1, m2] if (sign == '+' || sign == '-') && List.all [h1, h2, m1, m2] isDigit ->
+            hours = digitsToNum [h1, h2]
+            minutes = digitsToNum [m1, m2]
+            offset = hours * nanosPerHour + minutes * nanosPerMinute
+
+            if hours >= 24 || minutes >= 60 then
+                Err InvalidTimeStr
+            else if sign == '-' then
+                Ok -offset
+            else
+                Ok offset
+
+        _ -> Err InvalidTimeStr
+
+isDigit : U8 -> Bool
+isDigit = \byte -> byte >= '0' && byte <= '9'
+
+countDigits : List U8 -> U64
+countDigits = \bytes ->
+    List.walkUntil bytes 0 \count, byte ->
+        if isDigit byte then Continue (count + 1) else Break count
+
+digitsToNum : List U8 -> I128
+digitsToNum = \digits ->
+    List.walk digits 0 \num, digit -> num * 10 + Num.toI128 (digit - '0')
+
+# Division that rounds toward negative infinity, so times before the epoch still belong to the
+# right day.
+floorDiv : I128, I128 -> I128
+floorDiv = \a, b ->
+    quotient = a // b
+
+    if a % b != 0 && (a < 0) != (b < 0) then quotient - 1 else quotient
+
+expect toIsoStr epoch == "1970-01-01T00:00:00Z"
+expect toIsoStr (fromUnixMilliseconds -1) == "1969-12-31T23:59:59.999Z"
+expect toIsoStr (fromUnixSeconds 1_709_214_330) == "2024-02-29T13:45:30Z"
+expect toUnixSeconds (fromUnixMilliseconds -1) == -1
+expect fromIsoStr "2024-02-29T13:45:30.25Z" == Ok (fromUnixMilliseconds 1_709_214_330_250)
+expect fromIsoStr "2024-02-29T08:15:30-05:30" == Ok (fromUnixSeconds 1_709_214_330)
+expect fromIsoStr "2024-02-29T24:00:00Z" == Err InvalidTimeStr
+expect fromIsoStr "2024-02-29T13:45:30" == Err InvalidTimeStr
+expect fromIsoStr "2024-02-29T13:45:30.Z" == Err InvalidTimeStr
+expect since (fromUnixSeconds 90) (fromUnixSeconds 30) == Duration.fromMinutes 1
+expect toDate (fromUnixNanoseconds -1) == toDate (fromUnixSeconds -86_400)
+
+expect
+    time = fromUnixNanoseconds 1_709_214_330_123_456_789
+
+    fromIsoStr (toIsoStr time) == Ok time
@@ -252,7 +252,10 @@ encodeRecord = \fields ->
                 { buffer: bufferWithSuffix, fieldsLeft: fieldsLeft - 1 }
 
         bytesHead = List.append bytes (Num.toU8 '{')
-        { buffer: bytesWithRecord } = List.walk fields { buffer: bytesHead, fieldsLeft: List.len fields } writeRecord
+        { buffer: bytesWithRecord } = List.walk fields {
+            buffer: bytesHead,
+            fieldsLeft: List.len fields,
+        } writeRecord
 
         List.append bytesWithRecord (Num.toU8 '}')
 
@@ -276,7 +279,10 @@ encodeTuple = \elems ->
                 { buffer: bufferWithSuffix, elemsLeft: elemsLeft - 1 }
 
         bytesHead = List.append bytes (Num.toU8 '[')
-        { buffer: bytesWithRecord } = List.walk elems { buffer: bytesHead, elemsLeft: List.len elems } writeTuple
+        { buffer: bytesWithRecord } = List.walk elems {
+            buffer: bytesHead,
+            elemsLeft: List.len elems,
+        } writeTuple
 
         List.append bytesWithRecord (Num.toU8 ']')
 encodeTag = \name, payload ->
@@ -300,7 +306,10 @@ encodeTag = \name, payload ->
             |> List.append (Num.toU8 ':')
             |> List.append (Num.toU8 '[')
 
-        { buffer: bytesWithPayload } = List.walk payload { buffer: bytesHead, itemsLeft: List.len payload } writePayload
+        { buffer: bytesWithPayload } = List.walk payload {
+            buffer: bytesHead,
+            itemsLeft: List.len payload,
+        } writePayload
 
         List.append bytesWithPayload (Num.toU8 ']')
         |> List.append (Num.toU8 '}')
@@ -555,8 +564,7 @@ decodeTuple = \initialState, stepElem, finalizer -> Decode.custom \initialBytes,
                                 { rest: beforeCommaOrBreak } <- bytes |> anything |> tryDecode
                                 { result: Ok state, rest: beforeCommaOrBreak }
 
-                            Next decoder ->
-                                Decode.decodeWith bytes decoder json
+                            Next decoder -> Decode.decodeWith bytes decoder json
                     )
 
             { result: commaResult, rest: nextBytes } = comma beforeCommaOrBreak
@@ -639,8 +647,7 @@ takeJsonNumber = \bytes ->
 
             { taken, rest: List.dropFirst bytes n }
 
-        _ ->
-            { taken: [], rest: bytes }
+        _ -> { taken: [], rest: bytes }
 
 numberHelp : NumberState, U8 -> [Continue NumberState, Break NumberState]
 numberHelp = \state, byte ->
@@ -825,9 +832,7 @@ expect
 # handled in json list or record decodin.
 decodeString = Decode.custom \bytes, @Json {} ->
     when bytes is
-        ['n', 'u', 'l', 'l', ..] ->
-            { result: Ok "null", rest: List.dropFirst bytes 4 }
-
+        ['n', 'u', 'l', 'l', ..] -> { result: Ok "null", rest: List.dropFirst bytes 4 }
         _ ->
             { taken: strBytes, rest } = takeJsonString bytes
 
@@ -839,33 +844,21 @@ decodeString = Decode.custom \bytes, @Json {} ->
                 # bytes
                 result =
                     strBytes
-                    |> List.sublist {
-                        start: 1,
-                        len: Num.subSaturated (List.len strBytes) 2,
-                    }
+                    |> List.sublist { start: 1, len: Num.subSaturated (List.len strBytes) 2 }
                     |> \bytesWithoutQuotationMarks ->
                         replaceEscapedChars { inBytes: bytesWithoutQuotationMarks, outBytes: [] }
                     |> .outBytes
                     |> Str.fromUtf8
 
                 when result is
-                    Ok str ->
-                        { result: Ok str, rest }
-
-                    Err _ ->
-                        { result: Err TooShort, rest: bytes }
+                    Ok str -> { result: Ok str, rest }
+                    Err _ -> { result: Err TooShort, rest: bytes }
 
 takeJsonString : List U8 -> { taken : List U8, rest : List U8 }
 takeJsonString = \bytes ->
     when List.walkUntil bytes Start stringHelp is
-        Finish n ->
-            {
-                taken: List.sublist bytes { start: 0, len: n },
-                rest: List.dropFirst bytes n,
-            }
-
-        _ ->
-            { taken: [], rest: bytes }
+        Finish n -> { taken: List.sublist bytes { start: 0, len: n }, rest: List.dropFirst bytes n }
+        _ -> { taken: [], rest: bytes }
 
 stringHelp : StringState, U8 -> [Continue StringState, Break StringState]
 stringHelp = \state, byte ->
@@ -988,7 +981,10 @@ expect
 
 unicodeReplacement = hexToUtf8 'f' 'f' 'd' 'd'
 
-replaceEscapedChars : { inBytes : List U8, outBytes : List U8 } -> { inBytes : List U8, outBytes : List U8 }
+replaceEscapedChars : { inBytes : List U8, outBytes : List U8 } -> {
+    inBytes : List U8,
+    outBytes : List U8,
+}
 replaceEscapedChars = \{ inBytes, outBytes } ->
 
     firstByte = List.get inBytes 0
@@ -1029,8 +1025,7 @@ replaceEscapedChars = \{ inBytes, outBytes } ->
                 outBytes: List.append outBytes a,
             }
 
-        _ ->
-            { inBytes, outBytes }
+        _ -> { inBytes, outBytes }
 
 # Test replacement of both extended and shorthand unicode escapes
 expect
@@ -1085,8 +1080,7 @@ decodeList = \elemDecoder -> Decode.custom \bytes, @Json {} ->
 
         when result is
             Ok elemBytes -> decodeElems elemBytes []
-            Err ExpectedOpeningBracket ->
-                crash "expected opening bracket"
+            Err ExpectedOpeningBracket -> crash "expected opening bracket"
 
 arrayElemDecoder = \elemDecoder ->
 
@@ -1145,7 +1139,9 @@ arrayOpeningHelp = \state, byte ->
 arrayClosingHelp : ArrayClosingState, U8 -> [Continue ArrayClosingState, Break ArrayClosingState]
 arrayClosingHelp = \state, byte ->
     when (state, byte) is
-        (BeforeNextElemOrClosingBracket n, b) if isWhitespace b -> Continue (BeforeNextElemOrClosingBracket (n + 1))
+        (BeforeNextElemOrClosingBracket n, b) if isWhitespace b ->
+            Continue (BeforeNextElemOrClosingBracket (n + 1))
+
         (BeforeNextElemOrClosingBracket n, b) if b == ',' -> Continue (BeforeNextElement (n + 1))
         (BeforeNextElemOrClosingBracket n, b) if b == ']' -> Continue (AfterClosingBracket (n + 1))
         (BeforeNextElement n, b) if isWhitespace b -> Continue (BeforeNextElement (n + 1))
@@ -1165,10 +1161,7 @@ expect
 
     actual == expected
 
-ArrayOpeningState : [
-    BeforeOpeningBracket U64,
-    AfterOpeningBracket U64,
-]
+ArrayOpeningState : [BeforeOpeningBracket U64, AfterOpeningBracket U64]
 
 ArrayClosingState : [
     BeforeNextElemOrClosingBracket U64,
@@ -12,4 +12,10 @@ package [
     Box,
     TotallyNotJson,
     Inspect,
+    Random,
+    Duration,
+    Date,
+    Time,
+    Order,
+    Target,
 ] {}
@@ -299,12 +299,14 @@ pub const INT_TO_FLOAT_CAST_F64: IntrinsicName =
 
 pub const NUM_ADD_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_or_panic");
 pub const NUM_ADD_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_saturated");
+pub const NUM_ADD_WRAP_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_wrapped");
 pub const NUM_ADD_CHECKED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_with_overflow");
 pub const NUM_ADD_CHECKED_FLOAT: IntrinsicName =
     float_intrinsic!("roc_builtins.num.add_with_overflow");
 
 pub const NUM_SUB_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_or_panic");
 pub const NUM_SUB_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_saturated");
+pub const NUM_SUB_WRAP_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_wrapped");
 pub const NUM_SUB_CHECKED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_with_overflow");
 pub const NUM_SUB_CHECKED_FLOAT: IntrinsicName =
     float_intrinsic!("roc_builtins.num.sub_with_overflow");
@@ -318,9 +320,15 @@ pub const NUM_MUL_CHECKED_FLOAT: IntrinsicName =
 
 pub const NUM_IS_MULTIPLE_OF: IntrinsicName = int_intrinsic!("roc_builtins.num.is_multiple_of");
 
+pub const NUM_SHIFT_LEFT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_left_by");
+pub const NUM_SHIFT_RIGHT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_right_by");
 pub const NUM_SHIFT_RIGHT_ZERO_FILL: IntrinsicName =
     int_intrinsic!("roc_builtins.num.shift_right_zero_fill");
 
+pub const NUM_BITWISE_AND: IntrinsicName = int_intrinsic!("roc_builtins.num.bitwise_and");
+pub const NUM_BITWISE_OR: IntrinsicName = int_intrinsic!("roc_builtins.num.bitwise_or");
+pub const NUM_BITWISE_XOR: IntrinsicName = int_intrinsic!("roc_builtins.num.bitwise_xor");
+
 pub const NUM_COMPARE: IntrinsicName = int_intrinsic!("roc_builtins.num.compare");
 pub const NUM_LESS_THAN: IntrinsicName = int_intrinsic!("roc_builtins.num.less_than");
 pub const NUM_LESS_THAN_OR_EQUAL: IntrinsicName =
@@ -358,6 +366,9 @@ pub const STR_TO_DECIMAL: &str = "roc_builtins.str.to_decimal";
 pub const STR_EQUAL: &str = "roc_builtins.str.equal";
 pub const STR_SUBSTRING_UNSAFE: &str = "roc_builtins.str.substring_unsafe";
 pub const STR_TO_UTF8: &str = "roc_builtins.str.to_utf8";
+pub const STR_TO_SCALARS: &str = "roc_builtins.str.to_scalars";
+pub const STR_GRAPHEMES: &str = "roc_builtins.str.graphemes";
+pub const STR_COUNT_GRAPHEMES: &str = "roc_builtins.str.count_graphemes";
 pub const STR_FROM_UTF8: &str = "roc_builtins.str.from_utf8";
 pub const STR_REPEAT: &str = "roc_builtins.str.repeat";
 pub const STR_TRIM: &str = "roc_builtins.str.trim";
@@ -374,6 +385,7 @@ pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
 pub const LIST_MAP3: &str = "roc_builtins.list.map3";
 pub const LIST_MAP4: &str = "roc_builtins.list.map4";
+pub const LIST_WALK: &str = "roc_builtins.list.walk";
 pub const LIST_SUBLIST: &str = "roc_builtins.list.sublist";
 pub const LIST_DROP_AT: &str = "roc_builtins.list.drop_at";
 pub const LIST_SWAP: &str = "roc_builtins.list.swap";
@@ -418,6 +430,7 @@ pub const DEC_SUB_OR_PANIC: &str = "roc_builtins.dec.sub_or_panic";
 pub const DEC_SUB_SATURATED: &str = "roc_builtins.dec.sub_saturated";
 pub const DEC_SUB_WITH_OVERFLOW: &str = "roc_builtins.dec.sub_with_overflow";
 pub const DEC_TAN: &str = "roc_builtins.dec.tan";
+pub const DEC_TO_F64: &str = "roc_builtins.dec.to_f64";
 pub const DEC_TO_I128: &str = "roc_builtins.dec.to_i128";
 pub const DEC_FROM_I128: &str = "roc_builtins.dec.from_i128";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
@@ -437,6 +450,15 @@ pub const UTILS_FREE_DATA_PTR: &str = "roc_builtins.utils.free_data_ptr";
 pub const UTILS_IS_UNIQUE: &str = "roc_builtins.utils.is_unique";
 pub const UTILS_DECREF_CHECK_NULL: &str = "roc_builtins.utils.decref_check_null";
 pub const UTILS_DICT_PSEUDO_SEED: &str = "roc_builtins.utils.dict_pseudo_seed";
+pub const UTILS_LEAK_CHECK_REPORT: &str = "roc_builtins.utils.leak_check_report";
+pub const UTILS_LEAK_CHECK_ENABLED: &str = "roc_builtins.utils.leak_check_enabled";
+pub const UTILS_LEAK_CHECK_SITE: &str = "roc_builtins.utils.leak_check_site";
+pub const UTILS_SET_DICT_SEED: &str = "roc_set_dict_seed";
+pub const UTILS_TEST_SEED: &str = "roc_builtins.utils.test_seed";
+pub const UTILS_SET_TEST_SEED: &str = "roc_set_test_seed";
+
+/// The seed of every Dict in tests, so that their order and hashes are the same on every run.
+pub const DICT_TEST_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
 
 pub const UTILS_EXPECT_FAILED_START_SHARED_BUFFER: &str =
     "roc_builtins.utils.expect_failed_start_shared_buffer";
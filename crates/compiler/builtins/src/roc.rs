@@ -17,6 +17,12 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
         ModuleId::HASH => HASH,
         ModuleId::INSPECT => INSPECT,
         ModuleId::JSON => JSON,
+        ModuleId::RANDOM => RANDOM,
+        ModuleId::DURATION => DURATION,
+        ModuleId::DATE => DATE,
+        ModuleId::TIME => TIME,
+        ModuleId::ORDER => ORDER,
+        ModuleId::TARGET => TARGET,
         _ => internal_error!(
             "ModuleId {:?} is not part of the standard library",
             module_id
@@ -24,6 +30,31 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
     }
 }
 
+/// The file name and source of each file in the standard library's package, `main.roc` included.
+pub const PACKAGE_FILES: &[(&str, &str)] = &[
+    ("main.roc", MAIN),
+    ("Result.roc", RESULT),
+    ("Num.roc", NUM),
+    ("Str.roc", STR),
+    ("List.roc", LIST),
+    ("Dict.roc", DICT),
+    ("Set.roc", SET),
+    ("Box.roc", BOX),
+    ("Bool.roc", BOOL),
+    ("Encode.roc", ENCODE),
+    ("Decode.roc", DECODE),
+    ("Hash.roc", HASH),
+    ("Inspect.roc", INSPECT),
+    ("TotallyNotJson.roc", JSON),
+    ("Random.roc", RANDOM),
+    ("Duration.roc", DURATION),
+    ("Date.roc", DATE),
+    ("Time.roc", TIME),
+    ("Order.roc", ORDER),
+    ("Target.roc", TARGET),
+];
+
+const MAIN: &str = include_str!("../roc/main.roc");
 const RESULT: &str = include_str!("../roc/Result.roc");
 const NUM: &str = include_str!("../roc/Num.roc");
 const STR: &str = include_str!("../roc/Str.roc");
@@ -37,3 +68,9 @@ const DECODE: &str = include_str!("../roc/Decode.roc");
 const HASH: &str = include_str!("../roc/Hash.roc");
 const INSPECT: &str = include_str!("../roc/Inspect.roc");
 const JSON: &str = include_str!("../roc/TotallyNotJson.roc");
+const RANDOM: &str = include_str!("../roc/Random.roc");
+const DURATION: &str = include_str!("../roc/Duration.roc");
+const DATE: &str = include_str!("../roc/Date.roc");
+const TIME: &str = include_str!("../roc/Time.roc");
+const ORDER: &str = include_str!("../roc/Order.roc");
+const TARGET: &str = include_str!("../roc/Target.roc");
@@ -388,6 +388,8 @@ pub(crate) fn make_apply_symbol(
         match scope.lookup_str(ident, region) {
             Ok(symbol) => {
                 references.insert_type_lookup(symbol, QualifiedReference::Unqualified);
+                env.register_builtin_lookup(symbol, region);
+
                 Ok(symbol)
             }
             Err(problem) => {
@@ -118,6 +118,9 @@ map_symbol_to_lowlevel_and_arity! {
     StrCountUtf8Bytes; STR_COUNT_UTF8_BYTES; 1,
     StrFromUtf8; STR_FROM_UTF8_LOWLEVEL; 1,
     StrToUtf8; STR_TO_UTF8; 1,
+    StrToScalars; STR_TO_SCALARS; 1,
+    StrGraphemes; STR_GRAPHEMES; 1,
+    StrCountGraphemes; STR_COUNT_GRAPHEMES; 1,
     StrRepeat; STR_REPEAT; 2,
     StrTrim; STR_TRIM; 1,
     StrTrimStart; STR_TRIM_START; 1,
@@ -220,6 +223,7 @@ map_symbol_to_lowlevel_and_arity! {
     UnboxExpr; BOX_UNBOX; 1,
     Unreachable; LIST_UNREACHABLE; 1,
     DictPseudoSeed; DICT_PSEUDO_SEED; 1,
+    RandomTestSeed; RANDOM_TEST_SEED; 1,
 }
 
 /// Some builtins cannot be constructed in code gen alone, and need to be defined
@@ -10,13 +10,13 @@ use crate::annotation::IntroducedVariables;
 use crate::annotation::OwnedNamedOrAble;
 use crate::derive;
 use crate::env::Env;
-use crate::expr::get_lookup_symbols;
 use crate::expr::AnnotatedMark;
 use crate::expr::ClosureData;
 use crate::expr::Declarations;
 use crate::expr::Expr::{self, *};
 use crate::expr::StructAccessorData;
 use crate::expr::{canonicalize_expr, Output, Recursive};
+use crate::expr::{get_lookup_symbols, report_expect_with_dbg};
 use crate::pattern::{canonicalize_def_header_pattern, BindingsFromPattern, Pattern};
 use crate::procedure::References;
 use crate::scope::create_alias;
@@ -1135,6 +1135,7 @@ fn canonicalize_value_defs<'a>(
     let mut def_ordering = DefOrdering::from_symbol_to_id(env.home, symbol_to_index, capacity);
 
     for (def_id, pending_def) in pending_value_defs.into_iter().enumerate() {
+        let _compiling = roc_region::compiling::enter(pending_def.loc_pattern().region);
         let temp_output = canonicalize_pending_value_def(
             env,
             pending_def,
@@ -2602,7 +2603,7 @@ pub fn can_defs_with_return<'a>(
     let mut loc_expr: Loc<Expr> = ret_expr;
 
     for declaration in declarations.into_iter().rev() {
-        loc_expr = decl_to_let(declaration, loc_expr);
+        loc_expr = decl_to_let(env, var_store, scope, &mut output, declaration, loc_expr);
     }
 
     (loc_expr.value, output)
@@ -2630,7 +2631,14 @@ pub fn report_unused_imports(
     }
 }
 
-fn decl_to_let(decl: Declaration, loc_ret: Loc<Expr>) -> Loc<Expr> {
+fn decl_to_let(
+    env: &mut Env,
+    var_store: &mut VarStore,
+    scope: &mut Scope,
+    output: &mut Output,
+    decl: Declaration,
+    loc_ret: Loc<Expr>,
+) -> Loc<Expr> {
     match decl {
         Declaration::Declare(def) => {
             let region = Region::span_across(&def.loc_pattern.region, &loc_ret.region);
@@ -2662,10 +2670,25 @@ fn decl_to_let(decl: Declaration, loc_ret: Loc<Expr>) -> Loc<Expr> {
                 let region = Region::span_across(&expect_region, &loc_ret.region);
                 let lookups_in_cond = get_lookup_symbols(&condition);
 
-                let expr = Expr::Expect {
-                    loc_condition: Box::new(Loc::at(condition_region, condition)),
-                    loc_continuation: Box::new(loc_ret),
-                    lookups_in_cond,
+                let expr = if env.report_expects_with_dbg && !env.home.is_builtin() {
+                    let (expr, report_output) = report_expect_with_dbg(
+                        env,
+                        var_store,
+                        scope,
+                        Loc::at(condition_region, condition),
+                        &lookups_in_cond,
+                        loc_ret,
+                    );
+
+                    output.union(report_output);
+
+                    expr
+                } else {
+                    Expr::Expect {
+                        loc_condition: Box::new(Loc::at(condition_region, condition)),
+                        loc_continuation: Box::new(loc_ret),
+                        lookups_in_cond,
+                    }
                 };
 
                 loc_ret = Loc::at(region, expr);
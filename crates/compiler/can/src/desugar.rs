@@ -1136,9 +1136,19 @@ fn desugar_bin_ops<'a>(
     let mut arg_stack: Vec<&'a Loc<Expr>> = Vec::with_capacity_in(lefts.len() + 1, arena);
     let mut op_stack: Vec<Loc<BinOp>> = Vec::with_capacity_in(lefts.len(), arena);
 
-    for (loc_expr, loc_op) in lefts {
+    for (index, (loc_expr, loc_op)) in lefts.iter().enumerate() {
         arg_stack.push(desugar_expr(arena, loc_expr, src, line_info, module_path));
-        match run_binop_step(arena, whole_region, &mut arg_stack, &mut op_stack, *loc_op) {
+
+        let next_operand = operand_after(lefts, right, index);
+
+        match run_binop_step(
+            arena,
+            whole_region,
+            &mut arg_stack,
+            &mut op_stack,
+            *loc_op,
+            next_operand,
+        ) {
             Err(problem) => return problem,
             Ok(()) => continue,
         }
@@ -1153,6 +1163,27 @@ fn desugar_bin_ops<'a>(
     expr
 }
 
+/// The region of the operand after the operator at `index`, including any operators in it that
+/// bind more tightly, like `c + 1` in `a < b < c + 1`.
+fn operand_after(
+    lefts: &[(Loc<Expr<'_>>, Loc<BinOp>)],
+    right: &Loc<Expr<'_>>,
+    index: usize,
+) -> Region {
+    let op = lefts[index].1.value;
+    let mut start = None;
+
+    for (loc_expr, next_op) in &lefts[index + 1..] {
+        let first = *start.get_or_insert(loc_expr.region);
+
+        if next_op.value <= op {
+            return Region::span_across(&first, &loc_expr.region);
+        }
+    }
+
+    Region::span_across(&start.unwrap_or(right.region), &right.region)
+}
+
 enum Step<'a> {
     Error(&'a Loc<Expr<'a>>),
     Push(Loc<BinOp>),
@@ -1165,12 +1196,27 @@ fn run_binop_step<'a>(
     arg_stack: &mut Vec<&'a Loc<Expr<'a>>>,
     op_stack: &mut Vec<Loc<BinOp>>,
     next_op: Loc<BinOp>,
+    next_operand: Region,
 ) -> Result<(), &'a Loc<Expr<'a>>> {
     use Step::*;
 
-    match binop_step(arena, whole_region, arg_stack, op_stack, next_op) {
+    match binop_step(
+        arena,
+        whole_region,
+        arg_stack,
+        op_stack,
+        next_op,
+        next_operand,
+    ) {
         Error(problem) => Err(problem),
-        Push(loc_op) => run_binop_step(arena, whole_region, arg_stack, op_stack, loc_op),
+        Push(loc_op) => run_binop_step(
+            arena,
+            whole_region,
+            arg_stack,
+            op_stack,
+            loc_op,
+            next_operand,
+        ),
         Skip => Ok(()),
     }
 }
@@ -1181,6 +1227,8 @@ fn binop_step<'a>(
     arg_stack: &mut Vec<&'a Loc<Expr<'a>>>,
     op_stack: &mut Vec<Loc<BinOp>>,
     next_op: Loc<BinOp>,
+    // The operand after next_op
+    next_operand: Region,
 ) -> Step<'a> {
     use roc_module::called_via::Associativity::*;
     use std::cmp::Ordering;
@@ -1245,6 +1293,7 @@ fn binop_step<'a>(
                                 binop1: stack_op.value,
                                 binop2_position: bad_op.region.start(),
                                 binop2: bad_op.value,
+                                operands: [left.region, right.region, next_operand],
                                 expr: arena.alloc(broken_expr),
                             };
                             let value = Expr::PrecedenceConflict(arena.alloc(data));
@@ -7,7 +7,7 @@ use roc_collections::{MutMap, VecSet};
 use roc_module::ident::{Ident, ModuleName};
 use roc_module::symbol::{IdentIdsByModule, ModuleId, PQModuleName, PackageModuleIds, Symbol};
 use roc_problem::can::{Problem, RuntimeError};
-use roc_region::all::{Loc, Region};
+use roc_region::all::{LineInfo, Loc, Region};
 
 /// The canonicalization environment for a particular module.
 pub struct Env<'a> {
@@ -17,6 +17,11 @@ pub struct Env<'a> {
 
     pub module_path: &'a Path,
 
+    /// The module's source, used to point `dbg` reports at the code they came from.
+    pub src: &'a str,
+
+    line_info: Option<LineInfo>,
+
     pub dep_idents: &'a IdentIdsByModule,
 
     pub qualified_module_ids: &'a PackageModuleIds<'a>,
@@ -38,24 +43,37 @@ pub struct Env<'a> {
 
     pub top_level_symbols: VecSet<Symbol>,
 
+    /// Values and types of builtin modules that this module refers to. The platform decides which
+    /// builtin modules are available, and it may not be known yet, so load checks these later.
+    pub builtin_lookups: Vec<Loc<Symbol>>,
+
     pub arena: &'a Bump,
 
     pub opt_shorthand: Option<&'a str>,
+
+    /// Report failing inline `expect`s through `dbg`, for backends that can't stop and
+    /// report them the way the LLVM backend does.
+    pub report_expects_with_dbg: bool,
 }
 
 impl<'a> Env<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         arena: &'a Bump,
         home: ModuleId,
         module_path: &'a Path,
+        src: &'a str,
         dep_idents: &'a IdentIdsByModule,
         qualified_module_ids: &'a PackageModuleIds<'a>,
         opt_shorthand: Option<&'a str>,
+        report_expects_with_dbg: bool,
     ) -> Env<'a> {
         Env {
             arena,
             home,
             module_path,
+            src,
+            line_info: None,
             dep_idents,
             qualified_module_ids,
             problems: Vec::new(),
@@ -64,10 +82,18 @@ impl<'a> Env<'a> {
             qualified_type_lookups: VecSet::default(),
             tailcallable_symbol: None,
             top_level_symbols: VecSet::default(),
+            builtin_lookups: Vec::new(),
             opt_shorthand,
+            report_expects_with_dbg,
         }
     }
 
+    pub fn line_info(&mut self) -> &LineInfo {
+        // Computed lazily, so modules that never need it don't pay for scanning the source.
+        self.line_info
+            .get_or_insert_with(|| LineInfo::new(self.src))
+    }
+
     pub fn qualified_lookup(
         &mut self,
         scope: &Scope,
@@ -168,6 +194,8 @@ impl<'a> Env<'a> {
                             self.qualified_value_lookups.insert(symbol);
                         }
 
+                        self.register_builtin_lookup(symbol, region);
+
                         Ok(symbol)
                     }
                     None => Err(RuntimeError::ValueNotExposed {
@@ -213,4 +241,11 @@ impl<'a> Env<'a> {
     pub fn problem(&mut self, problem: Problem) {
         self.problems.push(problem)
     }
+
+    /// Remembers where a builtin is used outside of the builtins themselves.
+    pub fn register_builtin_lookup(&mut self, symbol: Symbol, region: Region) {
+        if symbol.module_id().is_builtin() && !self.home.is_builtin() {
+            self.builtin_lookups.push(Loc::at(region, symbol));
+        }
+    }
 }
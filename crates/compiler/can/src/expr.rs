@@ -15,7 +15,7 @@ use roc_collections::soa::Index;
 use roc_collections::{SendMap, VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::called_via::CalledVia;
-use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
+use roc_module::ident::{ForeignSymbol, Lowercase, ModuleName, TagName};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, Defs, PrecedenceConflict, StrLiteral};
@@ -673,6 +673,8 @@ pub fn canonicalize_expr<'a>(
                     Ok((can_fields, mut output)) => {
                         output.references.union_mut(&update_out.references);
 
+                        report_unchanged_update_fields(env, *symbol, region, &can_fields);
+
                         let answer = RecordUpdate {
                             record_var: var_store.fresh(),
                             ext_var: var_store.fresh(),
@@ -1085,7 +1087,9 @@ pub fn canonicalize_expr<'a>(
             (expr, output)
         }
         ast::Expr::RecordAccess(record_expr, field) => {
-            let (loc_expr, output) = canonicalize_expr(env, var_store, scope, region, record_expr);
+            let record_region = access_chain_region(region, record_expr);
+            let (loc_expr, output) =
+                canonicalize_expr(env, var_store, scope, record_region, record_expr);
 
             (
                 RecordAccess {
@@ -1114,7 +1118,9 @@ pub fn canonicalize_expr<'a>(
             Output::default(),
         ),
         ast::Expr::TupleAccess(tuple_expr, field) => {
-            let (loc_expr, output) = canonicalize_expr(env, var_store, scope, region, tuple_expr);
+            let tuple_region = access_chain_region(region, tuple_expr);
+            let (loc_expr, output) =
+                canonicalize_expr(env, var_store, scope, tuple_region, tuple_expr);
 
             (
                 TupleAccess {
@@ -1203,14 +1209,29 @@ pub fn canonicalize_expr<'a>(
             output.union(output1);
             output.union(output2);
 
-            (
-                Expect {
-                    loc_condition: Box::new(loc_condition),
-                    loc_continuation: Box::new(loc_continuation),
-                    lookups_in_cond,
-                },
-                output,
-            )
+            if env.report_expects_with_dbg && !env.home.is_builtin() {
+                let (expr, output3) = report_expect_with_dbg(
+                    env,
+                    var_store,
+                    scope,
+                    loc_condition,
+                    &lookups_in_cond,
+                    loc_continuation,
+                );
+
+                output.union(output3);
+
+                (expr, output)
+            } else {
+                (
+                    Expect {
+                        loc_condition: Box::new(loc_condition),
+                        loc_continuation: Box::new(loc_continuation),
+                        lookups_in_cond,
+                    },
+                    output,
+                )
+            }
         }
         ast::Expr::Dbg(_, _) => {
             internal_error!("Dbg should have been desugared by now")
@@ -1301,6 +1322,7 @@ pub fn canonicalize_expr<'a>(
             binop2_position,
             binop1,
             binop2,
+            operands,
             expr: _,
         }) => {
             use roc_problem::can::RuntimeError::*;
@@ -1318,7 +1340,12 @@ pub fn canonicalize_expr<'a>(
             let loc_binop2 = Loc::at(region2, *binop2);
 
             let problem =
-                PrecedenceProblem::BothNonAssociative(*whole_region, loc_binop1, loc_binop2);
+                PrecedenceProblem::BothNonAssociative(
+                    *whole_region,
+                    loc_binop1,
+                    loc_binop2,
+                    *operands,
+                );
 
             env.problem(Problem::PrecedenceProblem(problem.clone()));
 
@@ -1727,6 +1754,89 @@ fn canonicalize_when_branch<'a>(
     )
 }
 
+/// The parser represents `Foo.bar.baz` as `(Foo.bar).baz` without giving the inner
+/// expressions regions of their own. When the accessed expression is such an access chain,
+/// narrow the region of the whole chain down to just the inner part, so that problems with
+/// e.g. the `Foo.bar` lookup point at `Foo.bar` rather than at `Foo.bar.baz`.
+fn access_chain_region(region: Region, accessed: &ast::Expr) -> Region {
+    fn chain_len(expr: &ast::Expr) -> Option<u32> {
+        match expr {
+            ast::Expr::Var { module_name, ident } => {
+                let qualifier_len = if module_name.is_empty() {
+                    0
+                } else {
+                    module_name.len() + 1
+                };
+
+                Some((qualifier_len + ident.len()) as u32)
+            }
+            ast::Expr::RecordAccess(inner, field) | ast::Expr::TupleAccess(inner, field) => {
+                chain_len(inner).map(|len| len + 1 + field.len() as u32)
+            }
+            _ => None,
+        }
+    }
+
+    match chain_len(accessed) {
+        Some(len) if len < region.len() => {
+            Region::new(region.start(), region.start().bump_column(len))
+        }
+        _ => region,
+    }
+}
+
+/// Warn about fields in `{ r & x: r.x }` that are set to the value they already have.
+/// These are almost always leftovers from a refactoring.
+///
+/// Record builders are not checked: each of them applies at least one `<-` field, so they never
+/// just copy a record, and a plain field copied into one is building a new record type.
+fn report_unchanged_update_fields(
+    env: &mut Env,
+    record: Symbol,
+    record_region: Region,
+    fields: &SendMap<Lowercase, Field>,
+) {
+    let mut unchanged: Vec<(&Lowercase, Region)> = fields
+        .iter()
+        .filter(|(label, field)| match &field.loc_expr.value {
+            Expr::RecordAccess {
+                loc_expr,
+                field: accessed,
+                ..
+            } => {
+                accessed == *label
+                    && matches!(loc_expr.value, Expr::Var(symbol, _) if symbol == record)
+            }
+            _ => false,
+        })
+        .map(|(label, field)| (label, field.region))
+        .collect();
+
+    if unchanged.is_empty() {
+        return;
+    }
+
+    if unchanged.len() == fields.len() {
+        env.problem(Problem::IdentityRecordUpdate {
+            record,
+            region: record_region,
+        });
+
+        return;
+    }
+
+    unchanged.sort_by_key(|(_, field_region)| *field_region);
+
+    for (label, field_region) in unchanged {
+        env.problem(Problem::UnchangedRecordUpdateField {
+            record,
+            field_name: label.clone(),
+            record_region,
+            field_region,
+        });
+    }
+}
+
 enum CanonicalizeRecordProblem {
     InvalidOptionalValue {
         field_name: Lowercase,
@@ -1856,6 +1966,8 @@ fn canonicalize_var_lookup(
                     .references
                     .insert_value_lookup(symbol, QualifiedReference::Unqualified);
 
+                env.register_builtin_lookup(symbol, region);
+
                 if scope.abilities_store.is_ability_member_name(symbol) {
                     AbilityMember(
                         symbol,
@@ -3084,6 +3196,126 @@ pub struct DestructureDef {
     pub pattern_vars: VecMap<Symbol, Variable>,
 }
 
+/// Lowers an inline `expect` to
+///
+/// ```text
+/// #report = if condition then {} else dbg "This expectation failed. ..." {}
+/// continuation
+/// ```
+///
+/// where the message renders the values the condition looked up with `Inspect.toStr`.
+/// Backends without the LLVM backend's expect machinery report failures this way, and
+/// then carry on with the continuation like the LLVM backend does.
+pub(crate) fn report_expect_with_dbg<'a>(
+    env: &mut Env<'a>,
+    var_store: &mut VarStore,
+    scope: &mut Scope,
+    loc_condition: Loc<Expr>,
+    lookups_in_cond: &[ExpectLookup],
+    loc_continuation: Loc<Expr>,
+) -> (Expr, Output) {
+    use Expr::*;
+
+    let arena = env.arena;
+    let region = loc_condition.region;
+
+    // Only report the lookups that the condition's own scope can see by name,
+    // i.e. not the arguments of closures defined inside the condition.
+    let names = lookups_in_cond.iter().filter_map(|lookup| {
+        if lookup.symbol.module_id() != env.home {
+            return None;
+        }
+
+        let name = scope.locals.ident_ids.get_name(lookup.symbol.ident_id())?;
+
+        match scope.lookup_str(name, region) {
+            Ok(symbol) if symbol == lookup.symbol => Some(name),
+            _ => None,
+        }
+    });
+
+    let mut segments = bumpalo::collections::Vec::new_in(arena);
+    segments.push(ast::StrSegment::Plaintext("This expectation failed."));
+
+    for (index, name) in names.enumerate() {
+        if index == 0 {
+            segments.push(ast::StrSegment::Plaintext(
+                " When it failed, these variables had these values:",
+            ));
+        }
+
+        let inspect_fn = arena.alloc(Loc::at(
+            region,
+            ast::Expr::Var {
+                module_name: ModuleName::INSPECT,
+                ident: "toStr",
+            },
+        ));
+        let value = arena.alloc(Loc::at(
+            region,
+            ast::Expr::Var {
+                module_name: "",
+                ident: arena.alloc_str(name),
+            },
+        ));
+
+        segments.push(ast::StrSegment::Plaintext(
+            arena.alloc_str(&format!("\n    {name} = ")),
+        ));
+        segments.push(ast::StrSegment::Interpolated(Loc::at(
+            region,
+            arena.alloc(ast::Expr::Apply(
+                inspect_fn,
+                arena.alloc([&*value]),
+                CalledVia::Space,
+            )),
+        )));
+    }
+
+    let line = env.line_info().convert_pos(region.start()).line + 1;
+    let source_location = format!("{}:{}", env.module_path.display(), line);
+    let source = format!(
+        "expect {}",
+        &env.src[region.start().offset as usize..region.end().offset as usize]
+    );
+
+    let dbg = arena.alloc(ast::Expr::LowLevelDbg(
+        arena.alloc((
+            &*arena.alloc_str(&source_location),
+            &*arena.alloc_str(&source),
+        )),
+        arena.alloc(Loc::at(
+            region,
+            ast::Expr::Str(StrLiteral::Line(segments.into_bump_slice())),
+        )),
+        arena.alloc(Loc::at(region, ast::Expr::Record(ast::Collection::empty()))),
+    ));
+
+    let (loc_dbg, output) = canonicalize_expr(env, var_store, scope, region, dbg);
+
+    let report = If {
+        cond_var: var_store.fresh(),
+        branch_var: var_store.fresh(),
+        branches: vec![(loc_condition, Loc::at(region, EmptyRecord))],
+        final_else: Box::new(loc_dbg),
+    };
+
+    let symbol = scope.gen_unique_symbol();
+    let expr_var = var_store.fresh();
+    let mut pattern_vars = SendMap::default();
+    pattern_vars.insert(symbol, expr_var);
+
+    let def = Def {
+        loc_pattern: Loc::at(region, Pattern::Identifier(symbol)),
+        loc_expr: Loc::at(region, report),
+        expr_var,
+        pattern_vars,
+        annotation: None,
+    };
+
+    (LetNonRec(Box::new(def), Box::new(loc_continuation)), output)
+}
+
 pub(crate) fn get_lookup_symbols(expr: &Expr) -> Vec<ExpectLookup> {
     let mut stack: Vec<&Expr> = vec![expr];
     let mut lookups: Vec<ExpectLookup> = Vec::new();
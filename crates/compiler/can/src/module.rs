@@ -14,9 +14,11 @@ use crate::scope::Scope;
 use bumpalo::Bump;
 use roc_collections::{MutMap, SendMap, VecMap, VecSet};
 use roc_error_macros::internal_error;
-use roc_module::ident::Ident;
 use roc_module::ident::Lowercase;
-use roc_module::symbol::{IdentIds, IdentIdsByModule, ModuleId, PackageModuleIds, Symbol};
+use roc_module::ident::{Ident, ModuleName};
+use roc_module::symbol::{
+    IdentIds, IdentIdsByModule, ModuleId, PQModuleName, PackageModuleIds, Symbol,
+};
 use roc_parse::ast::{Defs, TypeAnnotation};
 use roc_parse::header::HeaderType;
 use roc_parse::pattern::PatternType;
@@ -159,6 +161,7 @@ pub struct ModuleOutput {
     pub scope: Scope,
     pub loc_expects: VecMap<Region, Vec<ExpectLookup>>,
     pub loc_dbgs: VecMap<Symbol, DbgLookup>,
+    pub builtin_lookups: Vec<Loc<Symbol>>,
 }
 
 fn validate_generate_with<'a>(
@@ -185,6 +188,21 @@ fn validate_generate_with<'a>(
     (functions, unknown)
 }
 
+/// Only builtin modules can be made unavailable by a platform.
+fn validate_unavailable(env: &mut Env, unavailable: &[Loc<roc_parse::header::ModuleName>]) {
+    for loc_name in unavailable {
+        let module_name = ModuleName::from(loc_name.value);
+        let is_builtin = env
+            .qualified_module_ids
+            .get_id(&PQModuleName::Unqualified(module_name.clone()))
+            .map_or(false, |module_id| module_id.is_builtin());
+
+        if !is_builtin {
+            env.problem(Problem::UnavailableNotBuiltin(module_name, loc_name.region));
+        }
+    }
+}
+
 #[derive(Debug)]
 enum GeneratedInfo {
     Hosted {
@@ -288,6 +306,7 @@ pub fn canonicalize_module_defs<'a>(
     symbols_from_requires: &[(Loc<Symbol>, Loc<TypeAnnotation<'a>>)],
     var_store: &mut VarStore,
     opt_shorthand: Option<&'a str>,
+    report_expects_with_dbg: bool,
 ) -> ModuleOutput {
     let mut can_exposed_imports = MutMap::default();
     let mut scope = Scope::new(
@@ -304,9 +323,11 @@ pub fn canonicalize_module_defs<'a>(
         arena,
         home,
         arena.alloc(Path::new(module_path)),
+        src,
         dep_idents,
         qualified_module_ids,
         opt_shorthand,
+        report_expects_with_dbg,
     );
 
     for (name, alias) in aliases.into_iter() {
@@ -323,6 +344,10 @@ pub fn canonicalize_module_defs<'a>(
     let generated_info =
         GeneratedInfo::from_header_type(&mut env, &mut scope, var_store, header_type);
 
+    if let HeaderType::Platform { unavailable, .. } = header_type {
+        validate_unavailable(&mut env, unavailable);
+    }
+
     // Desugar operators (convert them to Apply calls, taking into account
     // operator precedence and associativity rules), before doing other canonicalization.
     //
@@ -821,6 +846,7 @@ pub fn canonicalize_module_defs<'a>(
         loc_expects: collected.expects,
         loc_dbgs: collected.dbgs,
         exposed_symbols,
+        builtin_lookups: env.builtin_lookups,
     }
 }
 
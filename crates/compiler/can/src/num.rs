@@ -281,7 +281,7 @@ fn from_str_radix(src: &str, radix: u32) -> Result<ParsedNumResult, IntErrorKind
             Ok(ParsedNumResult::Float(
                 match result {
                     IntValue::I128(n) => i128::from_ne_bytes(n) as f64,
-                    IntValue::U128(n) => i128::from_ne_bytes(n) as f64,
+                    IntValue::U128(n) => u128::from_ne_bytes(n) as f64,
                 },
                 FloatBound::Exact(fw),
             ))
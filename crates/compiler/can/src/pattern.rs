@@ -11,7 +11,7 @@ use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
+use roc_problem::can::{IntErrorKind, MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
 use roc_region::all::{Loc, Region};
 use roc_types::num::SingleQuoteBound;
 use roc_types::subs::{VarStore, Variable};
@@ -490,6 +490,9 @@ pub fn canonicalize_pattern<'a>(
 
         &NumLiteral(str) => match pattern_type {
             WhenBranch => match finish_parsing_num(str) {
+                Err((raw, error @ (IntErrorKind::Overflow | IntErrorKind::Underflow))) => {
+                    int_out_of_range_pattern(env, error, ast::Base::Decimal, raw, region)
+                }
                 Err(_error) => {
                     let problem = MalformedPatternProblem::MalformedInt;
                     malformed_pattern(env, problem, region)
@@ -521,29 +524,18 @@ pub fn canonicalize_pattern<'a>(
             is_negative,
         } => match pattern_type {
             WhenBranch => match finish_parsing_base(string, base, is_negative) {
+                Err((raw, error @ (IntErrorKind::Overflow | IntErrorKind::Underflow))) => {
+                    int_out_of_range_pattern(env, error, base, raw, region)
+                }
                 Err(_error) => {
                     let problem = MalformedPatternProblem::MalformedBase(base);
                     malformed_pattern(env, problem, region)
                 }
-                Ok((IntValue::U128(_), _)) if is_negative => {
-                    // Can't negate a u128; that doesn't fit in any integer literal type we support.
-                    let problem = MalformedPatternProblem::MalformedInt;
-                    malformed_pattern(env, problem, region)
-                }
                 Ok((int, bound)) => {
-                    use std::ops::Neg;
-
-                    let sign_str = if is_negative { "-" } else { "" };
-                    let int_str = format!("{sign_str}{int}").into_boxed_str();
-                    let i = match int {
-                        // Safety: this is fine because I128::MAX = |I128::MIN| - 1
-                        IntValue::I128(n) if is_negative => {
-                            IntValue::I128(i128::from_ne_bytes(n).neg().to_ne_bytes())
-                        }
-                        IntValue::I128(n) => IntValue::I128(n),
-                        IntValue::U128(_) => unreachable!(),
-                    };
-                    Pattern::IntLiteral(var_store.fresh(), var_store.fresh(), int_str, i, bound)
+                    // the minus sign was already added before parsing, like in expressions
+                    let int_str = int.to_string().into_boxed_str();
+
+                    Pattern::IntLiteral(var_store.fresh(), var_store.fresh(), int_str, int, bound)
                 }
             },
             ptype => unsupported_pattern(env, ptype, region),
@@ -692,17 +684,19 @@ pub fn canonicalize_pattern<'a>(
                         });
                     }
                     OptionalField(label, loc_default) => {
+                        // The default is canonicalized before the label is introduced,
+                        // so that it cannot refer to the very field it provides a value for.
+                        let (can_default, expr_output) = canonicalize_expr(
+                            env,
+                            var_store,
+                            scope,
+                            loc_default.region,
+                            &loc_default.value,
+                        );
+
                         // an optional DOES introduce the label into scope!
                         match scope.introduce(label.into(), region) {
                             Ok(symbol) => {
-                                let (can_default, expr_output) = canonicalize_expr(
-                                    env,
-                                    var_store,
-                                    scope,
-                                    loc_default.region,
-                                    &loc_default.value,
-                                );
-
                                 // an optional field binds the symbol!
                                 output.references.insert_bound(symbol);
 
@@ -725,6 +719,10 @@ pub fn canonicalize_pattern<'a>(
                                     kind: ShadowKind::Variable,
                                 }));
 
+                                // The default was still canonicalized, so keep
+                                // the references it makes.
+                                output.union(expr_output);
+
                                 // No matter what the other patterns
                                 // are, we're definitely shadowed and will
                                 // get a runtime exception as soon as we
@@ -915,6 +913,31 @@ fn malformed_pattern(env: &mut Env, problem: MalformedPatternProblem, region: Re
     Pattern::MalformedPattern(problem, region)
 }
 
+/// When an integer pattern like `0x1_0000_0000_0000_0000_0000_0000_0000_0000` doesn't fit in
+/// any integer type, report it the same way as an integer literal expression that's too big
+/// or too small, and return a malformed pattern.
+fn int_out_of_range_pattern(
+    env: &mut Env,
+    error: IntErrorKind,
+    base: ast::Base,
+    raw: &str,
+    region: Region,
+) -> Pattern {
+    env.problem(Problem::RuntimeError(RuntimeError::InvalidInt(
+        error,
+        base,
+        region,
+        raw.into(),
+    )));
+
+    let problem = match base {
+        ast::Base::Decimal => MalformedPatternProblem::MalformedInt,
+        base => MalformedPatternProblem::MalformedBase(base),
+    };
+
+    Pattern::MalformedPattern(problem, region)
+}
+
 /// An iterator over the bindings made by a pattern.
 ///
 /// We attempt to make no allocations when we can.
@@ -31,6 +31,7 @@ pub enum DeclarationInfo<'a> {
         expr_var: Variable,
         pattern: Pattern,
         function: &'a Loc<expr::FunctionDef>,
+        annotation: Option<&'a Annotation>,
     },
     Destructure {
         loc_pattern: &'a Loc<Pattern>,
@@ -130,6 +131,7 @@ pub fn walk_decls<V: Visitor>(visitor: &mut V, decls: &Declarations) {
                     expr_var,
                     pattern,
                     function: function_def,
+                    annotation: decls.annotations[index].as_ref(),
                 }
             }
             Destructure(destructure_index) => {
@@ -191,6 +193,7 @@ pub fn walk_decl<V: Visitor>(visitor: &mut V, decl: DeclarationInfo<'_>) {
             expr_var,
             pattern,
             function,
+            annotation: _,
         } => {
             visitor.visit_pattern(&pattern, loc_symbol.region, Some(expr_var));
 
@@ -892,6 +895,119 @@ pub fn symbols_introduced_from_pattern(
     }
 }
 
+/// The identifiers bound by the pattern of the innermost `when` branch whose body contains
+/// `region`, with their variables.
+pub fn find_when_branch_bindings(region: Region, decls: &Declarations) -> Vec<(Symbol, Variable)> {
+    let mut visitor = Finder {
+        region,
+        bindings: Vec::new(),
+    };
+    visitor.visit_decls(decls);
+    return visitor.bindings;
+
+    struct Finder {
+        region: Region,
+        bindings: Vec<(Symbol, Variable)>,
+    }
+
+    impl Visitor for Finder {
+        fn should_visit(&mut self, region: Region) -> bool {
+            region.contains(&self.region)
+        }
+
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if !self.should_visit(region) {
+                return;
+            }
+
+            if let Expr::When {
+                branches,
+                branches_cond_var,
+                ..
+            } = expr
+            {
+                let branch = branches
+                    .iter()
+                    .find(|branch| branch.value.region.contains(&self.region));
+
+                // All the patterns of a branch bind the same identifiers, so the first will do.
+                if let Some(pattern) = branch.and_then(|branch| branch.patterns.first()) {
+                    self.bindings.clear();
+                    pattern_bindings(
+                        &pattern.pattern.value,
+                        *branches_cond_var,
+                        &mut self.bindings,
+                    );
+                }
+            }
+
+            walk_expr(self, expr, var);
+        }
+    }
+
+    fn pattern_bindings(pattern: &Pattern, var: Variable, bindings: &mut Vec<(Symbol, Variable)>) {
+        use Pattern::*;
+
+        match pattern {
+            Identifier(symbol) | Shadowed(_, _, symbol) => bindings.push((*symbol, var)),
+            As(subpattern, symbol) => {
+                bindings.push((*symbol, var));
+                pattern_bindings(&subpattern.value, var, bindings);
+            }
+            AppliedTag { arguments, .. } => {
+                for (arg_var, arg) in arguments {
+                    pattern_bindings(&arg.value, *arg_var, bindings);
+                }
+            }
+            UnwrappedOpaque { argument, .. } => {
+                let (arg_var, arg) = &**argument;
+                pattern_bindings(&arg.value, *arg_var, bindings);
+            }
+            RecordDestructure { destructs, .. } => {
+                for destruct in destructs {
+                    match &destruct.value.typ {
+                        DestructType::Guard(guard_var, guard) => {
+                            pattern_bindings(&guard.value, *guard_var, bindings)
+                        }
+                        DestructType::Required | DestructType::Optional(..) => {
+                            bindings.push((destruct.value.symbol, destruct.value.var))
+                        }
+                    }
+                }
+            }
+            TupleDestructure { destructs, .. } => {
+                for destruct in destructs {
+                    let (elem_var, elem) = &destruct.value.typ;
+                    pattern_bindings(&elem.value, *elem_var, bindings);
+                }
+            }
+            List {
+                list_var,
+                elem_var,
+                patterns,
+            } => {
+                for elem in &patterns.patterns {
+                    pattern_bindings(&elem.value, *elem_var, bindings);
+                }
+
+                if let Some((_, Some(rest))) = patterns.opt_rest {
+                    bindings.push((rest, *list_var));
+                }
+            }
+            NumLiteral(..)
+            | IntLiteral(..)
+            | FloatLiteral(..)
+            | StrLiteral(..)
+            | SingleQuote(..)
+            | Underscore
+            | AbilityMemberSpecialization { .. }
+            | OpaqueNotInScope(..)
+            | UnsupportedPattern(..)
+            | MalformedPattern(..) => {}
+        }
+    }
+}
+
 pub enum FoundDeclaration<'a> {
     Decl(DeclarationInfo<'a>),
     Def(&'a Def),
@@ -911,6 +1027,19 @@ impl<'a> FoundDeclaration<'a> {
             FoundDeclaration::Def(def) => def.expr_var,
         }
     }
+
+    /// The type annotation the declaration was written with, if any.
+    pub fn annotation(&self) -> Option<&Annotation> {
+        match self {
+            FoundDeclaration::Decl(
+                DeclarationInfo::Value { annotation, .. }
+                | DeclarationInfo::Function { annotation, .. }
+                | DeclarationInfo::Destructure { annotation, .. },
+            ) => *annotation,
+            FoundDeclaration::Decl(DeclarationInfo::Expectation { .. }) => None,
+            FoundDeclaration::Def(def) => def.annotation.as_ref(),
+        }
+    }
 }
 
 /// Finds the declaration of `symbol`.
@@ -84,9 +84,11 @@ pub fn can_expr_with(arena: &Bump, home: ModuleId, expr_str: &str) -> CanExprOut
         arena,
         home,
         Path::new("Test.roc"),
+        expr_str,
         &dep_idents,
         &qualified_module_ids,
         None,
+        false,
     );
     let (loc_expr, output) = canonicalize_expr(
         &mut env,
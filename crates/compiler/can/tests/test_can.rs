@@ -657,6 +657,48 @@ mod test_can {
         ));
     }
 
+    #[test]
+    fn optional_field_default_cannot_see_its_own_field() {
+        let src = indoc!(
+            r"
+                f = \{ x ? x } -> x
+
+                f {}
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().all(|problem| matches!(
+            problem,
+            Problem::RuntimeError(roc_problem::can::RuntimeError::LookupNotInScope { .. })
+        )));
+    }
+
+    #[test]
+    fn shadowing_optional_field_keeps_default_references() {
+        let src = indoc!(
+            r"
+                fallback = 1
+                x = 2
+
+                f = \{ x ? fallback } -> x
+
+                f {}
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        // `fallback` is used by the default, so it must not be reported as unused.
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().all(|problem| matches!(
+            problem,
+            Problem::RuntimeError(RuntimeError::Shadowing { .. })
+        )));
+    }
+
     // RECORD BUILDERS
     #[test]
     fn record_builder_desugar() {
@@ -1483,145 +1525,137 @@ mod test_can {
     //    );
     //}
 
-    // // ASSIGNMENT REORDERING
+    // ASSIGNMENT REORDERING
 
-    //#[test]
-    //fn reorder_assignments() {
-    //    let (expr, output, problems, _) = can_expr(indoc!(
-    //        r"
-    //        increment = \arg -> arg + 1
-    //        z = (increment 2) + y
-    //        y = x + 1
-    //        x = 9
+    #[test]
+    fn reorder_assignments() {
+        let src = indoc!(
+            r"
+                increment = \arg -> arg + 1
+                z = (increment 2) + y
+                y = x + 1
+                x = 9
 
-    //        z * 3
-    //    "
-    //    ));
+                z * 3
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr,
+            problems,
+            interns,
+            ..
+        } = can_expr_with(&arena, test_home(), src);
 
-    //    assert_eq!(problems, vec![]);
+        assert_eq!(problems, Vec::new());
 
-    //    assert_eq!(
-    //        output,
-    //        Out {
-    //            lookups: vec!["increment", "x", "y", "z"],
-    //            calls: vec!["increment"],
-    //            tail_call: None
-    //        }
-    //        .into()
-    //    );
+        let symbols = assigned_symbols(&loc_expr.value, &interns);
 
-    //    let symbols = assigned_symbols(expr);
-
-    //    // In code gen, for everything to have been set before it gets read,
-    //    // the following must be true about when things are assigned:
-    //    //
-    //    // x must be assigned before y
-    //    // y must be assigned before z
-    //    //
-    //    // The order of the increment function doesn't matter.
-    //    assert_before("x", "y", &symbols);
-    //    assert_before("y", "z", &symbols);
-    //}
+        // In code gen, for everything to have been set before it gets read,
+        // the following must be true about when things are assigned:
+        //
+        // x must be assigned before y
+        // y must be assigned before z
+        //
+        // The order of the increment function doesn't matter.
+        assert_before("x", "y", &symbols);
+        assert_before("y", "z", &symbols);
+        assert_before("increment", "z", &symbols);
+    }
 
-    //#[test]
-    //fn reorder_closed_over_assignments() {
-    //    let (expr, output, problems, _) = can_expr(indoc!(
-    //        r"
-    //        z = func1 x
-    //        x = 9
-    //        y = func2 3
-    //        func1 = \arg -> func2 arg + y
-    //        func2 = \arg -> arg + x
+    #[test]
+    fn reorder_closed_over_assignments() {
+        let src = indoc!(
+            r"
+                z = func1 x
+                x = 9
+                y = func2 3
+                func1 = \arg -> func2 arg + y
+                func2 = \arg -> arg + x
 
-    //        z
-    //    "
-    //    ));
+                z
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr,
+            problems,
+            interns,
+            ..
+        } = can_expr_with(&arena, test_home(), src);
 
-    //    assert_eq!(problems, vec![]);
+        assert_eq!(problems, Vec::new());
 
-    //    assert_eq!(
-    //        output,
-    //        Out {
-    //            lookups: vec!["func1", "func2", "x", "y", "z"],
-    //            calls: vec!["func1", "func2"],
-    //            tail_call: None
-    //        }
-    //        .into()
-    //    );
+        let symbols = assigned_symbols(&loc_expr.value, &interns);
 
-    //    let symbols = assigned_symbols(expr);
+        // In code gen, for everything to have been set before it gets read,
+        // the following must be true about when things are assigned:
+        //
+        // x and func2 must be assigned (in either order) before y
+        // y and func1 must be assigned (in either order) before z
+        assert_before("x", "y", &symbols);
+        assert_before("func2", "y", &symbols);
+
+        assert_before("func1", "z", &symbols);
+        assert_before("y", "z", &symbols);
+    }
+
+    fn assert_before(before: &str, after: &str, symbols: &[String]) {
+        assert_ne!(before, after);
+
+        let position = |name: &str| {
+            symbols
+                .iter()
+                .position(|symbol| symbol == name)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "error in assert_before({:?}, {:?}): {:?} could not be found in {:?}",
+                        before, after, name, symbols
+                    )
+                })
+        };
 
-    //    // In code gen, for everything to have been set before it gets read,
-    //    // the following must be true about when things are assigned:
-    //    //
-    //    // x and func2 must be assigned (in either order) before y
-    //    // y and func1 must be assigned (in either order) before z
-    //    assert_before("x", "y", &symbols);
-    //    assert_before("func2", "y", &symbols);
+        let before_index = position(before);
+        let after_index = position(after);
+
+        if before_index == after_index {
+            panic!(
+                "error in assert_before({:?}, {:?}): both were at index {} in {:?}",
+                before, after, after_index, symbols
+            );
+        } else if before_index > after_index {
+            panic!(
+                "error in assert_before: {:?} appeared *after* {:?} (not before, as expected) in {:?}",
+                before, after, symbols
+            );
+        }
+    }
 
-    //    assert_before("func1", "z", &symbols);
-    //    assert_before("y", "z", &symbols);
-    //}
+    /// The names of the identifiers assigned by a chain of `LetNonRec`/`LetRec` defs,
+    /// in the order in which they will be assigned.
+    fn assigned_symbols(expr: &Expr, interns: &roc_module::symbol::Interns) -> Vec<String> {
+        let def_symbol = |def: &roc_can::def::Def| match def.loc_pattern.value {
+            roc_can::pattern::Pattern::Identifier(symbol) => symbol.as_str(interns).to_string(),
+            _ => panic!("Called assigned_symbols on a def with a non-Identifier pattern!"),
+        };
 
-    //fn assert_before(before: &str, after: &str, symbols: &Vec<Symbol>) {
-    //    assert_ne!(before, after);
-
-    //    let before_symbol = sym(before);
-    //    let after_symbol = sym(after);
-    //    let before_index = symbols
-    //        .iter()
-    //        .position(|symbol| symbol == &before_symbol)
-    //        .unwrap_or_else(|| {
-    //            panic!(
-    //                "error in assert_before({:?}, {:?}): {:?} could not be found in {:?}",
-    //                before,
-    //                after,
-    //                sym(before),
-    //                symbols
-    //            )
-    //        });
-    //    let after_index = symbols
-    //        .iter()
-    //        .position(|symbol| symbol == &after_symbol)
-    //        .unwrap_or_else(|| {
-    //            panic!(
-    //                "error in assert_before({:?}, {:?}): {:?} could not be found in {:?}",
-    //                before,
-    //                after,
-    //                sym(after),
-    //                symbols
-    //            )
-    //        });
-
-    //    if before_index == after_index {
-    //        panic!(
-    //            "error in assert_before({:?}, {:?}): both were at index {} in {:?}",
-    //            before, after, after_index, symbols
-    //        );
-    //    } else if before_index > after_index {
-    //        panic!("error in assert_before: {:?} appeared *after* {:?} (not before, as expected) in {:?}", before, after, symbols);
-    //    }
-    //}
+        let mut symbols = Vec::new();
+        let mut current = expr;
 
-    //fn assigned_symbols(expr: Expr) -> Vec<Symbol> {
-    //    match expr {
-    //        Assign(assignments, _) => {
-    //            assignments.into_iter().map(|(pattern, _)| {
-    //                match pattern.value {
-    //                    Identifier(symbol) => {
-    //                        symbol
-    //                    },
-    //                    _ => {
-    //                        panic!("Called assigned_symbols passing an Assign expr with non-Identifier patterns!");
-    //                    }
-    //                }
-    //            }).collect()
-    //        },
-    //        _ => {
-    //            panic!("Called assigned_symbols passing a non-Assign expr!");
-    //        }
-    //    }
-    //}
+        loop {
+            match current {
+                LetNonRec(def, body) => {
+                    symbols.push(def_symbol(def.as_ref()));
+                    current = &body.value;
+                }
+                LetRec(defs, body, _) => {
+                    symbols.extend(defs.iter().map(def_symbol));
+                    current = &body.value;
+                }
+                _ => return symbols,
+            }
+        }
+    }
 
     // // CIRCULAR ASSIGNMENT
 
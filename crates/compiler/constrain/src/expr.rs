@@ -467,6 +467,7 @@ pub fn constrain_expr(
                 name: opt_symbol,
                 arity: loc_args.len() as u8,
                 called_via: *called_via,
+                arg_regions: loc_args.iter().map(|(_, loc_arg)| loc_arg.region).collect(),
             };
 
             let fn_con = constrain_expr(
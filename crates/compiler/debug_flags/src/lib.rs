@@ -32,6 +32,12 @@
 //!
 //! These flags are also set in .cargo/config found at the repository root. You can modify them
 //! there to avoid maintaining a separate script.
+//!
+//! Output that is only informational, rather than a check or a dump of a whole IR, goes through
+//! the [`roc_log!`] macro instead, whose output is chosen per subsystem with `ROC_LOG`. See the
+//! [`log`] module.
+
+pub mod log;
 
 #[macro_export]
 macro_rules! dbg_set {
@@ -79,12 +85,6 @@ flags! {
     /// Only use this in single-threaded mode!
     ROC_PRINT_UNIFICATIONS
 
-    /// Prints types whose ability impls failed to be derived.
-    ROC_PRINT_UNDERIVABLE
-
-    /// Prints traces of unspecialized lambda set compaction
-    ROC_TRACE_COMPACTION
-
     /// Like ROC_PRINT_UNIFICATIONS, in the context of typechecking derived implementations.
     /// Only use this in single-threaded mode!
     ROC_PRINT_UNIFICATIONS_DERIVED
@@ -127,6 +127,14 @@ flags! {
     /// Writes a pretty-printed mono IR to stderr after function specialization.
     ROC_PRINT_IR_AFTER_SPECIALIZATION
 
+    /// Writes a pretty-printed mono IR to stderr after constant top-level definitions have
+    /// been evaluated at compile time.
+    ROC_PRINT_IR_AFTER_CONSTANT_FOLDING
+
+    /// Writes a pretty-printed mono IR to stderr after chained `List.map`, `List.keepIf` and
+    /// `List.walk` calls have been fused.
+    ROC_PRINT_IR_AFTER_LIST_FUSION
+
     /// Writes a pretty-printed mono IR to stderr after insertion of reset/reuse
     /// instructions.
     ROC_PRINT_IR_AFTER_RESET_REUSE
@@ -168,9 +176,6 @@ flags! {
 
     // ===Load===
 
-    /// Print load phases as they complete.
-    ROC_PRINT_LOAD_LOG
-
     /// Don't build and use the subs cache (speeds up compilation of load and previous crates)
     ROC_SKIP_SUBS_CACHE
 
@@ -0,0 +1,171 @@
+//! Logging for the compiler's subsystems, configured with the `ROC_LOG` environment variable.
+//!
+//! `ROC_LOG` is a comma-separated list of `subsystem=level` entries, for example
+//! `ROC_LOG=solve=debug,mono=trace`. A bare level like `ROC_LOG=info` applies to every subsystem
+//! without an entry of its own. Messages are written to stderr.
+//!
+//! Like the other debug flags, logging only exists in debug builds: in release builds the
+//! [`roc_log!`](crate::roc_log) macro expands to nothing, so its arguments are never evaluated.
+
+use std::sync::OnceLock;
+
+/// The name of the environment variable that configures logging.
+pub static ROC_LOG: &str = "ROC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    fn parse(level: &str) -> Option<Self> {
+        match level.trim().to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+/// The most verbose level enabled for each subsystem.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Filter {
+    default: Option<Level>,
+    subsystems: Vec<(String, Level)>,
+}
+
+impl Filter {
+    /// Entries that can't be parsed are ignored, so that a typo doesn't stop the compiler.
+    fn parse(spec: &str) -> Self {
+        let mut filter = Filter::default();
+
+        for entry in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            match entry.split_once('=') {
+                Some((subsystem, level)) => {
+                    if let Some(level) = Level::parse(level) {
+                        filter
+                            .subsystems
+                            .push((subsystem.trim().to_string(), level));
+                    }
+                }
+                None => {
+                    if let Some(level) = Level::parse(entry) {
+                        filter.default = Some(level);
+                    }
+                }
+            }
+        }
+
+        filter
+    }
+
+    fn enabled(&self, subsystem: &str, level: Level) -> bool {
+        let max_level = self
+            .subsystems
+            .iter()
+            .rev()
+            .find(|(name, _)| name == subsystem)
+            .map(|(_, level)| *level)
+            .or(self.default);
+
+        max_level.is_some_and(|max_level| level <= max_level)
+    }
+}
+
+fn filter() -> &'static Filter {
+    static FILTER: OnceLock<Filter> = OnceLock::new();
+
+    FILTER.get_or_init(|| match std::env::var(ROC_LOG) {
+        Ok(spec) => Filter::parse(&spec),
+        Err(_) => Filter::default(),
+    })
+}
+
+/// Whether messages of the given level are logged for the subsystem.
+pub fn enabled(subsystem: &str, level: Level) -> bool {
+    filter().enabled(subsystem, level)
+}
+
+/// Logs a message for a subsystem, if `ROC_LOG` enables its level.
+///
+/// ```
+/// roc_debug_flags::roc_log!("load", Debug, "loaded {} modules", 3);
+/// ```
+#[macro_export]
+macro_rules! roc_log {
+    ($subsystem:literal, $level:ident, $($arg:tt)+) => {
+        #[cfg(debug_assertions)]
+        {
+            if $crate::log::enabled($subsystem, $crate::log::Level::$level) {
+                eprintln!(
+                    "[{} {}] {}",
+                    $subsystem,
+                    $crate::log::Level::$level.as_str(),
+                    format_args!($($arg)+)
+                );
+            }
+        }
+    };
+}
+
+/// Whether `ROC_LOG` enables the level for a subsystem. Always `false` in release builds, for
+/// guarding logging that takes more than a message.
+#[macro_export]
+macro_rules! roc_log_enabled {
+    ($subsystem:literal, $level:ident) => {{
+        #[cfg(not(debug_assertions))]
+        {
+            false
+        }
+        #[cfg(debug_assertions)]
+        {
+            $crate::log::enabled($subsystem, $crate::log::Level::$level)
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Filter, Level};
+
+    #[test]
+    fn subsystem_levels() {
+        let filter = Filter::parse("solve=debug, mono=TRACE");
+
+        assert!(filter.enabled("solve", Level::Debug));
+        assert!(!filter.enabled("solve", Level::Trace));
+        assert!(filter.enabled("mono", Level::Trace));
+        assert!(!filter.enabled("load", Level::Error));
+    }
+
+    #[test]
+    fn default_level() {
+        let filter = Filter::parse("warn,load=trace,nonsense=loud");
+
+        assert!(filter.enabled("parse", Level::Warn));
+        assert!(!filter.enabled("parse", Level::Info));
+        assert!(filter.enabled("load", Level::Trace));
+        assert!(!filter.enabled("nonsense", Level::Info));
+    }
+}
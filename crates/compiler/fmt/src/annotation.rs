@@ -1,5 +1,5 @@
 use crate::{
-    collection::{fmt_collection, Braces},
+    collection::{fmt_collection, fmt_fitting_collection, Braces},
     spaces::{fmt_comments_only, fmt_spaces, NewlineAt, INDENT},
     Buf,
 };
@@ -99,6 +99,22 @@ pub fn is_collection_multiline<T: Formattable>(collection: &Collection<'_, T>) -
     collection.items.iter().any(Formattable::is_multiline)
 }
 
+/// Whether a collection needs a line per item however short it is. Unlike
+/// [is_collection_multiline], newlines between the items don't count, only comments and items
+/// that are multiline themselves.
+pub fn collection_needs_multiline<'a, T>(collection: &Collection<'a, T>) -> bool
+where
+    T: ExtractSpaces<'a>,
+    <T as ExtractSpaces<'a>>::Item: Formattable,
+{
+    collection.final_comments().iter().any(|s| s.is_comment())
+        || collection.items.iter().any(|item| {
+            let item = item.extract_spaces();
+
+            item.before.iter().chain(item.after).any(|s| s.is_comment()) || item.item.is_multiline()
+        })
+}
+
 /// A Located formattable value is also formattable
 impl<T> Formattable for Loc<T>
 where
@@ -174,7 +190,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                     _ => {}
                 }
 
-                fields.items.iter().any(|field| field.value.is_multiline())
+                collection_needs_multiline(fields)
             }
 
             TagUnion { tags, ext } => {
@@ -183,7 +199,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                     _ => {}
                 }
 
-                tags.iter().any(|tag| tag.value.is_multiline())
+                collection_needs_multiline(tags)
             }
         }
     }
@@ -312,7 +328,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
             }
 
             TagUnion { tags, ext } => {
-                fmt_collection(buf, indent, Braces::Square, *tags, newlines);
+                fmt_fitting_collection(buf, indent, Braces::Square, *tags, newlines);
 
                 if let Some(loc_ext_ann) = *ext {
                     loc_ext_ann.value.format(buf, indent);
@@ -328,7 +344,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
             }
 
             Record { fields, ext } => {
-                fmt_collection(buf, indent, Braces::Curly, *fields, newlines);
+                fmt_fitting_collection(buf, indent, Braces::Curly, *fields, newlines);
 
                 if let Some(loc_ext_ann) = *ext {
                     loc_ext_ann.value.format(buf, indent);
@@ -1,7 +1,7 @@
 use roc_parse::ast::{Collection, CommentOrNewline, ExtractSpaces};
 
 use crate::{
-    annotation::{is_collection_multiline, Formattable, Newlines},
+    annotation::{collection_needs_multiline, is_collection_multiline, Formattable, Newlines},
     spaces::{fmt_comments_only, NewlineAt, INDENT},
     Buf,
 };
@@ -13,6 +13,16 @@ pub enum Braces {
     Curly,
 }
 
+impl Braces {
+    fn delimiters(self) -> (char, char) {
+        match self {
+            Braces::Round => ('(', ')'),
+            Braces::Curly => ('{', '}'),
+            Braces::Square => ('[', ']'),
+        }
+    }
+}
+
 pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
     buf: &mut Buf<'buf>,
     indent: u16,
@@ -22,123 +32,165 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
 ) where
     <T as ExtractSpaces<'a>>::Item: Formattable,
 {
-    let start = match braces {
-        Braces::Round => '(',
-        Braces::Curly => '{',
-        Braces::Square => '[',
-    };
-
-    let end = match braces {
-        Braces::Round => ')',
-        Braces::Curly => '}',
-        Braces::Square => ']',
-    };
-
     if is_collection_multiline(&items) {
-        let braces_indent = indent;
-        let item_indent = braces_indent + INDENT;
-        if newline == Newlines::Yes {
-            buf.ensure_ends_with_newline();
+        fmt_multiline_collection(buf, indent, braces, items, newline);
+    } else {
+        fmt_single_line_collection(buf, indent, braces, items);
+    }
+}
+
+/// Like [fmt_collection], but the layout doesn't depend on where the collection had newlines.
+/// It goes on one line when that fits within [MAX_LINE_WIDTH](crate::MAX_LINE_WIDTH), and otherwise gets a line per
+/// item. Comments and multiline items always need a line per item.
+pub fn fmt_fitting_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
+    buf: &mut Buf<'buf>,
+    indent: u16,
+    braces: Braces,
+    items: Collection<'a, T>,
+    newline: Newlines,
+) where
+    <T as ExtractSpaces<'a>>::Item: Formattable,
+{
+    if !collection_needs_multiline(&items) {
+        let checkpoint = buf.checkpoint();
+
+        fmt_single_line_collection(buf, indent, braces, items);
+
+        if buf.fits_on_line_since(checkpoint) {
+            return;
         }
-        buf.indent(braces_indent);
-        buf.push(start);
 
-        for (index, item) in items.iter().enumerate() {
-            let is_first_item = index == 0;
-            let item = item.extract_spaces();
-            let is_only_newlines = item.before.iter().all(|s| s.is_newline());
+        buf.rollback(checkpoint);
+    }
+
+    fmt_multiline_collection(buf, indent, braces, items, newline);
+}
+
+fn fmt_multiline_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
+    buf: &mut Buf<'buf>,
+    indent: u16,
+    braces: Braces,
+    items: Collection<'a, T>,
+    newline: Newlines,
+) where
+    <T as ExtractSpaces<'a>>::Item: Formattable,
+{
+    let (start, end) = braces.delimiters();
+
+    let braces_indent = indent;
+    let item_indent = braces_indent + INDENT;
+    if newline == Newlines::Yes {
+        buf.ensure_ends_with_newline();
+    }
+    buf.indent(braces_indent);
+    buf.push(start);
+
+    for (index, item) in items.iter().enumerate() {
+        let is_first_item = index == 0;
+        let item = item.extract_spaces();
+        let is_only_newlines = item.before.iter().all(|s| s.is_newline());
 
-            if item.before.is_empty() || is_only_newlines {
+        if item.before.is_empty() || is_only_newlines {
+            buf.ensure_ends_with_newline();
+        } else {
+            if is_first_item {
+                // The first item in a multiline collection always begins with exactly
+                // one newline (so the delimiter is at the end of its own line),
+                // and that newline appears before the first comment (if there is one).
                 buf.ensure_ends_with_newline();
             } else {
-                if is_first_item {
-                    // The first item in a multiline collection always begins with exactly
-                    // one newline (so the delimiter is at the end of its own line),
-                    // and that newline appears before the first comment (if there is one).
+                if item.before.starts_with(&[CommentOrNewline::Newline]) {
                     buf.ensure_ends_with_newline();
-                } else {
-                    if item.before.starts_with(&[CommentOrNewline::Newline]) {
-                        buf.ensure_ends_with_newline();
-                    }
-
-                    if item
-                        .before
-                        .starts_with(&[CommentOrNewline::Newline, CommentOrNewline::Newline])
-                    {
-                        // If there's a comment, and it's not on the first item,
-                        // and it's preceded by at least one blank line, maintain 1 blank line.
-                        // (We already ensured that it ends in a newline, so this will turn that
-                        // into a blank line.)
-
-                        buf.newline();
-                    }
                 }
 
-                fmt_comments_only(buf, item.before.iter(), NewlineAt::None, item_indent);
-
-                if !is_only_newlines {
-                    if item.before.ends_with(&[CommentOrNewline::Newline]) {
-                        buf.newline();
-                    }
+                if item
+                    .before
+                    .starts_with(&[CommentOrNewline::Newline, CommentOrNewline::Newline])
+                {
+                    // If there's a comment, and it's not on the first item,
+                    // and it's preceded by at least one blank line, maintain 1 blank line.
+                    // (We already ensured that it ends in a newline, so this will turn that
+                    // into a blank line.)
 
                     buf.newline();
                 }
             }
 
-            buf.indent(item_indent);
-            item.item.format(buf, item_indent);
+            fmt_comments_only(buf, item.before.iter(), NewlineAt::None, item_indent);
 
-            buf.push(',');
-
-            if !item.after.is_empty() {
-                if item.after.iter().any(|s| s.is_newline()) {
+            if !is_only_newlines {
+                if item.before.ends_with(&[CommentOrNewline::Newline]) {
                     buf.newline();
                 }
 
-                fmt_comments_only(buf, item.after.iter(), NewlineAt::None, item_indent);
+                buf.newline();
             }
         }
 
-        if items.final_comments().iter().any(|s| s.is_newline()) {
-            buf.newline();
-        }
-
-        if items
-            .final_comments()
-            .starts_with(&[CommentOrNewline::Newline, CommentOrNewline::Newline])
-        {
-            buf.newline();
-        }
+        buf.indent(item_indent);
+        item.item.format(buf, item_indent);
 
-        fmt_comments_only(
-            buf,
-            items.final_comments().iter(),
-            NewlineAt::None,
-            item_indent,
-        );
+        buf.push(',');
 
-        buf.ensure_ends_with_newline();
-        buf.indent(braces_indent);
-    } else {
-        // is_multiline == false
-        // there is no comment to add
-        buf.indent(indent);
-        buf.push(start);
-        let mut iter = items.iter().enumerate().peekable();
-        while let Some((index, item)) = iter.next() {
-            if braces == Braces::Curly || index != 0 {
-                buf.spaces(1);
+        if !item.after.is_empty() {
+            if item.after.iter().any(|s| s.is_newline()) {
+                buf.newline();
             }
 
-            item.format(buf, indent);
-            if iter.peek().is_some() {
-                buf.push(',');
-            }
+            fmt_comments_only(buf, item.after.iter(), NewlineAt::None, item_indent);
         }
+    }
+
+    if items.final_comments().iter().any(|s| s.is_newline()) {
+        buf.newline();
+    }
+
+    if items
+        .final_comments()
+        .starts_with(&[CommentOrNewline::Newline, CommentOrNewline::Newline])
+    {
+        buf.newline();
+    }
 
-        if !items.is_empty() && braces == Braces::Curly {
+    fmt_comments_only(
+        buf,
+        items.final_comments().iter(),
+        NewlineAt::None,
+        item_indent,
+    );
+
+    buf.ensure_ends_with_newline();
+    buf.indent(braces_indent);
+    buf.push(end);
+}
+
+fn fmt_single_line_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
+    buf: &mut Buf<'buf>,
+    indent: u16,
+    braces: Braces,
+    items: Collection<'a, T>,
+) where
+    <T as ExtractSpaces<'a>>::Item: Formattable,
+{
+    let (start, end) = braces.delimiters();
+
+    buf.indent(indent);
+    buf.push(start);
+    let mut iter = items.iter().enumerate().peekable();
+    while let Some((index, item)) = iter.next() {
+        if braces == Braces::Curly || index != 0 {
             buf.spaces(1);
         }
+
+        // Any spaces around the item are newlines, which a single line leaves out.
+        item.extract_spaces().item.format(buf, indent);
+        if iter.peek().is_some() {
+            buf.push(',');
+        }
+    }
+
+    if !items.is_empty() && braces == Braces::Curly {
+        buf.spaces(1);
     }
 
     buf.push(end);
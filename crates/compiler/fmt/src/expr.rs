@@ -1,5 +1,7 @@
-use crate::annotation::{except_last, is_collection_multiline, Formattable, Newlines, Parens};
-use crate::collection::{fmt_collection, Braces};
+use crate::annotation::{
+    collection_needs_multiline, except_last, is_collection_multiline, Formattable, Newlines, Parens,
+};
+use crate::collection::{fmt_collection, fmt_fitting_collection, Braces};
 use crate::def::fmt_defs;
 use crate::pattern::fmt_pattern;
 use crate::spaces::{
@@ -55,7 +57,7 @@ impl<'a> Formattable for Expr<'a> {
             // These expressions always have newlines
             Defs(_, _) | When(_, _) => true,
 
-            List(items) => is_collection_multiline(items),
+            List(items) => collection_needs_multiline(items),
 
             Str(literal) => is_str_multiline(literal),
             Apply(loc_expr, args, _) => {
@@ -107,10 +109,10 @@ impl<'a> Formattable for Expr<'a> {
                         .any(|loc_pattern| loc_pattern.is_multiline())
             }
 
-            Record(fields) => is_collection_multiline(fields),
+            Record(fields) => collection_needs_multiline(fields),
             Tuple(fields) => is_collection_multiline(fields),
-            RecordUpdate { fields, .. } => is_collection_multiline(fields),
-            RecordBuilder(fields) => is_collection_multiline(fields),
+            RecordUpdate { fields, .. } => collection_needs_multiline(fields),
+            RecordBuilder(fields) => collection_needs_multiline(fields),
         }
     }
 
@@ -456,7 +458,9 @@ impl<'a> Formattable for Expr<'a> {
             }
             When(loc_condition, branches) => fmt_when(buf, loc_condition, branches, indent),
             Tuple(items) => fmt_collection(buf, indent, Braces::Round, *items, Newlines::No),
-            List(items) => fmt_collection(buf, indent, Braces::Square, *items, Newlines::No),
+            List(items) => {
+                fmt_fitting_collection(buf, indent, Braces::Square, *items, Newlines::No)
+            }
             BinOps(lefts, right) => fmt_binops(buf, lefts, right, false, indent),
             UnaryOp(sub_expr, unary_op) => {
                 buf.indent(indent);
@@ -753,7 +757,14 @@ fn fmt_binops<'a>(
     for (loc_left_side, loc_binop) in lefts {
         let binop = loc_binop.value;
 
-        loc_left_side.format_with_options(buf, Parens::InOperator, Newlines::No, adjusted_indent);
+        // Comments between an operand and the next operator stay on the lines they were
+        // written on, so that commentary on a step of a pipeline stays with that step.
+        let (left_side, spaces_after) = match loc_left_side.value {
+            Expr::SpaceAfter(left_side, spaces) if is_multiline => (left_side, spaces),
+            _ => (&loc_left_side.value, &[][..]),
+        };
+
+        left_side.format_with_options(buf, Parens::InOperator, Newlines::No, adjusted_indent);
 
         if is_first {
             // indent the remaining lines, but only if the expression is suffixed.
@@ -761,6 +772,8 @@ fn fmt_binops<'a>(
             adjusted_indent = indent + 4;
         }
 
+        fmt_spaces_no_blank_lines(buf, spaces_after.iter(), adjusted_indent);
+
         if is_multiline {
             buf.ensure_ends_with_newline();
             buf.indent(adjusted_indent);
@@ -874,7 +887,6 @@ fn fmt_when<'a>(
     for (branch_index, branch) in branches.iter().enumerate() {
         let expr = &branch.value;
         let patterns = &branch.patterns;
-        let is_multiline_expr = expr.is_multiline();
         let is_multiline_patterns = is_when_patterns_multiline(branch);
 
         for (pattern_index, pattern) in patterns.iter().enumerate() {
@@ -950,37 +962,36 @@ fn fmt_when<'a>(
 
         buf.push_str(" ->");
 
-        match expr.value {
-            Expr::SpaceBefore(nested, spaces) => {
-                fmt_spaces_no_blank_lines(buf, spaces.iter(), indent + (INDENT * 2));
+        // Where the body starts doesn't depend on where it started in the source: it goes on
+        // the line of the `->` if it's a single line without comments above it that fits there,
+        // and otherwise starts on the next line, one indent past the patterns.
+        let (body, spaces_before_body) = match expr.value {
+            Expr::SpaceBefore(body, spaces) => (body, spaces),
+            _ => (&expr.value, &[][..]),
+        };
+        let body_indent = indent + 2 * INDENT;
+        let has_comments_before_body = spaces_before_body.iter().any(|space| !space.is_newline());
+        let mut is_multiline_expr = has_comments_before_body || body.is_multiline();
 
-                if is_multiline_expr {
-                    buf.ensure_ends_with_newline();
-                } else {
-                    buf.spaces(1);
-                }
+        if !is_multiline_expr {
+            let checkpoint = buf.checkpoint();
 
-                nested.format_with_options(
-                    buf,
-                    Parens::NotNeeded,
-                    Newlines::Yes,
-                    indent + 2 * INDENT,
-                );
+            buf.spaces(1);
+            body.format_with_options(buf, Parens::NotNeeded, Newlines::Yes, body_indent);
+
+            if !buf.fits_on_line_since(checkpoint) {
+                buf.rollback(checkpoint);
+                is_multiline_expr = true;
             }
-            _ => {
-                if is_multiline_expr {
-                    buf.ensure_ends_with_newline();
-                } else {
-                    buf.spaces(1);
-                }
+        }
 
-                expr.format_with_options(
-                    buf,
-                    Parens::NotNeeded,
-                    Newlines::Yes,
-                    indent + 2 * INDENT,
-                );
+        if is_multiline_expr {
+            if has_comments_before_body {
+                fmt_spaces_no_blank_lines(buf, spaces_before_body.iter(), body_indent);
             }
+
+            buf.ensure_ends_with_newline();
+            body.format_with_options(buf, Parens::NotNeeded, Newlines::Yes, body_indent);
         }
 
         prev_branch_was_multiline = is_multiline_expr || is_multiline_patterns;
@@ -1381,7 +1392,8 @@ fn fmt_record_like<'a, Field, Format, ToSpaceBefore>(
     format_field_multiline: Format,
     to_space_before: ToSpaceBefore,
 ) where
-    Field: Formattable,
+    Field: Formattable + ExtractSpaces<'a>,
+    <Field as ExtractSpaces<'a>>::Item: Formattable,
     Format: Fn(&mut Buf, &Field, u16, &str),
     ToSpaceBefore: Fn(&'a Field) -> Option<(&'a Field, &'a [CommentOrNewline<'a>])>,
 {
@@ -1406,53 +1418,19 @@ fn fmt_record_like<'a, Field, Format, ToSpaceBefore>(
             }
         }
 
-        let is_multiline = loc_fields.iter().any(|loc_field| loc_field.is_multiline())
-            || !final_comments.is_empty();
-
-        if is_multiline {
-            let field_indent = indent + INDENT;
-            for (index, field) in loc_fields.iter().enumerate() {
-                // comma addition is handled by the `format_field_multiline` function
-                // since we can have stuff like:
-                // { x # comment
-                // , y
-                // }
-                // In this case, we have to move the comma before the comment.
-
-                let is_first_item = index == 0;
-                if let Some((_sub_field, spaces)) = to_space_before(&field.value) {
-                    let is_only_newlines = spaces.iter().all(|s| s.is_newline());
-                    if !is_first_item
-                        && !is_only_newlines
-                        && count_leading_newlines(spaces.iter()) > 1
-                    {
-                        buf.newline();
-                    }
-
-                    fmt_comments_only(buf, spaces.iter(), NewlineAt::Top, field_indent);
-
-                    if !is_only_newlines && count_leading_newlines(spaces.iter().rev()) > 0 {
-                        buf.newline();
-                    }
-                }
-
-                format_field_multiline(buf, &field.value, field_indent, "");
-            }
-
-            if count_leading_newlines(final_comments.iter()) > 1 {
-                buf.newline();
-            }
+        if !collection_needs_multiline(&fields) {
+            let checkpoint = buf.checkpoint();
 
-            fmt_comments_only(buf, final_comments.iter(), NewlineAt::Top, field_indent);
-
-            buf.newline();
-        } else {
-            // is_multiline == false
             buf.spaces(1);
-            let field_indent = indent;
             let mut iter = loc_fields.iter().peekable();
             while let Some(field) = iter.next() {
-                field.format_with_options(buf, Parens::NotNeeded, Newlines::No, field_indent);
+                // Any spaces around the field are newlines, which a single line leaves out.
+                field.value.extract_spaces().item.format_with_options(
+                    buf,
+                    Parens::NotNeeded,
+                    Newlines::No,
+                    indent,
+                );
 
                 if iter.peek().is_some() {
                     buf.push_str(",");
@@ -1460,10 +1438,50 @@ fn fmt_record_like<'a, Field, Format, ToSpaceBefore>(
                 }
             }
             buf.spaces(1);
-            // if we are here, that means that `final_comments` is empty, thus we don't have
-            // to add a comment. Anyway, it is not possible to have a single line record with
-            // a comment in it.
-        };
+            buf.indent(indent);
+            buf.push('}');
+
+            if buf.fits_on_line_since(checkpoint) {
+                return;
+            }
+
+            buf.rollback(checkpoint);
+        }
+
+        let field_indent = indent + INDENT;
+        for (index, field) in loc_fields.iter().enumerate() {
+            // comma addition is handled by the `format_field_multiline` function
+            // since we can have stuff like:
+            // { x # comment
+            // , y
+            // }
+            // In this case, we have to move the comma before the comment.
+
+            let is_first_item = index == 0;
+            if let Some((_sub_field, spaces)) = to_space_before(&field.value) {
+                let is_only_newlines = spaces.iter().all(|s| s.is_newline());
+                if !is_first_item && !is_only_newlines && count_leading_newlines(spaces.iter()) > 1
+                {
+                    buf.newline();
+                }
+
+                fmt_comments_only(buf, spaces.iter(), NewlineAt::Top, field_indent);
+
+                if !is_only_newlines && count_leading_newlines(spaces.iter().rev()) > 0 {
+                    buf.newline();
+                }
+            }
+
+            format_field_multiline(buf, &field.value, field_indent, "");
+        }
+
+        if count_leading_newlines(final_comments.iter()) > 1 {
+            buf.newline();
+        }
+
+        fmt_comments_only(buf, final_comments.iter(), NewlineAt::Top, field_indent);
+
+        buf.newline();
 
         // closes the initial bracket
         buf.indent(indent);
@@ -8,10 +8,12 @@ pub mod def;
 pub mod expr;
 pub mod module;
 pub mod pattern;
+pub mod rewrite;
 pub mod spaces;
 
 use bumpalo::{collections::String, Bump};
 use roc_parse::ast::Module;
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub struct Ast<'a> {
@@ -19,6 +21,24 @@ pub struct Ast<'a> {
     pub defs: roc_parse::ast::Defs<'a>,
 }
 
+/// Formatted code always ends its lines with `\n`. This converts it to `\r\n` line endings if
+/// that is what the source it was formatted from uses, going by the source's first line break.
+pub fn match_line_endings<'a>(src: &str, formatted: &'a str) -> Cow<'a, str> {
+    let uses_crlf = src
+        .find('\n')
+        .is_some_and(|index| src[..index].ends_with('\r'));
+
+    if uses_crlf {
+        Cow::Owned(formatted.replace('\n', "\r\n"))
+    } else {
+        Cow::Borrowed(formatted)
+    }
+}
+
+/// How wide a line may get before the formatter spreads a record, list, or tag union over
+/// multiple lines.
+pub const MAX_LINE_WIDTH: usize = 100;
+
 #[derive(Debug)]
 pub struct Buf<'a> {
     text: String<'a>,
@@ -165,4 +185,43 @@ impl<'a> Buf<'a> {
     fn is_empty(&self) -> bool {
         self.spaces_to_flush == 0 && self.text.is_empty()
     }
+
+    /// Remembers where the buffer is, so that something can be formatted one way and then
+    /// undone with [Buf::rollback] if it turns out not to fit.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.text.len(),
+            spaces_to_flush: self.spaces_to_flush,
+            newlines_to_flush: self.newlines_to_flush,
+            beginning_of_line: self.beginning_of_line,
+        }
+    }
+
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        self.text.truncate(checkpoint.len);
+        self.spaces_to_flush = checkpoint.spaces_to_flush;
+        self.newlines_to_flush = checkpoint.newlines_to_flush;
+        self.beginning_of_line = checkpoint.beginning_of_line;
+    }
+
+    /// Whether everything since the checkpoint is on the current line, and the line is no wider
+    /// than [MAX_LINE_WIDTH].
+    pub fn fits_on_line_since(&self, checkpoint: Checkpoint) -> bool {
+        // Newlines that were waiting to be flushed at the checkpoint came before it.
+        let added = self.text[checkpoint.len..].trim_start_matches('\n');
+        let line_start = self.text.rfind('\n').map_or(0, |index| index + 1);
+
+        self.newlines_to_flush == 0
+            && !added.contains('\n')
+            && self.text[line_start..].chars().count() <= MAX_LINE_WIDTH
+    }
+}
+
+/// A position in a [Buf] to roll back to.
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint {
+    len: usize,
+    spaces_to_flush: usize,
+    newlines_to_flush: usize,
+    beginning_of_line: bool,
 }
@@ -5,13 +5,13 @@ use crate::spaces::RemoveSpaces;
 use crate::spaces::{fmt_comments_only, fmt_default_spaces, fmt_spaces, NewlineAt, INDENT};
 use crate::Buf;
 use bumpalo::Bump;
-use roc_parse::ast::{Collection, CommentOrNewline, Header, Module, Spaced, Spaces};
+use roc_parse::ast::{Collection, CommentOrNewline, ExtractSpaces, Header, Module, Spaced, Spaces};
 use roc_parse::header::{
     AppHeader, ExposedName, ExposesKeyword, GeneratesKeyword, HostedHeader, ImportsEntry,
     ImportsKeyword, Keyword, KeywordItem, ModuleHeader, ModuleName, PackageEntry, PackageHeader,
     PackageKeyword, PackageName, PackagesKeyword, PlatformHeader, PlatformKeyword,
     PlatformRequires, ProvidesKeyword, ProvidesTo, RequiresKeyword, To, ToKeyword, TypedIdent,
-    WithKeyword,
+    UnavailableKeyword, WithKeyword,
 };
 use roc_parse::ident::UppercaseIdent;
 use roc_region::all::Loc;
@@ -77,6 +77,7 @@ keywords! {
     ProvidesKeyword,
     ToKeyword,
     PlatformKeyword,
+    UnavailableKeyword,
 }
 
 impl<V: Formattable> Formattable for Option<V> {
@@ -247,20 +248,48 @@ pub fn fmt_platform_header<'a>(buf: &mut Buf, header: &'a PlatformHeader<'a>) {
     fmt_imports(buf, header.imports.item, indent);
     header.provides.keyword.format(buf, indent);
     fmt_provides(buf, header.provides.item, None, indent);
+
+    if let Some(unavailable) = &header.unavailable {
+        unavailable.keyword.format(buf, indent);
+        fmt_exposes(buf, unavailable.item, indent);
+    }
 }
 
 fn fmt_requires(buf: &mut Buf, requires: &PlatformRequires, indent: u16) {
     fmt_collection(buf, indent, Braces::Curly, requires.rigids, Newlines::No);
 
     buf.push_str(" {");
-    buf.spaces(1);
-    requires.signature.value.format(buf, indent);
-    buf.push_str(" }");
+
+    if requires.signature.is_multiline() {
+        // Like a multiline collection with one item, so that a long signature gets lines of its own
+        let signature = requires.signature.extract_spaces();
+        let item_indent = indent + INDENT;
+
+        buf.ensure_ends_with_newline();
+        fmt_comments_only(buf, signature.before.iter(), NewlineAt::Bottom, item_indent);
+
+        signature.item.format(buf, item_indent);
+        buf.push(',');
+
+        if signature.after.iter().any(|space| space.is_newline()) {
+            buf.newline();
+        }
+
+        fmt_comments_only(buf, signature.after.iter(), NewlineAt::None, item_indent);
+
+        buf.ensure_ends_with_newline();
+        buf.indent(indent);
+        buf.push('}');
+    } else {
+        buf.spaces(1);
+        requires.signature.value.format(buf, indent);
+        buf.push_str(" }");
+    }
 }
 
 impl<'a> Formattable for TypedIdent<'a> {
     fn is_multiline(&self) -> bool {
-        false
+        self.ann.is_multiline()
     }
 
     fn format_with_options(
@@ -468,6 +497,15 @@ fn fmt_packages_entry(buf: &mut Buf, entry: &PackageEntry, indent: u16) {
     }
 
     fmt_package_name(buf, entry.package_name.value, indent);
+
+    if let Some(target) = entry.target {
+        fmt_default_spaces(buf, target.spaces_before_if, indent);
+        buf.indent(indent);
+        buf.push_str(roc_parse::keyword::IF);
+        fmt_default_spaces(buf, target.spaces_after_if, indent);
+        buf.indent(indent);
+        buf.push_str(target.name.value);
+    }
 }
 
 fn fmt_imports_entry(buf: &mut Buf, entry: &ImportsEntry, indent: u16) {
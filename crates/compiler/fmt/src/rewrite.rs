@@ -0,0 +1,782 @@
+//! Structural rewrites of a module's syntax tree, for tools that migrate code when an API changes.
+//!
+//! A [Rewriter] says what to do with each expression, pattern, type annotation, tag, and def,
+//! and [rewrite_module] applies it to every one of them in a module, then prints the result
+//! through the formatter. Spaces and comments are kept where they were.
+use crate::def::fmt_defs;
+use crate::module::fmt_module;
+use crate::{match_line_endings, Ast, Buf};
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_module::called_via::BinOp;
+use roc_parse::ast::{
+    AbilityMember, AssignedField, Collection, Defs, Expr, ImplementsClause, Pattern,
+    RecordBuilderField, StrLiteral, StrSegment, Tag, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    WhenBranch,
+};
+use roc_parse::module::{self, parse_module_defs};
+use roc_parse::parser::SyntaxError;
+use roc_parse::state::State;
+use roc_region::all::Loc;
+
+/// What to change in a module. Each method is called on a node after everything inside it has
+/// been rewritten, and returns what should replace it. The defaults leave the node alone.
+pub trait Rewriter<'a> {
+    fn expr(&self, _arena: &'a Bump, expr: Expr<'a>) -> Expr<'a> {
+        expr
+    }
+
+    fn pattern(&self, _arena: &'a Bump, pattern: Pattern<'a>) -> Pattern<'a> {
+        pattern
+    }
+
+    fn type_annotation(&self, _arena: &'a Bump, ann: TypeAnnotation<'a>) -> TypeAnnotation<'a> {
+        ann
+    }
+
+    fn tag(&self, _arena: &'a Bump, tag: Tag<'a>) -> Tag<'a> {
+        tag
+    }
+
+    fn value_def(&self, _arena: &'a Bump, def: ValueDef<'a>) -> ValueDef<'a> {
+        def
+    }
+}
+
+/// Parses a module, applies the rewriter to all of it, and formats the result.
+pub fn rewrite_module<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    rewriter: &dyn Rewriter<'a>,
+) -> Result<String, SyntaxError<'a>> {
+    let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
+        .map_err(|e| SyntaxError::Header(e.problem))?;
+
+    let (module, defs) = module.upgrade_header_imports(arena);
+
+    let defs = parse_module_defs(arena, state, defs)?;
+
+    let ast = Ast { module, defs }.rewrite(arena, rewriter);
+
+    let mut buf = Buf::new_in(arena);
+
+    fmt_module(&mut buf, &ast.module);
+    fmt_defs(&mut buf, &ast.defs, 0);
+    buf.fmt_end_of_file();
+
+    Ok(match_line_endings(src, buf.as_str()).into_owned())
+}
+
+/// Renames a tag everywhere it's used in a module: in expressions, in patterns, and in the tag
+/// unions of type annotations.
+pub struct RenameTag<'r> {
+    pub from: &'r str,
+    pub to: &'r str,
+}
+
+impl<'a, 'r> Rewriter<'a> for RenameTag<'r> {
+    fn expr(&self, arena: &'a Bump, expr: Expr<'a>) -> Expr<'a> {
+        match expr {
+            Expr::Tag(name) if name == self.from => Expr::Tag(arena.alloc_str(self.to)),
+            _ => expr,
+        }
+    }
+
+    fn pattern(&self, arena: &'a Bump, pattern: Pattern<'a>) -> Pattern<'a> {
+        match pattern {
+            Pattern::Tag(name) if name == self.from => Pattern::Tag(arena.alloc_str(self.to)),
+            _ => pattern,
+        }
+    }
+
+    fn tag(&self, arena: &'a Bump, tag: Tag<'a>) -> Tag<'a> {
+        match tag {
+            Tag::Apply { name, args } if name.value == self.from => Tag::Apply {
+                name: Loc::at(name.region, arena.alloc_str(self.to)),
+                args,
+            },
+            _ => tag,
+        }
+    }
+}
+
+/// Changes the order of a function's arguments at every call site in a module. `order[i]` is
+/// the index of the argument that moves to position `i`, so `[1, 0]` swaps two arguments.
+///
+/// Calls are matched by the name they're made with, where `module_name` is empty for calls that
+/// aren't qualified. When it's empty, a definition of the function in the module gets its
+/// arguments, and the arguments of its annotation, reordered too.
+///
+/// A call on the right of `|>` is only changed when its first argument stays first, since the
+/// piped value can't be moved anywhere else.
+pub struct ReorderArgs<'r> {
+    module_name: &'r str,
+    ident: &'r str,
+    order: &'r [usize],
+}
+
+impl<'r> ReorderArgs<'r> {
+    /// Returns `None` if `order` isn't an ordering of `0..order.len()`.
+    pub fn new(module_name: &'r str, ident: &'r str, order: &'r [usize]) -> Option<Self> {
+        let mut seen = vec![false; order.len()];
+
+        for &index in order {
+            if index >= order.len() || std::mem::replace(&mut seen[index], true) {
+                return None;
+            }
+        }
+
+        Some(ReorderArgs {
+            module_name,
+            ident,
+            order,
+        })
+    }
+
+    fn is_function(&self, expr: &Expr<'_>) -> bool {
+        matches!(
+            without_spaces(expr),
+            Expr::Var { module_name, ident } if *module_name == self.module_name && *ident == self.ident
+        )
+    }
+
+    fn is_definition(&self, pattern: &Pattern<'_>) -> bool {
+        self.module_name.is_empty()
+            && matches!(without_spaces_pattern(pattern), Pattern::Identifier { ident } if *ident == self.ident)
+    }
+
+    fn reorder<'a, T: Copy>(&self, arena: &'a Bump, items: &[T]) -> &'a [T] {
+        Vec::from_iter_in(self.order.iter().map(|&index| items[index]), arena).into_bump_slice()
+    }
+
+    /// Reorders the arguments of a call whose first argument comes from `|>`.
+    fn reorder_piped<'a>(&self, arena: &'a Bump, expr: &Expr<'a>) -> Option<Expr<'a>> {
+        map_under_spaces(arena, expr, &|expr| match *expr {
+            Expr::Apply(function, args, called_via)
+                if self.order.first() == Some(&0)
+                    && args.len() + 1 == self.order.len()
+                    && self.is_function(&function.value) =>
+            {
+                let args =
+                    Vec::from_iter_in(self.order[1..].iter().map(|&index| args[index - 1]), arena);
+
+                Some(Expr::Apply(function, args.into_bump_slice(), called_via))
+            }
+            _ => None,
+        })
+    }
+
+    /// Reorders the arguments of a function's definition, found under any spaces or parens.
+    fn reorder_closure<'a>(&self, arena: &'a Bump, expr: &Expr<'a>) -> Option<Expr<'a>> {
+        map_under_spaces(arena, expr, &|expr| match *expr {
+            Expr::Closure(args, body) if args.len() == self.order.len() => {
+                Some(Expr::Closure(self.reorder(arena, args), body))
+            }
+            _ => None,
+        })
+    }
+
+    fn reorder_function_type<'a>(
+        &self,
+        arena: &'a Bump,
+        ann: &TypeAnnotation<'a>,
+    ) -> Option<TypeAnnotation<'a>> {
+        match ann {
+            TypeAnnotation::Function(args, ret) if args.len() == self.order.len() => {
+                Some(TypeAnnotation::Function(self.reorder(arena, args), ret))
+            }
+            TypeAnnotation::Where(ann, clauses) => Some(TypeAnnotation::Where(
+                arena.alloc(Loc::at(
+                    ann.region,
+                    self.reorder_function_type(arena, &ann.value)?,
+                )),
+                clauses,
+            )),
+            TypeAnnotation::SpaceBefore(ann, spaces) => Some(TypeAnnotation::SpaceBefore(
+                arena.alloc(self.reorder_function_type(arena, ann)?),
+                spaces,
+            )),
+            TypeAnnotation::SpaceAfter(ann, spaces) => Some(TypeAnnotation::SpaceAfter(
+                arena.alloc(self.reorder_function_type(arena, ann)?),
+                spaces,
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'r> Rewriter<'a> for ReorderArgs<'r> {
+    fn expr(&self, arena: &'a Bump, expr: Expr<'a>) -> Expr<'a> {
+        match expr {
+            Expr::Apply(function, args, called_via)
+                if args.len() == self.order.len() && self.is_function(&function.value) =>
+            {
+                Expr::Apply(function, self.reorder(arena, args), called_via)
+            }
+            Expr::BinOps(lefts, right) => {
+                let mut lefts = Vec::from_iter_in(lefts.iter().copied(), arena);
+                let mut right = *right;
+
+                for index in 0..lefts.len() {
+                    if lefts[index].1.value != BinOp::Pizza {
+                        continue;
+                    }
+
+                    let operand = match lefts.get_mut(index + 1) {
+                        Some((operand, _)) => operand,
+                        None => &mut right,
+                    };
+
+                    if let Some(reordered) = self.reorder_piped(arena, &operand.value) {
+                        operand.value = reordered;
+                    }
+                }
+
+                Expr::BinOps(lefts.into_bump_slice(), arena.alloc(right))
+            }
+            _ => expr,
+        }
+    }
+
+    fn value_def(&self, arena: &'a Bump, def: ValueDef<'a>) -> ValueDef<'a> {
+        match def {
+            ValueDef::Body(pattern, body) if self.is_definition(&pattern.value) => {
+                match self.reorder_closure(arena, &body.value) {
+                    Some(closure) => {
+                        ValueDef::Body(pattern, arena.alloc(Loc::at(body.region, closure)))
+                    }
+                    None => def,
+                }
+            }
+            ValueDef::Annotation(pattern, ann) if self.is_definition(&pattern.value) => {
+                match self.reorder_function_type(arena, &ann.value) {
+                    Some(function_type) => {
+                        ValueDef::Annotation(pattern, Loc::at(ann.region, function_type))
+                    }
+                    None => def,
+                }
+            }
+            ValueDef::AnnotatedBody {
+                ann_pattern,
+                ann_type,
+                comment,
+                body_pattern,
+                body_expr,
+            } if self.is_definition(&body_pattern.value) => ValueDef::AnnotatedBody {
+                ann_pattern,
+                ann_type: match self.reorder_function_type(arena, &ann_type.value) {
+                    Some(function_type) => arena.alloc(Loc::at(ann_type.region, function_type)),
+                    None => ann_type,
+                },
+                comment,
+                body_pattern,
+                body_expr: match self.reorder_closure(arena, &body_expr.value) {
+                    Some(closure) => arena.alloc(Loc::at(body_expr.region, closure)),
+                    None => body_expr,
+                },
+            },
+            _ => def,
+        }
+    }
+}
+
+fn without_spaces<'a, 'b>(expr: &'b Expr<'a>) -> &'b Expr<'a> {
+    match expr {
+        Expr::SpaceBefore(expr, _) | Expr::SpaceAfter(expr, _) | Expr::ParensAround(expr) => {
+            without_spaces(expr)
+        }
+        _ => expr,
+    }
+}
+
+fn without_spaces_pattern<'a, 'b>(pattern: &'b Pattern<'a>) -> &'b Pattern<'a> {
+    match pattern {
+        Pattern::SpaceBefore(pattern, _) | Pattern::SpaceAfter(pattern, _) => {
+            without_spaces_pattern(pattern)
+        }
+        _ => pattern,
+    }
+}
+
+/// Replaces the expression under any spaces and parens around it, keeping them.
+fn map_under_spaces<'a>(
+    arena: &'a Bump,
+    expr: &Expr<'a>,
+    f: &dyn Fn(&Expr<'a>) -> Option<Expr<'a>>,
+) -> Option<Expr<'a>> {
+    match expr {
+        Expr::SpaceBefore(inner, spaces) => Some(Expr::SpaceBefore(
+            arena.alloc(map_under_spaces(arena, inner, f)?),
+            spaces,
+        )),
+        Expr::SpaceAfter(inner, spaces) => Some(Expr::SpaceAfter(
+            arena.alloc(map_under_spaces(arena, inner, f)?),
+            spaces,
+        )),
+        Expr::ParensAround(inner) => Some(Expr::ParensAround(
+            arena.alloc(map_under_spaces(arena, inner, f)?),
+        )),
+        _ => f(expr),
+    }
+}
+
+pub trait Rewrite<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self;
+}
+
+impl<'a> Rewrite<'a> for Ast<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        Ast {
+            module: self.module.clone(),
+            defs: self.defs.rewrite(arena, rewriter),
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for Defs<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        let mut defs = self.clone();
+
+        for type_def in defs.type_defs.iter_mut() {
+            *type_def = type_def.rewrite(arena, rewriter);
+        }
+
+        for value_def in defs.value_defs.iter_mut() {
+            *value_def = value_def.rewrite(arena, rewriter);
+        }
+
+        defs
+    }
+}
+
+impl<'a, T: Rewrite<'a>> Rewrite<'a> for Option<T> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        self.as_ref().map(|a| a.rewrite(arena, rewriter))
+    }
+}
+
+impl<'a, T: Rewrite<'a>> Rewrite<'a> for Loc<T> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        Loc::at(self.region, self.value.rewrite(arena, rewriter))
+    }
+}
+
+impl<'a, A: Rewrite<'a>, B: Rewrite<'a>> Rewrite<'a> for (A, B) {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        (
+            self.0.rewrite(arena, rewriter),
+            self.1.rewrite(arena, rewriter),
+        )
+    }
+}
+
+impl<'a, T: Rewrite<'a>> Rewrite<'a> for Collection<'a, T> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        self.replace_items(self.items.rewrite(arena, rewriter))
+    }
+}
+
+impl<'a, T: Rewrite<'a>> Rewrite<'a> for &'a [T] {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        Vec::from_iter_in(self.iter().map(|item| item.rewrite(arena, rewriter)), arena)
+            .into_bump_slice()
+    }
+}
+
+impl<'a, T: Rewrite<'a>> Rewrite<'a> for &'a T {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        arena.alloc((*self).rewrite(arena, rewriter))
+    }
+}
+
+impl<'a> Rewrite<'a> for BinOp {
+    fn rewrite(&self, _arena: &'a Bump, _rewriter: &dyn Rewriter<'a>) -> Self {
+        *self
+    }
+}
+
+impl<'a> Rewrite<'a> for TypeDef<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        use TypeDef::*;
+
+        match *self {
+            Alias { header, ann } => Alias {
+                header: header.rewrite(arena, rewriter),
+                ann: ann.rewrite(arena, rewriter),
+            },
+            Opaque {
+                header,
+                typ,
+                derived,
+            } => Opaque {
+                header: header.rewrite(arena, rewriter),
+                typ: typ.rewrite(arena, rewriter),
+                derived,
+            },
+            Ability {
+                header,
+                loc_implements,
+                members,
+            } => Ability {
+                header: header.rewrite(arena, rewriter),
+                loc_implements,
+                members: members.rewrite(arena, rewriter),
+            },
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for TypeHeader<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        TypeHeader {
+            name: self.name,
+            vars: self.vars.rewrite(arena, rewriter),
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for AbilityMember<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        AbilityMember {
+            name: self.name,
+            typ: self.typ.rewrite(arena, rewriter),
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for ValueDef<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        use ValueDef::*;
+
+        let def = match *self {
+            Annotation(a, b) => Annotation(a.rewrite(arena, rewriter), b.rewrite(arena, rewriter)),
+            Body(a, b) => Body(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            AnnotatedBody {
+                ann_pattern,
+                ann_type,
+                comment,
+                body_pattern,
+                body_expr,
+            } => AnnotatedBody {
+                ann_pattern: arena.alloc(ann_pattern.rewrite(arena, rewriter)),
+                ann_type: arena.alloc(ann_type.rewrite(arena, rewriter)),
+                comment,
+                body_pattern: arena.alloc(body_pattern.rewrite(arena, rewriter)),
+                body_expr: arena.alloc(body_expr.rewrite(arena, rewriter)),
+            },
+            Dbg {
+                condition,
+                preceding_comment,
+            } => Dbg {
+                condition: arena.alloc(condition.rewrite(arena, rewriter)),
+                preceding_comment,
+            },
+            Expect {
+                condition,
+                preceding_comment,
+            } => Expect {
+                condition: arena.alloc(condition.rewrite(arena, rewriter)),
+                preceding_comment,
+            },
+            ExpectFx {
+                condition,
+                preceding_comment,
+            } => ExpectFx {
+                condition: arena.alloc(condition.rewrite(arena, rewriter)),
+                preceding_comment,
+            },
+            ModuleImport(_) | IngestedFileImport(_) => *self,
+            Stmt(loc_expr) => Stmt(arena.alloc(loc_expr.rewrite(arena, rewriter))),
+        };
+
+        rewriter.value_def(arena, def)
+    }
+}
+
+impl<'a> Rewrite<'a> for WhenBranch<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        WhenBranch {
+            patterns: self.patterns.rewrite(arena, rewriter),
+            value: self.value.rewrite(arena, rewriter),
+            guard: self.guard.rewrite(arena, rewriter),
+        }
+    }
+}
+
+impl<'a, T: Rewrite<'a> + Copy> Rewrite<'a> for AssignedField<'a, T> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        match *self {
+            AssignedField::RequiredValue(a, b, c) => {
+                AssignedField::RequiredValue(a, b, arena.alloc(c.rewrite(arena, rewriter)))
+            }
+            AssignedField::OptionalValue(a, b, c) => {
+                AssignedField::OptionalValue(a, b, arena.alloc(c.rewrite(arena, rewriter)))
+            }
+            AssignedField::LabelOnly(a) => AssignedField::LabelOnly(a),
+            AssignedField::Malformed(a) => AssignedField::Malformed(a),
+            AssignedField::SpaceBefore(a, b) => {
+                AssignedField::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            AssignedField::SpaceAfter(a, b) => {
+                AssignedField::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for RecordBuilderField<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        match *self {
+            RecordBuilderField::Value(a, b, c) => {
+                RecordBuilderField::Value(a, b, arena.alloc(c.rewrite(arena, rewriter)))
+            }
+            RecordBuilderField::ApplyValue(a, b, c, d) => {
+                RecordBuilderField::ApplyValue(a, b, c, arena.alloc(d.rewrite(arena, rewriter)))
+            }
+            RecordBuilderField::LabelOnly(a) => RecordBuilderField::LabelOnly(a),
+            RecordBuilderField::Malformed(a) => RecordBuilderField::Malformed(a),
+            RecordBuilderField::SpaceBefore(a, b) => {
+                RecordBuilderField::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            RecordBuilderField::SpaceAfter(a, b) => {
+                RecordBuilderField::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for StrLiteral<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        match *self {
+            StrLiteral::PlainLine(t) => StrLiteral::PlainLine(t),
+            StrLiteral::Line(t) => StrLiteral::Line(t.rewrite(arena, rewriter)),
+            StrLiteral::Block(t) => StrLiteral::Block(t.rewrite(arena, rewriter)),
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for StrSegment<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        match *self {
+            StrSegment::Plaintext(_) | StrSegment::Unicode(_) | StrSegment::EscapedChar(_) => *self,
+            StrSegment::Interpolated(t) => StrSegment::Interpolated(t.rewrite(arena, rewriter)),
+            StrSegment::DeprecatedInterpolated(t) => {
+                StrSegment::DeprecatedInterpolated(t.rewrite(arena, rewriter))
+            }
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for Expr<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        let expr = match *self {
+            Expr::Float(_)
+            | Expr::Num(_)
+            | Expr::NonBase10Int { .. }
+            | Expr::SingleQuote(_)
+            | Expr::AccessorFunction(_)
+            | Expr::Var { .. }
+            | Expr::Underscore(_)
+            | Expr::Crash
+            | Expr::Tag(_)
+            | Expr::OpaqueRef(_)
+            | Expr::EmptyDefsFinal
+            | Expr::MalformedIdent(_, _)
+            | Expr::MalformedClosure
+            | Expr::PrecedenceConflict(_) => *self,
+            Expr::Str(a) => Expr::Str(a.rewrite(arena, rewriter)),
+            Expr::RecordAccess(a, b) => {
+                Expr::RecordAccess(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            Expr::TupleAccess(a, b) => {
+                Expr::TupleAccess(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            Expr::TaskAwaitBang(a) => Expr::TaskAwaitBang(arena.alloc(a.rewrite(arena, rewriter))),
+            Expr::List(a) => Expr::List(a.rewrite(arena, rewriter)),
+            Expr::RecordUpdate { update, fields } => Expr::RecordUpdate {
+                update: arena.alloc(update.rewrite(arena, rewriter)),
+                fields: fields.rewrite(arena, rewriter),
+            },
+            Expr::Record(a) => Expr::Record(a.rewrite(arena, rewriter)),
+            Expr::RecordBuilder(a) => Expr::RecordBuilder(a.rewrite(arena, rewriter)),
+            Expr::Tuple(a) => Expr::Tuple(a.rewrite(arena, rewriter)),
+            Expr::Closure(a, b) => Expr::Closure(
+                a.rewrite(arena, rewriter),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::Defs(a, b) => Expr::Defs(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::Backpassing(a, b, c) => Expr::Backpassing(
+                a.rewrite(arena, rewriter),
+                arena.alloc(b.rewrite(arena, rewriter)),
+                arena.alloc(c.rewrite(arena, rewriter)),
+            ),
+            Expr::Expect(a, b) => Expr::Expect(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::Dbg(a, b) => Expr::Dbg(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::LowLevelDbg(a, b, c) => Expr::LowLevelDbg(
+                a,
+                arena.alloc(b.rewrite(arena, rewriter)),
+                arena.alloc(c.rewrite(arena, rewriter)),
+            ),
+            Expr::Apply(a, b, c) => Expr::Apply(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                b.rewrite(arena, rewriter),
+                c,
+            ),
+            Expr::BinOps(a, b) => Expr::BinOps(
+                a.rewrite(arena, rewriter),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::UnaryOp(a, b) => Expr::UnaryOp(arena.alloc(a.rewrite(arena, rewriter)), b),
+            Expr::If(a, b) => Expr::If(
+                a.rewrite(arena, rewriter),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            Expr::When(a, b) => Expr::When(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                b.rewrite(arena, rewriter),
+            ),
+            Expr::SpaceBefore(a, b) => {
+                Expr::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            Expr::SpaceAfter(a, b) => Expr::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b),
+            Expr::ParensAround(a) => Expr::ParensAround(arena.alloc(a.rewrite(arena, rewriter))),
+            Expr::MalformedSuffixed(a) => {
+                Expr::MalformedSuffixed(arena.alloc(a.rewrite(arena, rewriter)))
+            }
+            Expr::MultipleRecordBuilders(a) => {
+                Expr::MultipleRecordBuilders(arena.alloc(a.rewrite(arena, rewriter)))
+            }
+            Expr::UnappliedRecordBuilder(a) => {
+                Expr::UnappliedRecordBuilder(arena.alloc(a.rewrite(arena, rewriter)))
+            }
+        };
+
+        rewriter.expr(arena, expr)
+    }
+}
+
+impl<'a> Rewrite<'a> for Pattern<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        let pattern = match *self {
+            Pattern::Identifier { .. }
+            | Pattern::QualifiedIdentifier { .. }
+            | Pattern::Tag(_)
+            | Pattern::OpaqueRef(_)
+            | Pattern::NumLiteral(_)
+            | Pattern::NonBase10Literal { .. }
+            | Pattern::FloatLiteral(_)
+            | Pattern::StrLiteral(_)
+            | Pattern::Underscore(_)
+            | Pattern::SingleQuote(_)
+            | Pattern::ListRest(_)
+            | Pattern::Malformed(_)
+            | Pattern::MalformedIdent(_, _) => *self,
+            Pattern::Apply(a, b) => Pattern::Apply(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                b.rewrite(arena, rewriter),
+            ),
+            Pattern::RecordDestructure(a) => Pattern::RecordDestructure(a.rewrite(arena, rewriter)),
+            Pattern::RequiredField(a, b) => {
+                Pattern::RequiredField(a, arena.alloc(b.rewrite(arena, rewriter)))
+            }
+            Pattern::OptionalField(a, b) => {
+                Pattern::OptionalField(a, arena.alloc(b.rewrite(arena, rewriter)))
+            }
+            Pattern::Tuple(a) => Pattern::Tuple(a.rewrite(arena, rewriter)),
+            Pattern::List(a) => Pattern::List(a.rewrite(arena, rewriter)),
+            Pattern::As(a, b) => Pattern::As(arena.alloc(a.rewrite(arena, rewriter)), b),
+            Pattern::SpaceBefore(a, b) => {
+                Pattern::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            Pattern::SpaceAfter(a, b) => {
+                Pattern::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+        };
+
+        rewriter.pattern(arena, pattern)
+    }
+}
+
+impl<'a> Rewrite<'a> for TypeAnnotation<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        let ann = match *self {
+            TypeAnnotation::BoundVariable(_)
+            | TypeAnnotation::Inferred
+            | TypeAnnotation::Wildcard
+            | TypeAnnotation::Malformed(_) => *self,
+            TypeAnnotation::Function(a, b) => TypeAnnotation::Function(
+                a.rewrite(arena, rewriter),
+                arena.alloc(b.rewrite(arena, rewriter)),
+            ),
+            TypeAnnotation::Apply(a, b, c) => {
+                TypeAnnotation::Apply(a, b, c.rewrite(arena, rewriter))
+            }
+            TypeAnnotation::As(a, b, c) => TypeAnnotation::As(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                b,
+                c.rewrite(arena, rewriter),
+            ),
+            TypeAnnotation::Tuple { elems, ext } => TypeAnnotation::Tuple {
+                elems: elems.rewrite(arena, rewriter),
+                ext: ext.rewrite(arena, rewriter),
+            },
+            TypeAnnotation::Record { fields, ext } => TypeAnnotation::Record {
+                fields: fields.rewrite(arena, rewriter),
+                ext: ext.rewrite(arena, rewriter),
+            },
+            TypeAnnotation::TagUnion { ext, tags } => TypeAnnotation::TagUnion {
+                ext: ext.rewrite(arena, rewriter),
+                tags: tags.rewrite(arena, rewriter),
+            },
+            TypeAnnotation::Where(a, b) => TypeAnnotation::Where(
+                arena.alloc(a.rewrite(arena, rewriter)),
+                b.rewrite(arena, rewriter),
+            ),
+            TypeAnnotation::SpaceBefore(a, b) => {
+                TypeAnnotation::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+            TypeAnnotation::SpaceAfter(a, b) => {
+                TypeAnnotation::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b)
+            }
+        };
+
+        rewriter.type_annotation(arena, ann)
+    }
+}
+
+impl<'a> Rewrite<'a> for ImplementsClause<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        ImplementsClause {
+            var: self.var,
+            abilities: self.abilities.rewrite(arena, rewriter),
+        }
+    }
+}
+
+impl<'a> Rewrite<'a> for Tag<'a> {
+    fn rewrite(&self, arena: &'a Bump, rewriter: &dyn Rewriter<'a>) -> Self {
+        let tag = match *self {
+            Tag::Apply { name, args } => Tag::Apply {
+                name,
+                args: args.rewrite(arena, rewriter),
+            },
+            Tag::Malformed(_) => *self,
+            Tag::SpaceBefore(a, b) => Tag::SpaceBefore(arena.alloc(a.rewrite(arena, rewriter)), b),
+            Tag::SpaceAfter(a, b) => Tag::SpaceAfter(arena.alloc(a.rewrite(arena, rewriter)), b),
+        };
+
+        rewriter.tag(arena, tag)
+    }
+}
@@ -12,8 +12,8 @@ use roc_parse::{
     },
     header::{
         AppHeader, ExposedName, HostedHeader, ImportsEntry, KeywordItem, ModuleHeader, ModuleName,
-        PackageEntry, PackageHeader, PackageName, PlatformHeader, PlatformRequires, ProvidesTo, To,
-        TypedIdent,
+        PackageEntry, PackageHeader, PackageName, PackageTarget, PlatformHeader, PlatformRequires,
+        ProvidesTo, To, TypedIdent,
     },
     ident::{BadIdent, UppercaseIdent},
 };
@@ -313,6 +313,7 @@ impl<'a> RemoveSpaces<'a> for Module<'a> {
                 packages: header.packages.remove_spaces(arena),
                 imports: header.imports.remove_spaces(arena),
                 provides: header.provides.remove_spaces(arena),
+                unavailable: header.unavailable.remove_spaces(arena),
             }),
             Header::Hosted(header) => Header::Hosted(HostedHeader {
                 before_name: &[],
@@ -414,6 +415,11 @@ impl<'a> RemoveSpaces<'a> for PackageEntry<'a> {
                 None => None,
             },
             package_name: self.package_name.remove_spaces(arena),
+            target: self.target.map(|target| PackageTarget {
+                spaces_before_if: &[],
+                spaces_after_if: &[],
+                name: target.name.remove_spaces(arena),
+            }),
         }
     }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::redundant_closure_call)]
 //|> clippy false positive: https://github.com/rust-lang/rust-clippy/issues/1553
 
-use crate::generic64::{storage::StorageManager, Assembler, CallConv, RegTrait};
+use crate::generic64::{storage::StorageManager, Assembler, CallConv, LocalDataPointer, RegTrait};
 use crate::{
     pointer_layouts, single_register_floats, single_register_int_builtins,
     single_register_integers, single_register_layouts, Relocation,
@@ -1370,6 +1370,9 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         });
     }
 
+    // Static list literals are allocated on the heap instead.
+    const LOCAL_DATA_POINTER: Option<LocalDataPointer<AArch64GeneralReg>> = None;
+
     #[inline(always)]
     fn imul_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
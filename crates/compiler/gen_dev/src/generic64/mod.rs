@@ -156,6 +156,9 @@ pub enum CompareOperation {
 /// Thus, some backends will need to use mulitiple instructions to preform a single one of this calls.
 /// Generally, I prefer explicit sources, as opposed to dst being one of the sources. Ex: `x = x + y` would be `add x, x, y` instead of `add x, y`.
 /// dst should always come before sources.
+pub type LocalDataPointer<GeneralReg> =
+    fn(&mut Vec<'_, u8>, &mut Vec<'_, Relocation>, std::vec::Vec<u8>, GeneralReg);
+
 pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
     fn abs_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
     fn abs_freg64_freg64(
@@ -259,6 +262,10 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         dst: GeneralReg,
     );
 
+    /// Loads the address of `data`, which is stored in the data section of the object file.
+    /// `None` on architectures that can't refer to local data yet.
+    const LOCAL_DATA_POINTER: Option<LocalDataPointer<GeneralReg>>;
+
     /// Jumps by an offset of offset bytes unconditionally.
     /// It should always generate the same number of bytes to enable replacement if offset changes.
     /// It returns the base offset to calculate the jump from (generally the instruction after the jump).
@@ -1044,6 +1051,14 @@ impl<
         out.into_bump_slice()
     }
 
+    fn build_roc_dbg(&mut self) -> &'a [u8] {
+        let mut out = bumpalo::vec![in self.env.arena];
+
+        ASM::ret(&mut out);
+
+        out.into_bump_slice()
+    }
+
     fn build_roc_longjmp(&mut self) -> &'a [u8] {
         let mut out = bumpalo::vec![in self.env.arena];
 
@@ -1386,6 +1401,15 @@ impl<
                 ASM::add_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
 
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_ADD_WRAP_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
             LayoutRepr::Builtin(Builtin::Float(FloatWidth::F64)) => {
                 let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
@@ -1789,6 +1813,34 @@ impl<
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::neg_reg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
+            LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                // unlike subtracting from 0, multiplying by -1 turns 0.0 into -0.0
+                let minus_one = self.debug_symbol("minus_one");
+                self.load_literal(&minus_one, layout, &Literal::Float(-1.0));
+
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                let minus_one_reg = self
+                    .storage_manager
+                    .load_to_float_reg(&mut self.buf, &minus_one);
+
+                match float_width {
+                    FloatWidth::F64 => ASM::mul_freg64_freg64_freg64(
+                        &mut self.buf,
+                        dst_reg,
+                        src_reg,
+                        minus_one_reg,
+                    ),
+                    FloatWidth::F32 => ASM::mul_freg32_freg32_freg32(
+                        &mut self.buf,
+                        dst_reg,
+                        src_reg,
+                        minus_one_reg,
+                    ),
+                }
+
+                self.free_symbol(&minus_one);
+            }
             x => todo!("NumNeg: layout, {:?}", x),
         }
     }
@@ -1846,6 +1898,15 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::sub_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SUB_WRAP_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
             x => todo!("NumSubWrap: layout, {:?}", x),
         }
     }
@@ -2313,7 +2374,8 @@ impl<
             HigherOrder::ListMap { .. }
             | HigherOrder::ListMap2 { .. }
             | HigherOrder::ListMap3 { .. }
-            | HigherOrder::ListMap4 { .. } => CallerProc::new_list_map(
+            | HigherOrder::ListMap4 { .. }
+            | HigherOrder::ListWalk { .. } => CallerProc::new_list_map(
                 self.env.arena,
                 self.env.module_id,
                 ident_ids,
@@ -2827,6 +2889,94 @@ impl<
                 self.free_symbol(&Symbol::DEV_TMP);
                 self.free_symbol(&Symbol::DEV_TMP2);
             }
+            HigherOrder::ListWalk { xs, state } => {
+                let element_layout = higher_order.passed_function.argument_layouts[1];
+                let state_layout = higher_order.passed_function.return_layout;
+
+                let input_list_layout = LayoutRepr::Builtin(Builtin::List(element_layout));
+                let input_list_in_layout = self
+                    .layout_interner
+                    .insert_direct_no_semantic(input_list_layout);
+
+                let element_width = self.debug_symbol("element_width");
+                let state_width = self.debug_symbol("state_width");
+
+                self.load_layout_stack_size(element_layout, element_width);
+                self.load_layout_stack_size(state_layout, state_width);
+
+                let inc_n_element = self.increment_fn_pointer(element_layout);
+
+                // the zig code alternates between these two, and leaves the final state in the first
+                let state_ptr = self.debug_symbol("state_ptr");
+                let scratch_ptr = self.debug_symbol("scratch_ptr");
+
+                self.load_literal_symbols(&[state]);
+                self.build_alloca(state_ptr, Some(state), state_layout);
+                self.build_alloca(scratch_ptr, None, state_layout);
+
+                self.build_fn_pointer(&caller, caller_string);
+
+                // we pass a null pointer when the data is not owned. the zig code must not call this!
+                let data_is_owned = higher_order.closure_env_layout.is_some()
+                    && higher_order.passed_function.owns_captured_environment;
+
+                self.load_literal(
+                    &Symbol::DEV_TMP2,
+                    &Layout::BOOL,
+                    &Literal::Bool(data_is_owned),
+                );
+
+                //    list: RocList,
+                //    caller: Caller2,
+                //    data: Opaque,
+                //    inc_n_data: IncN,
+                //    data_is_owned: bool,
+                //    inc_n_element: IncN,
+                //    element_width: usize,
+                //    state: ?[*]u8,
+                //    scratch: ?[*]u8,
+                //    state_width: usize,
+
+                let arguments = [
+                    xs,
+                    caller,
+                    data,
+                    inc_n_data,
+                    Symbol::DEV_TMP2,
+                    inc_n_element,
+                    element_width,
+                    state_ptr,
+                    scratch_ptr,
+                    state_width,
+                ];
+
+                let layouts = [
+                    input_list_in_layout,
+                    ptr,
+                    ptr,
+                    ptr,
+                    Layout::BOOL,
+                    ptr,
+                    usize_,
+                    ptr,
+                    ptr,
+                    usize_,
+                ];
+
+                self.build_fn_call(
+                    &Symbol::DEV_TMP3,
+                    bitcode::LIST_WALK.to_string(),
+                    &arguments,
+                    &layouts,
+                    &Layout::UNIT,
+                );
+
+                self.build_ptr_load(*dst, state_ptr, state_layout);
+
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbol(&Symbol::DEV_TMP2);
+                self.free_symbol(&Symbol::DEV_TMP3);
+            }
         }
     }
 
@@ -3390,6 +3540,28 @@ impl<
         let element_width = self.layout_interner.stack_size(*element_in_layout) as u64;
         let element_alignment = self.layout_interner.alignment_bytes(*element_in_layout) as u64;
 
+        if let (Some(local_data_pointer), Some(data)) = (
+            ASM::LOCAL_DATA_POINTER,
+            self.static_list_data(*element_in_layout, elements),
+        ) {
+            // The refcount is zero, which marks the list as static: it is never freed and never
+            // updated in place.
+            let alignment = Ord::max(8, element_alignment) as usize;
+            let mut bytes = vec![0; alignment];
+            bytes.extend(data);
+
+            let allocation_symbol = self.debug_symbol("list_allocation");
+            let ptr_reg = self
+                .storage_manager
+                .claim_general_reg(&mut self.buf, &allocation_symbol);
+
+            local_data_pointer(&mut self.buf, &mut self.relocs, bytes, ptr_reg);
+            ASM::add_reg64_reg64_imm32(&mut self.buf, ptr_reg, ptr_reg, alignment as i32);
+
+            self.store_list_literal(sym, allocation_symbol, elements.len(), element_alignment);
+            return;
+        }
+
         // load the total size of the data we want to store (excludes refcount)
         let data_bytes_symbol = self.debug_symbol("data_bytes");
         let data_bytes = element_width * elements.len() as u64;
@@ -3469,21 +3641,7 @@ impl<
             }
         }
 
-        // Setup list on stack.
-        self.storage_manager.with_tmp_general_reg(
-            &mut self.buf,
-            |storage_manager, buf, tmp_reg| {
-                let alignment = Ord::max(8, element_alignment) as u32;
-                let base_offset =
-                    storage_manager.claim_stack_area_with_alignment(*sym, 24, alignment);
-                ASM::mov_base32_reg64(buf, base_offset, ptr_reg);
-
-                ASM::mov_reg64_imm64(buf, tmp_reg, elements.len() as i64);
-                ASM::mov_base32_reg64(buf, base_offset + 8, tmp_reg);
-                ASM::mov_base32_reg64(buf, base_offset + 16, tmp_reg);
-            },
-        );
-        self.free_symbol(&allocation_symbol);
+        self.store_list_literal(sym, allocation_symbol, elements.len(), element_alignment);
     }
 
     fn create_struct(&mut self, sym: &Symbol, layout: &InLayout<'a>, fields: &'a [Symbol]) {
@@ -4566,8 +4724,8 @@ impl<
                         },
                     );
                 } else {
-                    // load large string (pretend it's a `List U8`). We should move this data into
-                    // the binary eventually because our RC algorithm won't free this value
+                    // load large string (pretend it's a `List U8`), which puts its bytes in the
+                    // data section when the target supports that
                     let elements: Vec<_> = x
                         .as_bytes()
                         .iter()
@@ -4648,7 +4806,21 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = match int_width {
+                    IntWidth::I128 => Layout::I128,
+                    IntWidth::U128 => Layout::U128,
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_BITWISE_AND[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[layout, layout],
+                    &layout,
+                );
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4668,7 +4840,21 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = match int_width {
+                    IntWidth::I128 => Layout::I128,
+                    IntWidth::U128 => Layout::U128,
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_BITWISE_OR[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[layout, layout],
+                    &layout,
+                );
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4688,7 +4874,21 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = match int_width {
+                    IntWidth::I128 => Layout::I128,
+                    IntWidth::U128 => Layout::U128,
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_BITWISE_XOR[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[layout, layout],
+                    &layout,
+                );
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4708,7 +4908,21 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = match int_width {
+                    IntWidth::I128 => Layout::I128,
+                    IntWidth::U128 => Layout::U128,
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SHIFT_LEFT_BY[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[layout, Layout::U8],
+                    &layout,
+                );
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4735,7 +4949,21 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                let layout = match int_width {
+                    IntWidth::I128 => Layout::I128,
+                    IntWidth::U128 => Layout::U128,
+                    _ => unreachable!(),
+                };
+
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SHIFT_RIGHT_BY[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[layout, Layout::U8],
+                    &layout,
+                );
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -5045,6 +5273,18 @@ impl<
                 let int_width = arg_layout.to_int_width();
                 self.build_int_to_float_cast(dst, src, int_width, FloatWidth::F32);
             }
+            LayoutRepr::Builtin(Builtin::Decimal) => {
+                let f64_symbol = self.debug_symbol("dec_as_f64");
+                self.num_to_f64(&f64_symbol, src, arg_layout);
+
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self
+                    .storage_manager
+                    .load_to_float_reg(&mut self.buf, &f64_symbol);
+                ASM::to_float_freg32_freg64(&mut self.buf, dst_reg, src_reg);
+
+                self.free_symbol(&f64_symbol);
+            }
             arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F32),
         }
     }
@@ -5070,6 +5310,15 @@ impl<
                 let int_width = arg_layout.to_int_width();
                 self.build_int_to_float_cast(dst, src, int_width, FloatWidth::F64);
             }
+            LayoutRepr::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_TO_F64.to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    &Layout::F64,
+                );
+            }
             arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F64),
         }
     }
@@ -5085,6 +5334,15 @@ impl<
                     &Layout::DEC,
                 );
             }
+            LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_FROM_FLOAT[float_width].to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    &Layout::DEC,
+                );
+            }
 
             arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::DEC),
         }
@@ -5617,6 +5875,82 @@ impl<
 
         self.load_literal(&symbol, &u64_layout, &width_literal);
     }
+
+    /// Set up a list on the stack, given a pointer to its first element.
+    fn store_list_literal(
+        &mut self,
+        sym: &Symbol,
+        allocation_symbol: Symbol,
+        len: usize,
+        element_alignment: u64,
+    ) {
+        let ptr_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &allocation_symbol);
+
+        self.storage_manager.with_tmp_general_reg(
+            &mut self.buf,
+            |storage_manager, buf, tmp_reg| {
+                let alignment = Ord::max(8, element_alignment) as u32;
+                let base_offset =
+                    storage_manager.claim_stack_area_with_alignment(*sym, 24, alignment);
+                ASM::mov_base32_reg64(buf, base_offset, ptr_reg);
+
+                ASM::mov_reg64_imm64(buf, tmp_reg, len as i64);
+                ASM::mov_base32_reg64(buf, base_offset + 8, tmp_reg);
+                ASM::mov_base32_reg64(buf, base_offset + 16, tmp_reg);
+            },
+        );
+        self.free_symbol(&allocation_symbol);
+    }
+
+    /// The bytes of a list literal whose elements are all literals, if it can be stored as
+    /// static data.
+    fn static_list_data(
+        &self,
+        element_layout: InLayout<'a>,
+        elements: &[ListLiteralElement<'a>],
+    ) -> Option<std::vec::Vec<u8>> {
+        let mut data = std::vec::Vec::new();
+
+        for element in elements {
+            let literal = match element {
+                ListLiteralElement::Literal(literal) => literal,
+                ListLiteralElement::Symbol(_) => return None,
+            };
+
+            match (literal, self.layout_interner.get_repr(element_layout)) {
+                (
+                    Literal::Int(bytes) | Literal::U128(bytes),
+                    LayoutRepr::Builtin(Builtin::Int(int_width)),
+                ) => {
+                    data.extend_from_slice(&bytes[..int_width.stack_size() as usize]);
+                }
+                (Literal::Byte(byte), LayoutRepr::Builtin(Builtin::Int(_))) => data.push(*byte),
+                (Literal::Bool(bool), LayoutRepr::Builtin(Builtin::Bool)) => data.push(*bool as u8),
+                (Literal::Float(float), LayoutRepr::Builtin(Builtin::Float(FloatWidth::F64))) => {
+                    data.extend_from_slice(&float.to_le_bytes());
+                }
+                (Literal::Float(float), LayoutRepr::Builtin(Builtin::Float(FloatWidth::F32))) => {
+                    data.extend_from_slice(&(*float as f32).to_le_bytes());
+                }
+                (Literal::Decimal(bytes), LayoutRepr::Builtin(Builtin::Decimal)) => {
+                    data.extend_from_slice(bytes);
+                }
+                // Large strings would need a pointer to their bytes, which needs a relocation.
+                (Literal::Str(string), LayoutRepr::Builtin(Builtin::Str)) if string.len() < 24 => {
+                    let mut bytes = [0; 24];
+                    bytes[..string.len()].copy_from_slice(string.as_bytes());
+                    bytes[23] = (string.len() as u8) | 0b1000_0000;
+
+                    data.extend_from_slice(&bytes);
+                }
+                _ => return None,
+            }
+        }
+
+        Some(data)
+    }
 }
 
 #[macro_export]
@@ -1,7 +1,7 @@
 #![allow(clippy::redundant_closure_call)]
 //|> clippy false positive: https://github.com/rust-lang/rust-clippy/issues/1553
 
-use crate::generic64::{storage::StorageManager, Assembler, CallConv, RegTrait};
+use crate::generic64::{storage::StorageManager, Assembler, CallConv, LocalDataPointer, RegTrait};
 use crate::{
     pointer_layouts, single_register_floats, single_register_int_builtins,
     single_register_integers, single_register_layouts, Relocation,
@@ -2101,6 +2101,8 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         X86_64Assembler::mov_reg64_mem64_offset32(buf, dst, dst, 0);
     }
 
+    const LOCAL_DATA_POINTER: Option<LocalDataPointer<X86_64GeneralReg>> = Some(local_data_pointer);
+
     #[inline(always)]
     fn imul_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
@@ -3477,6 +3479,22 @@ fn mov_reg64_imm64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i64) {
     }
 }
 
+/// Loads the address of `data`, which is stored in the data section of the object file.
+#[inline(always)]
+fn local_data_pointer(
+    buf: &mut Vec<'_, u8>,
+    relocs: &mut Vec<'_, Relocation>,
+    data: std::vec::Vec<u8>,
+    dst: X86_64GeneralReg,
+) {
+    lea_reg64(buf, dst);
+
+    relocs.push(Relocation::LocalData {
+        offset: buf.len() as u64 - 4,
+        data,
+    });
+}
+
 /// `LEA r64, m` -> Store effective address for m in register r64.
 #[inline(always)]
 fn lea_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg) {
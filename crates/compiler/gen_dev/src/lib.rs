@@ -276,9 +276,25 @@ impl<'a> LastSeenMap<'a> {
                 }
             }
 
-            Stmt::Dbg { .. } => todo!("dbg not implemented in the dev backend"),
-            Stmt::Expect { .. } => todo!("expect is not implemented in the dev backend"),
-            Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the dev backend"),
+            Stmt::Dbg {
+                symbol, remainder, ..
+            } => {
+                self.set_last_seen(*symbol, stmt);
+                self.scan_ast_help(remainder);
+            }
+            Stmt::Expect {
+                condition,
+                remainder,
+                ..
+            }
+            | Stmt::ExpectFx {
+                condition,
+                remainder,
+                ..
+            } => {
+                self.set_last_seen(*condition, stmt);
+                self.scan_ast_help(remainder);
+            }
 
             Stmt::Crash(msg, _crash_tag) => {
                 self.set_last_seen(*msg, stmt);
@@ -484,6 +500,9 @@ trait Backend<'a> {
     fn build_roc_longjmp(&mut self) -> &'a [u8];
     fn build_roc_panic(&mut self) -> (&'a [u8], Vec<'a, Relocation>);
 
+    /// A roc_dbg for tests, which has no host to print to
+    fn build_roc_dbg(&mut self) -> &'a [u8];
+
     /// build_proc creates a procedure and outputs it to the wrapped object writer.
     /// Returns the procedure bytes, its relocations, and the names of the refcounting functions it references.
     fn build_proc(
@@ -649,7 +668,51 @@ trait Backend<'a> {
                 self.free_symbols(stmt);
             }
             Stmt::Crash(msg, crash_tag) => self.roc_panic(*msg, *crash_tag),
-            x => todo!("the statement, {:?}", x),
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                remainder,
+                ..
+            } => {
+                self.roc_dbg(source_location, source, *symbol);
+                self.free_symbols(stmt);
+                self.build_stmt(layout_ids, remainder, ret_layout)
+            }
+            Stmt::Expect {
+                condition,
+                remainder,
+                ..
+            }
+            | Stmt::ExpectFx {
+                condition,
+                remainder,
+                ..
+            } => {
+                // The dev backend does not report the values looked up by a failing expect
+                // like the LLVM backend does. It just crashes, in the same way as `crash` would.
+                // Builds that ask for it get inline expects as `dbg` reports instead, see
+                // `LoadConfig::report_expects_with_dbg`.
+                let arena = self.env().arena;
+                let msg = self.debug_symbol("expect_failed_msg");
+                let failed = Stmt::Let(
+                    msg,
+                    Expr::Literal(Literal::Str("An expectation failed!")),
+                    Layout::STR,
+                    arena.alloc(Stmt::Crash(msg, CrashTag::User)),
+                );
+
+                self.load_literal_symbols(&[*condition]);
+                self.build_switch(
+                    layout_ids,
+                    condition,
+                    &Layout::BOOL,
+                    arena.alloc([(0, BranchInfo::None, failed)]),
+                    &(BranchInfo::None, *remainder),
+                    ret_layout,
+                );
+                self.free_symbols(stmt);
+            }
         }
     }
 
@@ -685,6 +748,38 @@ trait Backend<'a> {
         self.free_symbol(&Symbol::DEV_TMP2);
     }
 
+    fn roc_dbg(&mut self, source_location: &'a str, source: &'a str, msg: Symbol) {
+        let location = self.debug_symbol("dbg_location");
+        let source_str = self.debug_symbol("dbg_source");
+        self.load_literal(&location, &Layout::STR, &Literal::Str(source_location));
+        self.load_literal(&source_str, &Layout::STR, &Literal::Str(source));
+        self.load_literal_symbols(&[msg]);
+
+        // roc_dbg expects a `*RocStr` for each of its arguments, so we copy the strings
+        // to the stack and pass pointers to those copies
+        let location_ptr = self.debug_symbol("dbg_location_ptr");
+        let msg_ptr = self.debug_symbol("dbg_msg_ptr");
+        let source_ptr = self.debug_symbol("dbg_source_ptr");
+        self.build_alloca(location_ptr, Some(location), Layout::STR);
+        self.build_alloca(msg_ptr, Some(msg), Layout::STR);
+        self.build_alloca(source_ptr, Some(source_str), Layout::STR);
+
+        // TODO: at some point it will be a breaking change, but flip order to (loc, src, msg)
+        let arguments = &[location_ptr, msg_ptr, source_ptr];
+        self.build_fn_call(
+            &Symbol::DEV_TMP2,
+            String::from("roc_dbg"),
+            arguments,
+            &[Layout::U64, Layout::U64, Layout::U64],
+            &Layout::UNIT,
+        );
+
+        for sym in [location, source_str, location_ptr, msg_ptr, source_ptr] {
+            self.free_symbol(&sym);
+        }
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
     // build_switch generates a instructions for a switch statement.
     fn build_switch(
         &mut self,
@@ -1623,6 +1718,27 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrToScalars => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_SCALARS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrGraphemes => self.build_fn_call(
+                sym,
+                bitcode::STR_GRAPHEMES.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCountGraphemes => self.build_fn_call(
+                sym,
+                bitcode::STR_COUNT_GRAPHEMES.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::StrFromUtf8 => {
                 let update_mode = self.debug_symbol("update_mode");
 
@@ -1801,9 +1917,21 @@ trait Backend<'a> {
             LowLevel::SetLongJmpBuffer => {
                 self.build_data_pointer(sym, String::from("setlongjmp_buffer"));
             }
-            LowLevel::DictPseudoSeed => self.build_fn_call(
+            LowLevel::DictPseudoSeed => match self.env().mode {
+                AssemblyBackendMode::Test => {
+                    self.load_literal_i64(sym, bitcode::DICT_TEST_SEED as i64)
+                }
+                AssemblyBackendMode::Binary | AssemblyBackendMode::Repl => self.build_fn_call(
+                    sym,
+                    bitcode::UTILS_DICT_PSEUDO_SEED.to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+            },
+            LowLevel::RandomTestSeed => self.build_fn_call(
                 sym,
-                bitcode::UTILS_DICT_PSEUDO_SEED.to_string(),
+                bitcode::UTILS_TEST_SEED.to_string(),
                 args,
                 arg_layouts,
                 ret_layout,
@@ -319,7 +319,7 @@ fn generate_roc_panic<'a, B: Backend<'a>>(backend: &mut B, output: &mut Object)
     }
 }
 
-fn generate_roc_dbg<'a, B: Backend<'a>>(_backend: &mut B, output: &mut Object) {
+fn generate_roc_dbg<'a, B: Backend<'a>>(backend: &mut B, output: &mut Object) {
     let text_section = output.section_id(StandardSection::Text);
     let proc_symbol = Symbol {
         name: "roc_dbg".as_bytes().to_vec(),
@@ -331,9 +331,11 @@ fn generate_roc_dbg<'a, B: Backend<'a>>(_backend: &mut B, output: &mut Object) {
         section: SymbolSection::Section(text_section),
         flags: SymbolFlags::None,
     };
-    let _proc_id = output.add_symbol(proc_symbol);
-    // TODO: Actually generate an impl instead of just an empty symbol.
-    // At a minimum, it should just be a ret.
+    let proc_id = output.add_symbol(proc_symbol);
+    // Tests have no host to print to, so this just returns.
+    let proc_data = backend.build_roc_dbg();
+
+    output.add_symbol_data(proc_id, text_section, proc_data, 16);
 }
 
 fn generate_wrapper<'a, B: Backend<'a>>(
@@ -430,7 +432,10 @@ fn build_object<'a, B: Backend<'a>>(
     mut backend: B,
     mut output: Object<'a>,
 ) -> Object<'a> {
-    let data_section = output.section_id(StandardSection::Data);
+    let mut local_data = LocalData {
+        section: output.section_id(StandardSection::ReadOnlyData),
+        symbols: MutMap::default(),
+    };
 
     let arena = backend.env().arena;
 
@@ -623,14 +628,13 @@ fn build_object<'a, B: Backend<'a>>(
 
     // Build procedures from user code
     let mut relocations = bumpalo::vec![in arena];
-    for (fn_name, section_id, proc_id, proc) in procs {
+    for (_, section_id, proc_id, proc) in procs {
         build_proc(
             &mut output,
             &mut backend,
             &mut relocations,
             &mut layout_ids,
-            data_section,
-            fn_name,
+            &mut local_data,
             section_id,
             proc_id,
             proc,
@@ -714,14 +718,13 @@ fn build_object<'a, B: Backend<'a>>(
     }
 
     // Build helpers
-    for (fn_name, section_id, proc_id, proc) in helper_names_symbols_procs {
+    for (_, section_id, proc_id, proc) in helper_names_symbols_procs {
         build_proc(
             &mut output,
             &mut backend,
             &mut relocations,
             &mut layout_ids,
-            data_section,
-            fn_name,
+            &mut local_data,
             section_id,
             proc_id,
             proc,
@@ -921,40 +924,59 @@ fn build_proc_symbol<'a, B: Backend<'a>>(
     procs.push((fn_name, section_id, proc_id, proc));
 }
 
+/// Constant data referred to by the generated code, like float and string literals. Every
+/// distinct piece of data is stored once, in the read-only data section.
+struct LocalData {
+    section: SectionId,
+    symbols: MutMap<std::vec::Vec<u8>, SymbolId>,
+}
+
+impl LocalData {
+    fn symbol(&mut self, output: &mut Object, data: &[u8]) -> SymbolId {
+        if let Some(symbol_id) = self.symbols.get(data) {
+            return *symbol_id;
+        }
+
+        let data_symbol = write::Symbol {
+            name: format!("roc__local_data{}", self.symbols.len())
+                .as_bytes()
+                .to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Data,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: SymbolSection::Section(self.section),
+            flags: SymbolFlags::None,
+        };
+        let data_id = output.add_symbol(data_symbol);
+        // static list literals are aligned like their elements, which is at most 16
+        output.add_symbol_data(data_id, self.section, data, 16);
+
+        self.symbols.insert(data.to_vec(), data_id);
+
+        data_id
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_proc<'a, B: Backend<'a>>(
     output: &mut Object,
     backend: &mut B,
     relocations: &mut Vec<'a, (SectionId, object::write::Relocation)>,
     layout_ids: &mut LayoutIds<'a>,
-    data_section: SectionId,
-    fn_name: String,
+    local_data: &mut LocalData,
     section_id: SectionId,
     proc_id: SymbolId,
     proc: Proc<'a>,
 ) {
-    let mut local_data_index = 0;
     let target = backend.target();
     let (proc_data, relocs, rc_proc_names) = backend.build_proc(proc, layout_ids);
     let proc_offset = output.add_symbol_data(proc_id, section_id, &proc_data, 16);
     for reloc in relocs.iter() {
         let elfreloc = match reloc {
             Relocation::LocalData { offset, data } => {
-                let data_symbol = write::Symbol {
-                    name: format!("{fn_name}.data{local_data_index}")
-                        .as_bytes()
-                        .to_vec(),
-                    value: 0,
-                    size: 0,
-                    kind: SymbolKind::Data,
-                    scope: SymbolScope::Compilation,
-                    weak: false,
-                    section: SymbolSection::Section(data_section),
-                    flags: SymbolFlags::None,
-                };
-                local_data_index += 1;
-                let data_id = output.add_symbol(data_symbol);
-                output.add_symbol_data(data_id, data_section, data, 4);
+                let data_id = local_data.symbol(output, data);
                 write::Relocation {
                     offset: offset + proc_offset,
                     size: 32,
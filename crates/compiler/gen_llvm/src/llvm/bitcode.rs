@@ -23,6 +23,7 @@ use roc_mono::layout::{
 
 use super::build::{create_entry_block_alloca, BuilderExt};
 use super::convert::{zig_list_type, zig_str_type};
+use super::leak_check::set_site;
 use super::struct_::struct_from_fields;
 
 pub fn call_bitcode_fn<'ctx>(
@@ -103,6 +104,10 @@ fn call_bitcode_fn_help<'ctx>(
         .get_function(fn_name)
         .unwrap_or_else(|| panic!("Unrecognized builtin function: {fn_name:?} - if you're working on the Roc compiler, do you need to rebuild the bitcode? See compiler/builtins/bitcode/README.md"));
 
+    if env.leak_check {
+        set_site(env);
+    }
+
     let call = env
         .builder
         .new_build_call(fn_val, &arguments, "call_builtin");
@@ -1169,6 +1174,40 @@ pub(crate) fn call_list_bitcode_fn<'ctx>(
     }
 }
 
+/// Like [call_list_bitcode_fn], for a bitcode function that returns nothing.
+pub(crate) fn call_void_list_bitcode_fn<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    lists: &[StructValue<'ctx>],
+    other_arguments: &[BasicValueEnum<'ctx>],
+    fn_name: &str,
+) -> InstructionValue<'ctx> {
+    use bumpalo::collections::Vec;
+    use roc_target::Architecture::*;
+
+    let mut arguments: Vec<BasicValueEnum> =
+        Vec::with_capacity_in(other_arguments.len() + 2 * lists.len(), env.arena);
+
+    for list in lists {
+        match env.target.architecture() {
+            Aarch32 | X86_32 => {
+                let (a, b) = pass_list_or_string_to_zig_32bit(env, *list);
+                arguments.push(a.into());
+                arguments.push(b.into());
+            }
+            X86_64 | Aarch64 => {
+                arguments.push(pass_list_to_zig_64bit(env, (*list).into()).into());
+            }
+            Wasm32 => {
+                arguments.push(pass_list_to_zig_wasm(env, (*list).into()).into());
+            }
+        }
+    }
+
+    arguments.extend(other_arguments);
+
+    call_void_bitcode_fn(env, &arguments, fn_name)
+}
+
 pub(crate) fn call_bitcode_fn_with_record_arg<'ctx>(
     env: &Env<'_, 'ctx, '_>,
     arg: BasicValueEnum<'ctx>,
@@ -8,7 +8,7 @@ use crate::llvm::memcpy::build_memcpy;
 use crate::llvm::refcounting::{
     build_reset, decrement_refcount_layout, increment_refcount_layout, PointerToRefcount,
 };
-use crate::llvm::struct_::{struct_from_fields, RocStruct};
+use crate::llvm::struct_::{struct_from_fields, update_struct_in_place, RocStruct};
 use crate::llvm::{erased, fn_ptr};
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
@@ -27,7 +27,7 @@ use inkwell::types::{
     IntMathType, IntType, PointerMathType, StructType,
 };
 use inkwell::values::{
-    BasicMetadataValueEnum, BasicValue, BasicValueEnum, CallSiteValue, FloatMathValue,
+    ArrayValue, BasicMetadataValueEnum, BasicValue, BasicValueEnum, CallSiteValue, FloatMathValue,
     FunctionValue, InstructionOpcode, InstructionValue, IntMathValue, IntValue, PhiValue,
     PointerMathValue, PointerValue, StructValue,
 };
@@ -63,6 +63,7 @@ use super::intrinsics::{
 };
 use super::lowlevel::run_higher_order_low_level;
 use super::scope::Scope;
+use super::stack_check::{build_stack_check, recursive_procs, target_supports_stack_check};
 
 pub(crate) trait BuilderExt<'ctx> {
     fn new_build_struct_gep(
@@ -739,6 +740,10 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target: Target,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    /// Record which Roc function makes each allocation, see [`super::leak_check`]
+    pub leak_check: bool,
+    /// Make recursive functions check that they don't run out of stack, see [`super::stack_check`]
+    pub stack_check: bool,
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -1069,6 +1074,8 @@ pub fn module_from_builtins<'ctx>(
         "__roc_force_longjmp",
         "__roc_force_setjmp",
         "set_shared_buffer",
+        bitcode::UTILS_SET_DICT_SEED,
+        bitcode::UTILS_SET_TEST_SEED,
     ];
     for func in module.get_functions() {
         let has_definition = func.count_basic_blocks() > 0;
@@ -1411,6 +1418,48 @@ fn build_string_literal<'ctx>(
     }
 }
 
+/// A string literal as a constant, for use in the initializer of a global.
+fn const_str_literal<'ctx>(env: &Env<'_, 'ctx, '_>, str_literal: &str) -> StructValue<'ctx> {
+    let ptr_type = env.context.i8_type().ptr_type(AddressSpace::default());
+    let small_str_bytes = env.small_str_bytes() as usize;
+
+    let (ptr, len, cap) = if str_literal.len() < small_str_bytes {
+        let word_bytes = env.target.ptr_width() as usize;
+        let mut array = [0u8; 24];
+
+        array[..str_literal.len()].copy_from_slice(str_literal.as_bytes());
+        array[small_str_bytes - 1] = str_literal.len() as u8 | roc_std::RocStr::MASK;
+
+        let word = |index: usize| {
+            let mut bytes = [0u8; 8];
+            bytes[..word_bytes]
+                .copy_from_slice(&array[index * word_bytes..(index + 1) * word_bytes]);
+
+            env.ptr_int().const_int(u64::from_le_bytes(bytes), false)
+        };
+
+        (word(0).const_to_pointer(ptr_type), word(1), word(2))
+    } else {
+        let global = define_global_str_literal(env, str_literal);
+        let offset = env
+            .ptr_int()
+            .const_int(env.target.ptr_width() as u64, false);
+
+        // a pointer to the first actual data (skipping over the refcount)
+        let ptr = unsafe {
+            global
+                .as_pointer_value()
+                .const_cast(ptr_type)
+                .const_in_bounds_gep(env.context.i8_type(), &[offset])
+        };
+        let len = env.ptr_int().const_int(str_literal.len() as u64, false);
+
+        (ptr, len, len)
+    };
+
+    zig_str_type(env).const_named_struct(&[ptr.into(), len.into(), cap.into()])
+}
+
 fn const_str_alloca_ptr<'ctx>(
     env: &Env<'_, 'ctx, '_>,
     parent: FunctionValue<'ctx>,
@@ -1587,6 +1636,12 @@ pub(crate) fn build_exp_call<'a, 'ctx>(
             )
         }
 
+        CallType::Foreign { foreign_symbol, .. }
+            if roc_mono::coverage::is_coverage_symbol(foreign_symbol) =>
+        {
+            build_coverage_hit(env, foreign_symbol)
+        }
+
         CallType::Foreign {
             foreign_symbol,
             ret_layout,
@@ -2964,11 +3019,15 @@ fn list_literal<'a, 'ctx>(
     let list_length = elems.len();
     let list_length_intval = env.ptr_int().const_int(list_length as _, false);
 
-    // TODO re-enable, currently causes morphic segfaults because it tries to update
-    // constants in-place...
-    // if element_type.is_int_type() {
-    if false {
-        let element_type = element_type.into_int_type();
+    let is_number = element_type.is_int_type() || element_type.is_float_type();
+    let is_static_str = matches!(
+        layout_interner.get_repr(element_layout),
+        LayoutRepr::Builtin(Builtin::Str)
+    ) && elems
+        .iter()
+        .all(|elem| matches!(elem, ListLiteralElement::Literal(_)));
+
+    if is_number || is_static_str {
         let element_width = layout_interner.stack_size(element_layout);
         let size = list_length * element_width as usize;
         let alignment = layout_interner
@@ -2990,7 +3049,8 @@ fn list_literal<'a, 'ctx>(
             // Add zero bytes that represent the refcount
             //
             // - if all elements are const, then we store the whole list as a constant.
-            //      It then needs a refcount before the first element.
+            //      It then needs a refcount before the first element. A refcount of zero marks
+            //      the list as static: it is never freed, and never updated in place.
             // - but if the list is not all constants, then we will just copy the constant values,
             //      and we do not need that refcount at the start
             //
@@ -3003,6 +3063,9 @@ fn list_literal<'a, 'ctx>(
             // Copy the elements from the list literal into the array
             for (index, element) in elems.iter().enumerate() {
                 match element {
+                    ListLiteralElement::Literal(roc_mono::ir::Literal::Str(str_literal)) => {
+                        global_elements.push(const_str_literal(env, str_literal).into());
+                    }
                     ListLiteralElement::Literal(literal) => {
                         let val = build_exp_literal(
                             env,
@@ -3011,22 +3074,20 @@ fn list_literal<'a, 'ctx>(
                             element_layout,
                             literal,
                         );
-                        global_elements.push(val.into_int_value());
+                        global_elements.push(val);
                     }
                     ListLiteralElement::Symbol(symbol) => {
                         let val = scope.load_symbol(symbol);
 
-                        // here we'd like to furthermore check for intval.is_const().
-                        // if all elements are const for LLVM, we could make the array a constant.
-                        // BUT morphic does not know about this, and could allow us to modify that
-                        // array in-place. That would cause a segfault. So, we'll have to find
-                        // constants ourselves and cannot lean on LLVM here.
+                        // Even if LLVM knows this value to be constant, alias analysis does not,
+                        // and could allow us to modify the list in-place. So we only put lists
+                        // that consist of literals in the constants section.
 
                         is_all_constant = false;
 
                         runtime_evaluated_elements.push((index, val));
 
-                        global_elements.push(element_type.get_undef());
+                        global_elements.push(undef_number(element_type));
                     }
                 };
             }
@@ -3046,7 +3107,7 @@ fn list_literal<'a, 'ctx>(
             global.set_unnamed_addr(true);
             global.set_linkage(inkwell::module::Linkage::Private);
 
-            global.set_initializer(&element_type.const_array(const_elements));
+            global.set_initializer(&const_array(env, element_type, const_elements));
             global.as_pointer_value()
         };
 
@@ -3054,14 +3115,18 @@ fn list_literal<'a, 'ctx>(
             // all elements are constants, so we can use the memory in the constants section directly
             // here we make a pointer to the first actual element (skipping the 0 bytes that
             // represent the refcount)
-            let zero = env.ptr_int().const_zero();
             let offset = env.ptr_int().const_int(zero_elements as _, false);
+            let global = env.builder.new_build_pointer_cast(
+                global,
+                element_type.ptr_type(AddressSpace::default()),
+                "to_element_pointer",
+            );
 
             let ptr = unsafe {
                 env.builder.new_build_in_bounds_gep(
                     element_type,
                     global,
-                    &[zero, offset],
+                    &[offset],
                     "first_element_pointer",
                 )
             };
@@ -3123,6 +3188,37 @@ fn list_literal<'a, 'ctx>(
     }
 }
 
+/// An array of constants, which are all of the given type.
+fn const_array<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    element_type: BasicTypeEnum<'ctx>,
+    values: &[BasicValueEnum<'ctx>],
+) -> ArrayValue<'ctx> {
+    match element_type {
+        BasicTypeEnum::IntType(int_type) => {
+            let values = Vec::from_iter_in(values.iter().map(|v| v.into_int_value()), env.arena);
+            int_type.const_array(&values)
+        }
+        BasicTypeEnum::FloatType(float_type) => {
+            let values = Vec::from_iter_in(values.iter().map(|v| v.into_float_value()), env.arena);
+            float_type.const_array(&values)
+        }
+        BasicTypeEnum::StructType(struct_type) => {
+            let values = Vec::from_iter_in(values.iter().map(|v| v.into_struct_value()), env.arena);
+            struct_type.const_array(&values)
+        }
+        _ => internal_error!("no constant arrays of {element_type:?}"),
+    }
+}
+
+fn undef_number(element_type: BasicTypeEnum<'_>) -> BasicValueEnum<'_> {
+    match element_type {
+        BasicTypeEnum::IntType(int_type) => int_type.get_undef().into(),
+        BasicTypeEnum::FloatType(float_type) => float_type.get_undef().into(),
+        _ => internal_error!("{element_type:?} is not a number type"),
+    }
+}
+
 pub fn load_roc_value<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -3212,6 +3308,82 @@ pub fn store_roc_value<'a, 'ctx>(
     }
 }
 
+fn is_struct_passed_by_reference<'a>(
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> bool {
+    let repr = layout_interner.get_repr(layout);
+
+    matches!(repr, LayoutRepr::Struct(_)) && repr.is_passed_by_reference(layout_interner)
+}
+
+/// When a struct is rebuilt from the fields of a struct that this function owns and that isn't
+/// used afterwards, it can reuse the old struct's memory, so only the changed fields are stored.
+/// Returns the old struct and the fields to store.
+fn struct_update_in_place<'a, 'ctx>(
+    layout_interner: &STLayoutInterner<'a>,
+    scope: &Scope<'a, 'ctx>,
+    layout: InLayout<'a>,
+    fields: &[Symbol],
+    is_used_later: impl Fn(Symbol) -> bool,
+) -> Option<(Symbol, std::vec::Vec<(u64, Symbol)>)> {
+    if !is_struct_passed_by_reference(layout_interner, layout) {
+        return None;
+    }
+
+    let structure = fields.iter().find_map(|field| {
+        let (structure, _) = scope.struct_field(field)?;
+        let (_, structure_layout) = scope.load_symbol_and_layout(&structure);
+
+        (structure_layout == layout && scope.is_owned_struct(&structure)).then_some(structure)
+    })?;
+
+    if is_used_later(structure) {
+        return None;
+    }
+
+    let updates = fields
+        .iter()
+        .enumerate()
+        .filter(|(index, field)| scope.struct_field(field) != Some((structure, *index as u64)))
+        .map(|(index, field)| (index as u64, *field))
+        .collect();
+
+    Some((structure, updates))
+}
+
+/// Copy the struct arguments of a jump into the memory of the parameters they are passed to.
+/// An argument that lives in the memory of another parameter is moved out of the way first, so
+/// it is read before it is overwritten.
+fn copy_join_point_arguments<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    copies: &[(PointerValue<'ctx>, PointerValue<'ctx>, InLayout<'a>)],
+) {
+    let mut sources = Vec::with_capacity_in(copies.len(), env.arena);
+
+    for (_, source, layout) in copies {
+        let repr = layout_interner.get_repr(*layout);
+        let is_overwritten = copies.iter().any(|(home, _, _)| home == source);
+
+        if is_overwritten {
+            let basic_type = basic_type_from_layout(env, layout_interner, repr);
+            let temporary = entry_block_alloca_zerofill(env, basic_type, "joinpointarg_tmp");
+
+            build_memcpy(env, layout_interner, repr, temporary, *source);
+            sources.push(temporary);
+        } else {
+            sources.push(*source);
+        }
+    }
+
+    for ((home, _, layout), source) in copies.iter().zip(sources) {
+        let repr = layout_interner.get_repr(*layout);
+
+        build_memcpy(env, layout_interner, repr, *home, source);
+    }
+}
+
 pub(crate) fn build_exp_stmt<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -3237,22 +3409,60 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
 
             let mut stack = Vec::with_capacity_in(queue.len(), env.arena);
 
-            for (symbol, expr, layout) in queue {
+            for (index, (symbol, expr, layout)) in queue.iter().enumerate() {
                 debug_assert!(!matches!(
-                    layout_interner.get_repr(*layout),
+                    layout_interner.get_repr(**layout),
                     LayoutRepr::RecursivePointer(_)
                 ));
 
-                let val = build_exp_expr(
-                    env,
-                    layout_interner,
-                    layout_ids,
-                    func_spec_solutions,
-                    scope,
-                    parent,
-                    *layout,
-                    expr,
-                );
+                let is_used_later = |symbol: Symbol| {
+                    queue[index + 1..].iter().any(|(_, expr, _)| {
+                        let mut used = false;
+                        expr.for_each_symbol(|s| used |= *s == symbol);
+                        used
+                    }) || cont.uses_symbol(symbol)
+                };
+
+                let in_place = match expr {
+                    Expr::Struct(fields) => struct_update_in_place(
+                        layout_interner,
+                        scope,
+                        **layout,
+                        fields,
+                        is_used_later,
+                    ),
+                    _ => None,
+                };
+
+                let val = match in_place {
+                    Some((structure, updates)) => {
+                        let ptr = scope.load_symbol(&structure).into_pointer_value();
+
+                        update_struct_in_place(
+                            env,
+                            layout_interner,
+                            scope,
+                            layout_interner.get_repr(**layout),
+                            ptr,
+                            &updates,
+                        );
+
+                        // The old struct is gone now, its memory belongs to the new one.
+                        scope.remove_owned_struct(&structure);
+
+                        ptr.into()
+                    }
+                    None => build_exp_expr(
+                        env,
+                        layout_interner,
+                        layout_ids,
+                        func_spec_solutions,
+                        scope,
+                        parent,
+                        **layout,
+                        expr,
+                    ),
+                };
 
                 // Make a new scope which includes the binding we just encountered.
                 // This should be done *after* compiling the bound expr, since any
@@ -3261,8 +3471,18 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                 // access itself!
                 // scope = scope.clone();
 
-                scope.insert(*symbol, *layout, val);
-                stack.push(*symbol);
+                scope.insert(**symbol, **layout, val);
+                stack.push(**symbol);
+
+                match expr {
+                    Expr::Struct(_) if is_struct_passed_by_reference(layout_interner, **layout) => {
+                        scope.insert_owned_struct(**symbol)
+                    }
+                    Expr::StructAtIndex {
+                        index, structure, ..
+                    } => scope.insert_struct_field(**symbol, *structure, *index),
+                    _ => {}
+                }
             }
 
             let result = build_exp_stmt(
@@ -3360,8 +3580,39 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                 builder.position_at_end(current);
             }
 
+            // Struct parameters get memory of their own, so the body can update them in place.
+            let homes = parameters
+                .iter()
+                .map(|param| {
+                    is_struct_passed_by_reference(layout_interner, param.layout).then(|| {
+                        let basic_type = basic_type_from_layout(
+                            env,
+                            layout_interner,
+                            layout_interner.get_repr(param.layout),
+                        );
+
+                        create_entry_block_alloca(env, parent, basic_type, "joinpointarg_home")
+                    })
+                })
+                .collect();
+
             // store this join point
             scope.insert_join_point(*id, cont_block, joinpoint_args);
+            scope.insert_join_parameter_homes(*id, homes);
+
+            // The body may run many times, so structs it reads must not be updated in place
+            // before jumping to it.
+            let owned_structs = scope.take_owned_structs();
+            let mut used_in_body = MutSet::default();
+            continuation.for_each_symbol(|symbol| {
+                used_in_body.insert(*symbol);
+            });
+
+            for symbol in owned_structs.iter() {
+                if !used_in_body.contains(symbol) {
+                    scope.insert_owned_struct(*symbol);
+                }
+            }
 
             // construct the blocks that may jump to this join point
             build_exp_stmt(
@@ -3374,6 +3625,8 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                 remainder,
             );
 
+            scope.take_owned_structs();
+
             let phi_block = builder.get_insert_block().unwrap();
 
             // put the cont block at the back
@@ -3384,6 +3637,12 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                 .bind_parameters_to_join_point(*id, parameters.iter())
                 .expect("join point not found, but it was inserted above");
 
+            for param in parameters.iter() {
+                if is_struct_passed_by_reference(layout_interner, param.layout) {
+                    scope.insert_owned_struct(param.symbol);
+                }
+            }
+
             // put the continuation in
             let result = build_exp_stmt(
                 env,
@@ -3397,6 +3656,7 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
 
             // remove this join point again
             scope.remove_join_point(*id);
+            scope.restore_owned_structs(owned_structs);
 
             cont_block.move_after(phi_block).unwrap();
 
@@ -3408,14 +3668,34 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
             let context = env.context;
             let (cont_block, argument_phi_values) = scope.get_join_point(*join_point).unwrap();
 
+            let homes = scope
+                .get_join_parameter_homes(*join_point)
+                .unwrap_or_default();
+
             let current_block = builder.get_insert_block().unwrap();
+            let mut copies = Vec::new_in(env.arena);
 
-            for (phi_value, argument) in argument_phi_values.iter().zip(arguments.iter()) {
-                let (value, _) = scope.load_symbol_and_layout(argument);
+            for (index, (phi_value, argument)) in
+                argument_phi_values.iter().zip(arguments.iter()).enumerate()
+            {
+                let (value, layout) = scope.load_symbol_and_layout(argument);
+
+                let value = match homes.get(index).copied().flatten() {
+                    Some(home) => {
+                        if value.into_pointer_value() != home {
+                            copies.push((home, value.into_pointer_value(), layout));
+                        }
+
+                        home.into()
+                    }
+                    None => value,
+                };
 
                 phi_value.add_incoming(&[(&value, current_block)]);
             }
 
+            copy_join_point_arguments(env, layout_interner, &copies);
+
             builder.new_build_unconditional_branch(*cont_block);
 
             // This doesn't currently do anything
@@ -5771,6 +6051,13 @@ fn build_procedures_help<'a>(
         .mod_solutions(roc_alias_analysis::MOD_APP)
         .unwrap();
 
+    // Recursive procs check that they don't run out of stack.
+    let recursive_procs = if env.stack_check && target_supports_stack_check(env) {
+        recursive_procs(&procedures)
+    } else {
+        MutSet::default()
+    };
+
     // Add all the Proc headers to the module.
     // We have to do this in a separate pass first,
     // because their bodies may reference each other.
@@ -5802,6 +6089,7 @@ fn build_procedures_help<'a>(
                 scope.clone(),
                 &proc,
                 fn_val,
+                recursive_procs.contains(&proc.name.name()),
             );
 
             // call finalize() before any code generation/verification
@@ -6234,6 +6522,7 @@ fn build_proc<'a, 'ctx>(
     mut scope: Scope<'a, 'ctx>,
     proc: &roc_mono::ir::Proc<'a>,
     fn_val: FunctionValue<'ctx>,
+    check_stack: bool,
 ) {
     let args = proc.args;
     let context = &env.context;
@@ -6246,6 +6535,10 @@ fn build_proc<'a, 'ctx>(
 
     debug_info_init!(env, fn_val);
 
+    if check_stack {
+        build_stack_check(env, fn_val, proc.name.name().as_str(&env.interns));
+    }
+
     // Add args to scope
     for (arg_val, (layout, arg_symbol)) in fn_val.get_param_iter().zip(args) {
         arg_val.set_name(arg_symbol.as_str(&env.interns));
@@ -6782,6 +7075,34 @@ fn function_arguments<'a, 'ctx>(
     Vec::from_iter_in(it, env.arena)
 }
 
+/// Increment the global counter of a coverage point, creating it on first use.
+/// The counter is exported under the name of the coverage point, so the test runner can read it.
+fn build_coverage_hit<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    foreign: &roc_module::ident::ForeignSymbol,
+) -> BasicValueEnum<'ctx> {
+    let i64_typ = env.context.i64_type();
+
+    let global_name = foreign.as_str();
+    let global = env.module.get_global(global_name).unwrap_or_else(|| {
+        let global = env.module.add_global(i64_typ, None, global_name);
+        global.set_initializer(&i64_typ.const_zero());
+        global
+    });
+
+    let counter = global.as_pointer_value();
+    let count = env
+        .builder
+        .new_build_load(i64_typ, counter, "coverage_count")
+        .into_int_value();
+    let incremented =
+        env.builder
+            .new_build_int_add(count, i64_typ.const_int(1, false), "coverage_increment");
+    env.builder.new_build_store(counter, incremented);
+
+    env.context.struct_type(&[], false).const_zero().into()
+}
+
 fn build_foreign_symbol<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -1,4 +1,4 @@
-use crate::llvm::bitcode::build_dec_wrapper;
+use crate::llvm::bitcode::{build_dec_wrapper, build_inc_n_wrapper};
 use crate::llvm::build::{allocate_with_refcount_help, cast_basic_basic, Env, RocFunctionCall};
 use crate::llvm::convert::basic_type_from_layout;
 use inkwell::builder::Builder;
@@ -12,7 +12,7 @@ use roc_mono::layout::{
     Builtin, InLayout, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
 };
 
-use super::bitcode::{call_list_bitcode_fn, BitcodeReturns};
+use super::bitcode::{call_list_bitcode_fn, call_void_list_bitcode_fn, BitcodeReturns};
 use super::build::{
     create_entry_block_alloca, load_roc_value, store_roc_value, use_roc_value, BuilderExt,
 };
@@ -538,6 +538,55 @@ pub(crate) fn list_map<'a, 'ctx>(
     )
 }
 
+/// List.walk : List elem, state, (state, elem -> state) -> state
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn list_walk<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    layout_ids: &mut LayoutIds<'a>,
+    roc_function_call: RocFunctionCall<'ctx>,
+    list: BasicValueEnum<'ctx>,
+    initial_state: BasicValueEnum<'ctx>,
+    element_layout: InLayout<'a>,
+    state_layout: InLayout<'a>,
+) -> BasicValueEnum<'ctx> {
+    let parent = env
+        .builder
+        .get_insert_block()
+        .and_then(|b| b.get_parent())
+        .unwrap();
+
+    let state_repr = layout_interner.get_repr(state_layout);
+    let state_type = basic_type_from_layout(env, layout_interner, state_repr);
+
+    // the zig code alternates between these two, and leaves the final state in the first
+    let state_ptr = create_entry_block_alloca(env, parent, state_type, "walk_state");
+    let scratch_ptr = create_entry_block_alloca(env, parent, state_type, "walk_scratch");
+
+    store_roc_value(env, layout_interner, state_repr, state_ptr, initial_state);
+
+    let inc_element = build_inc_n_wrapper(env, layout_interner, layout_ids, element_layout);
+
+    call_void_list_bitcode_fn(
+        env,
+        &[list.into_struct_value()],
+        &[
+            roc_function_call.caller.into(),
+            pass_as_opaque(env, roc_function_call.data),
+            roc_function_call.inc_n_data.into(),
+            roc_function_call.data_is_owned.into(),
+            inc_element.as_global_value().as_pointer_value().into(),
+            layout_width(env, layout_interner, element_layout),
+            pass_as_opaque(env, state_ptr),
+            pass_as_opaque(env, scratch_ptr),
+            layout_width(env, layout_interner, state_layout),
+        ],
+        bitcode::LIST_WALK,
+    );
+
+    load_roc_value(env, layout_interner, state_repr, state_ptr, "walk_result")
+}
+
 pub(crate) fn list_map2<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
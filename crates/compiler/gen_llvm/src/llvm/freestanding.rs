@@ -0,0 +1,84 @@
+//! Building objects for hosts without a C standard library, like microcontrollers.
+//!
+//! A freestanding object only calls into the host through the `roc_*` functions below, so it can
+//! be linked into a host that has no libc at all. Allocation goes through `roc_alloc`,
+//! `roc_realloc` and `roc_dealloc`, crashes go through `roc_panic`, and `dbg` goes through
+//! `roc_dbg`; the generated code never calls `malloc`, `abort` or similar functions itself.
+//!
+//! The host must define:
+//!
+//! - `void *roc_alloc(size_t size, uint32_t alignment)`
+//! - `void *roc_realloc(void *ptr, size_t new_size, size_t old_size, uint32_t alignment)`
+//! - `void roc_dealloc(void *ptr, uint32_t alignment)`
+//! - `void roc_panic(RocStr *message, uint32_t tag_id)`, which must not return
+//! - `void roc_dbg(RocStr *location, RocStr *message, RocStr *source)`
+//!
+//! LLVM may also emit calls to `memcpy`, `memmove`, `memset` and `memcmp`. Toolchains for
+//! embedded targets provide these in their runtime library (e.g. compiler-rt or newlib-nano),
+//! and the host can define them itself when they don't.
+//!
+//! The stack limit that protects recursive functions is a plain global in these objects, rather
+//! than a thread-local one, because thread-local storage needs support from the C runtime. How
+//! much stack these hosts have varies too much for a default, so the check starts out turned off.
+//! A host turns it on by setting the stack budget before calling into Roc:
+//!
+//! - `extern size_t roc__stack_budget;`, the number of bytes recursive functions may use
+
+use inkwell::module::Module;
+
+use super::stack_check::{STACK_BUDGET_GLOBAL, STACK_LIMIT_GLOBAL};
+
+/// The functions that the host has to define.
+pub const HOST_SYMBOLS: [&str; 5] = [
+    "roc_alloc",
+    "roc_realloc",
+    "roc_dealloc",
+    "roc_panic",
+    "roc_dbg",
+];
+
+/// The functions that LLVM may call, which come from the toolchain's runtime library.
+pub const RUNTIME_SYMBOLS: [&str; 4] = ["memcpy", "memmove", "memset", "memcmp"];
+
+/// Makes the module freestanding, after it was optimized. Fails with the names of the functions
+/// the module calls that neither the host nor the runtime library provides.
+pub fn prepare(module: &Module) -> Result<(), Vec<String>> {
+    if let Some(stack_limit) = module.get_global(STACK_LIMIT_GLOBAL) {
+        stack_limit.set_thread_local(false);
+    }
+
+    if let Some(stack_budget) = module.get_global(STACK_BUDGET_GLOBAL) {
+        if let Some(budget) = stack_budget.get_initializer() {
+            stack_budget.set_initializer(&budget.into_int_value().get_type().const_zero());
+        }
+    }
+
+    let mut missing = Vec::new();
+
+    for function in module.get_functions() {
+        let is_declaration = function.count_basic_blocks() == 0;
+        let is_used = function
+            .as_global_value()
+            .as_pointer_value()
+            .get_first_use()
+            .is_some();
+        let name = function.get_name().to_string_lossy();
+
+        if is_declaration
+            && is_used
+            && !name.starts_with("llvm.")
+            && !HOST_SYMBOLS.contains(&name.as_ref())
+            && !RUNTIME_SYMBOLS.contains(&name.as_ref())
+        {
+            missing.push(name.into_owned());
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        missing.sort();
+
+        Err(missing)
+    }
+}
@@ -0,0 +1,117 @@
+//! Leak checking, for validating the refcounting that the compiler inserts.
+//!
+//! In a program built with `--leak-check`, the builtins remember every allocation they make
+//! through `roc_alloc`, and forget it again when it goes through `roc_dealloc`. All allocation
+//! and freeing of Roc values goes through the builtins, so whatever is still remembered when the
+//! program exits was leaked. The builtins then print those allocations, grouped by the Roc
+//! function that made them.
+//!
+//! The builtins can't know which Roc function is calling them, so before each call into the
+//! builtins the generated code stores the caller's name in a global that the builtins read when
+//! they allocate. Because the report should run when the program exits, whatever the host does,
+//! it is registered as a global destructor of the object.
+
+use inkwell::module::Linkage;
+use inkwell::AddressSpace;
+use roc_builtins::bitcode;
+
+use super::build::{BuilderExt, Env};
+
+/// Prefix of the globals that hold the name of a function, for the builtins to report.
+const SITE_NAME_PREFIX: &str = "roc_leak_check_site.";
+
+/// Turns leak checking on or off for the whole module, depending on `env.leak_check`. Must be
+/// called once, after all procedures were built.
+///
+/// When it's off, the builtins' checks of the flag are constant, so LLVM removes them.
+pub fn configure(env: &Env<'_, '_, '_>) {
+    let enabled = match env.module.get_global(bitcode::UTILS_LEAK_CHECK_ENABLED) {
+        Some(global) => global,
+        None => return,
+    };
+
+    let flag_type = enabled
+        .get_initializer()
+        .expect("the leak check flag is defined in the builtins")
+        .into_int_value()
+        .get_type();
+
+    enabled.set_initializer(&flag_type.const_int(env.leak_check as u64, false));
+    enabled.set_constant(true);
+    enabled.set_linkage(Linkage::Internal);
+
+    if let Some(site) = env.module.get_global(bitcode::UTILS_LEAK_CHECK_SITE) {
+        site.set_linkage(Linkage::Internal);
+    }
+
+    if env.leak_check {
+        add_report_destructor(env);
+    }
+}
+
+/// Points the builtins at the name of the function we're currently building, so the allocations
+/// the next call into the builtins makes are attributed to it.
+pub(crate) fn set_site(env: &Env<'_, '_, '_>) {
+    let site = match env.module.get_global(bitcode::UTILS_LEAK_CHECK_SITE) {
+        Some(global) => global,
+        None => return,
+    };
+
+    let function = match env
+        .builder
+        .get_insert_block()
+        .and_then(|block| block.get_parent())
+    {
+        Some(function) => function,
+        None => return,
+    };
+
+    let function_name = function.get_name().to_string_lossy();
+    let global_name = format!("{SITE_NAME_PREFIX}{function_name}");
+
+    // One global per function, so the builtins can group allocations by the address of the name
+    let name = match env.module.get_global(&global_name) {
+        Some(global) => global,
+        None => {
+            let bytes = env.context.const_string(function_name.as_bytes(), true);
+            let global = env.module.add_global(bytes.get_type(), None, &global_name);
+
+            global.set_initializer(&bytes);
+            global.set_constant(true);
+            global.set_linkage(Linkage::Private);
+
+            global
+        }
+    };
+
+    env.builder
+        .new_build_store(site.as_pointer_value(), name.as_pointer_value());
+}
+
+/// Makes the builtins' leak report run when the program exits, through `llvm.global_dtors`.
+fn add_report_destructor(env: &Env<'_, '_, '_>) {
+    let report = env
+        .module
+        .get_function(bitcode::UTILS_LEAK_CHECK_REPORT)
+        .expect("the leak check report is defined in the builtins");
+
+    let i32_type = env.context.i32_type();
+    let ptr_type = env.context.i8_type().ptr_type(AddressSpace::default());
+    let entry_type = env
+        .context
+        .struct_type(&[i32_type.into(), ptr_type.into(), ptr_type.into()], false);
+
+    // The default priority, like a destructor written in C
+    let entry = entry_type.const_named_struct(&[
+        i32_type.const_int(65535, false).into(),
+        report.as_global_value().as_pointer_value().into(),
+        ptr_type.const_null().into(),
+    ]);
+
+    let destructors = env
+        .module
+        .add_global(entry_type.array_type(1), None, "llvm.global_dtors");
+
+    destructors.set_linkage(Linkage::Appending);
+    destructors.set_initializer(&entry_type.const_array(&[entry]));
+}
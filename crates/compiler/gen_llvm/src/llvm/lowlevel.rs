@@ -38,7 +38,8 @@ use crate::llvm::{
         layout_width, list_append_unsafe, list_concat, list_drop_at, list_get_unsafe,
         list_len_usize, list_map, list_map2, list_map3, list_map4, list_prepend,
         list_release_excess_capacity, list_replace_unsafe, list_reserve, list_sort_with,
-        list_sublist, list_swap, list_symbol_to_c_abi, list_with_capacity, pass_update_mode,
+        list_sublist, list_swap, list_symbol_to_c_abi, list_walk, list_with_capacity,
+        pass_update_mode,
     },
     compare::{generic_eq, generic_neq},
     convert::{
@@ -57,12 +58,15 @@ use crate::llvm::{
     refcounting::PointerToRefcount,
 };
 
-use super::{build::Env, convert::zig_dec_type};
 use super::{
     build::{throw_internal_exception, use_roc_value, FAST_CALL_CONV},
     convert::zig_with_overflow_roc_dec,
     scope::Scope,
 };
+use super::{
+    build::{Env, LlvmBackendMode},
+    convert::zig_dec_type,
+};
 
 pub(crate) fn run_low_level<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
@@ -464,6 +468,42 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 bitcode::STR_TO_UTF8,
             )
         }
+        StrToScalars => {
+            // Str.toScalars : Str -> List U32
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::List,
+                bitcode::STR_TO_SCALARS,
+            )
+        }
+        StrGraphemes => {
+            // Str.graphemes : Str -> List Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::List,
+                bitcode::STR_GRAPHEMES,
+            )
+        }
+        StrCountGraphemes => {
+            // Str.countGraphemes : Str -> U64
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_COUNT_GRAPHEMES,
+            )
+        }
         StrRepeat => {
             // Str.repeat : Str, U64 -> Str
             arguments!(string, count);
@@ -1375,7 +1415,20 @@ pub(crate) fn run_low_level<'a, 'ctx>(
         DictPseudoSeed => {
             // Dict.pseudoSeed : {} -> u64
 
-            call_bitcode_fn(env, &[], bitcode::UTILS_DICT_PSEUDO_SEED)
+            match env.mode {
+                LlvmBackendMode::GenTest
+                | LlvmBackendMode::WasmGenTest
+                | LlvmBackendMode::CliTest => env
+                    .context
+                    .i64_type()
+                    .const_int(bitcode::DICT_TEST_SEED, false)
+                    .into(),
+                _ => call_bitcode_fn(env, &[], bitcode::UTILS_DICT_PSEUDO_SEED),
+            }
+        }
+        RandomTestSeed => {
+            // Random.testSeed : {} -> U64
+            call_bitcode_fn(env, &[], bitcode::UTILS_TEST_SEED)
         }
 
         SetJmp | LongJmp | SetLongJmpBuffer => unreachable!("only inserted in dev backend codegen"),
@@ -2173,7 +2226,7 @@ fn change_with_overflow_to_roc_type<'a, 'ctx>(
 
 fn build_dec_unary_op<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
-    _layout_interner: &STLayoutInterner<'a>,
+    layout_interner: &STLayoutInterner<'a>,
     _parent: FunctionValue<'ctx>,
     arg: BasicValueEnum<'ctx>,
     return_layout: InLayout<'a>,
@@ -2196,6 +2249,22 @@ fn build_dec_unary_op<'a, 'ctx>(
         NumFloor => dec_unary_op(env, &bitcode::DEC_FLOOR[int_width()], arg),
         NumCeiling => dec_unary_op(env, &bitcode::DEC_CEILING[int_width()], arg),
 
+        NumToFrac => {
+            let f64_value = dec_unary_op(env, bitcode::DEC_TO_F64, arg);
+
+            match layout_interner.get_repr(return_layout) {
+                LayoutRepr::Builtin(Builtin::Float(FloatWidth::F64)) => f64_value,
+                LayoutRepr::Builtin(Builtin::Float(FloatWidth::F32)) => env.builder.new_build_cast(
+                    InstructionOpcode::FPTrunc,
+                    f64_value,
+                    env.context.f32_type(),
+                    "f64_to_f32",
+                ),
+                LayoutRepr::Builtin(Builtin::Decimal) => arg,
+                _ => internal_error!("Layout for returning is not Frac : {:?}", return_layout),
+            }
+        }
+
         _ => {
             unreachable!("Unrecognized dec unary operation: {:?}", op);
         }
@@ -2653,7 +2722,14 @@ fn build_float_unary_op<'a, 'ctx>(
         NumToFrac => {
             let return_width = match layout_interner.get_repr(layout) {
                 LayoutRepr::Builtin(Builtin::Float(return_width)) => return_width,
-                _ => internal_error!("Layout for returning is not Float : {:?}", layout),
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    return call_bitcode_fn(
+                        env,
+                        &[arg.into()],
+                        &bitcode::DEC_FROM_FLOAT[float_width],
+                    );
+                }
+                _ => internal_error!("Layout for returning is not Frac : {:?}", layout),
             };
             match (float_width, return_width) {
                 (FloatWidth::F32, FloatWidth::F32) => arg.into(),
@@ -3018,6 +3094,43 @@ pub(crate) fn run_higher_order_low_level<'a, 'ctx>(
                 _ => unreachable!("invalid list layout"),
             }
         }
+        ListWalk { xs, state } => {
+            // List.walk : List elem, state, (state, elem -> state) -> state
+            let (list, list_layout) = scope.load_symbol_and_layout(xs);
+            let initial_state = scope.load_symbol(state);
+
+            let (function, closure, closure_layout) = function_details!();
+
+            match layout_interner.get_repr(list_layout) {
+                LayoutRepr::Builtin(Builtin::List(element_layout)) => {
+                    let argument_layouts = &[result_layout, element_layout];
+
+                    let roc_function_call = roc_function_call(
+                        env,
+                        layout_interner,
+                        layout_ids,
+                        function,
+                        closure,
+                        closure_layout,
+                        function_owns_closure_data,
+                        argument_layouts,
+                        result_layout,
+                    );
+
+                    list_walk(
+                        env,
+                        layout_interner,
+                        layout_ids,
+                        roc_function_call,
+                        list,
+                        initial_state,
+                        element_layout,
+                        result_layout,
+                    )
+                }
+                _ => unreachable!("invalid list layout"),
+            }
+        }
     }
 }
 
@@ -6,7 +6,9 @@ pub mod compare;
 pub mod convert;
 mod expect;
 pub mod externs;
+pub mod freestanding;
 mod intrinsics;
+pub mod leak_check;
 mod lowlevel;
 pub mod refcounting;
 
@@ -15,4 +17,5 @@ mod erased;
 mod fn_ptr;
 mod memcpy;
 mod scope;
+mod stack_check;
 mod struct_;
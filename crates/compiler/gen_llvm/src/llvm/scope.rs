@@ -1,8 +1,8 @@
 use inkwell::{
     basic_block::BasicBlock,
-    values::{BasicValueEnum, FunctionValue, PhiValue},
+    values::{BasicValueEnum, FunctionValue, PhiValue, PointerValue},
 };
-use roc_collections::ImMap;
+use roc_collections::{ImMap, ImSet};
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_mono::{
     ir::{JoinPointId, Param, ProcLayout},
@@ -14,6 +14,13 @@ pub(crate) struct Scope<'a, 'ctx> {
     symbols: ImMap<Symbol, (InLayout<'a>, BasicValueEnum<'ctx>)>,
     top_level_thunks: ImMap<Symbol, (ProcLayout<'a>, FunctionValue<'ctx>)>,
     join_points: ImMap<JoinPointId, (BasicBlock<'ctx>, Vec<PhiValue<'ctx>>)>,
+    /// Structs passed by reference whose memory belongs to the current function, so they can be
+    /// updated in place once nothing else uses them.
+    owned_structs: ImSet<Symbol>,
+    /// Symbols defined by a `StructAtIndex`, with the struct and index they were loaded from
+    struct_fields: ImMap<Symbol, (Symbol, u64)>,
+    /// For each join point, the memory its struct parameters are copied into
+    join_parameter_homes: ImMap<JoinPointId, Vec<Option<PointerValue<'ctx>>>>,
 }
 
 #[derive(Debug)]
@@ -51,6 +58,36 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
 
     pub fn remove(&mut self, symbol: &Symbol) {
         self.symbols.remove(symbol);
+        self.owned_structs.remove(symbol);
+        self.struct_fields.remove(symbol);
+    }
+
+    pub fn insert_owned_struct(&mut self, symbol: Symbol) {
+        self.owned_structs.insert(symbol);
+    }
+
+    pub fn remove_owned_struct(&mut self, symbol: &Symbol) {
+        self.owned_structs.remove(symbol);
+    }
+
+    pub fn is_owned_struct(&self, symbol: &Symbol) -> bool {
+        self.owned_structs.contains(symbol)
+    }
+
+    pub fn take_owned_structs(&mut self) -> ImSet<Symbol> {
+        std::mem::take(&mut self.owned_structs)
+    }
+
+    pub fn restore_owned_structs(&mut self, owned_structs: ImSet<Symbol>) {
+        self.owned_structs = owned_structs;
+    }
+
+    pub fn insert_struct_field(&mut self, symbol: Symbol, structure: Symbol, index: u64) {
+        self.struct_fields.insert(symbol, (structure, index));
+    }
+
+    pub fn struct_field(&self, symbol: &Symbol) -> Option<(Symbol, u64)> {
+        self.struct_fields.get(symbol).copied()
     }
 
     pub fn retain_top_level_thunks_for_module(&mut self, module_id: ModuleId) {
@@ -69,6 +106,24 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
 
     pub fn remove_join_point(&mut self, join_point_id: JoinPointId) {
         self.join_points.remove(&join_point_id);
+        self.join_parameter_homes.remove(&join_point_id);
+    }
+
+    pub fn insert_join_parameter_homes(
+        &mut self,
+        join_point_id: JoinPointId,
+        homes: Vec<Option<PointerValue<'ctx>>>,
+    ) {
+        self.join_parameter_homes.insert(join_point_id, homes);
+    }
+
+    pub fn get_join_parameter_homes(
+        &self,
+        join_point_id: JoinPointId,
+    ) -> Option<&[Option<PointerValue<'ctx>>]> {
+        self.join_parameter_homes
+            .get(&join_point_id)
+            .map(|homes| homes.as_slice())
     }
 
     pub fn get_join_point(
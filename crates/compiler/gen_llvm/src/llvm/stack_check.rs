@@ -0,0 +1,253 @@
+//! Protection against running out of stack in recursive Roc functions.
+//!
+//! Functions that may call themselves (directly or through other functions) check at their start
+//! whether the stack is about to run out. If it is, they call `roc_panic` with a
+//! "deep recursion in <function>" message, rather than running into the end of the stack and
+//! crashing with a segmentation fault.
+//!
+//! The generated code doesn't know where the stack ends, so the first recursive function that
+//! runs on a thread puts the limit `roc__stack_budget` bytes below its own frame. That global
+//! starts out with a default for the backend mode. A host whose threads have a different amount of
+//! stack can set it before calling into Roc, and setting it to 0 turns the check off.
+//!
+//! The surgical linker can't add thread-local or writable globals to the host, so apps linked
+//! with it are built without the check, see [`super::build::Env::stack_check`].
+
+use inkwell::{values::FunctionValue, IntPredicate};
+use roc_collections::{MutMap, MutSet, ReferenceMatrix};
+use roc_module::symbol::Symbol;
+use roc_mono::ir::{Call, CallType, Expr, Proc, ProcLayout, Stmt};
+use roc_target::Architecture;
+
+use super::build::{throw_internal_exception, BuilderExt, Env, LlvmBackendMode};
+use super::intrinsics::LLVM_FRAME_ADDRESS;
+
+pub(crate) const STACK_LIMIT_GLOBAL: &str = "roc__stack_limit";
+pub(crate) const STACK_BUDGET_GLOBAL: &str = "roc__stack_budget";
+
+/// How many bytes of stack recursive functions may use, unless the host says otherwise. Test
+/// threads get 2MiB of stack, while a host usually calls into Roc from its main thread, which has
+/// at least 8MiB on common platforms.
+fn default_stack_budget(mode: LlvmBackendMode) -> u64 {
+    match mode {
+        LlvmBackendMode::GenTest | LlvmBackendMode::WasmGenTest | LlvmBackendMode::CliTest => {
+            1024 * 1024
+        }
+        LlvmBackendMode::Binary | LlvmBackendMode::BinaryDev | LlvmBackendMode::BinaryGlue => {
+            7 * 1024 * 1024
+        }
+    }
+}
+
+/// Whether the target lets us look at the stack pointer. Wasm keeps its own call stack, which
+/// the engine protects, so there is nothing to check there.
+pub(crate) fn target_supports_stack_check(env: &Env<'_, '_, '_>) -> bool {
+    env.target.architecture() != Architecture::Wasm32
+}
+
+/// The procedures that are part of a cycle of calls, and so could use an unbounded amount of
+/// stack. Calls in tail position were already turned into loops, so they don't count.
+pub(crate) fn recursive_procs<'a>(
+    procedures: &MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> MutSet<Symbol> {
+    let mut symbols = Vec::new();
+    let mut indices = MutMap::default();
+
+    for (symbol, _) in procedures.keys() {
+        indices.entry(*symbol).or_insert_with(|| {
+            symbols.push(*symbol);
+            symbols.len() - 1
+        });
+    }
+
+    let mut matrix = ReferenceMatrix::new(indices.len());
+
+    for ((symbol, _), proc) in procedures.iter() {
+        let row = indices[symbol];
+
+        for callee in callees(&proc.body) {
+            if let Some(col) = indices.get(&callee) {
+                matrix.set_row_col(row, *col, true);
+            }
+        }
+    }
+
+    let mut recursive = MutSet::default();
+
+    for (group, _) in matrix.strongly_connected_components_all().groups() {
+        let mut nodes = group.iter_ones();
+
+        let is_recursive = match (nodes.next(), nodes.next()) {
+            (Some(node), None) => matrix.get_row_col(node, node),
+            (Some(_), Some(_)) => true,
+            (None, _) => false,
+        };
+
+        if is_recursive {
+            recursive.extend(group.iter_ones().map(|index| symbols[index]));
+        }
+    }
+
+    recursive
+}
+
+fn callees(body: &Stmt<'_>) -> Vec<Symbol> {
+    let mut callees = Vec::new();
+    let mut stack = vec![body];
+
+    while let Some(stmt) = stack.pop() {
+        match stmt {
+            Stmt::Let(_, expr, _, cont) => {
+                if let Expr::Call(Call { call_type, .. }) = expr {
+                    match call_type {
+                        CallType::ByName { name, .. } => callees.push(name.name()),
+                        CallType::HigherOrder(higher_order) => {
+                            callees.push(higher_order.passed_function.name.name())
+                        }
+                        CallType::ByPointer { .. }
+                        | CallType::Foreign { .. }
+                        | CallType::LowLevel { .. } => {}
+                    }
+                }
+
+                stack.push(cont);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Stmt::Refcounting(_, cont) => stack.push(cont),
+            Stmt::Expect { remainder, .. }
+            | Stmt::ExpectFx { remainder, .. }
+            | Stmt::Dbg { remainder, .. } => stack.push(remainder),
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => {}
+        }
+    }
+
+    callees
+}
+
+/// Panic if the stack has grown past its limit. Must be called at the start of `parent`.
+pub(crate) fn build_stack_check<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    parent: FunctionValue<'ctx>,
+    function_name: &str,
+) {
+    let builder = env.builder;
+    let context = env.context;
+    let ptr_int = env.ptr_int();
+
+    let limit_ptr = match env.module.get_global(STACK_LIMIT_GLOBAL) {
+        Some(global) => global,
+        None => {
+            let global = env.module.add_global(ptr_int, None, STACK_LIMIT_GLOBAL);
+            global.set_initializer(&ptr_int.const_zero());
+            global.set_thread_local(true);
+            global.set_linkage(inkwell::module::Linkage::Internal);
+            global
+        }
+    }
+    .as_pointer_value();
+
+    let budget_ptr = match env.module.get_global(STACK_BUDGET_GLOBAL) {
+        Some(global) => global,
+        None => {
+            // Not internal and not constant, so the host can change it.
+            let global = env.module.add_global(ptr_int, None, STACK_BUDGET_GLOBAL);
+            global.set_initializer(&ptr_int.const_int(default_stack_budget(env.mode), false));
+            global.set_linkage(inkwell::module::Linkage::External);
+            global
+        }
+    }
+    .as_pointer_value();
+
+    let frame_address = env.call_intrinsic(
+        LLVM_FRAME_ADDRESS,
+        &[context.i32_type().const_zero().into()],
+    );
+    let frame_address =
+        builder.new_build_ptr_to_int(frame_address.into_pointer_value(), ptr_int, "frame_address");
+
+    let limit = builder
+        .new_build_load(ptr_int, limit_ptr, "stack_limit")
+        .into_int_value();
+
+    let set_limit_block = context.append_basic_block(parent, "set_stack_limit");
+    let check_block = context.append_basic_block(parent, "check_stack_limit");
+    let overflow_block = context.append_basic_block(parent, "stack_overflow");
+    let cont_block = context.append_basic_block(parent, "stack_ok");
+
+    let is_unset = builder.new_build_int_compare(
+        IntPredicate::EQ,
+        limit,
+        ptr_int.const_zero(),
+        "stack_limit_is_unset",
+    );
+    builder.new_build_conditional_branch(is_unset, set_limit_block, check_block);
+
+    {
+        builder.position_at_end(set_limit_block);
+
+        let budget = builder
+            .new_build_load(ptr_int, budget_ptr, "stack_budget")
+            .into_int_value();
+
+        // A budget of 0 turns the check off, and so does one that is bigger than the whole
+        // address range below this frame. Either way the limit stays unset, at 0.
+        let is_disabled = builder.new_build_int_compare(
+            IntPredicate::EQ,
+            budget,
+            ptr_int.const_zero(),
+            "stack_check_is_disabled",
+        );
+        let would_underflow = builder.new_build_int_compare(
+            IntPredicate::ULT,
+            frame_address,
+            budget,
+            "stack_budget_is_too_big",
+        );
+        let no_limit = builder.new_build_or(is_disabled, would_underflow, "no_stack_limit");
+
+        let new_limit = builder.new_build_int_sub(frame_address, budget, "new_stack_limit");
+        let new_limit = builder.new_build_select(
+            no_limit,
+            ptr_int.const_zero(),
+            new_limit,
+            "clamped_stack_limit",
+        );
+        builder.new_build_store(limit_ptr, new_limit);
+
+        builder.new_build_unconditional_branch(cont_block);
+    }
+
+    {
+        builder.position_at_end(check_block);
+
+        let is_overflow = builder.new_build_int_compare(
+            IntPredicate::ULT,
+            frame_address,
+            limit,
+            "stack_is_exhausted",
+        );
+        builder.new_build_conditional_branch(is_overflow, overflow_block, cont_block);
+    }
+
+    {
+        builder.position_at_end(overflow_block);
+
+        let message = format!("deep recursion in {function_name}");
+        throw_internal_exception(env, parent, &message);
+    }
+
+    builder.position_at_end(cont_block);
+}
@@ -109,6 +109,36 @@ impl<'ctx> RocStruct<'ctx> {
     }
 }
 
+/// Store new values for some of the fields of a struct passed by reference, leaving the others
+/// as they are. This turns the struct's memory into the memory of the updated struct.
+pub(crate) fn update_struct_in_place<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    scope: &Scope<'a, 'ctx>,
+    struct_layout: LayoutRepr<'a>,
+    ptr: PointerValue<'ctx>,
+    updates: &[(u64, Symbol)],
+) {
+    let struct_type =
+        basic_type_from_layout(env, layout_interner, struct_layout).into_struct_type();
+
+    for (index, symbol) in updates {
+        let (field_value, field_layout) = scope.load_symbol_and_layout(symbol);
+        let field_repr = layout_interner.get_repr(field_layout);
+
+        if field_repr.is_dropped_because_empty() {
+            continue;
+        }
+
+        let name = format!("struct_field_update_{index}");
+        let dst = env
+            .builder
+            .new_build_struct_gep(struct_type, ptr, *index as u32, &name);
+
+        store_roc_value(env, layout_interner, field_repr, dst, field_value);
+    }
+}
+
 fn index_struct_value<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
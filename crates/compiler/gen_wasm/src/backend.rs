@@ -65,6 +65,8 @@ pub struct WasmBackend<'a, 'r> {
     host_lookup: Vec<'a, (&'a str, u32)>,
     helper_proc_gen: CodeGenHelp<'a>,
     can_relocate_heap: bool,
+    /// Addresses of the string literals already stored in the data section
+    string_literals: MutMap<&'a [u8], u32>,
 
     // Function-level data
     pub code_builder: CodeBuilder<'a>,
@@ -135,6 +137,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             host_lookup,
             helper_proc_gen,
             can_relocate_heap: has_heap_base && has_heap_end,
+            string_literals: MutMap::default(),
 
             // Function-level data
             block_depth: 0,
@@ -726,9 +729,23 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 _ => self.stmt_refcounting(modify, following),
             },
 
-            Stmt::Dbg { .. } => todo!("dbg is not implemented in the wasm backend"),
-            Stmt::Expect { .. } => todo!("expect is not implemented in the wasm backend"),
-            Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the wasm backend"),
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                remainder,
+                ..
+            } => self.stmt_dbg(source_location, source, *symbol, remainder),
+            Stmt::Expect {
+                condition,
+                remainder,
+                ..
+            }
+            | Stmt::ExpectFx {
+                condition,
+                remainder,
+                ..
+            } => self.stmt_expect(*condition, remainder),
 
             Stmt::Crash(sym, tag) => self.stmt_crash(*sym, *tag),
         }
@@ -1016,24 +1033,69 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
     }
 
     pub fn stmt_internal_error(&mut self, msg: &'a str) {
-        let msg_sym = self.create_symbol("panic_str");
-        let msg_storage = self.storage.allocate_var(
+        let msg_sym = self.stack_string_literal("panic_str", msg);
+
+        self.stmt_crash(msg_sym, CrashTag::Roc);
+    }
+
+    /// Store a string literal as a RocStr on the stack
+    fn stack_string_literal(&mut self, debug_name: &str, string: &'a str) -> Symbol {
+        let sym = self.create_symbol(debug_name);
+        let storage = self.storage.allocate_var(
             self.layout_interner,
             Layout::STR,
-            msg_sym,
+            sym,
             StoredVarKind::Variable,
         );
 
-        // Store the message as a RocStr on the stack
-        let (local_id, offset) = match msg_storage {
+        let (local_id, offset) = match storage {
             StoredValue::StackMemory { location, .. } => {
                 location.local_and_offset(self.storage.stack_frame_pointer)
             }
             _ => internal_error!("String must always have stack memory"),
         };
-        self.expr_string_literal(msg, local_id, offset);
+        self.expr_string_literal(string, local_id, offset);
 
-        self.stmt_crash(msg_sym, CrashTag::Roc);
+        sym
+    }
+
+    fn stmt_dbg(
+        &mut self,
+        source_location: &'a str,
+        source: &'a str,
+        message: Symbol,
+        remainder: &'a Stmt<'a>,
+    ) {
+        let location = self.stack_string_literal("dbg_location", source_location);
+        let source = self.stack_string_literal("dbg_source", source);
+
+        // Strings live in stack memory, so this loads pointers to them
+        self.storage
+            .load_symbols(&mut self.code_builder, &[location, message, source]);
+        self.call_host_fn_after_loading_args("roc_dbg");
+
+        self.stmt(remainder);
+    }
+
+    /// A failed expectation crashes, in the same way as `crash` would.
+    /// Unlike the LLVM backend, we don't report the values that the condition looked up.
+    /// Builds that ask for it get inline expects as `dbg` reports instead, see
+    /// `LoadConfig::report_expects_with_dbg`.
+    fn stmt_expect(&mut self, condition: Symbol, remainder: &'a Stmt<'a>) {
+        let msg = self.create_symbol("expect_failed_msg");
+        let failed = Stmt::Let(
+            msg,
+            Expr::Literal(Literal::Str("An expectation failed!")),
+            Layout::STR,
+            self.env.arena.alloc(Stmt::Crash(msg, CrashTag::User)),
+        );
+
+        self.stmt_switch(
+            condition,
+            Layout::BOOL,
+            self.env.arena.alloc([(0, BranchInfo::None, failed)]),
+            &(BranchInfo::None, remainder),
+        );
     }
 
     pub fn stmt_crash(&mut self, msg: Symbol, tag: CrashTag) {
@@ -1250,9 +1312,13 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         };
     }
 
-    /// Create a string constant in the module data section
+    /// Create a string constant in the module data section, unless an identical one exists
     /// Return the data we need for code gen: linker symbol index and memory address
     fn store_bytes_in_data_section(&mut self, bytes: &[u8]) -> u32 {
+        if let Some(elements_addr) = self.string_literals.get(bytes) {
+            return *elements_addr;
+        }
+
         // Place the segment at a 4-byte aligned offset
         let segment_addr = round_up_to_alignment!(self.module.data.end_addr, PTR_SIZE);
         let elements_addr = segment_addr + PTR_SIZE;
@@ -1271,6 +1337,9 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.module.data.append_segment(segment);
 
+        let bytes = self.env.arena.alloc_slice_copy(bytes);
+        self.string_literals.insert(bytes, elements_addr);
+
         elements_addr
     }
 
@@ -248,6 +248,9 @@ impl<'a> LowLevelCall<'a> {
             StrTrimStart => self.load_args_and_call_zig(backend, bitcode::STR_TRIM_START),
             StrTrimEnd => self.load_args_and_call_zig(backend, bitcode::STR_TRIM_END),
             StrToUtf8 => self.load_args_and_call_zig(backend, bitcode::STR_TO_UTF8),
+            StrToScalars => self.load_args_and_call_zig(backend, bitcode::STR_TO_SCALARS),
+            StrGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEMES),
+            StrCountGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_COUNT_GRAPHEMES),
             StrReserve => self.load_args_and_call_zig(backend, bitcode::STR_RESERVE),
             StrReleaseExcessCapacity => {
                 self.load_args_and_call_zig(backend, bitcode::STR_RELEASE_EXCESS_CAPACITY)
@@ -787,8 +790,7 @@ impl<'a> LowLevelCall<'a> {
             NumAddWrap => match self.ret_layout_raw {
                 LayoutRepr::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_WRAP_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -878,8 +880,7 @@ impl<'a> LowLevelCall<'a> {
             NumSubWrap => match self.ret_layout_raw {
                 LayoutRepr::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_WRAP_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -1529,11 +1530,16 @@ impl<'a> LowLevelCall<'a> {
                     (F32, I64) => backend.code_builder.f32_convert_s_i64(),
                     (F32, F32) => {}
                     (F32, F64) => backend.code_builder.f32_demote_f64(),
+                    (F32, Decimal) => {
+                        backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64);
+                        backend.code_builder.f32_demote_f64();
+                    }
 
                     (F64, I32) => backend.code_builder.f64_convert_s_i32(),
                     (F64, I64) => backend.code_builder.f64_convert_s_i64(),
                     (F64, F32) => backend.code_builder.f64_promote_f32(),
                     (F64, F64) => {}
+                    (F64, Decimal) => backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64),
 
                     (Decimal, I32) => {
                         let int_width = match arg_is_signed {
@@ -1730,7 +1736,11 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_and(),
                     I64 => backend.code_builder.i64_and(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_BITWISE_AND[width]),
+                        _ => panic_ret_type(),
+                    },
                     _ => panic_ret_type(),
                 }
             }
@@ -1739,7 +1749,11 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_xor(),
                     I64 => backend.code_builder.i64_xor(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_BITWISE_XOR[width]),
+                        _ => panic_ret_type(),
+                    },
                     _ => panic_ret_type(),
                 }
             }
@@ -1748,23 +1762,38 @@ impl<'a> LowLevelCall<'a> {
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => backend.code_builder.i32_or(),
                     I64 => backend.code_builder.i64_or(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => {
+                            backend.call_host_fn_after_loading_args(&bitcode::NUM_BITWISE_OR[width])
+                        }
+                        _ => panic_ret_type(),
+                    },
                     _ => panic_ret_type(),
                 }
             }
             NumShiftLeftBy => {
                 let num = self.arguments[0];
                 let bits = self.arguments[1];
-                backend
-                    .storage
-                    .load_symbols(&mut backend.code_builder, &[num, bits]);
                 match CodeGenNumType::from(self.ret_layout) {
-                    I32 => backend.code_builder.i32_shl(),
+                    I32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i32_shl();
+                    }
                     I64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
                         backend.code_builder.i64_extend_u_i32();
                         backend.code_builder.i64_shl();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => {
+                            self.load_args_and_call_zig(backend, &bitcode::NUM_SHIFT_LEFT_BY[width])
+                        }
+                        _ => panic_ret_type(),
+                    },
                     _ => panic_ret_type(),
                 }
             }
@@ -1811,7 +1840,11 @@ impl<'a> LowLevelCall<'a> {
                         backend.code_builder.i64_extend_u_i32();
                         backend.code_builder.i64_shr_s();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => self
+                            .load_args_and_call_zig(backend, &bitcode::NUM_SHIFT_RIGHT_BY[width]),
+                        _ => panic_ret_type(),
+                    },
                     _ => panic_ret_type(),
                 }
             }
@@ -1941,6 +1974,10 @@ impl<'a> LowLevelCall<'a> {
                 match (ret_type, arg_type) {
                     (F32, F32) => {}
                     (F32, F64) => backend.code_builder.f32_demote_f64(),
+                    (F32, Decimal) => {
+                        backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64);
+                        backend.code_builder.f32_demote_f64();
+                    }
                     (F32, I32) => {
                         if arg_signed {
                             backend.code_builder.f32_convert_s_i32()
@@ -1956,6 +1993,7 @@ impl<'a> LowLevelCall<'a> {
                         }
                     }
                     (F64, F64) => {}
+                    (F64, Decimal) => backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64),
                     (F64, I32) => {
                         if arg_signed {
                             backend.code_builder.f64_convert_s_i32()
@@ -2101,6 +2139,7 @@ impl<'a> LowLevelCall<'a> {
                 StoredValue::StackMemory { .. } => { /* do nothing */ }
             },
             DictPseudoSeed => self.load_args_and_call_zig(backend, bitcode::UTILS_DICT_PSEUDO_SEED),
+            RandomTestSeed => self.load_args_and_call_zig(backend, bitcode::UTILS_TEST_SEED),
 
             SetJmp | LongJmp | SetLongJmpBuffer => {
                 unreachable!("only inserted in dev backend codegen")
@@ -2578,9 +2617,11 @@ pub fn call_higher_order_lowlevel<'a>(
             .unwrap();
         match op {
             ListSortWith { .. } => ProcSource::HigherOrderCompare(passed_proc_index),
-            ListMap { .. } | ListMap2 { .. } | ListMap3 { .. } | ListMap4 { .. } => {
-                ProcSource::HigherOrderMapper(passed_proc_index)
-            }
+            ListMap { .. }
+            | ListMap2 { .. }
+            | ListMap3 { .. }
+            | ListMap4 { .. }
+            | ListWalk { .. } => ProcSource::HigherOrderMapper(passed_proc_index),
         }
     };
     let wrapper_sym = backend.create_symbol(&format!("#wrap#{fn_name:?}"));
@@ -2735,6 +2776,88 @@ pub fn call_higher_order_lowlevel<'a>(
 
             backend.call_host_fn_after_loading_args(bitcode::LIST_SORT_WITH);
         }
+
+        ListWalk { xs, state } => {
+            let elem_layout = unwrap_list_elem_layout(
+                backend
+                    .layout_interner
+                    .get_repr(backend.storage.symbol_layouts[xs]),
+            );
+            let element_width = backend.layout_interner.stack_size(elem_layout);
+            let (state_width, state_alignment) = backend
+                .layout_interner
+                .stack_size_and_alignment(*result_layout);
+
+            // The inc function will be passed a pointer to the element within the list, not the element itself!
+            // Here we wrap the layout in a Struct to ensure we get the right code gen
+            let elem_ptr = backend
+                .layout_interner
+                .insert_direct_no_semantic(LayoutRepr::Struct(
+                    backend.env.arena.alloc([elem_layout]),
+                ));
+            let inc_elem_fn = backend.get_refcount_fn_index(elem_ptr, HelperOp::Inc);
+            let inc_elem_fn_ptr = backend.get_fn_ptr(inc_elem_fn);
+
+            // The Zig code alternates between these two, and leaves the final state in the first
+            let (frame_ptr, state_offset) = backend
+                .storage
+                .allocate_anonymous_stack_memory(state_width, state_alignment);
+            let (_, scratch_offset) = backend
+                .storage
+                .allocate_anonymous_stack_memory(state_width, state_alignment);
+
+            backend.storage.copy_value_to_memory(
+                &mut backend.code_builder,
+                frame_ptr,
+                state_offset,
+                *state,
+            );
+
+            let cb = &mut backend.code_builder;
+
+            // list: RocList,        i32
+            // caller: Caller2,      i32
+            // data: Opaque,         i32
+            // inc_n_data: IncN,     i32
+            // data_is_owned: bool,  i32
+            // inc_n_element: IncN,  i32
+            // element_width: usize, i32
+            // state: ?[*]u8,        i32
+            // scratch: ?[*]u8,      i32
+            // state_width: usize,   i32
+
+            backend.storage.load_symbols(cb, &[*xs]);
+            cb.i32_const(wrapper_fn_ptr);
+            if closure_data_exists {
+                backend
+                    .storage
+                    .load_symbols(cb, &[wrapped_captured_environment]);
+            } else {
+                // load_symbols assumes that a zero-size arg should be eliminated in code gen,
+                // but that's a specialization that our Zig code doesn't have! Pass a null pointer.
+                cb.i32_const(0);
+            }
+            cb.i32_const(inc_fn_ptr);
+            cb.i32_const(*owns_captured_environment as i32);
+            cb.i32_const(inc_elem_fn_ptr);
+            cb.i32_const(element_width as i32);
+            cb.get_local(frame_ptr);
+            cb.i32_const(state_offset as i32);
+            cb.i32_add();
+            cb.get_local(frame_ptr);
+            cb.i32_const(scratch_offset as i32);
+            cb.i32_add();
+            cb.i32_const(state_width as i32);
+
+            backend.call_host_fn_after_loading_args(bitcode::LIST_WALK);
+
+            backend.storage.copy_value_from_memory(
+                &mut backend.code_builder,
+                return_sym,
+                AddressValue::NotLoaded(frame_ptr),
+                state_offset,
+            );
+        }
     }
 }
 
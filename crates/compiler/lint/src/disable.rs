@@ -0,0 +1,57 @@
+//! Comments that turn rules off.
+//!
+//! `# lint-disable redundant-bool-if` on a line of its own turns the rule off for the whole
+//! module, and `# lint-disable-next-line redundant-bool-if` only for the line after it. Either
+//! can list several rules, separated by commas or spaces.
+use roc_collections::MutMap;
+
+use crate::Rule;
+
+const DISABLE: &str = "lint-disable";
+pub(crate) const DISABLE_NEXT_LINE: &str = "lint-disable-next-line";
+
+#[derive(Debug, Default)]
+pub(crate) struct Disabled {
+    module: Vec<Rule>,
+    /// By zero-based line number.
+    lines: MutMap<u32, Vec<Rule>>,
+}
+
+impl Disabled {
+    pub fn from_source(src: &str) -> Self {
+        let mut disabled = Disabled::default();
+
+        for (line_number, line) in src.lines().enumerate() {
+            let comment = match line.trim_start().strip_prefix('#') {
+                Some(comment) => comment.trim_start(),
+                None => continue,
+            };
+
+            if let Some(rules) = comment.strip_prefix(DISABLE_NEXT_LINE) {
+                disabled
+                    .lines
+                    .entry(line_number as u32 + 1)
+                    .or_default()
+                    .extend(parse_rules(rules));
+            } else if let Some(rules) = comment.strip_prefix(DISABLE) {
+                disabled.module.extend(parse_rules(rules));
+            }
+        }
+
+        disabled
+    }
+
+    pub fn is_disabled(&self, rule: Rule, line: u32) -> bool {
+        self.module.contains(&rule)
+            || self
+                .lines
+                .get(&line)
+                .is_some_and(|rules| rules.contains(&rule))
+    }
+}
+
+fn parse_rules(rules: &str) -> impl Iterator<Item = Rule> + '_ {
+    rules
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(Rule::from_name)
+}
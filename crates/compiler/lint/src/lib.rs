@@ -0,0 +1,260 @@
+//! Finds code that works but could be simpler, like `if x then Bool.true else Bool.false`.
+//!
+//! [lint] runs every [Rule] over the modules of a [Checked] program that are in the root
+//! module's directory, leaving out packages and builtins. Each [Lint] has a rendered report and,
+//! when there's a single obvious way to change the code, a [Fix].
+//!
+//! Rules can be turned off with comments, for a whole module or for the next line only; see
+//! [disable].
+use roc_compiler::{Checked, RenderTarget};
+use roc_region::all::{LineColumnRegion, LineInfo};
+use roc_reporting::report::{RocDocAllocator, DEFAULT_PALETTE};
+use std::path::{Path, PathBuf};
+
+pub mod disable;
+mod report;
+mod rules;
+
+use disable::Disabled;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// A function that recurses through a list with an accumulator, which `List.walk` does.
+    PreferListWalk,
+    /// `if x then Bool.true else Bool.false`, which is just `x`.
+    RedundantBoolIf,
+    /// A default for an optional record field that's never used.
+    UnusedOptionalDefault,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 3] = [
+        Rule::PreferListWalk,
+        Rule::RedundantBoolIf,
+        Rule::UnusedOptionalDefault,
+    ];
+
+    /// The name that disable comments use.
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::PreferListWalk => "prefer-list-walk",
+            Rule::RedundantBoolIf => "redundant-bool-if",
+            Rule::UnusedOptionalDefault => "unused-optional-default",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Rule> {
+        Rule::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+}
+
+/// Code that one of the rules matched.
+#[derive(Clone, Debug)]
+pub struct Lint {
+    pub rule: Rule,
+    /// The file the code is in.
+    pub path: PathBuf,
+    pub region: LineColumnRegion,
+    /// The full report, rendered as asked for.
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// A change to the source that resolves a [Lint].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    /// The code to replace.
+    pub region: LineColumnRegion,
+    pub replacement: String,
+}
+
+/// Runs every rule over the modules next to the root module, in order of path and then of
+/// where they are in the module.
+pub fn lint(checked: &Checked, render: RenderTarget) -> Vec<Lint> {
+    let module = checked.loaded_module();
+    let root_dir = module
+        .sources
+        .get(&module.module_id)
+        .and_then(|(path, _)| path.parent())
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut modules: Vec<_> = module
+        .sources
+        .iter()
+        .filter(|(_, (path, _))| path.starts_with(&root_dir))
+        .collect();
+
+    modules.sort_by(|(_, (a, _)), (_, (b, _))| a.cmp(b));
+
+    let mut lints = Vec::new();
+
+    for (home, (path, src)) in modules {
+        let decls = match module.declarations_by_id.get(home) {
+            Some(decls) => decls,
+            None => continue,
+        };
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let line_info = LineInfo::new(src);
+        let alloc = RocDocAllocator::new(&src_lines, *home, &module.interns);
+        let disabled = Disabled::from_source(src);
+
+        for finding in rules::find(decls) {
+            let rule = finding.rule();
+            let region = line_info.convert_region(finding.region());
+
+            if disabled.is_disabled(rule, region.start.line) {
+                continue;
+            }
+
+            let fix = report::fix(src, &finding);
+            let report = report::finding_report(
+                &alloc,
+                &line_info,
+                path.clone(),
+                &finding,
+                fix.as_ref().map(|(_, replacement)| replacement.as_str()),
+            );
+            let mut message = String::new();
+
+            report.render(render, &mut message, &alloc, &DEFAULT_PALETTE);
+
+            lints.push(Lint {
+                rule,
+                path: path.clone(),
+                region,
+                message,
+                fix: fix.map(|(region, replacement)| Fix {
+                    region: line_info.convert_region(region),
+                    replacement,
+                }),
+            });
+        }
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use roc_compiler::{check_str, Options, Threading};
+
+    fn lints(source: &str) -> Vec<Lint> {
+        let dir = tempfile::tempdir().unwrap();
+        let options = Options {
+            render: RenderTarget::Generic,
+            threading: Threading::Single,
+            offline: true,
+            ..Options::default()
+        };
+        let checked = check_str(dir.path().join("Test.roc"), source, &options).unwrap();
+
+        assert!(!checked.has_errors(), "{:#?}", checked.diagnostics);
+
+        lint(&checked, RenderTarget::Generic)
+    }
+
+    fn rules(lints: &[Lint]) -> Vec<(Rule, u32)> {
+        lints
+            .iter()
+            .map(|lint| (lint.rule, lint.region.start.line))
+            .collect()
+    }
+
+    #[test]
+    fn redundant_bool_if() {
+        let lints = lints(indoc!(
+            r"
+            module [isBig, isSmall]
+
+            isBig = \n -> if n > 10 then Bool.true else Bool.false
+
+            isSmall = \n ->
+                if Num.isZero n then Bool.false else Bool.true
+            "
+        ));
+
+        assert_eq!(
+            rules(&lints),
+            [(Rule::RedundantBoolIf, 2), (Rule::RedundantBoolIf, 5)]
+        );
+
+        let fixes: Vec<&str> = lints
+            .iter()
+            .map(|lint| lint.fix.as_ref().unwrap().replacement.as_str())
+            .collect();
+
+        assert_eq!(fixes, ["n > 10", "!(Num.isZero n)"]);
+        assert!(lints[0].message.contains("REDUNDANT IF"));
+    }
+
+    #[test]
+    fn prefer_list_walk() {
+        let lints = lints(indoc!(
+            r"
+            module [sum]
+
+            sum : List I64, I64 -> I64
+            sum = \list, total ->
+                when list is
+                    [] -> total
+                    [first, .. as rest] -> sum rest (total + first)
+            "
+        ));
+
+        assert_eq!(rules(&lints), [(Rule::PreferListWalk, 3)]);
+        assert!(lints[0].fix.is_none());
+        assert!(lints[0].message.contains("List.walk"));
+    }
+
+    #[test]
+    fn unused_optional_default() {
+        let lints = lints(indoc!(
+            r#"
+            module [greet]
+
+            greet = \{ name, greeting ? "Hello", punctuation ? "!" } ->
+                Str.concat name punctuation
+            "#
+        ));
+
+        assert_eq!(rules(&lints), [(Rule::UnusedOptionalDefault, 2)]);
+        assert!(lints[0].message.contains("greeting"));
+    }
+
+    #[test]
+    fn disable_next_line() {
+        let lints = lints(indoc!(
+            r"
+            module [isBig, isSmall]
+
+            # lint-disable-next-line redundant-bool-if
+            isBig = \n -> if n > 10 then Bool.true else Bool.false
+
+            # lint-disable-next-line prefer-list-walk
+            isSmall = \n -> if n < 10 then Bool.true else Bool.false
+            "
+        ));
+
+        assert_eq!(rules(&lints), [(Rule::RedundantBoolIf, 6)]);
+    }
+
+    #[test]
+    fn disable_module() {
+        let lints = lints(indoc!(
+            r"
+            module [isBig, isSmall]
+
+            # lint-disable redundant-bool-if, prefer-list-walk
+
+            isBig = \n -> if n > 10 then Bool.true else Bool.false
+
+            isSmall = \n -> if n < 10 then Bool.true else Bool.false
+            "
+        ));
+
+        assert!(lints.is_empty());
+    }
+}
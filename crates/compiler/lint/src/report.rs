@@ -0,0 +1,133 @@
+//! The reports and fixes for what the rules find.
+use roc_module::symbol::Symbol;
+use roc_problem::Severity;
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::report::{Annotation, Report, RocDocAllocator, RocDocBuilder};
+use std::path::PathBuf;
+use ven_pretty::DocAllocator;
+
+use crate::disable::DISABLE_NEXT_LINE;
+use crate::rules::Finding;
+use crate::Rule;
+
+pub(crate) fn finding_report<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    finding: &Finding,
+    fix: Option<&str>,
+) -> Report<'b> {
+    let region = alloc.region(lines.convert_region(finding.region()));
+
+    let (title, doc) = match finding {
+        Finding::RedundantBoolIf { negated, .. } => {
+            let replacement = fix.unwrap_or_default().to_string();
+
+            (
+                "REDUNDANT IF",
+                alloc.stack([
+                    alloc.concat([
+                        alloc.reflow("This "),
+                        alloc.keyword("if"),
+                        alloc.reflow(" evaluates to the same "),
+                        alloc.type_str("Bool"),
+                        alloc.reflow(" as its condition"),
+                        alloc.reflow(if *negated { ", negated:" } else { ":" }),
+                    ]),
+                    region,
+                    alloc.reflow("It can be replaced with just:"),
+                    alloc.string(replacement).indent(4),
+                ]),
+            )
+        }
+        Finding::PreferListWalk {
+            function,
+            list,
+            state,
+            ..
+        } => (
+            "MANUAL LIST WALK",
+            alloc.stack([
+                alloc.concat([
+                    alloc.symbol_unqualified(*function),
+                    alloc.reflow(" recurses through "),
+                    alloc.symbol_unqualified(*list),
+                    alloc.reflow(" one element at a time, carrying "),
+                    alloc.symbol_unqualified(*state),
+                    alloc.reflow(" along:"),
+                ]),
+                region,
+                alloc.tip().append(alloc.concat([
+                    alloc.symbol_qualified(Symbol::LIST_WALK),
+                    alloc.reflow(" does the same thing, and it reads more plainly, like "),
+                    alloc.parser_suggestion("List.walk list state \\state, elem -> …"),
+                    alloc.text("."),
+                ])),
+            ]),
+        ),
+        Finding::UnusedOptionalDefault { label, .. } => (
+            "UNUSED DEFAULT",
+            alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The "),
+                    alloc.string(label.to_string()),
+                    alloc.reflow(" field has a default value, but "),
+                    alloc.string(label.to_string()),
+                    alloc.reflow(" is never used:"),
+                ]),
+                region,
+                alloc.reflow(
+                    "Nothing reads the default, so either use the field or leave it out of this pattern.",
+                ),
+            ]),
+        ),
+    };
+
+    Report {
+        title: title.to_string(),
+        filename,
+        doc: alloc.stack([doc, disable_hint(alloc, finding.rule())]),
+        severity: Severity::Warning,
+    }
+}
+
+fn disable_hint<'b>(alloc: &'b RocDocAllocator<'b>, rule: Rule) -> RocDocBuilder<'b> {
+    alloc.concat([
+        alloc.reflow("To allow this here, put "),
+        alloc
+            .string(format!("# {DISABLE_NEXT_LINE} {}", rule.name()))
+            .annotate(Annotation::ParserSuggestion),
+        alloc.reflow(" on the line above."),
+    ])
+}
+
+/// The source that replaces the finding's region, for the findings that have a single obvious
+/// fix.
+pub(crate) fn fix(src: &str, finding: &Finding) -> Option<(Region, String)> {
+    match finding {
+        Finding::RedundantBoolIf {
+            region,
+            condition,
+            negated,
+        } => {
+            let condition = slice(src, *condition);
+            let replacement = if !negated {
+                condition.to_string()
+            } else if condition
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+            {
+                format!("!{condition}")
+            } else {
+                format!("!({condition})")
+            };
+
+            Some((*region, replacement))
+        }
+        Finding::PreferListWalk { .. } | Finding::UnusedOptionalDefault { .. } => None,
+    }
+}
+
+fn slice(src: &str, region: Region) -> &str {
+    &src[region.start().offset as usize..region.end().offset as usize]
+}
@@ -0,0 +1,279 @@
+//! Finding the code each [Rule] is about in a module's canonical IR.
+use roc_can::def::Def;
+use roc_can::expr::{AnnotatedMark, Declarations, Expr};
+use roc_can::pattern::{DestructType, ListPatterns, Pattern, RecordDestruct};
+use roc_can::traverse::{
+    walk_decl, walk_def, walk_expr, walk_record_destruct, DeclarationInfo, Visitor,
+};
+use roc_collections::MutSet;
+use roc_module::ident::Lowercase;
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+use roc_types::subs::Variable;
+
+use crate::Rule;
+
+pub(crate) enum Finding {
+    /// `if cond then Bool.true else Bool.false`, or the other way around when `negated`.
+    RedundantBoolIf {
+        region: Region,
+        condition: Region,
+        negated: bool,
+    },
+    /// A function that recurses on the rest of `list`, returning `state` once it runs out.
+    PreferListWalk {
+        region: Region,
+        function: Symbol,
+        list: Symbol,
+        state: Symbol,
+    },
+    /// An optional record field whose default is never used, because the field isn't either.
+    UnusedOptionalDefault {
+        region: Region,
+        label: Lowercase,
+        symbol: Symbol,
+    },
+}
+
+impl Finding {
+    pub fn rule(&self) -> Rule {
+        match self {
+            Finding::RedundantBoolIf { .. } => Rule::RedundantBoolIf,
+            Finding::PreferListWalk { .. } => Rule::PreferListWalk,
+            Finding::UnusedOptionalDefault { .. } => Rule::UnusedOptionalDefault,
+        }
+    }
+
+    pub fn region(&self) -> Region {
+        match self {
+            Finding::RedundantBoolIf { region, .. }
+            | Finding::PreferListWalk { region, .. }
+            | Finding::UnusedOptionalDefault { region, .. } => *region,
+        }
+    }
+}
+
+/// Everything the rules find in the declarations of one module, in source order.
+pub(crate) fn find(decls: &Declarations) -> Vec<Finding> {
+    let mut finder = Finder::default();
+
+    finder.visit_decls(decls);
+
+    let Finder {
+        mut findings,
+        optional_fields,
+        used,
+    } = finder;
+
+    findings.extend(optional_fields.into_iter().filter(|finding| match finding {
+        Finding::UnusedOptionalDefault { symbol, .. } => !used.contains(symbol),
+        _ => true,
+    }));
+    findings.sort_by_key(|finding| finding.region().start());
+
+    findings
+}
+
+#[derive(Default)]
+struct Finder {
+    findings: Vec<Finding>,
+    /// Whether these are unused depends on the rest of the module, so they wait until the end.
+    optional_fields: Vec<Finding>,
+    used: MutSet<Symbol>,
+}
+
+impl Finder {
+    fn check_function(
+        &mut self,
+        region: Region,
+        function: Symbol,
+        arguments: &[(Variable, AnnotatedMark, Loc<Pattern>)],
+        body: &Expr,
+    ) {
+        if let Some((list, state)) = manual_list_walk(function, arguments, body) {
+            self.findings.push(Finding::PreferListWalk {
+                region,
+                function,
+                list,
+                state,
+            });
+        }
+    }
+}
+
+impl Visitor for Finder {
+    fn visit_decl(&mut self, decl: DeclarationInfo<'_>) {
+        if let DeclarationInfo::Function {
+            loc_symbol,
+            loc_body,
+            function,
+            ..
+        } = &decl
+        {
+            self.check_function(
+                loc_symbol.region,
+                loc_symbol.value,
+                &function.value.arguments,
+                &loc_body.value,
+            );
+        }
+
+        walk_decl(self, decl);
+    }
+
+    fn visit_def(&mut self, def: &Def) {
+        if let Expr::Closure(closure) = &def.loc_expr.value {
+            self.check_function(
+                def.loc_pattern.region,
+                closure.name,
+                &closure.arguments,
+                &closure.loc_body.value,
+            );
+        }
+
+        walk_def(self, def);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        match expr {
+            Expr::Var(symbol, _) | Expr::RecordUpdate { symbol, .. } => {
+                self.used.insert(*symbol);
+            }
+            Expr::If {
+                branches,
+                final_else,
+                ..
+            } if branches.len() == 1 => {
+                let (condition, then) = &branches[0];
+                let negated = match (&then.value, &final_else.value) {
+                    (Expr::Var(Symbol::BOOL_TRUE, _), Expr::Var(Symbol::BOOL_FALSE, _)) => {
+                        Some(false)
+                    }
+                    (Expr::Var(Symbol::BOOL_FALSE, _), Expr::Var(Symbol::BOOL_TRUE, _)) => {
+                        Some(true)
+                    }
+                    _ => None,
+                };
+
+                if let Some(negated) = negated {
+                    self.findings.push(Finding::RedundantBoolIf {
+                        region,
+                        condition: condition.region,
+                        negated,
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        walk_expr(self, expr, var);
+    }
+
+    fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+        if let DestructType::Optional(..) = destruct.typ {
+            self.optional_fields.push(Finding::UnusedOptionalDefault {
+                region,
+                label: destruct.label.clone(),
+                symbol: destruct.symbol,
+            });
+        }
+
+        walk_record_destruct(self, destruct);
+    }
+}
+
+/// Matches functions shaped like
+///
+/// ```roc
+/// sum = \list, state ->
+///     when list is
+///         [] -> state
+///         [x, .. as rest] -> sum rest (state + x)
+/// ```
+///
+/// returning the list and the accumulator.
+fn manual_list_walk(
+    function: Symbol,
+    arguments: &[(Variable, AnnotatedMark, Loc<Pattern>)],
+    body: &Expr,
+) -> Option<(Symbol, Symbol)> {
+    let arguments: Vec<Symbol> = arguments
+        .iter()
+        .filter_map(|(_, _, pattern)| match pattern.value {
+            Pattern::Identifier(symbol) => Some(symbol),
+            _ => None,
+        })
+        .collect();
+
+    let (list, branches) = match body {
+        Expr::When {
+            loc_cond, branches, ..
+        } => match loc_cond.value {
+            Expr::Var(list, _) if arguments.contains(&list) => (list, branches),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let recurses_on_rest = branches.iter().any(|branch| {
+        branch
+            .patterns
+            .iter()
+            .any(|pattern| match &pattern.pattern.value {
+                Pattern::List {
+                    patterns:
+                        ListPatterns {
+                            opt_rest: Some((_, Some(rest))),
+                            ..
+                        },
+                    ..
+                } => calls_with(&branch.value, function, *rest),
+                _ => false,
+            })
+    });
+
+    let state = branches.iter().find_map(|branch| match branch.value.value {
+        Expr::Var(state, _) if state != list && arguments.contains(&state) => Some(state),
+        _ => None,
+    });
+
+    match state {
+        Some(state) if recurses_on_rest => Some((list, state)),
+        _ => None,
+    }
+}
+
+/// Whether the expression calls `function` with `argument` as one of its arguments.
+fn calls_with(expr: &Loc<Expr>, function: Symbol, argument: Symbol) -> bool {
+    struct FindCall {
+        function: Symbol,
+        argument: Symbol,
+        found: bool,
+    }
+
+    impl Visitor for FindCall {
+        fn visit_expr(&mut self, expr: &Expr, _region: Region, var: Variable) {
+            if let Expr::Call(call, arguments, _) = expr {
+                let calls_function =
+                    matches!(call.1.value, Expr::Var(symbol, _) if symbol == self.function);
+                let passes_argument = arguments.iter().any(
+                    |(_, arg)| matches!(arg.value, Expr::Var(symbol, _) if symbol == self.argument),
+                );
+
+                self.found |= calls_function && passes_argument;
+            }
+
+            walk_expr(self, expr, var);
+        }
+    }
+
+    let mut visitor = FindCall {
+        function,
+        argument,
+        found: false,
+    };
+
+    visitor.visit_expr(&expr.value, expr.region, Variable::NULL);
+
+    visitor.found
+}
@@ -26,6 +26,12 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::HASH, "Hash.roc"),
     (ModuleId::INSPECT, "Inspect.roc"),
     (ModuleId::JSON, "TotallyNotJson.roc"),
+    (ModuleId::RANDOM, "Random.roc"),
+    (ModuleId::DURATION, "Duration.roc"),
+    (ModuleId::DATE, "Date.roc"),
+    (ModuleId::TIME, "Time.roc"),
+    (ModuleId::ORDER, "Order.roc"),
+    (ModuleId::TARGET, "Target.roc"),
 ];
 
 fn main() {
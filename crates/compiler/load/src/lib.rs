@@ -16,14 +16,20 @@ const SKIP_SUBS_CACHE: bool = {
     }
 };
 
+pub use roc_load_internal::call_graph;
+pub use roc_load_internal::cancellation::CancellationToken;
+pub use roc_load_internal::defines::{DefineError, DefineValue, Defines};
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
     ExecutionMode, ExpectMetadata, LoadConfig, LoadResult, LoadStart, LoadingProblem, Phase,
-    Threading,
+    TestOptions, Threading,
 };
+pub use roc_load_internal::ice;
 pub use roc_load_internal::module::{
     CheckedModule, EntryPoint, Expectations, ExposedToHost, LoadedModule, MonomorphizedModule,
 };
+pub use roc_load_internal::problem_sink::{ProblemSink, ReportedProblem};
+pub use roc_load_internal::symbol_index;
 pub use roc_solve::FunctionKind;
 
 #[allow(clippy::too_many_arguments)]
@@ -57,6 +63,8 @@ pub fn load_single_threaded<'a>(
     palette: Palette,
     roc_cache_dir: RocCacheDir<'_>,
     exec_mode: ExecutionMode,
+    cancellation: Option<CancellationToken>,
+    problem_sink: Option<ProblemSink>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let cached_subs = read_cached_types();
     let exposed_types = ExposedByModule::default();
@@ -71,6 +79,11 @@ pub fn load_single_threaded<'a>(
         render,
         palette,
         exec_mode,
+        false,
+        false,
+        cancellation.unwrap_or_default(),
+        problem_sink,
+        Defines::default(),
         roc_cache_dir,
     )
 }
@@ -109,7 +122,14 @@ pub fn load_and_monomorphize_from_str<'a>(
 ) -> Result<MonomorphizedModule<'a>, LoadMonomorphizedError<'a>> {
     use LoadResult::*;
 
-    let load_start = LoadStart::from_str(arena, filename, src, roc_cache_dir, src_dir)?;
+    let load_start = LoadStart::from_str(
+        arena,
+        filename,
+        src,
+        load_config.target,
+        roc_cache_dir,
+        src_dir,
+    )?;
     let exposed_types = ExposedByModule::default();
 
     match load(arena, load_start, exposed_types, roc_cache_dir, load_config)? {
@@ -129,6 +149,7 @@ pub fn load_and_monomorphize<'a>(
     let load_start = LoadStart::from_path(
         arena,
         filename,
+        load_config.target,
         load_config.render,
         roc_cache_dir,
         load_config.palette,
@@ -153,6 +174,7 @@ pub fn load_and_typecheck<'a>(
     let load_start = LoadStart::from_path(
         arena,
         filename,
+        load_config.target,
         load_config.render,
         roc_cache_dir,
         load_config.palette,
@@ -177,10 +199,11 @@ pub fn load_and_typecheck_str<'a>(
     render: RenderTarget,
     roc_cache_dir: RocCacheDir<'_>,
     palette: Palette,
+    cancellation: Option<CancellationToken>,
 ) -> Result<LoadedModule, LoadingProblem<'a>> {
     use LoadResult::*;
 
-    let load_start = LoadStart::from_str(arena, filename, source, roc_cache_dir, src_dir)?;
+    let load_start = LoadStart::from_str(arena, filename, source, target, roc_cache_dir, src_dir)?;
 
     // NOTE: this function is meant for tests, and so we use single-threaded
     // solving so we don't use too many threads per-test. That gives higher
@@ -194,6 +217,8 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         roc_cache_dir,
         ExecutionMode::Check,
+        cancellation,
+        None,
     )? {
         Monomorphized(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
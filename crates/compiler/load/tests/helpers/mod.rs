@@ -41,6 +41,7 @@ pub fn infer_expr(
     abilities_store: &mut AbilitiesStore,
     derived_module: SharedDerivedModule,
     expr_var: Variable,
+    max_variables: Option<usize>,
 ) -> (Content, Subs) {
     let config = SolveConfig {
         types,
@@ -51,6 +52,8 @@ pub fn infer_expr(
         exposed_by_module: &Default::default(),
         derived_module,
         function_kind: FunctionKind::LambdaSet,
+        cancelled: None,
+        max_variables,
         #[cfg(debug_assertions)]
         checkmate: None,
     };
@@ -186,9 +189,11 @@ pub fn can_expr_with<'a>(
         arena,
         home,
         Path::new("Test.roc"),
+        expr_str,
         &dep_idents,
         &module_ids,
         None,
+        false,
     );
     let (loc_expr, output) = canonicalize_expr(
         &mut env,
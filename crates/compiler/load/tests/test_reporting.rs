@@ -130,6 +130,11 @@ mod test_reporting {
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
                 function_kind: FunctionKind::LambdaSet,
+                optimize_ir: false,
+                report_expects_with_dbg: false,
+                cancellation: None,
+                problem_sink: None,
+                defines: Default::default(),
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -229,6 +234,7 @@ mod test_reporting {
     fn infer_expr_help<'a>(
         arena: &'a Bump,
         expr_src: &'a str,
+        max_variables: Option<usize>,
     ) -> Result<
         (
             Vec<TypeError>,
@@ -282,13 +288,19 @@ mod test_reporting {
             &mut abilities_store,
             Default::default(),
             var,
+            max_variables,
         );
 
         Ok((unify_problems, can_problems, home, interns))
     }
 
-    fn list_reports<F>(arena: &Bump, src: &str, buf: &mut String, callback: F)
-    where
+    fn list_reports<F>(
+        arena: &Bump,
+        src: &str,
+        max_variables: Option<usize>,
+        buf: &mut String,
+        callback: F,
+    ) where
         F: FnOnce(RocDocBuilder<'_>, &mut String),
     {
         use ven_pretty::DocAllocator;
@@ -298,7 +310,7 @@ mod test_reporting {
 
         let filename = filename_from_string(r"/code/proj/Main.roc");
 
-        match infer_expr_help(arena, src) {
+        match infer_expr_help(arena, src, max_variables) {
             Err(parse_err) => {
                 let ParseErrOut {
                     fail,
@@ -399,6 +411,28 @@ mod test_reporting {
         assert_eq!(buf, expected_rendering);
     }
 
+    fn report_with_max_variables_as(src: &str, max_variables: usize, expected_rendering: &str) {
+        let mut buf: String = String::new();
+        let arena = Bump::new();
+
+        let callback = |doc: RocDocBuilder<'_>, buf: &mut String| {
+            doc.1
+                .render_raw(70, &mut roc_reporting::report::CiWrite::new(buf))
+                .expect("list_reports")
+        };
+
+        list_reports(&arena, src, Some(max_variables), &mut buf, callback);
+
+        // convenient to copy-paste the generated message
+        if buf != expected_rendering {
+            for line in buf.split('\n') {
+                println!("                {line}");
+            }
+        }
+
+        assert_eq!(buf, expected_rendering);
+    }
+
     fn color_report_problem_as(src: &str, expected_rendering: &str) {
         let mut buf: String = String::new();
         let arena = Bump::new();
@@ -415,7 +449,7 @@ mod test_reporting {
                 .expect("list_reports")
         };
 
-        list_reports(&arena, src, &mut buf, callback);
+        list_reports(&arena, src, None, &mut buf, callback);
 
         let readable = human_readable(&buf);
 
@@ -605,6 +639,30 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        chained_comparison,
+        indoc!(
+            r"
+            x = 5
+            inRange = 0 < x < 10
+
+            { x, inRange }
+            "
+        ),
+        @r"
+    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+
+    Comparisons like < can't be chained, because each one gives a Bool:
+
+    5│      inRange = 0 < x < 10
+                      ^^^^^^^^^^
+
+    To check both comparisons, combine them with &&:
+
+        0 < x && x < 10
+    "
+    );
+
     test_report!(
         #[ignore = "Blocked on https://github.com/roc-lang/roc/issues/3385"]
         unrecognized_name,
@@ -743,7 +801,7 @@ mod test_reporting {
 
         let arena = Bump::new();
         let (_type_problems, _can_problems, home, interns) =
-            infer_expr_help(&arena, src).expect("parse error");
+            infer_expr_help(&arena, src, None).expect("parse error");
 
         let mut buf = String::new();
         let src_lines: Vec<&str> = src.split('\n').collect();
@@ -774,7 +832,7 @@ mod test_reporting {
 
         let arena = Bump::new();
         let (_type_problems, _can_problems, home, mut interns) =
-            infer_expr_help(&arena, src).expect("parse error");
+            infer_expr_help(&arena, src, None).expect("parse error");
 
         let mut buf = String::new();
         let src_lines: Vec<&str> = src.split('\n').collect();
@@ -790,6 +848,41 @@ mod test_reporting {
         assert_eq!(human_readable(&buf), "<green>Util.Int<reset>");
     }
 
+    #[test]
+    fn too_many_type_variables_blames_the_enclosing_def() {
+        // `x` is solved before the list, so `f` is the def that runs out of type variables
+        report_with_max_variables_as(
+            indoc!(
+                r"
+                    f =
+                        x = 1
+
+                        [x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x, x]
+
+                    f
+                "
+            ),
+            100,
+            indoc!(
+                r"
+                ── TOO MANY TYPE VARIABLES in /code/proj/Main.roc ──────────────────────────────
+
+                Inferring the types in this module needed more than 100 type
+                variables, so I stopped.
+
+                I was working on the `f` definition:
+
+                1│  f =
+                    ^
+
+                Tip: This usually comes from a very large record or list literal.
+                Splitting it up, or giving it a type annotation, may help. The limit
+                can be raised with the `ROC_MAX_TYPE_VARIABLES` environment variable.
+                "
+            ),
+        );
+    }
+
     #[test]
     fn report_region_in_color() {
         color_report_problem_as(
@@ -845,6 +938,56 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        and_operand_not_bool,
+        indoc!(
+            r#"
+            "foo" && Bool.true
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This 1st argument to && has an unexpected type:
+
+    4│      "foo" && Bool.true
+            ^^^^^
+
+    The argument is a string of type:
+
+        Str
+
+    But && needs its 1st argument to be:
+
+        Bool
+    "#
+    );
+
+    test_report!(
+        or_operand_not_bool,
+        indoc!(
+            r#"
+            Bool.false || 1
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This 2nd argument to || has an unexpected type:
+
+    4│      Bool.false || 1
+                          ^
+
+    The argument is a number of type:
+
+        Num *
+
+    But || needs its 2nd argument to be:
+
+        Bool
+    "#
+    );
+
     test_report!(
         when_if_guard,
         indoc!(
@@ -1438,6 +1581,43 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        record_field_typo_nearest,
+        indoc!(
+            r#"
+            f : { name : Str } -> Str
+            f = \_ -> "hi"
+
+            f { age: "1", nmae: "x" }
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This 1st argument to `f` has an unexpected type:
+
+    7│      f { age: "1", nmae: "x" }
+              ^^^^^^^^^^^^^^^^^^^^^^^
+
+    The argument is a record of type:
+
+        {
+            age : Str,
+            nmae : Str,
+        }
+
+    But `f` needs its 1st argument to be:
+
+        { name : Str }
+
+    Tip: Seems like a record field typo. Maybe `nmae` should be `name`?
+
+    Tip: Can more type annotations be added? Type annotations always help
+    me give more specific messages, and I think they could help a lot in
+    this case
+    "#
+    );
+
     test_report!(
         tag_mismatch,
         indoc!(
@@ -1649,12 +1829,35 @@ mod test_reporting {
     The `f` function expects 1 argument, but it got 2 instead:
 
     7│      f 1 2
-            ^
+                ^
 
     Are there any missing commas? Or missing parentheses?
     "
     );
 
+    test_report!(
+        fncall_overapplied_curried,
+        indoc!(
+            r"
+            f : Num.I64 -> (Num.I64 -> Num.I64)
+            f = \a -> \b -> a + b
+
+            f 1 2
+            "
+        ),
+        @r"
+    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+
+    The `f` function expects 1 argument, but it got 2 instead:
+
+    7│      f 1 2
+                ^
+
+    Tip: This function returns another function. To pass the extra
+    arguments to that one, put parentheses around the first call.
+    "
+    );
+
     test_report!(
         fncall_underapplied,
         indoc!(
@@ -1678,6 +1881,40 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        when_branch_body_shows_pattern_bindings,
+        indoc!(
+            r#"
+            pair : [Pair Str (List Str)]
+            pair = Pair "a" ["b"]
+
+            when pair is
+                Pair name rest -> Str.concat name rest
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This 2nd argument to `concat` has an unexpected type:
+
+    8│          Pair name rest -> Str.concat name rest
+                                                  ^^^^
+
+    This `rest` value is a:
+
+        List Str
+
+    But `concat` needs its 2nd argument to be:
+
+        Str
+
+    The pattern of this `when` branch binds these names:
+
+        name : Str
+        rest : List Str
+    "#
+    );
+
     test_report!(
         pattern_when_condition,
         indoc!(
@@ -2037,6 +2274,30 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        hex_pattern_too_big,
+        indoc!(
+            r"
+            when 1 is
+                0x1_0000_0000_0000_0000_0000_0000_0000_0000 -> 3
+                _ -> 4
+            "
+        ),
+        @r"
+    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+
+    This integer literal is too big:
+
+    5│          0x1_0000_0000_0000_0000_0000_0000_0000_0000 -> 3
+                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The largest number representable in Roc is the maximum U128 value,
+    340_282_366_920_938_463_463_374_607_431_768_211_455.
+
+    Tip: Learn more about number literals at TODO
+    "
+    );
+
     test_report!(
         missing_fields,
         indoc!(
@@ -3032,6 +3293,50 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        record_update_unchanged_field,
+        indoc!(
+            r"
+            r = { x: 1, y: 2 }
+
+            { r & x: r.x, y: 3 }
+            "
+        ),
+        @r"
+    ── UNCHANGED RECORD FIELD in /code/proj/Main.roc ───────────────────────────────
+
+    This record update sets the `.x` field to the value it already has:
+
+    6│      { r & x: r.x, y: 3 }
+                  ^^^^^^
+
+    Copying `.x` from `r` back into `r` doesn't change anything, so this field
+    can be removed.
+    "
+    );
+
+    test_report!(
+        record_update_identity,
+        indoc!(
+            r"
+            r = { x: 1, y: 2 }
+
+            { r & x: r.x, y: r.y }
+            "
+        ),
+        @r"
+    ── IDENTITY RECORD UPDATE in /code/proj/Main.roc ───────────────────────────────
+
+    This record update doesn't change anything:
+
+    6│      { r & x: r.x, y: r.y }
+            ^^^^^^^^^^^^^^^^^^^^^^
+
+    Every field is set to the value it already has in `r`, so this whole
+    expression can be replaced with just `r`.
+    "
+    );
+
     test_report!(
         record_duplicate_field_multiline,
         indoc!(
@@ -3793,8 +4098,8 @@ mod test_reporting {
 
         Set
         List
+        Time
         Dict
-        Hash
 
     ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
 
@@ -3832,6 +4137,31 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        module_not_imported_field_access,
+        indoc!(
+            r"
+            Foo.test.field
+            "
+        ),
+        @r"
+    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+
+    The `Foo` module is not imported:
+
+    4│      Foo.test.field
+            ^^^^^^^^
+
+    Is there an import missing? Perhaps there is a typo. Did you mean one
+    of these?
+
+        Box
+        Bool
+        Num
+        Set
+    "
+    );
+
     test_report!(
         optional_record_default_type_error,
         indoc!(
@@ -4536,8 +4866,8 @@ mod test_reporting {
 
     I encountered a tab character:
 
-    4│      f : { foo 	 }
-                      ^
+    4│      f : { foo      }
+                      ^^^^
 
     Tab characters are not allowed in Roc code. Please use spaces instead!
     "###
@@ -4551,8 +4881,38 @@ mod test_reporting {
 
     I encountered a tab character:
 
-    4│      # comment with a 	
-                             ^
+    4│      # comment with a     
+                             ^^^^
+
+    Tab characters are not allowed in Roc code. Please use spaces instead!
+    "###
+    );
+
+    test_report!(
+        tab_in_indentation,
+        "x =\n\t1\n\nx",
+        @r###"
+    ── TAB CHARACTER in tmp/tab_in_indentation/Test.roc ────────────────────────────
+
+    I encountered a tab character:
+
+    5│          1
+            ^^^^
+
+    Tab characters are not allowed in Roc code. Please use spaces instead!
+    "###
+    );
+
+    test_report!(
+        tab_between_tokens,
+        "x =\n    1\t+ 2\n\nx",
+        @r###"
+    ── TAB CHARACTER in tmp/tab_between_tokens/Test.roc ────────────────────────────
+
+    I encountered a tab character:
+
+    5│          1    + 2
+                 ^^^^
 
     Tab characters are not allowed in Roc code. Please use spaces instead!
     "###
@@ -5459,7 +5819,6 @@ mod test_reporting {
     "
     );
 
-    // this should get better with time
     test_report!(
         if_missing_else,
         indoc!(
@@ -5468,14 +5827,41 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED IF in tmp/if_missing_else/Test.roc ───────────────────────────────
+    ── MISSING ELSE in tmp/if_missing_else/Test.roc ────────────────────────────────
 
-    I was partway through parsing an `if` expression, but I got stuck here:
+    This `if` has no `else` branch:
 
     4│      if 5 == 5 then 2
-                            ^
+            ^^^^^^^^^^^^^^^^
+
+    In Roc, `if` is an expression, so it needs a value whichever way the
+    condition goes. That means every `if` must end with an `else` branch.
+
+    Tip: Add an `else` branch with the value to use when the condition is
+    false.
+    "
+    );
+
+    test_report!(
+        else_if_missing_else,
+        indoc!(
+            r"
+            if 5 == 5 then 2 else if 6 == 6 then 3
+            "
+        ),
+        @r"
+    ── MISSING ELSE in tmp/else_if_missing_else/Test.roc ───────────────────────────
 
-    I was expecting to see the `else` keyword next.
+    This `if` has no `else` branch:
+
+    4│      if 5 == 5 then 2 else if 6 == 6 then 3
+                                  ^^^^^^^^^^^^^^^^
+
+    In Roc, `if` is an expression, so it needs a value whichever way the
+    condition goes. That means every `if` must end with an `else` branch.
+
+    Tip: Add an `else` branch with the value to use when the condition is
+    false.
     "
     );
 
@@ -8053,9 +8439,9 @@ In roc, functions are always written as a lambda, like{}
     of these?
 
         Hash
+        Time
+        Date
         List
-        Num
-        Box
     "
     );
 
@@ -10593,6 +10979,24 @@ In roc, functions are always written as a lambda, like{}
     "#
     );
 
+    test_report!(
+        record_builder_copied_fields_are_not_unchanged,
+        indoc!(
+            r#"
+            r = { b: "b" }
+
+            succeed = \f -> f
+            apply = \x -> \build -> build x
+
+            succeed {
+                a: <- apply "a",
+                b: r.b,
+            }
+            "#
+        ),
+        @"" // copying `b` from `r` into a new record is not an unchanged update
+    );
+
     test_report!(
         record_update_builder,
         indoc!(
@@ -11926,7 +12330,7 @@ In roc, functions are always written as a lambda, like{}
     This 2nd argument to `contains` has an unexpected type:
 
     7│      List.contains digits '☃'
-                                 ^^^^^
+                                 ^^^
 
     The argument is a Unicode scalar value of type:
 
@@ -0,0 +1,157 @@
+//! How much the build tasks allocate in their arenas, to find the phases whose `Bump`
+//! allocations are worth pooling. Only collected with the `arena-stats` feature; without it,
+//! measuring does nothing.
+//!
+//! A worker allocates in a single arena for the whole load, so nothing a task allocates is freed
+//! before the load is done. When a load finishes, the usage of each phase is printed to stderr,
+//! along with the tasks and arenas that grew past the thresholds below.
+
+use bumpalo::Bump;
+
+use crate::ice::CompilerContext;
+
+/// A task that grows its arena by more than this is reported.
+#[cfg(feature = "arena-stats")]
+const TASK_THRESHOLD_BYTES: usize = 64 * 1024 * 1024;
+
+/// An arena that holds more than this when the load finishes is reported.
+#[cfg(feature = "arena-stats")]
+const ARENA_THRESHOLD_BYTES: usize = 512 * 1024 * 1024;
+
+#[cfg(feature = "arena-stats")]
+mod collect {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use crate::ice::CompilerPhase;
+
+    #[derive(Default)]
+    pub(super) struct PhaseUsage {
+        pub tasks: usize,
+        pub bytes: usize,
+        pub largest_task: usize,
+    }
+
+    #[derive(Default)]
+    pub(super) struct Stats {
+        pub by_phase: BTreeMap<CompilerPhase, PhaseUsage>,
+        /// The tasks that grew past the threshold, with their phase, module and growth
+        pub large_tasks: Vec<(CompilerPhase, String, usize)>,
+        /// How much each worker arena held when its worker finished
+        pub worker_arenas: Vec<usize>,
+    }
+
+    pub(super) static STATS: Mutex<Option<Stats>> = Mutex::new(None);
+}
+
+/// Records how much the arena grew while the task runs, once dropped.
+pub(crate) struct TaskUsage<'b> {
+    #[cfg(feature = "arena-stats")]
+    arena: &'b Bump,
+    #[cfg(feature = "arena-stats")]
+    context: CompilerContext,
+    #[cfg(feature = "arena-stats")]
+    before: usize,
+    #[cfg(not(feature = "arena-stats"))]
+    _arena: std::marker::PhantomData<&'b Bump>,
+}
+
+/// Starts measuring the task that runs in the arena.
+#[cfg(feature = "arena-stats")]
+pub(crate) fn measure<'b>(arena: &'b Bump, context: &CompilerContext) -> TaskUsage<'b> {
+    TaskUsage {
+        arena,
+        context: context.clone(),
+        before: arena.allocated_bytes(),
+    }
+}
+
+#[cfg(not(feature = "arena-stats"))]
+#[inline(always)]
+pub(crate) fn measure<'b>(_arena: &'b Bump, _context: &CompilerContext) -> TaskUsage<'b> {
+    TaskUsage {
+        _arena: std::marker::PhantomData,
+    }
+}
+
+#[cfg(feature = "arena-stats")]
+impl Drop for TaskUsage<'_> {
+    fn drop(&mut self) {
+        let grown = self.arena.allocated_bytes().saturating_sub(self.before);
+        let phase = self.context.phase;
+
+        let mut stats = collect::STATS.lock().unwrap();
+        let stats = stats.get_or_insert_with(Default::default);
+
+        let usage = stats.by_phase.entry(phase).or_default();
+        usage.tasks += 1;
+        usage.bytes += grown;
+        usage.largest_task = usage.largest_task.max(grown);
+
+        if grown > TASK_THRESHOLD_BYTES {
+            let module = std::mem::take(&mut self.context.module);
+
+            stats.large_tasks.push((phase, module, grown));
+        }
+    }
+}
+
+/// Records how much a worker's arena holds once the worker has run out of tasks.
+#[cfg(feature = "arena-stats")]
+pub(crate) fn finish_worker(arena: &Bump) {
+    let held = arena.allocated_bytes();
+    let mut stats = collect::STATS.lock().unwrap();
+
+    stats
+        .get_or_insert_with(Default::default)
+        .worker_arenas
+        .push(held);
+}
+
+#[cfg(not(feature = "arena-stats"))]
+#[inline(always)]
+pub(crate) fn finish_worker(_arena: &Bump) {}
+
+/// Prints the usage collected since the last report, and how much the arenas of the load hold
+/// now that it is done.
+#[cfg(feature = "arena-stats")]
+pub(crate) fn report(main_arena: &Bump) {
+    let stats = collect::STATS.lock().unwrap().take().unwrap_or_default();
+
+    eprintln!("\n── ARENA USAGE ──\n");
+
+    for (phase, usage) in &stats.by_phase {
+        eprintln!(
+            "{:<26} {:>6} tasks {:>10} KiB, largest task {:>10} KiB",
+            phase.to_string(),
+            usage.tasks,
+            usage.bytes / 1024,
+            usage.largest_task / 1024,
+        );
+    }
+
+    for (phase, module, grown) in &stats.large_tasks {
+        eprintln!(
+            "warning: {phase} of {module} grew its arena by {} KiB",
+            grown / 1024
+        );
+    }
+
+    let arenas = std::iter::once(("main", main_arena.allocated_bytes()))
+        .chain(stats.worker_arenas.iter().map(|held| ("worker", *held)));
+
+    for (index, (kind, held)) in arenas.enumerate() {
+        if held > ARENA_THRESHOLD_BYTES {
+            eprintln!(
+                "warning: the {kind} arena #{index} still holds {} KiB at the end of the load",
+                held / 1024
+            );
+        }
+    }
+
+    eprintln!();
+}
+
+#[cfg(not(feature = "arena-stats"))]
+#[inline(always)]
+pub(crate) fn report(_main_arena: &Bump) {}
@@ -0,0 +1,71 @@
+//! The calls each module makes, so editors can show who calls a function and what it calls.
+//!
+//! Every call is attributed to the top-level def it appears in, including calls inside nested
+//! defs and lambdas. The graph is built from the canonical declarations, so calls written with
+//! `|>` or operators are found just like direct ones.
+
+use roc_can::{
+    expr::{Declarations, Expr},
+    traverse::{walk_decl, walk_expr, DeclarationInfo, Visitor},
+};
+use roc_module::symbol::Symbol;
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Call {
+    /// The top-level def the call is in
+    pub caller: Symbol,
+    pub callee: Symbol,
+    /// Where the function being called is named
+    pub region: Region,
+}
+
+pub fn module_calls(declarations: &Declarations) -> Vec<Call> {
+    let mut collector = CallCollector {
+        caller: None,
+        calls: Vec::new(),
+    };
+
+    collector.visit_decls(declarations);
+
+    collector.calls
+}
+
+struct CallCollector {
+    caller: Option<Symbol>,
+    calls: Vec<Call>,
+}
+
+impl Visitor for CallCollector {
+    fn visit_decl(&mut self, decl: DeclarationInfo<'_>) {
+        self.caller = match &decl {
+            DeclarationInfo::Value { loc_symbol, .. }
+            | DeclarationInfo::Function { loc_symbol, .. } => Some(loc_symbol.value),
+            DeclarationInfo::Expectation { .. } | DeclarationInfo::Destructure { .. } => None,
+        };
+
+        walk_decl(self, decl);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr, _region: Region, var: Variable) {
+        if let (Some(caller), Expr::Call(call, _, _)) = (self.caller, expr) {
+            let (_, loc_fn, _, _) = &**call;
+
+            let callee = match loc_fn.value {
+                Expr::Var(symbol, _) | Expr::AbilityMember(symbol, _, _) => Some(symbol),
+                _ => None,
+            };
+
+            if let Some(callee) = callee {
+                self.calls.push(Call {
+                    caller,
+                    callee,
+                    region: loc_fn.region,
+                });
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
@@ -0,0 +1,35 @@
+//! Stopping a load that is in progress, because its result is no longer needed.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared between the code that starts a load and the code that may cancel it. Once cancelled,
+/// the load stops at the next point it checks, and returns [`LoadingProblem::Cancelled`].
+///
+/// The load checks between tasks, and inside the loops of solving and specialization, which can
+/// run for a long time on a single module.
+///
+/// [`LoadingProblem::Cancelled`]: crate::file::LoadingProblem::Cancelled
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// The flag that is set on cancellation, for code that can't depend on this crate.
+    pub(crate) fn flag(&self) -> &AtomicBool {
+        &self.cancelled
+    }
+}
@@ -0,0 +1,157 @@
+//! Constants given on the command line, like `roc build --define version=1.2.0`, which an app
+//! reads from the `Defines` module that the compiler generates for it instead of reading a file.
+//!
+//! ```roc
+//! import Defines
+//!
+//! main = Stdout.line! "Running version $(Defines.version)"
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use roc_parse::keyword::KEYWORDS;
+
+/// The name of the generated module, which apps import like any module in their directory.
+pub const DEFINES_MODULE_NAME: &str = "Defines";
+
+/// The value of a define. `true` and `false` become a `Bool`, so they can toggle features, and
+/// anything else is a `Str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefineValue {
+    Bool(bool),
+    Str(String),
+}
+
+impl DefineValue {
+    fn from_arg(value: &str) -> Self {
+        match value {
+            "true" => DefineValue::Bool(true),
+            "false" => DefineValue::Bool(false),
+            _ => DefineValue::Str(value.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefineError {
+    /// The argument had no `=`, e.g. `--define version`
+    MissingValue(String),
+    /// The name isn't one that Roc code could refer to, e.g. `--define Version=1`
+    InvalidName(String),
+    /// The same name was given more than once
+    Duplicate(String),
+}
+
+impl fmt::Display for DefineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefineError::MissingValue(arg) => {
+                write!(f, "`{arg}` has no value; defines look like `name=value`")
+            }
+            DefineError::InvalidName(name) => write!(
+                f,
+                "`{name}` can't be the name of a define; it must be a lowercase name like `version` that isn't a keyword"
+            ),
+            DefineError::Duplicate(name) => write!(f, "`{name}` was defined more than once"),
+        }
+    }
+}
+
+impl std::error::Error for DefineError {}
+
+/// The defines of a build, in the order they were given.
+#[derive(Debug, Clone, Default)]
+pub struct Defines {
+    entries: Arc<Vec<(String, DefineValue)>>,
+}
+
+impl Defines {
+    /// Parses arguments like `version=1.2.0`. Everything after the first `=` is the value, so
+    /// values can contain `=` themselves.
+    pub fn parse<'s>(args: impl IntoIterator<Item = &'s str>) -> Result<Self, DefineError> {
+        let mut entries: Vec<(String, DefineValue)> = Vec::new();
+
+        for arg in args {
+            let (name, value) = arg
+                .split_once('=')
+                .ok_or_else(|| DefineError::MissingValue(arg.to_string()))?;
+
+            if !is_valid_name(name) {
+                return Err(DefineError::InvalidName(name.to_string()));
+            }
+
+            if entries.iter().any(|(existing, _)| existing == name) {
+                return Err(DefineError::Duplicate(name.to_string()));
+            }
+
+            entries.push((name.to_string(), DefineValue::from_arg(value)));
+        }
+
+        Ok(Self {
+            entries: Arc::new(entries),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DefineValue)> {
+        self.entries
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// The source of the `Defines` module, which exposes every define as an annotated constant.
+    pub fn module_source(&self) -> String {
+        let names: Vec<&str> = self.iter().map(|(name, _)| name).collect();
+        let mut source = format!("module [{}]\n", names.join(", "));
+
+        for (name, value) in self.iter() {
+            match value {
+                DefineValue::Bool(true) => {
+                    source.push_str(&format!("\n{name} : Bool\n{name} = Bool.true\n"))
+                }
+                DefineValue::Bool(false) => {
+                    source.push_str(&format!("\n{name} : Bool\n{name} = Bool.false\n"))
+                }
+                DefineValue::Str(string) => source.push_str(&format!(
+                    "\n{name} : Str\n{name} = \"{}\"\n",
+                    escape_str(string)
+                )),
+            }
+        }
+
+        source
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(first) if first.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_alphanumeric())
+        && !KEYWORDS.contains(&name)
+}
+
+/// Escapes a value so it can go between the quotes of a Roc string literal, without `$(`
+/// starting an interpolation.
+fn escape_str(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for c in string.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '$' => escaped.push_str("\\$"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u({:x})", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,83 @@
+//! Support for `roc test --doc`, which runs the `expect`s written in doc comment code blocks.
+//!
+//! Rather than parsing code blocks separately, we uncomment them in the module's source, so
+//! they are compiled as top-level code of the module they document. Every line keeps its
+//! length, so all the offsets after it (and all the line numbers) stay the same.
+
+/// Uncomment the fenced code blocks in `##` doc comments that contain at least one `expect`.
+/// Code inside such a block moves to the start of its line, and the fences become blank.
+pub(crate) fn uncomment_doc_tests(src: &str) -> String {
+    let mut output = String::with_capacity(src.len());
+    let mut block: Vec<&str> = Vec::new();
+    let mut in_block = false;
+
+    for line in src.split_inclusive('\n') {
+        let content = doc_content(line);
+        let is_fence = content.map_or(false, |content| content.starts_with("```"));
+
+        if in_block {
+            if content.is_none() {
+                // The doc comment ended before the code block did, so leave it alone.
+                output.extend(block.drain(..));
+                output.push_str(line);
+                in_block = false;
+                continue;
+            }
+
+            block.push(line);
+
+            if is_fence {
+                push_block(&mut output, &block);
+                block.clear();
+                in_block = false;
+            }
+        } else if is_fence {
+            block.push(line);
+            in_block = true;
+        } else {
+            output.push_str(line);
+        }
+    }
+
+    // An unclosed code block is left alone.
+    output.extend(block);
+
+    output
+}
+
+/// The text after the `##` of a doc comment line, without the line ending.
+fn doc_content(line: &str) -> Option<&str> {
+    let content = line.strip_prefix("##")?;
+    let content = content.strip_prefix(' ').unwrap_or(content);
+
+    Some(content.trim_end_matches(['\n', '\r']))
+}
+
+fn push_block(output: &mut String, block: &[&str]) {
+    let code_lines = &block[1..block.len() - 1];
+
+    let has_expect = code_lines
+        .iter()
+        .any(|line| doc_content(line).map_or(false, |content| content.starts_with("expect")));
+
+    if !has_expect {
+        output.extend(block.iter().copied());
+        return;
+    }
+
+    for (index, line) in block.iter().enumerate() {
+        let is_fence = index == 0 || index == block.len() - 1;
+        let content = if is_fence {
+            ""
+        } else {
+            doc_content(line).unwrap_or_default()
+        };
+
+        let line_ending = &line[line.trim_end_matches(['\n', '\r']).len()..];
+        let padding = line.len() - line_ending.len() - content.len();
+
+        output.push_str(content);
+        output.extend(std::iter::repeat(' ').take(padding));
+        output.push_str(line_ending);
+    }
+}
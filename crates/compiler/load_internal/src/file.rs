@@ -1,5 +1,6 @@
 #![allow(clippy::too_many_arguments)]
 
+use crate::call_graph::Call;
 use crate::docs::ModuleDocumentation;
 use crate::module::{
     CheckedModule, ConstrainedModule, EntryPoint, Expectations, ExposedToHost,
@@ -7,6 +8,7 @@ use crate::module::{
     ModuleTiming, MonomorphizedModule, ParsedModule, ToplevelExpects, TypeCheckedModule,
 };
 use crate::module_cache::ModuleCache;
+use crate::symbol_index::IndexedSymbol;
 use bumpalo::{collections::CollectIn, Bump};
 use crossbeam::channel::{bounded, Sender};
 use crossbeam::deque::{Injector, Stealer, Worker};
@@ -22,12 +24,12 @@ use roc_can::module::{
 };
 use roc_collections::{default_hasher, BumpMap, MutMap, MutSet, VecMap, VecSet};
 use roc_constrain::module::constrain_module;
-use roc_debug_flags::dbg_do;
+use roc_debug_flags::{dbg_do, roc_log};
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION, ROC_PRINT_IR_AFTER_REFCOUNT,
-    ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_IR_AFTER_TRMC,
-    ROC_PRINT_LOAD_LOG,
+    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_CONSTANT_FOLDING, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION,
+    ROC_PRINT_IR_AFTER_LIST_FUSION, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
+    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_IR_AFTER_TRMC,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
@@ -39,7 +41,7 @@ use roc_module::symbol::{
 };
 use roc_mono::ir::{
     CapturedSymbols, ExternalSpecializations, GlueLayouts, HostExposedLambdaSets, PartialProc,
-    Proc, ProcLayout, Procs, ProcsBase, UpdateModeIds, UsageTrackingMap,
+    Proc, ProcLayout, Procs, ProcsBase, RunawaySpecialization, UpdateModeIds, UsageTrackingMap,
 };
 use roc_mono::layout::{
     GlobalLayoutInterner, LambdaName, Layout, LayoutCache, LayoutProblem, Niche, STLayoutInterner,
@@ -72,6 +74,7 @@ use std::iter;
 use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 use std::str::from_utf8_unchecked;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::{env, fs};
 #[cfg(not(target_family = "wasm"))]
@@ -80,6 +83,11 @@ use {
     roc_packaging::https::{PackageMetadata, Problem},
 };
 
+use crate::arena_stats;
+use crate::cancellation::CancellationToken;
+use crate::defines::{Defines, DEFINES_MODULE_NAME};
+use crate::ice::{self, CompilerContext, CompilerPhase};
+use crate::problem_sink::ProblemSink;
 pub use crate::work::Phase;
 use crate::work::{DepCycle, Dependencies};
 
@@ -96,8 +104,17 @@ const MODULE_SEPARATOR: char = '.';
 
 const EXPANDED_STACK_SIZE: usize = 8 * 1024 * 1024;
 
+/// The byte order mark some editors put at the start of UTF-8 files
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The most type variables inference may allocate for one module, unless overridden with
+/// `ROC_MAX_TYPE_VARIABLES`. Real modules stay far below this; going past it means a
+/// pathological def, which would otherwise run the compiler out of memory.
+const DEFAULT_MAX_TYPE_VARIABLES: usize = 50_000_000;
+
+/// Load phases as they complete, shown with `ROC_LOG=load=debug`.
 macro_rules! log {
-    ($($arg:tt)*) => (dbg_do!(ROC_PRINT_LOAD_LOG, println!($($arg)*)))
+    ($($arg:tt)*) => (roc_log!("load", Debug, $($arg)*))
 }
 
 #[derive(Debug)]
@@ -108,6 +125,19 @@ pub struct LoadConfig {
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
     pub function_kind: FunctionKind,
+    /// Run the mono IR optimizations meant for optimized builds, like
+    /// [roc_mono::fold_constants], [roc_mono::list_fusion] and [roc_mono::list_walk], and
+    /// remove the procedures they leave unused with [roc_mono::dead_code].
+    pub optimize_ir: bool,
+    /// Report failing inline `expect`s through `dbg` and carry on, for the backends that
+    /// can't report them the way the LLVM backend does.
+    pub report_expects_with_dbg: bool,
+    /// Lets the caller stop the load before it finishes, e.g. when its input has changed.
+    pub cancellation: Option<CancellationToken>,
+    /// Receives the problems of each module as soon as it is checked.
+    pub problem_sink: Option<ProblemSink>,
+    /// The constants that the generated `Defines` module exposes.
+    pub defines: Defines,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -118,7 +148,17 @@ pub enum ExecutionMode {
     ExecutableIfCheck,
     /// Test is like [`ExecutionMode::ExecutableIfCheck`], but rather than producing a proper
     /// executable, run tests.
-    Test,
+    Test(TestOptions),
+}
+
+/// Optional extras of [`ExecutionMode::Test`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestOptions {
+    /// Mark every `when` branch and function, so that `roc test --coverage` can report the
+    /// branches that the tests never took and the functions they never called.
+    pub coverage: bool,
+    /// Also run the `expect`s in code blocks of doc comments, for `roc test --doc`.
+    pub doc_tests: bool,
 }
 
 impl ExecutionMode {
@@ -127,12 +167,30 @@ impl ExecutionMode {
 
         match self {
             Executable => Phase::MakeSpecializations,
-            Check | ExecutableIfCheck | Test => Phase::SolveTypes,
+            Check | ExecutableIfCheck | Test(_) => Phase::SolveTypes,
         }
     }
 
     fn build_if_checks(&self) -> bool {
-        matches!(self, Self::ExecutableIfCheck | Self::Test)
+        matches!(self, Self::ExecutableIfCheck | Self::Test(_))
+    }
+
+    fn is_test(&self) -> bool {
+        matches!(self, Self::Test(_))
+    }
+
+    fn instrument_coverage(&self) -> bool {
+        matches!(self, Self::Test(TestOptions { coverage: true, .. }))
+    }
+
+    fn doc_tests(&self) -> bool {
+        matches!(
+            self,
+            Self::Test(TestOptions {
+                doc_tests: true,
+                ..
+            })
+        )
     }
 }
 
@@ -182,6 +240,7 @@ fn start_phase<'a>(
                             module_ids: Arc::clone(&state.arc_modules),
                             shorthands: Arc::clone(&state.arc_shorthands),
                             ident_ids_by_module: Arc::clone(&state.ident_ids_by_module),
+                            defines: state.defines.clone(),
                         }
                     }
                 }
@@ -194,6 +253,7 @@ fn start_phase<'a>(
                     header,
                     module_ids: Arc::clone(&state.arc_modules),
                     ident_ids_by_module: Arc::clone(&state.ident_ids_by_module),
+                    doc_tests: state.exec_mode.doc_tests(),
                 }
             }
             Phase::CanonicalizeAndConstrain => {
@@ -302,6 +362,7 @@ fn start_phase<'a>(
                     aliases,
                     abilities_store,
                     skip_constraint_gen,
+                    report_expects_with_dbg: state.report_expects_with_dbg,
                     exposed_module_ids: state.exposed_modules,
                 }
             }
@@ -397,8 +458,7 @@ fn start_phase<'a>(
 
                 let derived_module = SharedDerivedModule::clone(&state.derived_module);
 
-                let build_expects =
-                    matches!(state.exec_mode, ExecutionMode::Test) && expectations.is_some();
+                let build_expects = state.exec_mode.is_test() && expectations.is_some();
 
                 BuildTask::BuildPendingSpecializations {
                     layout_cache,
@@ -415,6 +475,7 @@ fn start_phase<'a>(
                     derived_module,
                     expectations,
                     build_expects,
+                    instrument_coverage: state.exec_mode.instrument_coverage(),
                 }
             }
             Phase::MakeSpecializations => {
@@ -516,6 +577,7 @@ fn start_phase<'a>(
                         &state.exposed_types,
                         &mut procs_base,
                         &mut state.world_abilities,
+                        state.exec_mode.instrument_coverage(),
                     );
                 }
 
@@ -534,6 +596,7 @@ fn start_phase<'a>(
                     exposed_by_module: state.exposed_types.clone(),
                     derived_module,
                     expectations,
+                    instrument_coverage: state.exec_mode.instrument_coverage(),
                 }
             }
         }
@@ -611,6 +674,13 @@ enum Msg<'a> {
         subs: Subs,
         expectations: Option<Expectations>,
     },
+    /// Specializing a module would never finish, because a function kept needing
+    /// specializations of itself at new layouts.
+    RunawaySpecialization {
+        module_id: ModuleId,
+        ident_ids: IdentIds,
+        cycle: Vec<Symbol>,
+    },
 
     /// The task is to only typecheck AND monomorphize modules
     /// all modules are now monomorphized, we are done
@@ -638,6 +708,9 @@ struct CanAndCon {
     constrained_module: ConstrainedModule,
     canonicalization_problems: Vec<roc_problem::can::Problem>,
     module_docs: Option<ModuleDocumentation>,
+    symbol_index: Vec<IndexedSymbol>,
+    calls: Vec<Call>,
+    builtin_lookups: Vec<Loc<Symbol>>,
 }
 
 #[derive(Debug)]
@@ -654,6 +727,8 @@ struct PlatformData<'a> {
     module_id: ModuleId,
     provides: &'a [(Loc<ExposedName<'a>>, Loc<TypedIdent<'a>>)],
     is_prebuilt: bool,
+    /// The builtin modules the platform doesn't support
+    unavailable: Vec<ModuleId>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -712,6 +787,8 @@ struct State<'a> {
     /// From now on, these will be used by multiple threads; time to make an Arc<Mutex<_>>!
     pub arc_modules: Arc<Mutex<PackageModuleIds<'a>>>,
     pub arc_shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
+    /// Every package shorthand declared so far, so conflicting declarations can be reported
+    package_requirements: std::vec::Vec<PackageRequirement<'a>>,
     pub derived_module: SharedDerivedModule,
 
     pub ident_ids_by_module: SharedIdentIdsByModule,
@@ -732,6 +809,11 @@ struct State<'a> {
     pub render: RenderTarget,
     pub palette: Palette,
     pub exec_mode: ExecutionMode,
+    pub optimize_ir: bool,
+    pub report_expects_with_dbg: bool,
+    pub cancellation: CancellationToken,
+    pub problem_sink: Option<ProblemSink>,
+    pub defines: Defines,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -765,9 +847,15 @@ impl<'a> State<'a> {
         palette: Palette,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        optimize_ir: bool,
+        report_expects_with_dbg: bool,
+        cancellation: CancellationToken,
+        problem_sink: Option<ProblemSink>,
+        defines: Defines,
+        roc_cache_dir: RocCacheDir<'_>,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
-        let cache_dir = roc_packaging::cache::roc_cache_dir();
+        let cache_dir = roc_cache_dir.packages_dir();
         let dependencies = Dependencies::new(exec_mode.goal_phase());
 
         Self {
@@ -790,6 +878,7 @@ impl<'a> State<'a> {
             exposed_types,
             arc_modules,
             arc_shorthands,
+            package_requirements: std::vec::Vec::new(),
             derived_module: Default::default(),
             constrained_ident_ids: IdentIds::exposed_builtins(0),
             ident_ids_by_module,
@@ -801,6 +890,11 @@ impl<'a> State<'a> {
             render,
             palette,
             exec_mode,
+            optimize_ir,
+            report_expects_with_dbg,
+            cancellation,
+            problem_sink,
+            defines,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalLayoutInterner::with_capacity(128, target),
@@ -831,6 +925,11 @@ impl std::fmt::Display for ModuleTiming {
         report_timing(f, "Canonicalize", module_timing.canonicalize)?;
         report_timing(f, "Constrain", module_timing.constrain)?;
         report_timing(f, "Solve", module_timing.solve)?;
+        writeln!(
+            f,
+            "        {:9}      type variables",
+            module_timing.type_variables
+        )?;
         report_timing(
             f,
             "Find Specializations",
@@ -862,11 +961,13 @@ enum BuildTask<'a> {
         module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
         shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
         ident_ids_by_module: SharedIdentIdsByModule,
+        defines: Defines,
     },
     Parse {
         header: ModuleHeader<'a>,
         module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
         ident_ids_by_module: SharedIdentIdsByModule,
+        doc_tests: bool,
     },
     CanonicalizeAndConstrain {
         parsed: ParsedModule<'a>,
@@ -877,6 +978,7 @@ enum BuildTask<'a> {
         abilities_store: PendingAbilitiesStore,
         exposed_module_ids: &'a [ModuleId],
         skip_constraint_gen: bool,
+        report_expects_with_dbg: bool,
     },
     Solve {
         module: Module,
@@ -911,6 +1013,7 @@ enum BuildTask<'a> {
         derived_module: SharedDerivedModule,
         expectations: Option<Expectations>,
         build_expects: bool,
+        instrument_coverage: bool,
     },
     MakeSpecializations {
         module_id: ModuleId,
@@ -924,6 +1027,7 @@ enum BuildTask<'a> {
         world_abilities: WorldAbilities,
         derived_module: SharedDerivedModule,
         expectations: Option<Expectations>,
+        instrument_coverage: bool,
     },
 }
 
@@ -960,6 +1064,12 @@ pub enum LoadingProblem<'a> {
         source: &'a [u8],
         region: Region,
     },
+    UnknownPackageTarget {
+        filename: PathBuf,
+        module_id: ModuleId,
+        source: &'a [u8],
+        region: Region,
+    },
 
     ErrJoiningWorkerThreads,
     TriedToImportAppModule,
@@ -971,6 +1081,8 @@ pub enum LoadingProblem<'a> {
     IncorrectModuleName(FileError<'a, IncorrectModuleName<'a>>),
     CouldNotFindCacheDir,
     ChannelProblem(ChannelProblem),
+    /// The load was stopped through its [CancellationToken].
+    Cancelled,
 }
 
 #[derive(Debug)]
@@ -1002,6 +1114,95 @@ pub enum Phases {
 
 type MsgSender<'a> = Sender<Msg<'a>>;
 
+/// Hands the problems of a module that was just solved to the problem sink.
+fn report_to_problem_sink(
+    state: &State,
+    problem_sink: &ProblemSink,
+    module_id: ModuleId,
+    ident_ids: &IdentIds,
+) {
+    let can_problems = state
+        .module_cache
+        .can_problems
+        .get(&module_id)
+        .map_or(&[][..], Vec::as_slice);
+    let type_problems = state
+        .module_cache
+        .type_problems
+        .get(&module_id)
+        .map_or(&[][..], Vec::as_slice);
+
+    if can_problems.is_empty() && type_problems.is_empty() {
+        return;
+    }
+
+    let (path, source) = match state.module_cache.sources.get(&module_id) {
+        Some(source) => source,
+        None => return,
+    };
+
+    // The problems can mention symbols of this module and any module it depends on, whose ident
+    // IDs may still be waiting in the typechecked cache.
+    let mut all_ident_ids = state.constrained_ident_ids.clone();
+
+    for (id, checked) in state.module_cache.typechecked.iter() {
+        if all_ident_ids.get(id).is_none() {
+            all_ident_ids.insert(*id, checked.ident_ids.clone());
+        }
+    }
+
+    all_ident_ids.insert(module_id, ident_ids.clone());
+
+    let interns = Interns {
+        module_ids: state.arc_modules.lock().clone().into_module_ids(),
+        all_ident_ids,
+    };
+
+    problem_sink.report_module(
+        module_id,
+        path,
+        source,
+        &interns,
+        can_problems,
+        type_problems,
+        state.render,
+        &state.palette,
+    );
+}
+
+/// Reports the uses of builtins whose modules the platform lists as unavailable, in the modules
+/// of the app's package. The platform header and the app's modules can arrive in either order, so
+/// this runs whenever either does, and only once the platform is known.
+fn report_unavailable_builtins(state: &mut State) {
+    let unavailable = match &state.platform_data {
+        Some(platform_data) => &platform_data.unavailable,
+        None => return,
+    };
+
+    let module_ids = state.arc_modules.lock();
+
+    for (module_id, lookups) in state.module_cache.builtin_lookups.drain() {
+        if module_ids.package_eq(module_id, state.root_id) != Some(true) {
+            continue;
+        }
+
+        let problems = state
+            .module_cache
+            .can_problems
+            .entry(module_id)
+            .or_default();
+
+        for Loc { region, value } in lookups {
+            if unavailable.contains(&value.module_id()) {
+                problems.push(roc_problem::can::Problem::UnavailableBuiltin {
+                    symbol: value,
+                    region,
+                });
+            }
+        }
+    }
+}
+
 /// Add a task to the queue, and notify all the listeners.
 fn enqueue_task<'a>(
     injector: &Injector<BuildTask<'a>>,
@@ -1056,7 +1257,7 @@ pub fn load_and_typecheck_str<'a>(
 ) -> Result<LoadedModule, LoadingProblem<'a>> {
     use LoadResult::*;
 
-    let load_start = LoadStart::from_str(arena, filename, source, roc_cache_dir, src_dir)?;
+    let load_start = LoadStart::from_str(arena, filename, source, target, roc_cache_dir, src_dir)?;
 
     // this function is used specifically in the case
     // where we want to regenerate the cached data
@@ -1069,6 +1270,11 @@ pub fn load_and_typecheck_str<'a>(
         threading,
         exec_mode: ExecutionMode::Check,
         function_kind,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Defines::default(),
     };
 
     match load(
@@ -1104,6 +1310,7 @@ impl<'a> LoadStart<'a> {
     pub fn from_path(
         arena: &'a Bump,
         filename: PathBuf,
+        target: Target,
         render: RenderTarget,
         roc_cache_dir: RocCacheDir<'_>,
         palette: Palette,
@@ -1126,6 +1333,7 @@ impl<'a> LoadStart<'a> {
                 Arc::clone(&arc_modules),
                 Arc::clone(&ident_ids_by_module),
                 roc_cache_dir,
+                target,
                 root_start_time,
             );
 
@@ -1164,6 +1372,7 @@ impl<'a> LoadStart<'a> {
         arena: &'a Bump,
         filename: PathBuf,
         src: &'a str,
+        target: Target,
         roc_cache_dir: RocCacheDir<'_>,
         mut src_dir: PathBuf,
     ) -> Result<Self, LoadingProblem<'a>> {
@@ -1186,6 +1395,7 @@ impl<'a> LoadStart<'a> {
                 Arc::clone(&arc_modules),
                 Arc::clone(&ident_ids_by_module),
                 roc_cache_dir,
+                target,
                 root_start_time,
             )?;
 
@@ -1338,6 +1548,11 @@ pub fn load<'a>(
             load_config.render,
             load_config.palette,
             load_config.exec_mode,
+            load_config.optimize_ir,
+            load_config.report_expects_with_dbg,
+            load_config.cancellation.unwrap_or_default(),
+            load_config.problem_sink,
+            load_config.defines,
             roc_cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
@@ -1351,6 +1566,11 @@ pub fn load<'a>(
             load_config.palette,
             threads,
             load_config.exec_mode,
+            load_config.optimize_ir,
+            load_config.report_expects_with_dbg,
+            load_config.cancellation.unwrap_or_default(),
+            load_config.problem_sink,
+            load_config.defines,
             roc_cache_dir,
         ),
     }
@@ -1367,6 +1587,11 @@ pub fn load_single_threaded<'a>(
     render: RenderTarget,
     palette: Palette,
     exec_mode: ExecutionMode,
+    optimize_ir: bool,
+    report_expects_with_dbg: bool,
+    cancellation: CancellationToken,
+    problem_sink: Option<ProblemSink>,
+    defines: Defines,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1401,6 +1626,12 @@ pub fn load_single_threaded<'a>(
         palette,
         number_of_workers,
         exec_mode,
+        optimize_ir,
+        report_expects_with_dbg,
+        cancellation.clone(),
+        problem_sink,
+        defines,
+        roc_cache_dir,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1443,6 +1674,7 @@ pub fn load_single_threaded<'a>(
             &src_dir,
             roc_cache_dir,
             target,
+            &cancellation,
         );
 
         match control_flow {
@@ -1464,7 +1696,15 @@ fn state_thread_step<'a>(
     msg_tx: &crossbeam::channel::Sender<Msg<'a>>,
     msg_rx: &crossbeam::channel::Receiver<Msg<'a>>,
 ) -> Result<ControlFlow<LoadResult<'a>, State<'a>>, LoadingProblem<'a>> {
-    match msg_rx.try_recv() {
+    let received = msg_rx.try_recv();
+
+    // Checked after receiving, so that a message from a task that stopped early because of the
+    // cancellation is never used.
+    if state.cancellation.is_cancelled() {
+        return Err(LoadingProblem::Cancelled);
+    }
+
+    match received {
         Ok(msg) => {
             match msg {
                 Msg::FinishedAllTypeChecking {
@@ -1707,6 +1947,20 @@ pub fn report_loading_problem(
             source,
             render,
         ),
+        LoadingProblem::UnknownPackageTarget {
+            filename,
+            module_id,
+            source,
+            region,
+        } => to_unknown_package_target_report(
+            module_ids,
+            IdentIds::exposed_builtins(0),
+            module_id,
+            filename,
+            region,
+            source,
+            render,
+        ),
         err => todo!("Loading error: {:?}", err),
     }
 }
@@ -1722,6 +1976,11 @@ fn load_multi_threaded<'a>(
     palette: Palette,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    optimize_ir: bool,
+    report_expects_with_dbg: bool,
+    cancellation: CancellationToken,
+    problem_sink: Option<ProblemSink>,
+    defines: Defines,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1771,6 +2030,12 @@ fn load_multi_threaded<'a>(
         palette,
         num_workers,
         exec_mode,
+        optimize_ir,
+        report_expects_with_dbg,
+        cancellation.clone(),
+        problem_sink,
+        defines,
+        roc_cache_dir,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -1804,7 +2069,7 @@ fn load_multi_threaded<'a>(
     let mut sources_recorded = MutMap::default();
     let mut interns_recorded = Interns::default();
 
-    {
+    let result = {
         let thread_result = thread::scope(|thread_scope| {
             let mut worker_listeners =
                 bumpalo::collections::Vec::with_capacity_in(num_workers, arena);
@@ -1821,6 +2086,7 @@ fn load_multi_threaded<'a>(
                 // (since other threads need to reference it too). Same with src_dir.
                 let injector = &injector;
                 let src_dir = &src_dir;
+                let cancellation = &cancellation;
 
                 // Record this thread's handle so the main thread can join it later.
                 let res_join_handle = thread_scope
@@ -1828,7 +2094,7 @@ fn load_multi_threaded<'a>(
                     .stack_size(EXPANDED_STACK_SIZE)
                     .spawn(move |_| {
                         // will process messages until we run out
-                        worker_task(
+                        let result = worker_task(
                             worker_arena,
                             worker,
                             injector,
@@ -1838,7 +2104,12 @@ fn load_multi_threaded<'a>(
                             src_dir,
                             roc_cache_dir,
                             target,
-                        )
+                            cancellation,
+                        );
+
+                        arena_stats::finish_worker(worker_arena);
+
+                        result
                     });
 
                 res_join_handle.unwrap_or_else(|_| {
@@ -1864,7 +2135,7 @@ fn load_multi_threaded<'a>(
                         // occurred on that thread), we want to continue shutting down
                         // the others regardless.
                         if listener.send(WorkerMsg::Shutdown).is_err() {
-                            log!("There was an error trying to shutdown a worker thread. One reason this can happen is if the thread panicked.");
+                            roc_log!("load", Warn, "There was an error trying to shutdown a worker thread. One reason this can happen is if the thread panicked.");
                         }
                     }
                 };
@@ -1954,7 +2225,11 @@ fn load_multi_threaded<'a>(
                 .to_string(),
             ))
         })
-    }
+    };
+
+    arena_stats::report(arena);
+
+    result
 }
 
 fn worker_task_step<'a>(
@@ -1967,6 +2242,7 @@ fn worker_task_step<'a>(
     src_dir: &Path,
     roc_cache_dir: RocCacheDir<'_>,
     target: Target,
+    cancellation: &CancellationToken,
 ) -> Result<ControlFlow<(), ()>, LoadingProblem<'a>> {
     match worker_msg_rx.try_recv() {
         Ok(msg) => {
@@ -1996,6 +2272,7 @@ fn worker_task_step<'a>(
                             msg_tx.clone(),
                             roc_cache_dir,
                             target,
+                            cancellation,
                         );
 
                         match result {
@@ -2041,6 +2318,7 @@ fn worker_task<'a>(
     src_dir: &Path,
     roc_cache_dir: RocCacheDir<'_>,
     target: Target,
+    cancellation: &CancellationToken,
 ) -> Result<(), LoadingProblem<'a>> {
     // Keep listening until we receive a Shutdown msg
     for msg in worker_msg_rx.iter() {
@@ -2095,6 +2373,7 @@ fn worker_task<'a>(
                         msg_tx.clone(),
                         roc_cache_dir,
                         target,
+                        cancellation,
                     );
 
                     match result {
@@ -2288,12 +2567,37 @@ fn update<'a>(
                         }
                     };
 
+                    let requirement = PackageRequirement {
+                        shorthand,
+                        package: package_str,
+                        root_module: shorthand_path.root_module().to_path_buf(),
+                        required_by: header.module_path.clone(),
+                    };
+
+                    // Shorthands resolve the same way in every module, so two modules must not
+                    // require different packages (e.g. two versions of one package) under one.
+                    if let Some(existing) = shorthands.get(shorthand) {
+                        if existing.root_module() != requirement.root_module {
+                            let module_ids = state.arc_modules.lock().clone().into_module_ids();
+                            let buf = to_conflicting_packages_report(
+                                module_ids,
+                                state.root_id,
+                                &state.package_requirements,
+                                &requirement,
+                                state.render,
+                            );
+
+                            return Err(LoadingProblem::FormattedReport(buf));
+                        }
+                    }
+
                     log!(
                         "New package shorthand: {:?} => {:?}",
                         shorthand,
                         shorthand_path
                     );
 
+                    state.package_requirements.push(requirement);
                     shorthands.insert(shorthand, shorthand_path);
                 }
 
@@ -2316,6 +2620,7 @@ fn update<'a>(
                         config_shorthand,
                         provides,
                         exposes_ids,
+                        unavailable,
                         ..
                     } => {
                         work.extend(state.dependencies.notify_package(config_shorthand));
@@ -2343,11 +2648,32 @@ fn update<'a>(
                         if state.opt_platform_shorthand == Some(config_shorthand) {
                             debug_assert!(state.platform_data.is_none());
 
+                            // Names that aren't builtin modules are reported when the platform
+                            // module is canonicalized.
+                            let unavailable = {
+                                let module_ids = state.arc_modules.lock();
+
+                                unavailable
+                                    .iter()
+                                    .filter_map(|loc_name| {
+                                        module_ids.get_id(&PQModuleName::Unqualified(
+                                            loc_name.value.into(),
+                                        ))
+                                    })
+                                    .filter(|module_id| module_id.is_builtin())
+                                    .collect()
+                            };
+
                             state.platform_data = Some(PlatformData {
                                 module_id: header.module_id,
                                 provides,
                                 is_prebuilt,
+                                unavailable,
                             });
+
+                            // Reporting needs all of the state, so let go of the shorthands first
+                            drop(shorthands);
+                            report_unavailable_builtins(&mut state);
                         }
 
                         if header.is_root_module {
@@ -2476,6 +2802,9 @@ fn update<'a>(
             constrained_module,
             canonicalization_problems,
             module_docs,
+            symbol_index,
+            calls,
+            builtin_lookups,
         }) => {
             let module_id = constrained_module.module.module_id;
             log!("generated constraints for {:?}", module_id);
@@ -2484,10 +2813,26 @@ fn update<'a>(
                 .can_problems
                 .insert(module_id, canonicalization_problems);
 
+            if !builtin_lookups.is_empty() {
+                state
+                    .module_cache
+                    .builtin_lookups
+                    .insert(module_id, builtin_lookups);
+
+                report_unavailable_builtins(&mut state);
+            }
+
             if let Some(docs) = module_docs {
                 state.module_cache.documentation.insert(module_id, docs);
             }
 
+            state
+                .module_cache
+                .symbol_index
+                .insert(module_id, symbol_index);
+
+            state.module_cache.calls.insert(module_id, calls);
+
             state
                 .module_cache
                 .exposed_imports
@@ -2538,6 +2883,10 @@ fn update<'a>(
                 .module_cache
                 .type_problems
                 .insert(module_id, solved_module.problems);
+
+            if let Some(problem_sink) = &state.problem_sink {
+                report_to_problem_sink(&state, problem_sink, module_id, &ident_ids);
+            }
             state
                 .module_cache
                 .exposes
@@ -2575,7 +2924,7 @@ fn update<'a>(
 
             let add_to_host_exposed = is_host_exposed &&
                 // During testing, we don't need to expose anything to the host.
-                !matches!(state.exec_mode, ExecutionMode::Test);
+                !state.exec_mode.is_test();
 
             if add_to_host_exposed {
                 state.exposed_to_host.top_level_values.extend(
@@ -2916,6 +3265,61 @@ fn update<'a>(
 
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
+                    // Anything nothing else calls yet is an entry point, which must survive the
+                    // optimizations below even if they stop other code from calling it.
+                    let mut entry_points =
+                        roc_mono::dead_code::unreferenced_procs(&state.procedures);
+                    entry_points.extend(state.exposed_to_host.top_level_values.keys());
+                    entry_points.extend(state.exposed_to_host.getters.iter());
+                    entry_points.extend(
+                        state
+                            .host_exposed_lambda_sets
+                            .iter()
+                            .flat_map(|(name, _, hels)| [name.name(), hels.symbol]),
+                    );
+                    entry_points.extend(state.toplevel_expects.values().flat_map(|expects| {
+                        expects.pure.keys().chain(expects.fx.keys()).copied()
+                    }));
+
+                    if state.optimize_ir {
+                        roc_mono::fold_constants::fold_constants(
+                            arena,
+                            &layout_interner,
+                            module_id,
+                            ident_ids,
+                            &mut state.procedures,
+                        );
+
+                        debug_print_ir!(
+                            state,
+                            &layout_interner,
+                            ROC_PRINT_IR_AFTER_CONSTANT_FOLDING
+                        );
+
+                        // Fusion works on the `ListWalk` lowlevel, so `List.walk` is turned into
+                        // it first.
+                        roc_mono::list_walk::walk_in_bitcode(
+                            arena,
+                            &layout_interner,
+                            &mut state.procedures,
+                        );
+
+                        roc_mono::list_fusion::fuse_list_calls(
+                            arena,
+                            &mut layout_interner,
+                            module_id,
+                            ident_ids,
+                            &mut state.procedures,
+                        );
+
+                        debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_LIST_FUSION);
+                    }
+
+                    roc_mono::dead_code::remove_unreachable_procs(
+                        &mut state.procedures,
+                        entry_points,
+                    );
+
                     roc_mono::tail_recursion::apply_trmc(
                         arena,
                         &mut layout_interner,
@@ -3073,6 +3477,32 @@ fn update<'a>(
             // TODO report the error and continue instead of erroring out
             Err(problem)
         }
+        Msg::RunawaySpecialization {
+            module_id,
+            ident_ids,
+            cycle,
+        } => {
+            // the module's idents were taken out to specialize it; the cycle names some of them
+            state.constrained_ident_ids.insert(module_id, ident_ids);
+
+            let module_ids = state.arc_modules.lock().clone().into_module_ids();
+            let filename = state
+                .module_cache
+                .sources
+                .get(&module_id)
+                .map(|(path, _)| path.clone())
+                .unwrap_or_default();
+
+            let buf = to_runaway_specialization_report(
+                module_ids,
+                state.constrained_ident_ids,
+                module_id,
+                cycle,
+                filename,
+                state.render,
+            );
+            Err(LoadingProblem::FormattedReport(buf))
+        }
         Msg::FinishedAllTypeChecking { .. } => {
             unreachable!();
         }
@@ -3122,12 +3552,12 @@ fn finish_specialization<'a>(
     exposed_to_host: ExposedToHost,
     module_expectations: VecMap<ModuleId, Expectations>,
 ) -> Result<MonomorphizedModule<'a>, LoadingProblem<'a>> {
-    if false {
-        println!(
-            "total Type clones: {} ",
-            roc_types::types::get_type_clone_count()
-        );
-    }
+    roc_log!(
+        "load",
+        Trace,
+        "total Type clones: {}",
+        roc_types::types::get_type_clone_count()
+    );
     let module_ids = Arc::try_unwrap(state.arc_modules)
         .unwrap_or_else(|_| panic!("There were still outstanding Arc references to module_ids"))
         .into_inner()
@@ -3152,7 +3582,7 @@ fn finish_specialization<'a>(
     let entry_point = {
         let interns: &mut Interns = &mut interns;
         match state.exec_mode {
-            ExecutionMode::Test => Ok(EntryPoint::Test),
+            ExecutionMode::Test(_) => Ok(EntryPoint::Test),
             ExecutionMode::Executable | ExecutionMode::ExecutableIfCheck => {
                 use PlatformPath::*;
 
@@ -3349,6 +3779,8 @@ fn finish(
         sources,
         timings: state.timings,
         docs_by_module: documentation,
+        symbol_index: state.module_cache.symbol_index,
+        calls: state.module_cache.calls,
         abilities_store,
         exposed_imports: state.module_cache.exposed_imports,
         imports: state.module_cache.imports,
@@ -3372,8 +3804,9 @@ fn load_package_from_disk<'a>(
 
     match read_result {
         Ok(bytes_vec) => {
+            let bytes = source_bytes(filename, arena.alloc(bytes_vec))?;
+
             let parse_start = Instant::now();
-            let bytes = arena.alloc(bytes_vec);
             let parse_state = roc_parse::state::State::new(bytes);
             let parsed = roc_parse::module::parse_header(arena, parse_state.clone());
             let parse_header_duration = parse_start.elapsed();
@@ -3569,7 +4002,9 @@ fn load_module<'a>(
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     arc_shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
     roc_cache_dir: RocCacheDir<'_>,
+    target: Target,
     ident_ids_by_module: SharedIdentIdsByModule,
+    defines: &Defines,
 ) -> Result<HeaderOutput<'a>, LoadingProblem<'a>> {
     let module_start_time = Instant::now();
 
@@ -3616,6 +4051,36 @@ fn load_module<'a>(
         "Hash", ModuleId::HASH
         "Inspect", ModuleId::INSPECT
         "TotallyNotJson", ModuleId::JSON
+        "Random", ModuleId::RANDOM
+        "Duration", ModuleId::DURATION
+        "Date", ModuleId::DATE
+        "Time", ModuleId::TIME
+        "Order", ModuleId::ORDER
+        "Target", ModuleId::TARGET
+    }
+
+    // The app's `Defines` module is generated from the defines of the build rather than read
+    // from a file, so the same code can be built with different ones.
+    if let PQModuleName::Unqualified(name) = &module_name {
+        if name.as_str() == DEFINES_MODULE_NAME {
+            let src = arena.alloc_str(&defines.module_source());
+            let filename = src_dir.join(format!("{DEFINES_MODULE_NAME}.{ROC_FILE_EXTENSION}"));
+
+            return parse_header(
+                arena,
+                Duration::default(),
+                filename,
+                false,
+                None,
+                Some(module_name),
+                module_ids,
+                ident_ids_by_module,
+                src.as_bytes(),
+                roc_cache_dir,
+                target,
+                module_start_time,
+            );
+        }
     }
 
     let (filename, opt_shorthand) = module_name_to_path(src_dir, &module_name, arc_shorthands);
@@ -3629,6 +4094,7 @@ fn load_module<'a>(
         module_ids,
         ident_ids_by_module,
         roc_cache_dir,
+        target,
         module_start_time,
     )
 }
@@ -3670,6 +4136,41 @@ impl ShorthandPath {
     }
 }
 
+/// A package shorthand declared in the header of a module, e.g. `json: "https://…"`
+#[derive(Debug)]
+struct PackageRequirement<'a> {
+    shorthand: &'a str,
+    /// The URL or path of the package, as written in the header
+    package: &'a str,
+    root_module: PathBuf,
+    /// The path of the module whose header declared the shorthand
+    required_by: PathBuf,
+}
+
+/// The requirements that led to the given one, starting from one declared by the root module.
+fn requirement_chain<'r, 'a>(
+    requirements: &'r [PackageRequirement<'a>],
+    requirement: &'r PackageRequirement<'a>,
+) -> std::vec::Vec<&'r PackageRequirement<'a>> {
+    let mut chain = vec![requirement];
+
+    // Every requirement appears in the chain at most once, which also guards against cycles.
+    while chain.len() <= requirements.len() {
+        let current = chain.last().unwrap();
+
+        match requirements
+            .iter()
+            .find(|parent| parent.root_module == current.required_by)
+        {
+            Some(parent) => chain.push(parent),
+            None => break,
+        }
+    }
+
+    chain.reverse();
+    chain
+}
+
 fn module_name_to_path<'a>(
     src_dir: &Path,
     module_name: &PQModuleName<'a>,
@@ -3744,6 +4245,20 @@ struct HeaderOutput<'a> {
     opt_platform_shorthand: Option<&'a str>,
 }
 
+/// The source of a module without its UTF-8 byte order mark, if it has one. Source that isn't
+/// valid UTF-8, like a file saved as UTF-16, is reported here rather than handed to the parser.
+fn source_bytes<'a>(filename: &Path, src_bytes: &'a [u8]) -> Result<&'a [u8], LoadingProblem<'a>> {
+    let src_bytes = src_bytes.strip_prefix(UTF8_BOM).unwrap_or(src_bytes);
+
+    match std::str::from_utf8(src_bytes) {
+        Ok(_) => Ok(src_bytes),
+        Err(_) => Err(LoadingProblem::FileProblem {
+            filename: filename.to_path_buf(),
+            error: io::ErrorKind::InvalidData,
+        }),
+    }
+}
+
 fn ensure_roc_file<'a>(filename: &Path, src_bytes: &[u8]) -> Result<(), LoadingProblem<'a>> {
     match filename.extension() {
         Some(ext) => {
@@ -3784,8 +4299,11 @@ fn parse_header<'a>(
     ident_ids_by_module: SharedIdentIdsByModule,
     src_bytes: &'a [u8],
     roc_cache_dir: RocCacheDir<'_>,
+    target: Target,
     start_time: Instant,
 ) -> Result<HeaderOutput<'a>, LoadingProblem<'a>> {
+    let src_bytes = source_bytes(&filename, src_bytes)?;
+
     let parse_start = Instant::now();
     let parse_state = roc_parse::state::State::new(src_bytes);
     let parsed = roc_parse::module::parse_header(arena, parse_state.clone());
@@ -3880,6 +4398,26 @@ fn parse_header<'a>(
 
             let packages = unspace(arena, header.packages.value.items);
 
+            if let Some(unknown) = packages
+                .iter()
+                .filter_map(|package| package.value.target)
+                .find(|package_target| !is_package_target_name(package_target.name.value))
+            {
+                let mut module_ids = (*module_ids).lock();
+
+                let module_id = module_ids
+                    .get_or_insert(arena.alloc(PQModuleName::Unqualified(ModuleName::APP.into())));
+
+                return Err(LoadingProblem::UnknownPackageTarget {
+                    module_id,
+                    filename,
+                    source: src_bytes,
+                    region: unknown.name.region,
+                });
+            }
+
+            let packages = packages_for_target(arena, packages, target);
+
             let mut platform_shorthand = None;
 
             for package in packages.iter() {
@@ -4121,6 +4659,74 @@ fn load_packages<'a>(
     }
 }
 
+/// Pick the entry to use for each shorthand in an app's `packages`: the first one restricted to
+/// the target we're building for, or else the first one that isn't restricted to any target.
+fn packages_for_target<'a>(
+    arena: &'a Bump,
+    packages: &'a [Loc<PackageEntry<'a>>],
+    target: Target,
+) -> &'a [Loc<PackageEntry<'a>>] {
+    if packages
+        .iter()
+        .all(|package| package.value.target.is_none())
+    {
+        return packages;
+    }
+
+    let mut selected: bumpalo::collections::Vec<Loc<PackageEntry<'a>>> =
+        bumpalo::collections::Vec::with_capacity_in(packages.len(), arena);
+
+    for package in packages {
+        let shorthand = package.value.shorthand;
+
+        if selected
+            .iter()
+            .any(|chosen| chosen.value.shorthand == shorthand)
+        {
+            continue;
+        }
+
+        let for_target = packages.iter().find(|candidate| {
+            candidate.value.shorthand == shorthand
+                && candidate.value.target.map_or(false, |package_target| {
+                    package_target_matches(package_target.name.value, target)
+                })
+        });
+        let for_any_target = packages.iter().find(|candidate| {
+            candidate.value.shorthand == shorthand && candidate.value.target.is_none()
+        });
+
+        if let Some(chosen) = for_target.or(for_any_target) {
+            selected.push(*chosen);
+        }
+    }
+
+    selected.into_bump_slice()
+}
+
+const PACKAGE_TARGET_NAMES: &[&str] = &[
+    "aarch32", "aarch64", "wasm32", "x86_32", "x86_64", "linux", "macos", "windows",
+];
+
+fn is_package_target_name(name: &str) -> bool {
+    PACKAGE_TARGET_NAMES.contains(&name)
+}
+
+/// Whether the name in an `if` of a package entry (an architecture or operating system)
+/// describes the given target.
+fn package_target_matches(name: &str, target: Target) -> bool {
+    use roc_target::OperatingSystem;
+
+    let operating_system = match target.operating_system() {
+        OperatingSystem::Linux => "linux",
+        OperatingSystem::Mac => "macos",
+        OperatingSystem::Windows => "windows",
+        OperatingSystem::Freestanding => "freestanding",
+    };
+
+    name == target.architecture().to_string() || name == operating_system
+}
+
 /// Load a module by its filename
 fn load_filename<'a>(
     arena: &'a Bump,
@@ -4131,6 +4737,7 @@ fn load_filename<'a>(
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     ident_ids_by_module: SharedIdentIdsByModule,
     roc_cache_dir: RocCacheDir<'_>,
+    target: Target,
     module_start_time: Instant,
 ) -> Result<HeaderOutput<'a>, LoadingProblem<'a>> {
     let file_io_start = Instant::now();
@@ -4149,6 +4756,7 @@ fn load_filename<'a>(
             ident_ids_by_module,
             arena.alloc(bytes),
             roc_cache_dir,
+            target,
             module_start_time,
         ),
         Err(err) => Err(LoadingProblem::FileProblem {
@@ -4167,6 +4775,7 @@ fn load_from_str<'a>(
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     ident_ids_by_module: SharedIdentIdsByModule,
     roc_cache_dir: RocCacheDir<'_>,
+    target: Target,
     module_start_time: Instant,
 ) -> Result<HeaderOutput<'a>, LoadingProblem<'a>> {
     let file_io_start = Instant::now();
@@ -4183,6 +4792,7 @@ fn load_from_str<'a>(
         ident_ids_by_module,
         src.as_bytes(),
         roc_cache_dir,
+        target,
         module_start_time,
     )
 }
@@ -4574,6 +5184,15 @@ struct SolveResult {
 }
 
 #[allow(clippy::complexity)]
+fn max_type_variables() -> usize {
+    match env::var("ROC_MAX_TYPE_VARIABLES") {
+        Ok(env_str) => env_str
+            .parse::<usize>()
+            .unwrap_or(DEFAULT_MAX_TYPE_VARIABLES),
+        Err(_) => DEFAULT_MAX_TYPE_VARIABLES,
+    }
+}
+
 fn run_solve_solve(
     exposed_for_module: ExposedForModule,
     mut types: Types,
@@ -4584,6 +5203,7 @@ fn run_solve_solve(
     var_store: VarStore,
     module: Module,
     derived_module: SharedDerivedModule,
+    cancelled: &AtomicBool,
 
     #[cfg(debug_assertions)] checkmate: Option<roc_checkmate::Collector>,
 ) -> SolveResult {
@@ -4637,6 +5257,8 @@ fn run_solve_solve(
             pending_derives,
             exposed_by_module: &exposed_for_module.exposed_by_module,
             derived_module,
+            cancelled: Some(cancelled),
+            max_variables: Some(max_type_variables()),
             #[cfg(debug_assertions)]
             checkmate,
         };
@@ -4713,6 +5335,7 @@ fn run_solve<'a>(
     dep_idents: IdentIdsByModule,
     cached_types: CachedTypeState,
     derived_module: SharedDerivedModule,
+    cancelled: &AtomicBool,
 
     #[cfg(debug_assertions)] checkmate: Option<roc_checkmate::Collector>,
 ) -> Msg<'a> {
@@ -4741,6 +5364,7 @@ fn run_solve<'a>(
                     var_store,
                     module,
                     derived_module,
+                    cancelled,
                     //
                     #[cfg(debug_assertions)]
                     checkmate,
@@ -4772,6 +5396,7 @@ fn run_solve<'a>(
                 var_store,
                 module,
                 derived_module,
+                cancelled,
                 //
                 #[cfg(debug_assertions)]
                 checkmate,
@@ -4790,6 +5415,9 @@ fn run_solve<'a>(
         checkmate,
     } = solve_result;
 
+    let problems =
+        roc_solve::module::add_when_branch_bindings(problems, &decls, solved_subs.inner_mut());
+
     let exposed_types = roc_solve::module::exposed_types_storage_subs(
         module_id,
         &mut solved_subs,
@@ -4809,6 +5437,15 @@ fn run_solve<'a>(
     // Record the final timings
     let solve_end = Instant::now();
     module_timing.solve = solve_end.duration_since(solve_start);
+    module_timing.type_variables = solved_subs.inner().len();
+
+    roc_log!(
+        "solve",
+        Debug,
+        "{:?} solved with {} type variables",
+        module_id,
+        module_timing.type_variables
+    );
 
     // Send the subs to the main thread for processing,
     Msg::SolvedTypes {
@@ -4913,6 +5550,10 @@ fn build_platform_header<'a>(
         arena,
     );
     let requires_types = unspace(arena, header.requires.item.rigids.items);
+    let unavailable = match &header.unavailable {
+        Some(unavailable) => unspace(arena, unavailable.item.items),
+        None => &[],
+    };
 
     let header_type = HeaderType::Platform {
         // A config_shorthand of "" should be fine
@@ -4923,6 +5564,7 @@ fn build_platform_header<'a>(
         exposes: exposes.into_bump_slice(),
         requires,
         requires_types,
+        unavailable,
     };
 
     let info = HeaderInfo {
@@ -4948,6 +5590,7 @@ fn canonicalize_and_constrain<'a>(
     imported_abilities_state: PendingAbilitiesStore,
     parsed: ParsedModule<'a>,
     skip_constraint_gen: bool,
+    report_expects_with_dbg: bool,
     exposed_module_ids: &[ModuleId],
 ) -> CanAndCon {
     let canonicalize_start = Instant::now();
@@ -4992,6 +5635,7 @@ fn canonicalize_and_constrain<'a>(
         &symbols_from_requires,
         &mut var_store,
         opt_shorthand,
+        report_expects_with_dbg,
     );
 
     let mut types = Types::new();
@@ -5031,6 +5675,18 @@ fn canonicalize_and_constrain<'a>(
         })
     };
 
+    let symbol_index = crate::symbol_index::index_module(
+        module_id,
+        qualified_module_ids
+            .get_name(module_id)
+            .expect("home module not found"),
+        &module_output.scope.locals.ident_ids,
+        &parsed_defs_for_docs,
+        &module_output.exposed_symbols,
+    );
+
+    let calls = crate::call_graph::module_calls(&module_output.declarations);
+
     // _before has an underscore because it's unused in --release builds
     let _before = roc_types::types::get_type_clone_count();
 
@@ -5082,6 +5738,8 @@ fn canonicalize_and_constrain<'a>(
                         | ModuleId::SET
                         | ModuleId::HASH
                         | ModuleId::INSPECT
+                        | ModuleId::DURATION
+                        | ModuleId::DATE
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -5121,6 +5779,38 @@ fn canonicalize_and_constrain<'a>(
         constrained_module,
         canonicalization_problems: module_output.problems,
         module_docs,
+        symbol_index,
+        calls,
+        builtin_lookups: module_output.builtin_lookups,
+    }
+}
+
+/// Uncomment the doc comment code blocks with `expect`s in the body of a module, and return a
+/// parser state at the start of the new body. See [crate::doc_tests].
+fn parse_state_with_doc_tests<'a>(
+    arena: &'a Bump,
+    parse_state: roc_parse::state::State<'a>,
+) -> roc_parse::state::State<'a> {
+    let src = parse_state.original_bytes();
+    let (header, body) = src.split_at(parse_state.pos().offset as usize);
+
+    let body = match std::str::from_utf8(body) {
+        Ok(body) => crate::doc_tests::uncomment_doc_tests(body),
+        // Leave reporting invalid UTF-8 to the parser.
+        Err(_) => return parse_state,
+    };
+
+    let mut new_src = bumpalo::collections::Vec::with_capacity_in(src.len(), arena);
+    new_src.extend_from_slice(header);
+    new_src.extend_from_slice(body.as_bytes());
+
+    // Parse the (unchanged) header again, to get to the same position in the new source.
+    match roc_parse::module::parse_header(
+        arena,
+        roc_parse::state::State::new(new_src.into_bump_slice()),
+    ) {
+        Ok((_, new_state)) if new_state.pos() == parse_state.pos() => new_state,
+        _ => parse_state,
     }
 }
 
@@ -5129,11 +5819,16 @@ fn parse<'a>(
     header: ModuleHeader<'a>,
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     ident_ids_by_module: SharedIdentIdsByModule,
+    doc_tests: bool,
 ) -> Result<Msg<'a>, LoadingProblem<'a>> {
     let mut module_timing = header.module_timing;
     let parse_start = Instant::now();
-    let source = header.parse_state.original_bytes();
-    let parse_state = header.parse_state;
+    let parse_state = if doc_tests && !header.module_id.is_builtin() {
+        parse_state_with_doc_tests(arena, header.parse_state)
+    } else {
+        header.parse_state
+    };
+    let source = parse_state.original_bytes();
 
     let header_import_defs =
         roc_parse::ast::Module::header_imports_to_defs(arena, header.header_imports);
@@ -5409,6 +6104,8 @@ fn make_specializations<'a>(
     exposed_by_module: &ExposedByModule,
     derived_module: SharedDerivedModule,
     mut expectations: Option<Expectations>,
+    instrument_coverage: bool,
+    cancelled: &AtomicBool,
 ) -> Msg<'a> {
     let make_specializations_start = Instant::now();
     let mut update_mode_ids = UpdateModeIds::new();
@@ -5427,6 +6124,8 @@ fn make_specializations<'a>(
         exposed_by_module,
         derived_module: &derived_module,
         struct_indexing: UsageTrackingMap::default(),
+        instrument_coverage,
+        cancelled: Some(cancelled),
     };
 
     let mut procs = Procs::new_in(arena);
@@ -5446,13 +6145,22 @@ fn make_specializations<'a>(
     // TODO: for now this final specialization pass is sequential,
     // with no parallelization at all. We should try to parallelize
     // this, but doing so will require a redesign of Procs.
-    procs = roc_mono::ir::specialize_all(
+    procs = match roc_mono::ir::specialize_all(
         &mut mono_env,
         procs,
         specializations_we_must_make,
         procs_base.host_specializations,
         &mut layout_cache,
-    );
+    ) {
+        Ok(procs) => procs,
+        Err(RunawaySpecialization { cycle }) => {
+            return Msg::RunawaySpecialization {
+                module_id: home,
+                ident_ids,
+                cycle,
+            };
+        }
+    };
 
     let external_specializations_requested = procs.externals_we_need.clone();
     let (procedures, host_exposed_lambda_sets, restored_procs_base) =
@@ -5497,6 +6205,8 @@ fn build_pending_specializations<'a>(
     derived_module: SharedDerivedModule,
     mut expectations: Option<Expectations>,
     build_expects: bool,
+    instrument_coverage: bool,
+    cancelled: &AtomicBool,
 ) -> Msg<'a> {
     let find_specializations_start = Instant::now();
 
@@ -5530,6 +6240,8 @@ fn build_pending_specializations<'a>(
         exposed_by_module,
         derived_module: &derived_module,
         struct_indexing: UsageTrackingMap::default(),
+        instrument_coverage,
+        cancelled: Some(cancelled),
     };
 
     let layout_cache_snapshot = layout_cache.snapshot();
@@ -5546,8 +6258,13 @@ fn build_pending_specializations<'a>(
     for index in 0..declarations.len() {
         use roc_can::expr::DeclarationTag::*;
 
+        if mono_env.is_cancelled() {
+            break;
+        }
+
         let symbol = declarations.symbols[index].value;
         let expr_var = declarations.variables[index];
+        let _compiling = roc_region::compiling::enter(declarations.symbols[index].region);
 
         let is_host_exposed = exposed_to_host.top_level_values.contains_key(&symbol);
 
@@ -5621,6 +6338,8 @@ fn build_pending_specializations<'a>(
                             body_var: expr_var,
                             // This is a 0-arity thunk, so it cannot be recursive
                             is_self_recursive: false,
+                            coverage_region: None,
+                            region: Some(body.region),
                         };
 
                         procs_base.partial_procs.insert(symbol, proc);
@@ -5757,6 +6476,8 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    coverage_region: None,
+                    region: Some(body.region),
                 };
 
                 procs_base.partial_procs.insert(symbol, proc);
@@ -5825,6 +6546,8 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    coverage_region: None,
+                    region: Some(body.region),
                 };
 
                 // extend the region of the expect expression with the region of the preceding
@@ -5897,6 +6620,8 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    coverage_region: None,
+                    region: Some(body.region),
                 };
 
                 // extend the region of the expect expression with the region of the preceding
@@ -5973,6 +6698,7 @@ fn load_derived_partial_procs<'a>(
     exposed_by_module: &ExposedByModule,
     procs_base: &mut ProcsBase<'a>,
     world_abilities: &mut WorldAbilities,
+    instrument_coverage: bool,
 ) {
     debug_assert_eq!(home, ModuleId::DERIVED_GEN);
 
@@ -6011,6 +6737,8 @@ fn load_derived_partial_procs<'a>(
             exposed_by_module,
             derived_module,
             struct_indexing: UsageTrackingMap::default(),
+            instrument_coverage,
+            cancelled: None,
         };
 
         let partial_proc = match derived_expr {
@@ -6048,6 +6776,8 @@ fn load_derived_partial_procs<'a>(
                     body_var: derived_expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    coverage_region: None,
+                    region: None,
                 }
             }
         };
@@ -6068,6 +6798,43 @@ fn load_derived_partial_procs<'a>(
         load_derived_procs_end.duration_since(load_derived_procs_start);
 }
 
+/// What a task works on, to report if the compiler crashes while running it.
+fn task_context(task: &BuildTask<'_>) -> CompilerContext {
+    use BuildTask::*;
+
+    let (phase, module, module_path) = match task {
+        LoadModule { module_name, .. } => (
+            Phase::LoadHeader,
+            module_name.as_inner().as_str().to_string(),
+            None,
+        ),
+        Parse { header, .. } => (
+            Phase::Parse,
+            format!("{:?}", header.module_id),
+            Some(header.module_path.clone()),
+        ),
+        CanonicalizeAndConstrain { parsed, .. } => (
+            Phase::CanonicalizeAndConstrain,
+            format!("{:?}", parsed.module_id),
+            Some(parsed.module_path.clone()),
+        ),
+        Solve { module, .. } => (Phase::SolveTypes, format!("{:?}", module.module_id), None),
+        BuildPendingSpecializations { module_id, .. } => {
+            (Phase::FindSpecializations, format!("{module_id:?}"), None)
+        }
+        MakeSpecializations { module_id, .. } => {
+            (Phase::MakeSpecializations, format!("{module_id:?}"), None)
+        }
+    };
+
+    CompilerContext {
+        phase: CompilerPhase::Load(phase),
+        module,
+        module_path,
+        region: None,
+    }
+}
+
 fn run_task<'a>(
     task: BuildTask<'a>,
     arena: &'a Bump,
@@ -6075,15 +6842,27 @@ fn run_task<'a>(
     msg_tx: MsgSender<'a>,
     roc_cache_dir: RocCacheDir<'_>,
     target: Target,
+    cancellation: &CancellationToken,
 ) -> Result<(), LoadingProblem<'a>> {
     use BuildTask::*;
 
+    // The state thread stops the load when it sees the cancellation, so the result of the task
+    // would go unused.
+    if cancellation.is_cancelled() {
+        return Ok(());
+    }
+
+    let context = task_context(&task);
+    let _usage = arena_stats::measure(arena, &context);
+    let _context = ice::enter(context);
+
     let msg = match task {
         LoadModule {
             module_name,
             module_ids,
             shorthands,
             ident_ids_by_module,
+            defines,
         } => load_module(
             arena,
             src_dir,
@@ -6091,14 +6870,17 @@ fn run_task<'a>(
             module_ids,
             shorthands,
             roc_cache_dir,
+            target,
             ident_ids_by_module,
+            &defines,
         )
         .map(|HeaderOutput { msg, .. }| msg),
         Parse {
             header,
             module_ids,
             ident_ids_by_module,
-        } => parse(arena, header, module_ids, ident_ids_by_module),
+            doc_tests,
+        } => parse(arena, header, module_ids, ident_ids_by_module, doc_tests),
         CanonicalizeAndConstrain {
             parsed,
             qualified_module_ids,
@@ -6107,6 +6889,7 @@ fn run_task<'a>(
             aliases,
             abilities_store,
             skip_constraint_gen,
+            report_expects_with_dbg,
             exposed_module_ids,
         } => {
             let can_and_con = canonicalize_and_constrain(
@@ -6118,6 +6901,7 @@ fn run_task<'a>(
                 abilities_store,
                 parsed,
                 skip_constraint_gen,
+                report_expects_with_dbg,
                 exposed_module_ids,
             );
 
@@ -6156,6 +6940,7 @@ fn run_task<'a>(
             dep_idents,
             cached_subs,
             derived_module,
+            cancellation.flag(),
             //
             #[cfg(debug_assertions)]
             checkmate,
@@ -6174,6 +6959,7 @@ fn run_task<'a>(
             derived_module,
             expectations,
             build_expects,
+            instrument_coverage,
         } => Ok(build_pending_specializations(
             arena,
             solved_subs,
@@ -6190,6 +6976,8 @@ fn run_task<'a>(
             derived_module,
             expectations,
             build_expects,
+            instrument_coverage,
+            cancellation.flag(),
         )),
         MakeSpecializations {
             module_id,
@@ -6203,6 +6991,7 @@ fn run_task<'a>(
             exposed_by_module,
             derived_module,
             expectations,
+            instrument_coverage,
         } => Ok(make_specializations(
             arena,
             module_id,
@@ -6217,6 +7006,8 @@ fn run_task<'a>(
             &exposed_by_module,
             derived_module,
             expectations,
+            instrument_coverage,
+            cancellation.flag(),
         )),
     }?;
 
@@ -6286,6 +7077,142 @@ fn to_import_cycle_report(
     buf
 }
 
+fn to_runaway_specialization_report(
+    module_ids: ModuleIds,
+    all_ident_ids: IdentIdsByModule,
+    home: ModuleId,
+    cycle: Vec<Symbol>,
+    filename: PathBuf,
+    render: RenderTarget,
+) -> String {
+    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use ven_pretty::DocAllocator;
+
+    // cycle looks like Function, Call1, ..., CallN, where CallN calls Function again.
+    debug_assert!(!cycle.is_empty());
+    let function = cycle[0];
+
+    // We won't be printing any lines for this report, so this is okay.
+    let src_lines = &[];
+
+    let interns = Interns {
+        module_ids,
+        all_ident_ids,
+    };
+    let alloc = RocDocAllocator::new(src_lines, home, &interns);
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("I can't generate code for "),
+            alloc.symbol_foreign_qualified(function),
+            alloc.reflow(
+                " because every time around this chain of calls, it needs to be compiled again for a new type:",
+            ),
+        ]),
+        roc_reporting::report::cycle(
+            &alloc,
+            4,
+            alloc.symbol_foreign_qualified(function),
+            cycle
+                .into_iter()
+                .skip(1)
+                .chain([function])
+                .map(|symbol| alloc.symbol_foreign_qualified(symbol))
+                .collect(),
+        ),
+        alloc.reflow("This usually means a function calls itself with an argument whose type contains the type of its own argument, like a list of it. Can you change the function so that the type of the argument stays the same?"),
+    ]);
+
+    let report = Report {
+        filename,
+        doc,
+        title: "RUNAWAY SPECIALIZATION".to_string(),
+        severity: Severity::RuntimeError,
+    };
+
+    let mut buf = String::new();
+    let palette = DEFAULT_PALETTE;
+    report.render(render, &mut buf, &alloc, &palette);
+    buf
+}
+
+fn to_conflicting_packages_report<'a>(
+    module_ids: ModuleIds,
+    home: ModuleId,
+    requirements: &[PackageRequirement<'a>],
+    conflict: &PackageRequirement<'a>,
+    render: RenderTarget,
+) -> String {
+    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use ven_pretty::DocAllocator;
+
+    let existing = requirements
+        .iter()
+        .find(|requirement| requirement.shorthand == conflict.shorthand)
+        .expect("a conflicting shorthand must have been required before");
+
+    // We won't be printing any lines for this report, so this is okay.
+    let src_lines = &[];
+
+    let interns = Interns {
+        module_ids,
+        all_ident_ids: IdentIds::exposed_builtins(0),
+    };
+    let alloc = RocDocAllocator::new(src_lines, home, &interns);
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("The "),
+            alloc.keyword(conflict.shorthand),
+            alloc.reflow(" package shorthand refers to two different packages. It is required through this chain of package headers:"),
+        ]),
+        requirement_chain_doc(&alloc, requirements, existing),
+        alloc.reflow("but also through this one:"),
+        requirement_chain_doc(&alloc, requirements, conflict),
+        alloc.concat([
+            alloc.reflow("These are probably different versions of the same package. I can't pick one of them for you, because a module written for one version may not work with the other! Make all of these headers use the same URL for "),
+            alloc.keyword(conflict.shorthand),
+            alloc.reflow(", or give the other package a different shorthand."),
+        ]),
+    ]);
+
+    let report = Report {
+        filename: conflict.required_by.clone(),
+        doc,
+        title: "CONFLICTING PACKAGES".to_string(),
+        severity: Severity::RuntimeError,
+    };
+
+    let mut buf = String::new();
+    let palette = DEFAULT_PALETTE;
+    report.render(render, &mut buf, &alloc, &palette);
+    buf
+}
+
+fn requirement_chain_doc<'b>(
+    alloc: &'b roc_reporting::report::RocDocAllocator<'b>,
+    requirements: &'b [PackageRequirement<'b>],
+    requirement: &'b PackageRequirement<'b>,
+) -> roc_reporting::report::RocDocBuilder<'b> {
+    use ven_pretty::DocAllocator;
+
+    let chain = requirement_chain(requirements, requirement);
+    let root = chain[0].required_by.display().to_string();
+
+    let steps = chain.into_iter().map(|requirement| {
+        alloc.concat([
+            alloc.text("→ "),
+            alloc.keyword(requirement.shorthand),
+            alloc.text(": "),
+            alloc.string(format!("{:?}", requirement.package)),
+        ])
+    });
+
+    alloc
+        .stack(std::iter::once(alloc.string(root)).chain(steps))
+        .indent(4)
+}
+
 fn to_incorrect_module_name_report<'a>(
     module_ids: ModuleIds,
     all_ident_ids: IdentIdsByModule,
@@ -6425,6 +7352,54 @@ fn to_multiple_platform_packages_report(
     buf
 }
 
+fn to_unknown_package_target_report(
+    module_ids: ModuleIds,
+    all_ident_ids: IdentIdsByModule,
+    module_id: ModuleId,
+    filename: PathBuf,
+    region: Region,
+    src: &[u8],
+    render: RenderTarget,
+) -> String {
+    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use ven_pretty::DocAllocator;
+
+    // SAFETY: if the module was not UTF-8, that would be reported as a parsing problem, rather
+    // than an unknown package target problem (the latter can happen only after parsing).
+    let src = unsafe { from_utf8_unchecked(src) };
+    let src_lines = src.lines().collect::<Vec<_>>();
+    let lines = LineInfo::new(src);
+
+    let interns = Interns {
+        module_ids,
+        all_ident_ids,
+    };
+    let alloc = RocDocAllocator::new(&src_lines, module_id, &interns);
+
+    let doc = alloc.stack([
+        alloc.reflow("This package is restricted to a target I don't know:"),
+        alloc.region(lines.convert_region(region)),
+        alloc.reflow(
+            "A package can be restricted to one of these architectures or operating systems:",
+        ),
+        alloc
+            .stack(PACKAGE_TARGET_NAMES.iter().map(|name| alloc.keyword(name)))
+            .indent(4),
+    ]);
+
+    let report = Report {
+        filename,
+        doc,
+        title: "UNKNOWN TARGET".to_string(),
+        severity: Severity::RuntimeError,
+    };
+
+    let mut buf = String::new();
+    let palette = DEFAULT_PALETTE;
+    report.render(render, &mut buf, &alloc, &palette);
+    buf
+}
+
 fn to_parse_problem_report<'a>(
     problem: FileError<'a, SyntaxError<'a>>,
     mut module_ids: ModuleIds,
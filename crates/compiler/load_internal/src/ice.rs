@@ -0,0 +1,83 @@
+//! What the compiler is working on, so that a crash can be reported along with it.
+//!
+//! Each thread that runs build tasks records the task it is running, and the backend records the
+//! code generation and linking that follow the load. A panic hook can then read the context of the
+//! thread that panicked with [`current`], before the stack unwinds.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::path::PathBuf;
+
+use roc_region::all::Region;
+
+use crate::work::Phase;
+
+/// A phase of the load, or one of the backend's after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompilerPhase {
+    Load(Phase),
+    Codegen,
+    Link,
+}
+
+impl fmt::Display for CompilerPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompilerPhase::Load(phase) => write!(f, "{phase:?}"),
+            CompilerPhase::Codegen => write!(f, "Codegen"),
+            CompilerPhase::Link => write!(f, "Link"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompilerContext {
+    pub phase: CompilerPhase,
+    pub module: String,
+    /// The source file of the module, if known at this phase
+    pub module_path: Option<PathBuf>,
+    /// The def or function being compiled in the module, if the phase records it. Only filled in
+    /// by [`current`].
+    pub region: Option<Region>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CompilerContext>> = const { RefCell::new(None) };
+}
+
+/// The context of the task running on this thread, if any.
+pub fn current() -> Option<CompilerContext> {
+    CURRENT.with(|current| {
+        current.borrow().clone().map(|context| CompilerContext {
+            region: roc_region::compiling::current(),
+            ..context
+        })
+    })
+}
+
+/// Records the context until the returned guard is dropped. The region starts out unknown, so a
+/// region left behind by an earlier task is never blamed.
+pub fn enter(context: CompilerContext) -> ContextGuard {
+    let previous = CURRENT.with(|current| current.replace(Some(context)));
+    let previous_region = roc_region::compiling::set(None);
+
+    ContextGuard {
+        previous,
+        previous_region,
+    }
+}
+
+#[must_use]
+pub struct ContextGuard {
+    previous: Option<CompilerContext>,
+    previous_region: Option<Region>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        roc_region::compiling::set(self.previous_region);
+    }
+}
@@ -4,10 +4,18 @@
 #![allow(clippy::large_enum_variant)]
 
 use roc_module::symbol::ModuleId;
+mod arena_stats;
+pub mod call_graph;
+pub mod cancellation;
+pub mod defines;
+mod doc_tests;
 pub mod docs;
 pub mod file;
+pub mod ice;
 pub mod module;
 mod module_cache;
+pub mod problem_sink;
+pub mod symbol_index;
 mod work;
 
 #[cfg(target_family = "wasm")]
@@ -27,4 +35,10 @@ pub const BUILTIN_MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::HASH, "Hash"),
     (ModuleId::INSPECT, "Inspect"),
     (ModuleId::JSON, "TotallyNotJson"),
+    (ModuleId::RANDOM, "Random"),
+    (ModuleId::DURATION, "Duration"),
+    (ModuleId::DATE, "Date"),
+    (ModuleId::TIME, "Time"),
+    (ModuleId::ORDER, "Order"),
+    (ModuleId::TARGET, "Target"),
 ];
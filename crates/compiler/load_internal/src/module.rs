@@ -1,4 +1,6 @@
+use crate::call_graph::Call;
 use crate::docs::ModuleDocumentation;
+use crate::symbol_index::IndexedSymbol;
 use roc_can::constraint::{Constraint as ConstraintSoa, Constraints};
 use roc_can::expr::{DbgLookup, ExpectLookup};
 use roc_can::{
@@ -46,6 +48,8 @@ pub struct LoadedModule {
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub docs_by_module: VecMap<ModuleId, ModuleDocumentation>,
+    pub symbol_index: MutMap<ModuleId, Vec<IndexedSymbol>>,
+    pub calls: MutMap<ModuleId, Vec<Call>>,
     pub abilities_store: AbilitiesStore,
     pub typechecked: MutMap<ModuleId, CheckedModule>,
 
@@ -247,6 +251,8 @@ pub struct ModuleTiming {
     pub canonicalize: Duration,
     pub constrain: Duration,
     pub solve: Duration,
+    /// How many type variables the module's Subs held after solving
+    pub type_variables: usize,
     pub find_specializations: Duration,
     // indexed by make specializations pass
     pub make_specializations: Vec<Duration>,
@@ -266,6 +272,7 @@ impl ModuleTiming {
             canonicalize: Duration::default(),
             constrain: Duration::default(),
             solve: Duration::default(),
+            type_variables: 0,
             find_specializations: Duration::default(),
             make_specializations: Vec::with_capacity(2),
             start_time,
@@ -286,6 +293,7 @@ impl ModuleTiming {
             canonicalize,
             constrain,
             solve,
+            type_variables: _,
             find_specializations,
             make_specializations,
             start_time,
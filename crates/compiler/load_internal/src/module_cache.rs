@@ -1,15 +1,17 @@
+use crate::call_graph::Call;
 use crate::docs::ModuleDocumentation;
 use crate::module::{
     CheckedModule, ConstrainedModule, FoundSpecializationsModule, LateSpecializationsModule,
     ModuleHeader, ParsedModule, TypeCheckedModule,
 };
+use crate::symbol_index::IndexedSymbol;
 use roc_can::abilities::PendingAbilitiesStore;
 use roc_collections::{MutMap, MutSet, VecMap};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{ModuleId, PQModuleName, Symbol};
 use roc_mono::ir::ExternalSpecializations;
 use roc_problem::Severity;
-use roc_region::all::Region;
+use roc_region::all::{Loc, Region};
 use roc_solve_problem::TypeError;
 use roc_types::subs::Variable;
 use roc_types::types::Alias;
@@ -39,6 +41,10 @@ pub(crate) struct ModuleCache<'a> {
     pub(crate) exposed_imports: MutMap<ModuleId, MutMap<Symbol, Region>>,
     pub(crate) top_level_thunks: MutMap<ModuleId, MutSet<Symbol>>,
     pub(crate) documentation: VecMap<ModuleId, ModuleDocumentation>,
+    pub(crate) symbol_index: MutMap<ModuleId, Vec<IndexedSymbol>>,
+    pub(crate) calls: MutMap<ModuleId, Vec<Call>>,
+    /// Uses of builtins that haven't been checked against the platform's unavailable modules yet
+    pub(crate) builtin_lookups: MutMap<ModuleId, Vec<Loc<Symbol>>>,
     pub(crate) can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub(crate) type_problems: MutMap<ModuleId, Vec<TypeError>>,
 
@@ -92,6 +98,12 @@ impl Default for ModuleCache<'_> {
             HASH,
             INSPECT,
             JSON,
+            RANDOM,
+            DURATION,
+            DATE,
+            TIME,
+            ORDER,
+            TARGET,
         }
 
         Self {
@@ -111,6 +123,9 @@ impl Default for ModuleCache<'_> {
             exposes: Default::default(),
             top_level_thunks: Default::default(),
             documentation: Default::default(),
+            symbol_index: Default::default(),
+            calls: Default::default(),
+            builtin_lookups: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
             sources: Default::default(),
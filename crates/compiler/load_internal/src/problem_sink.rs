@@ -0,0 +1,90 @@
+//! Handing the problems of each module to the caller as soon as the module is checked, rather
+//! than all at once when the load finishes, so that long builds can show them as they go.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use roc_module::symbol::{Interns, ModuleId};
+use roc_problem::Severity;
+use roc_region::all::{LineColumnRegion, LineInfo};
+use roc_reporting::report::{can_problem, type_problem, Palette, RenderTarget, RocDocAllocator};
+use roc_solve_problem::TypeError;
+
+/// A problem in a module, rendered the way `roc check` renders it.
+#[derive(Debug, Clone)]
+pub struct ReportedProblem {
+    pub module_id: ModuleId,
+    pub path: PathBuf,
+    pub severity: Severity,
+    /// Where in the module the problem is, if it has a location.
+    pub region: Option<LineColumnRegion>,
+    pub report: String,
+}
+
+/// Receives the problems of each module once it has been type checked. It's called from the
+/// thread that coordinates the load, so it should return quickly.
+///
+/// The problems are still part of the result of the load, so code that reports them at the end
+/// doesn't have to change.
+#[derive(Clone)]
+pub struct ProblemSink {
+    callback: Arc<dyn Fn(ReportedProblem) + Send + Sync>,
+}
+
+impl ProblemSink {
+    pub fn new(callback: impl Fn(ReportedProblem) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn report_module(
+        &self,
+        module_id: ModuleId,
+        path: &Path,
+        source: &str,
+        interns: &Interns,
+        can_problems: &[roc_problem::can::Problem],
+        type_problems: &[TypeError],
+        render: RenderTarget,
+        palette: &Palette,
+    ) {
+        let src_lines: Vec<&str> = source.split('\n').collect();
+        let line_info = LineInfo::new(source);
+        let alloc = RocDocAllocator::new(&src_lines, module_id, interns);
+
+        let type_reports = type_problems.iter().filter_map(|problem| {
+            let report = type_problem(&alloc, &line_info, path.to_path_buf(), problem.clone())?;
+
+            Some((report, problem.region()))
+        });
+        let can_reports = can_problems.iter().map(|problem| {
+            let report = can_problem(&alloc, &line_info, path.to_path_buf(), problem.clone());
+
+            (report, problem.region())
+        });
+
+        for (report, region) in type_reports.chain(can_reports) {
+            let severity = report.severity;
+            let mut buf = String::new();
+
+            report.render(render, &mut buf, &alloc, palette);
+
+            (self.callback)(ReportedProblem {
+                module_id,
+                path: path.to_path_buf(),
+                severity,
+                region: region.map(|region| line_info.convert_region(region)),
+                report: buf,
+            });
+        }
+    }
+}
+
+impl fmt::Debug for ProblemSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProblemSink").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,102 @@
+//! An index of the names each module defines, so editors can search a whole project by name.
+//!
+//! The index lists every type alias, opaque type and ability a module declares, along with the
+//! values it exposes. It is built from the parsed defs right after canonicalization, which is
+//! when the loader knows the symbols they were given. Each entry has a [StableSymbolId] too, so
+//! that an index kept between loads stays valid for the modules that didn't change.
+
+use roc_collections::VecSet;
+use roc_module::symbol::{IdentIds, ModuleId, PQModuleName, StableSymbolId, Symbol};
+use roc_parse::ast::{Defs, Expr, ExtractSpaces, Pattern, TypeDef, TypeHeader, ValueDef};
+use roc_region::all::{Loc, Region};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Alias,
+    Opaque,
+    Ability,
+    Function,
+    Value,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedSymbol {
+    pub name: String,
+    pub symbol: Symbol,
+    pub stable_id: StableSymbolId,
+    pub kind: SymbolKind,
+    /// Where the name is introduced
+    pub region: Region,
+}
+
+pub fn index_module(
+    home: ModuleId,
+    module_name: &PQModuleName,
+    ident_ids: &IdentIds,
+    defs: &Defs<'_>,
+    exposed_symbols: &VecSet<Symbol>,
+) -> Vec<IndexedSymbol> {
+    let mut index = Vec::new();
+
+    let mut push = |name: &str, region: Region, kind: SymbolKind| {
+        let found = ident_ids.get_id(name).and_then(|ident_id| {
+            let stable_id = StableSymbolId::new(module_name, ident_ids, ident_id)?;
+
+            Some((ident_id, stable_id))
+        });
+
+        if let Some((ident_id, stable_id)) = found {
+            index.push(IndexedSymbol {
+                name: name.to_string(),
+                symbol: Symbol::new(home, ident_id),
+                stable_id,
+                kind,
+                region,
+            });
+        }
+    };
+
+    for either_index in defs.tags.iter() {
+        match either_index.split() {
+            Ok(type_index) => {
+                let (TypeHeader { name, .. }, kind) = match &defs.type_defs[type_index.index()] {
+                    TypeDef::Alias { header, .. } => (header, SymbolKind::Alias),
+                    TypeDef::Opaque { header, .. } => (header, SymbolKind::Opaque),
+                    TypeDef::Ability { header, .. } => (header, SymbolKind::Ability),
+                };
+
+                push(name.value, name.region, kind);
+            }
+            Err(value_index) => {
+                let (pattern, body) = match &defs.value_defs[value_index.index()] {
+                    ValueDef::Body(pattern, body) => (*pattern, *body),
+                    ValueDef::AnnotatedBody {
+                        body_pattern,
+                        body_expr,
+                        ..
+                    } => (*body_pattern, *body_expr),
+                    _ => continue,
+                };
+
+                if let Pattern::Identifier { ident } = pattern.value {
+                    let is_exposed = ident_ids.get_id(ident).map_or(false, |ident_id| {
+                        exposed_symbols.contains(&Symbol::new(home, ident_id))
+                    });
+
+                    if is_exposed {
+                        push(ident, pattern.region, value_kind(body));
+                    }
+                }
+            }
+        }
+    }
+
+    index
+}
+
+fn value_kind(body: &Loc<Expr<'_>>) -> SymbolKind {
+    match body.value.extract_spaces().item {
+        Expr::Closure(..) => SymbolKind::Function,
+        _ => SymbolKind::Value,
+    }
+}
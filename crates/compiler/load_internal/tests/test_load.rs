@@ -17,6 +17,7 @@ mod helpers;
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
+use roc_load_internal::defines::{DefineError, Defines};
 use roc_load_internal::docs::DocDef;
 use roc_load_internal::file::{
     ExecutionMode, LoadConfig, LoadResult, LoadStart, LoadingProblem, Threading,
@@ -44,12 +45,14 @@ fn load_and_typecheck(
     exposed_types: ExposedByModule,
     target: Target,
     function_kind: FunctionKind,
+    defines: Defines,
 ) -> Result<LoadedModule, LoadingProblem> {
     use LoadResult::*;
 
     let load_start = LoadStart::from_path(
         arena,
         filename,
+        target,
         RenderTarget::Generic,
         RocCacheDir::Disallowed,
         DEFAULT_PALETTE,
@@ -61,6 +64,11 @@ fn load_and_typecheck(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines,
     };
 
     match roc_load_internal::file::load(
@@ -110,7 +118,7 @@ fn multiple_modules(subdir: &str, files: Vec<(&str, &str)>) -> Result<LoadedModu
     let arena = Bump::new();
     let arena = &arena;
 
-    match multiple_modules_help(subdir, arena, files) {
+    match multiple_modules_help(subdir, arena, files, Defines::default()) {
         Err(io_error) => panic!("IO trouble: {io_error:?}"),
         Ok(Err(LoadingProblem::FormattedReport(buf))) => Err(buf),
         Ok(Err(loading_problem)) => Err(format!("{loading_problem:?}")),
@@ -144,6 +152,7 @@ fn multiple_modules_help<'a>(
     subdir: &str,
     arena: &'a Bump,
     mut files: Vec<(&str, &str)>,
+    defines: Defines,
 ) -> Result<Result<LoadedModule, roc_load_internal::file::LoadingProblem<'a>>, std::io::Error> {
     use std::fs::{self, File};
     use std::io::Write;
@@ -187,6 +196,7 @@ fn multiple_modules_help<'a>(
             Default::default(),
             TARGET,
             FunctionKind::LambdaSet,
+            defines,
         )
     };
 
@@ -207,6 +217,7 @@ fn load_fixture(
         subs_by_module,
         TARGET,
         FunctionKind::LambdaSet,
+        Defines::default(),
     );
     let mut loaded_module = match loaded {
         Ok(x) => x,
@@ -384,6 +395,7 @@ fn module_with_deps() {
         subs_by_module,
         TARGET,
         FunctionKind::LambdaSet,
+        Defines::default(),
     );
 
     let mut loaded_module = loaded.expect("Test module failed to load");
@@ -877,6 +889,63 @@ fn platform_exposes_main_return_by_pointer_issue() {
     assert!(multiple_modules("platform_exposes_main_return_by_pointer_issue", modules).is_ok());
 }
 
+#[test]
+fn conflicting_package_shorthands() {
+    let modules = vec![
+        ("json_a/main.roc", "package [] {}"),
+        ("json_b/main.roc", "package [] {}"),
+        ("dep/main.roc", r#"package [] { json: "json_b/main.roc" }"#),
+        (
+            "platform/main.roc",
+            indoc!(
+                r#"
+                    platform "hello-world"
+                        requires {} { main : Str }
+                        exposes []
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+
+                    mainForHost : Str
+                    mainForHost = main
+                    "#
+            ),
+        ),
+        (
+            "main.roc",
+            indoc!(
+                r#"
+                    app "hello-world"
+                        packages { pf: "platform/main.roc", json: "json_a/main.roc", dep: "dep/main.roc" }
+                        imports []
+                        provides [main] to pf
+
+                    main = "Hello, World!\n"
+                    "#
+            ),
+        ),
+    ];
+
+    match multiple_modules("conflicting_package_shorthands", modules) {
+        Err(report) => {
+            assert!(report.contains("CONFLICTING PACKAGES"), "report=({report})");
+            assert!(
+                report.contains(r#"`json`: "json_a/main.roc""#),
+                "report=({report})"
+            );
+            assert!(
+                report.contains(r#"`dep`: "dep/main.roc""#),
+                "report=({report})"
+            );
+            assert!(
+                report.contains(r#"`json`: "json_b/main.roc""#),
+                "report=({report})"
+            );
+        }
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
+
 #[test]
 fn opaque_wrapped_unwrapped_outside_defining_module() {
     let modules = vec![
@@ -955,6 +1024,70 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
     );
 }
 
+#[test]
+fn opaques_with_the_same_name_in_different_modules_do_not_unify() {
+    let modules = vec![
+        (
+            "UserA.roc",
+            indoc!(
+                r"
+                    module [UserId, new]
+
+                    UserId := U64
+
+                    new : U64 -> UserId
+                    new = \n -> @UserId n
+                    "
+            ),
+        ),
+        (
+            "UserB.roc",
+            indoc!(
+                r"
+                    module [UserId, toU64]
+
+                    UserId := U64
+
+                    toU64 : UserId -> U64
+                    toU64 = \@UserId n -> n
+                    "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r"
+                    module [mixed]
+
+                    import UserA
+                    import UserB
+
+                    mixed = UserB.toU64 (UserA.new 1)
+                    "
+            ),
+        ),
+    ];
+
+    let arena = Bump::new();
+    let mut loaded_module = multiple_modules_help(
+        "opaques_with_the_same_name_in_different_modules_do_not_unify",
+        &arena,
+        modules,
+        Defines::default(),
+    )
+    .unwrap()
+    .unwrap();
+
+    let home = loaded_module.module_id;
+    let type_problems = loaded_module
+        .type_problems
+        .remove(&home)
+        .unwrap_or_default();
+
+    // UserA.UserId and UserB.UserId are distinct types, even though they share a name
+    assert_eq!(type_problems.len(), 1, "{type_problems:?}");
+}
+
 #[test]
 fn unused_imports() {
     let modules = vec![
@@ -1110,6 +1243,57 @@ fn used_exposed_and_qualified() {
     assert!(result.is_ok())
 }
 
+#[test]
+fn defines_module() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r#"
+        module [version, beta]
+
+        import Defines
+
+        version = Defines.version
+        beta = Defines.beta
+            "#
+        ),
+    )];
+
+    let defines = Defines::parse(["version=1.2.0 \"$(beta)\"", "beta=true"]).unwrap();
+    let arena = Bump::new();
+    let loaded_module = multiple_modules_help("defines_module", &arena, modules, defines)
+        .unwrap()
+        .unwrap();
+
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "version" => "Str",
+            "beta" => "Bool",
+        },
+    );
+}
+
+#[test]
+fn invalid_defines() {
+    assert_eq!(
+        Defines::parse(["version"]).unwrap_err(),
+        DefineError::MissingValue("version".to_string())
+    );
+    assert_eq!(
+        Defines::parse(["Version=1"]).unwrap_err(),
+        DefineError::InvalidName("Version".to_string())
+    );
+    assert_eq!(
+        Defines::parse(["when=1"]).unwrap_err(),
+        DefineError::InvalidName("when".to_string())
+    );
+    assert_eq!(
+        Defines::parse(["beta=true", "beta=false"]).unwrap_err(),
+        DefineError::Duplicate("beta".to_string())
+    );
+}
+
 #[test]
 fn explicit_builtin_import() {
     let modules = vec![(
@@ -1662,6 +1846,51 @@ fn import_builtin_in_platform_and_check_app() {
     assert!(result.is_ok(), "should check");
 }
 
+#[test]
+fn platform_unavailable_builtin() {
+    let modules = vec![
+        (
+            "platform/main.roc",
+            indoc!(
+                r#"
+                    platform "testplatform"
+                        requires {} { main : Str }
+                        exposes []
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+                        unavailable [Dict, Set]
+
+                    mainForHost : Str
+                    mainForHost = main
+                    "#
+            ),
+        ),
+        (
+            "main.roc",
+            indoc!(
+                r#"
+                    app "test"
+                        packages { pf: "platform/main.roc" }
+                        provides [main] to pf
+
+                    main = Dict.empty {} |> Dict.len |> Num.toStr
+                    "#
+            ),
+        ),
+    ];
+
+    match multiple_modules("platform_unavailable_builtin", modules) {
+        Err(report) => {
+            assert!(report.contains("UNAVAILABLE BUILTIN"), "{report}");
+            assert!(report.contains("This uses `Dict.empty`"), "{report}");
+            assert!(report.contains("This uses `Dict.len`"), "{report}");
+            assert!(!report.contains("This uses `Num.toStr`"), "{report}");
+        }
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
+
 #[test]
 fn module_cyclic_import_itself() {
     let modules = vec![(
@@ -1751,6 +1980,56 @@ fn module_cyclic_import_transitive() {
     );
 }
 
+#[test]
+fn utf8_bom_is_skipped() {
+    let modules = vec![
+        (
+            "Dep.roc",
+            "\u{feff}interface Dep exposes [one] imports []\n\none = 1\n",
+        ),
+        (
+            "Main.roc",
+            "\u{feff}interface Main exposes [two] imports []\n\nimport Dep\n\ntwo = Dep.one + 1\n",
+        ),
+    ];
+
+    let result = multiple_modules("utf8_bom_is_skipped", modules);
+    assert!(result.is_ok())
+}
+
+#[test]
+fn file_not_utf8() {
+    let dir = TmpDir::new("tmp/file_not_utf8");
+    let file_path = dir.path().join("Main.roc");
+
+    // What an editor that saves as UTF-16 would write
+    let mut bytes = vec![0xFF, 0xFE];
+    bytes.extend(
+        "interface Main exposes [] imports []\n"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes),
+    );
+    std::fs::write(&file_path, bytes).unwrap();
+
+    let arena = Bump::new();
+    let result = load_and_typecheck(
+        &arena,
+        file_path,
+        Default::default(),
+        TARGET,
+        FunctionKind::LambdaSet,
+        Defines::default(),
+    );
+
+    match result {
+        Err(LoadingProblem::FormattedReport(report)) => {
+            assert!(report.contains("FILE IS NOT UTF-8"), "\n{report}")
+        }
+        Err(problem) => panic!("expected a report, but got {problem:?}"),
+        Ok(_) => panic!("expected loading to fail"),
+    }
+}
+
 #[test]
 fn non_roc_file_extension() {
     let modules = vec![(
@@ -75,6 +75,7 @@ const DISPLAY_STRINGS: [(BinOp, &str); 20] = [
 ];
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CalledVia {
     /// Calling with space, e.g. (foo bar)
     Space,
@@ -99,6 +100,7 @@ pub enum CalledVia {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnaryOp {
     /// (-), e.g. (-x)
     Negate,
@@ -116,6 +118,7 @@ impl std::fmt::Display for UnaryOp {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BinOp {
     // highest precedence
     Caret,
@@ -193,7 +196,8 @@ impl BinOp {
         ASSOCIATIVITY_TABLE[self as usize]
     }
 
-    fn precedence(self) -> u8 {
+    /// How tightly the operator binds; operators with a higher precedence are applied first.
+    pub fn precedence(self) -> u8 {
         // The compiler should never pass any of these to this function!
         debug_assert_ne!(self, Assignment);
         debug_assert_ne!(self, IsAliasType);
@@ -231,6 +235,16 @@ impl std::fmt::Display for BinOp {
     }
 }
 
+/// Every operator that can appear between two expressions, with its precedence and
+/// associativity, from the one that binds most tightly to the one that binds least.
+pub fn operator_table() -> impl Iterator<Item = (BinOp, u8, Associativity)> {
+    PRECEDENCES
+        .iter()
+        .zip(ASSOCIATIVITIES.iter())
+        .filter(|((_, precedence), _)| *precedence != 255)
+        .map(|(&(op, precedence), &(_, associativity))| (op, precedence, associativity))
+}
+
 const fn generate_precedence_table() -> [u8; 20] {
     let mut table = [0u8; 20];
     let mut i = 0;
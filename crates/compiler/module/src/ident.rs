@@ -132,6 +132,12 @@ impl ModuleName {
     pub const INSPECT: &'static str = "Inspect";
     pub const TASK: &'static str = "Task";
     pub const JSON: &'static str = "TotallyNotJson";
+    pub const RANDOM: &'static str = "Random";
+    pub const DURATION: &'static str = "Duration";
+    pub const DATE: &'static str = "Date";
+    pub const TIME: &'static str = "Time";
+    pub const ORDER: &'static str = "Order";
+    pub const TARGET: &'static str = "Target";
 
     pub fn as_str(&self) -> &str {
         self.0.as_str()
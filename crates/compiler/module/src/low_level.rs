@@ -15,6 +15,9 @@ pub enum LowLevel {
     StrFromInt,
     StrFromUtf8,
     StrToUtf8,
+    StrToScalars,
+    StrGraphemes,
+    StrCountGraphemes,
     StrRepeat,
     StrFromFloat,
     StrTrim,
@@ -127,6 +130,7 @@ pub enum LowLevel {
     UnboxExpr,
     Unreachable,
     DictPseudoSeed,
+    RandomTestSeed,
     SetJmp,
     LongJmp,
     SetLongJmpBuffer,
@@ -264,6 +268,9 @@ map_symbol_to_lowlevel! {
     StrCountUtf8Bytes <= STR_COUNT_UTF8_BYTES;
     StrFromUtf8 <= STR_FROM_UTF8_LOWLEVEL;
     StrToUtf8 <= STR_TO_UTF8;
+    StrToScalars <= STR_TO_SCALARS;
+    StrGraphemes <= STR_GRAPHEMES;
+    StrCountGraphemes <= STR_COUNT_GRAPHEMES;
     StrRepeat <= STR_REPEAT;
     StrTrim <= STR_TRIM;
     StrTrimStart <= STR_TRIM_START;
@@ -354,4 +361,5 @@ map_symbol_to_lowlevel! {
     Not <= BOOL_NOT;
     Unreachable <= LIST_UNREACHABLE;
     DictPseudoSeed <= DICT_PSEUDO_SEED;
+    RandomTestSeed <= RANDOM_TEST_SEED;
 }
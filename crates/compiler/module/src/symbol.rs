@@ -395,8 +395,19 @@ impl ModuleId {
     }
 
     pub fn is_automatically_imported(self) -> bool {
-        // The deprecated TotallyNotJson module is not automatically imported.
-        self.is_builtin() && self != ModuleId::JSON
+        // The deprecated TotallyNotJson module is not automatically imported, and the likes of
+        // Random have to be imported so they don't clash with the many user modules of the same name.
+        self.is_builtin()
+            && !matches!(
+                self,
+                ModuleId::JSON
+                    | ModuleId::RANDOM
+                    | ModuleId::DURATION
+                    | ModuleId::DATE
+                    | ModuleId::TIME
+                    | ModuleId::ORDER
+                    | ModuleId::TARGET
+            )
     }
 }
 
@@ -791,6 +802,83 @@ impl IdentIds {
     }
 }
 
+/// An ID for a symbol that doesn't depend on the order the modules of a program were loaded in,
+/// unlike the [ModuleId] in a [Symbol]. It's derived from the names of the symbol and its module,
+/// so it stays the same across compiler runs as long as those do. Caches and editor indexes that
+/// outlive a single load can key on it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableSymbolId(u64);
+
+impl StableSymbolId {
+    /// Returns None if the ident isn't in the module's ident IDs.
+    pub fn new(
+        module_name: &PQModuleName,
+        ident_ids: &IdentIds,
+        ident_id: IdentId,
+    ) -> Option<Self> {
+        let name = ident_ids.get_name(ident_id)?;
+
+        // A module can have several idents with the same name, like shadowed locals. They are
+        // added in the order they appear in the source, so counting the earlier ones tells them
+        // apart.
+        let occurrence = ident_ids
+            .get_id_many(name)
+            .take_while(|id| *id != ident_id)
+            .count();
+
+        let mut hash = StableHash::default();
+
+        match module_name {
+            PQModuleName::Unqualified(module) => {
+                hash.write_str("");
+                hash.write_str(module.as_str());
+            }
+            PQModuleName::Qualified(package, module) => {
+                hash.write_str(package);
+                hash.write_str(module.as_str());
+            }
+        }
+
+        hash.write_str(name);
+        hash.write_u64(occurrence as u64);
+
+        Some(Self(hash.0))
+    }
+
+    pub fn to_u64(self) -> u64 {
+        self.0
+    }
+}
+
+/// 64-bit FNV-1a. The hashers in std are free to change between Rust releases, and these hashes
+/// may be written to disk.
+struct StableHash(u64);
+
+impl Default for StableHash {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHash {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write_bytes(&n.to_le_bytes());
+    }
+
+    /// Writes the length first, so that the boundaries between strings are part of the hash.
+    fn write_str(&mut self, string: &str) {
+        self.write_u64(string.len() as u64);
+        self.write_bytes(string.as_bytes());
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct IdentIdsByModule(VecMap<ModuleId, IdentIds>);
 
@@ -1374,6 +1462,10 @@ define_builtins! {
         166 NUM_NAN_F64: "nanF64"
         167 NUM_INFINITY_F32: "infinityF32"
         168 NUM_INFINITY_F64: "infinityF64"
+        169 NUM_DIV_FLOOR: "divFloor"
+        170 NUM_DIV_FLOOR_CHECKED: "divFloorChecked"
+        171 NUM_MOD: "mod"
+        172 NUM_MOD_CHECKED: "modChecked"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1440,6 +1532,10 @@ define_builtins! {
         46 STR_REPLACE_FIRST: "replaceFirst"
         47 STR_REPLACE_LAST: "replaceLast"
         48 STR_RELEASE_EXCESS_CAPACITY: "releaseExcessCapacity"
+        49 STR_COMPARE: "compare"
+        50 STR_TO_SCALARS: "toScalars"
+        51 STR_GRAPHEMES: "graphemes"
+        52 STR_COUNT_GRAPHEMES: "countGraphemes"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1739,6 +1835,29 @@ define_builtins! {
         5 JSON_ARRAY_CLOSING_STATE: "ArrayClosingState"
         6 JSON_OBJECT_STATE: "ObjectState"
     }
+    16 RANDOM: "Random" => {
+        0 RANDOM_SEED: "Seed"
+        1 RANDOM_GENERATOR: "Generator"
+        2 RANDOM_TEST_SEED: "testSeed"
+    }
+    17 DURATION: "Duration" => {
+        0 DURATION_DURATION: "Duration"
+    }
+    18 DATE: "Date" => {
+        0 DATE_DATE: "Date"
+    }
+    19 TIME: "Time" => {
+        0 TIME_TIME: "Time"
+    }
+    20 ORDER: "Order" => {
+        0 ORDER_ORDER: "Order"
+    }
+    21 TARGET: "Target" => {
+        0 TARGET_IS_WASM: "isWasm"
+        1 TARGET_IS_WINDOWS: "isWindows"
+        2 TARGET_IS_MAC: "isMac"
+        3 TARGET_IS_LINUX: "isLinux"
+    }
 
-    num_modules: 16 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    num_modules: 22 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
@@ -1,6 +1,7 @@
 use bumpalo::collections::vec::Vec;
 use bumpalo::collections::CollectIn;
 use bumpalo::Bump;
+use roc_debug_flags::{roc_log, roc_log_enabled};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
 use roc_target::Target;
@@ -831,9 +832,14 @@ impl<'a> CallerProc<'a> {
             is_erased: false,
         };
 
-        if false {
+        if roc_log_enabled!("mono", Trace) {
             home.register_debug_idents(ident_ids);
-            println!("{}", proc.to_pretty(layout_interner, 200, true));
+            roc_log!(
+                "mono",
+                Trace,
+                "{}",
+                proc.to_pretty(layout_interner, 200, true)
+            );
         }
 
         Self {
@@ -973,9 +979,14 @@ impl<'a> CallerProc<'a> {
             is_erased: false,
         };
 
-        if false {
+        if roc_log_enabled!("mono", Trace) {
             home.register_debug_idents(ident_ids);
-            println!("{}", proc.to_pretty(layout_interner, 200, true));
+            roc_log!(
+                "mono",
+                Trace,
+                "{}",
+                proc.to_pretty(layout_interner, 200, true)
+            );
         }
 
         Self {
@@ -0,0 +1,151 @@
+//! Branch and function coverage instrumentation for `roc test --coverage`.
+//!
+//! When coverage is requested, every `when` branch and every function body is prefixed with a
+//! call to a foreign symbol whose name encodes the module and region of the branch or function.
+//! Backends that support coverage turn such a call into an increment of a global counter with the
+//! same name, which the test runner reads back once all expectations have run.
+//!
+//! Only the LLVM backend supports coverage, which is the one `roc test` compiles tests with. The
+//! other backends would treat these calls as calls to a missing host function.
+
+use roc_collections::MutSet;
+use roc_module::ident::ForeignSymbol;
+use roc_module::symbol::{Interns, ModuleId};
+use roc_region::all::{Position, Region};
+
+use crate::ir::{Call, CallType, Expr, Proc, Stmt};
+
+const COVERAGE_PREFIX: &str = "roc__coverage_";
+
+/// What a coverage point counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoverageKind {
+    /// A `when` branch, counted each time it is taken.
+    Branch,
+    /// A function, counted each time it is called.
+    Function,
+}
+
+impl CoverageKind {
+    fn tag(self) -> char {
+        match self {
+            CoverageKind::Branch => 'b',
+            CoverageKind::Function => 'f',
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "b" => Some(CoverageKind::Branch),
+            "f" => Some(CoverageKind::Function),
+            _ => None,
+        }
+    }
+}
+
+/// A `when` branch or function that was instrumented for coverage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoveragePoint {
+    pub module_id: ModuleId,
+    pub kind: CoverageKind,
+    pub region: Region,
+}
+
+impl CoveragePoint {
+    /// The name of the foreign symbol that marks this point, and of the counter it increments.
+    pub fn symbol_name(&self) -> String {
+        // Symbols are unique across modules, so the first symbol of a module makes for a
+        // per-module key that keeps equal regions in different modules apart.
+        let module_key = Interns::from_index(self.module_id, 0).as_u64();
+
+        format!(
+            "{COVERAGE_PREFIX}{}_{module_key}_{}_{}",
+            self.kind.tag(),
+            self.region.start().offset,
+            self.region.end().offset
+        )
+    }
+
+    pub fn foreign_symbol(&self) -> ForeignSymbol {
+        ForeignSymbol::from(self.symbol_name())
+    }
+}
+
+pub fn is_coverage_symbol(foreign_symbol: &ForeignSymbol) -> bool {
+    foreign_symbol.as_str().starts_with(COVERAGE_PREFIX)
+}
+
+fn parse_symbol_name(name: &str) -> Option<(CoverageKind, Region)> {
+    let rest = name.strip_prefix(COVERAGE_PREFIX)?;
+    let mut parts = rest.split('_');
+
+    let kind = CoverageKind::from_tag(parts.next()?)?;
+    let _module_key = parts.next()?;
+    let start = parts.next()?.parse().ok()?;
+    let end = parts.next()?.parse().ok()?;
+
+    Some((kind, Region::new(Position::new(start), Position::new(end))))
+}
+
+/// Find all coverage points that made it into the given procedures.
+pub fn coverage_points<'a, 'p>(
+    procs: impl IntoIterator<Item = &'p Proc<'a>>,
+) -> MutSet<CoveragePoint>
+where
+    'a: 'p,
+{
+    let mut points = MutSet::default();
+
+    for proc in procs {
+        let module_id = proc.name.name().module_id();
+
+        if module_id.is_builtin() {
+            continue;
+        }
+
+        let mut stack = vec![&proc.body];
+
+        while let Some(stmt) = stack.pop() {
+            match stmt {
+                Stmt::Let(_, expr, _, cont) => {
+                    if let Expr::Call(Call {
+                        call_type: CallType::Foreign { foreign_symbol, .. },
+                        ..
+                    }) = expr
+                    {
+                        if let Some((kind, region)) = parse_symbol_name(foreign_symbol.as_str()) {
+                            points.insert(CoveragePoint {
+                                module_id,
+                                kind,
+                                region,
+                            });
+                        }
+                    }
+
+                    stack.push(cont);
+                }
+                Stmt::Switch {
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                    stack.push(default_branch.1);
+                }
+                Stmt::Refcounting(_, cont) => stack.push(cont),
+                Stmt::Expect { remainder, .. }
+                | Stmt::ExpectFx { remainder, .. }
+                | Stmt::Dbg { remainder, .. } => stack.push(remainder),
+                Stmt::Join {
+                    body, remainder, ..
+                } => {
+                    stack.push(body);
+                    stack.push(remainder);
+                }
+                Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => {}
+            }
+        }
+    }
+
+    points
+}
@@ -0,0 +1,117 @@
+//! Removing procedures that the program can no longer reach.
+//!
+//! Specialization starts from the values exposed to the host and the top-level expects, and only
+//! specializes a definition once something reachable calls it, so a definition the program never
+//! uses is never specialized. The optimizations that run afterwards can still leave some of the
+//! specialized procedures unused: a folded constant no longer calls the functions that computed
+//! it, and a `List.walk` that runs in the bitcode no longer needs its Roc implementation. Those
+//! procedures are removed before code generation in every build, so the backends don't spend time
+//! on them.
+
+use roc_collections::{MutMap, MutSet};
+use roc_module::symbol::Symbol;
+
+use crate::ir::{Call, CallType, Expr, Proc, ProcLayout, Stmt};
+
+/// The procedures that no other procedure refers to. Before any optimization has run, these are
+/// the entry points of the program, like the functions exposed to the host and the expects.
+pub fn unreferenced_procs<'a>(
+    procs: &MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> MutSet<Symbol> {
+    let mut referenced = MutSet::default();
+
+    for ((symbol, _), proc) in procs.iter() {
+        referenced.extend(
+            references(&proc.body)
+                .into_iter()
+                .filter(|other| other != symbol),
+        );
+    }
+
+    procs
+        .keys()
+        .map(|(symbol, _)| *symbol)
+        .filter(|symbol| !referenced.contains(symbol))
+        .collect()
+}
+
+/// Remove every procedure that can't be reached from the given entry points.
+pub fn remove_unreachable_procs<'a>(
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    entry_points: impl IntoIterator<Item = Symbol>,
+) {
+    let mut by_symbol: MutMap<Symbol, Vec<&Proc<'a>>> = MutMap::default();
+
+    for ((symbol, _), proc) in procs.iter() {
+        by_symbol.entry(*symbol).or_default().push(proc);
+    }
+
+    let mut reachable = MutSet::default();
+    let mut stack: Vec<Symbol> = entry_points.into_iter().collect();
+
+    while let Some(symbol) = stack.pop() {
+        if !reachable.insert(symbol) {
+            continue;
+        }
+
+        for proc in by_symbol.get(&symbol).into_iter().flatten() {
+            stack.extend(
+                references(&proc.body)
+                    .into_iter()
+                    .filter(|other| !reachable.contains(other)),
+            );
+        }
+    }
+
+    procs.retain(|(symbol, _), _| reachable.contains(symbol));
+}
+
+/// The procedures a body calls or takes a pointer to.
+fn references(body: &Stmt<'_>) -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+    let mut stack = vec![body];
+
+    while let Some(stmt) = stack.pop() {
+        match stmt {
+            Stmt::Let(_, expr, _, cont) => {
+                match expr {
+                    Expr::Call(Call { call_type, .. }) => match call_type {
+                        CallType::ByName { name, .. } => symbols.push(name.name()),
+                        CallType::HigherOrder(higher_order) => {
+                            symbols.push(higher_order.passed_function.name.name())
+                        }
+                        CallType::ByPointer { .. }
+                        | CallType::Foreign { .. }
+                        | CallType::LowLevel { .. } => {}
+                    },
+                    Expr::FunctionPointer { lambda_name } => symbols.push(lambda_name.name()),
+                    Expr::ErasedMake { callee, .. } => symbols.push(*callee),
+                    _ => {}
+                }
+
+                stack.push(cont);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Stmt::Refcounting(_, cont) => stack.push(cont),
+            Stmt::Expect { remainder, .. }
+            | Stmt::ExpectFx { remainder, .. }
+            | Stmt::Dbg { remainder, .. } => stack.push(remainder),
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => {}
+        }
+    }
+
+    symbols
+}
@@ -1545,6 +1545,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         StrTrimStart => RC::Rc,
         StrTrimEnd => RC::Rc,
         StrSplit => RC::NoRc,
+        StrToScalars | StrGraphemes | StrCountGraphemes => RC::NoRc,
         StrToNum => RC::NoRc,
         ListPrepend => RC::Rc,
         StrJoinWith => RC::NoRc,
@@ -1598,7 +1599,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         | NumF32FromParts
         | NumF64FromParts => RC::NoRc,
         NumWithoutDecimalPoint | NumWithDecimalPoint => RC::NoRc,
-        DictPseudoSeed => RC::NoRc,
+        DictPseudoSeed | RandomTestSeed => RC::NoRc,
         StrStartsWith | StrEndsWith => RC::NoRc,
         StrFromUtf8 => RC::Rc,
         StrToUtf8 => RC::Rc,
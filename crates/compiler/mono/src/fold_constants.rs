@@ -0,0 +1,249 @@
+//! Compile-time evaluation of constant top-level definitions.
+//!
+//! A top-level definition that takes no arguments is specialized to a procedure without
+//! arguments, which is called wherever the definition is used. When the interpreter can evaluate
+//! such a procedure, its body is replaced by one that just builds the resulting value from
+//! literals, so the work is done once by the compiler instead of every time the program runs.
+
+use bumpalo::Bump;
+use roc_builtins::bitcode::IntWidth;
+use roc_collections::MutMap;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+use crate::interpret::{Interpreter, Value};
+use crate::ir::{Expr, ListLiteralElement, Literal, Proc, ProcLayout, Stmt};
+use crate::layout::{Builtin, InLayout, LayoutInterner, LayoutRepr, UnionLayout};
+
+/// How many statements the interpreter may evaluate for a single constant.
+const FUEL_PER_CONSTANT: usize = 100_000;
+
+/// Constants made of more values than this are left alone, so the generated code doesn't grow
+/// much larger than the code that computes them.
+const MAX_FOLDED_SIZE: usize = 256;
+
+pub fn fold_constants<'a, I: LayoutInterner<'a>>(
+    arena: &'a Bump,
+    layout_interner: &I,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let mut folded = Vec::new();
+
+    for ((symbol, proc_layout), proc) in procs.iter() {
+        let is_candidate = proc_layout.arguments.is_empty()
+            && proc.closure_data_layout.is_none()
+            && !symbol.module_id().is_builtin()
+            && !is_constant(&proc.body);
+
+        if !is_candidate {
+            continue;
+        }
+
+        let mut interpreter = Interpreter::for_constants(procs, layout_interner, FUEL_PER_CONSTANT);
+
+        // Anything that crashes or can't be interpreted is left to run at runtime.
+        if let Ok(value) = interpreter.call(proc.name, *proc_layout, Vec::new()) {
+            let mut builder = Builder {
+                arena,
+                layout_interner,
+                home,
+                ident_ids,
+                lets: Vec::new(),
+                size: 0,
+            };
+
+            if let Some(body) = builder.build_body(&value, proc_layout.result) {
+                folded.push(((*symbol, *proc_layout), body));
+            }
+        }
+    }
+
+    for (key, body) in folded {
+        if let Some(proc) = procs.get_mut(&key) {
+            proc.body = body;
+        }
+    }
+}
+
+/// Whether a body only builds a value out of literals, so there is nothing left to fold.
+fn is_constant(mut stmt: &Stmt) -> bool {
+    loop {
+        match stmt {
+            Stmt::Let(_, expr, _, cont) => {
+                match expr {
+                    Expr::Literal(_)
+                    | Expr::Struct(_)
+                    | Expr::Tag { .. }
+                    | Expr::Array { .. }
+                    | Expr::EmptyArray => {}
+                    _ => return false,
+                }
+
+                stmt = *cont;
+            }
+            Stmt::Ret(_) => return true,
+            _ => return false,
+        }
+    }
+}
+
+struct Builder<'a, 'r, I: LayoutInterner<'a>> {
+    arena: &'a Bump,
+    layout_interner: &'r I,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    lets: Vec<(Symbol, Expr<'a>, InLayout<'a>)>,
+    /// How many values were built so far, including the ones written as list literals.
+    size: usize,
+}
+
+impl<'a, 'r, I: LayoutInterner<'a>> Builder<'a, 'r, I> {
+    fn build_body(&mut self, value: &Value<'a>, layout: InLayout<'a>) -> Option<Stmt<'a>> {
+        let result = self.build(value, layout)?;
+        let mut stmt = Stmt::Ret(result);
+
+        for (symbol, expr, layout) in self.lets.drain(..).rev() {
+            stmt = Stmt::Let(symbol, expr, layout, self.arena.alloc(stmt));
+        }
+
+        Some(stmt)
+    }
+
+    fn build(&mut self, value: &Value<'a>, layout: InLayout<'a>) -> Option<Symbol> {
+        self.size += 1;
+
+        if self.size > MAX_FOLDED_SIZE {
+            return None;
+        }
+
+        let expr = match self.layout_interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::List(elem_layout)) => {
+                let elems = match value {
+                    Value::List(elems) => elems,
+                    _ => return None,
+                };
+
+                if elems.is_empty() {
+                    Expr::EmptyArray
+                } else {
+                    let mut list_elems =
+                        bumpalo::collections::Vec::with_capacity_in(elems.len(), self.arena);
+
+                    for elem in elems.iter() {
+                        list_elems.push(match self.literal(elem, elem_layout) {
+                            Some(literal) if self.size < MAX_FOLDED_SIZE => {
+                                self.size += 1;
+                                ListLiteralElement::Literal(literal)
+                            }
+                            Some(_) => return None,
+                            None => ListLiteralElement::Symbol(self.build(elem, elem_layout)?),
+                        });
+                    }
+
+                    Expr::Array {
+                        elem_layout,
+                        elems: list_elems.into_bump_slice(),
+                    }
+                }
+            }
+            LayoutRepr::Builtin(_) => Expr::Literal(self.literal(value, layout)?),
+            LayoutRepr::Struct(field_layouts) => match value {
+                Value::Struct(fields) if fields.len() == field_layouts.len() => {
+                    Expr::Struct(self.build_all(fields, field_layouts)?)
+                }
+                _ => return None,
+            },
+            LayoutRepr::Union(union_layout) => match value {
+                Value::Tag { tag_id, fields } => {
+                    let field_layouts = tag_field_layouts(union_layout, *tag_id)?;
+
+                    if fields.len() != field_layouts.len() {
+                        return None;
+                    }
+
+                    Expr::Tag {
+                        tag_layout: union_layout,
+                        tag_id: *tag_id,
+                        arguments: self.build_all(fields, field_layouts)?,
+                        reuse: None,
+                    }
+                }
+                _ => return None,
+            },
+            LayoutRepr::RecursivePointer(union_layout) => return self.build(value, union_layout),
+            // Functions and pointers can't be written down as literals.
+            LayoutRepr::Ptr(_)
+            | LayoutRepr::LambdaSet(_)
+            | LayoutRepr::FunctionPointer(_)
+            | LayoutRepr::Erased(_) => return None,
+        };
+
+        let symbol = Symbol::new(self.home, self.ident_ids.gen_unique());
+        self.lets.push((symbol, expr, layout));
+
+        Some(symbol)
+    }
+
+    fn build_all(
+        &mut self,
+        values: &[Value<'a>],
+        layouts: &[InLayout<'a>],
+    ) -> Option<&'a [Symbol]> {
+        let mut symbols = bumpalo::collections::Vec::with_capacity_in(values.len(), self.arena);
+
+        for (value, layout) in values.iter().zip(layouts) {
+            symbols.push(self.build(value, *layout)?);
+        }
+
+        Some(symbols.into_bump_slice())
+    }
+
+    fn literal(&self, value: &Value<'a>, layout: InLayout<'a>) -> Option<Literal<'a>> {
+        let literal = match (value, self.layout_interner.get_repr(layout)) {
+            (Value::Int(int, IntWidth::U128), LayoutRepr::Builtin(Builtin::Int(_))) => {
+                Literal::U128((*int as u128).to_ne_bytes())
+            }
+            (Value::Int(int, _), LayoutRepr::Builtin(Builtin::Int(_))) => {
+                Literal::Int(int.to_ne_bytes())
+            }
+            (Value::Float(float, _), LayoutRepr::Builtin(Builtin::Float(_))) => {
+                Literal::Float(*float)
+            }
+            (Value::Dec(dec), LayoutRepr::Builtin(Builtin::Decimal)) => {
+                Literal::Decimal(dec.to_ne_bytes())
+            }
+            (Value::Bool(bool), LayoutRepr::Builtin(Builtin::Bool)) => Literal::Bool(*bool),
+            (Value::Str(string), LayoutRepr::Builtin(Builtin::Str)) => {
+                Literal::Str(self.arena.alloc_str(string))
+            }
+            _ => return None,
+        };
+
+        Some(literal)
+    }
+}
+
+fn tag_field_layouts<'a>(union_layout: UnionLayout<'a>, tag_id: u16) -> Option<&'a [InLayout<'a>]> {
+    match union_layout {
+        UnionLayout::NonRecursive(tags) | UnionLayout::Recursive(tags) => {
+            tags.get(tag_id as usize).copied()
+        }
+        UnionLayout::NonNullableUnwrapped(fields) => Some(fields),
+        UnionLayout::NullableWrapped {
+            nullable_id,
+            other_tags,
+        } => match tag_id.cmp(&nullable_id) {
+            std::cmp::Ordering::Less => other_tags.get(tag_id as usize).copied(),
+            std::cmp::Ordering::Equal => Some(&[]),
+            std::cmp::Ordering::Greater => other_tags.get(tag_id as usize - 1).copied(),
+        },
+        UnionLayout::NullableUnwrapped {
+            nullable_id,
+            other_fields,
+        } => match tag_id == nullable_id as u16 {
+            true => Some(&[]),
+            false => Some(other_fields),
+        },
+    }
+}
@@ -1135,6 +1135,20 @@ fn insert_refcount_operations_binding<'a>(
                                 panic!("ListSortWith should have 3 arguments");
                             }
                         }
+                        HigherOrder::ListWalk { xs, state } => {
+                            // The list is only borrowed: its elements are incremented before
+                            // they are passed to the function.
+                            if let [_xs_symbol, _state_symbol, _function_symbol, closure_symbol] =
+                                &arguments
+                            {
+                                let new_stmt = dec_borrowed!([*xs, *closure_symbol], stmt);
+                                let new_let = new_let!(new_stmt);
+
+                                inc_owned!([*state].into_iter(), new_let)
+                            } else {
+                                panic!("ListWalk should have 4 arguments");
+                            }
+                        }
                     }
                 }
             }
@@ -1281,7 +1295,7 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
     // - other refcounted arguments are Borrowed
     match op {
         Unreachable => &[IRRELEVANT],
-        DictPseudoSeed => &[IRRELEVANT],
+        DictPseudoSeed | RandomTestSeed => &[IRRELEVANT],
         ListLenU64 | ListLenUsize | StrIsEmpty | StrCountUtf8Bytes | ListGetCapacity => &[BORROWED],
         ListWithCapacity | StrWithCapacity => &[IRRELEVANT],
         ListReplaceUnsafe => &[OWNED, IRRELEVANT, IRRELEVANT],
@@ -1294,6 +1308,7 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         StrTrimStart => &[OWNED],
         StrTrimEnd => &[OWNED],
         StrSplit => &[BORROWED, BORROWED],
+        StrToScalars | StrGraphemes | StrCountGraphemes => &[BORROWED],
         StrToNum => &[BORROWED],
         ListPrepend => &[OWNED, OWNED],
         StrJoinWith => &[BORROWED, BORROWED],
@@ -0,0 +1,1433 @@
+//! An interpreter for the mono IR, for evaluating simple programs without a backend.
+//!
+//! It runs the specialized procedures directly, so evaluating an expression doesn't require
+//! generating machine code and linking it. It only covers the pure core of the language: when
+//! a program does something the interpreter can't evaluate (effects, pointers, type erasure),
+//! evaluation stops with [InterpretError::Unsupported], and the caller should compile the
+//! program with a backend instead.
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::MutMap;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::Symbol;
+use roc_region::all::Region;
+
+use crate::coverage::is_coverage_symbol;
+use crate::ir::{
+    Call, CallType, Expr, HigherOrderLowLevel, JoinPointId, ListLiteralElement, Literal, Param,
+    Proc, ProcLayout, Stmt,
+};
+use crate::layout::{
+    Builtin, InLayout, LambdaName, Layout, LayoutInterner, LayoutRepr, Niche, TagIdIntType,
+    UnionLayout,
+};
+use crate::low_level::HigherOrder;
+
+/// How many bytes of stack nested calls may use. Evaluations that need more are left to a
+/// backend, so that the interpreter fits on a thread with 2MiB of stack (like test threads and
+/// tokio's blocking threads) even in a debug build, where each call takes a few KiB.
+const STACK_BUDGET: usize = 1024 * 1024;
+
+/// The number of decimal places of a `Dec`.
+const DEC_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// A runtime value. Composite values are reference counted, so copying them around is cheap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// An integer of the given width. A `U128` is stored as its bit pattern.
+    Int(i128, IntWidth),
+    /// A float of the given width. An `F32` is stored rounded to 32 bits.
+    Float(f64, FloatWidth),
+    /// A `Dec`, as its internal representation (the number times 10^18).
+    Dec(i128),
+    Bool(bool),
+    Str(Rc<str>),
+    List(Rc<Vec<Value<'a>>>),
+    Struct(Rc<[Value<'a>]>),
+    Tag {
+        tag_id: TagIdIntType,
+        fields: Rc<[Value<'a>]>,
+    },
+    Function(LambdaName<'a>),
+    /// A null pointer, e.g. a reuse token that can't be reused.
+    Null,
+}
+
+impl<'a> Value<'a> {
+    pub fn unit() -> Self {
+        Value::Struct(Rc::new([]))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpretError {
+    /// The program does something the interpreter can't evaluate. It should be compiled with
+    /// a backend instead.
+    Unsupported(String),
+    /// The program crashed, with this message.
+    Crash(String),
+}
+
+type Result<T> = std::result::Result<T, InterpretError>;
+
+fn unsupported<T>(what: impl Into<String>) -> Result<T> {
+    Err(InterpretError::Unsupported(what.into()))
+}
+
+fn crash<T>(message: &str) -> Result<T> {
+    Err(InterpretError::Crash(message.to_string()))
+}
+
+#[derive(Default)]
+struct Frame<'a, 'r> {
+    values: MutMap<Symbol, Value<'a>>,
+    join_points: MutMap<JoinPointId, (&'r [Param<'a>], &'r Stmt<'a>)>,
+}
+
+impl<'a, 'r> Frame<'a, 'r> {
+    fn get(&self, symbol: Symbol) -> Result<Value<'a>> {
+        match self.values.get(&symbol) {
+            Some(value) => Ok(value.clone()),
+            None => unsupported(format!("the unbound symbol {symbol:?}")),
+        }
+    }
+
+    fn get_all(&self, symbols: &[Symbol]) -> Result<Vec<Value<'a>>> {
+        symbols.iter().map(|symbol| self.get(*symbol)).collect()
+    }
+}
+
+pub struct Interpreter<'a, 'r, I: LayoutInterner<'a>> {
+    procedures: &'r MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    layout_interner: &'r I,
+    /// The stack address of the outermost call that is still running.
+    stack_start: Option<usize>,
+    /// How many more statements may be evaluated, if evaluation is limited.
+    fuel: Option<usize>,
+    /// Whether an `expect` may be evaluated; if not, reaching one stops evaluation.
+    allow_expects: bool,
+    /// The regions of the `expect`s that failed so far.
+    pub failed_expects: Vec<Region>,
+}
+
+impl<'a, 'r, I: LayoutInterner<'a>> Interpreter<'a, 'r, I> {
+    pub fn new(
+        procedures: &'r MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+        layout_interner: &'r I,
+    ) -> Self {
+        Self {
+            procedures,
+            layout_interner,
+            stack_start: None,
+            fuel: None,
+            allow_expects: true,
+            failed_expects: Vec::new(),
+        }
+    }
+
+    /// An interpreter for evaluating constants at compile time. It gives up on evaluations that
+    /// take longer than `fuel` statements, and on ones that reach an `expect`, because an
+    /// `expect` has to run whenever its constant is evaluated at runtime.
+    pub fn for_constants(
+        procedures: &'r MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+        layout_interner: &'r I,
+        fuel: usize,
+    ) -> Self {
+        Self {
+            fuel: Some(fuel),
+            allow_expects: false,
+            ..Self::new(procedures, layout_interner)
+        }
+    }
+
+    /// Run a top-level `expect`, and return whether all the expectations it reached held.
+    pub fn run_toplevel_expect(&mut self, symbol: Symbol) -> Result<bool> {
+        let layout = ProcLayout {
+            arguments: &[],
+            result: Layout::UNIT,
+            niche: Niche::NONE,
+        };
+        let failed_before = self.failed_expects.len();
+
+        self.call(LambdaName::no_niche(symbol), layout, Vec::new())?;
+
+        Ok(self.failed_expects.len() == failed_before)
+    }
+
+    /// Call the specialization of a procedure with the given layout.
+    pub fn call(
+        &mut self,
+        name: LambdaName<'a>,
+        layout: ProcLayout<'a>,
+        arguments: Vec<Value<'a>>,
+    ) -> Result<Value<'a>> {
+        let procedures = self.procedures;
+        let proc = match procedures.get(&(name.name(), layout)) {
+            Some(proc) => proc,
+            None => return unsupported(format!("a call to {:?}", name.name())),
+        };
+
+        let stack_address = stack_address();
+        let is_outermost = self.stack_start.is_none();
+        let stack_start = *self.stack_start.get_or_insert(stack_address);
+
+        if stack_start.abs_diff(stack_address) > STACK_BUDGET {
+            return unsupported("deeply nested calls");
+        }
+
+        let mut frame = Frame::default();
+
+        for ((_, symbol), argument) in proc.args.iter().zip(arguments) {
+            frame.values.insert(*symbol, argument);
+        }
+
+        let result = self.eval_stmt(&mut frame, &proc.body);
+
+        if is_outermost {
+            self.stack_start = None;
+        }
+
+        result
+    }
+
+    fn eval_stmt(
+        &mut self,
+        frame: &mut Frame<'a, 'r>,
+        mut stmt: &'r Stmt<'a>,
+    ) -> Result<Value<'a>> {
+        // Loops are join points that jump back to themselves, so this must not recurse.
+        loop {
+            if let Some(fuel) = self.fuel.as_mut() {
+                match fuel.checked_sub(1) {
+                    Some(remaining) => *fuel = remaining,
+                    None => return unsupported("an evaluation that takes too long"),
+                }
+            }
+
+            match stmt {
+                Stmt::Let(symbol, expr, layout, cont) => {
+                    let value = self.eval_expr(frame, expr, *layout)?;
+                    frame.values.insert(*symbol, value);
+                    stmt = *cont;
+                }
+                Stmt::Switch {
+                    cond_symbol,
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    let cond = switch_value(&frame.get(*cond_symbol)?)?;
+
+                    stmt = branches
+                        .iter()
+                        .find(|(value, _, _)| *value == cond)
+                        .map_or(default_branch.1, |(_, _, branch)| branch);
+                }
+                Stmt::Ret(symbol) => return frame.get(*symbol),
+                Stmt::Refcounting(_, cont) => stmt = *cont,
+                Stmt::Expect {
+                    condition,
+                    region,
+                    remainder,
+                    ..
+                }
+                | Stmt::ExpectFx {
+                    condition,
+                    region,
+                    remainder,
+                    ..
+                } => {
+                    if !self.allow_expects {
+                        return unsupported("expect");
+                    }
+
+                    if !as_bool(&frame.get(*condition)?)? {
+                        self.failed_expects.push(*region);
+                    }
+
+                    stmt = *remainder;
+                }
+                Stmt::Dbg { .. } => return unsupported("dbg"),
+                Stmt::Join {
+                    id,
+                    parameters,
+                    body,
+                    remainder,
+                } => {
+                    frame.join_points.insert(*id, (*parameters, *body));
+                    stmt = *remainder;
+                }
+                Stmt::Jump(id, arguments) => {
+                    let (parameters, body) = match frame.join_points.get(id) {
+                        Some(join_point) => *join_point,
+                        None => return unsupported("a jump to an unknown join point"),
+                    };
+
+                    let arguments = frame.get_all(arguments)?;
+
+                    for (parameter, argument) in parameters.iter().zip(arguments) {
+                        frame.values.insert(parameter.symbol, argument);
+                    }
+
+                    stmt = body;
+                }
+                Stmt::Crash(message, _) => {
+                    return match frame.get(*message)? {
+                        Value::Str(message) => crash(&message),
+                        _ => unsupported("a crash without a message"),
+                    };
+                }
+            }
+        }
+    }
+
+    fn eval_expr(
+        &mut self,
+        frame: &mut Frame<'a, 'r>,
+        expr: &'r Expr<'a>,
+        layout: InLayout<'a>,
+    ) -> Result<Value<'a>> {
+        match expr {
+            Expr::Literal(literal) => self.eval_literal(literal, layout),
+            Expr::Call(call) => self.eval_call(frame, call, layout),
+            Expr::Tag {
+                tag_id, arguments, ..
+            } => Ok(Value::Tag {
+                tag_id: *tag_id,
+                fields: frame.get_all(arguments)?.into(),
+            }),
+            Expr::Struct(fields) => Ok(Value::Struct(frame.get_all(fields)?.into())),
+            Expr::NullPointer | Expr::Reset { .. } | Expr::ResetRef { .. } => Ok(Value::Null),
+            Expr::StructAtIndex {
+                index, structure, ..
+            } => match frame.get(*structure)? {
+                Value::Struct(fields) => field(&fields, *index),
+                // A struct with a single field can be represented as just that field.
+                value if *index == 0 => Ok(value),
+                _ => unsupported("a field access on a value that is not a struct"),
+            },
+            Expr::GetTagId {
+                structure,
+                union_layout,
+            } => {
+                let tag_id = match (frame.get(*structure)?, union_layout) {
+                    (Value::Tag { tag_id, .. }, _) => tag_id,
+                    (Value::Null, UnionLayout::NullableWrapped { nullable_id, .. }) => *nullable_id,
+                    (Value::Null, UnionLayout::NullableUnwrapped { nullable_id, .. }) => {
+                        *nullable_id as TagIdIntType
+                    }
+                    _ => return unsupported("the tag id of a value that is not a tag"),
+                };
+
+                Ok(Value::Int(tag_id as i128, self.int_width(layout)?))
+            }
+            Expr::UnionAtIndex {
+                structure, index, ..
+            } => match frame.get(*structure)? {
+                Value::Tag { fields, .. } => field(&fields, *index),
+                _ => unsupported("a payload access on a value that is not a tag"),
+            },
+            Expr::Array { elem_layout, elems } => {
+                let mut list = Vec::with_capacity(elems.len());
+
+                for elem in elems.iter() {
+                    list.push(match elem {
+                        ListLiteralElement::Literal(literal) => {
+                            self.eval_literal(literal, *elem_layout)?
+                        }
+                        ListLiteralElement::Symbol(symbol) => frame.get(*symbol)?,
+                    });
+                }
+
+                Ok(Value::List(Rc::new(list)))
+            }
+            Expr::EmptyArray => Ok(Value::List(Rc::default())),
+            Expr::FunctionPointer { lambda_name } => Ok(Value::Function(*lambda_name)),
+            Expr::RuntimeErrorFunction(message) => crash(message),
+            Expr::GetElementPointer { .. } | Expr::Alloca { .. } => unsupported("pointers"),
+            Expr::ErasedMake { .. } | Expr::ErasedLoad { .. } => unsupported("type erasure"),
+        }
+    }
+
+    fn eval_literal(&self, literal: &Literal<'a>, layout: InLayout<'a>) -> Result<Value<'a>> {
+        let value = match literal {
+            Literal::Int(bytes) => {
+                let int = i128::from_ne_bytes(*bytes);
+
+                match self.layout_interner.get_repr(layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        Value::Int(wrap_int(width, int), width)
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(width)) => {
+                        Value::Float(round_float(width, int as f64), width)
+                    }
+                    LayoutRepr::Builtin(Builtin::Decimal) => Value::Dec(int * DEC_SCALE),
+                    _ => return unsupported("an integer literal that is not a number"),
+                }
+            }
+            Literal::U128(bytes) => Value::Int(u128::from_ne_bytes(*bytes) as i128, IntWidth::U128),
+            Literal::Float(float) => {
+                let width = self.float_width(layout)?;
+
+                Value::Float(round_float(width, *float), width)
+            }
+            Literal::Decimal(bytes) => Value::Dec(i128::from_ne_bytes(*bytes)),
+            Literal::Str(string) => Value::Str(Rc::from(*string)),
+            Literal::Bool(bool) => Value::Bool(*bool),
+            Literal::Byte(byte) => Value::Int(*byte as i128, IntWidth::U8),
+        };
+
+        Ok(value)
+    }
+
+    fn eval_call(
+        &mut self,
+        frame: &mut Frame<'a, 'r>,
+        call: &'r Call<'a>,
+        layout: InLayout<'a>,
+    ) -> Result<Value<'a>> {
+        // The arguments of a higher-order call include the function it passes, which isn't bound
+        // in the frame, so they are only looked up for the other calls.
+        let arguments = || frame.get_all(call.arguments);
+
+        match call.call_type.clone().replace_lowlevel_wrapper() {
+            CallType::ByName {
+                name,
+                ret_layout,
+                arg_layouts,
+                ..
+            } => {
+                let proc_layout = ProcLayout {
+                    arguments: arg_layouts,
+                    result: ret_layout,
+                    niche: name.niche(),
+                };
+
+                self.call(name, proc_layout, arguments()?)
+            }
+            CallType::ByPointer {
+                pointer,
+                ret_layout,
+                arg_layouts,
+            } => match frame.get(pointer)? {
+                Value::Function(name) => {
+                    let proc_layout = ProcLayout {
+                        arguments: arg_layouts,
+                        result: ret_layout,
+                        niche: name.niche(),
+                    };
+
+                    self.call(name, proc_layout, arguments()?)
+                }
+                _ => unsupported("a call through a value that is not a function"),
+            },
+            CallType::Foreign { foreign_symbol, .. } => {
+                if is_coverage_symbol(&foreign_symbol) {
+                    Ok(Value::unit())
+                } else {
+                    unsupported(format!("the effect {}", foreign_symbol.as_str()))
+                }
+            }
+            CallType::LowLevel { op, .. } => self.eval_lowlevel(op, arguments()?, layout),
+            CallType::HigherOrder(higher_order) => self.eval_higher_order(frame, higher_order),
+        }
+    }
+
+    fn eval_higher_order(
+        &mut self,
+        frame: &Frame<'a, 'r>,
+        higher_order: &HigherOrderLowLevel<'a>,
+    ) -> Result<Value<'a>> {
+        let passed = higher_order.passed_function;
+        let proc_layout = ProcLayout {
+            arguments: passed.argument_layouts,
+            result: passed.return_layout,
+            niche: passed.name.niche(),
+        };
+        let captures = match higher_order.closure_env_layout {
+            Some(_) => Some(frame.get(passed.captured_environment)?),
+            None => None,
+        };
+
+        let call_passed = |this: &mut Self, mut arguments: Vec<Value<'a>>| {
+            arguments.extend(captures.clone());
+            this.call(passed.name, proc_layout, arguments)
+        };
+
+        let lists = match higher_order.op {
+            HigherOrder::ListMap { xs } | HigherOrder::ListSortWith { xs } => vec![frame.get(xs)?],
+            HigherOrder::ListMap2 { xs, ys } => vec![frame.get(xs)?, frame.get(ys)?],
+            HigherOrder::ListMap3 { xs, ys, zs } => {
+                vec![frame.get(xs)?, frame.get(ys)?, frame.get(zs)?]
+            }
+            HigherOrder::ListMap4 { xs, ys, zs, ws } => vec![
+                frame.get(xs)?,
+                frame.get(ys)?,
+                frame.get(zs)?,
+                frame.get(ws)?,
+            ],
+            HigherOrder::ListWalk { xs, state } => {
+                let list = frame.get(xs)?;
+                let mut state = frame.get(state)?;
+
+                for element in as_list(&list)? {
+                    state = call_passed(self, vec![state, element.clone()])?;
+                }
+
+                return Ok(state);
+            }
+        };
+        let lists = lists.iter().map(as_list).collect::<Result<Vec<_>>>()?;
+
+        if let HigherOrder::ListSortWith { .. } = higher_order.op {
+            let mut sorted = lists[0].to_vec();
+            let mut error = None;
+
+            // Roc's sort is stable, and so is this one.
+            sorted.sort_by(|a, b| {
+                if error.is_some() {
+                    return Ordering::Equal;
+                }
+
+                match call_passed(self, vec![a.clone(), b.clone()]).and_then(order_value) {
+                    Ok(ordering) => ordering,
+                    Err(problem) => {
+                        error = Some(problem);
+                        Ordering::Equal
+                    }
+                }
+            });
+
+            return match error {
+                Some(problem) => Err(problem),
+                None => Ok(Value::List(Rc::new(sorted))),
+            };
+        }
+
+        let len = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+        let mut mapped = Vec::with_capacity(len);
+
+        for index in 0..len {
+            let arguments = lists.iter().map(|list| list[index].clone()).collect();
+            mapped.push(call_passed(self, arguments)?);
+        }
+
+        Ok(Value::List(Rc::new(mapped)))
+    }
+
+    fn eval_lowlevel(
+        &self,
+        op: LowLevel,
+        arguments: Vec<Value<'a>>,
+        layout: InLayout<'a>,
+    ) -> Result<Value<'a>> {
+        use LowLevel::*;
+
+        let arg = |index: usize| -> Result<&Value<'a>> {
+            match arguments.get(index) {
+                Some(value) => Ok(value),
+                None => unsupported(format!("{op:?} with too few arguments")),
+            }
+        };
+
+        let value = match op {
+            Eq => Value::Bool(arg(0)? == arg(1)?),
+            NotEq => Value::Bool(arg(0)? != arg(1)?),
+            And => Value::Bool(as_bool(arg(0)?)? && as_bool(arg(1)?)?),
+            Or => Value::Bool(as_bool(arg(0)?)? || as_bool(arg(1)?)?),
+            Not => Value::Bool(!as_bool(arg(0)?)?),
+            BoxExpr
+            | UnboxExpr
+            | ListClone
+            | ListReleaseExcessCapacity
+            | StrReleaseExcessCapacity => arg(0)?.clone(),
+            Unreachable => return crash("Reached unreachable code"),
+
+            NumAdd => num_arith(Arith::Add, Overflow::Crash, arg(0)?, arg(1)?)?,
+            NumAddWrap => num_arith(Arith::Add, Overflow::Wrap, arg(0)?, arg(1)?)?,
+            NumAddSaturated => num_arith(Arith::Add, Overflow::Saturate, arg(0)?, arg(1)?)?,
+            NumSub => num_arith(Arith::Sub, Overflow::Crash, arg(0)?, arg(1)?)?,
+            NumSubWrap => num_arith(Arith::Sub, Overflow::Wrap, arg(0)?, arg(1)?)?,
+            NumSubSaturated => num_arith(Arith::Sub, Overflow::Saturate, arg(0)?, arg(1)?)?,
+            NumMul => num_arith(Arith::Mul, Overflow::Crash, arg(0)?, arg(1)?)?,
+            NumMulWrap => num_arith(Arith::Mul, Overflow::Wrap, arg(0)?, arg(1)?)?,
+            NumMulSaturated => num_arith(Arith::Mul, Overflow::Saturate, arg(0)?, arg(1)?)?,
+            NumDivFrac => match (arg(0)?, arg(1)?) {
+                (Value::Float(a, width), Value::Float(b, _)) => {
+                    Value::Float(round_float(*width, a / b), *width)
+                }
+                // Dec rounding is left to the builtins.
+                _ => return unsupported("NumDivFrac on values that are not floats"),
+            },
+            NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked => {
+                let (a, b, width) = int_pair(arg(0)?, arg(1)?)?;
+
+                Value::Int(int_div(op, width, a, b)?, width)
+            }
+            NumIsMultipleOf => {
+                let (a, b, width) = int_pair(arg(0)?, arg(1)?)?;
+
+                Value::Bool(match b {
+                    0 => a == 0,
+                    _ => int_div(NumRemUnchecked, width, a, b)? == 0,
+                })
+            }
+            NumGt | NumGte | NumLt | NumLte => {
+                let ordering = compare(arg(0)?, arg(1)?)?;
+
+                Value::Bool(match op {
+                    NumGt => ordering == Some(Ordering::Greater),
+                    NumGte => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+                    NumLt => ordering == Some(Ordering::Less),
+                    _ => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                })
+            }
+            NumCompare => {
+                // Order is [EQ, GT, LT]
+                let tag_id = match compare(arg(0)?, arg(1)?)? {
+                    Some(Ordering::Greater) => 1,
+                    Some(Ordering::Less) => 2,
+                    _ => 0,
+                };
+
+                Value::Int(tag_id, IntWidth::U8)
+            }
+            NumNeg | NumAbs => match arg(0)? {
+                Value::Int(a, width) if width.is_signed() => {
+                    if op == NumAbs && *a >= 0 {
+                        Value::Int(*a, *width)
+                    } else {
+                        match a
+                            .checked_neg()
+                            .map(|negated| (negated, wrap_int(*width, negated)))
+                        {
+                            Some((negated, wrapped)) if negated == wrapped => {
+                                Value::Int(negated, *width)
+                            }
+                            _ if op == NumNeg => return crash("Integer negation overflowed!"),
+                            _ => return crash("Integer absolute value overflowed!"),
+                        }
+                    }
+                }
+                Value::Int(a, width) if op == NumAbs => Value::Int(*a, *width),
+                Value::Int(0, width) => Value::Int(0, *width),
+                Value::Int(..) => return crash("Integer negation overflowed!"),
+                Value::Float(a, width) if op == NumAbs => Value::Float(a.abs(), *width),
+                Value::Float(a, width) => Value::Float(-a, *width),
+                Value::Dec(a) if op == NumAbs && *a >= 0 => Value::Dec(*a),
+                Value::Dec(a) => match a.checked_neg() {
+                    Some(negated) => Value::Dec(negated),
+                    None => return crash("Decimal negation overflowed!"),
+                },
+                _ => return unsupported(format!("{op:?} on a value that is not a number")),
+            },
+            NumPowInt => {
+                let (mut base, exponent, width) = int_pair(arg(0)?, arg(1)?)?;
+                let mut result = 1;
+
+                if exponent < 0 && width.is_signed() {
+                    return unsupported("NumPowInt with a negative exponent");
+                }
+
+                let mut exponent = exponent as u128;
+
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result = int_arith(Arith::Mul, Overflow::Crash, width, result, base)?;
+                    }
+
+                    exponent >>= 1;
+
+                    if exponent > 0 {
+                        base = int_arith(Arith::Mul, Overflow::Crash, width, base, base)?;
+                    }
+                }
+
+                Value::Int(result, width)
+            }
+            NumSin | NumCos | NumTan | NumAtan | NumAcos | NumAsin | NumSqrtUnchecked
+            | NumLogUnchecked => match arg(0)? {
+                Value::Float(a, width) => {
+                    let result = match op {
+                        NumSin => a.sin(),
+                        NumCos => a.cos(),
+                        NumTan => a.tan(),
+                        NumAtan => a.atan(),
+                        NumAcos => a.acos(),
+                        NumAsin => a.asin(),
+                        NumSqrtUnchecked => a.sqrt(),
+                        _ => a.ln(),
+                    };
+
+                    Value::Float(round_float(*width, result), *width)
+                }
+                _ => return unsupported(format!("{op:?} on a value that is not a float")),
+            },
+            NumPow => match (arg(0)?, arg(1)?) {
+                (Value::Float(a, width), Value::Float(b, _)) => {
+                    Value::Float(round_float(*width, a.powf(*b)), *width)
+                }
+                _ => return unsupported("NumPow on values that are not floats"),
+            },
+            NumRound | NumFloor | NumCeiling => match arg(0)? {
+                Value::Float(a, _) => {
+                    let rounded = match op {
+                        NumRound => a.round(),
+                        NumFloor => a.floor(),
+                        _ => a.ceil(),
+                    };
+
+                    self.number_from_float(rounded, layout)?
+                }
+                _ => return unsupported(format!("{op:?} on a value that is not a float")),
+            },
+            NumIsNan | NumIsInfinite | NumIsFinite => match arg(0)? {
+                Value::Float(a, _) => Value::Bool(match op {
+                    NumIsNan => a.is_nan(),
+                    NumIsInfinite => a.is_infinite(),
+                    _ => a.is_finite(),
+                }),
+                _ => return unsupported(format!("{op:?} on a value that is not a float")),
+            },
+            NumBitwiseAnd | NumBitwiseOr | NumBitwiseXor => {
+                let (a, b, width) = int_pair(arg(0)?, arg(1)?)?;
+                let result = match op {
+                    NumBitwiseAnd => a & b,
+                    NumBitwiseOr => a | b,
+                    _ => a ^ b,
+                };
+
+                Value::Int(wrap_int(width, result), width)
+            }
+            NumShiftLeftBy | NumShiftRightBy | NumShiftRightZfBy => {
+                let (a, width) = as_int(arg(0)?)?;
+                let (by, _) = as_int(arg(1)?)?;
+                let bits = width.stack_size() as i128 * 8;
+                let unsigned = if bits == 128 {
+                    a as u128
+                } else {
+                    a as u128 & ((1 << bits) - 1)
+                };
+
+                let result = match op {
+                    _ if !(0..bits).contains(&by) => match op {
+                        NumShiftRightBy if a < 0 && width.is_signed() => -1,
+                        _ => 0,
+                    },
+                    NumShiftLeftBy => a << by,
+                    NumShiftRightBy if width == IntWidth::U128 => (unsigned >> by) as i128,
+                    NumShiftRightBy => a >> by,
+                    _ => (unsigned >> by) as i128,
+                };
+
+                Value::Int(wrap_int(width, result), width)
+            }
+            NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+                let (a, width) = as_int(arg(0)?)?;
+                let bits = width.stack_size() * 8;
+                let unsigned = if bits == 128 {
+                    a as u128
+                } else {
+                    a as u128 & ((1 << bits) - 1)
+                };
+
+                let count = match op {
+                    NumCountLeadingZeroBits => unsigned.leading_zeros() - (128 - bits),
+                    NumCountTrailingZeroBits => unsigned.trailing_zeros().min(bits),
+                    _ => unsigned.count_ones(),
+                };
+
+                Value::Int(count as i128, IntWidth::U8)
+            }
+            NumIntCast => {
+                let (a, _) = as_int(arg(0)?)?;
+                let width = self.int_width(layout)?;
+
+                Value::Int(wrap_int(width, a), width)
+            }
+            NumToFrac | NumToFloatCast => match (arg(0)?, self.layout_interner.get_repr(layout)) {
+                (value, LayoutRepr::Builtin(Builtin::Float(width))) => {
+                    let float = match value {
+                        Value::Int(a, IntWidth::U128) => *a as u128 as f64,
+                        Value::Int(a, _) => *a as f64,
+                        Value::Float(a, _) => *a,
+                        Value::Dec(a) => *a as f64 / DEC_SCALE as f64,
+                        _ => return unsupported(format!("{op:?} on a value that is not a number")),
+                    };
+
+                    Value::Float(round_float(width, float), width)
+                }
+                (Value::Int(a, width), LayoutRepr::Builtin(Builtin::Decimal))
+                    if *width != IntWidth::U128 =>
+                {
+                    match a.checked_mul(DEC_SCALE) {
+                        Some(dec) => Value::Dec(dec),
+                        None => return crash("Decimal conversion overflowed!"),
+                    }
+                }
+                _ => return unsupported(format!("{op:?} to this layout")),
+            },
+            NumToStr | StrFromInt => match arg(0)? {
+                Value::Int(a, IntWidth::U128) => Value::Str(Rc::from((*a as u128).to_string())),
+                Value::Int(a, _) => Value::Str(Rc::from(a.to_string())),
+                Value::Dec(a) => Value::Str(Rc::from(roc_std::RocDec::new(*a).to_string())),
+                // Float formatting is left to the backends, so it matches the builtins exactly.
+                _ => return unsupported(format!("{op:?} on a value that is not an integer")),
+            },
+
+            StrConcat => {
+                let mut string = as_str(arg(0)?)?.to_string();
+                string.push_str(as_str(arg(1)?)?);
+                Value::Str(Rc::from(string))
+            }
+            StrJoinWith => {
+                let strings = as_list(arg(0)?)?
+                    .iter()
+                    .map(as_str)
+                    .collect::<Result<Vec<_>>>()?;
+
+                Value::Str(Rc::from(strings.join(as_str(arg(1)?)?)))
+            }
+            StrIsEmpty => Value::Bool(as_str(arg(0)?)?.is_empty()),
+            StrStartsWith => Value::Bool(as_str(arg(0)?)?.starts_with(as_str(arg(1)?)?)),
+            StrEndsWith => Value::Bool(as_str(arg(0)?)?.ends_with(as_str(arg(1)?)?)),
+            StrSplit => {
+                let (string, delimiter) = (as_str(arg(0)?)?, as_str(arg(1)?)?);
+                let parts: Vec<_> = if delimiter.is_empty() {
+                    vec![Value::Str(Rc::from(string))]
+                } else {
+                    string
+                        .split(delimiter)
+                        .map(|part| Value::Str(Rc::from(part)))
+                        .collect()
+                };
+
+                Value::List(Rc::new(parts))
+            }
+            StrCountUtf8Bytes => {
+                Value::Int(as_str(arg(0)?)?.len() as i128, self.int_width(layout)?)
+            }
+            StrToUtf8 => Value::List(Rc::new(
+                as_str(arg(0)?)?
+                    .bytes()
+                    .map(|byte| Value::Int(byte as i128, IntWidth::U8))
+                    .collect(),
+            )),
+            StrToScalars => Value::List(Rc::new(
+                as_str(arg(0)?)?
+                    .chars()
+                    .map(|scalar| Value::Int(scalar as i128, IntWidth::U32))
+                    .collect(),
+            )),
+            StrRepeat => {
+                let (count, _) = as_int(arg(1)?)?;
+                Value::Str(Rc::from(as_str(arg(0)?)?.repeat(count.max(0) as usize)))
+            }
+            StrTrim => Value::Str(Rc::from(as_str(arg(0)?)?.trim())),
+            StrTrimStart => Value::Str(Rc::from(as_str(arg(0)?)?.trim_start())),
+            StrTrimEnd => Value::Str(Rc::from(as_str(arg(0)?)?.trim_end())),
+            StrGetUnsafe => {
+                let (index, _) = as_int(arg(1)?)?;
+
+                match as_str(arg(0)?)?.as_bytes().get(index as usize) {
+                    Some(byte) => Value::Int(*byte as i128, IntWidth::U8),
+                    None => return crash("Str.getUnsafe index out of bounds"),
+                }
+            }
+            StrSubstringUnsafe => {
+                let string = as_str(arg(0)?)?;
+                let (start, _) = as_int(arg(1)?)?;
+                let (len, _) = as_int(arg(2)?)?;
+
+                match string.get(start as usize..(start + len) as usize) {
+                    Some(substring) => Value::Str(Rc::from(substring)),
+                    None => return unsupported("a substring that splits a character"),
+                }
+            }
+            StrReserve => arg(0)?.clone(),
+            StrWithCapacity => Value::Str(Rc::from("")),
+
+            ListLenUsize | ListLenU64 | ListGetCapacity => {
+                Value::Int(as_list(arg(0)?)?.len() as i128, self.int_width(layout)?)
+            }
+            ListIsUnique => Value::Bool(true),
+            ListWithCapacity => Value::List(Rc::default()),
+            ListReserve => arg(0)?.clone(),
+            ListGetUnsafe => {
+                let (index, _) = as_int(arg(1)?)?;
+
+                match as_list(arg(0)?)?.get(index as usize) {
+                    Some(elem) => elem.clone(),
+                    None => return crash("List.getUnsafe index out of bounds"),
+                }
+            }
+            ListAppendUnsafe | ListPrepend => {
+                let mut list = as_list(arg(0)?)?.to_vec();
+                let elem = arg(1)?.clone();
+
+                if op == ListAppendUnsafe {
+                    list.push(elem);
+                } else {
+                    list.insert(0, elem);
+                }
+
+                Value::List(Rc::new(list))
+            }
+            ListConcat => {
+                let mut list = as_list(arg(0)?)?.to_vec();
+                list.extend(as_list(arg(1)?)?.iter().cloned());
+                Value::List(Rc::new(list))
+            }
+            ListSublist => {
+                let list = as_list(arg(0)?)?;
+                let (start, _) = as_int(arg(1)?)?;
+                let (len, _) = as_int(arg(2)?)?;
+                let start = (start.max(0) as usize).min(list.len());
+                let end = start.saturating_add(len.max(0) as usize).min(list.len());
+
+                Value::List(Rc::new(list[start..end].to_vec()))
+            }
+            ListDropAt => {
+                let mut list = as_list(arg(0)?)?.to_vec();
+                let (index, _) = as_int(arg(1)?)?;
+
+                if (0..list.len() as i128).contains(&index) {
+                    list.remove(index as usize);
+                }
+
+                Value::List(Rc::new(list))
+            }
+            ListSwap => {
+                let mut list = as_list(arg(0)?)?.to_vec();
+                let (i, _) = as_int(arg(1)?)?;
+                let (j, _) = as_int(arg(2)?)?;
+                let in_bounds = 0..list.len() as i128;
+
+                if in_bounds.contains(&i) && in_bounds.contains(&j) {
+                    list.swap(i as usize, j as usize);
+                }
+
+                Value::List(Rc::new(list))
+            }
+
+            NumAddChecked
+            | NumSubChecked
+            | NumMulChecked
+            | NumToIntChecked
+            | NumToFloatChecked
+            | NumWithoutDecimalPoint
+            | NumWithDecimalPoint
+            | NumF32ToParts
+            | NumF64ToParts
+            | NumF32FromParts
+            | NumF64FromParts
+            | StrFromUtf8
+            | StrToNum
+            | StrFromFloat
+            | ListReplaceUnsafe
+            | ListMap
+            | ListMap2
+            | ListMap3
+            | ListMap4
+            | ListSortWith
+            | Hash
+            | PtrCast
+            | PtrStore
+            | PtrLoad
+            | PtrClearTagId
+            | RefCountIncRcPtr
+            | RefCountDecRcPtr
+            | RefCountIncDataPtr
+            | RefCountDecDataPtr
+            | RefCountIsUnique
+            // A Dict built at compile time would have to use the same seed as the program, which
+            // is only known when it runs.
+            | DictPseudoSeed
+            // Properties at compile time would miss the seed that `roc test --seed` picks.
+            | RandomTestSeed
+            | SetJmp
+            | LongJmp
+            | SetLongJmpBuffer => return unsupported(format!("the lowlevel {op:?}")),
+            // Segmentation is left to the backends, so it always uses the Unicode tables of the
+            // builtins rather than whichever version of Unicode the compiler was built with.
+            StrGraphemes | StrCountGraphemes => {
+                return unsupported("grapheme segmentation, which needs the builtins' Unicode tables")
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn number_from_float(&self, float: f64, layout: InLayout<'a>) -> Result<Value<'a>> {
+        match self.layout_interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::Int(width)) => {
+                Ok(Value::Int(wrap_int(width, float as i128), width))
+            }
+            LayoutRepr::Builtin(Builtin::Float(width)) => {
+                Ok(Value::Float(round_float(width, float), width))
+            }
+            _ => unsupported("a conversion from a float to this layout"),
+        }
+    }
+
+    fn int_width(&self, layout: InLayout<'a>) -> Result<IntWidth> {
+        match self.layout_interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::Int(width)) => Ok(width),
+            _ => unsupported("an integer with a layout that is not an integer"),
+        }
+    }
+
+    fn float_width(&self, layout: InLayout<'a>) -> Result<FloatWidth> {
+        match self.layout_interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::Float(width)) => Ok(width),
+            _ => unsupported("a float with a layout that is not a float"),
+        }
+    }
+}
+
+fn field<'a>(fields: &[Value<'a>], index: u64) -> Result<Value<'a>> {
+    match fields.get(index as usize) {
+        Some(value) => Ok(value.clone()),
+        None => unsupported("an access to a field that doesn't exist"),
+    }
+}
+
+/// The address of a local, which tells how deep into the stack the current call is.
+#[inline(never)]
+fn stack_address() -> usize {
+    let local = 0u8;
+
+    std::hint::black_box(&local) as *const u8 as usize
+}
+
+fn switch_value(value: &Value) -> Result<u64> {
+    match value {
+        Value::Bool(bool) => Ok(*bool as u64),
+        Value::Int(int, _) => Ok(*int as u64),
+        _ => unsupported("a switch on a value that is not an integer"),
+    }
+}
+
+fn as_bool(value: &Value) -> Result<bool> {
+    match value {
+        Value::Bool(bool) => Ok(*bool),
+        _ => unsupported("a value that should be a Bool"),
+    }
+}
+
+fn as_int(value: &Value) -> Result<(i128, IntWidth)> {
+    match value {
+        Value::Int(int, width) => Ok((*int, *width)),
+        _ => unsupported("a value that should be an integer"),
+    }
+}
+
+fn int_pair(a: &Value, b: &Value) -> Result<(i128, i128, IntWidth)> {
+    let (a, width) = as_int(a)?;
+    let (b, _) = as_int(b)?;
+
+    Ok((a, b, width))
+}
+
+fn as_str<'v>(value: &'v Value) -> Result<&'v str> {
+    match value {
+        Value::Str(string) => Ok(string),
+        _ => unsupported("a value that should be a Str"),
+    }
+}
+
+fn as_list<'v, 'a>(value: &'v Value<'a>) -> Result<&'v [Value<'a>]> {
+    match value {
+        Value::List(list) => Ok(list),
+        _ => unsupported("a value that should be a List"),
+    }
+}
+
+fn order_value(value: Value) -> Result<Ordering> {
+    // Order is [EQ, GT, LT]
+    match value {
+        Value::Int(0, _) => Ok(Ordering::Equal),
+        Value::Int(1, _) => Ok(Ordering::Greater),
+        Value::Int(2, _) => Ok(Ordering::Less),
+        _ => unsupported("a comparison that doesn't return an Order"),
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Result<Option<Ordering>> {
+    match (a, b) {
+        (Value::Int(a, IntWidth::U128), Value::Int(b, _)) => {
+            Ok(Some((*a as u128).cmp(&(*b as u128))))
+        }
+        (Value::Int(a, _), Value::Int(b, _)) => Ok(Some(a.cmp(b))),
+        (Value::Float(a, _), Value::Float(b, _)) => Ok(a.partial_cmp(b)),
+        (Value::Dec(a), Value::Dec(b)) => Ok(Some(a.cmp(b))),
+        _ => unsupported("a comparison of values that are not numbers"),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Arith {
+    Add,
+    Sub,
+    Mul,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+    Crash,
+    Wrap,
+    Saturate,
+}
+
+fn num_arith<'a>(
+    arith: Arith,
+    overflow: Overflow,
+    a: &Value<'a>,
+    b: &Value<'a>,
+) -> Result<Value<'a>> {
+    match (a, b) {
+        (Value::Int(a, width), Value::Int(b, _)) => Ok(Value::Int(
+            int_arith(arith, overflow, *width, *a, *b)?,
+            *width,
+        )),
+        (Value::Float(a, width), Value::Float(b, _)) => {
+            let result = match arith {
+                Arith::Add => a + b,
+                Arith::Sub => a - b,
+                Arith::Mul => a * b,
+            };
+
+            Ok(Value::Float(round_float(*width, result), *width))
+        }
+        (Value::Dec(a), Value::Dec(b)) => {
+            let result = match arith {
+                Arith::Add => a.checked_add(*b),
+                Arith::Sub => a.checked_sub(*b),
+                // Dec rounding is left to the builtins.
+                Arith::Mul => return unsupported("Dec multiplication"),
+            };
+
+            match (result, overflow) {
+                (Some(result), _) => Ok(Value::Dec(result)),
+                (None, Overflow::Crash) => crash(match arith {
+                    Arith::Add => "Decimal addition overflowed!",
+                    Arith::Sub => "Decimal subtraction overflowed!",
+                    Arith::Mul => "Decimal multiplication overflowed!",
+                }),
+                (None, _) => unsupported("an overflowing Dec operation"),
+            }
+        }
+        _ => unsupported("arithmetic on values that are not numbers"),
+    }
+}
+
+fn int_arith(arith: Arith, overflow: Overflow, width: IntWidth, a: i128, b: i128) -> Result<i128> {
+    let overflow_message = match arith {
+        Arith::Add => "Integer addition overflowed!",
+        Arith::Sub => "Integer subtraction overflowed!",
+        Arith::Mul => "Integer multiplication overflowed!",
+    };
+
+    if width == IntWidth::U128 {
+        let (a, b) = (a as u128, b as u128);
+        let (result, overflowed) = match arith {
+            Arith::Add => a.overflowing_add(b),
+            Arith::Sub => a.overflowing_sub(b),
+            Arith::Mul => a.overflowing_mul(b),
+        };
+
+        return match (overflowed, overflow) {
+            (false, _) | (true, Overflow::Wrap) => Ok(result as i128),
+            (true, Overflow::Crash) => crash(overflow_message),
+            (true, Overflow::Saturate) => match arith {
+                Arith::Sub => Ok(0),
+                _ => Ok(u128::MAX as i128),
+            },
+        };
+    }
+
+    let (result, overflowed) = match arith {
+        Arith::Add => a.overflowing_add(b),
+        Arith::Sub => a.overflowing_sub(b),
+        Arith::Mul => a.overflowing_mul(b),
+    };
+    let wrapped = wrap_int(width, result);
+
+    if !overflowed && wrapped == result {
+        return Ok(result);
+    }
+
+    match overflow {
+        Overflow::Wrap => Ok(wrapped),
+        Overflow::Crash => crash(overflow_message),
+        Overflow::Saturate => {
+            // When the operation overflowed even an i128, the wrapped result has the opposite
+            // sign of the true one.
+            let too_large = if overflowed { result < 0 } else { result > 0 };
+
+            Ok(if too_large {
+                int_max(width)
+            } else {
+                int_min(width)
+            })
+        }
+    }
+}
+
+fn int_div(op: LowLevel, width: IntWidth, a: i128, b: i128) -> Result<i128> {
+    if b == 0 {
+        return crash("Integer division by 0!");
+    }
+
+    let result = if width == IntWidth::U128 {
+        let (a, b) = (a as u128, b as u128);
+
+        let result = match op {
+            LowLevel::NumRemUnchecked => a % b,
+            LowLevel::NumDivCeilUnchecked => a / b + (a % b != 0) as u128,
+            _ => a / b,
+        };
+
+        result as i128
+    } else {
+        let quotient = a.wrapping_div(b);
+        let remainder = a.wrapping_rem(b);
+
+        match op {
+            LowLevel::NumRemUnchecked => remainder,
+            LowLevel::NumDivCeilUnchecked if remainder != 0 && (remainder > 0) == (b > 0) => {
+                quotient + 1
+            }
+            _ => quotient,
+        }
+    };
+
+    Ok(wrap_int(width, result))
+}
+
+/// Truncate an integer to the given width, the way it would be stored in memory.
+fn wrap_int(width: IntWidth, int: i128) -> i128 {
+    let bits = width.stack_size() * 8;
+
+    if bits >= 128 {
+        return int;
+    }
+
+    let truncated = int & ((1 << bits) - 1);
+
+    if width.is_signed() && (truncated >> (bits - 1)) & 1 == 1 {
+        truncated - (1 << bits)
+    } else {
+        truncated
+    }
+}
+
+fn int_max(width: IntWidth) -> i128 {
+    let bits = width.stack_size() * 8;
+
+    match width {
+        IntWidth::I128 => i128::MAX,
+        _ if width.is_signed() => (1 << (bits - 1)) - 1,
+        _ => (1 << bits) - 1,
+    }
+}
+
+fn int_min(width: IntWidth) -> i128 {
+    let bits = width.stack_size() * 8;
+
+    match width {
+        IntWidth::I128 => i128::MIN,
+        _ if width.is_signed() => -(1 << (bits - 1)),
+        _ => 0,
+    }
+}
+
+fn round_float(width: FloatWidth, float: f64) -> f64 {
+    match width {
+        FloatWidth::F32 => float as f32 as f64,
+        FloatWidth::F64 => float,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ir::{BranchInfo, CallSpecId, SelfRecursive, UpdateModeId};
+    use crate::layout::STLayoutInterner;
+    use roc_target::Target;
+
+    /// `countDown = \n -> if n == 0 then n else countDown (n - 1)`, without the tail call
+    /// turned into a loop.
+    fn count_down<'a>(arena: &'a bumpalo::Bump) -> (Symbol, Proc<'a>) {
+        let name = LambdaName::no_niche(Symbol::DEV_TMP);
+        let n = Symbol::DEV_TMP2;
+        let one = Symbol::DEV_TMP3;
+        let m = Symbol::DEV_TMP4;
+        let result = Symbol::DEV_TMP5;
+
+        let recurse = Stmt::Let(
+            result,
+            Expr::Call(Call {
+                call_type: CallType::ByName {
+                    name,
+                    ret_layout: Layout::I64,
+                    arg_layouts: arena.alloc([Layout::I64]),
+                    specialization_id: CallSpecId::BACKEND_DUMMY,
+                },
+                arguments: arena.alloc([m]),
+            }),
+            Layout::I64,
+            arena.alloc(Stmt::Ret(result)),
+        );
+        let subtract = Stmt::Let(
+            m,
+            Expr::Call(Call {
+                call_type: CallType::LowLevel {
+                    op: LowLevel::NumSub,
+                    update_mode: UpdateModeId::BACKEND_DUMMY,
+                },
+                arguments: arena.alloc([n, one]),
+            }),
+            Layout::I64,
+            arena.alloc(recurse),
+        );
+        let otherwise = Stmt::Let(
+            one,
+            Expr::Literal(Literal::Int(1i128.to_ne_bytes())),
+            Layout::I64,
+            arena.alloc(subtract),
+        );
+        let body = Stmt::Switch {
+            cond_symbol: n,
+            cond_layout: Layout::I64,
+            branches: arena.alloc([(0, BranchInfo::None, Stmt::Ret(n))]),
+            default_branch: (BranchInfo::None, arena.alloc(otherwise)),
+            ret_layout: Layout::I64,
+        };
+
+        let proc = Proc {
+            name,
+            args: arena.alloc([(Layout::I64, n)]),
+            body,
+            closure_data_layout: None,
+            ret_layout: Layout::I64,
+            is_self_recursive: SelfRecursive::NotSelfRecursive,
+            is_erased: false,
+        };
+
+        (name.name(), proc)
+    }
+
+    #[test]
+    fn deep_recursion_fits_in_2mib_of_stack() {
+        let thread = std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(|| {
+                let arena = bumpalo::Bump::new();
+                let layout_interner = STLayoutInterner::with_capacity(4, Target::LinuxX64);
+                let (symbol, proc) = count_down(&arena);
+                let layout = ProcLayout {
+                    arguments: arena.alloc([Layout::I64]),
+                    result: Layout::I64,
+                    niche: Niche::NONE,
+                };
+
+                let mut procedures = MutMap::default();
+                procedures.insert((symbol, layout), proc);
+
+                let mut interpreter = Interpreter::new(&procedures, &layout_interner);
+                let mut count_down = |n: i128| {
+                    interpreter.call(
+                        LambdaName::no_niche(symbol),
+                        layout,
+                        vec![Value::Int(n, IntWidth::I64)],
+                    )
+                };
+
+                assert_eq!(count_down(100), Ok(Value::Int(0, IntWidth::I64)));
+                assert_eq!(
+                    count_down(1_000_000),
+                    Err(InterpretError::Unsupported(
+                        "deeply nested calls".to_string()
+                    ))
+                );
+                // The budget is measured from the outermost call again afterwards.
+                assert_eq!(count_down(100), Ok(Value::Int(0, IntWidth::I64)));
+            })
+            .unwrap();
+
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn grapheme_segmentation_is_left_to_the_backends() {
+        let layout_interner = STLayoutInterner::with_capacity(4, Target::LinuxX64);
+        let procedures = MutMap::default();
+        let interpreter = Interpreter::new(&procedures, &layout_interner);
+        let unsupported = Err(InterpretError::Unsupported(
+            "grapheme segmentation, which needs the builtins' Unicode tables".to_string(),
+        ));
+
+        for op in [LowLevel::StrGraphemes, LowLevel::StrCountGraphemes] {
+            let text = Value::Str(Rc::from("e\u{301}\u{1F1EB}\u{1F1F7}"));
+
+            assert_eq!(
+                interpreter.eval_lowlevel(op, vec![text], Layout::U64),
+                unsupported
+            );
+        }
+    }
+
+    #[test]
+    fn wrap_int_truncates_to_width() {
+        assert_eq!(wrap_int(IntWidth::U8, 256 + 7), 7);
+        assert_eq!(wrap_int(IntWidth::I8, 128), -128);
+        assert_eq!(wrap_int(IntWidth::I16, -1), -1);
+        assert_eq!(wrap_int(IntWidth::U16, -1), u16::MAX as i128);
+    }
+
+    #[test]
+    fn int_arith_overflow() {
+        let add = |overflow, width, a, b| int_arith(Arith::Add, overflow, width, a, b);
+
+        assert_eq!(add(Overflow::Crash, IntWidth::U8, 200, 55), Ok(255));
+        assert!(matches!(
+            add(Overflow::Crash, IntWidth::U8, 200, 56),
+            Err(InterpretError::Crash(_))
+        ));
+        assert_eq!(add(Overflow::Wrap, IntWidth::U8, 200, 56), Ok(0));
+        assert_eq!(add(Overflow::Saturate, IntWidth::I8, 100, 100), Ok(127));
+        assert_eq!(
+            add(Overflow::Saturate, IntWidth::I128, i128::MIN, -1),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            add(Overflow::Wrap, IntWidth::U128, u128::MAX as i128, 1),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn int_div_rounding() {
+        assert_eq!(
+            int_div(LowLevel::NumDivTruncUnchecked, IntWidth::I64, -7, 2),
+            Ok(-3)
+        );
+        assert_eq!(
+            int_div(LowLevel::NumDivCeilUnchecked, IntWidth::I64, 7, 2),
+            Ok(4)
+        );
+        assert_eq!(
+            int_div(LowLevel::NumDivCeilUnchecked, IntWidth::I64, -7, 2),
+            Ok(-3)
+        );
+        assert_eq!(
+            int_div(LowLevel::NumRemUnchecked, IntWidth::I64, -7, 2),
+            Ok(-1)
+        );
+        assert!(int_div(LowLevel::NumRemUnchecked, IntWidth::U8, 1, 0).is_err());
+    }
+}
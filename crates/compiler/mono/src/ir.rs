@@ -1,5 +1,6 @@
 #![allow(clippy::manual_map)]
 
+use crate::coverage::CoverageKind;
 use crate::ir::erased::{build_erased_function, ResolvedErasedLambda};
 use crate::ir::literal::{make_num_literal, IntOrFloatValue};
 use crate::layout::{
@@ -25,6 +26,7 @@ use roc_derive::SharedDerivedModule;
 use roc_error_macros::{internal_error, todo_abilities, todo_lambda_erasure};
 use roc_late_solve::storage::{ExternalModuleStorage, ExternalModuleStorageSnapshot};
 use roc_late_solve::{resolve_ability_specialization, AbilitiesView, Resolved, UnificationFailed};
+use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
@@ -37,9 +39,11 @@ use roc_types::subs::{
     StorageSubs, Subs, Variable, VariableSubsSlice,
 };
 use std::collections::HashMap;
+use std::sync::atomic::{self, AtomicBool};
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder};
 
 use pattern::{from_can_pattern, store_pattern, Pattern};
+use target::{prune_target_branches, target_constant};
 
 pub use literal::{ListLiteralElement, Literal};
 
@@ -48,6 +52,7 @@ mod decision_tree;
 mod erased;
 mod literal;
 mod pattern;
+mod target;
 
 #[inline(always)]
 pub fn pretty_print_ir_symbols() -> bool {
@@ -210,6 +215,11 @@ pub struct PartialProc<'a> {
     pub body: roc_can::expr::Expr,
     pub body_var: Variable,
     pub is_self_recursive: bool,
+    /// Where the function is defined, if calls to it are counted for `roc test --coverage`.
+    pub coverage_region: Option<Region>,
+    /// The body of the function, to report if the compiler crashes while specializing it. Derived
+    /// functions have no source, so no region.
+    pub region: Option<Region>,
 }
 
 impl<'a> PartialProc<'a> {
@@ -224,6 +234,8 @@ impl<'a> PartialProc<'a> {
         ret_var: Variable,
     ) -> PartialProc<'a> {
         let number_of_arguments = loc_args.len();
+        let region = loc_body.region;
+        let coverage_region = env.coverage_region(region);
 
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
             Ok((_, pattern_symbols, body)) => {
@@ -239,6 +251,8 @@ impl<'a> PartialProc<'a> {
                     body: body.value,
                     body_var: ret_var,
                     is_self_recursive,
+                    coverage_region,
+                    region: Some(region),
                 }
             }
 
@@ -256,6 +270,8 @@ impl<'a> PartialProc<'a> {
                     body: roc_can::expr::Expr::RuntimeError(error.value),
                     body_var: ret_var,
                     is_self_recursive: false,
+                    coverage_region,
+                    region: Some(region),
                 }
             }
         }
@@ -569,6 +585,8 @@ pub struct Suspended<'a> {
     pub symbol_or_lambdas: Vec<'a, LambdaName<'a>>,
     pub layouts: Vec<'a, ProcLayout<'a>>,
     pub variables: Vec<'a, Variable>,
+    /// For each specialization, the chain of specializations that led to it being needed
+    pub requested_by: Vec<'a, &'a [Symbol]>,
 }
 
 impl<'a> Suspended<'a> {
@@ -578,6 +596,7 @@ impl<'a> Suspended<'a> {
             symbol_or_lambdas: Vec::new_in(arena),
             layouts: Vec::new_in(arena),
             variables: Vec::new_in(arena),
+            requested_by: Vec::new_in(arena),
         }
     }
 
@@ -591,6 +610,7 @@ impl<'a> Suspended<'a> {
         symbol_or_lambda: LambdaName<'a>,
         proc_layout: ProcLayout<'a>,
         variable: Variable,
+        requested_by: &'a [Symbol],
     ) {
         // de-duplicate
         for (i, s) in self.symbol_or_lambdas.iter().enumerate() {
@@ -605,6 +625,7 @@ impl<'a> Suspended<'a> {
 
         self.symbol_or_lambdas.push(symbol_or_lambda);
         self.layouts.push(proc_layout);
+        self.requested_by.push(requested_by);
 
         let variable = self.store.import_variable_from(subs, variable).variable;
 
@@ -893,6 +914,29 @@ impl<'a> SpecializationStack<'a> {
             symbol: *self.0.last().unwrap(),
         }
     }
+
+    /// The chain of specializations that leads to a specialization requested right now: the
+    /// chain that led to the current suspended specialization, followed by the stack.
+    fn request_chain(&self, arena: &'a Bump, requested_by: &'a [Symbol]) -> &'a [Symbol] {
+        let mut chain = Vec::with_capacity_in(requested_by.len() + self.0.len(), arena);
+        chain.extend_from_slice(requested_by);
+        chain.extend_from_slice(&self.0);
+
+        chain.into_bump_slice()
+    }
+}
+
+/// How many times a function may appear in the chain of specializations that led to one of its
+/// own specializations. Polymorphic recursion would make this chain grow forever, with a new
+/// layout for the function every time around.
+const MAX_SPECIALIZATION_DEPTH: usize = 64;
+
+/// A function kept needing specializations of itself at new layouts, so specialization would
+/// never finish.
+#[derive(Debug)]
+pub struct RunawaySpecialization {
+    /// The function, followed by the functions that led from it back to itself.
+    pub cycle: std::vec::Vec<Symbol>,
 }
 
 pub type HostExposedLambdaSets<'a> =
@@ -909,6 +953,8 @@ pub struct Procs<'a> {
     pub externals_we_need: BumpMap<ModuleId, ExternalSpecializations<'a>>,
     symbol_specializations: SymbolSpecializations<'a>,
     specialization_stack: SpecializationStack<'a>,
+    /// The chain of specializations that led to the suspended specialization being made now
+    requested_by: &'a [Symbol],
 
     pub imported_module_thunks: &'a [Symbol],
     pub module_thunks: &'a [Symbol],
@@ -927,6 +973,7 @@ impl<'a> Procs<'a> {
             host_exposed_lambda_sets: std::vec::Vec::new(),
             symbol_specializations: Default::default(),
             specialization_stack: SpecializationStack(Vec::with_capacity_in(16, arena)),
+            requested_by: &[],
 
             imported_module_thunks: &[],
             module_thunks: &[],
@@ -1073,7 +1120,16 @@ impl<'a> Procs<'a> {
                         (PendingSpecializations::Finding(suspended), _)
                         | (PendingSpecializations::Making(suspended), true) => {
                             // register the pending specialization, so this gets code genned later
-                            suspended.specialization(env.subs, name, layout, annotation);
+                            let requested_by = self
+                                .specialization_stack
+                                .request_chain(env.arena, self.requested_by);
+                            suspended.specialization(
+                                env.subs,
+                                name,
+                                layout,
+                                annotation,
+                                requested_by,
+                            );
 
                             match self.partial_procs.symbol_to_id(name.name()) {
                                 Some(occupied) => {
@@ -1099,6 +1155,8 @@ impl<'a> Procs<'a> {
                                         body: body.value,
                                         body_var: ret_var,
                                         is_self_recursive,
+                                        coverage_region: env.coverage_region(body.region),
+                                        region: Some(body.region),
                                     };
 
                                     self.partial_procs.insert(name.name(), partial_proc);
@@ -1127,6 +1185,8 @@ impl<'a> Procs<'a> {
                                     body: body.value,
                                     body_var: ret_var,
                                     is_self_recursive,
+                                    coverage_region: env.coverage_region(body.region),
+                                    region: Some(body.region),
                                 };
 
                                 self.partial_procs.insert(name.name(), partial_proc)
@@ -1199,7 +1259,10 @@ impl<'a> Procs<'a> {
         ) {
             (PendingSpecializations::Finding(suspended), _)
             | (PendingSpecializations::Making(suspended), true) => {
-                suspended.specialization(env.subs, name, layout, fn_var);
+                let requested_by = self
+                    .specialization_stack
+                    .request_chain(env.arena, self.requested_by);
+                suspended.specialization(env.subs, name, layout, fn_var, requested_by);
             }
             (PendingSpecializations::Making(_), false) => {
                 let proc_name = name;
@@ -1362,9 +1425,25 @@ pub struct Env<'a, 'i> {
     pub exposed_by_module: &'i ExposedByModule,
     pub derived_module: &'i SharedDerivedModule,
     pub struct_indexing: UsageTrackingMap<(Symbol, u64), Symbol>,
+    /// Whether to mark every `when` branch and function with a
+    /// [coverage point][crate::coverage::CoveragePoint].
+    pub instrument_coverage: bool,
+    /// Set when the specializations are no longer needed. [specialize_all] then stops after the
+    /// specialization it is working on.
+    pub cancelled: Option<&'i AtomicBool>,
 }
 
 impl<'a, 'i> Env<'a, 'i> {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .is_some_and(|cancelled| cancelled.load(atomic::Ordering::Acquire))
+    }
+
+    /// The region to give a coverage point, if code at `region` should be marked with one.
+    fn coverage_region(&self, region: Region) -> Option<Region> {
+        (self.instrument_coverage && !self.home.is_builtin()).then_some(region)
+    }
+
     pub fn unique_symbol(&mut self) -> Symbol {
         let ident_id = self.ident_ids.gen_unique();
 
@@ -2185,6 +2264,38 @@ impl<'a> Expr<'a> {
         String::from_utf8(w).unwrap()
     }
 
+    /// Call `f` on every symbol this expression reads.
+    pub fn for_each_symbol(&self, mut f: impl FnMut(&Symbol)) {
+        match self {
+            Expr::Call(call) => call.arguments.iter().for_each(f),
+            Expr::Tag { arguments, .. } | Expr::Struct(arguments) => arguments.iter().for_each(f),
+            Expr::StructAtIndex { structure, .. }
+            | Expr::GetTagId { structure, .. }
+            | Expr::UnionAtIndex { structure, .. }
+            | Expr::GetElementPointer { structure, .. } => f(structure),
+            Expr::Array { elems, .. } => {
+                for elem in elems.iter() {
+                    if let ListLiteralElement::Symbol(symbol) = elem {
+                        f(symbol);
+                    }
+                }
+            }
+            Expr::ErasedMake { value, callee } => {
+                value.iter().for_each(&mut f);
+                f(callee);
+            }
+            Expr::ErasedLoad { symbol, .. }
+            | Expr::Reset { symbol, .. }
+            | Expr::ResetRef { symbol, .. } => f(symbol),
+            Expr::Alloca { initializer, .. } => initializer.iter().for_each(f),
+            Expr::Literal(_)
+            | Expr::NullPointer
+            | Expr::EmptyArray
+            | Expr::FunctionPointer { .. }
+            | Expr::RuntimeErrorFunction(_) => {}
+        }
+    }
+
     pub(crate) fn ptr_load(symbol: &'a Symbol) -> Expr<'a> {
         Expr::Call(Call {
             call_type: CallType::LowLevel {
@@ -2394,6 +2505,72 @@ impl<'a> Stmt<'a> {
         String::from_utf8(w).unwrap()
     }
 
+    /// Call `f` on every symbol read anywhere in this statement, including nested statements.
+    pub fn for_each_symbol(&self, mut f: impl FnMut(&Symbol)) {
+        let mut stack = vec![self];
+
+        while let Some(stmt) = stack.pop() {
+            match stmt {
+                Stmt::Let(_, expr, _, cont) => {
+                    expr.for_each_symbol(&mut f);
+                    stack.push(cont);
+                }
+                Stmt::Switch {
+                    cond_symbol,
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    f(cond_symbol);
+                    stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                    stack.push(default_branch.1);
+                }
+                Stmt::Ret(symbol) | Stmt::Crash(symbol, _) => f(symbol),
+                Stmt::Refcounting(modify_rc, cont) => {
+                    f(&modify_rc.get_symbol());
+                    stack.push(cont);
+                }
+                Stmt::Expect {
+                    condition,
+                    lookups,
+                    remainder,
+                    ..
+                }
+                | Stmt::ExpectFx {
+                    condition,
+                    lookups,
+                    remainder,
+                    ..
+                } => {
+                    f(condition);
+                    lookups.iter().for_each(&mut f);
+                    stack.push(remainder);
+                }
+                Stmt::Dbg {
+                    symbol, remainder, ..
+                } => {
+                    f(symbol);
+                    stack.push(remainder);
+                }
+                Stmt::Join {
+                    body, remainder, ..
+                } => {
+                    stack.push(body);
+                    stack.push(remainder);
+                }
+                Stmt::Jump(_, arguments) => arguments.iter().for_each(&mut f),
+            }
+        }
+    }
+
+    /// Whether `symbol` is read anywhere in this statement.
+    pub fn uses_symbol(&self, symbol: Symbol) -> bool {
+        let mut used = false;
+        self.for_each_symbol(|s| used |= *s == symbol);
+
+        used
+    }
+
     pub fn if_then_else(
         arena: &'a Bump,
         condition_symbol: Symbol,
@@ -2944,7 +3121,7 @@ fn specialize_suspended<'a>(
     procs: &mut Procs<'a>,
     layout_cache: &mut LayoutCache<'a>,
     suspended: Suspended<'a>,
-) {
+) -> Result<(), RunawaySpecialization> {
     let offset_variable = StorageSubs::merge_into(suspended.store, env.subs);
 
     for (i, (symbol_or_lambda, var)) in suspended
@@ -2955,6 +3132,7 @@ fn specialize_suspended<'a>(
     {
         let name = *symbol_or_lambda;
         let outside_layout = suspended.layouts[i];
+        let requested_by = suspended.requested_by[i];
 
         let var = offset_variable(*var);
 
@@ -2986,7 +3164,27 @@ fn specialize_suspended<'a>(
             }
         };
 
-        match specialize_variable(env, procs, name, layout_cache, var, partial_proc) {
+        let depth = requested_by
+            .iter()
+            .filter(|symbol| **symbol == name.name())
+            .count();
+
+        if depth > MAX_SPECIALIZATION_DEPTH {
+            let start = requested_by
+                .iter()
+                .rposition(|symbol| *symbol == name.name())
+                .unwrap();
+
+            return Err(RunawaySpecialization {
+                cycle: requested_by[start..].to_vec(),
+            });
+        }
+
+        procs.requested_by = requested_by;
+        let specialized = specialize_variable(env, procs, name, layout_cache, var, partial_proc);
+        procs.requested_by = &[];
+
+        match specialized {
             Ok((proc, raw_layout)) => {
                 let proc_layout = ProcLayout::from_raw_named(env.arena, name, raw_layout);
                 procs
@@ -3006,6 +3204,8 @@ fn specialize_suspended<'a>(
             }
         }
     }
+
+    Ok(())
 }
 
 pub fn specialize_all<'a>(
@@ -3014,7 +3214,7 @@ pub fn specialize_all<'a>(
     externals_others_need: std::vec::Vec<ExternalSpecializations<'a>>,
     specializations_for_host: HostSpecializations<'a>,
     layout_cache: &mut LayoutCache<'a>,
-) -> Procs<'a> {
+) -> Result<Procs<'a>, RunawaySpecialization> {
     // When calling from_can, pending_specializations should be unavailable.
     // This must be a single pass, and we must not add any more entries to it!
     let pending_specializations = std::mem::replace(
@@ -3025,7 +3225,7 @@ pub fn specialize_all<'a>(
     // Add all of our existing pending specializations.
     match pending_specializations {
         PendingSpecializations::Finding(suspended) => {
-            specialize_suspended(env, &mut procs, layout_cache, suspended)
+            specialize_suspended(env, &mut procs, layout_cache, suspended)?
         }
         PendingSpecializations::Making(suspended) => {
             debug_assert!(
@@ -3037,6 +3237,10 @@ pub fn specialize_all<'a>(
 
     // Specialize all the symbols everyone else needs.
     for externals in externals_others_need {
+        if env.is_cancelled() {
+            return Ok(procs);
+        }
+
         specialize_external_specializations(env, &mut procs, layout_cache, externals);
     }
 
@@ -3046,13 +3250,17 @@ pub fn specialize_all<'a>(
     // Now, we must go through and continuously complete any new suspended specializations that were
     // discovered in specializing the other demanded symbols.
     while !procs.pending_specializations.is_empty() {
+        if env.is_cancelled() {
+            return Ok(procs);
+        }
+
         let pending_specializations = std::mem::replace(
             &mut procs.pending_specializations,
             PendingSpecializations::Making(Suspended::new_in(env.arena)),
         );
         match pending_specializations {
             PendingSpecializations::Making(suspended) => {
-                specialize_suspended(env, &mut procs, layout_cache, suspended);
+                specialize_suspended(env, &mut procs, layout_cache, suspended)?;
             }
             PendingSpecializations::Finding(_) => {
                 internal_error!("should not have this variant after making specializations")
@@ -3066,7 +3274,7 @@ pub fn specialize_all<'a>(
         &procs.symbol_specializations
     );
 
-    procs
+    Ok(procs)
 }
 
 fn specialize_host_specializations<'a>(
@@ -3535,10 +3743,31 @@ fn specialize_proc_help<'a>(
         SelfRecursive::NotSelfRecursive
     };
 
+    let _compiling = partial_proc.region.map(roc_region::compiling::enter);
     let body = partial_proc.body.clone();
     let body_var = partial_proc.body_var;
+    let coverage_region = partial_proc.coverage_region;
 
-    let mut specialized_body = from_can(env, body_var, body, procs, layout_cache);
+    let mut specialized_body = match target_constant(lambda_name.name(), env.target) {
+        // The definitions of the `Target` constants are placeholders for type checking.
+        Some(value) => {
+            let symbol = env.unique_symbol();
+            let ret = env.arena.alloc(Stmt::Ret(symbol));
+
+            Stmt::Let(
+                symbol,
+                Expr::Literal(Literal::Bool(value)),
+                Layout::BOOL,
+                ret,
+            )
+        }
+        None => from_can(env, body_var, body, procs, layout_cache),
+    };
+
+    if let Some(region) = coverage_region {
+        specialized_body =
+            mark_coverage_point(env, CoverageKind::Function, region, specialized_body);
+    }
 
     let specialized_proc = match specialized {
         SpecializedLayout::FunctionPointerBody {
@@ -4261,6 +4490,34 @@ fn try_make_literal<'a>(
     }
 }
 
+/// `a && b` and `a || b` only evaluate `b` when `a` doesn't already decide the result, so they
+/// are lowered like `if a then b else Bool.false` and `if a then Bool.true else b`. They stay
+/// calls until now, so type errors in them are still reported as errors in `&&` and `||`.
+fn short_circuit_bin_op(
+    op: BinOp,
+    ret_var: Variable,
+    loc_args: std::vec::Vec<(Variable, Loc<roc_can::expr::Expr>)>,
+) -> roc_can::expr::Expr {
+    let [(cond_var, loc_left), (_, loc_right)]: [_; 2] = loc_args
+        .try_into()
+        .unwrap_or_else(|_| internal_error!("{op:?} must have exactly 2 arguments"));
+
+    let decided = |symbol| Loc::at(loc_left.region, roc_can::expr::Expr::Var(symbol, ret_var));
+
+    let (loc_then, loc_else) = match op {
+        BinOp::And => (loc_right, decided(Symbol::BOOL_FALSE)),
+        BinOp::Or => (decided(Symbol::BOOL_TRUE), loc_right),
+        _ => internal_error!("{op:?} does not short-circuit"),
+    };
+
+    roc_can::expr::Expr::If {
+        cond_var,
+        branch_var: ret_var,
+        branches: vec![(loc_left, loc_then)],
+        final_else: Box::new(loc_else),
+    }
+}
+
 pub fn with_hole<'a>(
     env: &mut Env<'a, '_>,
     can_expr: roc_can::expr::Expr,
@@ -4635,6 +4892,20 @@ pub fn with_hole<'a>(
             branches,
             final_else,
         } => {
+            let (branches, final_else) = prune_target_branches(env.target, branches, *final_else);
+
+            if branches.is_empty() {
+                return with_hole(
+                    env,
+                    final_else.value,
+                    branch_var,
+                    procs,
+                    layout_cache,
+                    assigned,
+                    hole,
+                );
+            }
+
             match (
                 layout_cache.from_var(env.arena, branch_var, env.subs),
                 layout_cache.from_var(env.arena, cond_var, env.subs),
@@ -5400,6 +5671,12 @@ pub fn with_hole<'a>(
             }
         }
 
+        Call(boxed, loc_args, CalledVia::BinOp(op @ (BinOp::And | BinOp::Or))) => {
+            let lowered = short_circuit_bin_op(op, boxed.3, loc_args);
+
+            with_hole(env, lowered, variable, procs, layout_cache, assigned, hole)
+        }
+
         Call(boxed, loc_args, _) => {
             let (fn_var, loc_expr, _lambda_set_var, _ret_var) = *boxed;
 
@@ -6962,6 +7239,12 @@ pub fn from_can<'a>(
             branches,
             final_else,
         } => {
+            let (branches, final_else) = prune_target_branches(env.target, branches, *final_else);
+
+            if branches.is_empty() {
+                return from_can(env, branch_var, final_else.value, procs, layout_cache);
+            }
+
             let ret_layout = return_on_layout_error!(
                 env,
                 layout_cache.from_var(env.arena, branch_var, env.subs),
@@ -7220,6 +7503,7 @@ fn to_opt_branches<'a>(
     Pattern<'a>,
     Option<Loc<roc_can::expr::Expr>>,
     roc_can::expr::Expr,
+    Option<Region>,
 )> {
     debug_assert!(!branches.is_empty());
 
@@ -7264,7 +7548,12 @@ fn to_opt_branches<'a>(
                     };
 
                     // TODO remove clone?
-                    opt_branches.push((mono_pattern, when_branch.guard.clone(), loc_expr.value));
+                    opt_branches.push((
+                        mono_pattern,
+                        when_branch.guard.clone(),
+                        loc_expr.value,
+                        Some(when_branch.value.region),
+                    ));
                 }
                 Err(runtime_error) => {
                     // TODO remove clone?
@@ -7272,6 +7561,7 @@ fn to_opt_branches<'a>(
                         Pattern::Underscore,
                         when_branch.guard.clone(),
                         roc_can::expr::Expr::RuntimeError(runtime_error),
+                        Some(when_branch.value.region),
                     ));
                 }
             }
@@ -7285,6 +7575,7 @@ fn to_opt_branches<'a>(
             Pattern::Underscore,
             None,
             roc_can::expr::Expr::RuntimeError(roc_problem::can::RuntimeError::NonExhaustivePattern),
+            None,
         ));
     }
 
@@ -7325,7 +7616,7 @@ fn from_can_when<'a>(
     let arena = env.arena;
     let it = opt_branches
         .into_iter()
-        .filter_map(|(pattern, opt_guard, can_expr)| {
+        .filter_map(|(pattern, opt_guard, can_expr, opt_region)| {
             // If the pattern has a void layout we can drop it; however, we must still perform the
             // work of building the body, because that may contain specializations we must
             // discover for use elsewhere. See
@@ -7353,6 +7644,11 @@ fn from_can_when<'a>(
                 }
             };
 
+            let branch_stmt = match opt_region.and_then(|region| env.coverage_region(region)) {
+                Some(region) => mark_coverage_point(env, CoverageKind::Branch, region, branch_stmt),
+                None => branch_stmt,
+            };
+
             use decision_tree::Guard;
             let result = if let Some(loc_expr) = opt_guard {
                 let guard_spec = GuardStmtSpec {
@@ -7392,6 +7688,36 @@ fn from_can_when<'a>(
     )
 }
 
+/// Prefix a `when` branch or function body with a call that records it was run; see
+/// [crate::coverage].
+fn mark_coverage_point<'a>(
+    env: &mut Env<'a, '_>,
+    kind: CoverageKind,
+    region: Region,
+    stmt: Stmt<'a>,
+) -> Stmt<'a> {
+    let point = crate::coverage::CoveragePoint {
+        module_id: env.home,
+        kind,
+        region,
+    };
+
+    let call = Call {
+        call_type: CallType::Foreign {
+            foreign_symbol: point.foreign_symbol(),
+            ret_layout: Layout::UNIT,
+        },
+        arguments: &[],
+    };
+
+    Stmt::Let(
+        env.unique_symbol(),
+        Expr::Call(call),
+        Layout::UNIT,
+        env.arena.alloc(stmt),
+    )
+}
+
 /// A functor to generate IR for a guard under a `when` branch.
 /// Used in the decision tree compiler, after building a decision tree and converting into IR.
 ///
@@ -8917,7 +9243,16 @@ fn call_by_name_help<'a>(
                 debug_assert!(!env.is_imported_symbol(proc_name.name()));
 
                 // register the pending specialization, so this gets code genned later
-                suspended.specialization(env.subs, proc_name, top_level_layout, fn_var);
+                let requested_by = procs
+                    .specialization_stack
+                    .request_chain(env.arena, procs.requested_by);
+                suspended.specialization(
+                    env.subs,
+                    proc_name,
+                    top_level_layout,
+                    fn_var,
+                    requested_by,
+                );
 
                 debug_assert_eq!(
                     argument_layouts.len(),
@@ -9081,11 +9416,15 @@ fn call_by_name_module_thunk<'a>(
                 debug_assert!(!env.is_imported_symbol(proc_name));
 
                 // register the pending specialization, so this gets code genned later
+                let requested_by = procs
+                    .specialization_stack
+                    .request_chain(env.arena, procs.requested_by);
                 suspended.specialization(
                     env.subs,
                     LambdaName::no_niche(proc_name),
                     top_level_layout,
                     fn_var,
+                    requested_by,
                 );
 
                 force_thunk(env, proc_name, inner_layout, assigned, hole)
@@ -0,0 +1,56 @@
+//! The constants of the `Target` module, like `Target.isWasm`, whose values depend on the target
+//! we're compiling for.
+
+use roc_can::expr::Expr;
+use roc_module::symbol::Symbol;
+use roc_region::all::Loc;
+use roc_target::{Architecture, OperatingSystem, Target};
+
+type Branches = Vec<(Loc<Expr>, Loc<Expr>)>;
+
+/// The value of a `Target` constant for the given target, or `None` for any other symbol.
+pub fn target_constant(symbol: Symbol, target: Target) -> Option<bool> {
+    match symbol {
+        Symbol::TARGET_IS_WASM => Some(target.architecture() == Architecture::Wasm32),
+        Symbol::TARGET_IS_WINDOWS => Some(target.operating_system() == OperatingSystem::Windows),
+        Symbol::TARGET_IS_MAC => Some(target.operating_system() == OperatingSystem::Mac),
+        Symbol::TARGET_IS_LINUX => Some(target.operating_system() == OperatingSystem::Linux),
+        _ => None,
+    }
+}
+
+/// The value of an `if` condition that is a `Target` constant, or its negation.
+fn known_condition(expr: &Expr, target: Target) -> Option<bool> {
+    match expr {
+        Expr::Var(symbol, _) => target_constant(*symbol, target),
+        Expr::Call(fun, args, _) => match (&fun.1.value, args.as_slice()) {
+            (Expr::Var(Symbol::BOOL_NOT, _), [(_, arg)]) => {
+                known_condition(&arg.value, target).map(|value| !value)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Drops the branches of an `if` that can't be taken on the target, and ends it at the first
+/// branch that always is, so the code that only runs on other targets is never specialized.
+///
+/// When no branches are left, the `if` is just the returned `else`.
+pub fn prune_target_branches(
+    target: Target,
+    branches: Branches,
+    final_else: Loc<Expr>,
+) -> (Branches, Loc<Expr>) {
+    let mut kept = Vec::with_capacity(branches.len());
+
+    for (loc_cond, loc_then) in branches {
+        match known_condition(&loc_cond.value, target) {
+            Some(true) => return (kept, loc_then),
+            Some(false) => {}
+            None => kept.push((loc_cond, loc_then)),
+        }
+    }
+
+    (kept, final_else)
+}
@@ -1,5 +1,4 @@
 use crate::ir::Parens;
-use crate::layout::intern::NeedsRecursionPointerFixup;
 use bitvec::vec::BitVec;
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
@@ -28,12 +27,15 @@ use ven_pretty::{DocAllocator, DocBuilder};
 
 mod erased;
 mod intern;
+mod report;
 mod semantic;
 
 pub use erased::Erased;
 pub use intern::{
-    GlobalLayoutInterner, InLayout, LayoutInterner, STLayoutInterner, TLLayoutInterner,
+    GlobalLayoutInterner, InLayout, LayoutInterner, NeedsRecursionPointerFixup, STLayoutInterner,
+    TLLayoutInterner,
 };
+pub use report::layout_report;
 pub use semantic::SemanticRepr;
 
 // if your changes cause this number to go down, great!
@@ -2564,9 +2566,7 @@ impl<'a> Layout<'a> {
         for tag in slices {
             let mut total = 0;
             for layout in tag.iter() {
-                let (stack_size, alignment) = interner
-                    .get_repr(*layout)
-                    .stack_size_and_alignment(interner);
+                let (stack_size, alignment) = interner.stack_size_and_alignment(*layout);
                 total += stack_size;
                 data_align = data_align.max(alignment);
             }
@@ -2744,7 +2744,7 @@ impl<'a> LayoutRepr<'a> {
                 let mut sum = 0;
 
                 for field_layout in *field_layouts {
-                    sum += interner.get_repr(*field_layout).stack_size(interner);
+                    sum += interner.stack_size(*field_layout);
                 }
 
                 sum
@@ -2768,7 +2768,7 @@ impl<'a> LayoutRepr<'a> {
         match self {
             Struct(field_layouts) => field_layouts
                 .iter()
-                .map(|x| interner.get_repr(*x).alignment_bytes(interner))
+                .map(|x| interner.alignment_bytes(*x))
                 .max()
                 .unwrap_or(0),
 
@@ -572,6 +572,9 @@ pub struct STLayoutInterner<'a> {
     normalized_lambda_set_map: BumpMap<LambdaSet<'a>, LambdaSet<'a>>,
     vec: Vec<Layout<'a>>,
     target: Target,
+    /// The stack size and alignment of each interned layout, filled in the first time they are
+    /// asked for. Interned layouts never change, so neither do these.
+    size_and_alignment: RefCell<Vec<Option<(u32, u32)>>>,
 }
 
 /// Interner constructed with an exclusive lock over [GlobalLayoutInterner]
@@ -644,6 +647,7 @@ impl<'a> GlobalLayoutInterner<'a> {
             normalized_lambda_set_map,
             vec,
             target,
+            size_and_alignment: Default::default(),
         })
     }
 
@@ -934,6 +938,7 @@ impl<'a> STLayoutInterner<'a> {
             normalized_lambda_set_map: BumpMap::with_capacity_and_hasher(cap, default_hasher()),
             vec: Vec::with_capacity(cap),
             target,
+            size_and_alignment: Default::default(),
         };
         fill_reserved_layouts(&mut interner);
         interner
@@ -949,6 +954,7 @@ impl<'a> STLayoutInterner<'a> {
             normalized_lambda_set_map,
             vec,
             target,
+            size_and_alignment: _,
         } = self;
         GlobalLayoutInterner(Arc::new(GlobalLayoutInternerInner {
             map: Mutex::new(map),
@@ -961,10 +967,29 @@ impl<'a> STLayoutInterner<'a> {
     pub fn is_empty(&self) -> bool {
         self.vec.is_empty()
     }
+
+    fn cached_size_and_alignment(&self, layout: InLayout<'a>) -> (u32, u32) {
+        if let Some(Some(cached)) = self.size_and_alignment.borrow().get(layout.0) {
+            return *cached;
+        }
+
+        let size_and_alignment = self.get_repr(layout).stack_size_and_alignment(self);
+
+        // A slot that is still being filled in holds a placeholder, whose size means nothing.
+        if self.vec[layout.0] != Layout::VOID_NAKED {
+            let mut cache = self.size_and_alignment.borrow_mut();
+            if cache.len() <= layout.0 {
+                cache.resize(self.vec.len(), None);
+            }
+            cache[layout.0] = Some(size_and_alignment);
+        }
+
+        size_and_alignment
+    }
 }
 
 macro_rules! st_impl {
-    ($($lt:lifetime)? $interner:ident) => {
+    ($($lt:lifetime)? $interner:ident $(, $($extra:tt)*)?) => {
         impl<'a$(, $lt)?> LayoutInterner<'a> for $interner<'a$(, $lt)?> {
             fn insert(&mut self, value: Layout<'a>) -> InLayout<'a> {
                 let hash = hash(value);
@@ -1069,11 +1094,26 @@ macro_rules! st_impl {
             fn target(&self) -> Target{
                 self.target
             }
+
+            $($($extra)*)?
         }
     };
 }
 
-st_impl!(STLayoutInterner);
+st_impl! {
+    STLayoutInterner,
+    fn alignment_bytes(&self, layout: InLayout<'a>) -> u32 {
+        self.cached_size_and_alignment(layout).1
+    }
+
+    fn stack_size(&self, layout: InLayout<'a>) -> u32 {
+        self.cached_size_and_alignment(layout).0
+    }
+
+    fn stack_size_and_alignment(&self, layout: InLayout<'a>) -> (u32, u32) {
+        self.cached_size_and_alignment(layout)
+    }
+}
 st_impl!('r LockedGlobalInterner);
 
 mod reify {
@@ -2027,3 +2067,34 @@ mod insert_recursive_layout {
         assert_eq!(in1, in2);
     }
 }
+
+#[cfg(test)]
+mod stack_size_and_alignment {
+    use bumpalo::Bump;
+    use roc_target::Target;
+
+    use crate::layout::{Layout, LayoutRepr};
+
+    use super::{LayoutInterner, STLayoutInterner};
+
+    const TARGET: Target = Target::LinuxX64;
+
+    #[test]
+    fn cached_matches_computed() {
+        let arena = &Bump::new();
+        let mut interner = STLayoutInterner::with_capacity(4, TARGET);
+
+        let inner = interner.insert_direct_no_semantic(LayoutRepr::struct_(
+            &*arena.alloc([Layout::U8, Layout::U64]),
+        ));
+        let outer = interner
+            .insert_direct_no_semantic(LayoutRepr::struct_(&*arena.alloc([inner, Layout::U16])));
+
+        let computed = interner.get_repr(outer).stack_size_and_alignment(&interner);
+
+        assert_eq!(interner.stack_size_and_alignment(outer), computed);
+        assert_eq!(interner.stack_size_and_alignment(outer), (24, 8));
+        assert_eq!(interner.stack_size(inner), 16);
+        assert_eq!(interner.alignment_bytes(inner), 8);
+    }
+}
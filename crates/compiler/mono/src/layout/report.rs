@@ -0,0 +1,265 @@
+//! A readable description of a layout, for tools that explain how values are represented, like
+//! `roc info --layouts` and the REPL's `:layout`.
+
+use std::fmt::Write;
+
+use bumpalo::Bump;
+use roc_target::Target;
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+use roc_types::types::RecordField;
+
+use super::{cmp_fields, Builtin, InLayout, LayoutCache, LayoutInterner, LayoutRepr, UnionLayout};
+
+/// Describes the layout of the variable's type: its size and alignment, the offsets of its
+/// fields, and how tag unions store their tag id. Field and tag names come from the type.
+pub fn layout_report<'a>(
+    arena: &'a Bump,
+    layout_cache: &mut LayoutCache<'a>,
+    subs: &Subs,
+    var: Variable,
+    layout: InLayout<'a>,
+) -> String {
+    let mut report = String::new();
+    let (size, alignment) = layout_cache.interner.stack_size_and_alignment(layout);
+
+    // Writing to a String can't fail.
+    let _ = writeln!(report, "size {size}, alignment {alignment}");
+
+    match layout_cache.interner.runtime_representation(layout) {
+        LayoutRepr::Struct(field_layouts) => {
+            let names = record_field_names(arena, layout_cache, subs, var)
+                .filter(|names| names.len() == field_layouts.len());
+
+            let _ = writeln!(report, "\nfields, in memory order");
+
+            write_fields(
+                &mut report,
+                &layout_cache.interner,
+                field_layouts,
+                names.as_deref().unwrap_or(&[]),
+            );
+        }
+        LayoutRepr::Union(union_layout) => {
+            let target = layout_cache.target;
+
+            write_union(
+                &mut report,
+                &layout_cache.interner,
+                union_layout,
+                tag_names(subs, var),
+                target,
+            );
+        }
+        LayoutRepr::Builtin(Builtin::Bool | Builtin::Int(_)) => match tag_names(subs, var) {
+            Some(names) => {
+                let _ = writeln!(
+                    report,
+                    "none of the tags have a payload, so the value is just the tag id\n"
+                );
+
+                for (tag_id, name) in names.iter().enumerate() {
+                    let _ = writeln!(report, "tag {tag_id}  {name}");
+                }
+            }
+            None => {
+                let _ = writeln!(report, "layout {}", layout_cache.interner.dbg(layout));
+            }
+        },
+        _ => {
+            let _ = writeln!(report, "layout {}", layout_cache.interner.dbg(layout));
+        }
+    }
+
+    report
+}
+
+fn actual_content(subs: &Subs, mut var: Variable) -> &Content {
+    while let Content::Alias(_, _, real_var, _) = subs.get_content_without_compacting(var) {
+        var = *real_var;
+    }
+
+    subs.get_content_without_compacting(var)
+}
+
+/// The names of a record's fields, in the order the layout stores them.
+fn record_field_names<'a>(
+    arena: &'a Bump,
+    layout_cache: &mut LayoutCache<'a>,
+    subs: &Subs,
+    var: Variable,
+) -> Option<Vec<String>> {
+    let (fields, ext) = match actual_content(subs, var) {
+        Content::Structure(FlatType::Record(fields, ext)) => (fields, *ext),
+        _ => return None,
+    };
+
+    let mut sortables = Vec::with_capacity(fields.len());
+
+    for (label, field) in fields.unsorted_iterator(subs, ext).ok()? {
+        match field {
+            RecordField::Required(field_var)
+            | RecordField::Demanded(field_var)
+            | RecordField::RigidRequired(field_var) => {
+                let field_layout = layout_cache.from_var(arena, field_var, subs).ok()?;
+
+                sortables.push((label, field_layout));
+            }
+            RecordField::Optional(_) | RecordField::RigidOptional(_) => {
+                // optional fields aren't part of the layout
+            }
+        }
+    }
+
+    // The same order as the layout itself uses
+    sortables.sort_by(|(label1, layout1), (label2, layout2)| {
+        cmp_fields(&layout_cache.interner, label1, *layout1, label2, *layout2)
+    });
+
+    Some(
+        sortables
+            .into_iter()
+            .map(|(label, _)| label.to_string())
+            .collect(),
+    )
+}
+
+/// The names of a tag union's tags, in the order of their tag ids.
+fn tag_names(subs: &Subs, var: Variable) -> Option<Vec<String>> {
+    let (tags, ext) = match actual_content(subs, var) {
+        Content::Structure(FlatType::TagUnion(tags, ext))
+        | Content::Structure(FlatType::RecursiveTagUnion(_, tags, ext)) => (tags, *ext),
+        _ => return None,
+    };
+
+    let (tags, _) = tags.sorted_iterator_and_ext(subs, ext);
+
+    Some(tags.map(|(name, _)| name.0.as_str().to_string()).collect())
+}
+
+fn write_fields<'a>(
+    report: &mut String,
+    interner: &impl LayoutInterner<'a>,
+    fields: &[InLayout<'a>],
+    names: &[String],
+) {
+    let mut offset = 0;
+
+    for (index, field) in fields.iter().enumerate() {
+        let (size, alignment) = interner.stack_size_and_alignment(*field);
+        let name = match names.get(index) {
+            Some(name) => format!("{name} : "),
+            None => String::new(),
+        };
+
+        let _ = writeln!(
+            report,
+            "    offset {offset:<4} {name}{}  (size {size}, alignment {alignment})",
+            interner.dbg(*field)
+        );
+
+        // Fields are sorted by alignment, so they follow each other without padding.
+        offset += interner.stack_size(*field);
+    }
+}
+
+fn write_union<'a>(
+    report: &mut String,
+    interner: &impl LayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+    names: Option<Vec<String>>,
+    target: Target,
+) {
+    let discriminant_size = union_layout.discriminant().stack_size();
+    let tag_id_offset = union_layout.tag_id_offset(interner).unwrap_or(0);
+    let tag_id_location = || {
+        if union_layout.stores_tag_id_in_pointer(target) {
+            "the tag id is stored in the low bits of the pointer".to_string()
+        } else {
+            format!(
+                "the tag id is a {discriminant_size}-byte integer at offset {tag_id_offset} of the allocation, after the payload"
+            )
+        }
+    };
+
+    // The payload of each tag, by tag id, where None is the tag that is stored as a null pointer
+    let mut payloads: Vec<Option<&[InLayout<'a>]>> = Vec::new();
+
+    let strategy = match union_layout {
+        UnionLayout::NonRecursive(tags) => {
+            payloads.extend(tags.iter().map(|fields| Some(*fields)));
+
+            format!(
+                "the tag id is a {discriminant_size}-byte integer at offset {tag_id_offset}, after the payload"
+            )
+        }
+        UnionLayout::Recursive(tags) => {
+            payloads.extend(tags.iter().map(|fields| Some(*fields)));
+
+            format!(
+                "a pointer to a heap allocation holding the payload, and {}",
+                tag_id_location()
+            )
+        }
+        UnionLayout::NonNullableUnwrapped(fields) => {
+            payloads.push(Some(fields));
+
+            "a pointer to a heap allocation holding the payload of the only tag, without a tag id"
+                .to_string()
+        }
+        UnionLayout::NullableWrapped {
+            nullable_id,
+            other_tags,
+        } => {
+            let mut other_tags = other_tags.iter();
+
+            for tag_id in 0..=other_tags.len() {
+                if tag_id == nullable_id as usize {
+                    payloads.push(None);
+                } else {
+                    payloads.push(other_tags.next().copied());
+                }
+            }
+
+            format!(
+                "a null pointer for tag {nullable_id}, and otherwise a pointer to a heap allocation holding the payload, and {}",
+                tag_id_location()
+            )
+        }
+        UnionLayout::NullableUnwrapped {
+            nullable_id,
+            other_fields,
+        } => {
+            if nullable_id {
+                payloads.extend([Some(other_fields), None]);
+            } else {
+                payloads.extend([None, Some(other_fields)]);
+            }
+
+            format!(
+                "a null pointer for tag {}, and otherwise a pointer to a heap allocation holding the payload, without a tag id",
+                nullable_id as u8
+            )
+        }
+    };
+
+    let _ = writeln!(report, "{strategy}");
+
+    let names = names
+        .filter(|names| names.len() == payloads.len())
+        .unwrap_or_default();
+
+    for (tag_id, payload) in payloads.into_iter().enumerate() {
+        let name = names.get(tag_id).map(String::as_str).unwrap_or("");
+
+        match payload {
+            Some(fields) => {
+                let _ = writeln!(report, "\ntag {tag_id}  {name}");
+
+                write_fields(report, interner, fields, &[]);
+            }
+            None => {
+                let _ = writeln!(report, "\ntag {tag_id}  {name}  (null)");
+            }
+        }
+    }
+}
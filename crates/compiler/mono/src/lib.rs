@@ -11,10 +11,16 @@
 
 pub mod borrow;
 pub mod code_gen_help;
+pub mod coverage;
+pub mod dead_code;
 pub mod drop_specialization;
+pub mod fold_constants;
 pub mod inc_dec;
+pub mod interpret;
 pub mod ir;
 pub mod layout;
+pub mod list_fusion;
+pub mod list_walk;
 pub mod low_level;
 pub mod reset_reuse;
 pub mod tail_recursion;
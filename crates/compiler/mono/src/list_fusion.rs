@@ -0,0 +1,744 @@
+//! Fusion of chained `List.map`, `List.keepIf` and `List.walk` calls.
+//!
+//! A pipeline like `list |> List.map f |> List.map g` allocates an intermediate list that is
+//! only ever read by the second `List.map`. When that list is used nowhere else, and neither
+//! function can be observed doing anything besides returning a value, the two calls are replaced
+//! by a single call with a new function that does the work of both:
+//!
+//! - `List.map f` followed by `List.map g` becomes a `List.map` with `\x -> g (f x)`
+//! - `List.map f` followed by `List.walk state step` becomes a `List.walk` with
+//!   `\s, x -> step s (f x)`
+//! - `List.keepIf p` followed by `List.walk state step` becomes a `List.walk` with
+//!   `\s, x -> if p x then step s x else s`
+//!
+//! Longer chains fuse one step at a time, so `list |> List.map f |> List.keepIf p |> List.walk
+//! state step` becomes a single walk over `list`. This pass runs after [crate::list_walk], which
+//! turns `List.walk` calls with a single known function into the `ListWalk` lowlevel, so that is
+//! the form of `List.walk` it fuses. `List.keepIf` is an ordinary Roc function, already
+//! specialized to its predicate, so a `List.map` in front of it, or a `List.keepIf` whose result
+//! isn't walked, is left alone: fusing those would mean specializing `List.keepIf` again.
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+use crate::ir::{
+    BranchInfo, Call, CallType, Expr, HigherOrderLowLevel, PassedFunction, Proc, ProcLayout,
+    SelfRecursive, Stmt, UpdateModeId,
+};
+use crate::layout::{
+    ClosureCallOptions, InLayout, LambdaName, Layout, LayoutInterner, LayoutRepr,
+    NeedsRecursionPointerFixup, STLayoutInterner,
+};
+use crate::low_level::HigherOrder;
+
+pub fn fuse_list_calls<'a>(
+    arena: &'a Bump,
+    interner: &mut STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let mut env = Env {
+        arena,
+        interner,
+        home,
+        ident_ids,
+        procs,
+        purity: MutMap::default(),
+        new_procs: std::vec::Vec::new(),
+        uses: MutMap::default(),
+    };
+
+    let mut fused_bodies = std::vec::Vec::new();
+    let original_procs = env.procs;
+
+    for (key, proc) in original_procs.iter() {
+        env.uses.clear();
+        count_uses(&proc.body, &mut env.uses);
+
+        let before = env.new_procs.len();
+        let body = env.fuse_stmt(&proc.body);
+
+        if env.new_procs.len() > before {
+            fused_bodies.push((*key, body.clone()));
+        }
+    }
+
+    let new_procs = std::mem::take(&mut env.new_procs);
+
+    for (key, body) in fused_bodies {
+        if let Some(proc) = procs.get_mut(&key) {
+            proc.body = body;
+        }
+    }
+
+    for (key, proc) in new_procs {
+        procs.insert(key, proc);
+    }
+}
+
+struct Env<'a, 'i> {
+    arena: &'a Bump,
+    interner: &'i mut STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    procs: &'i MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    /// Whether a procedure is known to have no observable effects
+    purity: MutMap<(Symbol, ProcLayout<'a>), bool>,
+    new_procs: std::vec::Vec<((Symbol, ProcLayout<'a>), Proc<'a>)>,
+    /// How often each symbol is used in the procedure being fused
+    uses: MutMap<Symbol, usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Map,
+    KeepIf,
+    Walk { state: Symbol },
+}
+
+/// A `List.map`, `List.keepIf` or `List.walk` call, as it appears in a `let`
+struct ListCall<'a> {
+    operation: Operation,
+    xs: Symbol,
+    function: PassedFunction<'a>,
+    closure_env_layout: Option<InLayout<'a>>,
+    update_mode: UpdateModeId,
+}
+
+impl<'a> ListCall<'a> {
+    /// The layout of the captured environment, if the function takes one.
+    fn captures(&self) -> Option<InLayout<'a>> {
+        if self.function.name.no_captures() {
+            None
+        } else {
+            self.function.argument_layouts.last().copied()
+        }
+    }
+
+    /// Whether the captured environment is passed exactly when the function expects it, which
+    /// is not the case for a function without captures in a lambda set with captures.
+    fn is_simple(&self) -> bool {
+        self.captures().is_some() == self.closure_env_layout.is_some()
+    }
+}
+
+impl<'a, 'i> Env<'a, 'i> {
+    fn fuse_stmt(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let mut lets = std::vec::Vec::new();
+        let mut current: &Stmt<'a> = stmt;
+
+        while let Stmt::Let(symbol, expr, layout, cont) = current {
+            lets.push((*symbol, expr.clone(), *layout));
+            current = cont;
+        }
+
+        let tail = self.fuse_tail(current);
+
+        // Symbols defined by a `List.map` or `List.keepIf` in this chain of lets, with the index
+        // of that let
+        let mut producers: MutMap<Symbol, usize> = MutMap::default();
+        // A let becomes `None` when the list it defines is fused away
+        let mut fused: std::vec::Vec<Option<(Symbol, Expr<'a>, InLayout<'a>)>> =
+            std::vec::Vec::with_capacity(lets.len());
+
+        for (symbol, mut expr, layout) in lets {
+            // Fuse the call with the one that made its list, and then the result with the one
+            // before that, for as long as that works.
+            while let Some(second) = self.list_call(&expr) {
+                let first = producers.get(&second.xs).and_then(|index| {
+                    let (_, first_expr, _) = fused[*index].as_ref()?;
+                    Some((*index, self.list_call(first_expr)?))
+                });
+
+                let (first_index, first) = match first {
+                    Some(first) => first,
+                    None => break,
+                };
+
+                match self.fuse(&first, &second) {
+                    Some((lets, fused_expr)) => {
+                        producers.remove(&second.xs);
+                        fused[first_index] = None;
+                        fused.extend(lets.into_iter().map(Some));
+                        expr = fused_expr;
+                    }
+                    None => break,
+                }
+            }
+
+            if let Some(Operation::Map | Operation::KeepIf) =
+                self.list_call(&expr).map(|call| call.operation)
+            {
+                producers.insert(symbol, fused.len());
+            }
+
+            fused.push(Some((symbol, expr, layout)));
+        }
+
+        let mut result = tail;
+
+        for (symbol, expr, layout) in fused.into_iter().flatten().rev() {
+            result = self.arena.alloc(Stmt::Let(symbol, expr, layout, result));
+        }
+
+        result
+    }
+
+    fn fuse_tail(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        match stmt {
+            Stmt::Let(..) => self.fuse_stmt(stmt),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(id, info, branch)| {
+                        (*id, info.clone(), self.fuse_stmt(branch).clone())
+                    }),
+                    arena,
+                );
+
+                arena.alloc(Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (default_branch.0.clone(), self.fuse_stmt(default_branch.1)),
+                    ret_layout: *ret_layout,
+                })
+            }
+            Stmt::Refcounting(modify_rc, cont) => {
+                arena.alloc(Stmt::Refcounting(*modify_rc, self.fuse_stmt(cont)))
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => arena.alloc(Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups: lookups,
+                variables: variables,
+                remainder: self.fuse_stmt(remainder),
+            }),
+            Stmt::ExpectFx {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => arena.alloc(Stmt::ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups: lookups,
+                variables: variables,
+                remainder: self.fuse_stmt(remainder),
+            }),
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => arena.alloc(Stmt::Dbg {
+                source_location: source_location,
+                source: source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.fuse_stmt(remainder),
+            }),
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => arena.alloc(Stmt::Join {
+                id: *id,
+                parameters: parameters,
+                body: self.fuse_stmt(body),
+                remainder: self.fuse_stmt(remainder),
+            }),
+            Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => arena.alloc(stmt.clone()),
+        }
+    }
+
+    /// Recognizes the calls this pass can fuse. By the time it runs, `List.map` is still a call to
+    /// the function that wraps the `ListMap` lowlevel, so both forms count.
+    fn list_call(&self, expr: &Expr<'a>) -> Option<ListCall<'a>> {
+        let (call_type, arguments) = match expr {
+            Expr::Call(Call {
+                call_type,
+                arguments,
+            }) => (call_type, *arguments),
+            _ => return None,
+        };
+
+        match call_type {
+            CallType::HigherOrder(higher_order) => {
+                let (operation, xs) = match higher_order.op {
+                    HigherOrder::ListMap { xs } => (Operation::Map, xs),
+                    HigherOrder::ListWalk { xs, state } => (Operation::Walk { state }, xs),
+                    _ => return None,
+                };
+
+                Some(ListCall {
+                    operation,
+                    xs,
+                    function: PassedFunction {
+                        captured_environment: *arguments.last()?,
+                        ..higher_order.passed_function
+                    },
+                    closure_env_layout: higher_order.closure_env_layout,
+                    update_mode: higher_order.update_mode,
+                })
+            }
+            CallType::ByName {
+                name,
+                arg_layouts,
+                specialization_id,
+                ..
+            } => {
+                let operation = match name.name() {
+                    Symbol::LIST_MAP => Operation::Map,
+                    Symbol::LIST_KEEP_IF => Operation::KeepIf,
+                    _ => return None,
+                };
+
+                let (xs, function, function_layout) = match (arguments, *arg_layouts) {
+                    ([xs, function], [_, function_layout]) => (*xs, *function, *function_layout),
+                    _ => return None,
+                };
+
+                let lambda_set = match self.interner.get_repr(function_layout) {
+                    LayoutRepr::LambdaSet(lambda_set) => lambda_set,
+                    _ => return None,
+                };
+
+                match lambda_set.call_by_name_options(&*self.interner) {
+                    ClosureCallOptions::Struct(_) | ClosureCallOptions::UnwrappedCapture(_) => {}
+                    ClosureCallOptions::Void
+                    | ClosureCallOptions::Union(_)
+                    | ClosureCallOptions::EnumDispatch(_) => return None,
+                }
+
+                let lambda_name = lambda_set.iter_set().next()?;
+
+                if lambda_set.args.len() != 1
+                    || (operation == Operation::KeepIf
+                        && self.interner.get_repr(lambda_set.ret) != LayoutRepr::BOOL)
+                {
+                    return None;
+                }
+
+                let argument_layouts = lambda_set.extend_argument_list_for_named(
+                    self.arena,
+                    lambda_name,
+                    lambda_set.args,
+                );
+
+                Some(ListCall {
+                    operation,
+                    xs,
+                    function: PassedFunction {
+                        name: lambda_name,
+                        argument_layouts,
+                        return_layout: lambda_set.ret,
+                        specialization_id: *specialization_id,
+                        captured_environment: function,
+                        owns_captured_environment: true,
+                    },
+                    closure_env_layout: lambda_set.is_represented(&*self.interner),
+                    update_mode: UpdateModeId::BACKEND_DUMMY,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Fuse `ys = first xs` and `zs = second ys` into a single call on `xs`. Returns the lets
+    /// that the new call needs, and the new call.
+    fn fuse(
+        &mut self,
+        first: &ListCall<'a>,
+        second: &ListCall<'a>,
+    ) -> Option<(std::vec::Vec<(Symbol, Expr<'a>, InLayout<'a>)>, Expr<'a>)> {
+        let arena = self.arena;
+        let f = &first.function;
+        let g = &second.function;
+
+        if self.uses.get(&second.xs) != Some(&1)
+            || !first.is_simple()
+            || !second.is_simple()
+            || !self.is_pure(f)
+            || !self.is_pure(g)
+        {
+            return None;
+        }
+
+        let element_layout = *f.argument_layouts.first()?;
+
+        // The arguments of the new function, besides its captures
+        let (operation, arguments): (_, &'a [InLayout<'a>]) =
+            match (first.operation, second.operation) {
+                (Operation::Map, Operation::Map) => {
+                    (Operation::Map, arena.alloc_slice_copy(&[element_layout]))
+                }
+                (Operation::Map | Operation::KeepIf, Operation::Walk { state }) => (
+                    Operation::Walk { state },
+                    arena.alloc_slice_copy(&[g.return_layout, element_layout]),
+                ),
+                _ => return None,
+            };
+
+        // The captured environments of both functions, in the new function's captures
+        let captures: std::vec::Vec<(Symbol, InLayout<'a>)> = [first, second]
+            .iter()
+            .filter_map(|call| Some((call.function.captured_environment, call.captures()?)))
+            .collect();
+        let capture_layouts =
+            Vec::from_iter_in(captures.iter().map(|(_, layout)| *layout), arena).into_bump_slice();
+
+        let representation = match capture_layouts {
+            [] => Layout::UNIT,
+            [capture_layout] => *capture_layout,
+            _ => self
+                .interner
+                .insert_direct_no_semantic(LayoutRepr::Struct(capture_layouts)),
+        };
+
+        let fused_symbol = Symbol::new(self.home, self.ident_ids.gen_unique());
+        let lambda_set = self.interner.insert_lambda_set(
+            arena,
+            arena.alloc(&*arguments),
+            g.return_layout,
+            arena.alloc(&*arena.alloc_slice_copy(&[(fused_symbol, capture_layouts)])),
+            NeedsRecursionPointerFixup(false),
+            representation,
+        );
+        let lambda_name = lambda_set.iter_set().next()?;
+        let closure_layout = lambda_set.full_layout;
+
+        let argument_layouts: &'a [InLayout<'a>] = if captures.is_empty() {
+            arguments
+        } else {
+            let mut layouts = Vec::from_iter_in(arguments.iter().copied(), arena);
+            layouts.push(closure_layout);
+            layouts.into_bump_slice()
+        };
+
+        let proc_layout = ProcLayout {
+            arguments: argument_layouts,
+            result: g.return_layout,
+            niche: lambda_name.niche(),
+        };
+
+        let proc = self.fused_proc(
+            first,
+            second,
+            lambda_name,
+            arguments,
+            &captures,
+            closure_layout,
+        );
+        self.new_procs.push(((fused_symbol, proc_layout), proc));
+        // so the result can be fused again
+        self.purity.insert((fused_symbol, proc_layout), true);
+
+        // Build the captured environment of the new function
+        let mut lets = std::vec::Vec::new();
+        let captured_environment = match captures.as_slice() {
+            [(symbol, _)] => *symbol,
+            _ => {
+                let symbol = Symbol::new(self.home, self.ident_ids.gen_unique());
+                let fields = Vec::from_iter_in(captures.iter().map(|(symbol, _)| *symbol), arena);
+                lets.push((
+                    symbol,
+                    Expr::Struct(fields.into_bump_slice()),
+                    closure_layout,
+                ));
+
+                symbol
+            }
+        };
+
+        let (op, update_mode, call_arguments) = match operation {
+            Operation::Walk { state } => (
+                HigherOrder::ListWalk {
+                    xs: first.xs,
+                    state,
+                },
+                second.update_mode,
+                arena.alloc_slice_copy(&[first.xs, state, fused_symbol, captured_environment]),
+            ),
+            Operation::Map | Operation::KeepIf => (
+                HigherOrder::ListMap { xs: first.xs },
+                first.update_mode,
+                arena.alloc_slice_copy(&[first.xs, fused_symbol, captured_environment]),
+            ),
+        };
+
+        let higher_order = HigherOrderLowLevel {
+            op,
+            closure_env_layout: lambda_set.is_represented(&*self.interner),
+            update_mode,
+            passed_function: PassedFunction {
+                name: lambda_name,
+                argument_layouts,
+                return_layout: g.return_layout,
+                specialization_id: g.specialization_id,
+                captured_environment,
+                owns_captured_environment: true,
+            },
+        };
+
+        let call = Call {
+            call_type: CallType::HigherOrder(arena.alloc(higher_order)),
+            arguments: call_arguments,
+        };
+
+        Some((lets, Expr::Call(call)))
+    }
+
+    /// The new function, one of
+    ///
+    /// - `\x, captures -> g (f x captures.0) captures.1`
+    /// - `\s, x, captures -> step s (f x captures.0) captures.1`
+    /// - `\s, x, captures -> if p x captures.0 then step s x captures.1 else s`
+    fn fused_proc(
+        &mut self,
+        first: &ListCall<'a>,
+        second: &ListCall<'a>,
+        lambda_name: LambdaName<'a>,
+        argument_layouts: &'a [InLayout<'a>],
+        captures: &[(Symbol, InLayout<'a>)],
+        closure_layout: InLayout<'a>,
+    ) -> Proc<'a> {
+        let arena = self.arena;
+        let f = &first.function;
+        let g = &second.function;
+
+        let arguments: std::vec::Vec<Symbol> = argument_layouts
+            .iter()
+            .map(|_| Symbol::new(self.home, self.ident_ids.gen_unique()))
+            .collect();
+        let closure_argument = Symbol::new(self.home, self.ident_ids.gen_unique());
+        let intermediate = Symbol::new(self.home, self.ident_ids.gen_unique());
+        let result = Symbol::new(self.home, self.ident_ids.gen_unique());
+
+        // the captures of `f` and `g`, as found in the closure argument
+        let mut lets = std::vec::Vec::new();
+        let mut capture_symbols = std::vec::Vec::new();
+
+        match captures {
+            [] => {}
+            [_] => capture_symbols.push(closure_argument),
+            _ => {
+                let field_layouts =
+                    Vec::from_iter_in(captures.iter().map(|(_, layout)| *layout), arena)
+                        .into_bump_slice();
+
+                for (index, (_, layout)) in captures.iter().enumerate() {
+                    let symbol = Symbol::new(self.home, self.ident_ids.gen_unique());
+                    let expr = Expr::StructAtIndex {
+                        index: index as _,
+                        field_layouts,
+                        structure: closure_argument,
+                    };
+
+                    lets.push((symbol, expr, *layout));
+                    capture_symbols.push(symbol);
+                }
+            }
+        }
+
+        let mut capture_symbols = capture_symbols.into_iter();
+        let mut call = |function: &PassedFunction<'a>, call_arguments: &[Symbol]| {
+            let mut arguments = Vec::from_iter_in(call_arguments.iter().copied(), arena);
+
+            if !function.name.no_captures() {
+                arguments.extend(capture_symbols.next());
+            }
+
+            Expr::Call(Call {
+                call_type: CallType::ByName {
+                    name: function.name,
+                    ret_layout: function.return_layout,
+                    arg_layouts: function.argument_layouts,
+                    specialization_id: function.specialization_id,
+                },
+                arguments: arguments.into_bump_slice(),
+            })
+        };
+
+        // The element is the last argument, after the state of a walk
+        let element = *arguments.last().unwrap();
+        let state = arguments.first().copied().filter(|_| arguments.len() == 2);
+
+        let mut body: &'a Stmt<'a> = match (first.operation, state) {
+            (Operation::KeepIf, Some(state)) => {
+                lets.push((intermediate, call(f, &[element]), f.return_layout));
+
+                let step = call(g, &[state, element]);
+                let then_branch = arena.alloc(Stmt::Let(
+                    result,
+                    step,
+                    g.return_layout,
+                    arena.alloc(Stmt::Ret(result)),
+                ));
+                let else_branch = arena.alloc(Stmt::Ret(state));
+
+                arena.alloc(Stmt::Switch {
+                    cond_symbol: intermediate,
+                    cond_layout: Layout::BOOL,
+                    branches: arena.alloc([(1, BranchInfo::None, then_branch.clone())]),
+                    default_branch: (BranchInfo::None, else_branch),
+                    ret_layout: g.return_layout,
+                })
+            }
+            (_, state) => {
+                lets.push((intermediate, call(f, &[element]), f.return_layout));
+
+                let step = match state {
+                    Some(state) => call(g, &[state, intermediate]),
+                    None => call(g, &[intermediate]),
+                };
+                lets.push((result, step, g.return_layout));
+
+                arena.alloc(Stmt::Ret(result))
+            }
+        };
+
+        for (symbol, expr, layout) in lets.into_iter().rev() {
+            body = arena.alloc(Stmt::Let(symbol, expr, layout, body));
+        }
+
+        let mut args = Vec::from_iter_in(
+            argument_layouts
+                .iter()
+                .copied()
+                .zip(arguments.iter().copied()),
+            arena,
+        );
+        let closure_data_layout = if captures.is_empty() {
+            None
+        } else {
+            args.push((closure_layout, closure_argument));
+            Some(closure_layout)
+        };
+
+        Proc {
+            name: lambda_name,
+            args: args.into_bump_slice(),
+            body: body.clone(),
+            closure_data_layout,
+            ret_layout: g.return_layout,
+            is_self_recursive: SelfRecursive::NotSelfRecursive,
+            is_erased: false,
+        }
+    }
+
+    /// Whether calling this function can do anything besides returning a value (or crashing).
+    fn is_pure(&mut self, function: &PassedFunction<'a>) -> bool {
+        let key = (
+            function.name.name(),
+            ProcLayout {
+                arguments: function.argument_layouts,
+                result: function.return_layout,
+                niche: function.name.niche(),
+            },
+        );
+
+        self.is_pure_proc(key)
+    }
+
+    fn is_pure_proc(&mut self, key: (Symbol, ProcLayout<'a>)) -> bool {
+        if let Some(pure) = self.purity.get(&key) {
+            return *pure;
+        }
+
+        let procs = self.procs;
+        let proc = match procs.get(&key) {
+            Some(proc) => proc,
+            None => return false,
+        };
+
+        // Recursive calls don't make a procedure impure by themselves
+        self.purity.insert(key, true);
+
+        let pure = self.is_pure_stmt(&proc.body);
+        self.purity.insert(key, pure);
+
+        pure
+    }
+
+    fn is_pure_stmt(&mut self, stmt: &Stmt<'a>) -> bool {
+        let mut stack = vec![stmt];
+
+        while let Some(stmt) = stack.pop() {
+            match stmt {
+                Stmt::Let(_, expr, _, cont) => {
+                    if let Expr::Call(call) = expr {
+                        let pure = match &call.call_type {
+                            CallType::ByName {
+                                name,
+                                ret_layout,
+                                arg_layouts,
+                                ..
+                            } => self.is_pure_proc((
+                                name.name(),
+                                ProcLayout {
+                                    arguments: arg_layouts,
+                                    result: *ret_layout,
+                                    niche: name.niche(),
+                                },
+                            )),
+                            CallType::HigherOrder(higher_order) => {
+                                self.is_pure(&higher_order.passed_function)
+                            }
+                            CallType::LowLevel { .. } => true,
+                            CallType::ByPointer { .. } | CallType::Foreign { .. } => false,
+                        };
+
+                        if !pure {
+                            return false;
+                        }
+                    }
+
+                    stack.push(cont);
+                }
+                Stmt::Switch {
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                    stack.push(default_branch.1);
+                }
+                Stmt::Refcounting(_, cont) => stack.push(cont),
+                Stmt::Join {
+                    body, remainder, ..
+                } => {
+                    stack.push(body);
+                    stack.push(remainder);
+                }
+                Stmt::Expect { .. } | Stmt::ExpectFx { .. } | Stmt::Dbg { .. } => return false,
+                Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => {}
+            }
+        }
+
+        true
+    }
+}
+
+fn count_uses(stmt: &Stmt<'_>, uses: &mut MutMap<Symbol, usize>) {
+    stmt.for_each_symbol(|symbol| *uses.entry(*symbol).or_insert(0) += 1);
+}
@@ -0,0 +1,253 @@
+//! Running `List.walk` in the bitcode.
+//!
+//! `List.walk` is an ordinary Roc function, specialized to every function it is given and
+//! compiled to a loop that calls that function. When the function is known at the call site,
+//! meaning its lambda set has a single member, the call is replaced by the `ListWalk`
+//! higher-order lowlevel instead. Like `List.map`, it hands the function to the Zig
+//! implementation as a caller that takes a pointer to the captured environment, pointers to
+//! the arguments and a pointer to write the result to. `Dict.walk` is built on `List.walk`, so
+//! it benefits as well.
+
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_module::symbol::Symbol;
+
+use crate::ir::{
+    Call, CallType, Expr, HigherOrderLowLevel, PassedFunction, Proc, ProcLayout, Stmt, UpdateModeId,
+};
+use crate::layout::{ClosureCallOptions, LayoutInterner, LayoutRepr, STLayoutInterner};
+use crate::low_level::HigherOrder;
+
+pub fn walk_in_bitcode<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let env = Env {
+        arena,
+        interner,
+        procs,
+    };
+
+    let mut rewritten = Vec::new();
+
+    for (key, proc) in procs.iter() {
+        let mut changed = false;
+        let body = env.rewrite_stmt(&proc.body, &mut changed);
+
+        if changed {
+            rewritten.push((*key, body.clone()));
+        }
+    }
+
+    for (key, body) in rewritten {
+        if let Some(proc) = procs.get_mut(&key) {
+            proc.body = body;
+        }
+    }
+}
+
+struct Env<'a, 'i> {
+    arena: &'a Bump,
+    interner: &'i STLayoutInterner<'a>,
+    procs: &'i MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+}
+
+impl<'a, 'i> Env<'a, 'i> {
+    fn rewrite_stmt(&self, stmt: &Stmt<'a>, changed: &mut bool) -> &'a Stmt<'a> {
+        let mut lets = Vec::new();
+        let mut current: &Stmt<'a> = stmt;
+
+        while let Stmt::Let(symbol, expr, layout, cont) = current {
+            let expr = match self.walk_call(expr) {
+                Some(walk) => {
+                    *changed = true;
+                    walk
+                }
+                None => expr.clone(),
+            };
+
+            lets.push((*symbol, expr, *layout));
+            current = cont;
+        }
+
+        let mut result = self.rewrite_tail(current, changed);
+
+        for (symbol, expr, layout) in lets.into_iter().rev() {
+            result = self.arena.alloc(Stmt::Let(symbol, expr, layout, result));
+        }
+
+        result
+    }
+
+    fn rewrite_tail(&self, stmt: &Stmt<'a>, changed: &mut bool) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        match stmt {
+            Stmt::Let(..) => self.rewrite_stmt(stmt, changed),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = bumpalo::collections::Vec::from_iter_in(
+                    branches.iter().map(|(id, info, branch)| {
+                        (
+                            *id,
+                            info.clone(),
+                            self.rewrite_stmt(branch, changed).clone(),
+                        )
+                    }),
+                    arena,
+                );
+
+                arena.alloc(Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (
+                        default_branch.0.clone(),
+                        self.rewrite_stmt(default_branch.1, changed),
+                    ),
+                    ret_layout: *ret_layout,
+                })
+            }
+            Stmt::Refcounting(modify_rc, cont) => arena.alloc(Stmt::Refcounting(
+                *modify_rc,
+                self.rewrite_stmt(cont, changed),
+            )),
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => arena.alloc(Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups: lookups,
+                variables: variables,
+                remainder: self.rewrite_stmt(remainder, changed),
+            }),
+            Stmt::ExpectFx {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => arena.alloc(Stmt::ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups: lookups,
+                variables: variables,
+                remainder: self.rewrite_stmt(remainder, changed),
+            }),
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => arena.alloc(Stmt::Dbg {
+                source_location: source_location,
+                source: source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.rewrite_stmt(remainder, changed),
+            }),
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => arena.alloc(Stmt::Join {
+                id: *id,
+                parameters: parameters,
+                body: self.rewrite_stmt(body, changed),
+                remainder: self.rewrite_stmt(remainder, changed),
+            }),
+            Stmt::Ret(_) | Stmt::Jump(..) | Stmt::Crash(..) => arena.alloc(stmt.clone()),
+        }
+    }
+
+    /// Turn `List.walk xs state f` into the `ListWalk` lowlevel, if `f` is a single known function.
+    fn walk_call(&self, expr: &Expr<'a>) -> Option<Expr<'a>> {
+        let (arguments, arg_layouts, specialization_id) = match expr {
+            Expr::Call(Call {
+                call_type:
+                    CallType::ByName {
+                        name,
+                        arg_layouts,
+                        specialization_id,
+                        ..
+                    },
+                arguments,
+            }) if name.name() == Symbol::LIST_WALK => {
+                (*arguments, *arg_layouts, *specialization_id)
+            }
+            _ => return None,
+        };
+
+        let (xs, state, function, state_layout, function_layout) = match (arguments, arg_layouts) {
+            ([xs, state, function], [_, state_layout, function_layout]) => {
+                (*xs, *state, *function, *state_layout, *function_layout)
+            }
+            _ => return None,
+        };
+
+        let lambda_set = match self.interner.get_repr(function_layout) {
+            LayoutRepr::LambdaSet(lambda_set) => lambda_set,
+            _ => return None,
+        };
+
+        match lambda_set.call_by_name_options(self.interner) {
+            ClosureCallOptions::Struct(_) | ClosureCallOptions::UnwrappedCapture(_) => {}
+            ClosureCallOptions::Void
+            | ClosureCallOptions::Union(_)
+            | ClosureCallOptions::EnumDispatch(_) => return None,
+        }
+
+        let lambda_name = lambda_set.iter_set().next()?;
+
+        if lambda_set.args.len() != 2 || lambda_set.ret != state_layout {
+            return None;
+        }
+
+        let argument_layouts =
+            lambda_set.extend_argument_list_for_named(self.arena, lambda_name, lambda_set.args);
+
+        let proc_layout = ProcLayout {
+            arguments: argument_layouts,
+            result: state_layout,
+            niche: lambda_name.niche(),
+        };
+
+        // The function must have been specialized for `List.walk` to call it.
+        if !self.procs.contains_key(&(lambda_name.name(), proc_layout)) {
+            return None;
+        }
+
+        let higher_order = HigherOrderLowLevel {
+            op: HigherOrder::ListWalk { xs, state },
+            closure_env_layout: lambda_set.is_represented(self.interner),
+            update_mode: UpdateModeId::BACKEND_DUMMY,
+            passed_function: PassedFunction {
+                name: lambda_name,
+                argument_layouts,
+                return_layout: state_layout,
+                specialization_id,
+                captured_environment: function,
+                owns_captured_environment: true,
+            },
+        };
+
+        Some(Expr::Call(Call {
+            call_type: CallType::HigherOrder(self.arena.alloc(higher_order)),
+            arguments: self
+                .arena
+                .alloc_slice_copy(&[xs, state, lambda_name.name(), function]),
+        }))
+    }
+}
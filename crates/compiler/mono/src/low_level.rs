@@ -23,6 +23,10 @@ pub enum HigherOrder {
     ListSortWith {
         xs: Symbol,
     },
+    ListWalk {
+        xs: Symbol,
+        state: Symbol,
+    },
 }
 
 impl HigherOrder {
@@ -33,6 +37,7 @@ impl HigherOrder {
             HigherOrder::ListMap3 { .. } => 3,
             HigherOrder::ListMap4 { .. } => 4,
             HigherOrder::ListSortWith { .. } => 2,
+            HigherOrder::ListWalk { .. } => 2,
         }
     }
 
@@ -43,7 +48,7 @@ impl HigherOrder {
 
         match self {
             ListMap { .. } | ListSortWith { .. } => 2,
-            ListMap2 { .. } => 3,
+            ListMap2 { .. } | ListWalk { .. } => 3,
             ListMap3 { .. } => 4,
             ListMap4 { .. } => 5,
         }
@@ -15,6 +15,7 @@ use roc_module::ident::QualifiedModuleName;
 use roc_region::all::{Loc, Position, Region};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Spaces<'a, T> {
     pub before: &'a [CommentOrNewline<'a>],
     pub item: T,
@@ -22,6 +23,7 @@ pub struct Spaces<'a, T> {
 }
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Spaced<'a, T> {
     Item(T),
 
@@ -106,6 +108,7 @@ impl<'a, T: ExtractSpaces<'a>> ExtractSpaces<'a> for Loc<T> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Module<'a> {
     pub comments: &'a [CommentOrNewline<'a>],
     pub header: Header<'a>,
@@ -253,6 +256,7 @@ impl<'a> Module<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Header<'a> {
     Module(ModuleHeader<'a>),
     App(AppHeader<'a>),
@@ -262,6 +266,7 @@ pub enum Header<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WhenBranch<'a> {
     pub patterns: &'a [Loc<Pattern<'a>>],
     pub value: Loc<Expr<'a>>,
@@ -269,12 +274,14 @@ pub struct WhenBranch<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WhenPattern<'a> {
     pub pattern: Loc<Pattern<'a>>,
     pub guard: Option<Loc<Expr<'a>>>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StrSegment<'a> {
     Plaintext(&'a str),       // e.g. "foo"
     Unicode(Loc<&'a str>),    // e.g. "00A0" in "\u(00A0)"
@@ -284,6 +291,7 @@ pub enum StrSegment<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SingleQuoteSegment<'a> {
     Plaintext(&'a str),    // e.g. 'f'
     Unicode(Loc<&'a str>), // e.g. '00A0' in '\u(00A0)'
@@ -292,6 +300,7 @@ pub enum SingleQuoteSegment<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EscapedChar {
     Newline,        // \n
     Tab,            // \t
@@ -334,6 +343,7 @@ impl EscapedChar {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SingleQuoteLiteral<'a> {
     /// The most common case: a plain character with no escapes
     PlainLine(&'a str),
@@ -380,6 +390,7 @@ impl<'a> TryFrom<StrSegment<'a>> for SingleQuoteSegment<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StrLiteral<'a> {
     /// The most common case: a plain string with no escapes or interpolations
     PlainLine(&'a str),
@@ -397,6 +408,7 @@ pub enum StrLiteral<'a> {
 /// it's doing things like turning local variables into fully qualified symbols.
 /// Once canonicalization is done, the arena and the input string get dropped.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Expr<'a> {
     // Number Literals
     Float(&'a str),
@@ -664,16 +676,20 @@ pub fn split_around<T>(items: &[T], target: usize) -> (&[T], &[T]) {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PrecedenceConflict<'a> {
     pub whole_region: Region,
     pub binop1_position: Position,
     pub binop2_position: Position,
     pub binop1: BinOp,
     pub binop2: BinOp,
+    /// The operands around the two operators, e.g. `a`, `b` and `c` in `a < b < c`
+    pub operands: [Region; 3],
     pub expr: &'a Loc<Expr<'a>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TypeHeader<'a> {
     pub name: Loc<&'a str>,
     pub vars: &'a [Loc<Pattern<'a>>],
@@ -691,6 +707,7 @@ impl<'a> TypeHeader<'a> {
 
 /// The `implements` keyword associated with ability definitions.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Implements<'a> {
     Implements,
     SpaceBefore(&'a Implements<'a>, &'a [CommentOrNewline<'a>]),
@@ -700,6 +717,7 @@ pub enum Implements<'a> {
 /// An ability demand is a value defining the ability; for example `hash : a -> U64 where a implements Hash`
 /// for a `Hash` ability.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AbilityMember<'a> {
     pub name: Loc<Spaced<'a, &'a str>>,
     pub typ: Loc<TypeAnnotation<'a>>,
@@ -712,6 +730,7 @@ impl AbilityMember<'_> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TypeDef<'a> {
     /// A type alias. This is like a standalone annotation, except the pattern
     /// must be a capitalized Identifier, e.g.
@@ -740,6 +759,7 @@ pub enum TypeDef<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ValueDef<'a> {
     // TODO in canonicalization, validate the pattern; only certain patterns
     // are allowed in annotations.
@@ -1043,6 +1063,7 @@ impl<'a, 'b> Iterator for RecursiveValueDefIter<'a, 'b> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ModuleImport<'a> {
     pub before_name: &'a [CommentOrNewline<'a>],
     pub name: Loc<ImportedModuleName<'a>>,
@@ -1057,6 +1078,7 @@ pub struct ModuleImport<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct IngestedFileImport<'a> {
     pub before_path: &'a [CommentOrNewline<'a>],
     pub path: Loc<StrLiteral<'a>>,
@@ -1065,6 +1087,7 @@ pub struct IngestedFileImport<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct IngestedFileAnnotation<'a> {
     pub before_colon: &'a [CommentOrNewline<'a>],
     pub annotation: Loc<TypeAnnotation<'a>>,
@@ -1077,6 +1100,7 @@ impl<'a> Malformed for IngestedFileAnnotation<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImportAsKeyword;
 
 impl header::Keyword for ImportAsKeyword {
@@ -1084,6 +1108,7 @@ impl header::Keyword for ImportAsKeyword {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImportExposingKeyword;
 
 impl header::Keyword for ImportExposingKeyword {
@@ -1091,6 +1116,7 @@ impl header::Keyword for ImportExposingKeyword {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImportedModuleName<'a> {
     pub package: Option<&'a str>,
     pub name: ModuleName<'a>,
@@ -1106,6 +1132,7 @@ impl<'a> From<ImportedModuleName<'a>> for QualifiedModuleName<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImportAlias<'a>(&'a str);
 
 impl<'a> ImportAlias<'a> {
@@ -1376,6 +1403,45 @@ impl<'a> Defs<'a> {
     }
 }
 
+/// The defs are stored as arrays that refer to one another by index, which would mean nothing to
+/// someone reading the serialized tree, so they're written out as one list in source order.
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Defs<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        #[derive(serde::Serialize)]
+        enum Def<'b, 'a> {
+            Type(&'b TypeDef<'a>),
+            Value(&'b ValueDef<'a>),
+        }
+
+        #[derive(serde::Serialize)]
+        struct Entry<'b, 'a> {
+            region: Region,
+            space_before: &'b [CommentOrNewline<'a>],
+            def: Def<'b, 'a>,
+            space_after: &'b [CommentOrNewline<'a>],
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+
+        for (index, def) in self.defs().enumerate() {
+            seq.serialize_element(&Entry {
+                region: self.regions[index],
+                space_before: &self.spaces[self.space_before[index].indices()],
+                def: match def {
+                    Ok(type_def) => Def::Type(type_def),
+                    Err(value_def) => Def::Value(value_def),
+                },
+                space_after: &self.spaces[self.space_after[index].indices()],
+            })?;
+        }
+
+        seq.end()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SplitDefsAround<'a> {
     pub before: Defs<'a>,
@@ -1386,12 +1452,14 @@ pub struct SplitDefsAround<'a> {
 pub type AbilityName<'a> = Loc<TypeAnnotation<'a>>;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImplementsClause<'a> {
     pub var: Loc<Spaced<'a, &'a str>>,
     pub abilities: &'a [AbilityName<'a>],
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AbilityImpls<'a> {
     // `{ eq: myEq }`
     AbilityImpls(Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>),
@@ -1403,6 +1471,7 @@ pub enum AbilityImpls<'a> {
 
 /// `Eq` or `Eq { eq: myEq }`
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImplementsAbility<'a> {
     ImplementsAbility {
         /// Should be a zero-argument `Apply` or an error; we'll check this in canonicalization
@@ -1416,6 +1485,7 @@ pub enum ImplementsAbility<'a> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImplementsAbilities<'a> {
     /// `implements [Eq { eq: myEq }, Hash]`
     Implements(Collection<'a, Loc<ImplementsAbility<'a>>>),
@@ -1444,6 +1514,7 @@ impl ImplementsAbilities<'_> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TypeAnnotation<'a> {
     /// A function. The types of its arguments, then the type of its return value.
     Function(&'a [Loc<TypeAnnotation<'a>>], &'a Loc<TypeAnnotation<'a>>),
@@ -1501,6 +1572,7 @@ pub enum TypeAnnotation<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Tag<'a> {
     Apply {
         name: Loc<&'a str>,
@@ -1516,6 +1588,7 @@ pub enum Tag<'a> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AssignedField<'a, Val> {
     // A required field with a label, e.g. `{ name: "blah" }` or `{ name : Str }`
     RequiredValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Val>),
@@ -1538,6 +1611,7 @@ pub enum AssignedField<'a, Val> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RecordBuilderField<'a> {
     // A field with a value, e.g. `{ name: "blah" }`
     Value(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
@@ -1562,6 +1636,7 @@ pub enum RecordBuilderField<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CommentOrNewline<'a> {
     Newline,
     LineComment(&'a str),
@@ -1597,6 +1672,7 @@ impl<'a> CommentOrNewline<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PatternAs<'a> {
     pub spaces_before: &'a [CommentOrNewline<'a>],
     pub identifier: Loc<&'a str>,
@@ -1609,6 +1685,7 @@ impl<'a> PatternAs<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Pattern<'a> {
     // Identifier
     Identifier {
@@ -1672,6 +1749,7 @@ pub enum Pattern<'a> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Base {
     Octal,
     Binary,
@@ -1873,6 +1951,7 @@ impl<'a> Pattern<'a> {
     }
 }
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Collection<'a, T> {
     pub items: &'a [T],
     // Use a pointer to a slice (rather than just a slice), in order to avoid bloating
@@ -2219,6 +2298,7 @@ impl_extract_spaces!(Expr);
 impl_extract_spaces!(Pattern);
 impl_extract_spaces!(Tag);
 impl_extract_spaces!(AssignedField<T>);
+impl_extract_spaces!(RecordBuilderField);
 impl_extract_spaces!(TypeAnnotation);
 impl_extract_spaces!(ImplementsAbility);
 
@@ -455,8 +455,11 @@ where
                 progress = MadeProgress;
             }
             Some(b'\t') => {
+                // Tabs are never allowed, so there is nothing to backtrack to. Committing to
+                // this error makes sure it is the one that gets reported, instead of a confusing
+                // indentation error from whatever parser would be tried next.
                 return Err((
-                    progress,
+                    MadeProgress,
                     E::space_problem(BadInputError::HasTab, state.pos()),
                 ));
             }
@@ -14,9 +14,9 @@ use crate::ident::{
 use crate::module::module_name_help;
 use crate::parser::{
     self, backtrackable, byte, byte_indent, increment_min_indent, line_min_indent, optional,
-    reset_min_indent, sep_by1, sep_by1_e, set_min_indent, specialize_err, specialize_err_ref, then,
-    two_bytes, EClosure, EExpect, EExpr, EIf, EImport, EInParens, EList, ENumber, EPattern,
-    ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
+    reset_min_indent, sep_by1, sep_by1_e, separating_comma, set_min_indent, specialize_err,
+    specialize_err_ref, then, two_bytes, EClosure, EExpect, EExpr, EIf, EImport, EInParens, EList,
+    ENumber, EPattern, ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
 };
 use crate::pattern::{closure_param, loc_implements_parser};
 use crate::state::State;
@@ -1222,7 +1222,7 @@ fn parse_statement_inside_def<'a>(
 
     for byte in &state.original_bytes()[spaces_before_start..spaces_before_end] {
         match byte {
-            b' ' | b'\n' => {
+            b' ' | b'\n' | b'\r' => {
                 spaces_before_current_start.offset += 1;
             }
             _ => break,
@@ -2541,6 +2541,12 @@ pub fn parse_top_level_defs<'a>(
 
 // PARSER HELPERS
 
+/// A closure's params, and the comments before its `->` along with its body
+type ClosureParts<'a> = (
+    Vec<'a, Loc<Pattern<'a>>>,
+    (&'a [CommentOrNewline<'a>], Loc<Expr<'a>>),
+);
+
 fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClosure<'a>> {
     // closure_help_help(options)
     map_with_arena!(
@@ -2554,7 +2560,7 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
                 // Parse the params
                 // Params are comma-separated
                 sep_by1_e(
-                    byte(b',', EClosure::Comma),
+                    separating_comma(EClosure::Comma),
                     space0_around_ee(
                         specialize_err(EClosure::Pattern, closure_param()),
                         EClosure::IndentArg,
@@ -2562,19 +2568,31 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
                     ),
                     EClosure::Arg,
                 ),
-                skip_first!(
-                    // Parse the -> which separates params from body
-                    two_bytes(b'-', b'>', EClosure::Arrow),
-                    // Parse the body
-                    space0_before_e(
-                        specialize_err_ref(EClosure::Body, expr_start(options)),
-                        EClosure::IndentBody
+                and!(
+                    // The last param can be followed by a trailing comma
+                    skip_first!(
+                        optional(byte(b',', EClosure::Comma)),
+                        space0_e(EClosure::IndentArrow)
+                    ),
+                    skip_first!(
+                        // Parse the -> which separates params from body
+                        two_bytes(b'-', b'>', EClosure::Arrow),
+                        // Parse the body
+                        space0_before_e(
+                            specialize_err_ref(EClosure::Body, expr_start(options)),
+                            EClosure::IndentBody
+                        )
                     )
                 )
             )
         ),
-        |arena: &'a Bump, (params, body)| {
-            let params: Vec<'a, Loc<Pattern<'a>>> = params;
+        |arena: &'a Bump, (mut params, (space_before_arrow, body)): ClosureParts<'a>| {
+            if !space_before_arrow.is_empty() {
+                if let Some(last) = params.last_mut() {
+                    last.value = arena.alloc(last.value).after(space_before_arrow);
+                }
+            }
+
             let params: &'a [Loc<Pattern<'a>>] = params.into_bump_slice();
             Expr::Closure(params, arena.alloc(body))
         }
@@ -2811,24 +2829,22 @@ mod when {
     }
 }
 
+/// The condition and `then` branch of an `if`, up to the `else` keyword.
 fn if_branch<'a>() -> impl Parser<'a, (Loc<Expr<'a>>, Loc<Expr<'a>>), EIf<'a>> {
-    skip_second!(
-        and!(
-            skip_second!(
-                space0_around_ee(
-                    specialize_err_ref(EIf::Condition, loc_expr(true)),
-                    EIf::IndentCondition,
-                    EIf::IndentThenToken,
-                ),
-                parser::keyword(keyword::THEN, EIf::Then)
-            ),
+    and!(
+        skip_second!(
             space0_around_ee(
-                specialize_err_ref(EIf::ThenBranch, loc_expr(true)),
-                EIf::IndentThenBranch,
-                EIf::IndentElseToken,
-            )
+                specialize_err_ref(EIf::Condition, loc_expr(true)),
+                EIf::IndentCondition,
+                EIf::IndentThenToken,
+            ),
+            parser::keyword(keyword::THEN, EIf::Then)
         ),
-        parser::keyword(keyword::ELSE, EIf::Else)
+        space0_around_ee(
+            specialize_err_ref(EIf::ThenBranch, loc_expr(true)),
+            EIf::IndentThenBranch,
+            EIf::IndentElseToken,
+        )
     )
 }
 
@@ -2905,7 +2921,9 @@ fn import_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EExpr
 }
 
 fn if_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EIf<'a>> {
-    move |arena: &'a Bump, state, min_indent| {
+    move |arena: &'a Bump, state: State<'a>, min_indent| {
+        let mut if_start = state.pos();
+
         let (_, _, state) =
             parser::keyword(keyword::IF, EIf::If).parse(arena, state, min_indent)?;
 
@@ -2914,8 +2932,27 @@ fn if_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EIf<
         let mut loop_state = state;
 
         let state_final_else = loop {
-            let (_, (cond, then_branch), state) =
-                if_branch().parse(arena, loop_state, min_indent)?;
+            let (_, (cond, then_branch), state) = if_branch()
+                .parse(arena, loop_state, min_indent)
+                .map_err(|(progress, fail)| match fail {
+                    // nothing more of the `if` follows its `then` branch, like at the end of a file
+                    EIf::IndentElseToken(pos) => {
+                        (progress, EIf::MissingElse(Region::new(if_start, pos), pos))
+                    }
+                    other => (progress, other),
+                })?;
+
+            // `if` is an expression, so every `if` needs an `else`
+            let (_, _, state) = parser::keyword(keyword::ELSE, EIf::Else)
+                .parse(arena, state, min_indent)
+                .map_err(|(_, fail)| match fail {
+                    EIf::Else(pos) => {
+                        let if_region = Region::new(if_start, then_branch.region.end());
+
+                        (MadeProgress, EIf::MissingElse(if_region, pos))
+                    }
+                    other => (MadeProgress, other),
+                })?;
 
             branches.push((cond, then_branch));
 
@@ -2923,12 +2960,13 @@ fn if_expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EIf<
             // NOTE this drops spaces between the `else` and the `if`
             let optional_if = and!(
                 backtrackable(space0_e(EIf::IndentIf)),
-                parser::keyword(keyword::IF, EIf::If)
+                loc!(parser::keyword(keyword::IF, EIf::If))
             );
 
             match optional_if.parse(arena, state.clone(), min_indent) {
                 Err((_, _)) => break state,
-                Ok((_, _, state)) => {
+                Ok((_, (_, loc_if), state)) => {
+                    if_start = loc_if.region.start();
                     loop_state = state;
                     continue;
                 }
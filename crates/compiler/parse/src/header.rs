@@ -4,8 +4,8 @@ use crate::ast::{
 use crate::blankspace::space0_e;
 use crate::expr::merge_spaces;
 use crate::ident::{lowercase_ident, UppercaseIdent};
+use crate::parser::{backtrackable, optional, then};
 use crate::parser::{byte, specialize_err, EPackageEntry, EPackageName, Parser};
-use crate::parser::{optional, then};
 use crate::string_literal;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::Loc;
@@ -68,6 +68,8 @@ pub enum HeaderType<'a> {
         requires_types: &'a [Loc<UppercaseIdent<'a>>],
         exposes: &'a [Loc<ModuleName<'a>>],
         exposes_ids: &'a [ModuleId],
+        /// builtin modules that code built on this platform can't use
+        unavailable: &'a [Loc<ModuleName<'a>>],
 
         /// usually `pf`
         config_shorthand: &'a str,
@@ -112,6 +114,7 @@ impl<'a> HeaderType<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Version<'a> {
     Exact(&'a str),
     Range {
@@ -123,12 +126,14 @@ pub enum Version<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VersionComparison {
     AllowsEqual,
     DisallowsEqual,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PackageName<'a>(&'a str);
 
 impl<'a> PackageName<'a> {
@@ -154,6 +159,7 @@ impl<'a> From<&'a str> for PackageName<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ModuleName<'a>(&'a str);
 
 impl<'a> From<ModuleName<'a>> for &'a str {
@@ -185,6 +191,7 @@ impl<'a> ModuleName<'a> {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ExposedName<'a>(&'a str);
 
 impl<'a> From<ExposedName<'a>> for &'a str {
@@ -211,6 +218,7 @@ macro_rules! keywords {
     ($($name:ident => $string:expr),* $(,)?) => {
         $(
             #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
             pub struct $name;
 
             impl Keyword for $name {
@@ -230,17 +238,20 @@ keywords! {
     ProvidesKeyword => "provides",
     ToKeyword => "to",
     PlatformKeyword => "platform",
+    UnavailableKeyword => "unavailable",
     // Deprecated
     ImportsKeyword => "imports",
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct KeywordItem<'a, K, V> {
     pub keyword: Spaces<'a, K>,
     pub item: V,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ModuleHeader<'a> {
     pub before_exposes: &'a [CommentOrNewline<'a>],
     pub exposes: Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
@@ -253,6 +264,7 @@ pub type ImportsKeywordItem<'a> = KeywordItem<'a, ImportsKeyword, ImportsCollect
 pub type ImportsCollection<'a> = Collection<'a, Loc<Spaced<'a, ImportsEntry<'a>>>>;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct HostedHeader<'a> {
     pub before_name: &'a [CommentOrNewline<'a>],
     pub name: Loc<ModuleName<'a>>,
@@ -266,12 +278,14 @@ pub struct HostedHeader<'a> {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum To<'a> {
     ExistingPackage(&'a str),
     NewPackage(PackageName<'a>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AppHeader<'a> {
     pub before_provides: &'a [CommentOrNewline<'a>],
     pub provides: Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
@@ -283,6 +297,7 @@ pub struct AppHeader<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProvidesTo<'a> {
     pub provides_keyword: Spaces<'a, ProvidesKeyword>,
     pub entries: Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
@@ -293,6 +308,7 @@ pub struct ProvidesTo<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PackageHeader<'a> {
     pub before_exposes: &'a [CommentOrNewline<'a>],
     pub exposes: Collection<'a, Loc<Spaced<'a, ModuleName<'a>>>>,
@@ -301,12 +317,14 @@ pub struct PackageHeader<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PlatformRequires<'a> {
     pub rigids: Collection<'a, Loc<Spaced<'a, UppercaseIdent<'a>>>>,
     pub signature: Loc<Spaced<'a, TypedIdent<'a>>>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PlatformHeader<'a> {
     pub before_name: &'a [CommentOrNewline<'a>],
     pub name: Loc<PackageName<'a>>,
@@ -318,9 +336,14 @@ pub struct PlatformHeader<'a> {
     pub imports: KeywordItem<'a, ImportsKeyword, Collection<'a, Loc<Spaced<'a, ImportsEntry<'a>>>>>,
     pub provides:
         KeywordItem<'a, ProvidesKeyword, Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>>,
+    /// Builtin modules that apps can't use on this platform, e.g. `unavailable [Dict, Set]`
+    pub unavailable: Option<
+        KeywordItem<'a, UnavailableKeyword, Collection<'a, Loc<Spaced<'a, ModuleName<'a>>>>>,
+    >,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImportsEntry<'a> {
     /// e.g. `Task` or `Task.{ Task, after }`
     Module(
@@ -343,6 +366,7 @@ pub enum ImportsEntry<'a> {
 ///
 /// printLine : Str -> Effect {}
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TypedIdent<'a> {
     pub ident: Loc<&'a str>,
     pub spaces_before_colon: &'a [CommentOrNewline<'a>],
@@ -350,11 +374,23 @@ pub struct TypedIdent<'a> {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PackageEntry<'a> {
     pub shorthand: &'a str,
     pub spaces_after_shorthand: &'a [CommentOrNewline<'a>],
     pub platform_marker: Option<&'a [CommentOrNewline<'a>]>,
     pub package_name: Loc<PackageName<'a>>,
+    pub target: Option<PackageTarget<'a>>,
+}
+
+/// Restricts a package entry to some targets, e.g. the `if wasm32` in
+/// `pf: platform "web/main.roc" if wasm32`. The name is an architecture or operating system.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PackageTarget<'a> {
+    pub spaces_before_if: &'a [CommentOrNewline<'a>],
+    pub spaces_after_if: &'a [CommentOrNewline<'a>],
+    pub name: Loc<&'a str>,
 }
 
 pub fn package_entry<'a>() -> impl Parser<'a, Spaced<'a, PackageEntry<'a>>, EPackageEntry<'a>> {
@@ -375,14 +411,19 @@ pub fn package_entry<'a>() -> impl Parser<'a, Spaced<'a, PackageEntry<'a>>, EPac
                 space0_e(EPackageEntry::IndentPackage)
             )),
             and!(
-                optional(skip_first!(
-                    crate::parser::keyword(crate::keyword::PLATFORM, EPackageEntry::Platform),
-                    space0_e(EPackageEntry::IndentPackage)
-                )),
-                loc!(specialize_err(EPackageEntry::BadPackage, package_name()))
+                and!(
+                    optional(skip_first!(
+                        crate::parser::keyword(crate::keyword::PLATFORM, EPackageEntry::Platform),
+                        space0_e(EPackageEntry::IndentPackage)
+                    )),
+                    loc!(specialize_err(EPackageEntry::BadPackage, package_name()))
+                ),
+                // You may optionally restrict the package to some targets,
+                // e.g. "if wasm32" in `pf: platform "web/main.roc" if wasm32`
+                optional(backtrackable(package_target()))
             )
         ),
-        move |arena, (opt_shorthand, (platform_marker, package_or_path))| {
+        move |arena, (opt_shorthand, ((platform_marker, package_or_path), target))| {
             let entry = match opt_shorthand {
                 Some(((shorthand, spaces_before_colon), spaces_after_colon)) => PackageEntry {
                     shorthand,
@@ -393,12 +434,14 @@ pub fn package_entry<'a>() -> impl Parser<'a, Spaced<'a, PackageEntry<'a>>, EPac
                     ),
                     platform_marker,
                     package_name: package_or_path,
+                    target,
                 },
                 None => PackageEntry {
                     shorthand: "",
                     spaces_after_shorthand: &[],
                     platform_marker,
                     package_name: package_or_path,
+                    target,
                 },
             };
 
@@ -407,6 +450,29 @@ pub fn package_entry<'a>() -> impl Parser<'a, Spaced<'a, PackageEntry<'a>>, EPac
     )
 }
 
+fn package_target<'a>() -> impl Parser<'a, PackageTarget<'a>, EPackageEntry<'a>> {
+    map!(
+        and!(
+            skip_second!(
+                space0_e(EPackageEntry::IndentPackage),
+                crate::parser::keyword(crate::keyword::IF, EPackageEntry::Target)
+            ),
+            and!(
+                space0_e(EPackageEntry::IndentPackage),
+                loc!(specialize_err(
+                    |_, pos| EPackageEntry::Target(pos),
+                    lowercase_ident()
+                ))
+            )
+        ),
+        |(spaces_before_if, (spaces_after_if, name))| PackageTarget {
+            spaces_before_if,
+            spaces_after_if,
+            name,
+        }
+    )
+}
+
 pub fn package_name<'a>() -> impl Parser<'a, PackageName<'a>, EPackageName<'a>> {
     then(
         loc!(specialize_err(
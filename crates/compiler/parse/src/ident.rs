@@ -8,6 +8,7 @@ use roc_region::all::{Position, Region};
 /// A tag, for example. Must start with an uppercase letter
 /// and then contain only letters and numbers afterwards - no dots allowed!
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UppercaseIdent<'a>(&'a str);
 
 impl<'a> From<&'a str> for UppercaseIdent<'a> {
@@ -33,6 +34,7 @@ impl<'a> From<&'a UppercaseIdent<'a>> for &'a str {
 /// "you can't redefine this tag!" if you wrote `Foo = ...` or
 /// "you can only define unqualified constants" if you wrote `Foo.bar = ...`
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Ident<'a> {
     /// Foo or Bar
     Tag(&'a str),
@@ -254,6 +256,7 @@ pub fn chomp_malformed(bytes: &[u8]) -> usize {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BadIdent {
     Start(Position),
     Space(BadInputError, Position),
@@ -349,6 +352,7 @@ where
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Accessor<'a> {
     RecordField(&'a str),
     TupleIndex(&'a str),
@@ -375,6 +379,7 @@ impl<'a> Accessor<'a> {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Suffix<'a> {
     Accessor(Accessor<'a>),
     TaskAwaitBang,
@@ -6,7 +6,7 @@ use crate::header::{
     HostedHeader, ImportsCollection, ImportsEntry, ImportsKeyword, ImportsKeywordItem, Keyword,
     KeywordItem, ModuleHeader, ModuleName, PackageEntry, PackageHeader, PackagesKeyword,
     PlatformHeader, PlatformRequires, ProvidesKeyword, ProvidesTo, RequiresKeyword, To, ToKeyword,
-    TypedIdent, WithKeyword,
+    TypedIdent, UnavailableKeyword, WithKeyword,
 };
 use crate::ident::{self, lowercase_ident, unqualified_ident, uppercase, UppercaseIdent};
 use crate::parser::Progress::{self, *};
@@ -438,6 +438,7 @@ fn platform_header<'a>() -> impl Parser<'a, PlatformHeader<'a>, EHeader<'a>> {
         packages: specialize_err(EHeader::Packages, packages()),
         imports: specialize_err(EHeader::Imports, imports()),
         provides: specialize_err(EHeader::Provides, provides_exposed()),
+        unavailable: optional(specialize_err(EHeader::Unavailable, unavailable_modules())),
     })
     .trace("platform_header")
 }
@@ -598,7 +599,11 @@ fn requires_typed_ident<'a>() -> impl Parser<'a, Loc<Spaced<'a, TypedIdent<'a>>>
         byte(b'{', ERequires::ListStart),
         skip_second!(
             reset_min_indent(space0_around_ee(
-                specialize_err(ERequires::TypedIdent, loc!(typed_ident()),),
+                specialize_err(
+                    ERequires::TypedIdent,
+                    // A signature on lines of its own is formatted with a trailing comma
+                    skip_second!(loc!(typed_ident()), optional(byte(b',', ETypedIdent::Name)))
+                ),
                 ERequires::ListStart,
                 ERequires::ListEnd
             )),
@@ -685,6 +690,22 @@ fn exposes_modules<'a>() -> impl Parser<
     })
 }
 
+fn unavailable_modules<'a>() -> impl Parser<
+    'a,
+    KeywordItem<'a, UnavailableKeyword, Collection<'a, Loc<Spaced<'a, ModuleName<'a>>>>>,
+    EExposes,
+> {
+    record!(KeywordItem {
+        keyword: spaces_around_keyword(
+            UnavailableKeyword,
+            EExposes::Exposes,
+            EExposes::IndentExposes,
+            EExposes::IndentListStart
+        ),
+        item: exposes_module_collection(),
+    })
+}
+
 fn exposes_module_collection<'a>(
 ) -> impl Parser<'a, Collection<'a, Loc<Spaced<'a, ModuleName<'a>>>>, EExposes> {
     collection_trailing_sep_e!(
@@ -2,6 +2,7 @@ use crate::ast::Base;
 use crate::parser::{ENumber, ParseResult, Parser, Progress};
 use crate::state::State;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NumLiteral<'a> {
     Float(&'a str),
     Num(&'a str),
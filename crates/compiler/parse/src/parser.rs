@@ -121,6 +121,7 @@ pub enum EHeader<'a> {
     Packages(EPackages<'a>, Position),
     Generates(EGenerates, Position),
     GeneratesWith(EGeneratesWith, Position),
+    Unavailable(EExposes, Position),
 
     Space(BadInputError, Position),
     Start(Position),
@@ -213,6 +214,7 @@ pub enum EPackageEntry<'a> {
     IndentPackage(Position),
     IndentPlatform(Position),
     Platform(Position),
+    Target(Position),
     Space(BadInputError, Position),
 }
 
@@ -264,6 +266,7 @@ pub enum EGeneratesWith {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BadInputError {
     HasTab,
     HasMisplacedCarriageReturn,
@@ -500,6 +503,8 @@ pub enum EIf<'a> {
     If(Position),
     Then(Position),
     Else(Position),
+    /// An `if` with no `else` after its `then` branch. The region is that of the `if`.
+    MissingElse(Region, Position),
     // TODO make EEXpr
     Condition(&'a EExpr<'a>, Position),
     ThenBranch(&'a EExpr<'a>, Position),
@@ -1309,6 +1314,34 @@ where
     }
 }
 
+/// A `,` between the items of a list that ends with `->`, like the arguments of a closure or of a
+/// function type. A `,` followed by the `->` is a trailing comma rather than a separator, so this
+/// fails without progress on it and leaves it to be parsed along with the `->`.
+pub fn separating_comma<'a, ToError, E>(to_error: ToError) -> impl Parser<'a, (), E>
+where
+    ToError: Fn(Position) -> E,
+    E: 'a + SpaceProblem,
+{
+    move |arena, state: State<'a>, min_indent: u32| {
+        if !state.bytes().starts_with(b",") {
+            return Err((NoProgress, to_error(state.pos())));
+        }
+
+        let after_comma = state.clone().advance(1);
+        let is_trailing =
+            match crate::blankspace::spaces::<E>().parse(arena, after_comma.clone(), min_indent) {
+                Ok((_, _, after_spaces)) => after_spaces.bytes().starts_with(b"->"),
+                Err(_) => false,
+            };
+
+        if is_trailing {
+            Err((NoProgress, to_error(state.pos())))
+        } else {
+            Ok((MadeProgress, (), after_comma))
+        }
+    }
+}
+
 /// Make the given parser optional, it can complete or not consume anything,
 /// but it can't error with progress made.
 ///
@@ -2458,7 +2491,7 @@ macro_rules! one_or_more {
     };
 }
 
-/// Creates a parser that debug prints the result of parsing.
+/// Creates a parser that logs the result of parsing, shown with `ROC_LOG=parse=trace`.
 /// It doesn't change the given parser at all,
 /// useful for inspecting a parser during development.
 ///
@@ -2491,11 +2524,19 @@ macro_rules! one_or_more {
 macro_rules! debug {
     ($parser:expr) => {
         move |arena, state: $crate::state::State<'a>, min_indent: u32| {
-            dbg!($parser.parse(arena, state, min_indent))
+            $crate::parser::log_parse_result($parser.parse(arena, state, min_indent))
         }
     };
 }
 
+/// Used by [`debug!`] to log a parse result.
+#[doc(hidden)]
+pub fn log_parse_result<T: std::fmt::Debug>(result: T) -> T {
+    roc_debug_flags::roc_log!("parse", Trace, "{:#?}", result);
+
+    result
+}
+
 /// Matches either of the two given parsers.
 /// If the first parser succeeds, its result is used,
 /// otherwise, the second parser's result is used.
@@ -85,8 +85,8 @@ fn loc_pattern_help_help<'a>(
             crate::pattern::record_pattern_help()
         )),
         loc!(specialize_err(EPattern::List, list_pattern_help())),
-        loc!(number_pattern_help()),
         loc!(string_like_pattern_help()),
+        loc!(number_pattern_help()),
     )
 }
 
@@ -221,26 +221,43 @@ fn loc_pattern_in_parens_help<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PInPare
 }
 
 fn number_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
-    specialize_err(
-        EPattern::NumLiteral,
-        map!(crate::number_literal::number_literal(), |literal| {
-            use crate::number_literal::NumLiteral::*;
-
-            match literal {
-                Num(s) => Pattern::NumLiteral(s),
-                Float(s) => Pattern::FloatLiteral(s),
-                NonBase10Int {
-                    string,
-                    base,
-                    is_negative,
-                } => Pattern::NonBase10Literal {
-                    string,
-                    base,
-                    is_negative,
-                },
+    move |arena, state: State<'a>, min_indent| {
+        use crate::number_literal::NumLiteral::*;
+
+        let start = state.pos();
+
+        match crate::number_literal::number_literal().parse(arena, state.clone(), min_indent) {
+            Ok((progress, literal, state)) => {
+                let pattern = match literal {
+                    Num(s) => Pattern::NumLiteral(s),
+                    Float(s) => Pattern::FloatLiteral(s),
+                    NonBase10Int {
+                        string,
+                        base,
+                        is_negative,
+                    } => Pattern::NonBase10Literal {
+                        string,
+                        base,
+                        is_negative,
+                    },
+                };
+
+                Ok((progress, pattern, state))
             }
-        }),
-    )
+            Err((NoProgress, problem))
+                if state.bytes().starts_with(b"-") && !state.bytes().starts_with(b"->") =>
+            {
+                // Patterns have no unary negation, so a minus sign can only start a number.
+                // This is the last kind of pattern we try, so report that rather than
+                // a pattern that didn't start at all.
+                Err((NoProgress, EPattern::NumLiteral(problem, start)))
+            }
+            Err((NoProgress, _)) => Err((NoProgress, EPattern::Start(start))),
+            Err((MadeProgress, problem)) => {
+                Err((MadeProgress, EPattern::NumLiteral(problem, start)))
+            }
+        }
+    }
 }
 
 fn string_like_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
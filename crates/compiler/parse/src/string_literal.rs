@@ -66,6 +66,7 @@ fn utf8<'a>(state: State<'a>, string_bytes: &'a [u8]) -> Result<&'a str, (Progre
     })
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StrLikeLiteral<'a> {
     SingleQuote(SingleQuoteLiteral<'a>),
     Str(StrLiteral<'a>),
@@ -100,7 +101,7 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
             is_multiline = true;
             is_single_quote = false;
 
-            if state.consume_mut("\n") {
+            if state.consume_mut("\n") || state.consume_mut("\r\n") {
                 state = consume_indent(state, indent)?;
             }
         } else if state.consume_mut("\"") {
@@ -322,6 +323,21 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         let without_newline = &state.bytes()[0..(segment_parsed_bytes - 1)];
                         let with_newline = &state.bytes()[0..segment_parsed_bytes];
 
+                        // A `\r\n` line ending is part of the string as just `\n`, so that the
+                        // string is the same whichever line endings its file uses.
+                        let (without_newline, with_newline) = match without_newline
+                            .strip_suffix(b"\r")
+                        {
+                            Some(without_cr) => {
+                                let mut with_lf = Vec::with_capacity_in(with_newline.len(), arena);
+                                with_lf.extend_from_slice(without_cr);
+                                with_lf.push(b'\n');
+
+                                (without_cr, with_lf.into_bump_slice())
+                            }
+                            None => (without_newline, with_newline),
+                        };
+
                         state.advance_mut(segment_parsed_bytes);
                         state = consume_indent(state, indent)?;
                         bytes = state.bytes().iter();
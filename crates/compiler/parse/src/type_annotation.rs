@@ -12,8 +12,9 @@ use crate::parser::{
     absolute_column_min_indent, increment_min_indent, then, ERecord, ETypeAbilityImpl,
 };
 use crate::parser::{
-    allocated, backtrackable, byte, fail, optional, specialize_err, specialize_err_ref, two_bytes,
-    word, EType, ETypeApply, ETypeInParens, ETypeInlineAlias, ETypeRecord, ETypeTagUnion, Parser,
+    allocated, backtrackable, byte, fail, optional, separating_comma, specialize_err,
+    specialize_err_ref, two_bytes, word, EType, ETypeApply, ETypeInParens, ETypeInlineAlias,
+    ETypeRecord, ETypeTagUnion, Parser,
     Progress::{self, *},
 };
 use crate::state::State;
@@ -579,7 +580,7 @@ fn expression<'a>(
 
         let result = and![
             zero_or_more!(skip_first!(
-                byte(b',', EType::TFunctionArgument),
+                separating_comma(EType::TFunctionArgument),
                 one_of![
                     space0_around_ee(
                         term(stop_at_surface_has),
@@ -591,7 +592,11 @@ fn expression<'a>(
             ))
             .trace("type_annotation:expression:rest_args"),
             skip_second!(
-                space0_e(EType::TIndentStart),
+                // The last argument can be followed by a trailing comma
+                skip_first!(
+                    optional(byte(b',', EType::TFunctionArgument)),
+                    space0_e(EType::TIndentStart)
+                ),
                 two_bytes(b'-', b'>', EType::TStart)
             )
             .trace("type_annotation:expression:arrow")
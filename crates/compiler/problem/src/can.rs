@@ -40,6 +40,13 @@ pub enum Problem {
     UnusedModuleImport(ModuleId, Region),
     ExposedButNotDefined(Symbol),
     UnknownGeneratesWith(Loc<Ident>),
+    /// A builtin used by an app whose platform lists the builtin's module as unavailable
+    UnavailableBuiltin {
+        symbol: Symbol,
+        region: Region,
+    },
+    /// A platform listed a module that isn't a builtin as unavailable
+    UnavailableNotBuiltin(ModuleName, Region),
     ImportNameConflict {
         name: ModuleName,
         is_alias: bool,
@@ -99,6 +106,20 @@ pub enum Problem {
         record_region: Region,
         field_region: Region,
     },
+    /// A record update field that is set to the value it already has, e.g. the `x` in
+    /// `{ r & x: r.x, y: 1 }`
+    UnchangedRecordUpdateField {
+        record: Symbol,
+        field_name: Lowercase,
+        record_region: Region,
+        field_region: Region,
+    },
+    /// A record update where every field is set to the value it already has,
+    /// e.g. `{ r & x: r.x }`, which is the same as just `r`
+    IdentityRecordUpdate {
+        record: Symbol,
+        region: Region,
+    },
 
     DuplicateTag {
         tag_name: TagName,
@@ -241,6 +262,8 @@ impl Problem {
             Problem::ImportShadowsSymbol { .. } => RuntimeError,
             Problem::ExposedButNotDefined(_) => RuntimeError,
             Problem::UnknownGeneratesWith(_) => RuntimeError,
+            Problem::UnavailableBuiltin { .. } => RuntimeError,
+            Problem::UnavailableNotBuiltin(_, _) => RuntimeError,
             Problem::UnusedArgument(_, _, _, _) => Warning,
             Problem::UnusedBranchDef(_, _) => Warning,
             Problem::PrecedenceProblem(_) => RuntimeError,
@@ -253,6 +276,8 @@ impl Problem {
             Problem::DuplicateRecordFieldValue { .. } => Warning,
             Problem::DuplicateRecordFieldType { .. } => RuntimeError,
             Problem::InvalidOptionalValue { .. } => RuntimeError,
+            Problem::UnchangedRecordUpdateField { .. } => Warning,
+            Problem::IdentityRecordUpdate { .. } => Warning,
             Problem::DuplicateTag { .. } => RuntimeError,
             Problem::RuntimeError(_) => RuntimeError,
             Problem::SignatureDefMismatch { .. } => RuntimeError,
@@ -321,9 +346,11 @@ impl Problem {
             | Problem::ExplicitBuiltinTypeImport(_, region)
             | Problem::ImportShadowsSymbol { region, .. }
             | Problem::UnknownGeneratesWith(Loc { region, .. })
+            | Problem::UnavailableBuiltin { region, .. }
+            | Problem::UnavailableNotBuiltin(_, region)
             | Problem::UnusedArgument(_, _, _, region)
             | Problem::UnusedBranchDef(_, region)
-            | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _))
+            | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _, _))
             | Problem::UnsupportedPattern(_, region)
             | Problem::CyclicAlias(_, region, _, _)
             | Problem::PhantomTypeArgument {
@@ -346,6 +373,11 @@ impl Problem {
                 record_region: region,
                 ..
             }
+            | Problem::UnchangedRecordUpdateField {
+                record_region: region,
+                ..
+            }
+            | Problem::IdentityRecordUpdate { region, .. }
             | Problem::DuplicateTag {
                 tag_union_region: region,
                 ..
@@ -469,13 +501,15 @@ pub enum ExtensionTypeKind {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PrecedenceProblem {
-    BothNonAssociative(Region, Loc<BinOp>, Loc<BinOp>),
+    /// Two comparisons in a row, like `a < b < c`. The regions at the end are those of the
+    /// operands around the two operators.
+    BothNonAssociative(Region, Loc<BinOp>, Loc<BinOp>, [Region; 3]),
 }
 
 impl PrecedenceProblem {
     pub fn region(&self) -> Region {
         match self {
-            PrecedenceProblem::BothNonAssociative(region, _, _) => *region,
+            PrecedenceProblem::BothNonAssociative(region, _, _, _) => *region,
         }
     }
 }
@@ -1,6 +1,7 @@
 use std::fmt::{self, Debug};
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Region {
     start: Position,
     end: Position,
@@ -34,6 +35,11 @@ impl Region {
         self.end.offset - self.start.offset
     }
 
+    /// The bytes of the source this region spans. Use [`LineInfo`] to get lines and columns.
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        self.start.byte_offset()..self.end.byte_offset()
+    }
+
     pub fn span_across(start: &Region, end: &Region) -> Self {
         Region {
             start: start.start,
@@ -97,6 +103,7 @@ impl fmt::Debug for Region {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct Position {
     pub offset: u32,
 }
@@ -290,6 +297,7 @@ impl fmt::Debug for LineColumnRegion {
 }
 
 #[derive(Clone, Eq, Copy, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Loc<T> {
     pub region: Region,
     pub value: T,
@@ -340,7 +348,7 @@ impl<T> Loc<T> {
     }
 
     pub fn byte_range(&self) -> std::ops::Range<usize> {
-        self.region.start.byte_offset()..self.region.end.byte_offset()
+        self.region.byte_range()
     }
 }
 
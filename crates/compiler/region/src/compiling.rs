@@ -0,0 +1,70 @@
+//! The region each thread is compiling, so that an internal compiler error can point at the code
+//! that triggered it.
+//!
+//! The phases record the def or function they are working on as they go, innermost last. A panic
+//! hook can then read the region of the thread that panicked with [`current`], before the stack
+//! unwinds.
+
+use std::cell::Cell;
+
+use crate::all::Region;
+
+thread_local! {
+    static CURRENT: Cell<Option<Region>> = const { Cell::new(None) };
+}
+
+/// The region this thread is compiling, if any.
+pub fn current() -> Option<Region> {
+    CURRENT.with(Cell::get)
+}
+
+/// Sets the region this thread is compiling, and returns the one it replaces. For code that can't
+/// hold an [`enter`] guard, like a loop over a work stack.
+pub fn set(region: Option<Region>) -> Option<Region> {
+    CURRENT.with(|current| current.replace(region))
+}
+
+/// Records the region until the returned guard is dropped.
+pub fn enter(region: Region) -> RegionGuard {
+    RegionGuard {
+        previous: set(Some(region)),
+    }
+}
+
+#[must_use]
+pub struct RegionGuard {
+    previous: Option<Region>,
+}
+
+impl Drop for RegionGuard {
+    fn drop(&mut self) {
+        set(self.previous);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{current, enter};
+    use crate::all::{Position, Region};
+
+    #[test]
+    fn guards_restore_the_outer_region() {
+        let outer = Region::new(Position::new(0), Position::new(10));
+        let inner = Region::new(Position::new(2), Position::new(4));
+
+        assert_eq!(current(), None);
+
+        {
+            let _outer = enter(outer);
+
+            {
+                let _inner = enter(inner);
+                assert_eq!(current(), Some(inner));
+            }
+
+            assert_eq!(current(), Some(outer));
+        }
+
+        assert_eq!(current(), None);
+    }
+}
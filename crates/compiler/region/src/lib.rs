@@ -5,3 +5,4 @@
 #![allow(clippy::large_enum_variant)]
 
 pub mod all;
+pub mod compiling;
@@ -0,0 +1,421 @@
+//! A stable API for embedding the compiler in other tools, like build systems, playgrounds, and
+//! linters.
+//!
+//! [check_file] and [check_str] load a module along with everything it imports, check it, and
+//! return a [Checked] module with its [Diagnostic]s and the types of its exposed values. Tools
+//! that need more than that can still reach the [LoadedModule] underneath, but the types in
+//! this crate are the ones meant to stay the same as the compiler changes.
+//!
+//! A [DiagnosticSink] gets each module's diagnostics as soon as that module is checked, so that
+//! long builds can show them as they go.
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_load::{
+    ExecutionMode, FunctionKind, LoadConfig, LoadResult, LoadStart, LoadingProblem, ProblemSink,
+    ReportedProblem,
+};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_region::all::{LineColumnRegion, LineInfo};
+use roc_reporting::report::{can_problem, type_problem, RocDocAllocator, DEFAULT_PALETTE};
+use roc_solve_problem::TypeError;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub use roc_load::{LoadedModule, Threading};
+pub use roc_problem::Severity;
+pub use roc_reporting::report::RenderTarget;
+pub use roc_target::Target;
+
+/// How to check a module.
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The target that numbers and layouts are checked against.
+    pub target: Target,
+    /// How the messages of diagnostics are rendered.
+    pub render: RenderTarget,
+    /// How many threads [check_file] may use. [check_str] always uses a single thread.
+    pub threading: Threading,
+    /// Report an error instead of downloading packages that aren't in the Roc cache yet.
+    pub offline: bool,
+    /// Receives the diagnostics of each module while the others are still being checked. They
+    /// are in [Checked::diagnostics] too.
+    pub diagnostic_sink: Option<DiagnosticSink>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            target: Target::default(),
+            render: RenderTarget::Generic,
+            threading: Threading::AllAvailable,
+            offline: false,
+            diagnostic_sink: None,
+        }
+    }
+}
+
+/// An error or warning about a module.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The file the problem is in.
+    pub path: PathBuf,
+    /// Where in the file the problem is, if it has a location.
+    pub region: Option<LineColumnRegion>,
+    /// The full report, rendered as [Options::render] asked for.
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        !matches!(self.severity, Severity::Warning)
+    }
+}
+
+/// A callback for diagnostics. It's called from the thread that coordinates checking, so it
+/// should return quickly, like by sending the diagnostic to a channel.
+#[derive(Clone)]
+pub struct DiagnosticSink {
+    callback: Arc<dyn Fn(Diagnostic) + Send + Sync>,
+}
+
+impl DiagnosticSink {
+    pub fn new(callback: impl Fn(Diagnostic) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+
+    fn problem_sink(&self) -> ProblemSink {
+        let callback = self.callback.clone();
+
+        ProblemSink::new(move |problem: ReportedProblem| {
+            callback(Diagnostic {
+                severity: problem.severity,
+                path: problem.path,
+                region: problem.region,
+                message: problem.report,
+            })
+        })
+    }
+}
+
+impl fmt::Debug for DiagnosticSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiagnosticSink").finish_non_exhaustive()
+    }
+}
+
+/// A module that made it through checking, possibly with errors.
+pub struct Checked {
+    pub diagnostics: Vec<Diagnostic>,
+    module: LoadedModule,
+}
+
+impl Checked {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
+    }
+
+    /// The names and types of the values the root module exposes, in the order it exposes them.
+    pub fn exposed_types(&mut self) -> Vec<(String, String)> {
+        let module = &mut self.module;
+        let home = module.module_id;
+        let exposes = module.exposes.get(&home).cloned().unwrap_or_default();
+        let subs = module.solved.inner_mut();
+
+        exposes
+            .into_iter()
+            .map(|(symbol, var)| {
+                let name = symbol.as_str(&module.interns).to_string();
+                let type_str =
+                    name_and_print_var(var, subs, home, &module.interns, DebugPrint::NOTHING);
+
+                (name, type_str)
+            })
+            .collect()
+    }
+
+    /// Everything the compiler knows about the module. Unlike the rest of this crate, its shape
+    /// follows the compiler's internals and changes along with them.
+    pub fn loaded_module(&self) -> &LoadedModule {
+        &self.module
+    }
+
+    pub fn into_loaded_module(self) -> LoadedModule {
+        self.module
+    }
+}
+
+/// Checks the module at the path, along with the modules and packages it imports.
+///
+/// Returns an error when the module can't be checked at all, like when a file is missing or a
+/// header doesn't parse. Problems in the code itself end up in [Checked::diagnostics].
+pub fn check_file(path: PathBuf, options: &Options) -> Result<Checked, Diagnostic> {
+    let arena = Bump::new();
+    let cache_dir = cache::roc_cache_dir();
+    let load_config = LoadConfig {
+        target: options.target,
+        function_kind: FunctionKind::LambdaSet,
+        render: options.render,
+        palette: DEFAULT_PALETTE,
+        threading: options.threading,
+        exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: options
+            .diagnostic_sink
+            .as_ref()
+            .map(DiagnosticSink::problem_sink),
+        defines: Default::default(),
+    };
+
+    let loaded = roc_load::load_and_typecheck(
+        &arena,
+        path.clone(),
+        roc_cache_dir(&cache_dir, options),
+        load_config,
+    );
+
+    match loaded {
+        Ok(module) => Ok(checked(module, options)),
+        Err(problem) => Err(loading_diagnostic(path, problem)),
+    }
+}
+
+/// Checks a module whose source is in memory, like the contents of an unsaved editor buffer.
+///
+/// The path is where the module would be on disk: the modules it imports are looked up next to
+/// it, and diagnostics refer to it.
+pub fn check_str(path: PathBuf, source: &str, options: &Options) -> Result<Checked, Diagnostic> {
+    let arena = Bump::new();
+    let cache_dir = cache::roc_cache_dir();
+    let src_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let source = arena.alloc_str(source);
+    let roc_cache_dir = roc_cache_dir(&cache_dir, options);
+
+    let load_start = match LoadStart::from_str(
+        &arena,
+        path.clone(),
+        source,
+        options.target,
+        roc_cache_dir,
+        src_dir,
+    ) {
+        Ok(load_start) => load_start,
+        Err(problem) => return Err(loading_diagnostic(path, problem)),
+    };
+
+    let loaded = roc_load::load_single_threaded(
+        &arena,
+        load_start,
+        options.target,
+        FunctionKind::LambdaSet,
+        options.render,
+        DEFAULT_PALETTE,
+        roc_cache_dir,
+        ExecutionMode::Check,
+        None,
+        options
+            .diagnostic_sink
+            .as_ref()
+            .map(DiagnosticSink::problem_sink),
+    );
+
+    match loaded {
+        Ok(LoadResult::TypeChecked(module)) => Ok(checked(module, options)),
+        Ok(LoadResult::Monomorphized(_)) => unreachable!("checking doesn't monomorphize"),
+        Err(problem) => Err(loading_diagnostic(path, problem)),
+    }
+}
+
+fn roc_cache_dir<'a>(cache_dir: &'a Path, options: &Options) -> RocCacheDir<'a> {
+    if options.offline {
+        RocCacheDir::Offline(cache_dir)
+    } else {
+        RocCacheDir::Persistent(cache_dir)
+    }
+}
+
+fn loading_diagnostic(path: PathBuf, problem: LoadingProblem) -> Diagnostic {
+    let message = match problem {
+        LoadingProblem::FormattedReport(report) => report,
+        // TODO: like handle_loading_problem in roc_build, this relies on load turning every
+        // problem into a formatted report eventually.
+        problem => format!("{problem:?}"),
+    };
+
+    Diagnostic {
+        severity: Severity::Fatal,
+        path,
+        region: None,
+        message,
+    }
+}
+
+fn checked(mut module: LoadedModule, options: &Options) -> Checked {
+    let diagnostics = diagnostics(
+        &module.sources,
+        &module.interns,
+        &mut module.can_problems,
+        &mut module.type_problems,
+        options.render,
+    );
+
+    Checked {
+        diagnostics,
+        module,
+    }
+}
+
+/// Renders the problems of every module, in the order `roc check` prints them.
+fn diagnostics(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    render: RenderTarget,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for (home, (path, src)) in sources.iter() {
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let line_info = LineInfo::new(src);
+        let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+
+        let type_reports = type_problems
+            .remove(home)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|problem| {
+                let region = problem.region();
+                let report = type_problem(&alloc, &line_info, path.clone(), problem)?;
+
+                Some((report, region))
+            });
+        let can_reports = can_problems
+            .remove(home)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|problem| {
+                let region = problem.region();
+                let report = can_problem(&alloc, &line_info, path.clone(), problem);
+
+                (report, region)
+            });
+
+        for (report, region) in type_reports.chain(can_reports) {
+            let severity = report.severity;
+            let mut message = String::new();
+
+            report.render(render, &mut message, &alloc, &DEFAULT_PALETTE);
+
+            diagnostics.push(Diagnostic {
+                severity,
+                path: path.clone(),
+                region: region.map(|region| line_info.convert_region(region)),
+                message,
+            });
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn check(source: &str, diagnostic_sink: Option<DiagnosticSink>) -> Checked {
+        let dir = tempfile::tempdir().unwrap();
+        let options = Options {
+            render: RenderTarget::Generic,
+            threading: Threading::Single,
+            offline: true,
+            diagnostic_sink,
+            ..Options::default()
+        };
+
+        check_str(dir.path().join("Test.roc"), source, &options).unwrap()
+    }
+
+    #[test]
+    fn exposed_types() {
+        let mut checked = check(
+            indoc!(
+                r#"
+                module [double, greeting]
+
+                double = \n -> n * 2
+
+                greeting : Str
+                greeting = "hi"
+                "#
+            ),
+            None,
+        );
+
+        assert!(checked.diagnostics.is_empty());
+        assert_eq!(
+            checked.exposed_types(),
+            [
+                ("double".to_string(), "Num a -> Num a".to_string()),
+                ("greeting".to_string(), "Str".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let checked = check(
+            indoc!(
+                r#"
+                module [answer]
+
+                answer : Str
+                answer = 42
+                "#
+            ),
+            None,
+        );
+
+        assert!(checked.has_errors());
+
+        let diagnostic = &checked.diagnostics[0];
+
+        assert!(diagnostic.message.contains("TYPE MISMATCH"));
+        assert_eq!(diagnostic.region.map(|region| region.start.line), Some(3));
+    }
+
+    #[test]
+    fn diagnostic_sink() {
+        let streamed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = {
+            let streamed = streamed.clone();
+
+            DiagnosticSink::new(move |diagnostic| streamed.lock().unwrap().push(diagnostic))
+        };
+
+        let checked = check(
+            indoc!(
+                r#"
+                module [answer]
+
+                answer : Str
+                answer = 42
+                "#
+            ),
+            Some(sink),
+        );
+
+        let streamed = streamed.lock().unwrap();
+
+        assert_eq!(streamed.len(), checked.diagnostics.len());
+        assert_eq!(streamed[0].message, checked.diagnostics[0].message);
+    }
+}
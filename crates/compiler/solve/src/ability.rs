@@ -2,9 +2,7 @@ use roc_can::abilities::AbilitiesStore;
 use roc_can::expr::PendingDerives;
 use roc_checkmate::with_checkmate;
 use roc_collections::{VecMap, VecSet};
-use roc_debug_flags::dbg_do;
-#[cfg(debug_assertions)]
-use roc_debug_flags::ROC_PRINT_UNDERIVABLE;
+use roc_debug_flags::roc_log;
 use roc_derive_key::{DeriveError, Derived};
 use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
@@ -322,9 +320,13 @@ impl ObligationCache {
                 var: failure_var,
                 context,
             })) => {
-                dbg_do!(ROC_PRINT_UNDERIVABLE, {
-                    eprintln!("❌ derived {:?} of {:?}", ability, subs.dbg(failure_var));
-                });
+                roc_log!(
+                    "solve",
+                    Debug,
+                    "could not derive {:?} for {:?}",
+                    ability,
+                    subs.dbg(failure_var)
+                );
 
                 Some(if failure_var == var {
                     UnderivableReason::SurfaceNotDerivable(context)
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use bumpalo::Bump;
 use roc_can::{constraint::Constraints, module::ExposedByModule};
 use roc_checkmate::with_checkmate;
@@ -26,6 +28,8 @@ pub struct SolveEnv<'a> {
 /// Environment necessary for inference.
 pub struct InferenceEnv<'a> {
     pub constraints: &'a Constraints,
+    pub cancelled: Option<&'a AtomicBool>,
+    pub max_variables: Option<usize>,
     pub function_kind: FunctionKind,
     pub arena: &'a Bump,
     pub derived_env: &'a DerivedEnv<'a>,
@@ -35,6 +39,18 @@ pub struct InferenceEnv<'a> {
     pub checkmate: Option<roc_checkmate::Collector>,
 }
 
+impl InferenceEnv<'_> {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .is_some_and(|cancelled| cancelled.load(Ordering::Acquire))
+    }
+
+    /// The limit on type variables, if the module has gone past it.
+    pub fn exceeded_max_variables(&self) -> Option<usize> {
+        self.max_variables.filter(|&max| self.subs.len() > max)
+    }
+}
+
 impl<'a> SolveEnv<'a> {
     /// Introduce some variables to Pools at the given rank.
     /// Also, set each of their ranks in Subs to be the given rank.
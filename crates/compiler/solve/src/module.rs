@@ -3,8 +3,9 @@ use crate::FunctionKind;
 use crate::{aliases::Aliases, solve};
 use roc_can::abilities::{AbilitiesStore, ResolvedImpl};
 use roc_can::constraint::{Constraint, Constraints};
-use roc_can::expr::PendingDerives;
+use roc_can::expr::{Declarations, PendingDerives};
 use roc_can::module::{ExposedByModule, ResolvedImplementations, RigidVariables};
+use roc_can::traverse::find_when_branch_bindings;
 use roc_collections::all::MutMap;
 use roc_collections::VecMap;
 use roc_derive::SharedDerivedModule;
@@ -12,7 +13,8 @@ use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_solve_problem::TypeError;
 use roc_types::subs::{Content, ExposedTypesStorageSubs, FlatType, StorageSubs, Subs, Variable};
-use roc_types::types::{Alias, MemberImpl, Types};
+use roc_types::types::{Alias, Category, ErrorType, MemberImpl, Polarity, Types};
+use std::sync::atomic::AtomicBool;
 
 /// A marker that a given Subs has been solved.
 /// The only way to obtain a Solved<Subs> is by running the solver on it.
@@ -76,6 +78,12 @@ pub struct SolveConfig<'a> {
     /// Needed during solving to resolve lambda sets from derived implementations that escape into
     /// the user module.
     pub derived_module: SharedDerivedModule,
+    /// Set when the types of the module are no longer needed, for example because its source
+    /// changed. Solving then stops early, leaving the remaining constraints unsolved.
+    pub cancelled: Option<&'a AtomicBool>,
+    /// The most type variables the module may allocate. Past it, solving stops with a
+    /// [`TypeError::TooManyTypeVariables`] rather than running out of memory.
+    pub max_variables: Option<usize>,
 
     #[cfg(debug_assertions)]
     /// The checkmate collector for this module.
@@ -139,6 +147,46 @@ pub fn run_solve(
     }
 }
 
+/// Adds the types of the identifiers bound by the enclosing `when` branch pattern to the
+/// problems in the bodies of `when` branches, since those are often where the confusion starts.
+pub fn add_when_branch_bindings(
+    problems: Vec<TypeError>,
+    decls: &Declarations,
+    subs: &mut Subs,
+) -> Vec<TypeError> {
+    problems
+        .into_iter()
+        .map(|problem| {
+            let (region, looked_up) = match &problem {
+                TypeError::BadExpr(region, Category::Lookup(symbol), found, _) => {
+                    (*region, Some((*symbol, found.clone())))
+                }
+                TypeError::BadExpr(region, ..) => (*region, None),
+                _ => return problem,
+            };
+
+            let bindings: Vec<_> = find_when_branch_bindings(region, decls)
+                .into_iter()
+                .map(|(symbol, var)| match &looked_up {
+                    // The failed unification has already replaced the type of the variable
+                    Some((looked_up, found)) if *looked_up == symbol => (symbol, found.clone()),
+                    _ => (symbol, subs.var_to_error_type(var, Polarity::OF_VALUE)),
+                })
+                .filter(|(_, typ)| !matches!(typ, ErrorType::Error))
+                .collect();
+
+            if bindings.is_empty() {
+                return problem;
+            }
+
+            TypeError::InWhenBranch {
+                problem: Box::new(problem),
+                bindings,
+            }
+        })
+        .collect()
+}
+
 /// Copies exposed types and all ability specializations, which may be implicitly exposed.
 pub fn exposed_types_storage_subs(
     home: ModuleId,
@@ -16,13 +16,13 @@ use roc_can::abilities::{AbilitiesStore, MemberSpecializationInfo};
 use roc_can::constraint::Constraint::{self, *};
 use roc_can::constraint::{Cycle, LetConstraint, OpportunisticResolve};
 use roc_can::expected::{Expected, PExpected};
-use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::ROC_VERIFY_RIGID_LET_GENERALIZED;
+use roc_debug_flags::{dbg_do, roc_log};
 use roc_error_macros::internal_error;
 use roc_module::symbol::Symbol;
 use roc_problem::can::CycleEntry;
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Region};
 use roc_solve_problem::TypeError;
 use roc_solve_schema::UnificationMode;
 use roc_types::subs::{
@@ -120,6 +120,8 @@ fn run_help(
     abilities_store: &mut AbilitiesStore,
 ) -> RunSolveOutput {
     let subs = &mut owned_subs;
+    #[cfg(debug_assertions)]
+    let home = config.home;
     let SolveConfig {
         home: _,
         constraints,
@@ -129,9 +131,18 @@ fn run_help(
         exposed_by_module,
         derived_module,
         function_kind,
+        cancelled,
+        max_variables,
         ..
     } = config;
 
+    roc_log!(
+        "solve",
+        Debug,
+        "solving {home:?} with {} constraints",
+        constraints.constraints.len()
+    );
+
     let mut pools = Pools::default();
 
     let state = State {
@@ -152,6 +163,8 @@ fn run_help(
     let mut env = InferenceEnv {
         arena: &arena,
         constraints,
+        cancelled,
+        max_variables,
         function_kind,
         derived_env: &derived_env,
         subs,
@@ -204,6 +217,8 @@ enum Work<'a> {
         constraint: &'a Constraint,
     },
     CheckForInfiniteTypes(LocalDefVarsVec<(Symbol, Loc<Variable>)>),
+    /// The defs constraint of a let has been solved, so its def is no longer being inferred
+    LeaveDef,
     /// The ret_con part of a let constraint that does NOT introduces rigid and/or flex variables
     LetConNoVariables {
         scope: &'a Scope,
@@ -253,7 +268,24 @@ fn solve(
 
     let mut stack = vec![initial];
 
+    // The defs whose constraints are being solved, innermost last. The innermost one is blamed
+    // if there are too many type variables.
+    let mut def_stack: Vec<(Symbol, Region)> = Vec::new();
+
     while let Some(work_item) = stack.pop() {
+        if env.is_cancelled() {
+            break;
+        }
+
+        if let Some(limit) = env.exceeded_max_variables() {
+            problems.push(TypeError::TooManyTypeVariables {
+                def: def_stack.last().copied(),
+                limit,
+            });
+
+            break;
+        }
+
         let (scope, rank, constraint) = match work_item {
             Work::Constraint {
                 scope,
@@ -272,6 +304,12 @@ fn solve(
 
                 continue;
             }
+            Work::LeaveDef => {
+                def_stack.pop();
+                roc_region::compiling::set(def_stack.last().map(|&(_, region)| region));
+
+                continue;
+            }
             Work::LetConNoVariables {
                 scope,
                 rank,
@@ -780,6 +818,17 @@ fn solve(
 
                 let pool_variables = &env.constraints.variables[pool_slice.indices()];
 
+                let def_symbols =
+                    &env.constraints.loc_symbols[let_con.def_types.loc_symbols.indices()];
+                let enters_def = match def_symbols.first() {
+                    Some(&def) => {
+                        def_stack.push(def);
+                        roc_region::compiling::set(Some(def.1));
+                        true
+                    }
+                    None => false,
+                };
+
                 if matches!(&ret_constraint, True) && let_con.rigid_vars.is_empty() {
                     debug_assert!(pool_variables.is_empty());
 
@@ -787,6 +836,9 @@ fn solve(
 
                     // If the return expression is guaranteed to solve,
                     // solve the assignments themselves and move on.
+                    if enters_def {
+                        stack.push(Work::LeaveDef);
+                    }
                     stack.push(Work::Constraint {
                         scope,
                         rank,
@@ -806,6 +858,9 @@ fn solve(
                         let_con,
                         pool_variables,
                     });
+                    if enters_def {
+                        stack.push(Work::LeaveDef);
+                    }
                     stack.push(Work::Constraint {
                         scope,
                         rank,
@@ -859,6 +914,9 @@ fn solve(
                         let_con,
                         pool_variables,
                     });
+                    if enters_def {
+                        stack.push(Work::LeaveDef);
+                    }
                     stack.push(Work::Constraint {
                         scope,
                         rank: binding_rank,
@@ -4,9 +4,8 @@ use std::collections::VecDeque;
 
 use roc_can::abilities::{AbilitiesStore, ImplKey};
 use roc_collections::{VecMap, VecSet};
-use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
-use roc_debug_flags::ROC_TRACE_COMPACTION;
+use roc_debug_flags::{roc_log, roc_log_enabled};
 use roc_derive_key::{DeriveError, DeriveKey};
 use roc_error_macros::{internal_error, todo_abilities};
 use roc_module::symbol::{ModuleId, Symbol};
@@ -187,10 +186,11 @@ fn trace_compaction_step_1(subs: &Subs, c_a: Variable, uls_a: &[Variable]) {
         })
         .collect::<Vec<_>>()
         .join(",");
-    eprintln!("===lambda set compaction===");
-    eprintln!("  concrete type: {c_a:?}");
-    eprintln!("  step 1:");
-    eprintln!("    uls_a = {{ {uls_a} }}");
+    roc_log!(
+        "solve",
+        Trace,
+        "compacting lambda sets of {c_a:?}, step 1: uls_a = {{ {uls_a} }}"
+    );
 }
 
 #[cfg(debug_assertions)]
@@ -205,13 +205,16 @@ fn trace_compaction_step_2(subs: &Subs, uls_a: &[Variable]) {
         })
         .collect::<Vec<_>>()
         .join(",");
-    eprintln!("  step 2:");
-    eprintln!("    uls_a' = {{ {uls_a} }}");
+    roc_log!(
+        "solve",
+        Trace,
+        "compacting lambda sets, step 2: uls_a' = {{ {uls_a} }}"
+    );
 }
 
 #[cfg(debug_assertions)]
 fn trace_compaction_step_3start() {
-    eprintln!("  step 3:");
+    roc_log!("solve", Trace, "compacting lambda sets, step 3");
 }
 
 #[cfg(debug_assertions)]
@@ -227,50 +230,58 @@ fn trace_compaction_step_3iter_start(
     );
     let t_f1 = roc_types::subs::SubsFmtContent(subs.get_content_without_compacting(t_f1), subs);
     let t_f2 = roc_types::subs::SubsFmtContent(subs.get_content_without_compacting(t_f2), subs);
-    eprintln!("    - iteration: {iteration_lambda_set:?}");
-    eprintln!("         {t_f1:?}");
-    eprintln!("      ~  {t_f2:?}");
+    roc_log!(
+        "solve",
+        Trace,
+        "compacting {iteration_lambda_set:?}: {t_f1:?} ~ {t_f2:?}"
+    );
 }
 
 #[cfg(debug_assertions)]
-#[rustfmt::skip]
 fn trace_compaction_step_3iter_end(subs: &Subs, t_f_result: Variable, skipped: bool) {
     let t_f_result =
         roc_types::subs::SubsFmtContent(subs.get_content_without_compacting(t_f_result), subs);
-    if skipped {
-    eprintln!("      SKIP");
-    }
-    eprintln!("      =  {t_f_result:?}\n");
+    let skipped = if skipped { " (skipped)" } else { "" };
+    roc_log!("solve", Trace, "compacted{skipped} to {t_f_result:?}");
 }
 
+/// Traces unspecialized lambda set compaction, shown with `ROC_LOG=solve=trace`.
 macro_rules! trace_compact {
     (1. $subs:expr, $c_a:expr, $uls_a:expr) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, {
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
             trace_compaction_step_1($subs, $c_a, $uls_a)
-        })
+        }
     }};
     (2. $subs:expr, $uls_a:expr) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, {
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
             trace_compaction_step_2($subs, $uls_a)
-        })
+        }
     }};
     (3start.) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, { trace_compaction_step_3start() })
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
+            trace_compaction_step_3start()
+        }
     }};
     (3iter_start. $subs:expr, $iteration_lset:expr, $t_f1:expr, $t_f2:expr) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, {
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
             trace_compaction_step_3iter_start($subs, $iteration_lset, $t_f1, $t_f2)
-        })
+        }
     }};
     (3iter_end. $subs:expr, $t_f_result:expr) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, {
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
             trace_compaction_step_3iter_end($subs, $t_f_result, false)
-        })
+        }
     }};
     (3iter_end_skipped. $subs:expr, $t_f_result:expr) => {{
-        dbg_do!(ROC_TRACE_COMPACTION, {
+        #[cfg(debug_assertions)]
+        if roc_log_enabled!("solve", Trace) {
             trace_compaction_step_3iter_end($subs, $t_f_result, true)
-        })
+        }
     }};
 }
 
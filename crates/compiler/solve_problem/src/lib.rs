@@ -33,6 +33,18 @@ pub enum TypeError {
     },
     IngestedFileBadUtf8(Box<PathBuf>, Utf8Error),
     IngestedFileUnsupportedType(Box<PathBuf>, ErrorType),
+    /// A problem in the body of a `when` branch, with the types of the identifiers bound by the
+    /// branch's pattern.
+    InWhenBranch {
+        problem: Box<TypeError>,
+        bindings: Vec<(Symbol, ErrorType)>,
+    },
+    /// Inference allocated more type variables than the limit allows, so solving stopped.
+    TooManyTypeVariables {
+        /// The def being solved when the limit was reached, if any
+        def: Option<(Symbol, Region)>,
+        limit: usize,
+    },
 }
 
 impl TypeError {
@@ -54,6 +66,8 @@ impl TypeError {
             TypeError::WrongSpecialization { .. } => RuntimeError,
             TypeError::IngestedFileBadUtf8(..) => Fatal,
             TypeError::IngestedFileUnsupportedType(..) => Fatal,
+            TypeError::TooManyTypeVariables { .. } => Fatal,
+            TypeError::InWhenBranch { problem, .. } => problem.severity(),
         }
     }
 
@@ -72,6 +86,8 @@ impl TypeError {
             TypeError::CircularDef(c) => c.first().map(|ce| ce.symbol_region),
             TypeError::IngestedFileBadUtf8(_, _) => None,
             TypeError::IngestedFileUnsupportedType(_, _) => None,
+            TypeError::TooManyTypeVariables { def, .. } => def.map(|(_, region)| region),
+            TypeError::InWhenBranch { problem, .. } => problem.region(),
         }
     }
 }
@@ -1,11 +1,11 @@
 #[cfg(feature = "gen-llvm")]
-use crate::helpers::llvm::assert_evals_to;
+use crate::helpers::llvm::{assert_evals_to, assert_optimized_evals_to};
 
 #[cfg(feature = "gen-dev")]
-use crate::helpers::dev::assert_evals_to;
+use crate::helpers::dev::{assert_evals_to, assert_optimized_evals_to};
 
 #[cfg(feature = "gen-wasm")]
-use crate::helpers::wasm::assert_evals_to;
+use crate::helpers::wasm::{assert_evals_to, assert_optimized_evals_to};
 
 use crate::helpers::with_larger_debug_stack;
 //use crate::assert_wasm_evals_to as assert_evals_to;
@@ -1017,12 +1017,90 @@ fn list_walk_with_str() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_walk_with_captures() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            words = ["a", "bb", "ccc"]
+            bonus = List.len words
+
+            List.walk words { count: 0, bytes: 0 } \state, word ->
+                { count: state.count + bonus, bytes: state.bytes + Str.countUtf8Bytes word }
+            "#
+        ),
+        (6, 9),
+        (u64, u64)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_walk_subtraction() {
     assert_evals_to!(r"List.walk [1, 2] 1 Num.sub", (1 - 1) - 2, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_walk_optimized() {
+    // optimized builds lower List.walk to a loop in the backend instead of calling the builtin
+    assert_optimized_evals_to!(r"List.walk [1, 2, 3] 0 Num.add", 6, i64);
+    assert_optimized_evals_to!(r"List.walk [1, 2] 1 Num.sub", (1 - 1) - 2, i64);
+    assert_optimized_evals_to!(r"List.walk [] 7 Num.add", 7, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_walk_with_captures_optimized() {
+    assert_optimized_evals_to!(
+        indoc!(
+            r#"
+            words = ["a", "bb", "ccc"]
+            bonus = List.len words
+
+            List.walk words { count: 0, bytes: 0 } \state, word ->
+                { count: state.count + bonus, bytes: state.bytes + Str.countUtf8Bytes word }
+            "#
+        ),
+        (6, 9),
+        (u64, u64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_map_keep_if_walk_fused() {
+    assert_optimized_evals_to!(
+        indoc!(
+            r"
+            [1, 2, 3, 4, 5]
+            |> List.map \x -> x * 10
+            |> List.keepIf \x -> x > 20
+            |> List.walk 0 Num.add
+            "
+        ),
+        120,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_map_map_fused() {
+    assert_optimized_evals_to!(
+        indoc!(
+            r"
+            [1, 2, 3]
+            |> List.map \x -> x + 1
+            |> List.map \x -> x * 2
+            "
+        ),
+        RocList::from_slice(&[4, 6, 8]),
+        RocList<i64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_walk_until_sum() {
@@ -2736,6 +2814,65 @@ fn list_literal_increment_decrement() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn static_list_literal_is_copied_on_update() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            makeList : {} -> List I64
+            makeList = \{} -> [1, 2, 3]
+
+            first = List.set (makeList {}) 0 10
+            second = List.set (makeList {}) 1 20
+
+            List.concat first second
+            "
+        ),
+        RocList::from_slice(&[10, 2, 3, 1, 20, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn static_str_list_literal() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            List.append ["short", "a string that is too long to be small"] "end"
+            "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from("short"),
+            RocStr::from("a string that is too long to be small"),
+            RocStr::from("end"),
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_map_chain() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            offset : I64
+            offset = 10
+
+            [1, 2, 3]
+            |> List.map (\x -> x * 2)
+            |> List.map (\x -> x + offset)
+            |> List.map Num.toStr
+            |> List.map Str.countUtf8Bytes
+            "
+        ),
+        RocList::from_slice(&[2u64, 2, 2]),
+        RocList<u64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_pass_to_function() {
@@ -3032,6 +3169,25 @@ fn list_sort_with() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_sort_with_order_by_keys() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Order] provides [main] to "./platform"
+
+            main =
+                [{ name: "b", age: 2 }, { name: "a", age: 2 }, { name: "c", age: 1 }]
+                |> List.sortWith (Order.byNum .age |> Order.thenBy (Order.byStr .name))
+                |> List.map .name
+            "#
+        ),
+        RocList::from_slice(&[RocStr::from("c"), RocStr::from("a"), RocStr::from("b")]),
+        RocList<RocStr>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_sort_asc() {
@@ -1214,6 +1214,46 @@ fn gen_rem_checked_div_by_zero_i64() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_div_floor_i64() {
+    assert_evals_to!("Num.divFloor 7 2", 3, i64);
+    assert_evals_to!("Num.divFloor -7 2", -4, i64);
+    assert_evals_to!("Num.divFloor 7 -2", -4, i64);
+    assert_evals_to!("Num.divFloor -8 2", -4, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_mod_i64() {
+    assert_evals_to!("Num.mod -8 3", 1, i64);
+    assert_evals_to!("Num.mod 8 -3", -1, i64);
+    assert_evals_to!("Num.mod -9 3", 0, i64);
+    assert_evals_to!("Num.mod 8u8 3", 2, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+#[should_panic(expected = r#"User crash with message: "Integer division by 0!"#)]
+fn gen_mod_div_by_zero_i64() {
+    assert_evals_to!("Num.mod 42 0", 100, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_div_floor_and_mod_checked_i64() {
+    assert_evals_to!(
+        "Num.divFloorChecked -7 2",
+        RocResult::ok(-4),
+        RocResult<i64, ()>
+    );
+    assert_evals_to!(
+        "Num.modChecked 8 0",
+        RocResult::err(()),
+        RocResult<i64, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_is_positive_i64() {
@@ -1782,6 +1822,46 @@ fn float_to_float() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_frac_f32_to_dec() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : Dec
+            x = Num.toFrac 1.5f32
+
+            x
+            "
+        ),
+        RocDec::from_str("1.5").unwrap(),
+        RocDec
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_frac_dec_to_f32() {
+    assert_evals_to!("Num.toF32 1.5dec", 1.5, f32);
+    assert_evals_to!("Num.toF32 -0.25dec", -0.25, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_frac_dec_to_f64() {
+    assert_evals_to!("Num.toF64 1.5dec", 1.5, f64);
+    assert_evals_to!("Num.toF64 -0.25dec", -0.25, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn frac_negate() {
+    assert_evals_to!("Num.neg 1.5f32", -1.5, f32);
+    assert_evals_to!("Num.neg -2.25f32", 2.25, f32);
+    assert_evals_to!("Num.neg 1.5f64", -1.5, f64);
+    assert_evals_to!("Num.neg -2.25f64", 2.25, f64);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn frac_is_nan() {
@@ -2067,6 +2147,20 @@ fn int_mul_wrap_i128() {
     assert_evals_to!("Num.mulWrap Num.maxI128 2", I128::from(-2), I128);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn int_add_wrap_i128() {
+    assert_evals_to!("Num.addWrap Num.maxI128 1", I128::from(i128::MIN), I128);
+    assert_evals_to!("Num.addWrap Num.maxU128 2", U128::from(1), U128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn int_sub_wrap_i128() {
+    assert_evals_to!("Num.subWrap Num.minI128 1", I128::from(i128::MAX), I128);
+    assert_evals_to!("Num.subWrap 0u128 1", U128::from(u128::MAX), U128);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn int_mul_checked() {
@@ -2192,6 +2286,58 @@ fn shift_right_cast_i8() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn shift_128_bit() {
+    assert_evals_to!("Num.shiftLeftBy 1u128 100", U128::from(1 << 100), U128);
+    assert_evals_to!("Num.shiftLeftBy 1i128 127", I128::from(i128::MIN), I128);
+    assert_evals_to!("Num.shiftLeftBy 1u128 128", U128::from(0), U128);
+    assert_evals_to!("Num.shiftRightBy -256i128 4", I128::from(-16), I128);
+    assert_evals_to!("Num.shiftRightBy Num.minI128 200", I128::from(-1), I128);
+    assert_evals_to!("Num.shiftRightBy Num.maxI128 200", I128::from(0), I128);
+    assert_evals_to!("Num.shiftRightZfBy -1i128 120", I128::from(0xff), I128);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn bitwise_128_bit() {
+    assert_evals_to!(
+        "Num.bitwiseAnd 0xffff_0000_0000_0000_0000_0000_0000_ff00u128 0x0ff0_0000_0000_0000_0000_0000_0000_0ff0u128",
+        U128::from(0x0ff0_0000_0000_0000_0000_0000_0000_0f00),
+        U128
+    );
+    assert_evals_to!(
+        "Num.bitwiseOr 0xffff_0000_0000_0000_0000_0000_0000_ff00u128 0x0ff0_0000_0000_0000_0000_0000_0000_0ff0u128",
+        U128::from(0xffff_0000_0000_0000_0000_0000_0000_fff0),
+        U128
+    );
+    assert_evals_to!(
+        "Num.bitwiseXor -1i128 0x1234_5678_9abc_def0_1234_5678_9abc_def0i128",
+        I128::from(!0x1234_5678_9abc_def0_1234_5678_9abc_def0),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn full_range_128_bit_literals() {
+    assert_evals_to!(
+        "Num.maxU128 == 340282366920938463463374607431768211455u128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "Num.minI128 == -170141183460469231731687303715884105728i128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "340282366920938463463374607431768211455f64",
+        u128::MAX as f64,
+        f64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn min_i128() {
@@ -3506,6 +3652,45 @@ fn when_on_u128() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn when_on_non_base_10_literals() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            f : I64 -> I64
+            f = \n ->
+                when n is
+                    0x7F -> 1
+                    -0x10 -> 2
+                    0o17 -> 3
+                    0b101 -> 4
+                    _ -> 0
+
+            f 127 + 10 * f -16 + 100 * f 15 + 1000 * f 5 + 10000 * f 16
+            "
+        ),
+        4321,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn when_on_u128_hex_literal() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            when Num.maxU128 is
+                0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF -> 42
+                _ -> 4
+            "
+        ),
+        42,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn condition_polymorphic_num_becomes_float() {
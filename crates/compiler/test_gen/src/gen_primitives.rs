@@ -1010,6 +1010,167 @@ fn a_crash() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn and_short_circuits() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            boom : {} -> Bool
+            boom = \{} -> crash "evaluated the right-hand side"
+
+            main = Bool.false && boom {}
+            "#
+        ),
+        false,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn or_short_circuits() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            boom : {} -> Bool
+            boom = \{} -> crash "evaluated the right-hand side"
+
+            main = Bool.true || boom {}
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+#[should_panic(expected = "User crash with message: \"evaluated the right-hand side\"")]
+fn and_evaluates_right_side_when_left_is_true() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            boom : {} -> Bool
+            boom = \{} -> crash "evaluated the right-hand side"
+
+            main = Bool.true && boom {}
+            "#
+        ),
+        false,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn if_target_is_wasm() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Target] provides [main] to "./platform"
+
+            main = if Target.isWasm then 1 else 2
+            "#
+        ),
+        if cfg!(any(feature = "gen-wasm", feature = "gen-llvm-wasm")) {
+            1
+        } else {
+            2
+        },
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn target_constant_as_value() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Target] provides [main] to "./platform"
+
+            main = [Target.isWasm, !Target.isWasm]
+            "#
+        ),
+        RocList::from_slice(&[
+            cfg!(any(feature = "gen-wasm", feature = "gen-llvm-wasm")),
+            !cfg!(any(feature = "gen-wasm", feature = "gen-llvm-wasm")),
+        ]),
+        RocList<bool>
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+#[should_panic(expected = "Roc failed with message: \"deep recursion in alternate\"")]
+fn deep_recursion_panics() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            alternate : I64 -> I64
+            alternate = \n ->
+                if n == 0 then
+                    0
+                else
+                    n - alternate (n - 1)
+
+            main = alternate 100_000_000
+            "#
+        ),
+        0,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn inline_expect_passes() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                x = 42i64
+                expect x == 42
+
+                x
+            "#
+        ),
+        42,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm", feature = "gen-dev"))]
+fn inline_expect_fails_and_continues() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                x = 42i64
+                expect x == 0
+
+                x
+            "#
+        ),
+        42,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 #[should_panic(expected = "Roc failed with message: ")]
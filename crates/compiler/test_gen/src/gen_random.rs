@@ -0,0 +1,86 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
+#[cfg(feature = "gen-wasm")]
+use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn step_is_deterministic() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Random] provides [main] to "./platform"
+
+            main =
+                { value } = Random.step Random.u64 (Random.seed 42)
+
+                value
+            "#
+        ),
+        13679457532755275413,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn between_stays_in_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Random] provides [main] to "./platform"
+
+            main =
+                Random.replay (Random.list (Random.between 6 1)) 7 100
+                |> List.all \roll -> roll >= 1 && roll <= 6
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn check_passes() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Random] provides [main] to "./platform"
+
+            main =
+                result = Random.check (Random.list Random.i64) \list ->
+                    List.reverse (List.reverse list) == list
+
+                result == Ok {}
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn check_shrinks_counterexample() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Random] provides [main] to "./platform"
+
+            main =
+                when Random.check (Random.list Random.u8) (\bytes -> List.len bytes < 5) is
+                    Ok {} -> 0
+                    Err { size } -> size
+            "#
+        ),
+        5,
+        u64
+    );
+}
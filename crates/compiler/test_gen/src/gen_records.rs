@@ -819,6 +819,41 @@ fn nested_record_load() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn nested_record_destructure_in_function_arg() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                f = \{ pos: { x, y }, scale } -> (x + y) * scale
+
+                f { pos: { x: 3, y: 4 }, scale: 2 }
+                "
+        ),
+        14,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn nested_record_destructure_with_optional_field_in_function_arg() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                app "test" provides [main] to "./platform"
+
+                f = \{ pos: { x, y ? 10 } } -> x + y
+
+                main =
+                    f { pos: { x: 3 } }
+                "#
+        ),
+        13,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn accessor_twice() {
@@ -1150,3 +1185,49 @@ fn pass_record_of_u8s() {
         bool
     )
 }
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn update_record_in_loop() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            State : { a : I64, b : I64, c : I64, d : I64 }
+
+            step : State, I64 -> State
+            step = \state, n ->
+                if n == 0 then
+                    state
+                else
+                    step { state & a: state.a + 1, d: state.d + state.a } (n - 1)
+
+            main =
+                initial = { a: 0, b: 1, c: 2, d: 0 }
+                final = step initial 10
+
+                (final.a, final.d, initial.a)
+            "#
+        ),
+        (10, 45, 0),
+        (i64, i64, i64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn update_record_still_in_use() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                rec = { w: 4, x: 1, y: 2, z: 3 }
+                updated = { rec & x: 10 }
+
+                updated.x + rec.x + updated.w
+                "
+        ),
+        15,
+        i64
+    );
+}
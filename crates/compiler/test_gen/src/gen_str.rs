@@ -13,7 +13,7 @@ use crate::helpers::dev::assert_evals_to as assert_llvm_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocList, RocResult, RocStr, I128, U128};
+use roc_std::{RocList, RocOrder, RocResult, RocStr, I128, U128};
 
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
@@ -905,6 +905,63 @@ fn str_to_utf8() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_scalars() {
+    assert_evals_to!(
+        r#"Str.toScalars "a鹏👩‍👦""#,
+        RocList::from_slice(&[97, 40527, 128105, 8205, 128102]),
+        RocList<u32>
+    );
+    assert_evals_to!(
+        r#"Str.toScalars """#,
+        RocList::<u32>::from_slice(&[]),
+        RocList<u32>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_graphemes_small_str() {
+    assert_evals_to!(
+        r#"Str.graphemes "e\u(301)!🇨🇦""#,
+        RocList::from_slice(&[
+            RocStr::from("e\u{301}"),
+            RocStr::from("!"),
+            RocStr::from("🇨🇦")
+        ]),
+        RocList<RocStr>
+    );
+    assert_evals_to!(
+        r#"Str.graphemes """#,
+        RocList::<RocStr>::from_slice(&[]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_graphemes_big_str() {
+    assert_evals_to!(
+        r#"Str.graphemes "👩‍👩‍👦‍👦\r\nசி👍🏽""#,
+        RocList::from_slice(&[
+            RocStr::from("👩‍👩‍👦‍👦"),
+            RocStr::from("\r\n"),
+            RocStr::from("சி"),
+            RocStr::from("👍🏽")
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_count_graphemes() {
+    assert_evals_to!(r#"Str.countGraphemes "Roc""#, 3, u64);
+    assert_evals_to!(r#"Str.countGraphemes """#, 0, u64);
+    assert_evals_to!(r#"Str.countGraphemes "👩‍👩‍👦‍👦 and 🇺🇸🇨🇦 and e\u(301)""#, 14, u64);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8() {
@@ -1988,3 +2045,11 @@ fn str_contains_self() {
         bool
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_compare() {
+    assert_evals_to!(r#"Str.compare "apple" "banana""#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare "apple" "app""#, RocOrder::Gt, RocOrder);
+    assert_evals_to!(r#"Str.compare "Roc" "Roc""#, RocOrder::Eq, RocOrder);
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
+#[cfg(feature = "gen-wasm")]
+use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+
+#[allow(unused_imports)]
+use roc_std::RocStr;
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn date_days_since_epoch() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Date] provides [main] to "./platform"
+
+            main =
+                when Date.fromCalendar 2024 2 29 is
+                    Ok date -> Date.toDaysSinceEpoch date
+                    Err InvalidDate -> 0
+            "#
+        ),
+        19782,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn date_add_months_clamps_day() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Date] provides [main] to "./platform"
+
+            main =
+                when Date.fromIsoStr "2024-01-31" is
+                    Ok date -> Date.addMonths date 13 |> Date.toIsoStr
+                    Err InvalidDateStr -> ""
+            "#
+        ),
+        RocStr::from("2025-02-28"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn time_to_iso_str() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Time] provides [main] to "./platform"
+
+            main =
+                Time.fromUnixMilliseconds 1_709_214_330_250 |> Time.toIsoStr
+            "#
+        ),
+        RocStr::from("2024-02-29T13:45:30.25Z"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn time_from_iso_str_with_offset() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Time] provides [main] to "./platform"
+
+            main =
+                when Time.fromIsoStr "2024-02-29T08:15:30-05:30" is
+                    Ok time -> Time.toUnixSeconds time |> Num.toI64
+                    Err InvalidTimeStr -> 0
+            "#
+        ),
+        1709214330,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn duration_iso_str_round_trip() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Duration] provides [main] to "./platform"
+
+            main =
+                when Duration.fromIsoStr "P1DT2H30M15.5S" is
+                    Ok duration -> Duration.toIsoStr duration
+                    Err InvalidDurationStr -> ""
+            "#
+        ),
+        RocStr::from("P1DT2H30M15.5S"),
+        RocStr
+    );
+}
@@ -36,6 +36,7 @@ pub fn helper(
     src: &str,
     _leak: bool,
     lazy_literals: bool,
+    optimize_ir: bool,
 ) -> (String, Vec<roc_problem::can::Problem>, Library) {
     use std::path::PathBuf;
 
@@ -62,6 +63,11 @@ pub fn helper(
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
         function_kind: FunctionKind::LambdaSet,
+        optimize_ir,
+        report_expects_with_dbg: true,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -325,6 +331,7 @@ pub(crate) fn asm_evals_to<T, U, F>(
     transform: F,
     leak: bool,
     lazy_literals: bool,
+    optimize_ir: bool,
 ) where
     U: PartialEq + std::fmt::Debug,
     F: FnOnce(T) -> U,
@@ -333,7 +340,7 @@ pub(crate) fn asm_evals_to<T, U, F>(
 
     let arena = Bump::new();
     let (_main_fn_name, errors, lib) =
-        crate::helpers::dev::helper(&arena, src, leak, lazy_literals);
+        crate::helpers::dev::helper(&arena, src, leak, lazy_literals, optimize_ir);
 
     let result = crate::helpers::dev::run_test_main::<T>(&lib);
 
@@ -393,9 +400,29 @@ macro_rules! assert_evals_to {
             $transform,
             $leak,
             $lazy_literals,
+            false,
+        );
+    };
+}
+
+/// Like `assert_evals_to!`, but with the mono IR optimizations of optimized builds, which
+/// rewrite some calls into lowlevels that unoptimized builds never produce.
+#[allow(unused_macros)]
+macro_rules! assert_optimized_evals_to {
+    ($src:expr, $expected:expr, $ty:ty) => {
+        $crate::helpers::dev::asm_evals_to::<$ty, _, _>(
+            $src,
+            $expected,
+            $crate::helpers::dev::identity,
+            true,
+            false,
+            true,
         );
     };
 }
 
 #[allow(unused_imports)]
 pub(crate) use assert_evals_to;
+
+#[allow(unused_imports)]
+pub(crate) use assert_optimized_evals_to;
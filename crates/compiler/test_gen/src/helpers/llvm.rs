@@ -76,6 +76,11 @@ fn create_llvm_module<'a>(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        optimize_ir: matches!(config.opt_level, OptLevel::Optimize | OptLevel::Size),
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -229,6 +234,8 @@ fn create_llvm_module<'a>(
         mode: config.mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        leak_check: false,
+        stack_check: true,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -542,6 +549,7 @@ pub(crate) fn llvm_evals_to<T, U, F>(
     transform: F,
     ignore_problems: bool,
     function_kind: FunctionKind,
+    opt_level: OptLevel,
 ) where
     U: PartialEq + std::fmt::Debug,
     F: FnOnce(T) -> U,
@@ -556,7 +564,7 @@ pub(crate) fn llvm_evals_to<T, U, F>(
         mode: LlvmBackendMode::GenTest,
         emit_debug_info: false,
         ignore_problems,
-        opt_level: crate::helpers::llvm::OPT_LEVEL,
+        opt_level,
     };
 
     let (main_fn_name, errors, lib) =
@@ -592,6 +600,7 @@ macro_rules! assert_llvm_evals_to {
             $transform,
             $ignore_problems,
             roc_load::FunctionKind::LambdaSet,
+            $crate::helpers::llvm::OPT_LEVEL,
         );
     };
 
@@ -656,6 +665,23 @@ macro_rules! assert_evals_to_erased {
             $crate::helpers::llvm::identity,
             false,
             roc_load::FunctionKind::Erased,
+            $crate::helpers::llvm::OPT_LEVEL,
+        );
+    }};
+}
+
+/// Like `assert_evals_to!`, but always an optimized build, so the mono IR optimizations run
+/// even in a debug build of the tests.
+#[allow(unused_macros)]
+macro_rules! assert_optimized_evals_to {
+    ($src:expr, $expected:expr, $ty:ty) => {{
+        crate::helpers::llvm::llvm_evals_to::<$ty, _, _>(
+            $src,
+            $expected,
+            $crate::helpers::llvm::identity,
+            false,
+            roc_load::FunctionKind::LambdaSet,
+            roc_mono::ir::OptLevel::Optimize,
         );
     }};
 }
@@ -667,5 +693,7 @@ pub fn identity<T>(value: T) -> T {
 pub(crate) use assert_evals_to;
 pub(crate) use assert_evals_to_erased;
 pub(crate) use assert_llvm_evals_to;
+#[allow(unused_imports)]
+pub(crate) use assert_optimized_evals_to;
 #[cfg(feature = "gen-llvm-wasm")]
 pub(crate) use assert_wasm_evals_to;
@@ -52,12 +52,18 @@ pub fn compile_to_wasm_bytes<'a, T: Wasm32Result>(
     arena: &'a bumpalo::Bump,
     src: &str,
     test_wrapper_type_info: PhantomData<T>,
+    optimize_ir: bool,
 ) -> Vec<u8> {
     let platform_bytes = include_bytes!(host_bytes_path!());
     println!("Loading test host {}", host_bytes_path!());
 
-    let compiled_bytes =
-        compile_roc_to_wasm_bytes(arena, platform_bytes, src, test_wrapper_type_info);
+    let compiled_bytes = compile_roc_to_wasm_bytes(
+        arena,
+        platform_bytes,
+        src,
+        test_wrapper_type_info,
+        optimize_ir,
+    );
 
     if write_final_wasm() {
         let build_dir_hash = crate::helpers::src_hash(src);
@@ -72,6 +78,7 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
     host_bytes: &[u8],
     src: &str,
     _test_wrapper_type_info: PhantomData<T>,
+    optimize_ir: bool,
 ) -> Vec<u8> {
     let filename = PathBuf::from("Test.roc");
     let src_dir = PathBuf::from("fake/test/path");
@@ -94,6 +101,11 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
         function_kind: FunctionKind::LambdaSet,
+        optimize_ir,
+        report_expects_with_dbg: true,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -175,13 +187,17 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
 }
 
 #[allow(dead_code)]
-pub fn assert_evals_to_help<T>(src: &str, phantom: PhantomData<T>) -> Result<T, String>
+pub fn assert_evals_to_help<T>(
+    src: &str,
+    phantom: PhantomData<T>,
+    optimize_ir: bool,
+) -> Result<T, String>
 where
     T: FromWasm32Memory + Wasm32Result,
 {
     let arena = bumpalo::Bump::new();
 
-    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom);
+    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom, optimize_ir);
 
     run_wasm_test_bytes::<T>(TEST_WRAPPER_NAME, wasm_bytes)
 }
@@ -264,7 +280,7 @@ where
 {
     let arena = bumpalo::Bump::new();
 
-    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom);
+    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom, false);
 
     let require_relocatable = false;
     let module = WasmModule::preload(&arena, &wasm_bytes, require_relocatable)
@@ -377,7 +393,7 @@ macro_rules! assert_evals_to {
     ($src:expr, $expected:expr, $ty:ty, $transform:expr, $ignore_problems: expr) => {{
         let phantom = std::marker::PhantomData;
         let _ = $ignore_problems; // Always ignore "problems"! One backend (LLVM) is enough to cover them.
-        match $crate::helpers::wasm::assert_evals_to_help::<$ty>($src, phantom) {
+        match $crate::helpers::wasm::assert_evals_to_help::<$ty>($src, phantom, false) {
             Err(msg) => panic!("{}", msg),
             Ok(actual) => {
                 assert_eq!($transform(actual), $expected)
@@ -386,6 +402,21 @@ macro_rules! assert_evals_to {
     }};
 }
 
+/// Like `assert_evals_to!`, but with the mono IR optimizations of optimized builds, which
+/// rewrite some calls into lowlevels that unoptimized builds never produce.
+#[allow(unused_macros)]
+macro_rules! assert_optimized_evals_to {
+    ($src:expr, $expected:expr, $ty:ty) => {{
+        let phantom = std::marker::PhantomData;
+        match $crate::helpers::wasm::assert_evals_to_help::<$ty>($src, phantom, true) {
+            Err(msg) => panic!("{}", msg),
+            Ok(actual) => {
+                assert_eq!(actual, $expected)
+            }
+        }
+    }};
+}
+
 #[allow(dead_code)]
 pub fn identity<T>(value: T) -> T {
     value
@@ -413,5 +444,8 @@ macro_rules! assert_refcounts {
 #[allow(unused_imports)]
 pub(crate) use assert_evals_to;
 
+#[allow(unused_imports)]
+pub(crate) use assert_optimized_evals_to;
+
 #[allow(unused_imports)]
 pub(crate) use assert_refcounts;
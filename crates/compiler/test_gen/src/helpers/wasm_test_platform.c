@@ -1,4 +1,5 @@
 #include <stdio.h>
+#include <stddef.h>
 #include <stdint.h>
 #include <stdlib.h>
 #include <string.h>
@@ -132,8 +133,40 @@ void roc_panic(void* msg, unsigned int panic_tag)
     exit(101);
 }
 
-// TODO: add a way to send dbg to rust.
-void roc_debug(void* loc, void* msg) {}
+struct RocStr
+{
+    char *bytes;
+    size_t length;
+    size_t capacity_or_alloc_ptr;
+};
+
+static void print_roc_str(struct RocStr *str)
+{
+    if ((ptrdiff_t)str->capacity_or_alloc_ptr < 0)
+    {
+        // A small string stores its bytes inline, and its length in the last byte
+        unsigned char *bytes = (unsigned char *)str;
+        size_t length = bytes[sizeof(struct RocStr) - 1] ^ 0x80;
+        fwrite(bytes, 1, length, stderr);
+    }
+    else
+    {
+        // The high bit of the length marks a seamless slice
+        size_t length = str->length & (SIZE_MAX >> 1);
+        fwrite(str->bytes, 1, length, stderr);
+    }
+}
+
+void roc_dbg(struct RocStr *loc, struct RocStr *msg, struct RocStr *src)
+{
+    fputc('[', stderr);
+    print_roc_str(loc);
+    fputs("] ", stderr);
+    print_roc_str(src);
+    fputs(" = ", stderr);
+    print_roc_str(msg);
+    fputc('\n', stderr);
+}
 
 //--------------------------
 
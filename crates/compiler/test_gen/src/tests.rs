@@ -13,12 +13,14 @@ pub mod gen_list;
 pub mod gen_num;
 pub mod gen_panic;
 pub mod gen_primitives;
+pub mod gen_random;
 pub mod gen_records;
 pub mod gen_refcount;
 pub mod gen_result;
 pub mod gen_set;
 pub mod gen_str;
 pub mod gen_tags;
+pub mod gen_time;
 pub mod gen_tuples;
 mod helpers;
 pub mod wasm_str;
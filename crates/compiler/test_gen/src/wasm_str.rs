@@ -207,6 +207,25 @@ fn str_split_small_str_20_char_delimiter() {
     );
 }
 
+#[test]
+fn str_graphemes_len() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                List.len (Str.graphemes "👩‍👩‍👦‍👦\r\nசி👍🏽")
+            "#
+        ),
+        4,
+        u64
+    );
+}
+
+#[test]
+fn str_count_graphemes() {
+    assert_evals_to!(r#"Str.countGraphemes "Roc""#, 3, u64);
+    assert_evals_to!(r#"Str.countGraphemes "e\u(301)🇺🇸🇨🇦""#, 3, u64);
+}
+
 #[test]
 fn str_concat_big_to_big() {
     assert_evals_to!(
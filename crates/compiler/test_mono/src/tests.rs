@@ -19,6 +19,7 @@ use roc_load::ExecutionMode;
 use roc_load::FunctionKind;
 use roc_load::LoadConfig;
 use roc_load::LoadMonomorphizedError;
+use roc_load::TestOptions;
 use roc_load::Threading;
 use roc_module::symbol::Interns;
 use roc_module::symbol::Symbol;
@@ -77,13 +78,46 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: bool, no_check: bool) {
+fn load_config(
+    exec_mode: ExecutionMode,
+    optimize_ir: bool,
+    report_expects_with_dbg: bool,
+) -> LoadConfig {
+    LoadConfig {
+        target: TARGET,
+        // TODO parameterize
+        function_kind: FunctionKind::LambdaSet,
+        threading: Threading::Single,
+        render: roc_reporting::report::RenderTarget::Generic,
+        palette: roc_reporting::report::DEFAULT_PALETTE,
+        exec_mode,
+        optimize_ir,
+        report_expects_with_dbg,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
+    }
+}
+
+fn compiles_to_ir(
+    test_name: &str,
+    src: &str,
+    mode: &str,
+    allow_type_errors: bool,
+    no_check: bool,
+    optimize: bool,
+    dbg_expects: bool,
+) {
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
     let exec_mode = match mode {
         "exec" => ExecutionMode::Executable,
-        "test" => ExecutionMode::Test,
+        "test" => ExecutionMode::Test(Default::default()),
+        "coverage" => ExecutionMode::Test(TestOptions {
+            coverage: true,
+            ..Default::default()
+        }),
         _ => panic!("Invalid test_mono exec mode {mode}"),
     };
 
@@ -103,22 +137,13 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
         module_src = &temp;
     }
 
-    let load_config = LoadConfig {
-        target: TARGET,
-        // TODO parameterize
-        function_kind: FunctionKind::LambdaSet,
-        threading: Threading::Single,
-        render: roc_reporting::report::RenderTarget::Generic,
-        palette: roc_reporting::report::DEFAULT_PALETTE,
-        exec_mode,
-    };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
         filename,
         module_src,
         src_dir,
         RocCacheDir::Disallowed,
-        load_config,
+        load_config(exec_mode, optimize, dbg_expects),
     );
 
     let mut loaded = match loaded {
@@ -3534,3 +3559,235 @@ fn issue_6606_2() {
         "
     )
 }
+
+#[mono_test]
+fn if_target_is_wasm_drops_the_other_branch() {
+    indoc!(
+        r#"
+        app "test" imports [Target] provides [main] to "./platform"
+
+        double = \n -> n * 2
+
+        main =
+            if Target.isWasm then
+                double 21
+            else if !Target.isLinux then
+                double 20
+            else
+                42
+        "#
+    )
+}
+
+#[test]
+fn runaway_specialization_is_reported() {
+    use roc_packaging::cache::RocCacheDir;
+    use std::path::PathBuf;
+
+    // An ability member is polymorphic, so an implementation can call it on a bigger type than its
+    // own without polymorphic recursion, and every specialization then needs another one.
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Size implements
+            size : val -> U64 where val implements Size
+
+        Leaf := {} implements [Size { size: leafSize }]
+
+        leafSize : Leaf -> U64
+        leafSize = \_ -> 1
+
+        Nest a := List a implements [Size { size: nestSize }]
+
+        nestSize : Nest a -> U64 where a implements Size
+        nestSize = \@Nest items -> size (@Nest [@Nest items])
+
+        main = size (@Nest [@Leaf {}])
+        "#
+    );
+
+    let arena = Bump::new();
+    let loaded = roc_load::load_and_monomorphize_from_str(
+        &arena,
+        PathBuf::from("Test.roc"),
+        src,
+        PathBuf::from("fake/test/path"),
+        RocCacheDir::Disallowed,
+        load_config(ExecutionMode::Executable, false, false),
+    );
+
+    match loaded {
+        Err(LoadMonomorphizedError::LoadingProblem(roc_load::LoadingProblem::FormattedReport(
+            report,
+        ))) => assert_eq!(
+            report,
+            indoc!(
+                r#"
+                ── RUNAWAY SPECIALIZATION in Test.roc ──────────────────────────────────────────
+
+                I can't generate code for `nestSize` because every time around this
+                chain of calls, it needs to be compiled again for a new type:
+
+                    ┌─────┐
+                    │     nestSize
+                    │     ↓
+                    │     nestSize
+                    └─────┘
+
+                This usually means a function calls itself with an argument whose type
+                contains the type of its own argument, like a list of it. Can you
+                change the function so that the type of the argument stays the same?"#
+            )
+        ),
+        Err(e) => panic!("{e:?}"),
+        Ok(_) => panic!("expected specialization to be reported as runaway"),
+    }
+}
+
+#[mono_test]
+fn and_or_short_circuit() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        isSmall = \n -> n < 10
+
+        main = (isSmall 3 && isSmall 4) || isSmall 5
+        "#
+    )
+}
+
+#[mono_test(optimize = "true")]
+fn dead_code_after_constant_folding() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        square = \n -> n * n
+
+        answer = square 6 + square 8
+
+        main = answer + 1
+        "#
+    )
+}
+
+#[mono_test]
+fn unreachable_def_is_not_specialized() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        double = \n -> n * 2
+
+        unused = \n -> double n + List.len [n]
+
+        unusedConstant = Str.concat "never" " used"
+
+        main = double 21
+        "#
+    )
+}
+
+#[mono_test(optimize = "true")]
+fn dead_code_keeps_getters() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        offset = 2 * 21
+
+        main = \n -> { add: \x -> x + n + offset, n }
+        "#
+    )
+}
+
+#[mono_test(optimize = "true")]
+fn list_map_map_fusion() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = \xs, k ->
+            xs
+            |> List.map (\n -> n + k)
+            |> List.map (\n -> n * 2)
+        "#
+    )
+}
+
+#[mono_test(optimize = "true")]
+fn list_map_keep_if_walk_fusion() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = \xs, limit ->
+            xs
+            |> List.map (\n -> n * 2)
+            |> List.keepIf (\n -> n > limit)
+            |> List.walk 0 Num.add
+        "#
+    )
+}
+
+#[mono_test(optimize = "true")]
+fn list_map_used_twice_is_not_fused() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = \xs ->
+            doubled = List.map xs (\n -> n * 2)
+
+            List.walk doubled (List.len doubled) Num.addWrap
+        "#
+    )
+}
+
+#[mono_test(mode = "test", optimize = "true")]
+fn dead_code_keeps_expects() {
+    indoc!(
+        r#"
+        interface Test exposes [] imports []
+
+        square = \n -> n * n
+
+        nine = square 3
+
+        expect nine == 9
+        "#
+    )
+}
+
+#[mono_test(mode = "coverage")]
+fn coverage_counts_branches_and_functions() {
+    indoc!(
+        r#"
+        interface Test exposes [] imports []
+
+        describe = \n ->
+            when n is
+                0 -> "zero"
+                _ -> "many"
+
+        expect describe 0 == "zero"
+        "#
+    )
+}
+
+#[mono_test(dbg_expects = "true")]
+fn inline_expect_reported_with_dbg() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            x = 41
+            y = 1
+            expect (\n -> n + y == x) 42
+            x + 1
+        "#
+    )
+}
@@ -10,6 +10,8 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
     let mut allow_type_errors = false;
     let mut mode = "exec".to_owned();
     let mut large_stack = false;
+    let mut optimize = false;
+    let mut dbg_expects = false;
     for arg in syn::parse_macro_input!(args as syn::AttributeArgs) {
         use syn::{Lit, Meta, MetaNameValue, NestedMeta};
         if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
@@ -30,6 +32,12 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
             if path.is_ident("large_stack") {
                 large_stack = true;
             }
+            if path.is_ident("optimize") {
+                optimize = true;
+            }
+            if path.is_ident("dbg_expects") {
+                dbg_expects = true;
+            }
         }
     }
 
@@ -49,9 +57,9 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
         #(#attributes)*
         #visibility fn #name(#args) {
             if #large_stack {
-                with_larger_debug_stack(|| compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check));
+                with_larger_debug_stack(|| compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check, #optimize, #dbg_expects));
             } else {
-                compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check);
+                compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check, #optimize, #dbg_expects);
             }
         }
     };
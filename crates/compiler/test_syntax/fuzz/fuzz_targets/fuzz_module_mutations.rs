@@ -0,0 +1,112 @@
+#![no_main]
+use bumpalo::Bump;
+use libfuzzer_sys::{fuzz_mutator, fuzz_target, fuzzer_mutate};
+use test_syntax::test_helpers::Input;
+
+// Meant to be run over a corpus of real Roc files (see the README). Most of the formatter's bugs
+// are about comments and newlines in unusual places, which byte-level mutations rarely produce
+// in code that still parses, so the mutations here move trivia around line by line.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let input = Input::Full(input);
+        let arena = Bump::new();
+        if input.parse_in(&arena).is_ok() {
+            input.check_invariants(|_| (), true);
+        }
+    }
+});
+
+fuzz_mutator!(|data: &mut [u8], size: usize, max_size: usize, seed: u32| {
+    let mut rng = Rng(seed | 1);
+
+    // Leave some of the mutating to libFuzzer, which knows which inputs were interesting.
+    if rng.below(4) == 0 {
+        return fuzzer_mutate(data, size, max_size);
+    }
+
+    let src = match std::str::from_utf8(&data[..size]) {
+        Ok(src) => src,
+        Err(_) => return fuzzer_mutate(data, size, max_size),
+    };
+
+    let mutated = mutate(src, &mut rng);
+
+    if mutated.len() > max_size {
+        return fuzzer_mutate(data, size, max_size);
+    }
+
+    data[..mutated.len()].copy_from_slice(mutated.as_bytes());
+
+    mutated.len()
+});
+
+/// Applies one small change to the lines of the source.
+fn mutate(src: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<String> = src.lines().map(String::from).collect();
+
+    if lines.is_empty() {
+        return "# comment\n".to_string();
+    }
+
+    let index = rng.below(lines.len());
+    let line = &lines[index];
+    let indentation = line.len() - line.trim_start().len();
+
+    match rng.below(8) {
+        // A comment at the end of a line
+        0 => lines[index].push_str(" # comment"),
+        // A comment on its own line, indented like the next one
+        1 => lines.insert(index, format!("{}# comment", " ".repeat(indentation))),
+        // A blank line
+        2 => lines.insert(index, String::new()),
+        // Joining a line with the next one
+        3 if index + 1 < lines.len() => {
+            let next = lines.remove(index + 1);
+            lines[index].push(' ');
+            lines[index].push_str(next.trim_start());
+        }
+        // Breaking a line after one of its spaces
+        4 => {
+            let spaces: Vec<usize> = line
+                .match_indices(' ')
+                .map(|(offset, _)| offset)
+                .filter(|offset| *offset > indentation)
+                .collect();
+
+            if !spaces.is_empty() {
+                let offset = spaces[rng.below(spaces.len())];
+                let rest = lines[index].split_off(offset + 1);
+                lines.insert(index + 1, format!("{}{rest}", " ".repeat(indentation + 4)));
+            }
+        }
+        // Indenting a line by one more level
+        5 => lines[index].insert_str(0, "    "),
+        // Outdenting a line by one level
+        6 if indentation >= 4 => {
+            lines[index].drain(..4);
+        }
+        // Dropping a line
+        _ => {
+            lines.remove(index);
+        }
+    }
+
+    let mut mutated = lines.join("\n");
+    mutated.push('\n');
+
+    mutated
+}
+
+/// A xorshift generator, since all a mutation needs is a few cheap choices that are the same for
+/// the same seed.
+struct Rng(u32);
+
+impl Rng {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+
+        self.0 as usize % bound
+    }
+}
@@ -172,7 +172,7 @@ impl<'a> Input<'a> {
 
                 let (header, defs) = header.upgrade_header_imports(arena);
 
-                let module_defs = parse_module_defs(arena, state, defs).unwrap();
+                let module_defs = parse_module_defs(arena, state, defs)?;
 
                 Ok(Output::Full {
                     header,
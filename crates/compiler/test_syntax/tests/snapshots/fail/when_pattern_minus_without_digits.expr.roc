@@ -0,0 +1,2 @@
+when x is
+    - -> 1
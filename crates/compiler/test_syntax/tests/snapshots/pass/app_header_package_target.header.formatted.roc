@@ -0,0 +1 @@
+app [main] { pf: platform "native/main.roc", pf: platform "web/main.roc" if wasm32 }
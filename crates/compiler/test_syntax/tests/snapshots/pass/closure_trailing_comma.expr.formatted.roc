@@ -0,0 +1 @@
+\a, b -> 42
\ No newline at end of file
@@ -1,7 +1,3 @@
-table :
-    {
-        height : Pixels,
-    }
-    -> Table
+table : { height : Pixels } -> Table
 table = \{ height } -> crash "not implemented"
 table
\ No newline at end of file
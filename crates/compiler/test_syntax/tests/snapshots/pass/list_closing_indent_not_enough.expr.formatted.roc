@@ -1,9 +1,2 @@
-myList = [
-    0,
-    [
-        a,
-        b,
-    ],
-    1,
-]
+myList = [0, [a, b], 1]
 42
\ No newline at end of file
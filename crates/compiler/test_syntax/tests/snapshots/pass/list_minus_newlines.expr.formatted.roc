@@ -1,4 +1 @@
-[
-    K,
-]
-- i
\ No newline at end of file
+[K] - i
\ No newline at end of file
@@ -1,3 +1 @@
-[
-    1,
-]
\ No newline at end of file
+[1]
\ No newline at end of file
@@ -1,6 +1,2 @@
-a = [
-    1,
-    2,
-    3,
-]
+a = [1, 2, 3]
 a
\ No newline at end of file
@@ -1,4 +1,2 @@
-x = foo {
-    bar: blah,
-}
+x = foo { bar: blah }
 x
\ No newline at end of file
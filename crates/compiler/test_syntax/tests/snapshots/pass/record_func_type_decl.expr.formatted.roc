@@ -1,8 +1,4 @@
-f : {
-    getLine : Effect Str,
-    putLine : Str -> Effect Int,
-    text : Str,
-    value : Int *,
-}
+f :
+    { getLine : Effect Str, putLine : Str -> Effect Int, text : Str, value : Int * }
 
 42
\ No newline at end of file
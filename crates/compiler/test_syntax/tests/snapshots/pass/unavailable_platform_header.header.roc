@@ -0,0 +1 @@
+platform "rtfeldman/blah" requires {} { main : {} } exposes [] packages {} imports [] provides [] unavailable [Dict, Set]
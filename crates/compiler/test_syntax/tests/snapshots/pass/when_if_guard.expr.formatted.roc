@@ -0,0 +1,4 @@
+when x is
+    _ -> 1
+    _ -> 2
+    Ok -> 3
\ No newline at end of file
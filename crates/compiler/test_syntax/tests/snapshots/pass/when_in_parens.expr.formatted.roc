@@ -1,3 +1,2 @@
 when x is
-    Ok ->
-        3
\ No newline at end of file
+    Ok -> 3
\ No newline at end of file
@@ -147,6 +147,11 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn crlf_line_endings() {
+        expr_formats_to("# comment\r\nx = 1\r\n\r\nx", "# comment\nx = 1\n\nx");
+    }
+
     #[test]
     fn comment_with_trailing_space() {
         expr_formats_to(
@@ -1463,19 +1468,28 @@ mod test_fmt {
 
     #[test]
     fn record_field_destructuring() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 when foo is
                     { x: 5 } ->
                         42
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                when foo is
+                    { x: 5 } -> 42
+                "
+            ),
+        );
     }
 
     #[test]
     fn lambda_returns_record() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 toRecord = \_ -> {
                     x: 1,
                     y: 2,
@@ -1483,8 +1497,16 @@ mod test_fmt {
                 }
 
                 toRecord
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                toRecord = \_ -> { x: 1, y: 2, z: 3 }
+
+                toRecord
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -1495,8 +1517,9 @@ mod test_fmt {
                 "
         ));
 
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 toRecord = \_ ->
                     val = 0
 
@@ -1507,8 +1530,19 @@ mod test_fmt {
                     }
 
                 toRecord
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                toRecord = \_ ->
+                    val = 0
+
+                    { x: 1, y: 2, z: 3 }
+
+                toRecord
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1525,11 +1559,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    toRecord = \_ -> {
-                        x: 1,
-                        y: 2,
-                        z: 3,
-                    }
+                    toRecord = \_ ->
+                        { x: 1, y: 2, z: 3 }
 
                     toRecord
                 "
@@ -1539,8 +1570,9 @@ mod test_fmt {
 
     #[test]
     fn lambda_returns_list() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 toList = \_ -> [
                     1,
                     2,
@@ -1548,8 +1580,16 @@ mod test_fmt {
                 ]
 
                 toList
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                toList = \_ -> [1, 2, 3]
+
+                toList
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -1560,8 +1600,9 @@ mod test_fmt {
                 "
         ));
 
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 toList = \_ ->
                     val = 0
 
@@ -1572,8 +1613,19 @@ mod test_fmt {
                     ]
 
                 toList
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                toList = \_ ->
+                    val = 0
+
+                    [1, 2, 3]
+
+                toList
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1590,11 +1642,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    toList = \_ -> [
-                        1,
-                        2,
-                        3,
-                    ]
+                    toList = \_ ->
+                        [1, 2, 3]
 
                     toList
                 "
@@ -1604,17 +1653,26 @@ mod test_fmt {
 
     #[test]
     fn multiline_list_func_arg() {
-        expr_formats_same(indoc!(
-            r"
-                    result = func arg [
-                        1,
-                        2,
-                        3,
-                    ]
+        expr_formats_to(
+            indoc!(
+                r"
+                result = func arg [
+                    1,
+                    2,
+                    3,
+                ]
 
-                    result
+                result
                 "
-        ));
+            ),
+            indoc!(
+                r"
+                result = func arg [1, 2, 3]
+
+                result
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1650,11 +1708,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    result = func arg [
-                        1,
-                        2,
-                        3,
-                    ]
+                    result = func arg [1, 2, 3]
 
                     result
                 "
@@ -1677,11 +1731,7 @@ mod test_fmt {
             indoc!(
                 r"
                     result = func
-                        [
-                            1,
-                            2,
-                            3,
-                        ]
+                        [1, 2, 3]
                         arg
 
                     result
@@ -1705,36 +1755,46 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    result = func arg [
+                    result = func
+                        arg
+                        [1, 2, 3]
+
+                    result
+                "
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                result = func
+                    arg
+                    [
                         1,
                         2,
                         3,
                     ]
 
-                    result
+                result
                 "
             ),
-        );
-
-        expr_formats_same(indoc!(
-            r"
-                    result = func
-                        arg
-                        [
-                            1,
-                            2,
-                            3,
-                        ]
+            indoc!(
+                r"
+                result = func
+                    arg
+                    [1, 2, 3]
 
-                    result
+                result
                 "
-        ));
+            ),
+        );
     }
 
     #[test]
     fn multiline_record_func_arg() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 result = func arg {
                     x: 1,
                     y: 2,
@@ -1742,8 +1802,16 @@ mod test_fmt {
                 }
 
                 result
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                result = func arg { x: 1, y: 2, z: 3 }
+
+                result
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1779,11 +1847,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    result = func arg {
-                        x: 1,
-                        y: 2,
-                        z: 3,
-                    }
+                    result = func arg { x: 1, y: 2, z: 3 }
 
                     result
                 "
@@ -1806,11 +1870,7 @@ mod test_fmt {
             indoc!(
                 r"
                     result = func
-                        {
-                            x: 1,
-                            y: 2,
-                            z: 3,
-                        }
+                        { x: 1, y: 2, z: 3 }
                         arg
 
                     result
@@ -1834,30 +1894,39 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    result = func arg {
+                    result = func
+                        arg
+                        { x: 1, y: 2, z: 3 }
+
+                    result
+                "
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                result = func
+                    arg
+                    {
                         x: 1,
                         y: 2,
                         z: 3,
                     }
 
-                    result
+                result
                 "
             ),
-        );
-
-        expr_formats_same(indoc!(
-            r"
-                    result = func
-                        arg
-                        {
-                            x: 1,
-                            y: 2,
-                            z: 3,
-                        }
+            indoc!(
+                r"
+                result = func
+                    arg
+                    { x: 1, y: 2, z: 3 }
 
-                    result
+                result
                 "
-        ));
+            ),
+        );
     }
 
     #[test]
@@ -1894,14 +1963,21 @@ mod test_fmt {
             ),
         );
 
-        expr_formats_same(indoc!(
-            r"
-            { shoes &
-                rightShoe: newRightShoe,
-                leftShoe: newLeftShoe,
-            }
-            "
-        ));
+        expr_formats_to(
+            indoc!(
+                r"
+                { shoes &
+                    rightShoe: newRightShoe,
+                    leftShoe: newLeftShoe,
+                }
+                "
+            ),
+            indoc!(
+                r"
+                { shoes & rightShoe: newRightShoe, leftShoe: newLeftShoe }
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1913,10 +1989,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                { shoes &
-                    rightShoe: bareFoot,
-                    leftShoe: bareFoot,
-                }
+                { shoes & rightShoe: bareFoot, leftShoe: bareFoot }
                 "
             ),
         );
@@ -1943,16 +2016,23 @@ mod test_fmt {
             ),
         );
 
-        expr_formats_same(indoc!(
-            r#"
-            {
-                a: 1,
-                b: <- get "b" |> batch,
-                c: <- get "c" |> batch,
-                d,
-            }
-            "#
-        ));
+        expr_formats_to(
+            indoc!(
+                r#"
+                {
+                    a: 1,
+                    b: <- get "b" |> batch,
+                    c: <- get "c" |> batch,
+                    d,
+                }
+                "#
+            ),
+            indoc!(
+                r#"
+                { a: 1, b: <- get "b" |> batch, c: <- get "c" |> batch, d }
+                "#
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -1963,12 +2043,7 @@ mod test_fmt {
             ),
             indoc!(
                 r#"
-                {
-                    a: 1,
-                    b: <- get "b" |> batch,
-                    c: <- get "c" |> batch,
-                    d,
-                }
+                { a: 1, b: <- get "b" |> batch, c: <- get "c" |> batch, d }
                 "#
             ),
         );
@@ -2038,10 +2113,7 @@ mod test_fmt {
             ),
             indoc!(
                 r#"
-                succeed {
-                    a: <- get "a" |> batch,
-                    b: <- get "b" |> batch,
-                }
+                succeed { a: <- get "a" |> batch, b: <- get "b" |> batch }
                 "#
             ),
         );
@@ -2058,10 +2130,8 @@ mod test_fmt {
             ),
             indoc!(
                 r#"
-                succeed {
-                    a: <- get "a" |> batch,
-                    b: <- get "b" |> batch,
-                }
+                succeed
+                    { a: <- get "a" |> batch, b: <- get "b" |> batch }
                 "#
             ),
         );
@@ -2222,10 +2292,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                f : {
-                    y : Int *,
-                    x : Int *,
-                }
+                f : { y : Int *, x : Int * }
 
                 f"
             ),
@@ -2234,16 +2301,25 @@ mod test_fmt {
 
     #[test]
     fn trailing_comma_in_record_annotation_same() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 f : {
                     y : Int *,
                     x : Int *,
                 }
 
                 f
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                f : { y : Int *, x : Int * }
+
+                f
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -2259,10 +2335,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    f : {
-                        y : Int *,
-                        x : Int *,
-                    }
+                    f :
+                        { y : Int *, x : Int * }
 
                     f
                 "
@@ -2292,15 +2366,25 @@ mod test_fmt {
             "
         ));
 
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 f :
                     {
                     }
 
                 f
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                f :
+                    {}
+
+                f
+                "
+            ),
+        );
     }
 
     #[test]
@@ -2352,9 +2436,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                f : {
-                    a ? Str,
-                }
+                f : { a ? Str }
 
                 f"
             ),
@@ -2386,15 +2468,24 @@ mod test_fmt {
 
     #[test]
     fn multiline_curly_brace_type() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 x : {
                     a : Int,
                 }
 
                 x
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                x : { a : Int }
+
+                x
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -2408,15 +2499,24 @@ mod test_fmt {
 
     #[test]
     fn multiline_brace_type() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 x : [
                     Int,
                 ]
 
                 x
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                x : [Int]
+
+                x
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -2518,6 +2618,51 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn trailing_comma_before_arrow() {
+        expr_formats_to(
+            indoc!(
+                r"
+                    foo : Str, U64, -> Bool
+                    foo = \a, b, -> Bool.true
+
+                    foo
+                "
+            ),
+            indoc!(
+                r"
+                    foo : Str, U64 -> Bool
+                    foo = \a, b -> Bool.true
+
+                    foo
+                "
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                    foo :
+                        Str,
+                        U64,
+                        -> Bool
+
+                    foo
+                "
+            ),
+            indoc!(
+                r"
+                    foo :
+                        Str,
+                        U64
+                        -> Bool
+
+                    foo
+                "
+            ),
+        );
+    }
+
     #[test]
     fn final_comment_record_annotation() {
         expr_formats_to(
@@ -2679,17 +2824,117 @@ mod test_fmt {
     }
 
     #[test]
-    fn multi_line_list() {
+    fn collections_expand_past_line_width() {
+        expr_formats_to(
+            indoc!(
+                r#"
+                names = ["Ada Lovelace", "Grace Hopper", "Barbara Liskov", "Frances Allen", "Margaret Hamilton", "Radia Perlman"]
+
+                names
+                "#
+            ),
+            indoc!(
+                r#"
+                names = [
+                    "Ada Lovelace",
+                    "Grace Hopper",
+                    "Barbara Liskov",
+                    "Frances Allen",
+                    "Margaret Hamilton",
+                    "Radia Perlman",
+                ]
+
+                names
+                "#
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                config = { name: "breakout", width: 800, height: 600, background: Color.black, title: "Breakout in Roc!" }
+
+                config
+                "#
+            ),
+            indoc!(
+                r#"
+                config = {
+                    name: "breakout",
+                    width: 800,
+                    height: 600,
+                    background: Color.black,
+                    title: "Breakout in Roc!",
+                }
+
+                config
+                "#
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                Event : [KeyDown Key, KeyUp Key, MouseMoved I32 I32, MouseClicked Button, Resized U32 U32, Tick U64, Quit]
+
+                Event
+                "
+            ),
+            indoc!(
+                r"
+                Event : [
+                    KeyDown Key,
+                    KeyUp Key,
+                    MouseMoved I32 I32,
+                    MouseClicked Button,
+                    Resized U32 U32,
+                    Tick U64,
+                    Quit,
+                ]
+
+                Event
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn collections_with_comments_stay_multiline() {
         expr_formats_same(indoc!(
             r"
             [
-                7,
-                8,
-                9,
+                # the answer
+                42,
             ]
             "
         ));
 
+        expr_formats_same(indoc!(
+            r"
+            { x: 1, y: 2 } # no comments inside, so this fits on one line
+            "
+        ));
+    }
+
+    #[test]
+    fn multi_line_list() {
+        expr_formats_to(
+            indoc!(
+                r"
+                [
+                    7,
+                    8,
+                    9,
+                ]
+                "
+            ),
+            indoc!(
+                r"
+                [7, 8, 9]
+                "
+            ),
+        );
+
         expr_formats_to(
             indoc!(
                 r"
@@ -2701,11 +2946,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                [
-                    17,
-                    18,
-                    19,
-                ]
+                [17, 18, 19]
                 "
             ),
         );
@@ -2724,11 +2965,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                [
-                    27,
-                    28,
-                    29,
-                ]
+                [27, 28, 29]
                 "
             ),
         );
@@ -2744,11 +2981,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                [
-                    157,
-                    158,
-                    159,
-                ]
+                [157, 158, 159]
                 "
             ),
         );
@@ -2764,12 +2997,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                [
-                    557,
-                    648,
-                    759,
-                    837,
-                ]
+                [557, 648, 759, 837]
                 "
             ),
         );
@@ -2888,16 +3116,25 @@ mod test_fmt {
     }
     #[test]
     fn multi_line_list_def() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 l = [
                     1,
                     2,
                 ]
 
                 l
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                l = [1, 2]
+
+                l
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -2922,10 +3159,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                l = [
-                    1,
-                    2,
-                ]
+                l =
+                    [1, 2]
 
                 l
                 "
@@ -2945,10 +3180,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                results = [
-                    Ok 4,
-                    Ok 5,
-                ]
+                results = [Ok 4, Ok 5]
 
                 allOks results
                 "
@@ -2972,10 +3204,7 @@ mod test_fmt {
                 r"
                     results =
                         # Let's count past 6
-                        [
-                            Ok 6,
-                            Err CountError,
-                        ]
+                        [Ok 6, Err CountError]
 
                     allOks results
                 "
@@ -3080,20 +3309,28 @@ mod test_fmt {
 
     #[test]
     fn two_fields_newline() {
-        expr_formats_same(indoc!(
-            r"
-            {
-                x: 4,
-                y: 42,
-            }
-            "
-        ));
+        expr_formats_to(
+            indoc!(
+                r"
+                {
+                    x: 4,
+                    y: 42,
+                }
+                "
+            ),
+            indoc!(
+                r"
+                { x: 4, y: 42 }
+                "
+            ),
+        );
     }
 
     #[test]
     fn multi_line_record_def() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 pos = {
                     x: 4,
                     y: 11,
@@ -3101,8 +3338,16 @@ mod test_fmt {
                 }
 
                 pos
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                pos = { x: 4, y: 11, z: 16 }
+
+                pos
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -3113,8 +3358,9 @@ mod test_fmt {
             "
         ));
 
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 myDef =
                     list = [
                         a,
@@ -3127,8 +3373,19 @@ mod test_fmt {
                     }
 
                 myDef
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                myDef =
+                    list = [a, b]
+
+                    { c, d }
+
+                myDef
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -3145,11 +3402,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                    pos = {
-                        x: 4,
-                        y: 11,
-                        z: 16,
-                    }
+                    pos =
+                        { x: 4, y: 11, z: 16 }
 
                     pos
                 "
@@ -3169,10 +3423,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                pos = {
-                    x: 5,
-                    y: 10,
-                }
+                pos = { x: 5, y: 10 }
 
                 pos
                 "
@@ -3192,10 +3443,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                {
-                    x: 4,
-                    y: 42,
-                }
+                { x: 4, y: 42 }
                 "
             ),
         );
@@ -3524,16 +3772,25 @@ mod test_fmt {
 
     #[test]
     fn integer_when() {
-        expr_formats_same(indoc!(
-            r"
-            when b is
-                1 ->
-                    1
+        expr_formats_to(
+            indoc!(
+                r"
+                when b is
+                    1 ->
+                        1
 
-                _ ->
-                    2
-            "
-        ));
+                    _ ->
+                        2
+                "
+            ),
+            indoc!(
+                r"
+                when b is
+                    1 -> 1
+                    _ -> 2
+                "
+            ),
+        );
     }
 
     #[test]
@@ -3557,11 +3814,8 @@ mod test_fmt {
             indoc!(
                 r"
                 when year is
-                    1999 ->
-                        1
-
-                    _ ->
-                        0
+                    1999 -> 1
+                    _ -> 0
                 "
             ),
         );
@@ -3602,34 +3856,59 @@ mod test_fmt {
 
     #[test]
     fn nested_when() {
-        expr_formats_same(indoc!(
-            r"
-            when b is
-                _ ->
-                    when c is
-                        _ ->
-                            1
-            "
-        ));
+        expr_formats_to(
+            indoc!(
+                r"
+                when b is
+                    _ ->
+                        when c is
+                            _ ->
+                                1
+                "
+            ),
+            indoc!(
+                r"
+                when b is
+                    _ ->
+                        when c is
+                            _ -> 1
+                "
+            ),
+        );
     }
 
     #[test]
     fn def_when() {
-        expr_formats_same(indoc!(
-            r"
-            myLongFunctionName = \x ->
-                when b is
-                    1 | 2 ->
-                        when c is
-                            6 | 7 ->
-                                8
+        expr_formats_to(
+            indoc!(
+                r"
+                myLongFunctionName = \x ->
+                    when b is
+                        1 | 2 ->
+                            when c is
+                                6 | 7 ->
+                                    8
 
-                    3 | 4 ->
-                        5
+                        3 | 4 ->
+                            5
 
-            123
-        "
-        ));
+                123
+                "
+            ),
+            indoc!(
+                r"
+                myLongFunctionName = \x ->
+                    when b is
+                        1 | 2 ->
+                            when c is
+                                6 | 7 -> 8
+
+                        3 | 4 -> 5
+
+                123
+                "
+            ),
+        );
     }
 
     #[test]
@@ -3674,18 +3953,30 @@ mod test_fmt {
 
     #[test]
     fn when_with_alternatives_1() {
-        expr_formats_same(indoc!(
-            r"
-            when b is
-                1 | 2 ->
-                    when c is
-                        6 | 7 ->
-                            8
+        expr_formats_to(
+            indoc!(
+                r"
+                when b is
+                    1 | 2 ->
+                        when c is
+                            6 | 7 ->
+                                8
 
-                3 | 4 ->
-                    5
-        "
-        ));
+                    3 | 4 ->
+                        5
+                "
+            ),
+            indoc!(
+                r"
+                when b is
+                    1 | 2 ->
+                        when c is
+                            6 | 7 -> 8
+
+                    3 | 4 -> 5
+                "
+            ),
+        );
     }
 
     #[test]
@@ -3715,8 +4006,7 @@ mod test_fmt {
             indoc!(
                 r"
             when b is
-                1 | 2 | 3 ->
-                    1
+                1 | 2 | 3 -> 1
                 "
             ),
         );
@@ -3753,12 +4043,9 @@ mod test_fmt {
             when b is
                 1
                 | 2
-                | 3 ->
-                    4
-
-                5 | 6 | 7 ->
-                    8
+                | 3 -> 4
 
+                5 | 6 | 7 -> 8
                 9
                 | 10 -> 11
 
@@ -3797,36 +4084,60 @@ mod test_fmt {
 
     #[test]
     fn multi_line_when_condition_1() {
-        expr_formats_same(indoc!(
-            r"
-            when
-                complexFunction a b c
-            is
-                1 ->
-                    Nothing
+        expr_formats_to(
+            indoc!(
+                r"
+                when
+                    complexFunction a b c
+                is
+                    1 ->
+                        Nothing
 
-                _ ->
-                    Just True
-            "
-        ));
+                    _ ->
+                        Just True
+                "
+            ),
+            indoc!(
+                r"
+                when
+                    complexFunction a b c
+                is
+                    1 -> Nothing
+                    _ -> Just True
+                "
+            ),
+        );
     }
 
     #[test]
     fn multi_line_when_condition_2() {
-        expr_formats_same(indoc!(
-            r"
-            when
-                # this is quite complicated
-                complexFunction a b c
-                # Watch out
-            is
-                Complex x y ->
-                    simplify x y
+        expr_formats_to(
+            indoc!(
+                r"
+                when
+                    # this is quite complicated
+                    complexFunction a b c
+                    # Watch out
+                is
+                    Complex x y ->
+                        simplify x y
 
-                Simple z ->
-                    z
-            "
-        ));
+                    Simple z ->
+                        z
+                "
+            ),
+            indoc!(
+                r"
+                when
+                    # this is quite complicated
+                    complexFunction a b c
+                    # Watch out
+                is
+                    Complex x y -> simplify x y
+                    Simple z -> z
+                "
+            ),
+        );
     }
 
     #[test]
@@ -3855,11 +4166,8 @@ mod test_fmt {
                 1
                 + 1
             is
-                2 ->
-                    x
-
-                _ ->
-                    y
+                2 -> x
+                _ -> y
             "
             ),
         );
@@ -3892,11 +4200,8 @@ mod test_fmt {
                 2
                 + 2
             is
-                4 ->
-                    x
-
-                _ ->
-                    y
+                4 -> x
+                _ -> y
             "
             ),
         );
@@ -3937,21 +4242,11 @@ mod test_fmt {
             "
         ));
 
-        expr_formats_same(indoc!(
-            r"
-            when x is
-                Foo -> 1
-                Bar ->
-                    2
-            "
-        ));
-
         expr_formats_to(
             indoc!(
                 r"
                 when x is
                     Foo -> 1
-
                     Bar ->
                         2
                 "
@@ -3960,10 +4255,28 @@ mod test_fmt {
                 r"
                 when x is
                     Foo -> 1
+                    Bar -> 2
+                "
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                when x is
+                    Foo -> 1
+
                     Bar ->
                         2
                 "
             ),
+            indoc!(
+                r"
+                when x is
+                    Foo -> 1
+                    Bar -> 2
+                "
+            ),
         );
 
         expr_formats_to(
@@ -4073,33 +4386,52 @@ mod test_fmt {
 
     #[test]
     fn when_guard() {
-        expr_formats_same(indoc!(
-            r"
-            when maybeScore is
-                Just score if score > 21 ->
-                    win
+        expr_formats_to(
+            indoc!(
+                r"
+                when maybeScore is
+                    Just score if score > 21 ->
+                        win
 
-                _ ->
-                    nextRound
-            "
-        ));
+                    _ ->
+                        nextRound
+                "
+            ),
+            indoc!(
+                r"
+                when maybeScore is
+                    Just score if score > 21 -> win
+                    _ -> nextRound
+                "
+            ),
+        );
     }
 
     #[test]
     fn when_guard_using_function() {
-        expr_formats_same(indoc!(
-            r"
-            when authenticationResponse is
-                Ok user if hasPermission user ->
-                    loadPage route user
+        expr_formats_to(
+            indoc!(
+                r"
+                when authenticationResponse is
+                    Ok user if hasPermission user ->
+                        loadPage route user
 
-                Ok user ->
-                    PageNotFound
+                    Ok user ->
+                        PageNotFound
 
-                Err _ ->
-                    ErrorPage
-            "
-        ));
+                    Err _ ->
+                        ErrorPage
+                "
+            ),
+            indoc!(
+                r"
+                when authenticationResponse is
+                    Ok user if hasPermission user -> loadPage route user
+                    Ok user -> PageNotFound
+                    Err _ -> ErrorPage
+                "
+            ),
+        );
     }
 
     // ACCESSOR
@@ -4196,16 +4528,25 @@ mod test_fmt {
 
     #[test]
     fn precedence_conflict_functions() {
-        expr_formats_same(indoc!(
-            r"
-            when f x == g y == h z is
-                True ->
-                    Ok 1
+        expr_formats_to(
+            indoc!(
+                r"
+                when f x == g y == h z is
+                    True ->
+                        Ok 1
 
-                False ->
-                    Err 2
-            "
-        ));
+                    False ->
+                        Err 2
+                "
+            ),
+            indoc!(
+                r"
+                when f x == g y == h z is
+                    True -> Ok 1
+                    False -> Err 2
+                "
+            ),
+        );
     }
 
     #[test]
@@ -4368,6 +4709,50 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn pipeline_comments_stay_with_their_step() {
+        expr_formats_same(indoc!(
+            r"
+            x =
+                list
+                # keep the evens
+                |> List.keepIf Num.isEven # the odd ones are handled later
+                # then double them
+                |> List.map \n -> n * 2
+
+            x
+            "
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r"
+            x =
+                1
+                + # the offset
+                2
+
+                # and the scale
+                * 3
+
+            x
+            "
+            ),
+            indoc!(
+                r"
+            x =
+                1
+                + # the offset
+                2
+                # and the scale
+                * 3
+
+            x
+            "
+            ),
+        );
+    }
+
     #[test]
     fn multiline_binop_if_with_comments() {
         expr_formats_same(indoc!(
@@ -4443,8 +4828,7 @@ mod test_fmt {
                     foo bar
                     |> Result.withDefault "" # one last comment
 
-                _ ->
-                    42
+                _ -> 42
             "#
             ),
         );
@@ -4697,8 +5081,9 @@ mod test_fmt {
 
     #[test]
     fn pipeline_apply_lambda_multiline() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 example = \model ->
                     model
                     |> withModel
@@ -4712,8 +5097,23 @@ mod test_fmt {
                         )
 
                 example
-            "
-        ));
+                "
+            ),
+            indoc!(
+                r"
+                example = \model ->
+                    model
+                    |> withModel
+                        (\result ->
+                            when result is
+                                Err _ -> Err {}
+                                Ok val -> Ok {}
+                        )
+
+                example
+                "
+            ),
+        );
 
         expr_formats_to(
             indoc!(
@@ -4740,11 +5140,8 @@ mod test_fmt {
                         |> withModel
                             (\result ->
                                 when result is
-                                    Err _ ->
-                                        Err {}
-
-                                    Ok val ->
-                                        Ok {}
+                                    Err _ -> Err {}
+                                    Ok val -> Ok {}
                             )
 
                     example
@@ -4929,19 +5326,75 @@ mod test_fmt {
             indoc!(
                 r#"
                     platform "tui"
-                        requires { Model } { main : { init : {} -> Model, update : Model, Str -> Model, view : Model -> Str } }
+                        requires { Model } { main : {
+                            init : {} -> Model,
+                            update : Model, Str -> Model,
+                            view : Model -> Str,
+                        } }
                         exposes []
                         packages {}
                         imports []
                         provides [mainForHost]
 
-                    mainForHost : { init : ({} -> Model) as Init, update : (Model, Str -> Model) as Update, view : (Model -> Str) as View }
+                    mainForHost : {
+                        init : ({} -> Model) as Init,
+                        update : (Model, Str -> Model) as Update,
+                        view : (Model -> Str) as View,
+                    }
                     mainForHost = main
                 "#
             ),
         );
     }
 
+    #[test]
+    fn multiline_platform_requires() {
+        module_formats_to(
+            indoc!(
+                r#"
+                    platform "tui"
+                        requires { Model, Msg } {
+                            main : {
+                                init : {} -> Model,
+                                update : Model, Msg -> Model,
+                                view : Model -> Str,
+                            }
+                        }
+                        exposes []
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+                "#
+            ),
+            indoc!(
+                r#"
+                    platform "tui"
+                        requires { Model, Msg } {
+                            main : { init : {} -> Model, update : Model, Msg -> Model, view : Model -> Str },
+                        }
+                        exposes []
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn platform_requires_type_variables() {
+        module_formats_same(indoc!(
+            r#"
+                platform "effects"
+                    requires {} { main : a, (a -> Task {} []) -> Task {} [] where a implements Inspect }
+                    exposes []
+                    packages {}
+                    imports []
+                    provides [mainForHost]
+            "#
+        ));
+    }
+
     #[test]
     fn single_line_hosted() {
         module_formats_same(indoc!(
@@ -5033,16 +5486,25 @@ mod test_fmt {
 
     #[test]
     fn multiline_tag_union_annotation_no_comments() {
-        expr_formats_same(indoc!(
-            r"
-            b : [
-                True,
-                False,
-            ]
+        expr_formats_to(
+            indoc!(
+                r"
+                b : [
+                    True,
+                    False,
+                ]
 
-            b
-            "
-        ));
+                b
+                "
+            ),
+            indoc!(
+                r"
+                b : [True, False]
+
+                b
+                "
+            ),
+        );
 
         expr_formats_same(indoc!(
             r"
@@ -5067,10 +5529,8 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                b : [
-                    True,
-                    False,
-                ]
+                b :
+                    [True, False]
 
                 b
                 "
@@ -5090,10 +5550,7 @@ mod test_fmt {
             ),
             indoc!(
                 r"
-                b : [
-                    True,
-                    False,
-                ]
+                b : [True, False]
 
                 b
                 "
@@ -5103,17 +5560,27 @@ mod test_fmt {
 
     #[test]
     fn multiline_tag_union_annotation_beginning_on_same_line() {
-        expr_formats_same(indoc!(
-            r"
-            Expr : [
-                Add Expr Expr,
-                Mul Expr Expr,
-                Val I64,
-                Var I64,
-            ]
+        expr_formats_to(
+            indoc!(
+                r"
+                Expr : [
+                    Add Expr Expr,
+                    Mul Expr Expr,
+                    Val I64,
+                    Var I64,
+                ]
 
-            Expr"
-        ));
+                Expr
+                "
+            ),
+            indoc!(
+                r"
+                Expr : [Add Expr Expr, Mul Expr Expr, Val I64, Var I64]
+
+                Expr
+                "
+            ),
+        );
     }
 
     #[test]
@@ -5184,20 +5651,33 @@ mod test_fmt {
 
     #[test]
     fn recursive_tag_union() {
-        expr_formats_same(indoc!(
-            r"
-            f : [Cons a (ConsList a), Nil] as ConsList a -> [Just a, Nothing]
-            f = \list ->
-                when list is
-                    Nil ->
-                        Nothing
+        expr_formats_to(
+            indoc!(
+                r"
+                f : [Cons a (ConsList a), Nil] as ConsList a -> [Just a, Nothing]
+                f = \list ->
+                    when list is
+                        Nil ->
+                            Nothing
 
-                    Cons first _ ->
-                        Just first
+                        Cons first _ ->
+                            Just first
 
-            f
-            "
-        ));
+                f
+                "
+            ),
+            indoc!(
+                r"
+                f : [Cons a (ConsList a), Nil] as ConsList a -> [Just a, Nothing]
+                f = \list ->
+                    when list is
+                        Nil -> Nothing
+                        Cons first _ -> Just first
+
+                f
+                "
+            ),
+        );
     }
 
     #[test]
@@ -5226,24 +5706,40 @@ mod test_fmt {
 
     #[test]
     fn record_pattern_with_apply_guard() {
-        expr_formats_same(indoc!(
-            r"
-            when { x: 1 } is
-                { x: Just 4 } ->
-                    4
-            "
-        ));
+        expr_formats_to(
+            indoc!(
+                r"
+                when { x: 1 } is
+                    { x: Just 4 } ->
+                        4
+                "
+            ),
+            indoc!(
+                r"
+                when { x: 1 } is
+                    { x: Just 4 } -> 4
+                "
+            ),
+        );
     }
 
     #[test]
     fn record_pattern_with_record_guard() {
-        expr_formats_same(indoc!(
-            r"
-            when { x: 1 } is
-                { x: { x: True } } ->
-                    4
-            "
-        ));
+        expr_formats_to(
+            indoc!(
+                r"
+                when { x: 1 } is
+                    { x: { x: True } } ->
+                        4
+                "
+            ),
+            indoc!(
+                r"
+                when { x: 1 } is
+                    { x: { x: True } } -> 4
+                "
+            ),
+        );
     }
 
     #[test]
@@ -5426,16 +5922,25 @@ mod test_fmt {
 
     #[test]
     fn multiline_opaque_tag_union() {
-        expr_formats_same(indoc!(
-            r"
-            A := [
-                B,
-                C,
-            ]
+        expr_formats_to(
+            indoc!(
+                r"
+                A := [
+                    B,
+                    C,
+                ]
 
-            0
-            "
-        ));
+                0
+                "
+            ),
+            indoc!(
+                r"
+                A := [B, C]
+
+                0
+                "
+            ),
+        );
     }
 
     #[test]
@@ -5632,8 +6137,9 @@ mod test_fmt {
 
     #[test]
     fn test_where_after() {
-        expr_formats_same(indoc!(
-            r"
+        expr_formats_to(
+            indoc!(
+                r"
                 Dict k v := {
                     metadata : List I8,
                     dataIndices : List U64,
@@ -5643,7 +6149,15 @@ mod test_fmt {
 
                 a
                 "
-        ));
+            ),
+            indoc!(
+                r"
+                Dict k v := { metadata : List I8, dataIndices : List U64, data : List (T k v), size : U64 } where k implements Hash & Eq
+
+                a
+                "
+            ),
+        );
     }
 
     #[test]
@@ -6050,8 +6564,7 @@ mod test_fmt {
             indoc!(
                 r"
                 when l1 is
-                    [.. as rest] as l2 ->
-                        f rest
+                    [.. as rest] as l2 -> f rest
                 "
             ),
         );
@@ -0,0 +1,201 @@
+#[macro_use]
+extern crate indoc;
+
+#[cfg(test)]
+mod test_rewrite {
+    use bumpalo::Bump;
+    use roc_fmt::rewrite::{rewrite_module, RenameTag, ReorderArgs, Rewriter};
+    use roc_test_utils::assert_multiline_str_eq;
+
+    fn rewrites_to(rewriter: &dyn for<'a> Rewriter<'a>, input: &str, expected: &str) {
+        let arena = Bump::new();
+        let output = rewrite_module(&arena, input, rewriter).unwrap();
+
+        assert_multiline_str_eq!(expected, output.as_str());
+    }
+
+    fn reorder_args<'r>(
+        module_name: &'r str,
+        ident: &'r str,
+        order: &'r [usize],
+    ) -> ReorderArgs<'r> {
+        ReorderArgs::new(module_name, ident, order).unwrap()
+    }
+
+    #[test]
+    fn rename_tag_everywhere() {
+        rewrites_to(
+            &RenameTag {
+                from: "Red",
+                to: "Crimson",
+            },
+            indoc!(
+                r#"
+                module [Color, toStr]
+
+                Color : [Red, Green]
+
+                toStr : Color -> Str
+                toStr = \color ->
+                    when color is
+                        Red -> "red"
+                        Green -> "green"
+
+                favorite = Red
+                "#
+            ),
+            indoc!(
+                r#"
+                module [Color, toStr]
+
+                Color : [Crimson, Green]
+
+                toStr : Color -> Str
+                toStr = \color ->
+                    when color is
+                        Crimson -> "red"
+                        Green -> "green"
+
+                favorite = Crimson
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn rename_tag_keeps_comments() {
+        rewrites_to(
+            &RenameTag {
+                from: "Ok",
+                to: "Done",
+            },
+            indoc!(
+                r"
+                module [result]
+
+                # The answer
+                result =
+                    # Always succeeds
+                    Ok 42
+                "
+            ),
+            indoc!(
+                r"
+                module [result]
+
+                # The answer
+                result =
+                    # Always succeeds
+                    Done 42
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn reorder_args_at_definition_and_call_sites() {
+        rewrites_to(
+            &reorder_args("", "sub", &[1, 0]),
+            indoc!(
+                r"
+                module [sub, answer]
+
+                sub : I64, I64 -> I64
+                sub = \a, b -> a - b
+
+                answer = sub 50 8
+                "
+            ),
+            indoc!(
+                r"
+                module [sub, answer]
+
+                sub : I64, I64 -> I64
+                sub = \b, a -> a - b
+
+                answer = sub 8 50
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn reorder_args_of_qualified_calls() {
+        rewrites_to(
+            &reorder_args("Str", "replaceFirst", &[0, 2, 1]),
+            indoc!(
+                r#"
+                module [fixed]
+
+                fixed = Str.replaceFirst "teh cat" "teh" "the"
+
+                replaceFirst = \a, b, c -> a
+                "#
+            ),
+            indoc!(
+                r#"
+                module [fixed]
+
+                fixed = Str.replaceFirst "teh cat" "the" "teh"
+
+                replaceFirst = \a, b, c -> a
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn reorder_args_of_piped_calls() {
+        rewrites_to(
+            &reorder_args("", "clamp", &[0, 2, 1]),
+            indoc!(
+                r"
+                module [clamped]
+
+                clamped =
+                    150
+                    |> clamp 0 100
+                    |> Num.toStr
+                "
+            ),
+            indoc!(
+                r"
+                module [clamped]
+
+                clamped =
+                    150
+                    |> clamp 100 0
+                    |> Num.toStr
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn reorder_args_leaves_piped_calls_when_first_arg_moves() {
+        rewrites_to(
+            &reorder_args("", "clamp", &[1, 0, 2]),
+            indoc!(
+                r"
+                module [clamped]
+
+                clamped = 150 |> clamp 0 100
+                "
+            ),
+            indoc!(
+                r"
+                module [clamped]
+
+                clamped = 150 |> clamp 0 100
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn reorder_args_rejects_non_permutations() {
+        assert!(ReorderArgs::new("", "f", &[0, 0]).is_none());
+        assert!(ReorderArgs::new("", "f", &[0, 2]).is_none());
+        assert!(ReorderArgs::new("", "f", &[1, 0]).is_some());
+    }
+}
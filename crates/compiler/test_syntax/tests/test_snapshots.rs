@@ -225,7 +225,9 @@ mod test_snapshots {
         fail/record_type_open_indent.expr,
         fail/record_type_tab.expr,
         fail/single_no_end.expr,
+        fail/tab_between_tokens.expr,
         fail/tab_crash.header,
+        fail/tab_in_indentation.expr,
         fail/tag_union_end.expr,
         fail/tag_union_lowercase_tag_name.expr,
         fail/tag_union_open.expr,
@@ -247,6 +249,7 @@ mod test_snapshots {
         fail/when_outdented_branch.expr,
         fail/when_over_indented_int.expr,
         fail/when_over_indented_underscore.expr,
+        fail/when_pattern_minus_without_digits.expr,
         fail/where_type_variable.expr,
         fail/wild_case_arrow.expr,
         malformed/bad_opaque_ref.expr,
@@ -267,6 +270,7 @@ mod test_snapshots {
         pass/annotated_record_destructure.expr,
         pass/annotated_tag_destructure.expr,
         pass/annotated_tuple_destructure.expr,
+        pass/app_header_package_target.header,
         pass/apply_parenthetical_tag_args.expr,
         pass/apply_tag.expr,
         pass/apply_three_args.expr,
@@ -283,6 +287,7 @@ mod test_snapshots {
         pass/call_with_newlines.expr,
         pass/closure_in_binop.expr,
         pass/closure_in_binop_with_spaces.expr,
+        pass/closure_trailing_comma.expr,
         pass/closure_with_underscores.expr,
         pass/comment_after_annotation.expr,
         pass/comment_after_def.moduledefs,
@@ -479,6 +484,7 @@ mod test_snapshots {
         pass/unary_negation_with_parens.expr,
         pass/unary_not.expr,
         pass/unary_not_with_parens.expr,
+        pass/unavailable_platform_header.header,
         pass/underscore_backpassing.expr,
         pass/underscore_in_assignment_pattern.expr,
         pass/value_def_confusion.expr,
@@ -3383,6 +3383,8 @@ pub enum Reason {
         name: Option<Symbol>,
         arity: u8,
         called_via: CalledVia,
+        /// The region of each argument the function was called with
+        arg_regions: Vec<Region>,
     },
     LowLevelOpArg {
         op: LowLevel,
@@ -0,0 +1,232 @@
+//! Searching the exposed values of a package by their type, like `List a, (a -> b) -> List b`.
+//!
+//! A value matches when its solved type is the same as the query up to renaming type variables.
+//! A `*` or `_` in the query stands for a variable that appears nowhere else. Unless the query
+//! has a `where` clause of its own, the `where` clause of a value's type is ignored.
+
+use bumpalo::Bump;
+use roc_load::LoadedModule;
+use roc_parse::parser::Parser;
+use roc_parse::state::State;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use std::path::PathBuf;
+
+use crate::{load_module_for_docs, write_builtin_package};
+
+/// A value whose type matches the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMatch {
+    pub module: String,
+    pub name: String,
+    pub type_str: String,
+}
+
+/// Prints the exposed values of the package whose types match the query, and returns them.
+pub fn find_by_type(root_file: PathBuf, query: &str) -> Vec<TypeMatch> {
+    let query = parse_query(query);
+    let mut loaded_module = load_module_for_docs(root_file);
+
+    let matches = find_matches(&mut loaded_module, &query);
+
+    print_matches(&matches);
+
+    matches
+}
+
+/// Like [`find_by_type`], for the values of the standard library.
+pub fn find_builtins_by_type(query: &str) -> Vec<TypeMatch> {
+    let package_dir = write_builtin_package();
+
+    find_by_type(package_dir.path().join("main.roc"), query)
+}
+
+/// Checks that the query is a type, and exits with an error if it isn't.
+fn parse_query(query: &str) -> Vec<String> {
+    let arena = Bump::new();
+    let state = State::new(query.trim().as_bytes());
+
+    let parsed = roc_parse::type_annotation::located(true).parse(&arena, state, 0);
+
+    match parsed {
+        Ok((_, _, rest)) if rest.has_reached_end() => normalize(query),
+        _ => {
+            eprintln!("This is not a type I can search for:\n\n    {query}\n");
+            eprintln!("A query looks like the type of a value, for example:\n\n    List a, (a -> b) -> List b\n");
+
+            std::process::exit(1);
+        }
+    }
+}
+
+fn find_matches(loaded_module: &mut LoadedModule, query: &[String]) -> Vec<TypeMatch> {
+    let query_has_where = query.iter().any(|token| token == "where");
+    let mut matches = Vec::new();
+
+    for module_id in loaded_module.exposed_modules.clone() {
+        let (module_name, exposed_symbols) = match loaded_module.docs_by_module.get(&module_id) {
+            Some(docs) => (docs.name.clone(), docs.exposed_symbols.clone()),
+            None => continue,
+        };
+
+        let exposes = loaded_module
+            .exposes
+            .get(&module_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let subs = if module_id == loaded_module.module_id {
+            loaded_module.solved.inner_mut()
+        } else {
+            match loaded_module.typechecked.get_mut(&module_id) {
+                Some(checked) => checked.solved_subs.inner_mut(),
+                None => continue,
+            }
+        };
+
+        for (symbol, var) in exposes {
+            if !exposed_symbols.contains(&symbol) {
+                continue;
+            }
+
+            let type_str = name_and_print_var(
+                var,
+                subs,
+                module_id,
+                &loaded_module.interns,
+                DebugPrint::NOTHING,
+            );
+
+            let mut candidate = normalize(&type_str);
+
+            if !query_has_where {
+                if let Some(index) = candidate.iter().position(|token| token == "where") {
+                    candidate.truncate(index);
+                }
+            }
+
+            if candidate == query {
+                matches.push(TypeMatch {
+                    module: module_name.clone(),
+                    name: symbol.as_str(&loaded_module.interns).to_string(),
+                    type_str,
+                });
+            }
+        }
+    }
+
+    matches.sort_by(|a, b| (&a.module, &a.name).cmp(&(&b.module, &b.name)));
+
+    matches
+}
+
+fn print_matches(matches: &[TypeMatch]) {
+    if matches.is_empty() {
+        println!("No exposed values have a type like that.");
+    }
+
+    for TypeMatch {
+        module,
+        name,
+        type_str,
+    } in matches
+    {
+        println!("{module}.{name} : {type_str}");
+    }
+}
+
+/// Splits a type into tokens, without whitespace or module names, and with the type variables
+/// renamed in the order they first appear.
+fn normalize(type_str: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = type_str.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+
+        if ch.is_alphanumeric() || ch == '_' {
+            let mut ident = ch.to_string();
+
+            while let Some(&next) = chars.peek() {
+                if next.is_alphanumeric() || next == '_' || next == '.' {
+                    ident.push(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+
+            tokens.push(ident);
+        } else if (ch == '-' || ch == '=') && chars.peek() == Some(&'>') {
+            chars.next();
+            tokens.push(format!("{ch}>"));
+        } else {
+            tokens.push(ch.to_string());
+        }
+    }
+
+    let mut variables: Vec<String> = Vec::new();
+
+    for index in 0..tokens.len() {
+        let token = &tokens[index];
+        let first = token.chars().next().unwrap_or_default();
+
+        if first.is_uppercase() {
+            // `Num.U64` and `U64` are the same type
+            if let Some((_, name)) = token.rsplit_once('.') {
+                tokens[index] = name.to_string();
+            }
+        } else if token == "*" || token == "_" {
+            // A variable that can't be named by any other token
+            variables.push(String::new());
+            tokens[index] = format!("'{}", variables.len() - 1);
+        } else if first.is_lowercase() {
+            let is_field = matches!(tokens.get(index + 1).map(String::as_str), Some(":" | "?"));
+            let is_keyword = token == "where" || token == "implements";
+
+            if !is_field && !is_keyword {
+                let position = match variables.iter().position(|var| var == token) {
+                    Some(position) => position,
+                    None => {
+                        variables.push(token.clone());
+                        variables.len() - 1
+                    }
+                };
+
+                tokens[index] = format!("'{position}");
+            }
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize;
+
+    #[test]
+    fn variables_are_renamed() {
+        assert_eq!(
+            normalize("List a, (a -> b) -> List b"),
+            normalize("List elem,(elem->out)->List out")
+        );
+        assert_ne!(
+            normalize("List a, (a -> b) -> List b"),
+            normalize("List a, (a -> a) -> List a")
+        );
+    }
+
+    #[test]
+    fn fields_and_modules_are_kept() {
+        assert_eq!(
+            normalize("{ len : Num.U64 }a -> a"),
+            normalize("{ len : U64 }r -> r")
+        );
+        assert_ne!(
+            normalize("{ len : U64 } -> U64"),
+            normalize("{ size : U64 } -> U64")
+        );
+    }
+}
@@ -18,6 +18,10 @@ use roc_region::all::Region;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod find;
+
+pub use find::{find_builtins_by_type, find_by_type, TypeMatch};
+
 const LINK_SVG: &str = include_str!("./static/link.svg");
 
 pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
@@ -124,6 +128,15 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
         set
     };
 
+    // Write the search index, which search.js fetches
+    fs::write(
+        build_dir.join("search-index.json"),
+        render_search_index(&exposed_module_docs, &all_exposed_symbols),
+    )
+    .unwrap_or_else(|error| {
+        panic!("Attempted to write search-index.json but failed with this error: {error}")
+    });
+
     // TODO fix: as is, this overrides an existing index.html
     // Write index.html for package (/index.html)
     {
@@ -181,6 +194,35 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
     println!("🎉 Docs generated in {}", build_dir.display());
 }
 
+/// Generates the standard library's documentation, from the sources of the builtin modules that
+/// are part of the compiler.
+pub fn generate_builtin_docs_html(build_dir: &Path) {
+    let package_dir = write_builtin_package();
+
+    generate_docs_html(package_dir.path().join("main.roc"), build_dir);
+}
+
+/// Writes the sources of the builtin modules to a new temporary directory, as a package whose
+/// main file is `main.roc`. The directory is removed when the returned value is dropped.
+fn write_builtin_package() -> tempfile::TempDir {
+    let package_dir =
+        tempfile::tempdir().expect("TODO gracefully handle being unable to create a temp dir");
+
+    for (filename, source) in roc_builtins::roc::PACKAGE_FILES {
+        let path = package_dir.path().join(filename);
+
+        fs::write(&path, source).unwrap_or_else(|error| {
+            panic!(
+                "Attempted to write {} but failed with this error: {}",
+                path.display(),
+                error
+            )
+        });
+    }
+
+    package_dir
+}
+
 /// Gives only the module docs for modules that are exposed by the platform or package.
 fn get_exposed_module_docs(
     loaded_module: &mut LoadedModule,
@@ -240,6 +282,50 @@ fn render_package_index(docs_by_module: &[(ModuleId, ModuleDocumentation)]) -> S
     index_buf
 }
 
+/// A JSON array with an entry for each exposed def: its name, module, type signature on one
+/// line, and the first line of its docs.
+fn render_search_index(
+    docs_by_module: &[(ModuleId, ModuleDocumentation)],
+    all_exposed_symbols: &VecSet<Symbol>,
+) -> String {
+    let mut entries = Vec::new();
+
+    for (_, module) in docs_by_module {
+        for entry in &module.entries {
+            if let DocEntry::DocDef(doc_def) = entry {
+                if all_exposed_symbols.contains(&doc_def.symbol) {
+                    let first_doc_line = doc_def
+                        .docs
+                        .as_deref()
+                        .and_then(|docs| docs.lines().map(str::trim).find(|line| !line.is_empty()))
+                        .unwrap_or_default();
+
+                    entries.push(serde_json::json!({
+                        "name": doc_def.name,
+                        "module": module.name,
+                        "type": type_signature(&doc_def.type_annotation),
+                        "doc": first_doc_line,
+                    }));
+                }
+            }
+        }
+    }
+
+    serde_json::Value::Array(entries).to_string()
+}
+
+/// The type annotation on a single line, or an empty string if there is none.
+fn type_signature(type_ann: &TypeAnnotation) -> String {
+    if matches!(type_ann, TypeAnnotation::NoTypeAnn) {
+        return String::new();
+    }
+
+    let mut buf = String::new();
+    type_annotation_to_html(0, &mut buf, type_ann, false);
+
+    buf.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 fn render_module_documentation(
     module_id: ModuleId,
     module: &ModuleDocumentation,
@@ -478,6 +564,11 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::AllAvailable,
         exec_mode: ExecutionMode::Check,
+        optimize_ir: false,
+        report_expects_with_dbg: false,
+        cancellation: None,
+        problem_sink: None,
+        defines: Default::default(),
     };
     match roc_load::load_and_typecheck(
         &arena,
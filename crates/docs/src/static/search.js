@@ -84,6 +84,119 @@
         });
     }
 
+    // Search every exposed def by name, or by type when the query has an arrow in it,
+    // like `List a, (a -> b) -> List b`.
+    let searchResults = document.getElementById("search-results");
+
+    if (searchBox != null && searchResults != null) {
+        const maxResults = 20;
+        let searchIndex = [];
+
+        // Type names in a signature. Lowercase names are type variables, which match anything.
+        const typeNames = (signature) =>
+            (signature.match(/[A-Za-z][A-Za-z0-9]*/g) || []).filter(
+                (name) => name[0] === name[0].toUpperCase()
+            );
+
+        const arrowCount = (signature) => signature.split("->").length - 1;
+
+        // Lower scores are better matches; null means no match.
+        const nameScore = (entry, text) => {
+            let name = entry.name.toLowerCase();
+            let qualified = `${entry.module}.${entry.name}`.toLowerCase();
+
+            if (name === text || qualified === text) {
+                return 0;
+            } else if (name.startsWith(text)) {
+                return 1;
+            } else if (qualified.includes(text)) {
+                return 2;
+            } else {
+                return null;
+            }
+        };
+
+        const typeScore = (entry, query) => {
+            let entryNames = typeNames(entry.type);
+            let queryNames = typeNames(query);
+
+            if (!queryNames.every((name) => entryNames.includes(name))) {
+                return null;
+            }
+
+            return (
+                Math.abs(arrowCount(entry.type) - arrowCount(query)) * 10 +
+                (entryNames.length - queryNames.length)
+            );
+        };
+
+        const showSearchResults = () => {
+            let query = searchBox.value.trim();
+
+            searchResults.replaceChildren();
+
+            if (query === "") {
+                searchResults.classList.add("hidden");
+                return;
+            }
+
+            let isTypeQuery = query.includes("->");
+            let text = query.toLowerCase();
+
+            let matches = searchIndex
+                .map((entry) => ({
+                    entry,
+                    score: isTypeQuery
+                        ? typeScore(entry, query)
+                        : nameScore(entry, text),
+                }))
+                .filter(({ score }) => score !== null)
+                .sort(
+                    (a, b) =>
+                        a.score - b.score ||
+                        a.entry.name.length - b.entry.name.length
+                )
+                .slice(0, maxResults);
+
+            for (let { entry } of matches) {
+                let item = document.createElement("li");
+                let link = document.createElement("a");
+                link.href = `${entry.module}#${entry.name}`;
+                link.textContent = `${entry.module}.${entry.name}`;
+
+                if (entry.type !== "") {
+                    let signature = document.createElement("span");
+                    signature.classList.add("search-result-type");
+                    signature.textContent = ` : ${entry.type}`;
+                    link.appendChild(signature);
+                }
+
+                item.appendChild(link);
+
+                if (entry.doc !== "") {
+                    let doc = document.createElement("p");
+                    doc.textContent = entry.doc;
+                    item.appendChild(doc);
+                }
+
+                searchResults.appendChild(item);
+            }
+
+            searchResults.classList.toggle("hidden", matches.length === 0);
+        };
+
+        searchBox.addEventListener("input", showSearchResults);
+
+        // Without an index, searching still filters the sidebar.
+        fetch("search-index.json")
+            .then((response) => response.json())
+            .then((index) => {
+                searchIndex = index;
+                showSearchResults();
+            })
+            .catch(() => {});
+    }
+
     const isTouchSupported = () => {
         try {
             document.createEvent("TouchEvent");
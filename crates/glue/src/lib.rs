@@ -4,6 +4,7 @@
 //! the plan is to support any language via a plugin model.
 pub mod enums;
 pub mod load;
+pub mod report;
 pub mod roc_type;
 pub mod structs;
 pub mod types;
@@ -11,7 +12,7 @@ pub mod types;
 #[rustfmt::skip]
 pub mod glue;
 
-pub use load::generate;
+pub use load::{emit_headers, generate};
 
 // required because we use roc_std here
 mod roc_externs {
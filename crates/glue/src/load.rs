@@ -1,3 +1,4 @@
+use crate::report::{layout_report, LAYOUT_REPORT_FILE_NAME};
 use crate::roc_type;
 use crate::types::Types;
 use bumpalo::Bump;
@@ -59,6 +60,9 @@ pub fn generate(
                 emit_debug_info: false,
                 emit_llvm_ir: false,
                 fuzz: false,
+                freestanding: false,
+                leak_check: false,
+                split_objects: false,
             };
 
             let load_config = standard_load_config(
@@ -215,6 +219,39 @@ pub fn generate(
     }
 }
 
+/// Writes the layout report of the platform's entry points into the output directory, along with
+/// the glue the spec generates if one is given. Only the platform is type checked; no code is
+/// generated for it.
+pub fn emit_headers(
+    input_path: &Path,
+    output_path: &Path,
+    spec_path: Option<&Path>,
+    backend: CodeGenBackend,
+) -> io::Result<i32> {
+    let target = Triple::host().into();
+    let types = load_types(
+        input_path.to_path_buf(),
+        Threading::AllAvailable,
+        IgnoreErrors::NONE,
+        target,
+    )?;
+
+    std::fs::create_dir_all(output_path)?;
+
+    let report_path = output_path.join(LAYOUT_REPORT_FILE_NAME);
+    std::fs::write(&report_path, layout_report(&types))?;
+
+    println!(
+        "Wrote the layouts of the entry points to:\n\n\t{}",
+        report_path.display()
+    );
+
+    match spec_path {
+        Some(spec_path) => generate(input_path, output_path, spec_path, backend),
+        None => Ok(0),
+    }
+}
+
 fn call_roc_make_glue(
     lib: &Library,
     backend: CodeGenBackend,
@@ -422,6 +459,11 @@ pub fn load_types(
             palette: DEFAULT_PALETTE,
             threading,
             exec_mode: ExecutionMode::Check,
+            optimize_ir: false,
+            report_expects_with_dbg: false,
+            cancellation: None,
+            problem_sink: None,
+            defines: Default::default(),
         },
     )
     .unwrap_or_else(|problem| match problem {
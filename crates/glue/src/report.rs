@@ -0,0 +1,99 @@
+//! A plain text report of the layouts a platform's entry points have on each target, so that
+//! changes to the ABI between the host and Roc show up in a diff.
+
+use crate::types::{RocTagUnion, RocType, TypeId, Types};
+use std::fmt::Write;
+
+pub const LAYOUT_REPORT_FILE_NAME: &str = "layouts.txt";
+
+/// The size and alignment of every entry point and named type, grouped by target.
+pub fn layout_report(types_by_target: &[Types]) -> String {
+    let mut report = String::new();
+
+    for types in types_by_target {
+        // Writing to a String can't fail.
+        let _ = writeln!(report, "{:?}", types.target());
+        let _ = writeln!(report, "\n    entry points");
+
+        for (name, id) in types.entry_points() {
+            let _ = writeln!(
+                report,
+                "        {name} : {}  {}",
+                type_name(types, *id),
+                size_and_align(types, *id)
+            );
+        }
+
+        let _ = writeln!(report, "\n    types");
+
+        for id in types.sorted_ids() {
+            let is_named = matches!(
+                types.get_type(id),
+                RocType::Struct { .. } | RocType::TagUnion(_) | RocType::Function(_)
+            );
+
+            if is_named {
+                let _ = writeln!(
+                    report,
+                    "        {}  {}",
+                    type_name(types, id),
+                    size_and_align(types, id)
+                );
+            }
+        }
+
+        report.push('\n');
+    }
+
+    report
+}
+
+fn size_and_align(types: &Types, id: TypeId) -> String {
+    format!(
+        "(size {}, alignment {})",
+        types.size_ignoring_alignment(id),
+        types.align(id)
+    )
+}
+
+fn type_name(types: &Types, id: TypeId) -> String {
+    let arg = |id: TypeId| {
+        let name = type_name(types, id);
+
+        if name.contains(' ') {
+            format!("({name})")
+        } else {
+            name
+        }
+    };
+
+    match types.get_type(id) {
+        RocType::RocStr => "Str".to_string(),
+        RocType::Bool => "Bool".to_string(),
+        RocType::Num(num) => format!("{num:?}"),
+        RocType::RocResult(ok, err) => format!("Result {} {}", arg(*ok), arg(*err)),
+        RocType::RocList(elem) => format!("List {}", arg(*elem)),
+        RocType::RocDict(key, value) => format!("Dict {} {}", arg(*key), arg(*value)),
+        RocType::RocSet(elem) => format!("Set {}", arg(*elem)),
+        RocType::RocBox(elem) => format!("Box {}", arg(*elem)),
+        RocType::TagUnion(union) => tag_union_name(union).to_string(),
+        RocType::EmptyTagUnion => "[]".to_string(),
+        RocType::Struct { name, .. } | RocType::TagUnionPayload { name, .. } => name.clone(),
+        RocType::RecursivePointer(id) => type_name(types, *id),
+        RocType::Function(function) => function.function_name.clone(),
+        RocType::Unit => "{}".to_string(),
+        RocType::Unsized => "Unsized".to_string(),
+    }
+}
+
+fn tag_union_name(union: &RocTagUnion) -> &str {
+    match union {
+        RocTagUnion::Enumeration { name, .. }
+        | RocTagUnion::NonRecursive { name, .. }
+        | RocTagUnion::Recursive { name, .. }
+        | RocTagUnion::NonNullableUnwrapped { name, .. }
+        | RocTagUnion::SingleTagStruct { name, .. }
+        | RocTagUnion::NullableWrapped { name, .. }
+        | RocTagUnion::NullableUnwrapped { name, .. } => name,
+    }
+}
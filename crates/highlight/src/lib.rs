@@ -1,4 +1,6 @@
-use roc_parse::highlight::Token;
+use roc_parse::highlight::{Token, HEADER_KEYWORDS};
+use roc_parse::keyword::KEYWORDS;
+use roc_region::all::Loc;
 
 pub fn highlight_roc_code(code: &str) -> String {
     let buf = highlight(code);
@@ -28,24 +30,50 @@ pub fn highlight(code: &str) -> Vec<String> {
         code
     };
 
-    for location in roc_parse::highlight::highlight(code) {
+    for location in classify(code) {
         let current_text = &code[offset..location.byte_range().end];
 
-        match location.value {
-            // Comments `#` and Documentation comments `##`
-            Token::LineComment | Token::DocComment => {
-                buf = push_html_span(buf, current_text, "comment");
-            }
-            // Number, String, Tag, Type literals
+        match location.value.css_class() {
+            Some(class) => buf = push_html_span(buf, current_text, class),
+            None => buf = push_html(buf, current_text),
+        }
+
+        offset = location.byte_range().end;
+    }
+
+    buf
+}
+
+/// The kinds of code that highlighters tell apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+    /// Comments `#` and documentation comments `##`
+    Comment,
+    /// Number, string, and character literals
+    Literal,
+    /// Keywords, and punctuation that is part of the syntax like `=` and `->`
+    Keyword,
+    Operator,
+    Delimiter,
+    /// Types, tags, and modules
+    UpperIdent,
+    /// Variables and field names
+    LowerIdent,
+    /// Code that could not be tokenized, like an unterminated string
+    Error,
+    Other,
+}
+
+impl TokenClass {
+    pub fn of(token: Token) -> Self {
+        match token {
+            Token::LineComment | Token::DocComment => TokenClass::Comment,
             Token::SingleQuote
             | Token::String
             | Token::UnicodeEscape
             | Token::EscapedChar
             | Token::Interpolated
-            | Token::Number => {
-                buf = push_html_span(buf, current_text, "literal");
-            }
-            // Keywords and punctuation
+            | Token::Number => TokenClass::Literal,
             Token::Keyword
             | Token::Equals
             | Token::Backslash
@@ -55,10 +83,7 @@ pub fn highlight(code: &str) -> Vec<String> {
             | Token::ColonEquals
             | Token::Colon
             | Token::And
-            | Token::QuestionMark => {
-                buf = push_html_span(buf, current_text, "kw");
-            }
-            // Operators
+            | Token::QuestionMark => TokenClass::Keyword,
             Token::Percent
             | Token::Caret
             | Token::Bang
@@ -75,36 +100,58 @@ pub fn highlight(code: &str) -> Vec<String> {
             | Token::DoubleBar
             | Token::Multiply
             | Token::Plus
-            | Token::DoubleAnd => {
-                buf = push_html_span(buf, current_text, "op");
-            }
-            // Delimieters
+            | Token::DoubleAnd => TokenClass::Operator,
             Token::Paren
             | Token::Bracket
             | Token::Brace
             | Token::Comma
             | Token::Bar
-            | Token::Decimal => {
-                buf = push_html_span(buf, current_text, "delimeter");
-            }
-            // Types, Tags, and Modules
-            Token::UpperIdent | Token::AtSign => {
-                buf = push_html_span(buf, current_text, "upperident");
-            }
-            // Variables modules and field names
-            Token::LowerIdent | Token::Underscore => {
-                buf = push_html_span(buf, current_text, "lowerident");
-            }
-            // Anyting else that wasn't tokenised
-            Token::Error | Token::Other => {
-                buf = push_html(buf, current_text);
-            }
+            | Token::Decimal => TokenClass::Delimiter,
+            Token::UpperIdent | Token::AtSign => TokenClass::UpperIdent,
+            Token::LowerIdent | Token::Underscore => TokenClass::LowerIdent,
+            Token::Error => TokenClass::Error,
+            Token::Other => TokenClass::Other,
         }
+    }
 
-        offset = location.byte_range().end;
+    /// The class of the html `span` around code of this kind, if it gets one.
+    pub fn css_class(self) -> Option<&'static str> {
+        match self {
+            TokenClass::Comment => Some("comment"),
+            TokenClass::Literal => Some("literal"),
+            TokenClass::Keyword => Some("kw"),
+            TokenClass::Operator => Some("op"),
+            TokenClass::Delimiter => Some("delimeter"),
+            TokenClass::UpperIdent => Some("upperident"),
+            TokenClass::LowerIdent => Some("lowerident"),
+            TokenClass::Error | TokenClass::Other => None,
+        }
     }
+}
 
-    buf
+/// The class of each token in the code, in order, leaving out whitespace.
+///
+/// Tokens are found lexically, so code that doesn't parse is classified too: whatever can't be
+/// tokenized is classified as [`TokenClass::Error`] up to the end of its line.
+pub fn classify(code: &str) -> Vec<Loc<TokenClass>> {
+    roc_parse::highlight::highlight(code)
+        .into_iter()
+        .map(|token| Loc::at(token.region, TokenClass::of(token.value)))
+        .collect()
+}
+
+/// Every keyword, in module headers and in the rest of the code.
+pub fn keywords() -> Vec<&'static str> {
+    let mut keywords: Vec<&'static str> = KEYWORDS
+        .iter()
+        .chain(HEADER_KEYWORDS.iter())
+        .copied()
+        .collect();
+
+    keywords.sort_unstable();
+    keywords.dedup();
+
+    keywords
 }
 
 fn push_html_span(mut buf: Vec<String>, curr: &str, class: &str) -> Vec<String> {
@@ -124,3 +171,26 @@ fn push_html(mut buf: Vec<String>, curr: &str) -> Vec<String> {
 
     buf
 }
+
+#[cfg(test)]
+mod test {
+    use super::{classify, TokenClass};
+
+    #[test]
+    fn classify_code_that_does_not_parse() {
+        let classes: Vec<_> = classify("x = \"unterminated\nwhen")
+            .into_iter()
+            .map(|token| token.value)
+            .collect();
+
+        assert_eq!(
+            classes,
+            vec![
+                TokenClass::LowerIdent,
+                TokenClass::Keyword,
+                TokenClass::Error,
+                TokenClass::Keyword,
+            ]
+        );
+    }
+}
@@ -9,27 +9,38 @@ use bumpalo::Bump;
 use parking_lot::Mutex;
 use roc_can::{abilities::AbilitiesStore, expr::Declarations};
 use roc_collections::{MutMap, MutSet, VecMap};
-use roc_load::{docs::ModuleDocumentation, CheckedModule, LoadedModule};
+use roc_load::{
+    call_graph::Call, docs::ModuleDocumentation, symbol_index::IndexedSymbol, CancellationToken,
+    CheckedModule, LoadedModule,
+};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_region::all::LineInfo;
 use roc_reporting::report::RocDocAllocator;
 use roc_solve_problem::TypeError;
+use roc_target::Target;
 use roc_types::subs::{Subs, Variable};
 
+use target_lexicon::Triple;
 use tower_lsp::lsp_types::{Diagnostic, SemanticTokenType, Url};
 
 mod analysed_doc;
+mod call_hierarchy;
 mod completion;
+mod evaluate;
+mod organize_imports;
 mod parse_ast;
 mod semantic_tokens;
+mod signature_help;
 mod tokens;
+mod type_hints;
 mod utils;
+mod workspace_symbols;
 
 use crate::convert::diag::{IntoLspDiagnostic, ProblemFmt};
 
-pub(crate) use self::analysed_doc::{AnalyzedDocument, DocInfo};
-use self::{analysed_doc::ModuleIdToUrl, tokens::Token};
+pub(crate) use self::analysed_doc::{value_hint, AnalyzedDocument, DocInfo};
+use self::{analysed_doc::ModuleIdToUrl, organize_imports::ImportFixes, tokens::Token};
 
 pub const HIGHLIGHT_TOKENS_LEGEND: &[SemanticTokenType] = Token::LEGEND;
 
@@ -89,6 +100,9 @@ pub(super) struct AnalyzedModule {
     subs: Subs,
     abilities: AbilitiesStore,
     declarations: Declarations,
+    symbol_index: Vec<IndexedSymbol>,
+    calls: Vec<Call>,
+    import_fixes: ImportFixes,
     modules_info: Arc<ModulesInfo>,
     // ModuleIds are not stable between compilations, so a ModuleId visible to
     // one module may not be true global to the language server.
@@ -100,7 +114,16 @@ pub struct AnalysisResult {
     diagnostics: Vec<Diagnostic>,
 }
 
-pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
+/// The target the workspace is checked and evaluated for: the machine the language server runs
+/// on, the same one `roc run` builds for.
+pub(crate) fn workspace_target() -> Target {
+    Triple::host().into()
+}
+
+pub(crate) fn global_analysis(
+    doc_info: DocInfo,
+    cancellation: CancellationToken,
+) -> Vec<AnalyzedDocument> {
     let fi = doc_info.url.to_file_path().unwrap();
     let src_dir = find_src_dir(&fi).to_path_buf();
 
@@ -109,12 +132,13 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         &arena,
         fi,
         &doc_info.source,
-        src_dir,
-        roc_target::Target::LinuxX64,
+        src_dir.clone(),
+        workspace_target(),
         roc_load::FunctionKind::LambdaSet,
         roc_reporting::report::RenderTarget::LanguageServer,
         RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
         roc_reporting::report::DEFAULT_PALETTE,
+        Some(cancellation),
     );
 
     let module = match loaded {
@@ -152,6 +176,8 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         mut imports,
         exposes,
         docs_by_module,
+        mut symbol_index,
+        mut calls,
         ..
     } = module;
 
@@ -162,6 +188,8 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
 
     let exposed_imports = resolve_exposed_imports(exposed_imports, &exposes);
 
+    let importable_modules = importable_modules(&src_dir, &interns, &exposes, &sources);
+
     let modules_info = Arc::new(ModulesInfo::from_loaded_module(
         exposes,
         &typechecked,
@@ -175,6 +203,9 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         type_problems: &mut type_problems,
         declarations_by_id: &mut declarations_by_id,
         typechecked: &mut typechecked,
+        symbol_index: &mut symbol_index,
+        calls: &mut calls,
+        importable_modules,
         root_module: &mut root_module,
         exposed_imports,
         imports: &mut imports,
@@ -218,6 +249,38 @@ fn resolve_exposed_imports(
         .collect()
 }
 
+/// The modules that can be imported by their name alone, because they are in the source
+/// directory rather than in a package, along with the names of the values each one exposes.
+fn importable_modules(
+    src_dir: &Path,
+    interns: &Interns,
+    exposes: &MutMap<ModuleId, Vec<(Symbol, Variable)>>,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+) -> Vec<(String, Vec<String>)> {
+    sources
+        .iter()
+        .filter_map(|(module_id, (path, _))| {
+            let name = interns.module_ids.get_name(*module_id)?.as_str();
+
+            let expected_path = src_dir
+                .join(name.replace('.', std::path::MAIN_SEPARATOR_STR))
+                .with_extension("roc");
+
+            if path != &expected_path {
+                return None;
+            }
+
+            let exposed = exposes
+                .get(module_id)?
+                .iter()
+                .map(|(symbol, _)| symbol.as_str(interns).to_string())
+                .collect();
+
+            Some((name.to_string(), exposed))
+        })
+        .collect()
+}
+
 fn find_src_dir(path: &Path) -> &Path {
     path.parent().unwrap_or(path)
 }
@@ -265,6 +328,9 @@ struct AnalyzedDocumentBuilder<'a> {
     type_problems: &'a mut MutMap<ModuleId, Vec<TypeError>>,
     declarations_by_id: &'a mut MutMap<ModuleId, Declarations>,
     typechecked: &'a mut MutMap<ModuleId, CheckedModule>,
+    symbol_index: &'a mut MutMap<ModuleId, Vec<IndexedSymbol>>,
+    calls: &'a mut MutMap<ModuleId, Vec<Call>>,
+    importable_modules: Vec<(String, Vec<String>)>,
     root_module: &'a mut Option<RootModule>,
     imports: &'a mut MutMap<ModuleId, MutSet<ModuleId>>,
     exposed_imports: HashMap<ModuleId, Vec<(Symbol, Variable)>>,
@@ -289,6 +355,17 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
 
         let exposed_imports = self.exposed_imports.remove(&module_id).unwrap_or_default();
 
+        let symbol_index = self.symbol_index.remove(&module_id).unwrap_or_default();
+        let calls = self.calls.remove(&module_id).unwrap_or_default();
+
+        let import_fixes = ImportFixes::new(
+            self.can_problems
+                .get(&module_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+            &self.importable_modules,
+        );
+
         if let Some(m) = self.typechecked.remove(&module_id) {
             subs = m.solved_subs.into_inner();
             abilities = m.abilities_store;
@@ -306,6 +383,9 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
             subs,
             abilities,
             declarations,
+            symbol_index,
+            calls,
+            import_fixes,
             module_id,
             modules_info: self.modules_info.clone(),
             interns: self.interns.clone(),
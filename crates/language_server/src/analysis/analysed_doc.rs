@@ -8,8 +8,10 @@ use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::LineInfo;
 
 use tower_lsp::lsp_types::{
-    CompletionItem, Diagnostic, GotoDefinitionResponse, Hover, HoverContents, LanguageString,
-    Location, MarkedString, Position, Range, SemanticTokens, SemanticTokensResult, TextEdit, Url,
+    CallHierarchyIncomingCall, CallHierarchyItem, CompletionItem, Diagnostic,
+    GotoDefinitionResponse, Hover, HoverContents, InlayHint, InlayHintKind, InlayHintLabel,
+    LanguageString, Location, MarkedString, Position, Range, SemanticTokens, SemanticTokensResult,
+    SignatureHelp, SymbolInformation, TextEdit, Url,
 };
 
 use crate::{
@@ -18,9 +20,15 @@ use crate::{
 };
 
 use super::{
+    call_hierarchy::{call_hierarchy_item, group_calls, top_level_def},
+    evaluate::{evaluate, top_level_constants, Expression},
+    organize_imports::organize_imports,
     parse_ast::Ast,
     semantic_tokens::arrange_semantic_tokens,
-    utils::{format_var_type, is_roc_identifier_char},
+    signature_help::{application_at, signature_help},
+    type_hints::inferred_types,
+    utils::{format_function_type, format_var_type, is_roc_identifier_char},
+    workspace_symbols::workspace_symbols,
     AnalysisResult, AnalyzedModule,
 };
 
@@ -90,12 +98,13 @@ impl DocInfo {
 
         let ast = Ast::parse(arena, source).ok()?;
         let fmt = ast.fmt();
+        let formatted = roc_fmt::match_line_endings(source, fmt.as_str());
 
-        if source == fmt.as_str() {
+        if source == formatted.as_ref() {
             None
         } else {
             let range = self.whole_document_range();
-            let text_edit = TextEdit::new(range, fmt.to_string().to_string());
+            let text_edit = TextEdit::new(range, formatted.into_owned());
             Some(vec![text_edit])
         }
     }
@@ -114,6 +123,89 @@ impl DocInfo {
             data,
         }))
     }
+
+    /// Evaluate the expression in the given range, in the context of this document.
+    pub fn evaluate(&self, range: Range) -> Result<String, String> {
+        let start = range.start.to_roc_position(&self.line_info).offset as usize;
+        let end = range.end.to_roc_position(&self.line_info).offset as usize;
+
+        let text = self
+            .source
+            .get(start..end)
+            .filter(|text| !text.trim().is_empty())
+            .ok_or_else(|| "there is no expression to evaluate".to_string())?;
+
+        let expression = Expression {
+            text,
+            column: range.start.character as usize,
+        };
+
+        evaluate(self.path(), &self.source, &[expression])
+            .pop()
+            .unwrap_or_else(|| Err("the expression wasn't evaluated".to_string()))
+    }
+
+    /// A hint after each top-level constant that shows its value.
+    pub fn constant_value_hints(&self) -> Vec<InlayHint> {
+        let constants = top_level_constants(&self.source);
+
+        let expressions = constants
+            .iter()
+            .map(|(name, _)| Expression {
+                text: name,
+                column: 0,
+            })
+            .collect::<Vec<_>>();
+
+        let values = evaluate(self.path(), &self.source, &expressions);
+
+        constants
+            .iter()
+            .zip(values)
+            .filter_map(|((_, region), value)| {
+                let position = region.to_range(&self.line_info).end;
+
+                Some(value_hint(position, value.ok()?))
+            })
+            .collect()
+    }
+
+    fn path(&self) -> std::path::PathBuf {
+        self.url
+            .to_file_path()
+            .unwrap_or_else(|_| std::path::PathBuf::from(self.url.path()))
+    }
+}
+
+/// A hint that shows a value at the end of the expression it came from.
+pub(crate) fn value_hint(position: Position, value: String) -> InlayHint {
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(format!("= {value}")),
+        kind: None,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
+}
+
+/// A hint that shows the inferred type of a name right after it.
+fn type_hint(position: Position, type_str: &str) -> InlayHint {
+    // Long types are rendered on several lines, but a hint has to fit on one.
+    let type_str = type_str.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(format!(": {type_str}")),
+        kind: Some(InlayHintKind::TYPE),
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
 }
 
 impl AnalyzedDocument {
@@ -178,25 +270,56 @@ impl AnalyzedDocument {
         let (region, var) = roc_can::traverse::find_closest_type_at(pos, declarations)?;
 
         //TODO: Can this be integrated into "find closest type"? Is it worth it?
-        let docs_opt = self.symbol_at(position).and_then(|symbol| {
+        let symbol = self.symbol_at(position);
+
+        let docs_opt = symbol.and_then(|symbol| {
             modules_info
-                .get_docs(module_id)?
+                .get_docs(&symbol.module_id())?
                 .get_doc_for_symbol(&symbol)
         });
 
-        let type_str = format_var_type(var, &mut subs.clone(), module_id, interns);
+        let inferred = format_var_type(var, &mut subs.clone(), module_id, interns);
 
-        let range = region.to_range(self.line_info());
+        // The annotation, as written, of a def in this module
+        let declared = symbol.and_then(|symbol| {
+            let declaration = roc_can::traverse::find_declaration(symbol, declarations)?;
+            let region = declaration.annotation()?.region;
 
-        let type_content = MarkedString::LanguageString(LanguageString {
-            language: "roc".to_string(),
-            value: type_str,
+            let annotation = self.doc_info.source.get(region.byte_range())?;
+
+            Some(format!(
+                "{} : {}",
+                symbol.as_str(interns),
+                annotation.trim()
+            ))
         });
 
-        let content = vec![Some(type_content), docs_opt.map(MarkedString::String)]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
+        let inferred = match symbol {
+            Some(symbol) if declared.is_some() => {
+                format!("{} : {}", symbol.as_str(interns), inferred)
+            }
+            _ => inferred,
+        };
+
+        let range = region.to_range(self.line_info());
+
+        let type_content = |value| {
+            MarkedString::LanguageString(LanguageString {
+                language: "roc".to_string(),
+                value,
+            })
+        };
+
+        let declared = declared.filter(|declared| declared != &inferred);
+
+        let content = vec![
+            declared.map(type_content),
+            Some(type_content(inferred)),
+            docs_opt.map(MarkedString::String),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
         Some(Hover {
             contents: HoverContents::Array(content),
@@ -204,6 +327,89 @@ impl AnalyzedDocument {
         })
     }
 
+    /// The type of the function applied where the cursor is, with the argument it's in highlighted.
+    pub fn signature_help(&self, position: Position) -> Option<SignatureHelp> {
+        let pos = position.to_roc_position(self.line_info());
+
+        let AnalyzedModule {
+            subs,
+            declarations,
+            module_id,
+            interns,
+            modules_info,
+            ..
+        } = self.module()?;
+
+        let application = application_at(&self.doc_info.source, pos)?;
+        let callee_start = application.callee.start();
+
+        let var =
+            roc_can::traverse::find_type_at(application.callee, declarations).or_else(|| {
+                roc_can::traverse::find_closest_type_at(callee_start, declarations)
+                    .map(|(_, var)| var)
+            })?;
+
+        let (args, ret) = format_function_type(var, &mut subs.clone(), module_id, interns)?;
+
+        let symbol = self.symbol_at(application.callee.to_range(self.line_info()).start);
+
+        let docs = symbol.and_then(|symbol| {
+            modules_info
+                .get_docs(&symbol.module_id())?
+                .get_doc_for_symbol(&symbol)
+        });
+
+        Some(signature_help(
+            symbol.map(|symbol| symbol.as_str(interns)),
+            args,
+            ret,
+            docs,
+            application.active_argument,
+        ))
+    }
+
+    /// An edit that removes unused imports, adds the missing ones we can find and sorts them.
+    pub fn organize_imports(&self) -> Option<Vec<TextEdit>> {
+        let AnalyzedModule { import_fixes, .. } = self.module()?;
+
+        let source = &self.doc_info.source;
+        let organized = organize_imports(source, import_fixes)?;
+
+        if source == &organized {
+            None
+        } else {
+            let range = self.doc_info.whole_document_range();
+            Some(vec![TextEdit::new(range, organized)])
+        }
+    }
+
+    /// A hint with the inferred type of each top-level def without an annotation, and of each
+    /// parameter of a lambda inside a def.
+    pub fn type_hints(&self) -> Vec<InlayHint> {
+        let Some(AnalyzedModule {
+            subs,
+            declarations,
+            module_id,
+            interns,
+            ..
+        }) = self.module()
+        else {
+            return vec![];
+        };
+
+        let mut subs = subs.clone();
+
+        inferred_types(declarations)
+            .into_iter()
+            .map(|(region, var)| {
+                let type_str = format_var_type(var, &mut subs, module_id, interns);
+                let position = region.to_range(self.line_info()).end;
+
+                type_hint(position, &type_str)
+            })
+            .collect()
+    }
+
     pub fn definition(&self, symbol: Symbol) -> Option<GotoDefinitionResponse> {
         let AnalyzedModule { declarations, .. } = self.module()?;
 
@@ -214,6 +420,132 @@ impl AnalyzedDocument {
         Some(GotoDefinitionResponse::Scalar(self.location(range)))
     }
 
+    /// The item for the top-level def with the given name.
+    pub fn call_hierarchy_item(&self, name: &str) -> Option<CallHierarchyItem> {
+        let AnalyzedModule {
+            declarations,
+            module_id,
+            interns,
+            ..
+        } = self.module()?;
+
+        let index = top_level_def(declarations, interns, name)?;
+
+        Some(call_hierarchy_item(
+            declarations,
+            index,
+            name,
+            interns.module_name(*module_id),
+            self.url(),
+            self.line_info(),
+        ))
+    }
+
+    /// The url of the document that defines the symbol at the position, and the symbol's name.
+    pub fn call_hierarchy_target(&self, position: Position) -> Option<(Url, String)> {
+        let symbol = self.symbol_at(position)?;
+        let url = self.module_url(symbol.module_id())?;
+        let AnalyzedModule { interns, .. } = self.module()?;
+
+        Some((url, symbol.as_str(interns).to_string()))
+    }
+
+    /// The calls made from this document to the def with the given name in the given document,
+    /// grouped by the def they are made from.
+    pub fn incoming_calls(&self, url: &Url, name: &str) -> Vec<CallHierarchyIncomingCall> {
+        let Some(AnalyzedModule {
+            declarations,
+            module_id,
+            interns,
+            calls,
+            module_id_to_url,
+            ..
+        }) = self.module()
+        else {
+            return vec![];
+        };
+
+        let incoming = calls.iter().filter_map(|call| {
+            let callee_url = module_id_to_url.get(&call.callee.module_id())?;
+
+            (callee_url == url && call.callee.as_str(interns) == name)
+                .then_some((call.caller, call.region))
+        });
+
+        group_calls(incoming, self.line_info())
+            .into_iter()
+            .filter_map(|(caller, from_ranges)| {
+                let caller_name = caller.as_str(interns);
+                let index = top_level_def(declarations, interns, caller_name)?;
+
+                let from = call_hierarchy_item(
+                    declarations,
+                    index,
+                    caller_name,
+                    interns.module_name(*module_id),
+                    self.url(),
+                    self.line_info(),
+                );
+
+                Some(CallHierarchyIncomingCall { from, from_ranges })
+            })
+            .collect()
+    }
+
+    /// The defs called from the def with the given name, as the url of the document each is in
+    /// and its name, along with the ranges of the calls.
+    pub fn outgoing_calls(&self, name: &str) -> Vec<(Url, String, Vec<Range>)> {
+        let Some(AnalyzedModule {
+            interns,
+            calls,
+            module_id_to_url,
+            ..
+        }) = self.module()
+        else {
+            return vec![];
+        };
+
+        // Builtins have no document to show.
+        let outgoing = calls
+            .iter()
+            .filter(|call| call.caller.as_str(interns) == name)
+            .filter_map(|call| {
+                let callee_url = module_id_to_url.get(&call.callee.module_id())?;
+
+                Some((
+                    (callee_url.clone(), call.callee.as_str(interns).to_string()),
+                    call.region,
+                ))
+            });
+
+        group_calls(outgoing, self.line_info())
+            .into_iter()
+            .map(|((url, name), ranges)| (url, name, ranges))
+            .collect()
+    }
+
+    /// The types, abilities and exposed values this document's module defines whose names match
+    /// the query.
+    pub fn workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        let Some(AnalyzedModule {
+            module_id,
+            interns,
+            symbol_index,
+            ..
+        }) = self.module()
+        else {
+            return vec![];
+        };
+
+        workspace_symbols(
+            query,
+            symbol_index,
+            interns.module_name(*module_id),
+            self.url(),
+            self.line_info(),
+        )
+    }
+
     pub(crate) fn module_url(&self, module_id: ModuleId) -> Option<Url> {
         self.module()?.module_id_to_url.get(&module_id).cloned()
     }
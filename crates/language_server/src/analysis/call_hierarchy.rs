@@ -0,0 +1,77 @@
+//! Finding the defs of a module for call hierarchies, and grouping the calls made to and from them.
+//!
+//! Items are identified by the url of their document and the name of their def, because symbols
+//! from different analyses of a document are not comparable.
+
+use roc_can::expr::{DeclarationTag, Declarations};
+use roc_module::symbol::Interns;
+use roc_region::all::{LineInfo, Region};
+use tower_lsp::lsp_types::{CallHierarchyItem, Range, SymbolKind, Url};
+
+use crate::convert::ToRange;
+
+/// The index of the top-level def with the given name.
+pub(super) fn top_level_def(
+    declarations: &Declarations,
+    interns: &Interns,
+    name: &str,
+) -> Option<usize> {
+    declarations
+        .symbols
+        .iter()
+        .position(|loc_symbol| loc_symbol.value.as_str(interns) == name)
+}
+
+pub(super) fn call_hierarchy_item(
+    declarations: &Declarations,
+    index: usize,
+    name: &str,
+    module_name: &str,
+    url: &Url,
+    line_info: &LineInfo,
+) -> CallHierarchyItem {
+    let loc_symbol = declarations.symbols[index];
+
+    let range = match roc_can::traverse::find_declaration(loc_symbol.value, declarations) {
+        Some(declaration) => declaration.region(),
+        None => loc_symbol.region,
+    };
+
+    let kind = match declarations.declarations[index] {
+        DeclarationTag::Function(_)
+        | DeclarationTag::Recursive(_)
+        | DeclarationTag::TailRecursive(_) => SymbolKind::FUNCTION,
+        _ => SymbolKind::CONSTANT,
+    };
+
+    CallHierarchyItem {
+        name: name.to_string(),
+        kind,
+        tags: None,
+        detail: Some(module_name.to_string()),
+        uri: url.clone(),
+        range: range.to_range(line_info),
+        selection_range: loc_symbol.region.to_range(line_info),
+        data: None,
+    }
+}
+
+/// The regions of the calls grouped by what they have in common, in the order each group is
+/// first seen.
+pub(super) fn group_calls<K: PartialEq>(
+    calls: impl IntoIterator<Item = (K, Region)>,
+    line_info: &LineInfo,
+) -> Vec<(K, Vec<Range>)> {
+    let mut groups: Vec<(K, Vec<Range>)> = Vec::new();
+
+    for (key, region) in calls {
+        let range = region.to_range(line_info);
+
+        match groups.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, ranges)) => ranges.push(range),
+            None => groups.push((key, vec![range])),
+        }
+    }
+
+    groups
+}
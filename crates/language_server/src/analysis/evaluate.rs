@@ -0,0 +1,269 @@
+//! Evaluating pure expressions from a document, to show their values in the editor.
+//!
+//! The expressions are added to a copy of the document as the values an app provides, each one
+//! wrapped in `Inspect.toStr`. That copy is monomorphized and the mono IR interpreter runs the
+//! new values, so nothing has to be compiled to machine code. Whatever the interpreter can't
+//! evaluate, like effects, is reported as an error instead.
+//!
+//! The copy keeps the document's imports, but not the packages of an app's header, so
+//! expressions can't use anything from the platform.
+
+use bumpalo::Bump;
+use roc_fmt::Buf;
+use roc_load::{
+    EntryPoint, ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, Threading,
+};
+use roc_mono::interpret::{InterpretError, Interpreter, Value};
+use roc_mono::layout::LambdaName;
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_parse::{
+    ast::{Defs, Expr, ExtractSpaces, Pattern, ValueDef},
+    module::{parse_header, parse_module_defs},
+    state::State,
+};
+use roc_region::all::Region;
+
+use crate::analysis::workspace_target;
+
+/// The name of the def that evaluates the expression at the given index.
+const EVALUATED_PREFIX: &str = "rocLsEvaluated";
+
+/// How many statements the interpreter may run for each expression, so a long computation
+/// doesn't keep the language server busy.
+const FUEL_PER_EXPRESSION: usize = 10_000_000;
+
+/// The stack of the thread that evaluates. Loading and interpreting recurse deeply, more than
+/// the 2MiB that tokio's blocking threads have allow.
+const EVALUATION_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+const INDENT: &str = "        ";
+
+/// An expression to evaluate, and the column its first line starts at in the document.
+pub(crate) struct Expression<'s> {
+    pub text: &'s str,
+    pub column: usize,
+}
+
+/// Evaluate the expressions in the context of the document at `path`, and render each value the
+/// way `Inspect.toStr` does. This runs on a thread of its own, so a crash in the compiler becomes
+/// an error instead of taking the language server down.
+pub(crate) fn evaluate(
+    path: std::path::PathBuf,
+    source: &str,
+    expressions: &[Expression<'_>],
+) -> Vec<Result<String, String>> {
+    let evaluated = std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .name("roc_ls_evaluate".to_string())
+            .stack_size(EVALUATION_STACK_SIZE)
+            .spawn_scoped(scope, || evaluate_on_this_thread(path, source, expressions))
+            .map(|thread| thread.join())
+    });
+
+    match evaluated {
+        Ok(Ok(values)) => values,
+        Ok(Err(_)) => errors(expressions, "the compiler crashed"),
+        Err(_) => errors(expressions, "there is no thread to evaluate on"),
+    }
+}
+
+fn evaluate_on_this_thread(
+    path: std::path::PathBuf,
+    source: &str,
+    expressions: &[Expression<'_>],
+) -> Vec<Result<String, String>> {
+    let arena = Bump::new();
+
+    let module_src = match promote_to_app(&arena, source, expressions) {
+        Some(module_src) => module_src,
+        None => return errors(expressions, "the document doesn't parse"),
+    };
+
+    let src_dir = path.parent().unwrap_or(path.as_path()).to_path_buf();
+
+    let loaded = roc_load::load_and_monomorphize_from_str(
+        &arena,
+        path,
+        module_src,
+        src_dir,
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        LoadConfig {
+            target: workspace_target(),
+            function_kind: FunctionKind::LambdaSet,
+            render: roc_reporting::report::RenderTarget::LanguageServer,
+            palette: roc_reporting::report::DEFAULT_PALETTE,
+            threading: Threading::Single,
+            exec_mode: ExecutionMode::Executable,
+            optimize_ir: false,
+            report_expects_with_dbg: false,
+            cancellation: None,
+            problem_sink: None,
+            defines: Default::default(),
+        },
+    );
+
+    let loaded = match loaded {
+        Ok(loaded) => loaded,
+        Err(LoadMonomorphizedError::ErrorModule(_))
+        | Err(LoadMonomorphizedError::LoadingProblem(_)) => {
+            return errors(expressions, "the document has errors")
+        }
+    };
+
+    if loaded
+        .type_problems
+        .values()
+        .any(|problems| !problems.is_empty())
+    {
+        return errors(expressions, "the document has type errors");
+    }
+
+    let exposed_to_host = match loaded.entry_point {
+        EntryPoint::Executable {
+            exposed_to_host, ..
+        } => exposed_to_host,
+        EntryPoint::Test => return errors(expressions, "there is nothing to evaluate"),
+    };
+
+    (0..expressions.len())
+        .map(|index| {
+            let name = format!("{EVALUATED_PREFIX}{index}");
+
+            let (symbol, layout) = exposed_to_host
+                .iter()
+                .find(|(symbol, _)| symbol.as_str(&loaded.interns) == name)
+                .ok_or_else(|| "the expression wasn't compiled".to_string())?;
+
+            let mut interpreter = Interpreter::for_constants(
+                &loaded.procedures,
+                &loaded.layout_interner,
+                FUEL_PER_EXPRESSION,
+            );
+
+            match interpreter.call(LambdaName::no_niche(*symbol), *layout, Vec::new()) {
+                Ok(Value::Str(rendered)) => Ok(rendered.to_string()),
+                Ok(_) => Err("the value couldn't be rendered".to_string()),
+                Err(InterpretError::Unsupported(what)) => {
+                    Err(format!("evaluating {what} needs a compiled program"))
+                }
+                Err(InterpretError::Crash(message)) => Err(format!("crashed: {message}")),
+            }
+        })
+        .collect()
+}
+
+fn errors(expressions: &[Expression<'_>], message: &str) -> Vec<Result<String, String>> {
+    expressions
+        .iter()
+        .map(|_| Err(message.to_string()))
+        .collect()
+}
+
+/// The names and regions of the top-level defs in the document that aren't functions.
+pub(crate) fn top_level_constants(source: &str) -> Vec<(String, Region)> {
+    let arena = Bump::new();
+
+    let Ok((module, state)) = parse_header(&arena, State::new(source.as_bytes())) else {
+        return vec![];
+    };
+
+    let (_, defs) = module.upgrade_header_imports(&arena);
+
+    let Ok(defs) = parse_module_defs(&arena, state, defs) else {
+        return vec![];
+    };
+
+    defs.value_defs
+        .iter()
+        .filter_map(|value_def| {
+            let (pattern, body) = match value_def {
+                ValueDef::Body(pattern, body) => (*pattern, *body),
+                ValueDef::AnnotatedBody {
+                    body_pattern,
+                    body_expr,
+                    ..
+                } => (*body_pattern, *body_expr),
+                _ => return None,
+            };
+
+            match (pattern.value, body.value.extract_spaces().item) {
+                (_, Expr::Closure(..)) => None,
+                (Pattern::Identifier { ident }, _) => Some((ident.to_string(), body.region)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Turn the document into an app that provides the values of the expressions.
+fn promote_to_app<'a>(
+    arena: &'a Bump,
+    source: &'a str,
+    expressions: &[Expression<'_>],
+) -> Option<&'a str> {
+    let (module, state) = parse_header(arena, State::new(source.as_bytes())).ok()?;
+    let body = &source[state.pos().offset as usize..];
+
+    // Imports in the header become `import` defs, which still work in an app.
+    let (_, imports): (_, Defs) = module.upgrade_header_imports(arena);
+
+    let provided = (0..expressions.len())
+        .map(|index| format!("{EVALUATED_PREFIX}{index}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut buf = Buf::new_in(arena);
+    roc_fmt::def::fmt_defs(&mut buf, &imports, 0);
+
+    let mut module_src = bumpalo::collections::String::new_in(arena);
+
+    module_src.push_str(&format!(
+        "app \"evaluate\" provides [{provided}] to \"./platform\"\n\n"
+    ));
+    module_src.push_str(buf.as_str());
+    module_src.push('\n');
+    module_src.push_str(body);
+    module_src.push('\n');
+
+    for (index, expression) in expressions.iter().enumerate() {
+        module_src.push_str(&format!(
+            "\n{EVALUATED_PREFIX}{index} =\n    Inspect.toStr (\n"
+        ));
+
+        for line in dedent(expression) {
+            module_src.push_str(INDENT);
+            module_src.push_str(&line);
+            module_src.push('\n');
+        }
+
+        module_src.push_str("    )\n");
+    }
+
+    Some(module_src.into_bump_str())
+}
+
+/// The lines of the expression, with the indentation they have in common removed. The first
+/// line counts as indented to the column it starts at.
+fn dedent(expression: &Expression<'_>) -> Vec<String> {
+    let mut lines = expression
+        .text
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    if let Some(first) = lines.first_mut() {
+        first.insert_str(0, &" ".repeat(expression.column));
+    }
+
+    let common = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| line.get(common..).unwrap_or_default().to_string())
+        .collect()
+}
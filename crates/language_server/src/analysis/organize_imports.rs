@@ -0,0 +1,352 @@
+//! Organizing the imports of a document: removing the unused ones, merging the duplicate ones,
+//! adding the ones that can be found unambiguously, and sorting them by module name.
+//!
+//! What is unused or missing comes from the problems canonicalization reported. The new imports
+//! are put in place of the old ones in the parse tree, which is then printed by the formatter.
+
+use bumpalo::{collections::Vec as BumpVec, Bump};
+use roc_parse::{
+    ast::{
+        Collection, CommentOrNewline, Defs, ImportExposingKeyword, ImportedModuleName,
+        ModuleImport, Spaced, Spaces, ValueDef,
+    },
+    header::{ExposedName, KeywordItem, ModuleName},
+};
+use roc_problem::can::{Problem, RuntimeError};
+use roc_region::all::{Loc, Region};
+
+use super::parse_ast::Ast;
+
+/// The changes to a module's imports that its problems call for.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ImportFixes {
+    /// The regions of imports none of whose names are used
+    unused_imports: Vec<Region>,
+    /// The regions of names in an `exposing` list that are not used
+    unused_exposed: Vec<Region>,
+    /// Modules that are referred to by name, but not imported
+    missing_modules: Vec<String>,
+    /// Values that are not in scope, each with the only module that exposes one of that name
+    missing_exposed: Vec<(String, String)>,
+}
+
+impl ImportFixes {
+    /// `importable` lists the modules that could be imported by name, along with the values each
+    /// of them exposes.
+    pub(super) fn new(problems: &[Problem], importable: &[(String, Vec<String>)]) -> Self {
+        let mut fixes = ImportFixes::default();
+
+        for problem in problems {
+            match problem {
+                Problem::UnusedModuleImport(_, region) => fixes.unused_imports.push(*region),
+                Problem::UnusedImport(_, region) => fixes.unused_exposed.push(*region),
+                Problem::RuntimeError(RuntimeError::ModuleNotImported {
+                    module_name,
+                    module_exists: true,
+                    ..
+                }) => {
+                    let module_name = module_name.as_str().to_string();
+
+                    if !fixes.missing_modules.contains(&module_name) {
+                        fixes.missing_modules.push(module_name);
+                    }
+                }
+                Problem::RuntimeError(RuntimeError::LookupNotInScope { loc_name, .. }) => {
+                    let name = loc_name.value.as_str();
+
+                    let mut exposing = importable
+                        .iter()
+                        .filter(|(_, exposed)| exposed.iter().any(|exposed| exposed == name));
+
+                    if let (Some((module_name, _)), None) = (exposing.next(), exposing.next()) {
+                        let missing = (module_name.clone(), name.to_string());
+
+                        if !fixes.missing_exposed.contains(&missing) {
+                            fixes.missing_exposed.push(missing);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        fixes
+    }
+}
+
+/// The source with its imports organized, or `None` if it doesn't parse.
+pub(super) fn organize_imports(source: &str, fixes: &ImportFixes) -> Option<String> {
+    let arena = Bump::new();
+    let mut ast = Ast::parse(&arena, source).ok()?;
+
+    let organized = organize_defs(&arena, ast.defs(), fixes);
+    ast.set_defs(organized);
+
+    let fmt = ast.fmt();
+
+    Some(roc_fmt::match_line_endings(source, fmt.as_str()).into_owned())
+}
+
+/// An import to put in the organized list, and the comments that were above it.
+struct OrganizedImport<'a> {
+    import: ModuleImport<'a>,
+    comments: Vec<CommentOrNewline<'a>>,
+}
+
+fn organize_defs<'a>(arena: &'a Bump, defs: &Defs<'a>, fixes: &ImportFixes) -> Defs<'a> {
+    let spaces_before = |index: usize| &defs.spaces[defs.space_before[index].indices()];
+    let spaces_after = |index: usize| &defs.spaces[defs.space_after[index].indices()];
+
+    let import_indices: Vec<usize> = defs
+        .defs()
+        .enumerate()
+        .filter(|(_, def)| matches!(def, Err(ValueDef::ModuleImport(_))))
+        .map(|(index, _)| index)
+        .collect();
+
+    // The comments between two defs are in the spaces after the first one, but they are about
+    // the second one, so the spaces after an import move along with the def that follows it.
+    let moved_spaces = |index: usize| match index.checked_sub(1) {
+        Some(previous) if import_indices.contains(&previous) => spaces_after(previous),
+        _ => &[],
+    };
+
+    let mut imports: Vec<OrganizedImport> = Vec::new();
+
+    for (index, def) in defs.defs().enumerate() {
+        let Err(ValueDef::ModuleImport(import)) = def else {
+            continue;
+        };
+
+        if fixes.unused_imports.contains(&defs.regions[index]) {
+            continue;
+        }
+
+        let import = without_unused(arena, *import, &fixes.unused_exposed);
+        let comments = moved_spaces(index)
+            .iter()
+            .chain(spaces_before(index))
+            .filter(|space| space.is_comment())
+            .copied();
+
+        // A module imported twice under the same name is imported once, exposing what both did
+        let duplicate = imports
+            .iter_mut()
+            .find(|organized| same_module(&organized.import, &import));
+
+        match duplicate {
+            Some(organized) => {
+                for name in exposed_names(&import) {
+                    if !exposed_names(&organized.import).contains(&name) {
+                        organized.import = with_exposed(arena, organized.import, name);
+                    }
+                }
+
+                organized.comments.extend(comments);
+            }
+            None => imports.push(OrganizedImport {
+                import,
+                comments: comments.collect(),
+            }),
+        }
+    }
+
+    for module_name in fixes.missing_modules.iter() {
+        if !imports
+            .iter()
+            .any(|organized| imports_module(&organized.import, module_name))
+        {
+            imports.push(OrganizedImport {
+                import: new_import(arena, module_name),
+                comments: Vec::new(),
+            });
+        }
+    }
+
+    for (module_name, name) in fixes.missing_exposed.iter() {
+        let existing = imports
+            .iter_mut()
+            .find(|organized| imports_module(&organized.import, module_name));
+
+        match existing {
+            Some(organized) => organized.import = with_exposed(arena, organized.import, name),
+            None => imports.push(OrganizedImport {
+                import: with_exposed(arena, new_import(arena, module_name), name),
+                comments: Vec::new(),
+            }),
+        }
+    }
+
+    imports.sort_by_key(|organized| full_module_name(&organized.import));
+
+    // The imports go where the first one was, keeping the spaces before the whole list, and the
+    // ones after it if nothing follows the last import.
+    let (insert_at, leading) = match import_indices.first() {
+        Some(&first) => (first, spaces_before(first)),
+        None => (0, &[][..]),
+    };
+
+    let trailing = match import_indices.last() {
+        Some(&last) if last + 1 == defs.len() => spaces_after(last),
+        _ => &[],
+    };
+
+    let mut organized = Defs::default();
+
+    let mut push_imports = |organized: &mut Defs<'a>| {
+        let count = imports.len();
+
+        for (position, OrganizedImport { import, comments }) in imports.drain(..).enumerate() {
+            // The comments above each import move along with it
+            let mut before = if position == 0 {
+                leading
+                    .iter()
+                    .filter(|space| !space.is_comment())
+                    .copied()
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            if !comments.is_empty() && before.last() != Some(&CommentOrNewline::Newline) {
+                before.push(CommentOrNewline::Newline);
+            }
+
+            before.extend(comments);
+
+            let after = if position + 1 == count { trailing } else { &[] };
+
+            organized.push_value_def(
+                ValueDef::ModuleImport(import),
+                Region::zero(),
+                &before,
+                after,
+            );
+        }
+    };
+
+    for (index, def) in defs.defs().enumerate() {
+        if index == insert_at {
+            push_imports(&mut organized);
+        }
+
+        match def {
+            Err(ValueDef::ModuleImport(_)) => {}
+            Err(value_def) => organized.push_value_def(
+                *value_def,
+                defs.regions[index],
+                &[moved_spaces(index), spaces_before(index)].concat(),
+                spaces_after(index),
+            ),
+            Ok(type_def) => organized.push_type_def(
+                *type_def,
+                defs.regions[index],
+                &[moved_spaces(index), spaces_before(index)].concat(),
+                spaces_after(index),
+            ),
+        }
+    }
+
+    // Without any other defs, the imports haven't been pushed yet
+    push_imports(&mut organized);
+
+    organized
+}
+
+fn without_unused<'a>(
+    arena: &'a Bump,
+    import: ModuleImport<'a>,
+    unused_exposed: &[Region],
+) -> ModuleImport<'a> {
+    let Some(exposed) = import.exposed else {
+        return import;
+    };
+
+    let used = exposed
+        .item
+        .iter()
+        .filter(|name| !unused_exposed.contains(&name.region))
+        .copied();
+
+    let used = BumpVec::from_iter_in(used, arena).into_bump_slice();
+
+    ModuleImport {
+        exposed: (!used.is_empty()).then_some(KeywordItem {
+            item: exposed.item.replace_items(used),
+            ..exposed
+        }),
+        ..import
+    }
+}
+
+fn with_exposed<'a>(arena: &'a Bump, import: ModuleImport<'a>, name: &str) -> ModuleImport<'a> {
+    let new_name = Loc::at_zero(Spaced::Item(ExposedName::new(arena.alloc_str(name))));
+
+    let exposed = match import.exposed {
+        Some(exposed) => {
+            let items = exposed.item.iter().copied().chain([new_name]);
+
+            KeywordItem {
+                item: exposed
+                    .item
+                    .replace_items(BumpVec::from_iter_in(items, arena).into_bump_slice()),
+                ..exposed
+            }
+        }
+        None => KeywordItem {
+            keyword: Spaces {
+                before: &[],
+                item: ImportExposingKeyword,
+                after: &[],
+            },
+            item: Collection::with_items(arena.alloc_slice_copy(&[new_name])),
+        },
+    };
+
+    ModuleImport {
+        exposed: Some(exposed),
+        ..import
+    }
+}
+
+fn new_import<'a>(arena: &'a Bump, module_name: &str) -> ModuleImport<'a> {
+    ModuleImport {
+        before_name: &[],
+        name: Loc::at_zero(ImportedModuleName {
+            package: None,
+            name: ModuleName::new(arena.alloc_str(module_name)),
+        }),
+        alias: None,
+        exposed: None,
+    }
+}
+
+fn imports_module(import: &ModuleImport<'_>, module_name: &str) -> bool {
+    import.name.value.package.is_none() && import.name.value.name.as_str() == module_name
+}
+
+fn same_module(import: &ModuleImport<'_>, other: &ModuleImport<'_>) -> bool {
+    full_module_name(import) == full_module_name(other) && alias(import) == alias(other)
+}
+
+fn alias<'b>(import: &'b ModuleImport<'_>) -> Option<&'b str> {
+    import.alias.as_ref().map(|alias| alias.item.value.as_str())
+}
+
+fn exposed_names<'b>(import: &'b ModuleImport<'_>) -> Vec<&'b str> {
+    import
+        .exposed
+        .iter()
+        .flat_map(|exposed| exposed.item.iter())
+        .map(|name| name.value.item().as_str())
+        .collect()
+}
+
+fn full_module_name(import: &ModuleImport<'_>) -> String {
+    let ImportedModuleName { package, name } = import.name.value;
+
+    match package {
+        Some(package) => format!("{package}.{}", name.as_str()),
+        None => name.as_str().to_string(),
+    }
+}
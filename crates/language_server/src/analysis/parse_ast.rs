@@ -37,6 +37,14 @@ impl<'a> Ast<'a> {
         })
     }
 
+    pub fn defs(&self) -> &Defs<'a> {
+        &self.defs
+    }
+
+    pub fn set_defs(&mut self, defs: Defs<'a>) {
+        self.defs = defs;
+    }
+
     pub fn fmt(&self) -> FormattedAst<'a> {
         let mut buf = Buf::new_in(self.arena);
 
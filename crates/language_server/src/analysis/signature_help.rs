@@ -0,0 +1,288 @@
+//! Finding the function application the cursor is in, for signature help.
+//!
+//! This works on the parse tree rather than the canonical one, because canonicalization turns
+//! pipes and operators into plain calls, and we want the arguments as the user wrote them.
+
+use bumpalo::Bump;
+use roc_module::called_via::BinOp;
+use roc_parse::ast::{
+    AssignedField, Collection, Defs, Expr, RecordBuilderField, ValueDef, WhenBranch,
+};
+use roc_region::all::{Loc, Position, Region};
+use tower_lsp::lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureInformation,
+};
+
+use super::parse_ast::Ast;
+
+/// A function application that contains the cursor.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Application {
+    pub callee: Region,
+    /// The argument the cursor is in, counting one that is piped in with `|>`.
+    pub active_argument: usize,
+}
+
+/// The innermost application around the position in the source. A function followed by a space
+/// counts as an application without arguments yet, since that is how one starts out.
+pub(super) fn application_at(source: &str, position: Position) -> Option<Application> {
+    let arena = Bump::new();
+    let ast = Ast::parse(&arena, source).ok()?;
+
+    let finder = Finder { source, position };
+
+    finder.in_defs(ast.defs())
+}
+
+/// A signature for the function type, with the argument at the given index highlighted.
+pub(super) fn signature_help(
+    name: Option<&str>,
+    args: Vec<String>,
+    ret: String,
+    docs: Option<String>,
+    active_argument: usize,
+) -> SignatureHelp {
+    let mut label = match name {
+        Some(name) => format!("{name} : "),
+        None => String::new(),
+    };
+
+    let mut parameters = Vec::with_capacity(args.len());
+
+    for (index, arg) in args.iter().enumerate() {
+        if index > 0 {
+            label.push_str(", ");
+        }
+
+        // Clients count offsets in UTF-16 code units.
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(arg);
+        let end = label.encode_utf16().count() as u32;
+
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+
+    label.push_str(" -> ");
+    label.push_str(&ret);
+
+    let active_parameter = (active_argument < args.len()).then_some(active_argument as u32);
+
+    let signature = SignatureInformation {
+        label,
+        documentation: docs.map(|value| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            })
+        }),
+        parameters: Some(parameters),
+        active_parameter,
+    };
+
+    SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter,
+    }
+}
+
+struct Finder<'s> {
+    source: &'s str,
+    position: Position,
+}
+
+impl Finder<'_> {
+    /// Whether the cursor is in the region, or after it with only spaces in between.
+    fn reaches(&self, region: Region) -> bool {
+        region.contains_pos(self.position) || self.follows(region)
+    }
+
+    /// Whether the cursor is after the region, separated from it by spaces on the same line.
+    fn follows(&self, region: Region) -> bool {
+        let start = region.end().offset as usize;
+        let end = self.position.offset as usize;
+
+        match self.source.get(start..end) {
+            Some(between) => !between.is_empty() && between.chars().all(|c| c == ' ' || c == '\t'),
+            None => false,
+        }
+    }
+
+    fn in_defs(&self, defs: &Defs<'_>) -> Option<Application> {
+        defs.value_defs.iter().find_map(|value_def| {
+            let loc_expr = match value_def {
+                ValueDef::Body(_, body) => *body,
+                ValueDef::AnnotatedBody { body_expr, .. } => *body_expr,
+                ValueDef::Dbg { condition, .. }
+                | ValueDef::Expect { condition, .. }
+                | ValueDef::ExpectFx { condition, .. } => *condition,
+                ValueDef::Stmt(loc_expr) => *loc_expr,
+                ValueDef::Annotation(..)
+                | ValueDef::ModuleImport(_)
+                | ValueDef::IngestedFileImport(_) => return None,
+            };
+
+            self.in_loc_expr(loc_expr)
+        })
+    }
+
+    fn in_loc_expr(&self, loc_expr: &Loc<Expr<'_>>) -> Option<Application> {
+        self.in_expr(loc_expr.region, &loc_expr.value, false)
+    }
+
+    fn in_loc_exprs<'e, 'a: 'e>(
+        &self,
+        loc_exprs: impl IntoIterator<Item = &'e Loc<Expr<'a>>>,
+    ) -> Option<Application> {
+        loc_exprs
+            .into_iter()
+            .find_map(|loc_expr| self.in_loc_expr(loc_expr))
+    }
+
+    /// `piped` is whether the expression is on the right of a `|>`, which passes it a first
+    /// argument.
+    fn in_expr(&self, region: Region, expr: &Expr<'_>, piped: bool) -> Option<Application> {
+        if !self.reaches(region) {
+            return None;
+        }
+
+        let piped_argument = piped as usize;
+
+        match expr {
+            Expr::Apply(callee, args, _) => {
+                // The cursor has to be inside an argument to be in an application there, or the
+                // argument would count as a function being applied to nothing yet.
+                let in_arg = args
+                    .iter()
+                    .filter(|arg| arg.region.contains_pos(self.position))
+                    .find_map(|arg| self.in_loc_expr(arg));
+
+                in_arg.or_else(|| {
+                    if self.position <= callee.region.end() {
+                        return self.in_loc_expr(callee);
+                    }
+
+                    let before_cursor = args
+                        .iter()
+                        .filter(|arg| arg.region.end() < self.position)
+                        .count();
+
+                    Some(Application {
+                        callee: callee.region,
+                        active_argument: before_cursor + piped_argument,
+                    })
+                })
+            }
+            Expr::Var { .. } | Expr::AccessorFunction(_) | Expr::Tag(_) | Expr::OpaqueRef(_) => {
+                self.follows(region).then_some(Application {
+                    callee: region,
+                    active_argument: piped_argument,
+                })
+            }
+            Expr::BinOps(operands, last) => {
+                let mut preceding = None;
+
+                for (operand, op) in operands.iter() {
+                    let found = self.in_expr(
+                        operand.region,
+                        &operand.value,
+                        preceding == Some(BinOp::Pizza),
+                    );
+
+                    if found.is_some() {
+                        return found;
+                    }
+
+                    preceding = Some(op.value);
+                }
+
+                self.in_expr(last.region, &last.value, preceding == Some(BinOp::Pizza))
+            }
+            Expr::SpaceBefore(inner, _) | Expr::SpaceAfter(inner, _) => {
+                self.in_expr(region, inner, piped)
+            }
+            Expr::ParensAround(inner)
+            | Expr::RecordAccess(inner, _)
+            | Expr::TupleAccess(inner, _)
+            | Expr::TaskAwaitBang(inner) => self.in_expr(region, inner, false),
+            Expr::List(items) | Expr::Tuple(items) => self.in_loc_exprs(items.iter().copied()),
+            Expr::Record(fields) => self.in_fields(fields),
+            Expr::RecordUpdate { update, fields } => {
+                self.in_loc_expr(update).or_else(|| self.in_fields(fields))
+            }
+            Expr::RecordBuilder(fields) => fields
+                .iter()
+                .find_map(|field| self.in_builder_field(&field.value)),
+            Expr::Closure(_, body) => self.in_loc_expr(body),
+            Expr::Defs(defs, final_expr) => {
+                self.in_defs(defs).or_else(|| self.in_loc_expr(final_expr))
+            }
+            Expr::Backpassing(_, e1, e2)
+            | Expr::Expect(e1, e2)
+            | Expr::Dbg(e1, e2)
+            | Expr::LowLevelDbg(_, e1, e2) => self.in_loc_exprs([*e1, *e2]),
+            Expr::UnaryOp(inner, _)
+            | Expr::MalformedSuffixed(inner)
+            | Expr::MultipleRecordBuilders(inner)
+            | Expr::UnappliedRecordBuilder(inner) => self.in_loc_expr(inner),
+            Expr::If(branches, final_else) => branches
+                .iter()
+                .find_map(|(condition, then)| self.in_loc_exprs([condition, then]))
+                .or_else(|| self.in_loc_expr(final_else)),
+            Expr::When(condition, branches) => self
+                .in_loc_expr(condition)
+                .or_else(|| branches.iter().find_map(|branch| self.in_branch(branch))),
+            Expr::Float(_)
+            | Expr::Num(_)
+            | Expr::NonBase10Int { .. }
+            | Expr::Str(_)
+            | Expr::SingleQuote(_)
+            | Expr::Underscore(_)
+            | Expr::Crash
+            | Expr::EmptyDefsFinal
+            | Expr::MalformedIdent(..)
+            | Expr::MalformedClosure
+            | Expr::PrecedenceConflict(_) => None,
+        }
+    }
+
+    fn in_branch(&self, branch: &WhenBranch<'_>) -> Option<Application> {
+        branch
+            .guard
+            .as_ref()
+            .and_then(|guard| self.in_loc_expr(guard))
+            .or_else(|| self.in_loc_expr(&branch.value))
+    }
+
+    fn in_fields(
+        &self,
+        fields: &Collection<'_, Loc<AssignedField<'_, Expr<'_>>>>,
+    ) -> Option<Application> {
+        fields.iter().find_map(|field| self.in_field(&field.value))
+    }
+
+    fn in_field(&self, field: &AssignedField<'_, Expr<'_>>) -> Option<Application> {
+        match field {
+            AssignedField::RequiredValue(_, _, value)
+            | AssignedField::OptionalValue(_, _, value) => self.in_loc_expr(value),
+            AssignedField::SpaceBefore(inner, _) | AssignedField::SpaceAfter(inner, _) => {
+                self.in_field(inner)
+            }
+            AssignedField::LabelOnly(_) | AssignedField::Malformed(_) => None,
+        }
+    }
+
+    fn in_builder_field(&self, field: &RecordBuilderField<'_>) -> Option<Application> {
+        match field {
+            RecordBuilderField::Value(_, _, value)
+            | RecordBuilderField::ApplyValue(_, _, _, value) => self.in_loc_expr(value),
+            RecordBuilderField::SpaceBefore(inner, _)
+            | RecordBuilderField::SpaceAfter(inner, _) => self.in_builder_field(inner),
+            RecordBuilderField::LabelOnly(_) | RecordBuilderField::Malformed(_) => None,
+        }
+    }
+}
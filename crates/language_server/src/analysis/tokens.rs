@@ -266,6 +266,7 @@ impl IterTokens for PlatformHeader<'_> {
             packages,
             imports,
             provides,
+            unavailable,
         } = self;
 
         (name.iter_tokens(arena).into_iter())
@@ -274,6 +275,11 @@ impl IterTokens for PlatformHeader<'_> {
             .chain(packages.item.iter_tokens(arena))
             .chain(imports.item.iter_tokens(arena))
             .chain(provides.item.iter_tokens(arena))
+            .chain(
+                unavailable
+                    .iter()
+                    .flat_map(|unavailable| unavailable.item.iter_tokens(arena)),
+            )
             .collect_in(arena)
     }
 }
@@ -314,6 +320,7 @@ impl IterTokens for Loc<Spaced<'_, PackageEntry<'_>>> {
             spaces_after_shorthand: _,
             platform_marker: _,
             package_name,
+            target: _,
         } = self.value.item();
 
         package_name.iter_tokens(arena)
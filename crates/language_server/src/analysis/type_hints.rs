@@ -0,0 +1,73 @@
+//! Finding the names whose types are inferred rather than written down, to show those types
+//! in the editor.
+
+use roc_can::{
+    expr::{ClosureData, DeclarationTag, Declarations, Expr},
+    pattern::Pattern,
+    traverse::{walk_expr, Visitor},
+};
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+/// The regions of the names of un-annotated top-level defs and of the parameters of lambdas
+/// nested in defs, each with the variable of its type.
+pub(super) fn inferred_types(declarations: &Declarations) -> Vec<(Region, Variable)> {
+    let mut inferred = Vec::new();
+
+    for (index, tag) in declarations.declarations.iter().enumerate() {
+        let is_def = matches!(
+            tag,
+            DeclarationTag::Value
+                | DeclarationTag::Function(_)
+                | DeclarationTag::Recursive(_)
+                | DeclarationTag::TailRecursive(_)
+        );
+
+        // Ability implementations get their types from the ability.
+        let is_specialization = declarations.specializes.contains_key(&index);
+
+        let loc_symbol = declarations.symbols[index];
+
+        if is_def
+            && !is_specialization
+            && declarations.annotations[index].is_none()
+            && !loc_symbol.region.is_empty()
+        {
+            inferred.push((loc_symbol.region, declarations.variables[index]));
+        }
+    }
+
+    // The parameters of top-level functions are part of the type of the def, so only those of
+    // nested lambdas are visited.
+    let mut visitor = LambdaParameters {
+        parameters: Vec::new(),
+    };
+    visitor.visit_decls(declarations);
+
+    inferred.extend(visitor.parameters);
+    inferred.sort_by_key(|(region, _)| region.start());
+
+    inferred
+}
+
+struct LambdaParameters {
+    parameters: Vec<(Region, Variable)>,
+}
+
+impl Visitor for LambdaParameters {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        if let Expr::Closure(ClosureData { arguments, .. }) = expr {
+            for (var, _, loc_pattern) in arguments {
+                if let Pattern::Identifier(_) = loc_pattern.value {
+                    if !loc_pattern.region.is_empty() {
+                        self.parameters.push((loc_pattern.region, *var));
+                    }
+                }
+            }
+        }
+
+        if self.should_visit(region) {
+            walk_expr(self, expr, var);
+        }
+    }
+}
@@ -1,21 +1,57 @@
 use roc_module::symbol::{Interns, ModuleId};
+use roc_reporting::report::{RocDocAllocator, RocDocBuilder};
 use roc_types::subs::{Subs, Variable};
+use roc_types::types::{ErrorType, Polarity};
 
+/// Render the type of a variable the way error reports do, keeping the aliases it was written with.
 pub(super) fn format_var_type(
     var: Variable,
     subs: &mut Subs,
     module_id: &ModuleId,
     interns: &Interns,
 ) -> String {
+    let error_type = to_error_type(var, subs);
+
+    let alloc = RocDocAllocator::new(&[], *module_id, interns);
+
+    render(roc_reporting::error::r#type::error_type_to_doc(
+        &alloc, error_type,
+    ))
+}
+
+/// Render the argument types and the return type of a function separately, or return [None] if
+/// the variable isn't a function.
+pub(super) fn format_function_type(
+    var: Variable,
+    subs: &mut Subs,
+    module_id: &ModuleId,
+    interns: &Interns,
+) -> Option<(Vec<String>, String)> {
+    let error_type = to_error_type(var, subs);
+
+    let alloc = RocDocAllocator::new(&[], *module_id, interns);
+
+    let (args, ret) = roc_reporting::error::r#type::function_type_to_docs(&alloc, error_type)?;
+
+    Some((args.into_iter().map(render).collect(), render(ret)))
+}
+
+fn to_error_type(var: Variable, subs: &mut Subs) -> ErrorType {
     let snapshot = subs.snapshot();
-    let type_str = roc_types::pretty_print::name_and_print_var(
-        var,
-        subs,
-        *module_id,
-        interns,
-        roc_types::pretty_print::DebugPrint::NOTHING,
-    );
+    let error_type = subs.var_to_error_type(var, Polarity::OF_VALUE);
     subs.rollback_to(snapshot);
+
+    error_type
+}
+
+/// Render a type without the markup of error reports, like the backticks around type names.
+fn render(doc: RocDocBuilder<'_>) -> String {
+    let mut type_str = String::new();
+
+    doc.1
+        .render_fmt(60, &mut type_str)
+        .expect("<buffer is not a utf-8 encoded string>");
+
     type_str
 }
 
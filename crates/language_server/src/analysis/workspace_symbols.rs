@@ -0,0 +1,54 @@
+use roc_load::symbol_index::{IndexedSymbol, SymbolKind};
+use roc_region::all::LineInfo;
+use tower_lsp::lsp_types::{self, Location, SymbolInformation, Url};
+
+use crate::convert::ToRange;
+
+/// The symbols from one module's index whose names fuzzily match the query.
+pub(super) fn workspace_symbols(
+    query: &str,
+    symbol_index: &[IndexedSymbol],
+    module_name: &str,
+    url: &Url,
+    line_info: &LineInfo,
+) -> Vec<SymbolInformation> {
+    symbol_index
+        .iter()
+        .filter(|indexed| fuzzy_matches(query, &indexed.name))
+        .map(|indexed| {
+            #[allow(deprecated)]
+            SymbolInformation {
+                name: indexed.name.clone(),
+                kind: symbol_kind(indexed.kind),
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri: url.clone(),
+                    range: indexed.region.to_range(line_info),
+                },
+                container_name: Some(module_name.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Whether the characters of the query appear in the name in order, ignoring case.
+/// An empty query matches everything.
+fn fuzzy_matches(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+}
+
+fn symbol_kind(kind: SymbolKind) -> lsp_types::SymbolKind {
+    match kind {
+        SymbolKind::Alias => lsp_types::SymbolKind::STRUCT,
+        SymbolKind::Opaque => lsp_types::SymbolKind::CLASS,
+        SymbolKind::Ability => lsp_types::SymbolKind::INTERFACE,
+        SymbolKind::Function => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Value => lsp_types::SymbolKind::CONSTANT,
+    }
+}
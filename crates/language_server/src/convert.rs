@@ -136,6 +136,12 @@ pub(crate) mod diag {
                         filename.display()
                     )
                 }
+                LoadingProblem::UnknownPackageTarget { filename, .. } => {
+                    format!(
+                        "A package is restricted to an unknown target ({}).",
+                        filename.display()
+                    )
+                }
                 LoadingProblem::ParsingFailed(fe) => {
                     let problem = &fe.problem.problem;
                     format!("Failed to parse Roc source file: {problem:?}")
@@ -157,6 +163,7 @@ pub(crate) mod diag {
                     "Circular dependency between modules".to_string()
                 }
                 LoadingProblem::IncorrectModuleName(_) => "Incorrect module name".to_string(),
+                LoadingProblem::Cancelled => "Analysis was cancelled".to_string(),
                 LoadingProblem::CouldNotFindCacheDir => {
                     format!(
                         "Could not find Roc cache directory {}",
@@ -9,11 +9,12 @@ use std::{
 use tokio::sync::{Mutex, MutexGuard};
 
 use tower_lsp::lsp_types::{
-    CompletionResponse, Diagnostic, GotoDefinitionResponse, Hover, Position, SemanticTokensResult,
-    TextEdit, Url,
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, CompletionResponse,
+    Diagnostic, GotoDefinitionResponse, Hover, InlayHint, Position, Range, SemanticTokensResult,
+    SignatureHelp, SymbolInformation, TextEdit, Url,
 };
 
-use crate::analysis::{AnalyzedDocument, DocInfo};
+use crate::analysis::{value_hint, AnalyzedDocument, DocInfo};
 
 #[derive(Debug)]
 pub(crate) struct DocumentPair {
@@ -48,9 +49,18 @@ impl Default for RegistryConfig {
     }
 }
 
+/// The values evaluated for one version of a document.
+#[derive(Debug, Default)]
+struct Evaluations {
+    version: i32,
+    constants: Option<Vec<InlayHint>>,
+    selections: Vec<InlayHint>,
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct Registry {
     documents: Mutex<HashMap<Url, DocumentPair>>,
+    evaluations: Mutex<HashMap<Url, Evaluations>>,
     config: RegistryConfig,
 }
 
@@ -58,6 +68,7 @@ impl Registry {
     pub(crate) fn new(config: RegistryConfig) -> Self {
         Self {
             documents: Default::default(),
+            evaluations: Default::default(),
             config,
         }
     }
@@ -175,6 +186,12 @@ impl Registry {
         self.latest_document_by_url(url).await?.hover(position)
     }
 
+    pub async fn signature_help(&self, url: &Url, position: Position) -> Option<SignatureHelp> {
+        self.latest_document_by_url(url)
+            .await?
+            .signature_help(position)
+    }
+
     pub async fn goto_definition(
         &self,
         url: &Url,
@@ -187,15 +204,161 @@ impl Registry {
         def_document.definition(symbol)
     }
 
+    pub async fn prepare_call_hierarchy(
+        &self,
+        url: &Url,
+        position: Position,
+    ) -> Option<Vec<CallHierarchyItem>> {
+        let document = self.latest_document_by_url(url).await?;
+        let (def_document_url, name) = document.call_hierarchy_target(position)?;
+        let def_document = self.latest_document_by_url(&def_document_url).await?;
+
+        Some(vec![def_document.call_hierarchy_item(&name)?])
+    }
+
+    /// Searches the last document that type checked for every module the server has seen.
+    pub async fn incoming_calls(&self, item: &CallHierarchyItem) -> Vec<CallHierarchyIncomingCall> {
+        let documents = self.documents.lock().await;
+
+        documents
+            .values()
+            .flat_map(|pair| {
+                pair.last_good_document
+                    .incoming_calls(&item.uri, &item.name)
+            })
+            .collect()
+    }
+
+    pub async fn outgoing_calls(&self, item: &CallHierarchyItem) -> Vec<CallHierarchyOutgoingCall> {
+        let Some(document) = self.latest_document_by_url(&item.uri).await else {
+            return vec![];
+        };
+
+        let mut outgoing = Vec::new();
+
+        for (callee_url, callee_name, from_ranges) in document.outgoing_calls(&item.name) {
+            let Some(callee_document) = self.latest_document_by_url(&callee_url).await else {
+                continue;
+            };
+
+            if let Some(to) = callee_document.call_hierarchy_item(&callee_name) {
+                outgoing.push(CallHierarchyOutgoingCall { to, from_ranges });
+            }
+        }
+
+        outgoing
+    }
+
     pub async fn formatting(&self, url: &Url) -> Option<Vec<TextEdit>> {
         let document = self.document_info_by_url(url).await?;
         document.format()
     }
 
+    pub async fn organize_imports(&self, url: &Url) -> Option<Vec<TextEdit>> {
+        self.latest_document_by_url(url).await?.organize_imports()
+    }
+
     pub async fn semantic_tokens(&self, url: &Url) -> Option<SemanticTokensResult> {
         let document = self.document_info_by_url(url).await?;
         document.semantic_tokens()
     }
+    /// Evaluates the expression in the given range, and remembers its value to show it inline
+    /// until the document changes.
+    pub async fn evaluate(&self, url: &Url, range: Range) -> Option<Result<String, String>> {
+        let document = self.document_info_by_url(url).await?;
+        let version = document.version;
+
+        let result = tokio::task::spawn_blocking(move || document.evaluate(range))
+            .await
+            .ok()?;
+
+        if let Ok(value) = &result {
+            let mut evaluations = self.evaluations.lock().await;
+            let evaluations = Self::evaluations_for(&mut evaluations, url, version);
+
+            evaluations
+                .selections
+                .push(value_hint(range.end, value.clone()));
+        }
+
+        Some(result)
+    }
+
+    /// The inferred types of the document's names, the values of its top-level constants, and
+    /// the values of the expressions evaluated in it.
+    pub async fn inlay_hints(&self, url: &Url) -> Option<Vec<InlayHint>> {
+        let document = self.document_info_by_url(url).await?;
+        let version = document.version;
+
+        // Types from an older version of the document would be shown in the wrong places.
+        let types = match self.latest_document_by_url(url).await {
+            Some(analyzed) if analyzed.doc_info.version == version => analyzed.type_hints(),
+            _ => Vec::new(),
+        };
+
+        let cached = {
+            let mut evaluations = self.evaluations.lock().await;
+            Self::evaluations_for(&mut evaluations, url, version)
+                .constants
+                .clone()
+        };
+
+        let constants = match cached {
+            Some(constants) => constants,
+            None => {
+                let constants =
+                    tokio::task::spawn_blocking(move || document.constant_value_hints())
+                        .await
+                        .ok()?;
+
+                let mut evaluations = self.evaluations.lock().await;
+                Self::evaluations_for(&mut evaluations, url, version).constants =
+                    Some(constants.clone());
+
+                constants
+            }
+        };
+
+        let mut evaluations = self.evaluations.lock().await;
+        let selections = &Self::evaluations_for(&mut evaluations, url, version).selections;
+
+        Some(
+            types
+                .into_iter()
+                .chain(constants)
+                .chain(selections.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// The evaluations for the given version of a document, dropping any from other versions.
+    fn evaluations_for<'e>(
+        evaluations: &'e mut MutexGuard<'_, HashMap<Url, Evaluations>>,
+        url: &Url,
+        version: i32,
+    ) -> &'e mut Evaluations {
+        let entry = evaluations.entry(url.clone()).or_default();
+
+        if entry.version != version {
+            *entry = Evaluations {
+                version,
+                ..Evaluations::default()
+            };
+        }
+
+        entry
+    }
+
+    /// Searches the last document that type checked for every module the server has seen.
+    pub async fn workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        let documents = self.documents.lock().await;
+
+        documents
+            .values()
+            .flat_map(|pair| pair.last_good_document.workspace_symbols(query))
+            .collect()
+    }
+
     pub async fn completion_items(
         &self,
         url: &Url,
@@ -1,7 +1,10 @@
 use analysis::HIGHLIGHT_TOKENS_LEGEND;
 
 use log::{debug, trace};
+use parking_lot::Mutex;
 use registry::{Registry, RegistryConfig};
+use roc_load::CancellationToken;
+use std::collections::HashMap;
 use std::future::Future;
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::time::Duration;
@@ -16,6 +19,9 @@ mod analysis;
 mod convert;
 mod registry;
 
+/// Evaluates the expression in a range of a document, given as the document's url and the range.
+const EVALUATE_SELECTION_COMMAND: &str = "roc.evaluateSelection";
+
 struct RocServer {
     pub state: RocServerState,
     client: Client,
@@ -37,6 +43,8 @@ impl Default for RocServerConfig {
 struct RocServerState {
     registry: Registry,
     config: RocServerConfig,
+    /// Cancels the analysis running for each document, once a newer version of it comes in.
+    analyses: Mutex<HashMap<Url, CancellationToken>>,
 }
 
 impl std::panic::RefUnwindSafe for RocServer {}
@@ -103,6 +111,13 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let signature_help_provider = SignatureHelpOptions {
+            trigger_characters: Some(vec![" ".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        };
         ServerCapabilities {
             text_document_sync: Some(text_document_sync),
             hover_provider: Some(hover_provider),
@@ -110,6 +125,17 @@ impl RocServer {
             document_formatting_provider: Some(OneOf::Right(document_formatting_provider)),
             semantic_tokens_provider: Some(semantic_tokens_provider),
             completion_provider: Some(completion_provider),
+            signature_help_provider: Some(signature_help_provider),
+            workspace_symbol_provider: Some(OneOf::Left(true)),
+            code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+            execute_command_provider: Some(ExecuteCommandOptions {
+                commands: vec![EVALUATE_SELECTION_COMMAND.to_string()],
+                work_done_progress_options: WorkDoneProgressOptions {
+                    work_done_progress: None,
+                },
+            }),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
             ..ServerCapabilities::default()
         }
     }
@@ -136,7 +162,11 @@ impl RocServer {
 
 impl RocServerState {
     pub fn new(config: RocServerConfig, registry: Registry) -> RocServerState {
-        Self { config, registry }
+        Self {
+            config,
+            registry,
+            analyses: Mutex::default(),
+        }
     }
 
     async fn close(&self, _fi: Url) {}
@@ -161,7 +191,7 @@ impl RocServerState {
 
         let inner_ref = self;
         let updating_result = async {
-            //This reduces wasted computation by waiting to allow a new change to come in and update the version before we check, but does delay the final analysis. Ideally this would be replaced with cancelling the analysis when a new one comes in.
+            //This reduces wasted computation by waiting to allow a new change to come in and update the version before we check, but does delay the final analysis. An analysis that is already running is cancelled below instead.
             tokio::time::sleep(self.config.debounce_ms).await;
             let is_latest = inner_ref
                 .registry
@@ -173,9 +203,21 @@ impl RocServerState {
                 return Err("Not latest version skipping analysis".to_string());
             }
 
+            let cancellation = CancellationToken::new();
+            let previous = inner_ref
+                .analyses
+                .lock()
+                .insert(fi.clone(), cancellation.clone());
+
+            if let Some(previous) = previous {
+                previous.cancel();
+            }
+
             let results = match tokio::time::timeout(
                 Duration::from_secs(60),
-                tokio::task::spawn_blocking(|| catch_unwind(|| global_analysis(doc_info))),
+                tokio::task::spawn_blocking(|| {
+                    catch_unwind(|| global_analysis(doc_info, cancellation))
+                }),
             )
             .await
             {
@@ -276,6 +318,25 @@ impl LanguageServer for RocServer {
         unwind_async(self.state.registry.hover(&text_document.uri, position)).await
     }
 
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let SignatureHelpParams {
+            context: _,
+            text_document_position_params:
+                TextDocumentPositionParams {
+                    text_document,
+                    position,
+                },
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .signature_help(&text_document.uri, position),
+        )
+        .await
+    }
+
     async fn goto_definition(
         &self,
         params: GotoDefinitionParams,
@@ -298,6 +359,57 @@ impl LanguageServer for RocServer {
         .await
     }
 
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let CallHierarchyPrepareParams {
+            text_document_position_params:
+                TextDocumentPositionParams {
+                    text_document,
+                    position,
+                },
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .prepare_call_hierarchy(&text_document.uri, position),
+        )
+        .await
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        let CallHierarchyIncomingCallsParams {
+            item,
+            work_done_progress_params: _,
+            partial_result_params: _,
+        } = params;
+
+        unwind_async(self.state.registry.incoming_calls(&item))
+            .await
+            .map(Some)
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let CallHierarchyOutgoingCallsParams {
+            item,
+            work_done_progress_params: _,
+            partial_result_params: _,
+        } = params;
+
+        unwind_async(self.state.registry.outgoing_calls(&item))
+            .await
+            .map(Some)
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let DocumentFormattingParams {
             text_document,
@@ -332,6 +444,122 @@ impl LanguageServer for RocServer {
         )
         .await
     }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        let WorkspaceSymbolParams {
+            query,
+            work_done_progress_params: _,
+            partial_result_params: _,
+        } = params;
+
+        unwind_async(self.state.registry.workspace_symbols(&query))
+            .await
+            .map(Some)
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let CodeActionParams {
+            text_document,
+            range,
+            context,
+            ..
+        } = params;
+
+        let mut actions = Vec::new();
+
+        if range.start != range.end {
+            let evaluate = Command {
+                title: "Evaluate selection".to_string(),
+                command: EVALUATE_SELECTION_COMMAND.to_string(),
+                arguments: Some(vec![
+                    serde_json::json!(text_document.uri),
+                    serde_json::json!(range),
+                ]),
+            };
+
+            actions.push(CodeActionOrCommand::Command(evaluate));
+        }
+
+        let wants_organize_imports = context.only.map_or(true, |only| {
+            only.iter().any(|kind| {
+                CodeActionKind::SOURCE_ORGANIZE_IMPORTS
+                    .as_str()
+                    .starts_with(kind.as_str())
+            })
+        });
+
+        if wants_organize_imports {
+            let edits =
+                unwind_async(self.state.registry.organize_imports(&text_document.uri)).await?;
+
+            if let Some(edits) = edits {
+                let organize_imports = CodeAction {
+                    title: "Organize imports".to_string(),
+                    kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(text_document.uri, edits)])),
+                        ..WorkspaceEdit::default()
+                    }),
+                    ..CodeAction::default()
+                };
+
+                actions.push(CodeActionOrCommand::CodeAction(organize_imports));
+            }
+        }
+
+        Ok((!actions.is_empty()).then_some(actions))
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        let ExecuteCommandParams {
+            command, arguments, ..
+        } = params;
+
+        if command != EVALUATE_SELECTION_COMMAND {
+            return Err(jsonrpc::Error::method_not_found());
+        }
+
+        let (url, range) = match arguments.as_slice() {
+            [url, range] => (
+                serde_json::from_value::<Url>(url.clone()),
+                serde_json::from_value::<Range>(range.clone()),
+            ),
+            _ => return Err(jsonrpc::Error::invalid_params("expected a url and a range")),
+        };
+
+        let (Ok(url), Ok(range)) = (url, range) else {
+            return Err(jsonrpc::Error::invalid_params("expected a url and a range"));
+        };
+
+        match unwind_async(self.state.registry.evaluate(&url, range)).await? {
+            Some(Ok(value)) => {
+                // The value is shown as an inlay hint at the end of the selection.
+                let _ = self.client.inlay_hint_refresh().await;
+
+                Ok(Some(serde_json::Value::String(value)))
+            }
+            Some(Err(problem)) => {
+                self.client
+                    .show_message(MessageType::WARNING, format!("Can't evaluate: {problem}"))
+                    .await;
+
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let InlayHintParams { text_document, .. } = params;
+
+        unwind_async(self.state.registry.inlay_hints(&text_document.uri)).await
+    }
 }
 
 async fn unwind_async<Fut, T>(future: Fut) -> tower_lsp::jsonrpc::Result<T>
@@ -410,6 +638,10 @@ mod tests {
     static INIT: Once = Once::new();
 
     async fn test_setup(doc: String) -> (RocServerState, Url) {
+        test_setup_at(Url::parse("file:/Test.roc").unwrap(), doc).await
+    }
+
+    async fn test_setup_at(url: Url, doc: String) -> (RocServerState, Url) {
         INIT.call_once(|| {
             env_logger::builder()
                 .is_test(true)
@@ -417,7 +649,6 @@ mod tests {
                 .init();
         });
         info!("Doc is:\n{0}", doc);
-        let url = Url::parse("file:/Test.roc").unwrap();
 
         let inner = RocServerState::new(RocServerConfig::default(), Registry::default());
         // setup the file
@@ -612,4 +843,443 @@ mod tests {
         "#]]
         .assert_debug_eq(&actual);
     }
+
+    /// gets the name and line of each workspace symbol matching the query
+    async fn workspace_symbol_names(reg: &Registry, query: &str) -> Vec<(String, u32)> {
+        reg.workspace_symbols(query)
+            .await
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.location.range.start.line))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_workspace_symbols() {
+        let doc = indoc! {r"
+            interface Test
+              exposes [Color, mapAll, limit]
+              imports []
+
+            Color : [Red, Green]
+
+            mapAll = \list -> list
+
+            limit = 10
+
+            helper = \x -> x
+            "};
+
+        let (inner, _) = test_setup(doc.to_string()).await;
+
+        let registry = &inner.registry;
+        let actual = [
+            workspace_symbol_names(registry, "").await,
+            workspace_symbol_names(registry, "ma").await,
+        ];
+
+        expect![[r#"
+            [
+                [
+                    (
+                        "Color",
+                        4,
+                    ),
+                    (
+                        "mapAll",
+                        6,
+                    ),
+                    (
+                        "limit",
+                        8,
+                    ),
+                ],
+                [
+                    (
+                        "mapAll",
+                        6,
+                    ),
+                ],
+            ]
+        "#]]
+        .assert_debug_eq(&actual);
+    }
+
+    /// The label of the signature at the position, and the parameter that is highlighted in it
+    async fn signature_at(
+        registry: &Registry,
+        url: &Url,
+        position: Position,
+    ) -> Option<(String, Option<u32>)> {
+        let help = registry.signature_help(url, position).await?;
+        let signature = help.signatures.into_iter().next()?;
+
+        Some((signature.label, help.active_parameter))
+    }
+
+    #[tokio::test]
+    async fn test_signature_help() {
+        let doc = indoc! {r#"
+            interface Test
+              exposes []
+              imports []
+
+            greet : Str, Str -> Str
+            greet = \greeting, name -> Str.concat greeting name
+
+            message = greet "Hello" "World"
+            "#};
+
+        let (inner, url) = test_setup(doc.to_string()).await;
+        let registry = &inner.registry;
+
+        let label = "greet : Str, Str -> Str".to_string();
+
+        assert_eq!(
+            signature_at(registry, &url, Position::new(7, 16)).await,
+            Some((label.clone(), Some(0)))
+        );
+        assert_eq!(
+            signature_at(registry, &url, Position::new(7, 24)).await,
+            Some((label, Some(1)))
+        );
+        assert_eq!(
+            signature_at(registry, &url, Position::new(4, 3)).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_hierarchy() {
+        let doc = indoc! {r"
+            interface Test
+              exposes []
+              imports []
+
+            double = \x -> plus x x
+
+            plus = \a, b -> a + b
+
+            quadruple = \x -> double (double x)
+            "};
+
+        let (inner, url) = test_setup(doc.to_string()).await;
+        let registry = &inner.registry;
+
+        let items = registry
+            .prepare_call_hierarchy(&url, Position::new(4, 16))
+            .await
+            .unwrap();
+        assert_eq!(
+            items.iter().map(|item| &item.name).collect::<Vec<_>>(),
+            vec!["plus"]
+        );
+
+        let incoming = registry.incoming_calls(&items[0]).await;
+        assert_eq!(
+            incoming
+                .iter()
+                .map(|call| (call.from.name.as_str(), call.from_ranges.len()))
+                .collect::<Vec<_>>(),
+            vec![("double", 1)]
+        );
+
+        let quadruple = registry
+            .prepare_call_hierarchy(&url, Position::new(8, 2))
+            .await
+            .unwrap();
+        let outgoing = registry.outgoing_calls(&quadruple[0]).await;
+        assert_eq!(
+            outgoing
+                .iter()
+                .map(|call| (call.to.name.as_str(), call.from_ranges.len()))
+                .collect::<Vec<_>>(),
+            vec![("double", 2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_type_hints() {
+        let doc = indoc! {r#"
+            interface Test
+              exposes []
+              imports []
+
+            shout = \words -> List.map words (\word -> Str.concat word "!")
+            "#};
+
+        let (inner, url) = test_setup(doc.to_string()).await;
+
+        let hints = inner.registry.inlay_hints(&url).await.unwrap();
+
+        let labels = hints
+            .into_iter()
+            .map(|hint| match hint.label {
+                InlayHintLabel::String(label) => (hint.position, label),
+                InlayHintLabel::LabelParts(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            labels,
+            vec![
+                (Position::new(4, 5), ": List Str -> List Str".to_string()),
+                (Position::new(4, 39), ": Str".to_string()),
+            ]
+        );
+    }
+
+    /// Writes the modules to a new directory along with `Test.roc`, which is then opened, so
+    /// that the modules can be imported from it.
+    async fn project_setup(
+        name: &str,
+        modules: &[(&str, &str)],
+        doc: &str,
+    ) -> (RocServerState, Url) {
+        let dir =
+            std::env::temp_dir().join(format!("roc_language_server_{name}_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for (module_name, source) in modules {
+            std::fs::write(dir.join(module_name).with_extension("roc"), source).unwrap();
+        }
+
+        let path = dir.join("Test.roc");
+        std::fs::write(&path, doc).unwrap();
+
+        test_setup_at(Url::from_file_path(path).unwrap(), doc.to_string()).await
+    }
+
+    const IMPORTED_MODULES: &[(&str, &str)] = &[
+        (
+            "Fruit",
+            indoc! {r#"
+                module [apple, banana]
+
+                apple = "apple"
+
+                banana = "banana"
+                "#},
+        ),
+        (
+            "Shapes",
+            indoc! {r#"
+                module [square, circle]
+
+                square = "square"
+
+                circle = "circle"
+                "#},
+        ),
+        (
+            "Color",
+            indoc! {r#"
+                module [red, shapes]
+
+                import Shapes
+
+                red = "red"
+
+                shapes = [Shapes.square]
+                "#},
+        ),
+        (
+            "Unused",
+            indoc! {r#"
+                module [unused]
+
+                unused = "unused"
+                "#},
+        ),
+    ];
+
+    /// The whole document after organizing its imports, or [None] if they already are
+    async fn organized_imports(registry: &Registry, url: &Url) -> Option<String> {
+        let edits = registry.organize_imports(url).await?;
+
+        assert_eq!(edits.len(), 1);
+
+        edits.into_iter().next().map(|edit| edit.new_text)
+    }
+
+    #[tokio::test]
+    async fn test_organize_imports() {
+        let doc = indoc! {r#"
+            module [main]
+
+            # fruit for the basket
+            import Fruit exposing [apple, banana]
+            # not needed
+            import Unused
+
+            # the only color we need
+            import Color exposing [red]
+            # the result
+            main = [red, apple, Shapes.square, Shapes.square, circle, circle]
+            "#};
+
+        let (inner, url) = project_setup("organize", IMPORTED_MODULES, doc).await;
+
+        // `Unused` and `banana` are removed, `Shapes` is added once, exposing `circle`, and the
+        // comments move along with the imports they are above.
+        assert_eq!(
+            organized_imports(&inner.registry, &url).await.as_deref(),
+            Some(indoc! {r#"
+                module [main]
+
+                # the only color we need
+                import Color exposing [red]
+                # fruit for the basket
+                import Fruit exposing [apple]
+                import Shapes exposing [circle]
+                # the result
+                main = [red, apple, Shapes.square, Shapes.square, circle, circle]
+                "#})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_organize_duplicate_imports() {
+        let doc = indoc! {r#"
+            module [main]
+
+            import Fruit exposing [apple]
+            # the banana too
+            import Fruit exposing [banana]
+
+            main = [apple, banana]
+            "#};
+
+        let (inner, url) = project_setup("duplicates", IMPORTED_MODULES, doc).await;
+
+        let organized = organized_imports(&inner.registry, &url).await.unwrap();
+
+        assert_eq!(
+            organized,
+            indoc! {r#"
+                module [main]
+
+                # the banana too
+                import Fruit exposing [apple, banana]
+
+                main = [apple, banana]
+                "#}
+        );
+
+        // Organizing imports that are already organized changes nothing
+        inner.change(&url, organized, 1).await.unwrap();
+
+        assert_eq!(organized_imports(&inner.registry, &url).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_hover() {
+        let doc = indoc! {r#"
+            interface Test
+              exposes []
+              imports []
+
+            ## Greets someone by name
+            greet : Str -> _
+            greet = \name -> Str.concat "Hello, " name
+
+            message = greet "World"
+            "#};
+
+        let (inner, url) = test_setup(doc.to_string()).await;
+
+        let roc = |value: &str| {
+            MarkedString::LanguageString(LanguageString {
+                language: "roc".to_string(),
+                value: value.to_string(),
+            })
+        };
+
+        // The annotation as written, the inferred type, and the docs
+        assert_eq!(
+            inner.registry.hover(&url, Position::new(8, 12)).await,
+            Some(Hover {
+                contents: HoverContents::Array(vec![
+                    roc("greet : Str -> _"),
+                    roc("greet : Str -> Str"),
+                    MarkedString::String("Greets someone by name\n".to_string()),
+                ]),
+                range: Some(Range::new(Position::new(8, 10), Position::new(8, 15))),
+            })
+        );
+
+        assert_eq!(
+            inner.registry.hover(&url, Position::new(8, 2)).await,
+            Some(Hover {
+                contents: HoverContents::Array(vec![roc("Str")]),
+                range: Some(Range::new(Position::new(8, 0), Position::new(8, 7))),
+            })
+        );
+    }
+
+    const EVALUATE_DOC: &str = indoc! {r#"
+        interface Test
+          exposes []
+          imports []
+
+        countDown = \n -> if n == 0 then 0 else 1 + countDown (n - 1)
+
+        doubled = List.map [1, 2] (\n -> n * 2)
+
+        deep = countDown 10000000
+
+        x = 1 + 2
+        "#};
+
+    #[tokio::test]
+    async fn test_evaluate() {
+        let (inner, url) = test_setup(EVALUATE_DOC.to_string()).await;
+
+        let doubled = Range::new(Position::new(6, 10), Position::new(6, 39));
+
+        assert_eq!(
+            inner.registry.evaluate(&url, doubled).await,
+            Some(Ok("[2, 4]".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_deep_recursion() {
+        let (inner, url) = test_setup(EVALUATE_DOC.to_string()).await;
+
+        // Deeper than the interpreter's stack budget, which has to fit on the evaluating thread.
+        let deep = Range::new(Position::new(8, 7), Position::new(8, 25));
+
+        assert_eq!(
+            inner.registry.evaluate(&url, deep).await,
+            Some(Err(
+                "evaluating deeply nested calls needs a compiled program".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_constant_value_hints() {
+        let (inner, url) = test_setup(EVALUATE_DOC.to_string()).await;
+
+        let hints = inner.registry.inlay_hints(&url).await.unwrap();
+
+        let values = hints
+            .into_iter()
+            .filter_map(|hint| match hint.label {
+                InlayHintLabel::String(label) if label.starts_with("= ") => {
+                    Some((hint.position, label))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // `deep` can't be evaluated, so it has no hint.
+        assert_eq!(
+            values,
+            vec![
+                (Position::new(6, 39), "= [2, 4]".to_string()),
+                (Position::new(10, 9), "= 3".to_string()),
+            ]
+        );
+    }
 }
@@ -87,6 +87,11 @@ pub fn generate_stub_lib(
             palette: DEFAULT_PALETTE,
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Executable,
+            optimize_ir: false,
+            report_expects_with_dbg: false,
+            cancellation: None,
+            problem_sink: None,
+            defines: Default::default(),
         },
     )
     .unwrap_or_else(|problem| todo!("{:?}", problem));
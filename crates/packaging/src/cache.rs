@@ -13,6 +13,9 @@ use std::path::{Path, PathBuf};
 pub enum RocCacheDir<'a> {
     /// Normal scenario: reading from the user's cache dir on disk
     Persistent(&'a Path),
+    /// For offline builds: read packages from this dir on disk (the cache dir, or a vendor dir
+    /// made by `roc vendor`), and report an error instead of downloading any that are missing.
+    Offline(&'a Path),
     /// For build.rs and tests where we never want to be downloading anything - yell loudly if we try!
     Disallowed,
     /// For tests only; we don't want to write to the real cache during a test!
@@ -20,6 +23,18 @@ pub enum RocCacheDir<'a> {
     Temp(&'a tempfile::TempDir),
 }
 
+impl RocCacheDir<'_> {
+    /// The dir that packages from URLs are installed into, and so are looked up in.
+    pub fn packages_dir(&self) -> PathBuf {
+        match self {
+            RocCacheDir::Persistent(dir) | RocCacheDir::Offline(dir) => dir.to_path_buf(),
+            RocCacheDir::Disallowed => roc_cache_dir(),
+            #[cfg(test)]
+            RocCacheDir::Temp(temp_dir) => temp_dir.path().to_path_buf(),
+        }
+    }
+}
+
 // Errors in case NixOS users try to use a dynamically linked platform
 #[cfg(target_os = "linux")]
 fn nixos_error_if_dynamic(url: &str, dest_dir: &Path) {
@@ -79,7 +94,7 @@ pub fn install_package<'a>(
     } = PackageMetadata::try_from(url).map_err(Problem::InvalidUrl)?;
 
     match roc_cache_dir {
-        RocCacheDir::Persistent(cache_dir) => {
+        RocCacheDir::Persistent(cache_dir) | RocCacheDir::Offline(cache_dir) => {
             // e.g. ~/.cache/roc/example.com/roc-packages/
             let parent_dir = cache_dir.join(cache_subdir);
             // e.g. ~/.cache/roc/example.com/roc-packages/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE
@@ -95,6 +110,8 @@ pub fn install_package<'a>(
                 }
 
                 Ok((dest_dir, root_module_filename))
+            } else if matches!(roc_cache_dir, RocCacheDir::Offline(_)) {
+                Err(Problem::NotAvailableOffline)
             } else {
                 // Download into a tempdir; only move it to dest_dir if hash verification passes.
                 println!(
@@ -186,6 +203,43 @@ pub fn install_package<'a>(
     }
 }
 
+/// Install the package at the given URL (downloading it if necessary), and copy it into
+/// `vendor_dir`, using the same layout as the cache dir. Loading with
+/// `RocCacheDir::Offline(vendor_dir)` then finds the package there.
+///
+/// Returns the path to the vendored package.
+#[cfg(not(target_family = "wasm"))]
+pub fn vendor_package(
+    roc_cache_dir: RocCacheDir<'_>,
+    url: &str,
+    vendor_dir: &Path,
+) -> Result<PathBuf, Problem> {
+    let PackageMetadata {
+        cache_subdir,
+        content_hash,
+        ..
+    } = PackageMetadata::try_from(url).map_err(Problem::InvalidUrl)?;
+
+    let (package_dir, _) = install_package(roc_cache_dir, url)?;
+    let dest_dir = vendor_dir.join(cache_subdir).join(content_hash);
+
+    if !dest_dir.exists() {
+        fs::create_dir_all(&dest_dir).map_err(Problem::IoErr)?;
+
+        fs_extra::dir::copy(
+            package_dir,
+            &dest_dir,
+            &fs_extra::dir::CopyOptions {
+                content_only: true,
+                ..Default::default()
+            },
+        )
+        .map_err(Problem::FsExtraErr)?;
+    }
+
+    Ok(dest_dir)
+}
+
 #[cfg(windows)]
 // e.g. the "Roc" in %APPDATA%\\Roc
 const ROC_CACHE_DIR_NAME: &str = "Roc";
@@ -241,6 +241,8 @@ pub enum Problem {
     /// The Content-Length header of the response exceeded max_download_bytes
     DownloadTooBig(u64),
     NotFound,
+    /// The package was not in the cache or vendor dir, and we may not download it
+    NotAvailableOffline,
 }
 
 pub fn download_and_hash(
@@ -128,17 +128,14 @@ fn write_archive<W: Write>(path: &Path, writer: W) -> io::Result<()> {
     // TODO use this when finding .roc files by discovering them from the root module.
     // let other_modules: &[Module<'_>] =
     match read_header(&arena, &mut buf, path)?.header {
-        Header::Module(_) => {
-            todo!();
-            // TODO report error
-        }
-        Header::App(_) => {
-            todo!();
-            // TODO report error
-        }
-        Header::Hosted(_) => {
-            todo!();
-            // TODO report error
+        Header::Module(_) | Header::App(_) | Header::Hosted(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} is not a package or a platform, so it cannot be bundled. Only the main.roc file of a package or a platform can be bundled.",
+                    path.to_string_lossy()
+                ),
+            ));
         }
         Header::Package(_) => {
             add_dot_roc_files(root_dir, &mut builder)?;
@@ -269,9 +266,16 @@ fn read_header<'a>(
     // (We can't use that for the parser state and still return Module<'a> unfortunately.)
     let arena_buf = bumpalo::collections::Vec::from_iter_in(buf.iter().copied(), arena);
     let parse_state = State::new(arena_buf.into_bump_slice());
-    let (module, _) = parse_header(arena, parse_state).unwrap_or_else(|_err| {
-        todo!(); // TODO report a nice error and exit 1 - or maybe just return Err, for better testability?
-    });
+    let (module, _) = parse_header(arena, parse_state).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Unable to parse the header of {}: {:?}",
+                path.to_string_lossy(),
+                err.problem
+            ),
+        )
+    })?;
 
     Ok(module)
 }
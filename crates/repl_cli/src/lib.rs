@@ -8,7 +8,10 @@ use roc_mono::ir::OptLevel;
 use roc_repl_eval::gen::Problems;
 use roc_repl_ui::colors::{CYAN, END_COL};
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_repl_ui::{format_output, is_incomplete, CONT_PROMPT, PROMPT, SHORT_INSTRUCTIONS, TIPS};
+use roc_repl_ui::{
+    format_layout, format_missing, format_output, is_incomplete, CONT_PROMPT, PROMPT,
+    SHORT_INSTRUCTIONS, TIPS,
+};
 use roc_reporting::report::{to_file_problem_report_string, ANSI_STYLE_CODES, DEFAULT_PALETTE};
 use roc_target::Target;
 use rustyline::highlight::{Highlighter, PromptInfo};
@@ -80,6 +83,12 @@ pub fn main() -> i32 {
                     ReplAction::Help => {
                         println!("{TIPS}");
                     }
+                    ReplAction::Missing { patterns, problems } => {
+                        println!("{}", format_missing(ANSI_STYLE_CODES, patterns, problems));
+                    }
+                    ReplAction::Layout { output, problems } => {
+                        println!("{}", format_layout(ANSI_STYLE_CODES, output, problems));
+                    }
                     ReplAction::Nothing => {}
                 }
             }
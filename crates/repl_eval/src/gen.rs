@@ -7,11 +7,16 @@ use std::path::PathBuf;
 
 use roc_fmt::annotation::Formattable;
 use roc_fmt::annotation::{Newlines, Parens};
-use roc_load::{LoadingProblem, MonomorphizedModule};
+use roc_load::{LoadedModule, LoadingProblem, MonomorphizedModule};
+use roc_mono::layout::{layout_report, GlobalLayoutInterner, LayoutCache, LayoutInterner};
 use roc_parse::ast::Expr;
-use roc_region::all::LineInfo;
-use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::error::r#type::unhandled_patterns_to_doc_block;
+use roc_reporting::report::{
+    can_problem, to_file_problem_report_string, type_problem, Report, RocDocAllocator,
+};
 use roc_solve::FunctionKind;
+use roc_solve_problem::TypeError;
 use roc_target::Target;
 
 #[derive(Debug)]
@@ -68,6 +73,11 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
             palette,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Executable,
+            optimize_ir: false,
+            report_expects_with_dbg: false,
+            cancellation: None,
+            problem_sink: None,
+            defines: Default::default(),
         },
     );
 
@@ -79,18 +89,15 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
                 (m.can_problems, m.type_problems)
             );
         }
-        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+        Err(LoadMonomorphizedError::LoadingProblem(problem)) => {
             return (
                 None,
                 Problems {
-                    errors: vec![report],
+                    errors: vec![loading_problem_report(problem)],
                     warnings: Vec::new(),
                 },
             );
         }
-        Err(e) => {
-            todo!("error while loading module: {:?}", e)
-        }
     };
 
     let MonomorphizedModule {
@@ -167,6 +174,204 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
     (Some(loaded), problems)
 }
 
+/// Type checks a `when` expression, and returns the patterns it doesn't cover (each rendered like
+/// in the error about them) along with the other problems in the expression.
+pub fn missing_patterns<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target: Target,
+    palette: Palette,
+) -> (Vec<String>, Problems) {
+    let (_, missing, problems) = check_expr(arena, defs, expr, target, palette);
+
+    (missing, problems)
+}
+
+#[derive(Debug)]
+pub struct LayoutOutput {
+    pub size: u32,
+    pub alignment: u32,
+    /// The offsets of the fields, how tags are stored, and so on
+    pub report: String,
+}
+
+/// Type checks an expression, and describes the layout of its type.
+pub fn layout_of<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target: Target,
+    palette: Palette,
+) -> (Option<LayoutOutput>, Problems) {
+    let (opt_loaded, _, mut problems) = check_expr(arena, defs, expr, target, palette);
+
+    let loaded = match opt_loaded {
+        Some(loaded) if problems.errors.is_empty() => loaded,
+        _ => return (None, problems),
+    };
+
+    let var = match loaded.exposed_to_host.values().next() {
+        Some(var) => *var,
+        None => return (None, problems),
+    };
+
+    let subs = loaded.solved.inner();
+    let layout_interner = GlobalLayoutInterner::with_capacity(128, target);
+    let mut layout_cache = LayoutCache::new(layout_interner.fork(), target);
+
+    match layout_cache.from_var(arena, var, subs) {
+        Ok(layout) => {
+            let (size, alignment) = layout_cache.interner.stack_size_and_alignment(layout);
+            let report = layout_report(arena, &mut layout_cache, subs, var, layout);
+
+            (
+                Some(LayoutOutput {
+                    size,
+                    alignment,
+                    report,
+                }),
+                problems,
+            )
+        }
+        Err(_) => {
+            problems.errors.push(
+                "This type has no layout yet, because it still has type variables in it, like the `a` in `List a`."
+                    .to_string(),
+            );
+
+            (None, problems)
+        }
+    }
+}
+
+/// Type checks an expression without compiling it, and renders the problems in it like
+/// compile_to_mono does. The patterns that `when`s in the expression don't cover are returned
+/// separately from the other problems.
+fn check_expr<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target: Target,
+    palette: Palette,
+) -> (Option<LoadedModule>, Vec<String>, Problems) {
+    let filename = PathBuf::from("replfile.roc");
+    let src_dir = PathBuf::from(".");
+    let (bytes_before_expr, module_src) = promote_expr_to_module(arena, defs, expr);
+    let loaded = roc_load::load_and_typecheck_str(
+        arena,
+        filename.clone(),
+        module_src,
+        src_dir,
+        target,
+        FunctionKind::LambdaSet,
+        roc_reporting::report::RenderTarget::ColorTerminal,
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        palette,
+        None,
+    );
+
+    let mut loaded = match loaded {
+        Ok(v) => v,
+        Err(problem) => {
+            return (
+                None,
+                Vec::new(),
+                Problems {
+                    errors: vec![loading_problem_report(problem)],
+                    warnings: Vec::new(),
+                },
+            );
+        }
+    };
+
+    let home = loaded.module_id;
+    let can_probs = loaded.can_problems.remove(&home).unwrap_or_default();
+    let type_probs = loaded.type_problems.remove(&home).unwrap_or_default();
+
+    let line_info = LineInfo::new(module_src);
+    let src_lines: Vec<&str> = module_src.split('\n').collect();
+    let alloc = RocDocAllocator::new(&src_lines, home, &loaded.interns);
+
+    // As in compile_to_mono, only the problems in the expression itself are shown.
+    let in_expr = |region: Option<Region>| {
+        region.unwrap_or_default().end().offset as usize >= bytes_before_expr
+    };
+
+    let mut missing = Vec::new();
+    let mut problems = Problems::default();
+
+    for problem in can_probs {
+        if in_expr(problem.region()) {
+            let report = can_problem(&alloc, &line_info, filename.clone(), problem);
+
+            push_report(report, &alloc, &palette, &mut problems);
+        }
+    }
+
+    for problem in type_probs {
+        if !in_expr(problem.region()) {
+            continue;
+        }
+
+        match problem {
+            TypeError::Exhaustive(roc_exhaustive::Error::Incomplete(
+                _,
+                roc_exhaustive::Context::BadCase,
+                patterns,
+            )) => {
+                for pattern in patterns {
+                    let report = Report {
+                        title: String::new(),
+                        filename: filename.clone(),
+                        doc: unhandled_patterns_to_doc_block(&alloc, vec![pattern]),
+                        severity: Severity::RuntimeError,
+                    };
+                    let mut buf = String::new();
+
+                    report.render_color_terminal(&mut buf, &alloc, &palette);
+                    missing.push(buf);
+                }
+            }
+            problem => {
+                if let Some(report) = type_problem(&alloc, &line_info, filename.clone(), problem) {
+                    push_report(report, &alloc, &palette, &mut problems);
+                }
+            }
+        }
+    }
+
+    (Some(loaded), missing, problems)
+}
+
+/// Renders a problem that stopped the load before the expression could be checked.
+fn loading_problem_report(problem: LoadingProblem) -> String {
+    match problem {
+        LoadingProblem::FormattedReport(report) => report,
+        LoadingProblem::FileProblem { filename, error } => {
+            to_file_problem_report_string(filename, error)
+        }
+        problem => format!("The REPL could not load this expression: {problem:?}"),
+    }
+}
+
+fn push_report<'b>(
+    report: Report<'b>,
+    alloc: &'b RocDocAllocator<'b>,
+    palette: &'b Palette,
+    problems: &mut Problems,
+) {
+    let severity = report.severity;
+    let mut buf = String::new();
+
+    report.render_color_terminal(&mut buf, alloc, palette);
+
+    match severity {
+        Severity::Warning => problems.warnings.push(buf),
+        Severity::Fatal | Severity::RuntimeError => problems.errors.push(buf),
+    }
+}
+
 fn promote_expr_to_module<'a, 'i, I: Iterator<Item = &'i str>>(
     arena: &'a Bump,
     defs: I,
@@ -123,7 +123,12 @@ mod test {
             render: RenderTarget::ColorTerminal,
             palette: DEFAULT_PALETTE,
             threading: Threading::Single,
-            exec_mode: ExecutionMode::Test,
+            exec_mode: ExecutionMode::Test(Default::default()),
+            optimize_ir: false,
+            report_expects_with_dbg: false,
+            cancellation: None,
+            problem_sink: None,
+            defines: Default::default(),
         };
         let loaded = match roc_load::load_and_monomorphize_from_str(
             arena,
@@ -177,6 +182,8 @@ mod test {
                 &mut expectations,
                 expect_funcs,
                 &mut memory,
+                &mut Vec::new(),
+                true,
             )
             .unwrap();
         }
@@ -544,6 +551,11 @@ mod test {
                 8│>
                 9│>      a == b
 
+                The strings differ (- expected, + actual):
+
+                    - Profundum et fundamentum
+                    + Astra mortemque praestare gradatim
+
                 When it failed, these variables had these values:
 
                 a : Str
@@ -556,6 +568,44 @@ mod test {
         );
     }
 
+    #[test]
+    fn compare_string_with_literal() {
+        run_expect_test(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = 0
+
+                expect
+                    greeting = "Hello, World!"
+
+                    greeting == "Hello, world!"
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation failed:
+
+                5│>  expect
+                6│>      greeting = "Hello, World!"
+                7│>
+                8│>      greeting == "Hello, world!"
+
+                The strings differ (- expected, + actual):
+
+                    - Hello, world!
+                    + Hello, World!
+
+                When it failed, these variables had these values:
+
+                greeting : Str
+                greeting = "Hello, World!"
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn struct_with_strings() {
         run_expect_test(
@@ -1248,4 +1298,51 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn update_keeps_block_string_quoting() {
+        use crate::run::{apply_snapshot_updates, SnapshotUpdate};
+        use roc_region::all::{Position, Region};
+
+        let source = indoc!(
+            r#"
+            expect
+                actual = greet "World"
+                actual == """
+                          Hello,
+                          world!
+                          """
+            "#
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Test.roc");
+        std::fs::write(&path, source).unwrap();
+
+        let start = source.find("\"\"\"").unwrap();
+        let end = source.rfind("\"\"\"").unwrap() + 3;
+        let update = || SnapshotUpdate {
+            path: path.clone(),
+            region: Region::new(Position::new(start as u32), Position::new(end as u32)),
+            actual: "Hello,\nWorld!".to_string(),
+        };
+
+        assert_eq!(apply_snapshot_updates(vec![update()]).unwrap(), 1);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            indoc!(
+                r#"
+                expect
+                    actual = greet "World"
+                    actual == """
+                              Hello,
+                              World!
+                              """
+                "#
+            )
+        );
+
+        // The literal already holds the actual string, so there is nothing left to update.
+        assert_eq!(apply_snapshot_updates(vec![update()]).unwrap(), 0);
+    }
 }
@@ -25,10 +25,151 @@ use roc_mono::{
     layout::{GlobalLayoutInterner, STLayoutInterner},
 };
 use roc_region::all::Region;
-use roc_reporting::{error::expect::Renderer, report::RenderTarget};
+use roc_reporting::{
+    error::expect::{Renderer, StrEquality},
+    report::RenderTarget,
+};
 use roc_target::Target;
 use roc_types::subs::Subs;
 
+/// A string literal in a failed `expect` that `roc test --update` replaces with the string it
+/// was compared against.
+pub struct SnapshotUpdate {
+    pub path: std::path::PathBuf,
+    pub region: Region,
+    pub actual: String,
+}
+
+/// Rewrite the string literals of failed snapshot-style expectations in their source files.
+/// Returns how many literals were updated.
+pub fn apply_snapshot_updates(updates: Vec<SnapshotUpdate>) -> std::io::Result<usize> {
+    let mut updates_by_path: MutMap<std::path::PathBuf, Vec<(Region, String)>> = MutMap::default();
+
+    for SnapshotUpdate {
+        path,
+        region,
+        actual,
+    } in updates
+    {
+        updates_by_path
+            .entry(path)
+            .or_default()
+            .push((region, actual));
+    }
+
+    let mut applied = 0;
+
+    for (path, mut updates) in updates_by_path {
+        let original = std::fs::read_to_string(&path)?;
+        let mut source = original.clone();
+
+        // Replace back to front, so earlier regions stay valid. The same expectation can fail
+        // more than once; only its first failure is used.
+        updates.sort_by_key(|(region, _)| std::cmp::Reverse(region.start()));
+        updates.dedup_by_key(|(region, _)| *region);
+
+        for (region, actual) in updates {
+            let range = region.byte_range();
+
+            let new_literal = match source.get(range.clone()) {
+                Some(old)
+                    if old.len() >= 6 && old.starts_with("\"\"\"") && old.ends_with("\"\"\"") =>
+                {
+                    let line_start = source[..range.start].rfind('\n').map_or(0, |i| i + 1);
+
+                    block_str_literal(&actual, range.start - line_start)
+                }
+                Some(old) if old.starts_with('"') && old.ends_with('"') => str_literal(&actual),
+                _ => continue,
+            };
+
+            if source[range.clone()] != new_literal {
+                source.replace_range(range, &new_literal);
+                applied += 1;
+            }
+        }
+
+        if source != original {
+            std::fs::write(&path, source)?;
+        }
+    }
+
+    Ok(applied)
+}
+
+/// Render a string as a single-line Roc string literal.
+fn str_literal(string: &str) -> String {
+    let mut literal = String::with_capacity(string.len() + 2);
+
+    literal.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => literal.push_str("\\\""),
+            _ => push_escaped(&mut literal, ch),
+        }
+    }
+
+    literal.push('"');
+
+    literal
+}
+
+/// Render a string as a triple-quoted Roc string literal whose opening quotes are `indent`
+/// columns into their line. A string with several lines puts each of them, and the closing
+/// quotes, on a line of its own.
+fn block_str_literal(string: &str, indent: usize) -> String {
+    let mut literal = String::with_capacity(string.len() + 6);
+
+    literal.push_str("\"\"\"");
+
+    let is_multiline = string.contains('\n');
+
+    for line in string.split('\n') {
+        if is_multiline {
+            literal.push('\n');
+
+            if !line.is_empty() {
+                literal.push_str(&" ".repeat(indent));
+            }
+        }
+
+        let mut chars = line.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                // Only a quote that could run into the closing quotes needs escaping.
+                '"' if matches!(chars.peek(), Some('"') | None) => literal.push_str("\\\""),
+                '"' => literal.push('"'),
+                _ => push_escaped(&mut literal, ch),
+            }
+        }
+    }
+
+    if is_multiline {
+        literal.push('\n');
+        literal.push_str(&" ".repeat(indent));
+    }
+
+    literal.push_str("\"\"\"");
+
+    literal
+}
+
+fn push_escaped(literal: &mut String, ch: char) {
+    match ch {
+        '\\' => literal.push_str("\\\\"),
+        '$' => literal.push_str("\\$"),
+        '\n' => literal.push_str("\\n"),
+        '\r' => literal.push_str("\\r"),
+        '\t' => literal.push_str("\\t"),
+        '\u{0000}'..='\u{001f}' | '\u{007f}'..='\u{009f}' => {
+            literal.push_str(&format!("\\u({:x})", ch as u32))
+        }
+        _ => literal.push(ch),
+    }
+}
+
 pub struct ExpectMemory<'a> {
     ptr: *mut u8,
     length: usize,
@@ -148,9 +289,14 @@ pub fn run_inline_expects<'a, W: std::io::Write>(
         expectations,
         expects,
         &mut memory,
+        &mut Vec::new(),
+        true,
     )
 }
 
+/// Run the top-level expects of a module. Failed string comparisons against a literal are added
+/// to `snapshot_updates`, so `roc test --update` can rewrite them, and `show_values` decides
+/// whether failures show the values of the variables in the condition.
 #[allow(clippy::too_many_arguments)]
 pub fn run_toplevel_expects<'a, W: std::io::Write>(
     writer: &mut W,
@@ -161,6 +307,8 @@ pub fn run_toplevel_expects<'a, W: std::io::Write>(
     lib: &libloading::Library,
     expectations: &mut VecMap<ModuleId, Expectations>,
     expects: ExpectFunctions<'_>,
+    snapshot_updates: &mut Vec<SnapshotUpdate>,
+    show_values: bool,
 ) -> std::io::Result<(usize, usize)> {
     let shm_name = format!("/roc_expect_buffer_{}", std::process::id());
     let mut memory = ExpectMemory::create_or_reuse_mmap(&shm_name);
@@ -175,9 +323,367 @@ pub fn run_toplevel_expects<'a, W: std::io::Write>(
         expectations,
         expects,
         &mut memory,
+        snapshot_updates,
+        show_values,
     )
 }
 
+/// How a top-level expect that ran in its own process ended.
+pub enum IsolatedExpectOutcome {
+    Passed,
+    /// The rendered report of the failure, and the literals `roc test --update` could replace.
+    Failed {
+        report: Vec<u8>,
+        snapshot_updates: Vec<SnapshotUpdate>,
+    },
+    TimedOut,
+}
+
+/// A top-level expect that is running in a forked process.
+struct IsolatedExpect {
+    /// Where the expect is in the list that was passed in.
+    index: usize,
+    pid: libc::pid_t,
+    pipe: std::fs::File,
+    bytes: Vec<u8>,
+    start_time: std::time::Instant,
+}
+
+/// Run top-level expects in forked processes, at most `jobs` at a time, and kill any that runs
+/// longer than `timeout`. The expect buffer of the dylib is a global, so this is what lets several
+/// expects run at once. Each child renders its own report and sends it back over a pipe. Returns
+/// the outcomes in the order of `expects`, with how long each one took.
+///
+/// Every child is forked from the calling thread, while it waits for the others, so this must be
+/// called while no other thread is running: a child gets a copy of the calling thread only, and
+/// a lock that another thread held at the fork would stay locked in it forever.
+#[allow(clippy::too_many_arguments)]
+pub fn run_toplevel_expects_isolated<'a>(
+    render_target: RenderTarget,
+    interns: &'a Interns,
+    layout_interner: &GlobalLayoutInterner<'a>,
+    lib: &libloading::Library,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    expects: &[(bool, ToplevelExpect<'_>)],
+    show_values: bool,
+    jobs: usize,
+    timeout: std::time::Duration,
+) -> std::io::Result<Vec<(IsolatedExpectOutcome, std::time::Duration)>> {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+
+    let mut outcomes: Vec<_> = expects.iter().map(|_| None).collect();
+    let mut running: Vec<IsolatedExpect> = Vec::new();
+    let mut next = 0;
+    let mut buf = [0; 4096];
+
+    while next < expects.len() || !running.is_empty() {
+        while running.len() < jobs.max(1) && next < expects.len() {
+            let (is_fx, expect) = expects[next];
+
+            let forked = fork_expect(
+                render_target,
+                interns,
+                layout_interner,
+                lib,
+                expectations,
+                expect,
+                is_fx,
+                show_values,
+            );
+
+            match forked {
+                Ok((pid, pipe)) => running.push(IsolatedExpect {
+                    index: next,
+                    pid,
+                    pipe,
+                    bytes: Vec::new(),
+                    start_time: std::time::Instant::now(),
+                }),
+                Err(error) => {
+                    for child in running {
+                        finish_isolated_expect(child, true);
+                    }
+
+                    return Err(error);
+                }
+            }
+
+            next += 1;
+        }
+
+        // Wait until a child sends something, or the first one runs out of time.
+        let deadline = running
+            .iter()
+            .map(|child| child.start_time + timeout)
+            .min()
+            .unwrap();
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let millis = remaining.as_millis().min(i32::MAX as u128) as i32;
+
+        let mut pollfds: Vec<_> = running
+            .iter()
+            .map(|child| libc::pollfd {
+                fd: child.pipe.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            })
+            .collect();
+
+        if unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as _, millis) } == -1 {
+            let error = std::io::Error::last_os_error();
+
+            if error.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+
+            for child in running {
+                finish_isolated_expect(child, true);
+            }
+
+            return Err(error);
+        }
+
+        let mut still_running = Vec::with_capacity(running.len());
+
+        for (mut child, pollfd) in running.into_iter().zip(pollfds) {
+            // `Some(timed_out)` once the child is done
+            let done = if pollfd.revents != 0 {
+                match child.pipe.read(&mut buf) {
+                    Ok(0) => Some(false),
+                    Ok(n) => {
+                        child.bytes.extend_from_slice(&buf[..n]);
+
+                        None
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => None,
+                    Err(_) => Some(false),
+                }
+            } else {
+                None
+            };
+
+            let done = done.or_else(|| (child.start_time.elapsed() >= timeout).then_some(true));
+
+            match done {
+                Some(timed_out) => {
+                    let index = child.index;
+                    let duration = child.start_time.elapsed();
+
+                    outcomes[index] = Some((finish_isolated_expect(child, timed_out), duration));
+                }
+                None => still_running.push(child),
+            }
+        }
+
+        running = still_running;
+    }
+
+    Ok(outcomes.into_iter().map(Option::unwrap).collect())
+}
+
+/// Forks a process that runs the expect, and returns its pid and the pipe it sends its outcome
+/// over.
+#[allow(clippy::too_many_arguments)]
+fn fork_expect<'a>(
+    render_target: RenderTarget,
+    interns: &'a Interns,
+    layout_interner: &GlobalLayoutInterner<'a>,
+    lib: &libloading::Library,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    expect: ToplevelExpect<'_>,
+    is_fx: bool,
+    show_values: bool,
+) -> std::io::Result<(libc::pid_t, std::fs::File)> {
+    use std::io::Write;
+    use std::os::fd::FromRawFd;
+
+    let mut fds = [0; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let [read_fd, write_fd] = fds;
+
+    match unsafe { libc::fork() } {
+        0 => unsafe {
+            // we are the child; put it in its own process group, so a timeout also kills the
+            // process that an effectful expect forks
+            libc::setpgid(0, 0);
+            libc::close(read_fd);
+
+            // the child exits right after this, so the arena never needs to be freed
+            let arena: &'a Bump = Box::leak(Box::default());
+
+            let shm_name = format!("/roc_expect_buffer_{}", std::process::id());
+            let mut memory = ExpectMemory::create_or_reuse_mmap(&shm_name);
+
+            let mut expects = ExpectFunctions {
+                pure: BumpVec::new_in(arena),
+                fx: BumpVec::new_in(arena),
+            };
+
+            if is_fx {
+                expects.fx.push(expect);
+            } else {
+                expects.pure.push(expect);
+            }
+
+            let mut report = Vec::new();
+            let mut snapshot_updates = Vec::new();
+
+            let result = run_expects_with_memory(
+                &mut report,
+                render_target,
+                arena,
+                interns,
+                layout_interner,
+                lib,
+                expectations,
+                expects,
+                &mut memory,
+                &mut snapshot_updates,
+                show_values,
+            );
+
+            let status = match result {
+                Ok((failed, _)) => {
+                    let bytes = encode_outcome(failed == 0, &report, &snapshot_updates);
+                    let mut pipe = std::fs::File::from_raw_fd(write_fd);
+
+                    pipe.write_all(&bytes).is_err() as i32
+                }
+                Err(_) => 1,
+            };
+
+            // the child only sends its outcome back, so it skips the exit handlers of the parent
+            libc::_exit(status)
+        },
+        -1 => {
+            let error = std::io::Error::last_os_error();
+
+            unsafe {
+                libc::close(read_fd);
+                libc::close(write_fd);
+            }
+
+            Err(error)
+        }
+        pid => {
+            unsafe { libc::close(write_fd) };
+
+            Ok((pid, unsafe { std::fs::File::from_raw_fd(read_fd) }))
+        }
+    }
+}
+
+/// Waits for the child of an expect to exit, killing it first if it timed out, and decodes what
+/// it sent.
+fn finish_isolated_expect(child: IsolatedExpect, timed_out: bool) -> IsolatedExpectOutcome {
+    let pid = child.pid;
+
+    unsafe {
+        if timed_out && libc::kill(-pid, libc::SIGKILL) == -1 {
+            libc::kill(pid, libc::SIGKILL);
+        }
+
+        let mut status = 0;
+        libc::waitpid(pid, &mut status, 0);
+
+        // the child can't clean up its expect buffer if it was killed or crashed
+        let shm_name = std::ffi::CString::new(format!("/roc_expect_buffer_{pid}")).unwrap();
+        libc::shm_unlink(shm_name.as_ptr());
+    }
+
+    if timed_out {
+        return IsolatedExpectOutcome::TimedOut;
+    }
+
+    decode_outcome(&child.bytes).unwrap_or_else(|| IsolatedExpectOutcome::Failed {
+        report: b"This expectation crashed the process that ran it.\n\n".to_vec(),
+        snapshot_updates: Vec::new(),
+    })
+}
+
+fn encode_outcome(passed: bool, report: &[u8], snapshot_updates: &[SnapshotUpdate]) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    fn push_bytes(bytes: &mut Vec<u8>, slice: &[u8]) {
+        bytes.extend_from_slice(&(slice.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(slice);
+    }
+
+    let mut bytes = vec![passed as u8];
+
+    push_bytes(&mut bytes, report);
+    bytes.extend_from_slice(&(snapshot_updates.len() as u64).to_le_bytes());
+
+    for update in snapshot_updates {
+        push_bytes(&mut bytes, update.path.as_os_str().as_bytes());
+        bytes.extend_from_slice(&update.region.start().offset.to_le_bytes());
+        bytes.extend_from_slice(&update.region.end().offset.to_le_bytes());
+        push_bytes(&mut bytes, update.actual.as_bytes());
+    }
+
+    bytes
+}
+
+fn decode_outcome(mut bytes: &[u8]) -> Option<IsolatedExpectOutcome> {
+    use roc_region::all::Position;
+    use std::os::unix::ffi::OsStrExt;
+
+    fn take<'b>(bytes: &mut &'b [u8], n: usize) -> Option<&'b [u8]> {
+        if bytes.len() < n {
+            return None;
+        }
+
+        let (taken, rest) = bytes.split_at(n);
+        *bytes = rest;
+
+        Some(taken)
+    }
+
+    fn take_u64(bytes: &mut &[u8]) -> Option<u64> {
+        Some(u64::from_le_bytes(take(bytes, 8)?.try_into().ok()?))
+    }
+
+    fn take_u32(bytes: &mut &[u8]) -> Option<u32> {
+        Some(u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?))
+    }
+
+    fn take_bytes<'b>(bytes: &mut &'b [u8]) -> Option<&'b [u8]> {
+        let len = take_u64(bytes)?;
+        take(bytes, len as usize)
+    }
+
+    let passed = take(&mut bytes, 1)?[0] == 1;
+
+    if passed {
+        return Some(IsolatedExpectOutcome::Passed);
+    }
+
+    let report = take_bytes(&mut bytes)?.to_vec();
+    let update_count = take_u64(&mut bytes)?;
+    let mut snapshot_updates = Vec::new();
+
+    for _ in 0..update_count {
+        let path = std::ffi::OsStr::from_bytes(take_bytes(&mut bytes)?).into();
+        let start = take_u32(&mut bytes)?;
+        let end = take_u32(&mut bytes)?;
+        let actual = String::from_utf8(take_bytes(&mut bytes)?.to_vec()).ok()?;
+
+        snapshot_updates.push(SnapshotUpdate {
+            path,
+            region: Region::new(Position::new(start), Position::new(end)),
+            actual,
+        });
+    }
+
+    Some(IsolatedExpectOutcome::Failed {
+        report,
+        snapshot_updates,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     writer: &mut W,
@@ -189,6 +695,8 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     expectations: &mut VecMap<ModuleId, Expectations>,
     expects: ExpectFunctions<'_>,
     memory: &mut ExpectMemory,
+    snapshot_updates: &mut Vec<SnapshotUpdate>,
+    show_values: bool,
 ) -> std::io::Result<(usize, usize)> {
     let mut failed = 0;
     let mut passed = 0;
@@ -204,6 +712,8 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
             expectations,
             memory,
             expect,
+            snapshot_updates,
+            show_values,
         )?;
 
         match result {
@@ -225,6 +735,8 @@ pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
             expectations,
             memory,
             expect,
+            snapshot_updates,
+            show_values,
         )?;
 
         match result {
@@ -247,6 +759,8 @@ fn run_expect_pure<'a, W: std::io::Write>(
     expectations: &mut VecMap<ModuleId, Expectations>,
     shared_memory: &mut ExpectMemory,
     expect: ToplevelExpect<'_>,
+    snapshot_updates: &mut Vec<SnapshotUpdate>,
+    show_values: bool,
 ) -> std::io::Result<bool> {
     use roc_gen_llvm::try_run_jit_function;
 
@@ -264,7 +778,8 @@ fn run_expect_pure<'a, W: std::io::Write>(
         let filename = data.path.to_owned();
         let source = std::fs::read_to_string(path).unwrap();
 
-        let renderer = Renderer::new(arena, interns, render_target, module_id, filename, &source);
+        let renderer = Renderer::new(arena, interns, render_target, module_id, filename, &source)
+            .with_values(show_values);
 
         if let Err((roc_panic_message, _roc_panic_tag)) = result {
             renderer.render_panic(writer, &roc_panic_message, expect.region)?;
@@ -282,6 +797,7 @@ fn run_expect_pure<'a, W: std::io::Write>(
                     layout_interner,
                     shared_memory_ptr,
                     offset,
+                    snapshot_updates,
                 )?;
             }
         }
@@ -305,6 +821,8 @@ fn run_expect_fx<'a, W: std::io::Write>(
     expectations: &mut VecMap<ModuleId, Expectations>,
     parent_memory: &mut ExpectMemory,
     expect: ToplevelExpect<'_>,
+    snapshot_updates: &mut Vec<SnapshotUpdate>,
+    show_values: bool,
 ) -> std::io::Result<bool> {
     use signal_hook::{consts::signal::SIGCHLD, consts::signal::SIGUSR1, iterator::Signals};
 
@@ -371,7 +889,8 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             module_id,
                             filename,
                             &source,
-                        );
+                        )
+                        .with_values(show_values);
 
                         render_expect_failure(
                             writer,
@@ -383,6 +902,7 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             layout_interner,
                             parent_memory.ptr,
                             ExpectSequence::START_OFFSET,
+                            snapshot_updates,
                         )?;
                     }
                     _ => println!("received signal {sig}"),
@@ -431,6 +951,7 @@ pub fn render_expects_in_memory<'a>(
         layout_interner,
         shared_ptr,
         ExpectSequence::START_OFFSET,
+        &mut Vec::new(),
     )
 }
 
@@ -466,6 +987,7 @@ fn render_expect_failure<'a>(
     layout_interner: &GlobalLayoutInterner<'a>,
     start: *const u8,
     offset: usize,
+    snapshot_updates: &mut Vec<SnapshotUpdate>,
 ) -> std::io::Result<usize> {
     // we always run programs as the host
     let target = target_lexicon::Triple::host().into();
@@ -474,7 +996,10 @@ fn render_expect_failure<'a>(
     let module_id = frame.module_id;
 
     let failure_region = frame.region;
-    let expect_region = expect.map(|e| e.region);
+    // Regions are only comparable within a module, so an expect in another module isn't shown.
+    let expect_region = expect
+        .filter(|e| e.symbol.module_id() == module_id)
+        .map(|e| e.region);
 
     let data = expectations.get_mut(&module_id).unwrap();
 
@@ -506,6 +1031,29 @@ fn render_expect_failure<'a>(
         failure_region,
     )?;
 
+    let source = std::fs::read_to_string(&data.path)?;
+    let str_equality = StrEquality::from_failure(
+        arena,
+        interns,
+        &source,
+        failure_region,
+        &symbols,
+        &expressions,
+    );
+
+    if let Some(StrEquality {
+        actual,
+        expected_literal: Some(region),
+        ..
+    }) = str_equality
+    {
+        snapshot_updates.push(SnapshotUpdate {
+            path: data.path.clone(),
+            region,
+            actual,
+        });
+    }
+
     Ok(offset)
 }
 
@@ -657,6 +1205,8 @@ pub fn expect_mono_module_to_dylib<'a>(
         mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        leak_check: false,
+        stack_check: true,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
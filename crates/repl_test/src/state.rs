@@ -1,9 +1,9 @@
 use bumpalo::Bump;
 use indoc::indoc;
 use roc_repl_cli::{evaluate, ReplHelper};
-use roc_repl_ui::is_incomplete;
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_reporting::report::DEFAULT_PALETTE;
+use roc_repl_ui::{format_layout, format_missing, is_incomplete};
+use roc_reporting::report::{ANSI_STYLE_CODES, DEFAULT_PALETTE};
 use rustyline::Editor;
 use target_lexicon::Triple;
 
@@ -80,6 +80,71 @@ fn exhaustiveness_problem() {
     }
 }
 
+#[test]
+fn missing_patterns() {
+    let mut state = ReplState::new();
+
+    {
+        let mut input = "t : [A, B, C]".to_string();
+
+        incomplete(&mut input);
+
+        input.push_str("t = A");
+
+        complete(&input, &mut state, "A : [A, B, C]");
+    }
+
+    {
+        let mut input = ":missing when t is".to_string();
+        incomplete(&mut input);
+
+        input.push_str("    A -> 1");
+        incomplete(&mut input);
+
+        let expected: &str = indoc!(
+            r#"
+            These patterns aren't covered:
+
+                B
+                C"#
+        );
+
+        missing(&input, &mut state, expected);
+    }
+
+    {
+        let mut input = ":missing when t is".to_string();
+        incomplete(&mut input);
+
+        input.push_str("    A -> 1");
+        incomplete(&mut input);
+
+        input.push_str("    _ -> 2");
+        incomplete(&mut input);
+
+        missing(&input, &mut state, "This covers every possibility.");
+    }
+}
+
+#[test]
+fn layouts() {
+    let mut state = ReplState::new();
+
+    layout(":sizeof Str", &mut state, "size 24, alignment 8");
+    layout(":sizeof 1u8", &mut state, "size 1, alignment 1");
+
+    let expected: &str = indoc!(
+        r#"
+        size 16, alignment 8
+
+        fields, in memory order
+            offset 0    x : Float64  (size 8, alignment 8)
+            offset 8    y : Float64  (size 8, alignment 8)"#
+    );
+
+    layout(":layout { x : F64, y : F64 }", &mut state, expected);
+}
+
 #[test]
 fn partial_record_definition() {
     // Partially define a record successfully
@@ -205,3 +270,47 @@ fn error(input: &str, state: &mut ReplState, expected_step_result: String) {
         }
     }
 }
+
+/// validate and step the given `:missing` input, then check the listed patterns
+/// with ANSI escape codes stripped.
+fn missing(input: &str, state: &mut ReplState, expected: &str) {
+    assert!(!is_incomplete(input));
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = state.step(&arena, input, target, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::Missing { patterns, problems } => {
+            let string = format_missing(ANSI_STYLE_CODES, patterns, problems);
+            let escaped =
+                std::string::String::from_utf8(strip_ansi_escapes::strip(string.trim()).unwrap())
+                    .unwrap();
+            assert_eq!(expected, escaped);
+        }
+        _ => {
+            panic!("Unexpected action: {:?}", action);
+        }
+    }
+}
+
+/// validate and step the given `:layout` or `:sizeof` input, then check the layout
+/// with ANSI escape codes stripped.
+fn layout(input: &str, state: &mut ReplState, expected: &str) {
+    assert!(!is_incomplete(input));
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = state.step(&arena, input, target, DEFAULT_PALETTE);
+
+    match action {
+        ReplAction::Layout { output, problems } => {
+            let string = format_layout(ANSI_STYLE_CODES, output, problems);
+            let escaped =
+                std::string::String::from_utf8(strip_ansi_escapes::strip(string.trim()).unwrap())
+                    .unwrap();
+            assert_eq!(expected, escaped);
+        }
+        _ => {
+            panic!("Unexpected action: {:?}", action);
+        }
+    }
+}
@@ -1580,6 +1580,8 @@ fn interpolation_with_nested_interpolation() {
 
                   - ctrl-v + ctrl-j makes a newline
                   - :q quits
+                  - :missing followed by a when lists the patterns it doesn't cover
+                  - :layout followed by a type shows how it's stored in memory
                   - :help shows this text again
             "#
         ),
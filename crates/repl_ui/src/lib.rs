@@ -45,6 +45,24 @@ pub const TIPS: &str = concatcp!(
             "  - ",
             END_COL,
             GREEN,
+            ":missing",
+            END_COL,
+            " followed by a ",
+            GREEN,
+            "when",
+            END_COL,
+            " lists the patterns it doesn't cover\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
+            ":layout",
+            END_COL,
+            " followed by a type shows how it's stored in memory\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
             ":help",
             END_COL,
             " shows this text again\n",
@@ -74,7 +92,10 @@ pub fn is_incomplete(input: &str) -> bool {
             // them until they enter a blank line!
             !input.ends_with('\n')
         }
+        ParseOutcome::Missing(expr) => is_incomplete(expr),
         ParseOutcome::Empty
+        | ParseOutcome::Layout(_)
+        | ParseOutcome::SizeOf(_)
         | ParseOutcome::Help
         | ParseOutcome::Exit
         | ParseOutcome::ValueDef(_)
@@ -124,3 +145,47 @@ pub fn format_output(
 
     buf
 }
+
+pub fn format_missing(
+    style_codes: StyleCodes,
+    patterns: Vec<String>,
+    problems: Problems,
+) -> String {
+    let has_errors = !problems.errors.is_empty();
+    let mut buf = format_output(style_codes, None, problems);
+
+    // Exhaustiveness isn't checked against a type that has errors, so there's nothing to add.
+    if has_errors {
+        return buf;
+    }
+
+    buf.push('\n');
+
+    if patterns.is_empty() {
+        buf.push_str("This covers every possibility.");
+    } else {
+        buf.push_str("These patterns aren't covered:\n");
+
+        for pattern in patterns {
+            buf.push('\n');
+            buf.push_str(&pattern);
+        }
+    }
+
+    buf
+}
+
+pub fn format_layout(
+    style_codes: StyleCodes,
+    output: Option<String>,
+    problems: Problems,
+) -> String {
+    let mut buf = format_output(style_codes, None, problems);
+
+    if let Some(output) = output {
+        buf.push('\n');
+        buf.push_str(output.trim_end());
+    }
+
+    buf
+}
@@ -4,7 +4,7 @@ use std::{fs, io};
 use bumpalo::Bump;
 use roc_collections::MutSet;
 use roc_load::MonomorphizedModule;
-use roc_parse::ast::{Expr, Pattern, StrLiteral, TypeDef, TypeHeader, ValueDef};
+use roc_parse::ast::{Expr, Pattern, StrLiteral, TypeAnnotation, TypeDef, TypeHeader, ValueDef};
 use roc_parse::expr::{parse_single_def, ExprParseOptions, SingleDef};
 use roc_parse::parser::Parser;
 use roc_parse::parser::{EClosure, EExpr, EPattern};
@@ -12,7 +12,7 @@ use roc_parse::parser::{EWhen, Either};
 use roc_parse::state::State;
 use roc_parse::{join_alias_to_body, join_ann_to_body};
 use roc_region::all::Loc;
-use roc_repl_eval::gen::{compile_to_mono, Problems};
+use roc_repl_eval::gen::{compile_to_mono, layout_of, missing_patterns, LayoutOutput, Problems};
 use roc_reporting::report::Palette;
 use roc_target::Target;
 
@@ -42,6 +42,16 @@ pub enum ReplAction<'a> {
     },
     Exit,
     Help,
+    /// The patterns that a `when` given to `:missing` doesn't cover
+    Missing {
+        patterns: Vec<String>,
+        problems: Problems,
+    },
+    /// The layout that `:layout` or `:sizeof` asked for
+    Layout {
+        output: Option<String>,
+        problems: Problems,
+    },
     FileProblem {
         filename: PathBuf,
         error: io::ErrorKind,
@@ -68,6 +78,14 @@ impl ReplState {
         let src: &str = match parse_src(arena, line) {
             ParseOutcome::Empty | ParseOutcome::Help => return ReplAction::Help,
             ParseOutcome::Exit => return ReplAction::Exit,
+            ParseOutcome::Missing(expr) => {
+                let (patterns, problems) =
+                    missing_patterns(arena, self.past_def_sources(), expr, target, palette);
+
+                return ReplAction::Missing { patterns, problems };
+            }
+            ParseOutcome::Layout(arg) => return self.layout(arena, arg, target, palette, true),
+            ParseOutcome::SizeOf(arg) => return self.layout(arena, arg, target, palette, false),
             ParseOutcome::Expr(_) | ParseOutcome::Incomplete | ParseOutcome::SyntaxErr => {
                 pending_past_def = None;
 
@@ -220,16 +238,8 @@ impl ReplState {
             }
         };
 
-        let (opt_mono, problems) = compile_to_mono(
-            arena,
-            self.past_defs.iter().map(|past_def| match past_def {
-                PastDef::Def { ident: _, src } => src.as_str(),
-                PastDef::Import(src) => src.as_str(),
-            }),
-            src,
-            target,
-            palette,
-        );
+        let (opt_mono, problems) =
+            compile_to_mono(arena, self.past_def_sources(), src, target, palette);
 
         if let Some((ident, src)) = pending_past_def {
             self.add_past_def(ident, src);
@@ -238,6 +248,56 @@ impl ReplState {
         ReplAction::Eval { opt_mono, problems }
     }
 
+    /// Describes the layout of a type, or of the type of an expression. With `full_report` set,
+    /// that includes its fields and tags; otherwise it's just the size and alignment.
+    fn layout<'a>(
+        &self,
+        arena: &'a Bump,
+        arg: &str,
+        target: Target,
+        palette: Palette,
+        full_report: bool,
+    ) -> ReplAction<'a> {
+        let arg = arg.trim();
+
+        if arg.is_empty() {
+            return ReplAction::Help;
+        }
+
+        // A type is given a value, so that it can be looked at like any other expression.
+        let src = if is_type(arena, arg) {
+            format!("{LAYOUT_DEF} : {arg}\n{LAYOUT_DEF} = crash \"\"\n{LAYOUT_DEF}")
+        } else {
+            arg.to_string()
+        };
+
+        let (opt_layout, problems) =
+            layout_of(arena, self.past_def_sources(), &src, target, palette);
+
+        let output = opt_layout.map(
+            |LayoutOutput {
+                 size,
+                 alignment,
+                 report,
+             }| {
+                if full_report {
+                    report
+                } else {
+                    format!("size {size}, alignment {alignment}")
+                }
+            },
+        );
+
+        ReplAction::Layout { output, problems }
+    }
+
+    fn past_def_sources(&self) -> impl Iterator<Item = &str> {
+        self.past_defs.iter().map(|past_def| match past_def {
+            PastDef::Def { ident: _, src } => src.as_str(),
+            PastDef::Import(src) => src.as_str(),
+        })
+    }
+
     fn add_past_def(&mut self, ident: String, src: String) {
         let existing_idents = &mut self.past_def_idents;
 
@@ -247,6 +307,13 @@ impl ReplState {
     }
 }
 
+pub const MISSING_COMMAND: &str = ":missing";
+pub const LAYOUT_COMMAND: &str = ":layout";
+pub const SIZEOF_COMMAND: &str = ":sizeof";
+
+/// The def that a type given to `:layout` or `:sizeof` is the type of
+const LAYOUT_DEF: &str = "replLayoutOf";
+
 #[derive(Debug, PartialEq)]
 pub enum ParseOutcome<'a> {
     ValueDef(ValueDef<'a>),
@@ -257,9 +324,51 @@ pub enum ParseOutcome<'a> {
     Empty,
     Help,
     Exit,
+    /// `:missing` followed by the `when` expression whose missing patterns should be listed
+    Missing(&'a str),
+    /// `:layout` followed by a type, or an expression of the type
+    Layout(&'a str),
+    /// `:sizeof` followed by a type, or an expression of the type
+    SizeOf(&'a str),
+}
+
+/// What follows the command, if the line starts with it.
+fn command_arg<'a>(line: &'a str, command: &str) -> Option<&'a str> {
+    let arg = line.trim_start().strip_prefix(command)?;
+
+    if arg.is_empty() || arg.starts_with(char::is_whitespace) {
+        Some(arg)
+    } else {
+        None
+    }
+}
+
+/// Whether the argument of `:layout` or `:sizeof` is a type, rather than an expression. A lone
+/// type variable can't be laid out, so that's taken to be a lookup instead.
+fn is_type(arena: &Bump, arg: &str) -> bool {
+    let state = State::new(arg.as_bytes());
+
+    match roc_parse::type_annotation::located(true).parse(arena, state, 0) {
+        Ok((_, loc_ann, rest)) if rest.has_reached_end() => {
+            !matches!(loc_ann.value, TypeAnnotation::BoundVariable(_))
+        }
+        _ => false,
+    }
 }
 
 pub fn parse_src<'a>(arena: &'a Bump, line: &'a str) -> ParseOutcome<'a> {
+    if let Some(expr) = command_arg(line, MISSING_COMMAND) {
+        return ParseOutcome::Missing(expr);
+    }
+
+    if let Some(arg) = command_arg(line, LAYOUT_COMMAND) {
+        return ParseOutcome::Layout(arg);
+    }
+
+    if let Some(arg) = command_arg(line, SIZEOF_COMMAND) {
+        return ParseOutcome::SizeOf(arg);
+    }
+
     match line.trim().to_lowercase().as_str() {
         "" => ParseOutcome::Empty,
         ":help" => ParseOutcome::Help,
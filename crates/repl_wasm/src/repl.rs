@@ -12,7 +12,7 @@ use roc_repl_eval::{
     ReplApp, ReplAppMemory,
 };
 use roc_repl_ui::{
-    format_output,
+    format_layout, format_missing, format_output,
     repl_state::{ReplAction, ReplState},
     TIPS,
 };
@@ -206,6 +206,12 @@ pub async fn entrypoint_from_js(src: String) -> String {
         ReplAction::FileProblem { .. } => {
             "The web version of the REPL cannot import files... for now!".to_string()
         }
+        ReplAction::Missing { patterns, problems } => {
+            format_missing(HTML_STYLE_CODES, patterns, problems)
+        }
+        ReplAction::Layout { output, problems } => {
+            format_layout(HTML_STYLE_CODES, output, problems)
+        }
         ReplAction::Nothing => String::new(),
         ReplAction::Eval { opt_mono, problems } => {
             let opt_output = match opt_mono {
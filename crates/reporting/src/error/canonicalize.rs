@@ -1,4 +1,5 @@
 use roc_collections::all::MutSet;
+use roc_module::called_via::BinOp;
 use roc_module::ident::{Ident, Lowercase, ModuleName};
 use roc_module::symbol::{ScopeModuleSource, DERIVABLE_ABILITIES};
 use roc_problem::can::PrecedenceProblem::BothNonAssociative;
@@ -27,8 +28,12 @@ const UNBOUND_TYPE_VARIABLE: &str = "UNBOUND TYPE VARIABLE";
 const UNUSED_ARG: &str = "UNUSED ARGUMENT";
 const MISSING_DEFINITION: &str = "MISSING DEFINITION";
 const UNKNOWN_GENERATES_WITH: &str = "UNKNOWN GENERATES FUNCTION";
+const UNAVAILABLE_BUILTIN: &str = "UNAVAILABLE BUILTIN";
+const UNAVAILABLE_NOT_BUILTIN: &str = "UNAVAILABLE NOT BUILTIN";
 const DUPLICATE_FIELD_NAME: &str = "DUPLICATE FIELD NAME";
 const DUPLICATE_TAG_NAME: &str = "DUPLICATE TAG NAME";
+const UNCHANGED_RECORD_FIELD: &str = "UNCHANGED RECORD FIELD";
+const IDENTITY_RECORD_UPDATE: &str = "IDENTITY RECORD UPDATE";
 const INVALID_UNICODE: &str = "INVALID UNICODE";
 pub const CIRCULAR_DEF: &str = "CIRCULAR DEFINITION";
 const DUPLICATE_NAME: &str = "DUPLICATE NAME";
@@ -299,6 +304,44 @@ pub fn can_problem<'b>(
 
             title = UNKNOWN_GENERATES_WITH.to_string();
         }
+        Problem::UnavailableBuiltin { symbol, region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This uses "),
+                    alloc.symbol_qualified(symbol),
+                    alloc.reflow(", but the platform doesn't support the "),
+                    alloc.module(symbol.module_id()),
+                    alloc.reflow(" module:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("The platform lists "),
+                    alloc.module(symbol.module_id()),
+                    alloc.reflow(" as "),
+                    alloc.keyword("unavailable"),
+                    alloc.reflow(
+                        " in its header, so apps built on it can't use anything from that module.",
+                    ),
+                ]),
+            ]);
+
+            title = UNAVAILABLE_BUILTIN.to_string();
+        }
+        Problem::UnavailableNotBuiltin(module_name, region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This platform lists "),
+                    alloc.module_name(module_name),
+                    alloc.reflow(" as "),
+                    alloc.keyword("unavailable"),
+                    alloc.reflow(", but it isn't a builtin module:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("Only builtin modules like Dict and Set can be made unavailable."),
+            ]);
+
+            title = UNAVAILABLE_NOT_BUILTIN.to_string();
+        }
         Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region) => {
             let line = "\". Adding an underscore at the start of a variable name is a way of saying that the variable is not used.";
 
@@ -353,7 +396,17 @@ pub fn can_problem<'b>(
 
             title = UNUSED_DEF.to_string();
         }
-        Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op)) => {
+        Problem::PrecedenceProblem(BothNonAssociative(
+            region,
+            left_bin_op,
+            right_bin_op,
+            operands,
+        )) if is_ordering(left_bin_op.value) && is_ordering(right_bin_op.value) => {
+            doc = chained_comparison(alloc, lines, region, left_bin_op, right_bin_op, operands);
+
+            title = SYNTAX_PROBLEM.to_string();
+        }
+        Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op, _)) => {
             doc = alloc.stack([
                 if left_bin_op.value == right_bin_op.value {
                     alloc.concat([
@@ -545,6 +598,50 @@ pub fn can_problem<'b>(
                 record_region,
             );
         }
+        Problem::UnchangedRecordUpdateField {
+            record,
+            field_name,
+            record_region,
+            field_region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This record update sets the "),
+                    alloc.record_field(field_name.clone()),
+                    alloc.reflow(" field to the value it already has:"),
+                ]),
+                alloc.region_with_subregion(
+                    lines.convert_region(record_region),
+                    lines.convert_region(field_region),
+                ),
+                alloc.concat([
+                    alloc.reflow("Copying "),
+                    alloc.record_field(field_name),
+                    alloc.reflow(" from "),
+                    alloc.symbol_unqualified(record),
+                    alloc.reflow(" back into "),
+                    alloc.symbol_unqualified(record),
+                    alloc.reflow(" doesn't change anything, so this field can be removed."),
+                ]),
+            ]);
+
+            title = UNCHANGED_RECORD_FIELD.to_string();
+        }
+        Problem::IdentityRecordUpdate { record, region } => {
+            doc = alloc.stack([
+                alloc.reflow("This record update doesn't change anything:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Every field is set to the value it already has in "),
+                    alloc.symbol_unqualified(record),
+                    alloc.reflow(", so this whole expression can be replaced with just "),
+                    alloc.symbol_unqualified(record),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            title = IDENTITY_RECORD_UPDATE.to_string();
+        }
         Problem::DuplicateRecordFieldType {
             field_name,
             field_region,
@@ -1286,6 +1383,84 @@ fn to_invalid_optional_value_report_help<'b>(
     ])
 }
 
+fn is_ordering(op: BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::LessThan | BinOp::GreaterThan | BinOp::LessThanOrEq | BinOp::GreaterThanOrEq
+    )
+}
+
+/// A report for comparisons like `a < b < c`, which is more likely a range check than an attempt
+/// to compare a `Bool`.
+fn chained_comparison<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    region: Region,
+    left_bin_op: Loc<BinOp>,
+    right_bin_op: Loc<BinOp>,
+    operands: [Region; 3],
+) -> RocDocBuilder<'b> {
+    let intro = alloc.concat([
+        alloc.reflow("Comparisons like "),
+        alloc.binop(left_bin_op.value),
+        alloc.reflow(" can't be chained, because each one gives a "),
+        alloc.type_str("Bool"),
+        alloc.reflow(":"),
+    ]);
+
+    // The operands as written, if each of them is on a single line
+    let operand_texts: Option<Vec<&str>> = operands
+        .iter()
+        .map(|operand| {
+            let region = lines.convert_region(*operand);
+
+            if region.start.line != region.end.line {
+                return None;
+            }
+
+            alloc
+                .src_lines
+                .get(region.start.line as usize)?
+                .get(region.start.column as usize..region.end.column as usize)
+        })
+        .collect();
+
+    let combine = alloc.concat([
+        alloc.reflow("To check both comparisons, combine them with "),
+        alloc.binop(BinOp::And),
+    ]);
+
+    match operand_texts {
+        Some(texts) => {
+            let suggestion = format!(
+                "{} {} {} {} {} {} {}",
+                texts[0],
+                left_bin_op.value,
+                texts[1],
+                BinOp::And,
+                texts[1],
+                right_bin_op.value,
+                texts[2]
+            );
+
+            alloc.stack([
+                intro,
+                alloc.region(lines.convert_region(region)),
+                combine.append(alloc.reflow(":")),
+                alloc
+                    .string(suggestion)
+                    .annotate(Annotation::ParserSuggestion)
+                    .indent(4),
+            ])
+        }
+        None => alloc.stack([
+            intro,
+            alloc.region(lines.convert_region(region)),
+            combine.append(alloc.reflow(".")),
+        ]),
+    }
+}
+
 fn to_bad_ident_expr_report<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
@@ -1,10 +1,11 @@
 use std::path::PathBuf;
 
 use bumpalo::Bump;
+use roc_module::called_via::BinOp;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
-use roc_parse::ast::Expr;
+use roc_parse::ast::{Expr, StrLiteral, StrSegment};
 use roc_problem::Severity;
-use roc_region::all::{LineColumnRegion, LineInfo, Region};
+use roc_region::all::{LineColumnRegion, LineInfo, Loc, Position, Region};
 use roc_types::{
     subs::{Subs, Variable},
     types::{ErrorType, Polarity},
@@ -16,8 +17,179 @@ pub struct Renderer<'a> {
     arena: &'a Bump,
     alloc: RocDocAllocator<'a>,
     filename: PathBuf,
+    source: &'a str,
     line_info: LineInfo,
     render_target: RenderTarget,
+    show_values: bool,
+}
+
+/// A failed `expect lhs == rhs` where both sides are strings.
+pub struct StrEquality {
+    /// The string that was computed.
+    pub actual: String,
+    /// The string it was compared against.
+    pub expected: String,
+    /// The region of the expected string, if it is written as a literal in the source.
+    pub expected_literal: Option<Region>,
+}
+
+impl StrEquality {
+    /// Find the strings compared by a failed expectation. Each side of the `==` must be either a
+    /// string literal or a variable whose value was recorded when the expectation failed.
+    pub fn from_failure(
+        arena: &Bump,
+        interns: &Interns,
+        source: &str,
+        failure_region: Region,
+        symbols: &[Symbol],
+        expressions: &[Expr<'_>],
+    ) -> Option<Self> {
+        let start = failure_region.start().offset;
+        let end = failure_region.end().offset;
+        let line_start = source
+            .get(..start as usize)?
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+
+        // Pad the condition to its original column, so its indentation still lines up.
+        let column = start - line_start as u32;
+        let mut condition = " ".repeat(column as usize);
+        condition.push_str(source.get(start as usize..end as usize)?);
+        let condition = arena.alloc_str(&condition);
+
+        let state = roc_parse::state::State::new(condition.as_bytes());
+        let loc_condition = roc_parse::expr::test_parse_expr(0, arena, state).ok()?;
+
+        let (lhs, rhs) = match without_spaces(&loc_condition.value) {
+            Expr::BinOps([(lhs, op)], rhs) if op.value == BinOp::Equals => (lhs, *rhs),
+            _ => return None,
+        };
+
+        // Either a string literal, along with its region in the source, or a string value.
+        let side = |loc_expr: &Loc<Expr<'_>>| match without_spaces(&loc_expr.value) {
+            Expr::Str(literal) => {
+                let region = Region::new(
+                    Position::new(start - column + loc_expr.region.start().offset),
+                    Position::new(start - column + loc_expr.region.end().offset),
+                );
+
+                Some((str_literal_value(literal)?, Some(region)))
+            }
+            Expr::Var {
+                module_name: "",
+                ident,
+            } => {
+                let index = symbols
+                    .iter()
+                    .position(|symbol| symbol.as_str(interns) == *ident)?;
+
+                match expressions.get(index)? {
+                    Expr::Str(literal) => Some((str_literal_value(literal)?, None)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let (lhs, lhs_literal) = side(lhs)?;
+        let (rhs, rhs_literal) = side(rhs)?;
+
+        // The literal side holds the expected value; otherwise read it as `actual == expected`.
+        if lhs_literal.is_some() && rhs_literal.is_none() {
+            Some(Self {
+                actual: rhs,
+                expected: lhs,
+                expected_literal: lhs_literal,
+            })
+        } else {
+            Some(Self {
+                actual: lhs,
+                expected: rhs,
+                expected_literal: rhs_literal,
+            })
+        }
+    }
+}
+
+/// Skip spaces, parens and any defs that come before the final expression.
+fn without_spaces<'b>(mut expr: &'b Expr<'b>) -> &'b Expr<'b> {
+    loop {
+        match expr {
+            Expr::SpaceBefore(inner, _)
+            | Expr::SpaceAfter(inner, _)
+            | Expr::ParensAround(inner) => expr = *inner,
+            Expr::Defs(_, final_expr) => expr = &final_expr.value,
+            _ => return expr,
+        }
+    }
+}
+
+fn str_literal_value(literal: &StrLiteral<'_>) -> Option<String> {
+    match literal {
+        StrLiteral::PlainLine(string) => Some(string.to_string()),
+        StrLiteral::Line(segments) => {
+            let mut string = String::new();
+
+            for segment in segments.iter() {
+                match segment {
+                    StrSegment::Plaintext(text) => string.push_str(text),
+                    StrSegment::EscapedChar(escaped) => string.push(escaped.unescape()),
+                    StrSegment::Unicode(loc_hex) => {
+                        let code_point = u32::from_str_radix(loc_hex.value, 16).ok()?;
+                        string.push(char::from_u32(code_point)?);
+                    }
+                    StrSegment::Interpolated(_) | StrSegment::DeprecatedInterpolated(_) => {
+                        return None
+                    }
+                }
+            }
+
+            Some(string)
+        }
+        StrLiteral::Block(_) => None,
+    }
+}
+
+/// A line-based diff of two strings, with `-` marking lines only in `expected` and `+` marking
+/// lines only in `actual`.
+fn diff_lines(expected: &str, actual: &str) -> Vec<String> {
+    let old: Vec<&str> = expected.split('\n').collect();
+    let new: Vec<&str> = actual.split('\n').collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(old[i..].iter().map(|line| format!("- {line}")));
+    lines.extend(new[j..].iter().map(|line| format!("+ {line}")));
+
+    lines
 }
 
 impl<'a> Renderer<'a> {
@@ -39,10 +211,20 @@ impl<'a> Renderer<'a> {
             alloc,
             line_info,
             filename,
+            source,
             render_target,
+            show_values: true,
         }
     }
 
+    /// Whether expectation failures show the values of the variables in the condition. Large
+    /// values can bury the rest of the report, so `roc test --hide-values` leaves them out.
+    pub fn with_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+
+        self
+    }
+
     fn render_expr(&'a self, error_type: ErrorType) -> RocDocBuilder<'a> {
         use crate::error::r#type::error_type_to_doc;
 
@@ -72,14 +254,17 @@ impl<'a> Renderer<'a> {
         ])
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_lookups(
         &'a self,
         subs: &mut Subs,
         line_col_region: LineColumnRegion,
+        caller_region: Option<LineColumnRegion>,
 
         symbols: &[Symbol],
         variables: &[Variable],
         expressions: &[Expr<'_>],
+        str_equality: Option<StrEquality>,
     ) -> RocDocBuilder<'a> {
         use ven_pretty::DocAllocator;
 
@@ -93,22 +278,45 @@ impl<'a> Renderer<'a> {
                     self.render_lookup(*symbol, expr, error_type)
                 });
 
-        if it.len() > 0 {
-            self.alloc.stack([
-                self.alloc.text("This expectation failed:"),
-                self.alloc.region(line_col_region),
+        let mut docs = vec![
+            self.alloc.text("This expectation failed:"),
+            self.alloc.region(line_col_region),
+        ];
+
+        if let Some(caller_region) = caller_region {
+            docs.push(
+                self.alloc
+                    .text("It failed in a function called by this top-level expect:"),
+            );
+            docs.push(self.alloc.region(caller_region));
+        }
+
+        if let Some(StrEquality {
+            actual, expected, ..
+        }) = str_equality
+        {
+            let diff = diff_lines(&expected, &actual)
+                .into_iter()
+                .map(|line| self.alloc.text(line));
+
+            docs.push(
+                self.alloc
+                    .text("The strings differ (- expected, + actual):"),
+            );
+            docs.push(self.alloc.vcat(diff).indent(4));
+        }
+
+        if self.show_values && it.len() > 0 {
+            docs.push(
                 self.alloc
                     .text("When it failed, these variables had these values:"),
-                self.alloc.stack(it),
-                self.alloc.text(""), // Blank line at the end
-            ])
-        } else {
-            self.alloc.stack([
-                self.alloc.text("This expectation failed:"),
-                self.alloc.region(line_col_region),
-                self.alloc.text(""), // Blank line at the end
-            ])
+            );
+            docs.push(self.alloc.stack(it));
         }
+
+        docs.push(self.alloc.text("")); // Blank line at the end
+
+        self.alloc.stack(docs)
     }
 
     fn to_line_col_region(
@@ -149,7 +357,26 @@ impl<'a> Renderer<'a> {
         use crate::report::Report;
 
         let line_col_region = self.to_line_col_region(expect_region, failure_region);
-        let doc = self.render_lookups(subs, line_col_region, symbols, variables, expressions);
+        let caller_region = expect_region
+            .filter(|expect_region| !expect_region.contains(&failure_region))
+            .map(|expect_region| self.line_info.convert_region(expect_region));
+        let str_equality = StrEquality::from_failure(
+            self.arena,
+            self.alloc.interns,
+            self.source,
+            failure_region,
+            symbols,
+            expressions,
+        );
+        let doc = self.render_lookups(
+            subs,
+            line_col_region,
+            caller_region,
+            symbols,
+            variables,
+            expressions,
+            str_equality,
+        );
 
         let report = Report {
             title: "EXPECT FAILED".into(),
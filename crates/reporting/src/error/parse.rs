@@ -1699,6 +1699,45 @@ fn to_if_report<'a>(
             )
         }
 
+        EIf::MissingElse(if_region, pos) => {
+            let surroundings = Region::new(if_region.start(), pos);
+            let region = lines.convert_region(if_region);
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"This "),
+                    alloc.keyword("if"),
+                    alloc.reflow(r" has no "),
+                    alloc.keyword("else"),
+                    alloc.reflow(r" branch:"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow(r"In Roc, "),
+                    alloc.keyword("if"),
+                    alloc.reflow(r" is an expression, so it needs a value whichever way the condition goes. "),
+                    alloc.reflow(r"That means every "),
+                    alloc.keyword("if"),
+                    alloc.reflow(r" must end with an "),
+                    alloc.keyword("else"),
+                    alloc.reflow(r" branch."),
+                ]),
+                alloc.concat([
+                    alloc.tip(),
+                    alloc.reflow(r"Add an "),
+                    alloc.keyword("else"),
+                    alloc.reflow(r" branch with the value to use when the condition is false."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "MISSING ELSE".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
         EIf::IndentCondition(pos) => to_unfinished_if_report(
             alloc,
             lines,
@@ -3605,6 +3644,47 @@ fn to_header_report<'a>(
         EHeader::GeneratesWith(generates_with, pos) => {
             to_generates_with_report(alloc, lines, filename, generates_with, *pos)
         }
+        EHeader::Unavailable(unavailable, pos) => {
+            to_unavailable_report(alloc, lines, filename, unavailable, *pos)
+        }
+    }
+}
+
+fn to_unavailable_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    parse_problem: &roc_parse::parser::EExposes,
+    start: Position,
+) -> Report<'a> {
+    use roc_parse::parser::EExposes;
+
+    match *parse_problem {
+        EExposes::ListEnd(pos) | // TODO: give this its own error message
+        EExposes::Identifier(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing an `unavailable` list, but I got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([alloc.reflow(
+                    "I was expecting the name of a builtin module next, like",
+                )]),
+                alloc
+                    .parser_suggestion("[Dict, Set]")
+                    .indent(4),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "WEIRD UNAVAILABLE".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
+        _ => to_exposes_report(alloc, lines, filename, parse_problem, start),
     }
 }
 
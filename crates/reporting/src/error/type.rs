@@ -247,6 +247,63 @@ pub fn type_problem<'b>(
                 severity,
             })
         }
+        TooManyTypeVariables { def, limit } => {
+            let limit_doc = alloc.concat([
+                alloc.reflow("Inferring the types in this module needed more than "),
+                text!(alloc, "{}", limit),
+                alloc.reflow(" type variables, so I stopped."),
+            ]);
+
+            let mut stack = match def {
+                Some((symbol, region)) => vec![
+                    limit_doc,
+                    alloc.concat([
+                        alloc.reflow("I was working on the "),
+                        alloc.symbol_unqualified(symbol),
+                        alloc.reflow(" definition:"),
+                    ]),
+                    alloc.region(lines.convert_region(region)),
+                ],
+                None => vec![limit_doc],
+            };
+
+            stack.push(alloc.concat([
+                alloc.tip(),
+                alloc.reflow("This usually comes from a very large record or list literal. Splitting it up, or giving it a type annotation, may help. The limit can be raised with the "),
+                alloc.keyword("ROC_MAX_TYPE_VARIABLES"),
+                alloc.reflow(" environment variable."),
+            ]));
+
+            Some(Report {
+                title: "TOO MANY TYPE VARIABLES".to_string(),
+                filename,
+                doc: alloc.stack(stack),
+                severity,
+            })
+        }
+        InWhenBranch { problem, bindings } => {
+            let mut report = type_problem(alloc, lines, filename, *problem)?;
+
+            let bindings = bindings.into_iter().map(|(symbol, typ)| {
+                alloc.concat([
+                    alloc.symbol_unqualified(symbol),
+                    alloc.text(" : "),
+                    to_doc(alloc, Parens::Unnecessary, typ).0,
+                ])
+            });
+
+            report.doc = alloc.stack([
+                report.doc,
+                alloc.concat([
+                    alloc.reflow("The pattern of this "),
+                    alloc.keyword("when"),
+                    alloc.reflow(" branch binds these names:"),
+                ]),
+                alloc.type_block(alloc.vcat(bindings)),
+            ]);
+
+            Some(report)
+        }
     }
 }
 
@@ -624,10 +681,11 @@ fn to_expr_report<'b>(
                     found.clone().unwrap_structural_alias()
                 {
                     let expected_set: MutSet<_> = expected_fields.keys().cloned().collect();
-                    let found_set: MutSet<_> = found_fields.keys().cloned().collect();
-                    let mut diff = expected_set.difference(&found_set);
+                    let found_names: Vec<_> = found_fields.keys().cloned().collect();
+                    let found_set: MutSet<_> = found_names.iter().cloned().collect();
+                    let diff = expected_set.difference(&found_set);
 
-                    if let Some(field) = diff.next() {
+                    if let Some(field) = nearest_typo(diff, &found_names) {
                         let opt_sym = match category {
                             Category::Lookup(name) => Some(name),
                             _ => None,
@@ -1118,6 +1176,7 @@ fn to_expr_report<'b>(
                 name,
                 arity,
                 called_via,
+                arg_regions,
             } => match describe_wanted_function(&found) {
                 DescribedFunction::NotAFunction(tag) => {
                     let this_value = match name {
@@ -1195,6 +1254,27 @@ fn to_expr_report<'b>(
                     };
 
                     if n < arity as usize {
+                        let extra_args = &arg_regions[n..];
+                        let snippet = match (extra_args.first(), extra_args.last()) {
+                            (Some(first), Some(last)) => alloc.region_with_subregion(
+                                lines.convert_region(region),
+                                lines.convert_region(Region::span_across(first, last)),
+                            ),
+                            _ => alloc.region(lines.convert_region(expr_region)),
+                        };
+
+                        let hint = if returns_function(&found) {
+                            alloc.concat([
+                                alloc.tip(),
+                                alloc.reflow(
+                                    "This function returns another function. To pass the extra \
+                                    arguments to that one, put parentheses around the first call.",
+                                ),
+                            ])
+                        } else {
+                            alloc.reflow("Are there any missing commas? Or missing parentheses?")
+                        };
+
                         let lines = vec![
                             alloc.concat([
                                 this_function,
@@ -1208,8 +1288,8 @@ fn to_expr_report<'b>(
                                     arity
                                 )),
                             ]),
-                            alloc.region(lines.convert_region(expr_region)),
-                            alloc.reflow("Are there any missing commas? Or missing parentheses?"),
+                            snippet,
+                            hint,
                         ];
 
                         Report {
@@ -1577,6 +1657,18 @@ enum NotAFunctionTag {
     Other,
 }
 
+/// Whether the function returns another function, as curried functions do.
+fn returns_function(tipe: &ErrorType) -> bool {
+    match tipe {
+        ErrorType::Function(_, _, ret) => matches!(
+            describe_wanted_function(ret),
+            DescribedFunction::Arguments(_)
+        ),
+        ErrorType::Alias(_, _, actual, AliasKind::Structural) => returns_function(actual),
+        _ => false,
+    }
+}
+
 fn describe_wanted_function(tipe: &ErrorType) -> DescribedFunction {
     use ErrorType::*;
 
@@ -2772,6 +2864,45 @@ pub fn error_type_to_doc<'b>(
     type_with_able_vars(alloc, typ, able_vars)
 }
 
+/// Like [error_type_to_doc], but renders the arguments and the return type of a function type
+/// separately, so they can be shown one at a time. Type variables get the same names they would
+/// in the whole type. Returns [None] if the type is not a function.
+pub fn function_type_to_docs<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    error_type: ErrorType,
+) -> Option<(Vec<RocDocBuilder<'b>>, RocDocBuilder<'b>)> {
+    let (args, ret) = match error_type {
+        ErrorType::Function(args, _, ret) => (args, *ret),
+        ErrorType::Alias(_, _, real, AliasKind::Structural) => {
+            return function_type_to_docs(alloc, *real)
+        }
+        _ => return None,
+    };
+
+    let mut ctx = Context::default();
+
+    let mut generated_name_usages = VecMap::default();
+    count_generated_name_usages(&mut generated_name_usages, args.iter().chain([&ret]));
+
+    let arg_docs = args
+        .into_iter()
+        .map(|arg| to_doc_help(&mut ctx, &generated_name_usages, alloc, Parens::InFn, arg))
+        .collect();
+
+    let ret_doc = to_doc_help(
+        &mut ctx,
+        &generated_name_usages,
+        alloc,
+        Parens::Unnecessary,
+        ret,
+    );
+
+    Some((
+        arg_docs,
+        type_with_able_vars(alloc, ret_doc, ctx.able_variables),
+    ))
+}
+
 fn compact_builtin_aliases(typ: ErrorType) -> ErrorType {
     use ErrorType::*;
     match typ {
@@ -3097,6 +3228,32 @@ fn ext_has_fixed_fields(ext: &TypeExt) -> bool {
     }
 }
 
+/// Of the fields that only one of the records has, the one most likely to be a typo: the one
+/// closest to a field of the other record.
+fn nearest_typo<'a>(
+    typos: impl Iterator<Item = &'a Lowercase>,
+    possibilities: &[Lowercase],
+) -> Option<&'a Lowercase> {
+    typos.min_by_key(|typo| {
+        possibilities
+            .iter()
+            .map(|field| distance::damerau_levenshtein(typo.as_str(), field.as_str()))
+            .min()
+            .unwrap_or(usize::MAX)
+    })
+}
+
+fn field_typo<'a>(
+    typos: impl Iterator<Item = &'a Lowercase>,
+    possibilities: Vec<Lowercase>,
+) -> Problem {
+    let typo = nearest_typo(typos, &possibilities)
+        .cloned()
+        .unwrap_or_default();
+
+    Problem::FieldTypo(typo, possibilities)
+}
+
 fn diff_record<'b>(
     alloc: &'b RocDocAllocator<'b>,
     fields1: SendMap<Lowercase, RecordField<ErrorType>>,
@@ -3197,8 +3354,8 @@ fn diff_record<'b>(
 
     let status = match (ext_has_fixed_fields(&ext1), ext_has_fixed_fields(&ext2)) {
         (true, true) => match left.peek() {
-            Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
-                f.clone(),
+            Some(_) => Status::Different(vec![field_typo(
+                fields_in_left_only.iter().map(|(field, _)| field),
                 fields_in_right_only.keys().cloned().collect(),
             )]),
             None => {
@@ -3215,15 +3372,15 @@ fn diff_record<'b>(
             }
         },
         (false, true) => match left.peek() {
-            Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
-                f.clone(),
+            Some(_) => Status::Different(vec![field_typo(
+                fields_in_left_only.iter().map(|(field, _)| field),
                 fields_in_right_only.keys().cloned().collect(),
             )]),
             None => Status::Similar,
         },
         (true, false) => match right.peek() {
-            Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
-                f.clone(),
+            Some(_) => Status::Different(vec![field_typo(
+                fields_in_right_only.keys(),
                 fields_in_left_only
                     .iter()
                     .map(|(field, _)| field.clone())
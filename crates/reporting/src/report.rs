@@ -5,6 +5,8 @@ use roc_problem::Severity;
 use roc_region::all::LineColumnRegion;
 use std::path::{Path, PathBuf};
 use std::{fmt, io};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
 
 #[cfg(not(target_family = "wasm"))]
@@ -34,6 +36,44 @@ const ERROR_UNDERLINE: &str = "^";
 /// (This is not necessarily the same as GUTTER_BAR.len()!)
 const GUTTER_BAR_WIDTH: usize = 1;
 
+/// How many columns a tab takes up when we show source code.
+const TAB_WIDTH: usize = 4;
+
+/// A line of source code as we show it in a snippet. Tabs are expanded, so that they take up
+/// the same number of columns in every terminal.
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+/// The number of terminal columns a line of source code takes up when we show it. Emoji and
+/// CJK characters take up two columns, and combining characters none.
+fn display_width(text: &str) -> usize {
+    text.graphemes(true)
+        .map(|grapheme| match grapheme {
+            "\t" => TAB_WIDTH,
+            _ => grapheme.width().min(2),
+        })
+        .sum()
+}
+
+/// The terminal column where the byte at `column` of the line is shown. Past the end of the
+/// line, like where something is missing, every byte takes up one column.
+fn display_column(line: &str, column: u32) -> usize {
+    let column = column as usize;
+
+    if column >= line.len() {
+        return display_width(line) + (column - line.len());
+    }
+
+    let mut end = column;
+
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    display_width(&line[..end])
+}
+
 pub fn cycle<'b>(
     alloc: &'b RocDocAllocator<'b>,
     indent: usize,
@@ -127,7 +167,7 @@ impl<'b> Report<'b> {
     pub fn render(
         self,
         target: RenderTarget,
-        buf: &'b mut String,
+        buf: &mut String,
         alloc: &'b RocDocAllocator<'b>,
         palette: &'b Palette,
     ) {
@@ -581,7 +621,7 @@ impl<'a> RocDocAllocator<'a> {
             let line = self.src_lines[i as usize];
             let is_line_empty = line.trim().is_empty();
             let rest_of_line = if !is_line_empty {
-                self.text(line).indent(indent)
+                self.text(expand_tabs(line)).indent(indent)
             } else {
                 self.nil()
             };
@@ -623,28 +663,24 @@ impl<'a> RocDocAllocator<'a> {
         }
 
         if error_highlight_line {
+            let line = self.src_lines[region.start().line as usize];
+            let start1 = display_column(line, sub_region1.start().column);
+            let end1 = display_column(line, sub_region1.end().column);
+            let start2 = display_column(line, sub_region2.start().column);
+            let end2 = display_column(line, sub_region2.end().column);
+
             let overlapping = sub_region2.start().column < sub_region1.end().column;
 
             let highlight = if overlapping {
-                self.text(
-                    ERROR_UNDERLINE
-                        .repeat((sub_region2.end().column - sub_region1.start().column) as usize),
-                )
+                self.text(ERROR_UNDERLINE.repeat(end2.saturating_sub(start1)))
             } else {
-                let highlight1 = ERROR_UNDERLINE
-                    .repeat((sub_region1.end().column - sub_region1.start().column) as usize);
+                let highlight1 = ERROR_UNDERLINE.repeat(end1.saturating_sub(start1));
                 let highlight2 = if sub_region1 == sub_region2 {
                     "".repeat(0)
                 } else {
-                    ERROR_UNDERLINE
-                        .repeat((sub_region2.end().column - sub_region2.start().column) as usize)
+                    ERROR_UNDERLINE.repeat(end2.saturating_sub(start2))
                 };
-                let in_between = " ".repeat(
-                    (sub_region2
-                        .start()
-                        .column
-                        .saturating_sub(sub_region1.end().column)) as usize,
-                );
+                let in_between = " ".repeat(start2.saturating_sub(end1));
 
                 self.text(highlight1)
                     .append(self.text(in_between))
@@ -659,7 +695,7 @@ impl<'a> RocDocAllocator<'a> {
                 .append(if sub_region1.is_empty() && sub_region2.is_empty() {
                     self.nil()
                 } else {
-                    self.text(" ".repeat(sub_region1.start().column as usize))
+                    self.text(" ".repeat(start1))
                         .indent(indent)
                         .append(highlight)
                         .annotate(error_annotation)
@@ -710,6 +746,7 @@ impl<'a> RocDocAllocator<'a> {
                 .chars()
                 .filter(|&c| !c.is_ascii_control() || c == '\t')
                 .collect::<String>();
+            let line = expand_tabs(&line);
 
             let is_line_empty = line.trim().is_empty();
             let rest_of_line = if !is_line_empty {
@@ -756,8 +793,14 @@ impl<'a> RocDocAllocator<'a> {
         }
 
         if error_highlight_line {
-            let highlight_text = ERROR_UNDERLINE
-                .repeat((sub_region.end().column - sub_region.start().column) as usize);
+            let line = self
+                .src_lines
+                .get(sub_region.start().line as usize)
+                .unwrap_or(&"");
+            let start_column = display_column(line, sub_region.start().column);
+            let end_column = display_column(line, sub_region.end().column);
+
+            let highlight_text = ERROR_UNDERLINE.repeat(end_column.saturating_sub(start_column));
 
             let highlight_line = self
                 .line()
@@ -767,7 +810,7 @@ impl<'a> RocDocAllocator<'a> {
                 .append(if highlight_text.is_empty() {
                     self.nil()
                 } else {
-                    self.text(" ".repeat(sub_region.start().column as usize))
+                    self.text(" ".repeat(start_column))
                         .indent(indent)
                         .append(self.text(highlight_text).annotate(Annotation::Error))
                 });
@@ -1604,6 +1647,31 @@ pub fn to_https_problem_report<'b>(
                 severity: Severity::Fatal,
             }
         }
+        Problem::NotAvailableOffline => {
+            let doc = alloc.stack([
+                alloc.reflow(r"I need the package at this URL:"),
+                alloc
+                    .string((&url).to_string())
+                    .annotate(Annotation::Url)
+                    .indent(4),
+                alloc.reflow(r"But it has not been downloaded yet, and I am not allowed to download anything because this is an offline build."),
+                alloc.concat([
+                    alloc.tip(),
+                    alloc.reflow(r"Build once without "),
+                    alloc.keyword("--offline"),
+                    alloc.reflow(r" to download it, or if this app has a vendor directory, run "),
+                    alloc.keyword("roc vendor"),
+                    alloc.reflow(r" to add the package to it."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "PACKAGE NOT AVAILABLE OFFLINE".to_string(),
+                severity: Severity::Fatal,
+            }
+        }
     }
 }
 
@@ -1705,6 +1773,25 @@ pub fn to_file_problem_report<'b>(
                 severity: Severity::Fatal,
             }
         }
+        io::ErrorKind::InvalidData => {
+            let doc = alloc.stack([
+                alloc.reflow(r"I tried to read this file, but it isn't valid UTF-8:"),
+                alloc
+                    .string(filename_str)
+                    .annotate(Annotation::Error)
+                    .indent(4),
+                alloc.reflow(
+                    r"Roc files must be encoded as UTF-8. Some editors save files as UTF-16 instead, so maybe try saving it as UTF-8?",
+                ),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "FILE IS NOT UTF-8".to_string(),
+                severity: Severity::Fatal,
+            }
+        }
         _ => {
             let error = std::io::Error::from(error);
             let formatted = format!("{error}");
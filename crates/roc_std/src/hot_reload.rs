@@ -0,0 +1,151 @@
+//! The protocol `roc build --lib --watch` uses to tell a running host that its Roc code changed.
+//!
+//! Every time the app's `.roc` files change, the compiler builds a new shared library and writes
+//! it to a path of its own, given by [`generation_library_path`]: `libapp.so` becomes
+//! `libapp.1.so`, then `libapp.2.so`, and so on. A library is never written over, so a host never
+//! sees a half-written file, and never has a library it loaded changed underneath it. Only after
+//! the library is in place does the compiler write the generation file next to it (the library's
+//! path with `.generation` appended), which holds a decimal number that goes up by one per build.
+//!
+//! A host that wants to pick up changes while it runs polls [`read_generation`]. When the number
+//! changes, it loads the library of that generation and looks up its `roc__*` entrypoints there,
+//! replacing the function pointers it called before. Since every generation has its own path,
+//! platforms that cache loaded libraries by path load the new one rather than the cached one.
+//!
+//! Values the old library returned stay valid, because their memory came from the host's
+//! `roc_alloc`. Closures and other function pointers that came out of the old library do not
+//! survive unloading it, so a host that holds on to them must keep the old library loaded. The
+//! libraries of earlier generations are left in place for that reason.
+
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const GENERATION_FILE_EXTENSION: &str = "generation";
+
+/// The file that holds the generation of the library at the given path.
+pub fn generation_path(library: &Path) -> PathBuf {
+    let mut path = OsString::from(library.as_os_str());
+    path.push(".");
+    path.push(GENERATION_FILE_EXTENSION);
+
+    PathBuf::from(path)
+}
+
+/// Where the given generation of the library at the given path is written, which is the
+/// library's path with the generation put before its extension.
+pub fn generation_library_path(library: &Path, generation: u64) -> PathBuf {
+    let mut file_name = OsString::from(library.file_stem().unwrap_or_default());
+    file_name.push(format!(".{generation}"));
+
+    if let Some(extension) = library.extension() {
+        file_name.push(".");
+        file_name.push(extension);
+    }
+
+    library.with_file_name(file_name)
+}
+
+/// The latest generation of the library at the given path, or `None` if nothing has written one
+/// yet.
+pub fn read_generation(library: &Path) -> Option<u64> {
+    fs::read_to_string(generation_path(library))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Announce that the given generation of the library at the given path is ready. The number is
+/// written to a temporary file first and renamed into place, so a host never reads a partial
+/// number.
+pub fn write_generation(library: &Path, generation: u64) -> io::Result<()> {
+    let path = generation_path(library);
+
+    let mut temporary = OsString::from(path.as_os_str());
+    temporary.push(".tmp");
+
+    fs::write(&temporary, generation.to_string())?;
+    fs::rename(&temporary, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory of its own for each test, since they run in parallel
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("roc_std_hot_reload_{name}_{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn generation_path_appends_extension() {
+        assert_eq!(
+            generation_path(Path::new("build/libapp.so")),
+            PathBuf::from("build/libapp.so.generation")
+        );
+        assert_eq!(
+            generation_path(Path::new("app")),
+            PathBuf::from("app.generation")
+        );
+    }
+
+    #[test]
+    fn generation_library_path_is_new_per_generation() {
+        assert_eq!(
+            generation_library_path(Path::new("build/libapp.so"), 1),
+            PathBuf::from("build/libapp.1.so")
+        );
+        assert_eq!(
+            generation_library_path(Path::new("build/app.dll"), 12),
+            PathBuf::from("build/app.12.dll")
+        );
+        assert_eq!(
+            generation_library_path(Path::new("app"), 3),
+            PathBuf::from("app.3")
+        );
+    }
+
+    #[test]
+    fn read_generation_without_file() {
+        let library = test_dir("missing").join("libapp.so");
+
+        assert_eq!(read_generation(&library), None);
+    }
+
+    #[test]
+    fn read_generation_ignores_garbage() {
+        let library = test_dir("garbage").join("libapp.so");
+
+        fs::write(generation_path(&library), "not a number").unwrap();
+
+        assert_eq!(read_generation(&library), None);
+    }
+
+    #[test]
+    fn write_then_read_generation() {
+        let dir = test_dir("round_trip");
+        let library = dir.join("libapp.so");
+
+        write_generation(&library, 1).unwrap();
+        assert_eq!(read_generation(&library), Some(1));
+
+        write_generation(&library, 2).unwrap();
+        assert_eq!(read_generation(&library), Some(2));
+
+        // The temporary file the number was written to is gone
+        let files = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+
+        assert_eq!(files, vec![OsString::from("libapp.so.generation")]);
+    }
+}
@@ -11,6 +11,8 @@ use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::Drop;
 use core::str;
 
+pub mod hot_reload;
+
 mod roc_box;
 mod roc_dict;
 mod roc_list;
@@ -40,6 +42,23 @@ extern "C" {
     pub fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut c_void;
 }
 
+// A list of C functions that the Roc app provides to the host
+extern "C" {
+    fn roc_set_dict_seed(seed: u64);
+}
+
+/// Makes every `Dict` and `Set` in the Roc app hash with the given seed, so their order is the
+/// same on every run. By default each run picks its own seed, which makes hash flooding attacks
+/// harder.
+///
+/// # Safety
+///
+/// This must be called before the app creates any `Dict` or `Set`, because those don't remember
+/// the seed they were created with.
+pub unsafe fn set_dict_seed(seed: u64) {
+    roc_set_dict_seed(seed);
+}
+
 pub fn roc_alloc_refcounted<T>() -> *mut T {
     let size = core::mem::size_of::<T>();
     let align = core::mem::align_of::<T>();
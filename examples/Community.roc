@@ -9,27 +9,13 @@ module [
 
 ## Datatype representing a community for demonstration purposes in inspect-gui.roc and inspect-logging.roc
 
-Community := {
-    people : List Person,
-    friends : List (Set U64),
-}
+Community := { people : List Person, friends : List (Set U64) }
     implements [Inspect]
 
-Person := {
-    firstName : Str,
-    lastName : Str,
-    age : U8,
-    hasBeard : Bool,
-    favoriteColor : Color,
-}
+Person := { firstName : Str, lastName : Str, age : U8, hasBeard : Bool, favoriteColor : Color }
     implements [Inspect]
 
-Color : [
-    Red,
-    Green,
-    Blue,
-    RGB (U8, U8, U8),
-]
+Color : [Red, Green, Blue, RGB (U8, U8, U8)]
 
 empty = @Community { people: [], friends: [] }
 
@@ -49,8 +35,7 @@ addFriend = \@Community { people, friends }, from, to ->
                 |> List.set to (Set.insert toSet from),
             }
 
-        _ ->
-            @Community { people, friends }
+        _ -> @Community { people, friends }
 
 walkFriendNames : Community, state, (state, Str, Set Str -> state) -> state
 walkFriendNames = \@Community { people, friends }, s0, nextFn ->
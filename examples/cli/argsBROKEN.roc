@@ -31,23 +31,19 @@ main =
             Arg.succeed (\base -> \num -> Log (Num.toF64 base) (Num.toF64 num))
             |> Arg.withParser
                 (
-                    Arg.i64Option {
-                        long: "base",
-                        short: "b",
-                        help: "base of the logarithm",
-                    }
+                    Arg.i64Option { long: "base", short: "b", help: "base of the logarithm" }
                 )
             |> Arg.withParser
                 (
-                    Arg.i64Option {
-                        long: "num",
-                        help: "the number to take the logarithm of",
-                    }
+                    Arg.i64Option { long: "num", help: "the number to take the logarithm of" }
                 )
             |> Arg.subCommand "log"
 
         Arg.choice [divCmd, logCmd]
-        |> Arg.program { name: "args-example", help: "A calculator example of the CLI platform argument parser" }
+        |> Arg.program {
+            name: "args-example",
+            help: "A calculator example of the CLI platform argument parser",
+        }
 
     when Arg.parseFormatted parser args is
         Ok cmd ->
@@ -55,8 +51,7 @@ main =
             |> Num.toStr
             |> Stdout.line
 
-        Err helpMenuErr ->
-            Task.err (Exit 1 "unable to parse args: $(Inspect.toStr helpMenuErr)")
+        Err helpMenuErr -> Task.err (Exit 1 "unable to parse args: $(Inspect.toStr helpMenuErr)")
 
 runCmd = \cmd ->
     when cmd is
@@ -13,8 +13,11 @@ tick : {} -> Task [Step {}, Done {}] _
 tick = \{} ->
     when Stdin.line |> Task.result! is
         Ok str -> Stdout.line (echo str) |> Task.map Step
-        Err (StdinErr EndOfFile) -> Stdout.line (echo "Received end of input (EOF).") |> Task.map Done
-        Err (StdinErr err) -> Stdout.line (echo "Unable to read input $(Inspect.toStr err)") |> Task.map Done
+        Err (StdinErr EndOfFile) ->
+            Stdout.line (echo "Received end of input (EOF).") |> Task.map Done
+
+        Err (StdinErr err) ->
+            Stdout.line (echo "Unable to read input $(Inspect.toStr err)") |> Task.map Done
 
 echo : Str -> Str
 echo = \shout ->
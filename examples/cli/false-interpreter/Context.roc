@@ -11,7 +11,15 @@ Data : [Lambda (List U8), Number I32, Var Variable]
 # While loops are special and have their own Scope specific state.
 WhileState : { cond : List U8, body : List U8, state : [InCond, InBody] }
 Scope : { data : Option File.Handle, index : U64, buf : List U8, whileInfo : Option WhileState }
-State : [Executing, InComment, InLambda U64 (List U8), InString (List U8), InNumber I32, InSpecialChar, LoadChar]
+State : [
+    Executing,
+    InComment,
+    InLambda U64 (List U8),
+    InString (List U8),
+    InNumber I32,
+    InSpecialChar,
+    LoadChar,
+]
 Context : { scopes : List Scope, stack : List Data, vars : List Data, state : State }
 
 pushStack : Context, Data -> Context
@@ -29,8 +37,7 @@ popStack = \ctx ->
 
             Ok (T poppedCtx val)
 
-        Err ListWasEmpty ->
-            Err EmptyStack
+        Err ListWasEmpty -> Err EmptyStack
 
 toStrData : Data -> Str
 toStrData = \data ->
@@ -65,7 +72,12 @@ with = \path, callback ->
     # I cant define scope here and put it in the list in callback. It breaks alias anaysis.
     # Instead I have to inline this.
     # root_scope = { data: Some handle, index: 0, buf: [], whileInfo: None }
-    callback { scopes: [{ data: Some handle, index: 0, buf: [], whileInfo: None }], state: Executing, stack: [], vars: List.repeat (Number 0) Variable.totalCount }
+    callback {
+        scopes: [{ data: Some handle, index: 0, buf: [], whileInfo: None }],
+        state: Executing,
+        stack: [],
+        vars: List.repeat (Number 0) Variable.totalCount,
+    }
 
 # I am pretty sure there is a syntax to destructure and keep a reference to the whole, but Im not sure what it is.
 getChar : Context -> Task [T U8 Context] [EndOfData, NoScope]
@@ -73,17 +85,16 @@ getChar = \ctx ->
     when List.last ctx.scopes is
         Ok scope ->
             (T val newScope) <- Task.await (getCharScope scope)
-            Task.succeed (T val { ctx & scopes: List.set ctx.scopes (List.len ctx.scopes - 1) newScope })
+            Task.succeed (T val { ctx &
+                scopes: List.set ctx.scopes (List.len ctx.scopes - 1) newScope,
+            })
 
-        Err ListWasEmpty ->
-            Task.fail NoScope
+        Err ListWasEmpty -> Task.fail NoScope
 
 getCharScope : Scope -> Task [T U8 Scope] [EndOfData, NoScope]
 getCharScope = \scope ->
     when List.get scope.buf scope.index is
-        Ok val ->
-            Task.succeed (T val { scope & index: scope.index + 1 })
-
+        Ok val -> Task.succeed (T val { scope & index: scope.index + 1 })
         Err OutOfBounds ->
             when scope.data is
                 Some h ->
@@ -93,17 +104,12 @@ getCharScope = \scope ->
                             # This starts at 1 because the first character is already being returned.
                             Task.succeed (T val { scope & buf: bytes, index: 1 })
 
-                        Err ListWasEmpty ->
-                            Task.fail EndOfData
+                        Err ListWasEmpty -> Task.fail EndOfData
 
-                None ->
-                    Task.fail EndOfData
+                None -> Task.fail EndOfData
 
 inWhileScope : Context -> Bool
 inWhileScope = \ctx ->
     when List.last ctx.scopes is
-        Ok scope ->
-            scope.whileInfo != None
-
-        Err ListWasEmpty ->
-            Bool.false
+        Ok scope -> scope.whileInfo != None
+        Err ListWasEmpty -> Bool.false
@@ -19,7 +19,20 @@ import Variable exposing [Variable]
 # In an imperative language, a few of these pieces would be in while loops and it would basically never overflow.
 # This implementation is easy to overflow, either make the input long enough or make a false while loop run long enough.
 # I assume all of the Task.awaits are the cause of this, but I am not 100% sure.
-InterpreterErrors : [BadUtf8, DivByZero, EmptyStack, InvalidBooleanValue, InvalidChar Str, MaxInputNumber, NoLambdaOnStack, NoNumberOnStack, NoVariableOnStack, NoScope, OutOfBounds, UnexpectedEndOfData]
+InterpreterErrors : [
+    BadUtf8,
+    DivByZero,
+    EmptyStack,
+    InvalidBooleanValue,
+    InvalidChar Str,
+    MaxInputNumber,
+    NoLambdaOnStack,
+    NoNumberOnStack,
+    NoVariableOnStack,
+    NoScope,
+    OutOfBounds,
+    UnexpectedEndOfData,
+]
 
 main : Str -> Task {} []
 main = \filename ->
@@ -31,15 +44,9 @@ interpretFile = \filename ->
     ctx <- Context.with filename
     result <- Task.attempt (interpretCtx ctx)
     when result is
-        Ok _ ->
-            Task.succeed {}
-
-        Err BadUtf8 ->
-            Task.fail (StringErr "Failed to convert string from Utf8 bytes")
-
-        Err DivByZero ->
-            Task.fail (StringErr "Division by zero")
-
+        Ok _ -> Task.succeed {}
+        Err BadUtf8 -> Task.fail (StringErr "Failed to convert string from Utf8 bytes")
+        Err DivByZero -> Task.fail (StringErr "Division by zero")
         Err EmptyStack ->
             Task.fail (StringErr "Tried to pop a value off of the stack when it was empty")
 
@@ -61,9 +68,7 @@ interpretFile = \filename ->
         Err NoVariableOnStack ->
             Task.fail (StringErr "Tried to load a variable when no variable was on the stack")
 
-        Err NoScope ->
-            Task.fail (StringErr "Tried to run code when not in any scope")
-
+        Err NoScope -> Task.fail (StringErr "Tried to run code when not in any scope")
         Err OutOfBounds ->
             Task.fail (StringErr "Tried to load from an offset that was outside of the stack")
 
@@ -107,26 +112,41 @@ interpretCtxLoop = \ctx ->
                                     if n == 0 then
                                         newScope = { scope & whileInfo: None }
 
-                                        Task.succeed (Step { popCtx & scopes: List.set ctx.scopes last newScope })
+                                        Task.succeed (Step { popCtx &
+                                            scopes: List.set ctx.scopes last newScope,
+                                        })
                                     else
-                                        newScope = { scope & whileInfo: Some { state: InBody, body, cond } }
+                                        newScope = { scope &
+                                            whileInfo: Some { state: InBody, body, cond },
+                                        }
 
-                                        Task.succeed (Step { popCtx & scopes: List.append (List.set ctx.scopes last newScope) { data: None, buf: body, index: 0, whileInfo: None } })
+                                        Task.succeed (Step { popCtx &
+                                            scopes: List.append (List.set ctx.scopes last newScope) {
+                                                data: None,
+                                                buf: body,
+                                                index: 0,
+                                                whileInfo: None,
+                                            },
+                                        })
 
-                                Err e ->
-                                    Task.fail e
+                                Err e -> Task.fail e
 
                         Some { state: InBody, body, cond } ->
                             # Just rand the body. Run the condition again.
                             newScope = { scope & whileInfo: Some { state: InCond, body, cond } }
 
-                            Task.succeed (Step { ctx & scopes: List.append (List.set ctx.scopes last newScope) { data: None, buf: cond, index: 0, whileInfo: None } })
+                            Task.succeed (Step { ctx &
+                                scopes: List.append (List.set ctx.scopes last newScope) {
+                                    data: None,
+                                    buf: cond,
+                                    index: 0,
+                                    whileInfo: None,
+                                },
+                            })
 
-                        None ->
-                            Task.fail NoScope
+                        None -> Task.fail NoScope
 
-                Err OutOfBounds ->
-                    Task.fail NoScope
+                Err OutOfBounds -> Task.fail NoScope
 
         Executing ->
             # {} <- Task.await (Stdout.line (Context.toStr ctx))
@@ -136,9 +156,7 @@ interpretCtxLoop = \ctx ->
                     execCtx <- Task.await (stepExecCtx newCtx val)
                     Task.succeed (Step execCtx)
 
-                Err NoScope ->
-                    Task.fail NoScope
-
+                Err NoScope -> Task.fail NoScope
                 Err EndOfData ->
                     # Computation complete for this scope.
                     # Drop a scope.
@@ -160,11 +178,8 @@ interpretCtxLoop = \ctx ->
                     else
                         Task.succeed (Step { newCtx & state: InComment })
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
         InNumber accum ->
             result <- Task.attempt (Context.getChar ctx)
@@ -185,11 +200,8 @@ interpretCtxLoop = \ctx ->
                         execCtx <- Task.await (stepExecCtx { pushCtx & state: Executing } val)
                         Task.succeed (Step execCtx)
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
         InString bytes ->
             result <- Task.attempt (Context.getChar ctx)
@@ -202,16 +214,12 @@ interpretCtxLoop = \ctx ->
                                 {} <- Task.await (Stdout.raw str)
                                 Task.succeed (Step { newCtx & state: Executing })
 
-                            Err _ ->
-                                Task.fail BadUtf8
+                            Err _ -> Task.fail BadUtf8
                     else
                         Task.succeed (Step { newCtx & state: InString (List.append bytes val) })
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
         InLambda depth bytes ->
             result <- Task.attempt (Context.getChar ctx)
@@ -219,7 +227,9 @@ interpretCtxLoop = \ctx ->
                 Ok (T val newCtx) ->
                     if val == 0x5B then
                         # start of a nested lambda `[`
-                        Task.succeed (Step { newCtx & state: InLambda (depth + 1) (List.append bytes val) })
+                        Task.succeed (Step { newCtx &
+                            state: InLambda (depth + 1) (List.append bytes val),
+                        })
                     else if val == 0x5D then
                         # `]` end of current lambda
                         if depth == 0 then
@@ -227,15 +237,16 @@ interpretCtxLoop = \ctx ->
                             Task.succeed (Step (Context.pushStack { newCtx & state: Executing } (Lambda bytes)))
                         else
                             # end of nested lambda
-                            Task.succeed (Step { newCtx & state: InLambda (depth - 1) (List.append bytes val) })
+                            Task.succeed (Step { newCtx &
+                                state: InLambda (depth - 1) (List.append bytes val),
+                            })
                     else
-                        Task.succeed (Step { newCtx & state: InLambda depth (List.append bytes val) })
+                        Task.succeed (Step { newCtx &
+                            state: InLambda depth (List.append bytes val),
+                        })
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
         InSpecialChar ->
             result <- Task.attempt (Context.getChar { ctx & state: Executing })
@@ -266,11 +277,8 @@ interpretCtxLoop = \ctx ->
 
                     Task.fail (InvalidChar data)
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
         LoadChar ->
             result <- Task.attempt (Context.getChar { ctx & state: Executing })
@@ -278,11 +286,8 @@ interpretCtxLoop = \ctx ->
                 Ok (T x newCtx) ->
                     Task.succeed (Step (Context.pushStack newCtx (Number (Num.intCast x))))
 
-                Err NoScope ->
-                    Task.fail NoScope
-
-                Err EndOfData ->
-                    Task.fail UnexpectedEndOfData
+                Err NoScope -> Task.fail NoScope
+                Err EndOfData -> Task.fail UnexpectedEndOfData
 
 # If it weren't for reading stdin or writing to stdout, this could return a result.
 stepExecCtx : Context, U8 -> Task Context InterpreterErrors
@@ -293,7 +298,14 @@ stepExecCtx = \ctx, char ->
             Task.fromResult
                 (
                     (T popCtx bytes) <- Result.try (popLambda ctx)
-                    Ok { popCtx & scopes: List.append popCtx.scopes { data: None, buf: bytes, index: 0, whileInfo: None } }
+                    Ok { popCtx &
+                        scopes: List.append popCtx.scopes {
+                            data: None,
+                            buf: bytes,
+                            index: 0,
+                            whileInfo: None,
+                        },
+                    }
                 )
 
         0x3F ->
@@ -305,7 +317,14 @@ stepExecCtx = \ctx, char ->
                     if n1 == 0 then
                         Ok popCtx2
                     else
-                        Ok { popCtx2 & scopes: List.append popCtx2.scopes { data: None, buf: bytes, index: 0, whileInfo: None } }
+                        Ok { popCtx2 &
+                            scopes: List.append popCtx2.scopes {
+                                data: None,
+                                buf: bytes,
+                                index: 0,
+                                whileInfo: None,
+                            },
+                        }
                 )
 
         0x23 ->
@@ -319,13 +338,21 @@ stepExecCtx = \ctx, char ->
                     when List.get popCtx2.scopes last is
                         Ok scope ->
                             # set the current scope to be in a while loop.
-                            scopes = List.set popCtx2.scopes last { scope & whileInfo: Some { cond: cond, body: body, state: InCond } }
+                            scopes = List.set popCtx2.scopes last { scope &
+                                whileInfo: Some { cond: cond, body: body, state: InCond },
+                            }
 
                             # push a scope to execute the condition.
-                            Ok { popCtx2 & scopes: List.append scopes { data: None, buf: cond, index: 0, whileInfo: None } }
-
-                        Err OutOfBounds ->
-                            Err NoScope
+                            Ok { popCtx2 &
+                                scopes: List.append scopes {
+                                    data: None,
+                                    buf: cond,
+                                    index: 0,
+                                    whileInfo: None,
+                                },
+                            }
+
+                        Err OutOfBounds -> Err NoScope
                 )
 
         0x24 ->
@@ -351,12 +378,9 @@ stepExecCtx = \ctx, char ->
                 Ok (Context.pushStack (Context.pushStack popCtx2 n1) n2)
 
             when result2 is
-                Ok a ->
-                    Task.succeed a
-
+                Ok a -> Task.succeed a
                 # Being explicit with error type is required to stop the need to propogate the error parameters to Context.popStack
-                Err EmptyStack ->
-                    Task.fail EmptyStack
+                Err EmptyStack -> Task.fail EmptyStack
 
         0x40 ->
             # `@` rot
@@ -367,12 +391,9 @@ stepExecCtx = \ctx, char ->
                 Ok (Context.pushStack (Context.pushStack (Context.pushStack popCtx3 n2) n1) n3)
 
             when result2 is
-                Ok a ->
-                    Task.succeed a
-
+                Ok a -> Task.succeed a
                 # Being explicit with error type is required to stop the need to propogate the error parameters to Context.popStack
-                Err EmptyStack ->
-                    Task.fail EmptyStack
+                Err EmptyStack -> Task.fail EmptyStack
 
         0xC3 ->
             # `ø` pick or `ß` flush
@@ -475,11 +496,9 @@ stepExecCtx = \ctx, char ->
                             {} <- Task.await (Stdout.raw str)
                             Task.succeed popCtx
 
-                        Err _ ->
-                            Task.fail BadUtf8
+                        Err _ -> Task.fail BadUtf8
 
-                Err e ->
-                    Task.fail e
+                Err e -> Task.fail e
 
         0x2E ->
             # `.` write int
@@ -488,8 +507,7 @@ stepExecCtx = \ctx, char ->
                     {} <- Task.await (Stdout.raw (Num.toStr (Num.intCast num)))
                     Task.succeed popCtx
 
-                Err e ->
-                    Task.fail e
+                Err e -> Task.fail e
 
         0x5E ->
             # `^` read char as int
@@ -535,15 +553,11 @@ stepExecCtx = \ctx, char ->
             # number start
             Task.succeed { ctx & state: InNumber (Num.intCast (x - 0x30)) }
 
-        x if isWhitespace x ->
-            Task.succeed ctx
-
+        x if isWhitespace x -> Task.succeed ctx
         x ->
             when Variable.fromUtf8 x is
                 # letters are variable names
-                Ok var ->
-                    Task.succeed (Context.pushStack ctx (Var var))
-
+                Ok var -> Task.succeed (Context.pushStack ctx (Var var))
                 Err _ ->
                     data = Num.toStr (Num.intCast x)
 
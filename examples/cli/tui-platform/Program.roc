@@ -1,7 +1,3 @@
 module [Program]
 
-Program model : {
-    init : {} -> model,
-    update : model, Str -> model,
-    view : model -> Str,
-}
+Program model : { init : {} -> model, update : model, Str -> model, view : model -> Str }
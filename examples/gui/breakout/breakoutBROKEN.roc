@@ -45,20 +45,11 @@ init = \{ width, height } -> {
 update : Model, Event -> Model
 update = \model, event ->
     when event is
-        Resize size ->
-            { model & width: size.width, height: size.height }
-
-        KeyDown Left ->
-            { model & paddleX: model.paddleX - paddleSpeed }
-
-        KeyDown Right ->
-            { model & paddleX: model.paddleX + paddleSpeed }
-
-        Tick _ ->
-            tick model
-
-        _ ->
-            model
+        Resize size -> { model & width: size.width, height: size.height }
+        KeyDown Left -> { model & paddleX: model.paddleX - paddleSpeed }
+        KeyDown Right -> { model & paddleX: model.paddleX + paddleSpeed }
+        Tick _ -> tick model
+        _ -> model
 
 tick : Model -> Model
 tick = \model ->
@@ -11,6 +11,8 @@ update : Model, Event -> Model
 update = \model, _ -> model
 
 render : Model -> List Elem
-render = \model -> [Text { text: model.text, top: 0, left: 0, size: 40, color: { r: 1, g: 1, b: 1, a: 1 } }]
+render = \model -> [
+    Text { text: model.text, top: 0, left: 0, size: 40, color: { r: 1, g: 1, b: 1, a: 1 } },
+]
 
 program = { init, update, render }
@@ -4,7 +4,12 @@ app # [pf.Action.{ Action }, pf.Elem.{ button, text, row, col }]
 render =
     rgba = \r, g, b, a -> { r: r / 255, g: g / 255, b: b / 255, a }
 
-    styles = { bgColor: rgba 100 50 50 1, borderColor: rgba 10 20 30 1, borderWidth: 10, textColor: rgba 220 220 250 1 }
+    styles = {
+        bgColor: rgba 100 50 50 1,
+        borderColor: rgba 10 20 30 1,
+        borderWidth: 10,
+        textColor: rgba 220 220 250 1,
+    }
 
     Col [
         Row [
@@ -78,15 +78,9 @@ none = None # I've often wanted this in elm/html. Usually end up resorting to (H
 ##
 translate = \child, toChild, toParent ->
     when child is
-        Text str ->
-            Text str
-
-        Col elems ->
-            Col (List.map elems \elem -> translate elem toChild toParent)
-
-        Row elems ->
-            Row (List.map elems \elem -> translate elem toChild toParent)
-
+        Text str -> Text str
+        Col elems -> Col (List.map elems \elem -> translate elem toChild toParent)
+        Row elems -> Row (List.map elems \elem -> translate elem toChild toParent)
         Button config label ->
             onPress = \parentState, event ->
                 toChild parentState
@@ -100,13 +94,9 @@ translate = \child, toChild, toParent ->
                 \parentState ->
                     { elem, state } = renderChild (toChild parentState)
 
-                    {
-                        elem: translate toChild toParent newChild,
-                        state: toParent parentState state,
-                    }
+                    { elem: translate toChild toParent newChild, state: toParent parentState state }
 
-        None ->
-            None
+        None -> None
 
 ## Render a list of elements, using [Elem.translate] on each of them.
 ##
@@ -151,15 +141,9 @@ list = \renderChild, parent, toChildren, toParent ->
 translateOrDrop : Elem child, (parent -> Result child *), (parent, child -> parent) -> Elem parent
 translateOrDrop = \child, toChild, toParent ->
     when child is
-        Text str ->
-            Text str
-
-        Col elems ->
-            Col (List.map elems \elem -> translateOrDrop elem toChild toParent)
-
-        Row elems ->
-            Row (List.map elems \elem -> translateOrDrop elem toChild toParent)
-
+        Text str -> Text str
+        Col elems -> Col (List.map elems \elem -> translateOrDrop elem toChild toParent)
+        Row elems -> Row (List.map elems \elem -> translateOrDrop elem toChild toParent)
         Button config label ->
             onPress = \parentState, event ->
                 when toChild parentState is
@@ -184,9 +168,7 @@ translateOrDrop = \child, toChild, toParent ->
                             renderChild newChild
                             |> translateOrDrop toChild toParent
 
-                        Err _ ->
-                            None
+                        Err _ -> None
 
         # I don't think this should ever happen in practice.
-        None ->
-            None
+        None -> None
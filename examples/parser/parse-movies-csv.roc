@@ -26,9 +26,7 @@ main =
 
         Err problem ->
             when problem is
-                ParsingFailure failure ->
-                    Stderr.line "Parsing failure: $(failure)\n"
-
+                ParsingFailure failure -> Stderr.line "Parsing failure: $(failure)\n"
                 ParsingIncomplete leftover ->
                     leftoverStr = leftover |> List.map strFromUtf8 |> List.map (\val -> "\"$(val)\"") |> Str.joinWith ", "
 
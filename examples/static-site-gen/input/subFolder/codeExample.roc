@@ -38,9 +38,7 @@ view = \currentNavLink, htmlContent ->
         ### start snippet body
         body [] [
             div [class "main"] [
-                div [class "navbar"] [
-                    viewNavbar currentNavLink,
-                ],
+                div [class "navbar"] [viewNavbar currentNavLink],
                 div [class "article"] [
                     # For now `text` is not escaped so we can use it to insert HTML
                     # We'll probably want something more explicit in the long term though!
@@ -61,9 +59,7 @@ viewNavbar = \currentNavLink ->
 viewNavLink : Bool, NavLink -> Html.Node
 viewNavLink = \isCurrent, navlink ->
     if isCurrent then
-        li [class "nav-link nav-link--current"] [
-            text navlink.text,
-        ]
+        li [class "nav-link nav-link--current"] [text navlink.text]
     else
         li [class "nav-link"] [
             a
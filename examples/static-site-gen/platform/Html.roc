@@ -175,11 +175,8 @@ unclosedElem = \tagName ->
 nodeSize : Node -> U64
 nodeSize = \node ->
     when node is
-        Text content ->
-            Str.countUtf8Bytes content
-
-        Element _ size _ _ | UnclosedElem _ size _ ->
-            size
+        Text content -> Str.countUtf8Bytes content
+        Element _ size _ _ | UnclosedElem _ size _ -> size
 
 ## Render a Node to an HTML string
 ##
@@ -204,9 +201,7 @@ renderWithoutDocType = \node ->
 renderHelp : Str, Node -> Str
 renderHelp = \buffer, node ->
     when node is
-        Text content ->
-            Str.concat buffer content
-
+        Text content -> Str.concat buffer content
         Element tagName _ attrs children ->
             withTagName = "$(buffer)<$(tagName)"
             withAttrs =
@@ -3,11 +3,7 @@ app [transformFileContent] { pf: platform "platform/main.roc" }
 import pf.Html exposing [html, head, body, div, text, a, ul, li, link, meta]
 import pf.Html.Attributes exposing [httpEquiv, content, href, rel, lang, class, title]
 
-NavLink : {
-    url : Str,
-    title : Str,
-    text : Str,
-}
+NavLink : { url : Str, title : Str, text : Str }
 
 navLinks : List NavLink
 navLinks = [
@@ -33,9 +29,7 @@ view = \currentNavLink, htmlContent ->
         ],
         body [] [
             div [class "main"] [
-                div [class "navbar"] [
-                    viewNavbar currentNavLink,
-                ],
+                div [class "navbar"] [viewNavbar currentNavLink],
                 div [class "article"] [
                     # For now `text` is not escaped so we can use it to insert HTML
                     # We'll probably want something more explicit in the long term though!
@@ -54,9 +48,7 @@ viewNavbar = \currentNavLink ->
 viewNavLink : Bool, NavLink -> Html.Node
 viewNavLink = \isCurrent, navlink ->
     if isCurrent then
-        li [class "nav-link nav-link--current"] [
-            text navlink.text,
-        ]
+        li [class "nav-link nav-link--current"] [text navlink.text]
     else
         li [class "nav-link"] [
             a
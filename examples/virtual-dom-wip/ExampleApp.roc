@@ -2,16 +2,10 @@ module [exampleApp, State]
 
 import pf.Html exposing [App, Html, html, head, body, div, text, h1]
 
-State : {
-    answer : U32,
-}
+State : { answer : U32 }
 
 exampleApp : App State State
-exampleApp = {
-    init,
-    render,
-    wasmUrl: "assets/example-client.wasm",
-}
+exampleApp = { init, render, wasmUrl: "assets/example-client.wasm" }
 
 init = \result ->
     when result is
@@ -22,13 +16,7 @@ render : State -> Html State
 render = \state ->
     num = Num.toStr state.answer
 
-    html [] [
-        head [] [],
-        body [] [
-            h1 [] [text "The app"],
-            div [] [text "The answer is $(num)"],
-        ],
-    ]
+    html [] [head [] [], body [] [h1 [] [text "The app"], div [] [text "The answer is $(num)"]]]
 
 expect
     Html.renderStatic (Html.translateStatic (render { answer: 42 }))
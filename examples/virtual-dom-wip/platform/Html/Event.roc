@@ -24,7 +24,11 @@ import Html.Internal.Shared exposing [Attribute]
 Handler state : Html.Internal.Shared.Handler state
 CyclicStructureAccessor : Html.Internal.Shared.CyclicStructureAccessor
 
-custom : Str, List CyclicStructureAccessor, (state, List (List U8) -> { action : Action state, stopPropagation : Bool, preventDefault : Bool }) -> Attribute state
+custom : Str, List CyclicStructureAccessor, (state, List (List U8) -> {
+    action : Action state,
+    stopPropagation : Bool,
+    preventDefault : Bool,
+}) -> Attribute state
 custom = \eventName, accessors, callback ->
     EventListener eventName accessors (Custom callback)
 
@@ -52,7 +56,11 @@ onFocus = curriedOn "focus"
 
 onInput : List CyclicStructureAccessor, (state, List (List U8) -> Action state) -> Attribute state
 onInput = \accessors, callback ->
-    customCallback : state, List (List U8) -> { action : Action state, stopPropagation : Bool, preventDefault : Bool }
+    customCallback : state, List (List U8) -> {
+        action : Action state,
+        stopPropagation : Bool,
+        preventDefault : Bool,
+    }
     customCallback = \state, jsons -> {
         action: callback state jsons,
         stopPropagation: Bool.true,
@@ -23,11 +23,7 @@ import Html.Internal.Shared exposing [
 import TotallyNotJson
 import Action
 
-PlatformState state initData : {
-    app : App state initData,
-    state,
-    rendered : RenderedTree state,
-}
+PlatformState state initData : { app : App state initData, state, rendered : RenderedTree state }
 
 # The rendered tree uses indices rather than pointers
 # This makes it easier to communicate with JS using integer indices.
@@ -90,14 +86,14 @@ initClientApp = \json, app ->
     # Call out to JS to patch the DOM, attaching the event listeners
     _ <- applyPatches patches |> Effect.after
 
-    Effect.always {
-        app,
-        state,
-        rendered,
-    }
+    Effect.always { app, state, rendered }
 
 # Testable helper function to initialise the app
-initClientAppHelp : List U8, App state initData -> { state, rendered : RenderedTree state, patches : List Patch } where initData implements Decoding
+initClientAppHelp : List U8, App state initData -> {
+    state,
+    rendered : RenderedTree state,
+    patches : List Patch,
+} where initData implements Decoding
 initClientAppHelp = \json, app ->
     state =
         json
@@ -128,7 +124,10 @@ initClientAppHelp = \json, app ->
 # In Roc, we maintain a matching List of virtual DOM nodes with the same indices.
 # They are both initialised separately, but use the same indexing algorithm.
 # (We *could* pass this data in as JSON from the HTML file, but it would roughly double the size of that HTML file!)
-indexNodes : { nodes : List RenderedNode, siblingIds : List U64 }, Html state -> { nodes : List RenderedNode, siblingIds : List U64 }
+indexNodes : { nodes : List RenderedNode, siblingIds : List U64 }, Html state -> {
+    nodes : List RenderedNode,
+    siblingIds : List U64,
+}
 indexNodes = \{ nodes, siblingIds }, unrendered ->
     when unrendered is
         Text content ->
@@ -144,8 +143,12 @@ indexNodes = \{ nodes, siblingIds }, unrendered ->
                 List.walk attrs emptyRenderedAttrs \walkedAttrs, attr ->
                     when attr is
                         EventListener _ _ _ -> walkedAttrs # Dropped! Server-rendered HTML has no listeners
-                        HtmlAttr k v -> { walkedAttrs & htmlAttrs: Dict.insert walkedAttrs.htmlAttrs k v }
-                        DomProp k v -> { walkedAttrs & domProps: Dict.insert walkedAttrs.domProps k v }
+                        HtmlAttr k v ->
+                            { walkedAttrs & htmlAttrs: Dict.insert walkedAttrs.htmlAttrs k v }
+
+                        DomProp k v ->
+                            { walkedAttrs & domProps: Dict.insert walkedAttrs.domProps k v }
+
                         Style k v -> { walkedAttrs & styles: Dict.insert walkedAttrs.styles k v }
 
             {
@@ -176,7 +179,9 @@ applyPatch = \patch ->
         SetProperty nodeId propName json -> Effect.setProperty nodeId propName json
         RemoveProperty nodeId propName -> Effect.removeProperty nodeId propName
         SetStyle nodeId key value -> Effect.setStyle nodeId key value
-        SetListener nodeId eventType accessorsJson handlerId -> Effect.setListener nodeId eventType accessorsJson handlerId
+        SetListener nodeId eventType accessorsJson handlerId ->
+            Effect.setListener nodeId eventType accessorsJson handlerId
+
         RemoveListener nodeId handlerId -> Effect.removeListener nodeId handlerId
 
 walkPatches : Effect {}, Patch -> Effect {}
@@ -210,10 +215,13 @@ dispatchEvent = \platformState, eventData, handlerId ->
                 { action: Action.none, stopPropagation: Bool.false, preventDefault: Bool.false }
 
             Ok (Normal handler) ->
-                { action: handler state eventData, stopPropagation: Bool.false, preventDefault: Bool.false }
+                {
+                    action: handler state eventData,
+                    stopPropagation: Bool.false,
+                    preventDefault: Bool.false,
+                }
 
-            Ok (Custom handler) ->
-                handler state eventData
+            Ok (Custom handler) -> handler state eventData
 
     when action is
         Update newState ->
@@ -224,17 +232,12 @@ dispatchEvent = \platformState, eventData, handlerId ->
 
             _ <- applyPatches patches |> Effect.after
             Effect.always {
-                platformState: {
-                    app,
-                    state: newState,
-                    rendered: newRendered,
-                },
+                platformState: { app, state: newState, rendered: newRendered },
                 stopPropagation,
                 preventDefault,
             }
 
-        None ->
-            Effect.always { platformState, stopPropagation, preventDefault }
+        None -> Effect.always { platformState, stopPropagation, preventDefault }
 
 # -------------------------------
 #   DIFF
@@ -255,9 +258,7 @@ diff = \{ rendered, patches }, newNode ->
                     List.set rendered.nodes rendered.root (Ok (RenderedText newContent))
 
                 {
-                    rendered: { rendered &
-                        nodes: newNodes,
-                    },
+                    rendered: { rendered & nodes: newNodes },
                     patches: List.append patches (UpdateTextNode rendered.root newContent),
                 }
             else
@@ -270,10 +271,16 @@ diff = \{ rendered, patches }, newNode ->
                 stateAttrs =
                     diffAttrs { rendered, patches } root oldAttrs newAttrs
                 stateChildPairs =
-                    List.map2 oldChildren newChildren (\oldChildId, newChild -> { oldChildId, newChild })
+                    List.map2 oldChildren newChildren (\oldChildId, newChild -> {
+                        oldChildId,
+                        newChild,
+                    })
                     |> List.walk stateAttrs \childWalkState, { oldChildId, newChild } ->
                         { rendered: childWalkRendered, patches: childWalkPatches } = childWalkState
-                        diff { rendered: { childWalkRendered & root: oldChildId }, patches: childWalkPatches } newChild
+                        diff {
+                            rendered: { childWalkRendered & root: oldChildId },
+                            patches: childWalkPatches,
+                        } newChild
                 { rendered: renderedLeftOverChildren, patches: patchesLeftOverChildren } =
                     if List.len oldChildren > List.len newChildren then
                         List.walkFrom oldChildren (List.len newChildren) stateChildPairs deleteNode
@@ -288,8 +295,11 @@ diff = \{ rendered, patches }, newNode ->
                         # Look up the children again since they might have new node IDs!
                         nodeWithUpdatedChildren =
                             when List.get renderedAfterCreate.nodes root is
-                                Ok (Ok (RenderedElement n a c)) -> RenderedElement n a (List.concat c createdIds)
-                                _ -> crash "Bug in virtual-dom framework: nodeWithUpdatedChildren not found"
+                                Ok (Ok (RenderedElement n a c)) ->
+                                    RenderedElement n a (List.concat c createdIds)
+
+                                _ ->
+                                    crash "Bug in virtual-dom framework: nodeWithUpdatedChildren not found"
                         updatedNodes =
                             List.set renderedAfterCreate.nodes root (Ok nodeWithUpdatedChildren)
 
@@ -300,14 +310,9 @@ diff = \{ rendered, patches }, newNode ->
                     else
                         stateChildPairs
 
-                {
-                    rendered: { renderedLeftOverChildren & root },
-                    patches: patchesLeftOverChildren,
-                }
-
-        { oldNode: RenderedNone, newNode: None } ->
-            { rendered, patches }
+                { rendered: { renderedLeftOverChildren & root }, patches: patchesLeftOverChildren }
 
+        { oldNode: RenderedNone, newNode: None } -> { rendered, patches }
         _ ->
             # old node has been replaced with a totally different variant. There's no point in diffing, just replace.
             replaceNode { rendered, patches } rendered.root newNode
@@ -334,9 +339,7 @@ deleteNode = \diffState, id ->
         when List.get diffState.rendered.nodes id is
             Ok node ->
                 when node is
-                    Ok (RenderedElement _ _ children) ->
-                        List.walk children diffState deleteNode
-
+                    Ok (RenderedElement _ _ children) -> List.walk children diffState deleteNode
                     _ -> diffState
 
             _ -> diffState
@@ -357,25 +360,22 @@ deleteNode = \diffState, id ->
         List.append patchesRemoveListeners (RemoveNode id)
 
     {
-        rendered: { rendered &
-            nodes: newNodes,
-            deletedNodeCache: newDeletedNodeCache,
-        },
+        rendered: { rendered & nodes: newNodes, deletedNodeCache: newDeletedNodeCache },
         patches: newPatches,
     }
 
-createNode : DiffState state, Html state -> { rendered : RenderedTree state, patches : List Patch, id : NodeId }
+createNode : DiffState state, Html state -> {
+    rendered : RenderedTree state,
+    patches : List Patch,
+    id : NodeId,
+}
 createNode = \{ rendered, patches }, newNode ->
     when newNode is
         Text content ->
             { rendered: newRendered, id } =
                 insertNode rendered (RenderedText content)
 
-            {
-                rendered: newRendered,
-                patches: List.append patches (CreateTextNode id content),
-                id,
-            }
+            { rendered: newRendered, patches: List.append patches (CreateTextNode id content), id }
 
         None ->
             { rendered: newRendered, id } =
@@ -395,11 +395,7 @@ createNode = \{ rendered, patches }, newNode ->
             { rendered: renderedWithNode } =
                 insertNode renderedWithAttrs (RenderedElement tagName renderedAttrs childIds)
 
-            {
-                rendered: renderedWithNode,
-                patches: patchesWithAttrs,
-                id: nodeId,
-            }
+            { rendered: renderedWithNode, patches: patchesWithAttrs, id: nodeId }
 
 AttrDiffState state : {
     nodeId : NodeId,
@@ -426,10 +422,7 @@ diffAttrs = \{ rendered, patches }, nodeId, attrs, newAttrs ->
             deletedHandlerCache: finalState.deletedHandlerCache,
         }
 
-    {
-        rendered: newRendered,
-        patches: finalState.patches,
-    }
+    { rendered: newRendered, patches: finalState.patches }
 
 diffAttr : AttrDiffState state, Attribute state -> AttrDiffState state
 diffAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
@@ -444,7 +437,12 @@ diffAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
                             json = newAccessors |> Encode.toBytes TotallyNotJson.json
 
                             Tuple
-                                { attrs & eventListeners: Dict.insert attrs.eventListeners eventName { accessors, handlerId } }
+                                { attrs &
+                                    eventListeners: Dict.insert attrs.eventListeners eventName {
+                                        accessors,
+                                        handlerId,
+                                    },
+                                }
                                 (
                                     patches
                                     |> List.append (RemoveListener nodeId handlerId)
@@ -472,13 +470,7 @@ diffAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
                             Tuple
                                 { attrs & htmlAttrs: Dict.insert attrs.htmlAttrs k v }
                                 (patches |> List.append (SetAttribute nodeId k v))
-                    {
-                        nodeId,
-                        attrs: newAttrs,
-                        patches: newPatches,
-                        handlers,
-                        deletedHandlerCache,
-                    }
+                    { nodeId, attrs: newAttrs, patches: newPatches, handlers, deletedHandlerCache }
 
                 Err KeyNotFound ->
                     renderAttr { nodeId, attrs, patches, handlers, deletedHandlerCache } attr
@@ -493,13 +485,7 @@ diffAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
                             Tuple
                                 { attrs & domProps: Dict.insert attrs.domProps k v }
                                 (patches |> List.append (SetProperty nodeId k v))
-                    {
-                        nodeId,
-                        attrs: newAttrs,
-                        patches: newPatches,
-                        handlers,
-                        deletedHandlerCache,
-                    }
+                    { nodeId, attrs: newAttrs, patches: newPatches, handlers, deletedHandlerCache }
 
                 Err KeyNotFound ->
                     renderAttr { nodeId, attrs, patches, handlers, deletedHandlerCache } attr
@@ -514,18 +500,16 @@ diffAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
                             Tuple
                                 { attrs & styles: Dict.insert attrs.styles k v }
                                 (patches |> List.append (SetStyle nodeId k v))
-                    {
-                        nodeId,
-                        attrs: newAttrs,
-                        patches: newPatches,
-                        handlers,
-                        deletedHandlerCache,
-                    }
+                    { nodeId, attrs: newAttrs, patches: newPatches, handlers, deletedHandlerCache }
 
                 Err KeyNotFound ->
                     renderAttr { nodeId, attrs, patches, handlers, deletedHandlerCache } attr
 
-renderAttrs : List (Attribute state), RenderedTree state, List Patch, NodeId -> { renderedAttrs : RenderedAttributes, rendered : RenderedTree state, patches : List Patch }
+renderAttrs : List (Attribute state), RenderedTree state, List Patch, NodeId -> {
+    renderedAttrs : RenderedAttributes,
+    rendered : RenderedTree state,
+    patches : List Patch,
+}
 renderAttrs = \attrs, rendered, patches, nodeId ->
     initState = {
         nodeId,
@@ -599,7 +583,12 @@ renderAttr = \{ nodeId, attrs, patches, handlers, deletedHandlerCache }, attr ->
 
             {
                 nodeId,
-                attrs: { attrs & eventListeners: Dict.insert attrs.eventListeners eventType { accessors, handlerId } },
+                attrs: { attrs &
+                    eventListeners: Dict.insert attrs.eventListeners eventType {
+                        accessors,
+                        handlerId,
+                    },
+                },
                 handlers: newHandlers,
                 deletedHandlerCache: newDeletedHandlerCache,
                 patches: List.append patches patch,
@@ -613,11 +602,7 @@ createChildNode = \{ rendered, patches, ids }, childHtml ->
     { rendered: renderedChild, patches: childPatches, id } =
         createNode { rendered, patches } childHtml
 
-    {
-        rendered: renderedChild,
-        patches: childPatches,
-        ids: List.append ids id,
-    }
+    { rendered: renderedChild, patches: childPatches, ids: List.append ids id }
 
 # insert a node into the nodes list, assigning it a NodeId
 insertNode : RenderedTree state, RenderedNode -> { rendered : RenderedTree state, id : NodeId }
@@ -634,9 +619,7 @@ insertNode = \rendered, node ->
 
         Err _ ->
             newRendered =
-                { rendered &
-                    nodes: List.append rendered.nodes (Ok node),
-                }
+                { rendered & nodes: List.append rendered.nodes (Ok node) }
 
             { rendered: newRendered, id: List.len rendered.nodes }
 
@@ -672,7 +655,9 @@ expect
     expected = {
         nodes: [
             RenderedText "Roc",
-            RenderedElement "a" { emptyRenderedAttrs & htmlAttrs: Dict.fromList [("href", "https://www.roc-lang.org/")] } [0],
+            RenderedElement "a" { emptyRenderedAttrs &
+                htmlAttrs: Dict.fromList [("href", "https://www.roc-lang.org/")],
+            } [0],
         ],
         siblingIds: [1],
     }
@@ -763,11 +748,7 @@ expect
         ]
 
     app : App State State
-    app = {
-        init,
-        render,
-        wasmUrl: "assets/test.wasm",
-    }
+    app = { init, render, wasmUrl: "assets/test.wasm" }
 
     initJson : List U8
     initJson =
@@ -781,7 +762,9 @@ expect
                 Ok (RenderedText "The app"),
                 Ok (RenderedElement "h1" emptyRenderedAttrs [0]),
                 Ok (RenderedText "The answer is 42"),
-                Ok (RenderedElement "div" { emptyRenderedAttrs & eventListeners: Dict.fromList [("click", { accessors: [], handlerId: 0 })] } [2]),
+                Ok (RenderedElement "div" { emptyRenderedAttrs &
+                    eventListeners: Dict.fromList [("click", { accessors: [], handlerId: 0 })],
+                } [2]),
                 Ok (RenderedElement "body" emptyRenderedAttrs [1, 3]),
             ],
             deletedNodeCache: [],
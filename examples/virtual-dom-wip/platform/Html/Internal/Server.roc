@@ -12,9 +12,7 @@ import TotallyNotJson
 appendRenderedStatic : Str, Html [] -> Str
 appendRenderedStatic = \buffer, node ->
     when node is
-        Text content ->
-            Str.concat buffer content
-
+        Text content -> Str.concat buffer content
         Element name _ attrs children ->
             withTagName = "$(buffer)<$(name)"
             withAttrs =
@@ -37,7 +35,10 @@ appendRenderedStatic = \buffer, node ->
 
         None -> buffer
 
-appendRenderedStaticAttr : { buffer : Str, styles : Str }, Attribute [] -> { buffer : Str, styles : Str }
+appendRenderedStaticAttr : { buffer : Str, styles : Str }, Attribute [] -> {
+    buffer : Str,
+    styles : Str,
+}
 appendRenderedStaticAttr = \{ buffer, styles }, attr ->
     when attr is
         HtmlAttr key value ->
@@ -107,11 +108,7 @@ insertRocScript = \document, initData, wasmUrl, hostJavaScript ->
                                 foundBody: Bool.true,
                             }
 
-                        _ ->
-                            {
-                                newHtmlChildren: List.append newHtmlChildren hChild,
-                                foundBody,
-                            }
+                        _ -> { newHtmlChildren: List.append newHtmlChildren hChild, foundBody }
 
             if walkResult.foundBody then
                 Ok (Element "html" hSize hAttrs walkResult.newHtmlChildren)
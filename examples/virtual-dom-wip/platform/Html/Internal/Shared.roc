@@ -23,11 +23,7 @@ App state initData : {
 
 DecodingResult a : Result a [Leftover (List U8), TooShort]
 
-Html state : [
-    None,
-    Text Str,
-    Element Str Size (List (Attribute state)) (List (Html state)),
-]
+Html state : [None, Text Str, Element Str Size (List (Attribute state)) (List (Html state))]
 
 # The pre-calculated byte size of the rendered HTML string
 Size : U64
@@ -49,7 +45,11 @@ CyclicStructureAccessor : [
 # At some point we need a common format anyway. Wrapper lambda is irrelevant for perf in context of an event.
 Handler state : [
     Normal (state, List (List U8) -> Action state),
-    Custom (state, List (List U8) -> { action : Action state, stopPropagation : Bool, preventDefault : Bool }),
+    Custom (state, List (List U8) -> {
+        action : Action state,
+        stopPropagation : Bool,
+        preventDefault : Bool,
+    }),
 ]
 
 # -------------------------------
@@ -93,9 +93,7 @@ attrSize = \attr ->
 translate : Html c, (p -> c), (c -> p) -> Html p
 translate = \node, parentToChild, childToParent ->
     when node is
-        Text content ->
-            Text content
-
+        Text content -> Text content
         Element name size attrs children ->
             newAttrs = List.map attrs \a -> translateAttr a parentToChild childToParent
             newChildren = List.map children \c -> translate c parentToChild childToParent
@@ -134,9 +132,7 @@ translateHandler = \childHandler, parentToChild, childToParent ->
 translateStatic : Html state -> Html *
 translateStatic = \node ->
     when node is
-        Text content ->
-            Text content
-
+        Text content -> Text content
         Element name size attrs children ->
             newAttrs = List.keepOks attrs keepStaticAttr
             newChildren = List.map children translateStatic
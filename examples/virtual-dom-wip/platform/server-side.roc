@@ -19,11 +19,6 @@ main = \initJson, hostJavaScript ->
     |> Result.map renderStatic
     |> Result.mapErr \err ->
         when err is
-            TooShort ->
-                "JSON initialization data is too short!"
-
-            Leftover _ ->
-                "JSON initialization data is too long!"
-
-            InvalidDocument ->
-                "The HTML document must be an <html> tag containing a <body>"
+            TooShort -> "JSON initialization data is too short!"
+            Leftover _ -> "JSON initialization data is too long!"
+            InvalidDocument -> "The HTML document must be an <html> tag containing a <body>"
@@ -26,10 +26,7 @@ main = \req ->
     # Handle any application errors
     handleReq |> Task.onErr handleErr
 
-AppError : [
-    EnvURLNotFound,
-    HttpError Http.Error,
-]
+AppError : [EnvURLNotFound, HttpError Http.Error]
 
 logRequest : Request -> Task {} AppError
 logRequest = \req ->
@@ -63,9 +60,7 @@ handleErr = \err ->
     # Respond with Http 500 Error
     Task.ok {
         status: 500,
-        headers: [
-            { name: "Content-Type", value: Str.toUtf8 "text/html; charset=utf-8" },
-        ],
+        headers: [{ name: "Content-Type", value: Str.toUtf8 "text/html; charset=utf-8" }],
         body: Str.toUtf8 "Error 500 Internal Server Error\n",
     }
 
@@ -74,8 +69,6 @@ respond : U16, Str -> Task Response AppError
 respond = \code, body ->
     Task.ok {
         status: code,
-        headers: [
-            { name: "Content-Type", value: Str.toUtf8 "text/html; charset=utf-8" },
-        ],
+        headers: [{ name: "Content-Type", value: Str.toUtf8 "text/html; charset=utf-8" }],
         body: Str.toUtf8 body,
     }